@@ -1,9 +1,14 @@
 use alloc::collections::BTreeMap;
 
 use proc_macro2::Ident;
+use proc_macro2::Span;
 use proc_macro2::TokenStream;
 use quote::quote;
+use quote::quote_spanned;
+use syn::ext::IdentExt;
 use syn::parse::ParseStream;
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
 use syn::Attribute;
 use syn::Expr;
 use syn::Field;
@@ -11,7 +16,9 @@ use syn::FieldsNamed;
 use syn::FieldsUnnamed;
 use syn::Lit;
 use syn::LitStr;
+use syn::Path;
 use syn::Token;
+use syn::Type;
 use syn::UseTree;
 
 mod kw {
@@ -23,6 +30,18 @@ mod kw {
     syn::custom_keyword!(opt_out);
     syn::custom_keyword!(crate_name);
     syn::custom_keyword!(from_reflect_with);
+    syn::custom_keyword!(remote);
+    syn::custom_keyword!(no_from_reflect_fast_path);
+    syn::custom_keyword!(table_driven);
+    syn::custom_keyword!(rename);
+    syn::custom_keyword!(rename_all);
+    syn::custom_keyword!(sensitive);
+    syn::custom_keyword!(default);
+    syn::custom_keyword!(default_with);
+    syn::custom_keyword!(flatten);
+    syn::custom_keyword!(docs);
+    syn::custom_keyword!(validate_with);
+    syn::custom_keyword!(allow_hash_map);
 }
 
 #[derive(Clone)]
@@ -31,12 +50,29 @@ pub(super) struct ItemAttrs {
     pub(super) clone_opt_out: bool,
     pub(super) from_reflect_opt_out: bool,
     pub(super) crate_name: UseTree,
+    pub(super) remote: Option<Path>,
+    pub(super) no_from_reflect_fast_path: bool,
+    pub(super) table_driven: bool,
+    pub(super) rename: Option<LitStr>,
+    pub(super) rename_all: Option<RenameRule>,
+    /// The integer type from `#[repr(...)]`, if any.
+    pub(super) repr: Option<Ident>,
+    /// The type's const generic parameters, included in its name if it's renamed.
+    pub(super) const_params: Vec<Ident>,
+    /// `#[reflect(docs(skip))]`, which leaves out the docs of the type, its fields, and its
+    /// variants.
+    pub(super) skip_docs: bool,
+    /// `#[reflect(validate_with(...))]`, checking values of the type.
+    pub(super) validate_with: Option<Path>,
+    /// `#[reflect(allow_hash_map)]`, which allows fields of type `HashMap` despite them iterating
+    /// in an unstable order.
+    pub(super) allow_hash_map: bool,
     meta: BTreeMap<Ident, Expr>,
     docs: Vec<LitStr>,
 }
 
 impl ItemAttrs {
-    fn new(docs: Vec<LitStr>) -> Self {
+    fn new(docs: Vec<LitStr>, repr: Option<Ident>) -> Self {
         Self {
             debug_opt_out: Default::default(),
             clone_opt_out: Default::default(),
@@ -44,11 +80,22 @@ impl ItemAttrs {
             meta: Default::default(),
             docs,
             crate_name: syn::parse_quote!(mirror_mirror),
+            remote: None,
+            no_from_reflect_fast_path: false,
+            table_driven: false,
+            rename: None,
+            rename_all: None,
+            repr,
+            const_params: Vec::new(),
+            skip_docs: false,
+            validate_with: None,
+            allow_hash_map: false,
         }
     }
 
     pub(super) fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
         let docs = parse_docs(attrs);
+        let repr = parse_repr(attrs);
 
         let mut reflect_attrs = attrs
             .iter()
@@ -56,7 +103,7 @@ impl ItemAttrs {
             .peekable();
 
         let Some(attr) = reflect_attrs.next() else {
-            return Ok(Self::new(docs));
+            return Ok(Self::new(docs, repr));
         };
 
         if let Some(next) = reflect_attrs.peek() {
@@ -67,7 +114,7 @@ impl ItemAttrs {
         }
 
         attr.parse_args_with(|input: ParseStream<'_>| {
-            let mut item_attrs = Self::new(docs);
+            let mut item_attrs = Self::new(docs, repr);
 
             while !input.is_empty() {
                 let lh = input.lookahead1();
@@ -110,11 +157,53 @@ impl ItemAttrs {
 
                         let _ = content.parse::<Token![,]>();
                     }
+                } else if lh.peek(kw::sensitive) {
+                    let keyword = input.parse::<kw::sensitive>()?;
+                    insert_sensitive(&mut item_attrs.meta, keyword)?;
                 } else if lh.peek(kw::crate_name) {
                     input.parse::<kw::crate_name>()?;
                     let content;
                     syn::parenthesized!(content in input);
                     item_attrs.crate_name = content.parse()?;
+                } else if lh.peek(kw::remote) {
+                    input.parse::<kw::remote>()?;
+                    input.parse::<Token![=]>()?;
+                    item_attrs.remote = Some(input.parse()?);
+                } else if lh.peek(kw::no_from_reflect_fast_path) {
+                    input.parse::<kw::no_from_reflect_fast_path>()?;
+                    item_attrs.no_from_reflect_fast_path = true;
+                } else if lh.peek(kw::table_driven) {
+                    input.parse::<kw::table_driven>()?;
+                    item_attrs.table_driven = true;
+                } else if lh.peek(kw::default_with) {
+                    input.parse::<kw::default_with>()?;
+                    let content;
+                    syn::parenthesized!(content in input);
+                    let keyword = Ident::new("default", input.span());
+                    let function = content.parse::<Path>()?;
+                    insert_default(
+                        &mut item_attrs.meta,
+                        keyword,
+                        syn::parse_quote!(#function()),
+                    )?;
+                    let _ = content.parse::<Token![,]>();
+                } else if lh.peek(kw::rename_all) {
+                    input.parse::<kw::rename_all>()?;
+                    input.parse::<Token![=]>()?;
+                    item_attrs.rename_all = Some(RenameRule::parse(&input.parse()?)?);
+                } else if lh.peek(kw::rename) {
+                    input.parse::<kw::rename>()?;
+                    input.parse::<Token![=]>()?;
+                    item_attrs.rename = Some(input.parse()?);
+                } else if lh.peek(kw::validate_with) {
+                    item_attrs.validate_with = Some(parse_validate_with(input)?);
+                } else if lh.peek(kw::allow_hash_map) {
+                    input.parse::<kw::allow_hash_map>()?;
+                    item_attrs.allow_hash_map = true;
+                } else if lh.peek(kw::docs) {
+                    parse_docs_skip(input)?;
+                    item_attrs.skip_docs = true;
+                    item_attrs.docs.clear();
                 } else {
                     return Err(lh.error());
                 }
@@ -126,6 +215,12 @@ impl ItemAttrs {
         })
     }
 
+    /// Whether `FromReflect` should first try downcasting fields and cloning them, before falling
+    /// back to `FromReflect`. That requires the type to be `Clone`.
+    pub(super) fn use_from_reflect_fast_path(&self) -> bool {
+        !self.clone_opt_out && !self.no_from_reflect_fast_path
+    }
+
     pub(super) fn fn_debug_tokens(&self) -> TokenStream {
         if self.debug_opt_out {
             quote! {
@@ -163,6 +258,28 @@ impl ItemAttrs {
         }
     }
 
+    /// `Reflect::validate_self` which checks each field with `field_checks` and then the value
+    /// itself with `#[reflect(validate_with(...))]`.
+    pub(super) fn fn_validate_self_tokens(&self, field_checks: Vec<TokenStream>) -> TokenStream {
+        if field_checks.is_empty() && self.validate_with.is_none() {
+            return TokenStream::new();
+        }
+        let type_check = self.validate_with.as_ref().map(|validator| {
+            quote! {
+                if let Err(reason) = #validator(self) {
+                    return Err(invalid_value(self, reason));
+                }
+            }
+        });
+        quote! {
+            fn validate_self(&self) -> Result<(), ReflectError> {
+                #(#field_checks)*
+                #type_check
+                Ok(())
+            }
+        }
+    }
+
     pub(super) fn meta(&self) -> TokenStream {
         tokenize_meta(&self.meta)
     }
@@ -171,8 +288,160 @@ impl ItemAttrs {
         let docs = &self.docs;
         quote! { &[#(#docs,)*] }
     }
+
+    /// The name of the type as seen through reflection.
+    pub(super) fn type_name(&self) -> TokenStream {
+        match &self.rename {
+            Some(rename) if !self.const_params.is_empty() => {
+                let const_params = &self.const_params;
+                quote! {
+                    const_generic_type_name(#rename, &[#(&#const_params),*])
+                }
+            }
+            Some(rename) => quote! { #rename },
+            None => quote! { ::core::any::type_name::<Self>() },
+        }
+    }
+
+    /// Tokens to append to the type's node in `DescribeType` and a `Reflect::type_name` override,
+    /// if the type has been renamed.
+    ///
+    /// Renamed types with const generic parameters get names like `Grid<3>`, which aren't known
+    /// until the parameters are, so they're taken from the type descriptor instead.
+    pub(super) fn rename_tokens(&self) -> (TokenStream, TokenStream) {
+        match &self.rename {
+            Some(_) if !self.const_params.is_empty() => {
+                let type_name = self.type_name();
+                (
+                    quote! { .with_type_name(&#type_name) },
+                    quote! {
+                        fn type_name(&self) -> &str {
+                            described_type_name::<Self>()
+                        }
+                    },
+                )
+            }
+            Some(rename) => (
+                quote! { .with_type_name(#rename) },
+                quote! {
+                    fn type_name(&self) -> &str {
+                        #rename
+                    }
+                },
+            ),
+            None => Default::default(),
+        }
+    }
 }
 
+/// A `#[reflect(rename_all = "...")]` rule, with the same names as serde's.
+#[derive(Clone, Copy)]
+pub(super) enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+}
+
+impl RenameRule {
+    fn parse(lit: &LitStr) -> syn::Result<Self> {
+        Ok(match &*lit.value() {
+            "lowercase" => Self::Lower,
+            "UPPERCASE" => Self::Upper,
+            "PascalCase" => Self::Pascal,
+            "camelCase" => Self::Camel,
+            "snake_case" => Self::Snake,
+            "SCREAMING_SNAKE_CASE" => Self::ScreamingSnake,
+            "kebab-case" => Self::Kebab,
+            "SCREAMING-KEBAB-CASE" => Self::ScreamingKebab,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    lit,
+                    "unknown rename rule. Expected one of `lowercase`, `UPPERCASE`, \
+                    `PascalCase`, `camelCase`, `snake_case`, `SCREAMING_SNAKE_CASE`, \
+                    `kebab-case`, or `SCREAMING-KEBAB-CASE`",
+                ))
+            }
+        })
+    }
+
+    /// Apply the rule to a `snake_case` field name or `PascalCase` variant name.
+    fn apply(self, name: &str) -> String {
+        let words = split_words(name);
+        let capitalize = |word: &str| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        };
+
+        match self {
+            Self::Lower => words.concat(),
+            Self::Upper => words.concat().to_uppercase(),
+            Self::Pascal => words.iter().map(|word| capitalize(word)).collect(),
+            Self::Camel => words
+                .iter()
+                .enumerate()
+                .map(|(index, word)| {
+                    if index == 0 {
+                        word.clone()
+                    } else {
+                        capitalize(word)
+                    }
+                })
+                .collect(),
+            Self::Snake => words.join("_"),
+            Self::ScreamingSnake => words.join("_").to_uppercase(),
+            Self::Kebab => words.join("-"),
+            Self::ScreamingKebab => words.join("-").to_uppercase(),
+        }
+    }
+}
+
+/// Split a `snake_case` or `PascalCase` name into lowercase words.
+fn split_words(name: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    for part in name.split('_').filter(|part| !part.is_empty()) {
+        let mut word = String::new();
+        let mut prev_lowercase = false;
+        for c in part.chars() {
+            if c.is_uppercase() && prev_lowercase {
+                words.push(core::mem::take(&mut word));
+            }
+            prev_lowercase = c.is_lowercase() || c.is_ascii_digit();
+            word.extend(c.to_lowercase());
+        }
+        words.push(word);
+    }
+    words
+}
+
+/// The name of a field or variant as seen through reflection.
+///
+/// Names that aren't renamed are stringified as is, so raw identifiers keep their `r#` prefix.
+fn reflect_name(
+    ident: &Ident,
+    rename: Option<&LitStr>,
+    rename_all: Option<RenameRule>,
+) -> TokenStream {
+    if let Some(rename) = rename {
+        return quote! { #rename };
+    }
+    match rename_all {
+        Some(rule) => {
+            let name = rule.apply(&ident.unraw().to_string());
+            quote! { #name }
+        }
+        None => quote! { ::core::stringify!(#ident) },
+    }
+}
+
+/// Doc comments captured as docs of the reflected item.
 fn parse_docs(attrs: &[Attribute]) -> Vec<LitStr> {
     attrs
         .iter()
@@ -190,10 +459,166 @@ fn parse_docs(attrs: &[Attribute]) -> Vec<LitStr> {
         .collect::<Vec<_>>()
 }
 
+/// Parse `validate_with(function)`.
+fn parse_validate_with(input: ParseStream<'_>) -> syn::Result<Path> {
+    input.parse::<kw::validate_with>()?;
+    let content;
+    syn::parenthesized!(content in input);
+    let validator = content.parse()?;
+    let _ = content.parse::<Token![,]>();
+    Ok(validator)
+}
+
+/// `Reflect::patch` which undoes the patch, made by `body`, if it leaves the value invalid.
+///
+/// The value is restored by swapping in a copy from before, rather than patching it back, since
+/// values such as function pointers can't be patched back from their `Value`s.
+pub(super) fn fn_patch_tokens(validated: bool, body: TokenStream) -> TokenStream {
+    if validated {
+        quote! {
+            fn patch(&mut self, value: &dyn Reflect) {
+                let mut backup = Reflect::clone_reflect(self);
+                #body
+                if Reflect::validate_self(self).is_err() {
+                    if let Some(backup) = backup.downcast_mut::<Self>() {
+                        ::core::mem::swap(self, backup);
+                    }
+                }
+            }
+        }
+    } else {
+        quote! {
+            fn patch(&mut self, value: &dyn Reflect) {
+                #body
+            }
+        }
+    }
+}
+
+/// `FromReflect::from_reflect` that builds the value with `body` and, if the type is
+/// validated, checks it. Validated types also get a `FromReflect::try_from_reflect` that
+/// reports what was rejected.
+pub(super) fn fn_from_reflect_tokens(validated: bool, body: TokenStream) -> TokenStream {
+    if validated {
+        quote! {
+            fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+                Self::try_from_reflect(reflect).ok()
+            }
+
+            fn try_from_reflect(reflect: &dyn Reflect) -> Result<Self, ReflectError>
+            where
+                Self: DescribeType,
+            {
+                #[allow(clippy::redundant_closure_call)]
+                let value = (|| -> Option<Self> { #body })()
+                    .ok_or_else(|| from_reflect_error::<Self>(reflect))?;
+                Reflect::validate_self(&value)?;
+                Ok(value)
+            }
+        }
+    } else {
+        quote! {
+            fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+                #body
+            }
+        }
+    }
+}
+
+/// Check a field with its `#[reflect(validate_with(...))]` validator in `Reflect::validate_self`.
+///
+/// `access` is an expression for the field, such as `self.foo`, and `name` its name or index.
+pub(super) fn validate_field_tokens(
+    validator: &Path,
+    access: TokenStream,
+    name: TokenStream,
+) -> TokenStream {
+    quote! {
+        if let Err(reason) = #validator(&#access) {
+            return Err(invalid_field(&#access, reason, #name));
+        }
+    }
+}
+
+/// Parse `docs(skip)`, the only option for docs.
+fn parse_docs_skip(input: ParseStream<'_>) -> syn::Result<()> {
+    input.parse::<kw::docs>()?;
+    let content;
+    syn::parenthesized!(content in input);
+    content.parse::<kw::skip>()?;
+    let _ = content.parse::<Token![,]>();
+    if !content.is_empty() {
+        return Err(content.error("expected `)`"));
+    }
+    Ok(())
+}
+
+/// Find the integer type in `#[repr(...)]`, ignoring other representation hints such as `C`.
+fn parse_repr(attrs: &[Attribute]) -> Option<Ident> {
+    const INTEGERS: &[&str] = &[
+        "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+    ];
+
+    attrs
+        .iter()
+        .filter(|attr| attr.meta.path().is_ident("repr"))
+        .filter_map(|attr| {
+            attr.parse_args_with(Punctuated::<Ident, Token![,]>::parse_terminated)
+                .ok()
+        })
+        .flatten()
+        .find(|ident| INTEGERS.iter().any(|integer| ident == integer))
+}
+
+/// `#[reflect(default = ...)]` and `#[reflect(default_with(...))]` are stored as the metadata
+/// `default` so `TypeDescriptor::default_value` can use them.
+fn insert_default(meta: &mut BTreeMap<Ident, Expr>, ident: Ident, expr: Expr) -> syn::Result<()> {
+    if meta.contains_key(&ident) {
+        return Err(syn::Error::new_spanned(
+            &ident,
+            "`default` specified more than once",
+        ));
+    }
+    meta.insert(ident, expr);
+    Ok(())
+}
+
+/// `#[reflect(sensitive)]` is stored as the metadata `sensitive = true` so it can be read with
+/// `GetMeta::is_sensitive`.
+fn insert_sensitive(meta: &mut BTreeMap<Ident, Expr>, keyword: kw::sensitive) -> syn::Result<()> {
+    let ident = Ident::new("sensitive", keyword.span);
+    if meta.contains_key(&ident) {
+        return Err(syn::Error::new_spanned(
+            &ident,
+            "`sensitive` specified more than once",
+        ));
+    }
+    meta.insert(ident, syn::parse_quote!(true));
+    Ok(())
+}
+
+/// Metadata keys with a known type of value, and their `mirror_mirror::meta::known` type.
+const KNOWN_META: &[(&str, &str)] = &[
+    ("read_only", "ReadOnly"),
+    ("range", "Range"),
+    ("tooltip", "Tooltip"),
+    ("category", "Category"),
+];
+
 fn tokenize_meta(meta: &BTreeMap<Ident, Expr>) -> TokenStream {
     let pairs = meta.iter().map(|(ident, expr)| {
-        quote! {
-            (stringify!(#ident), IntoValue::into_value(#expr)),
+        let known = KNOWN_META
+            .iter()
+            .find(|(key, _)| ident == key)
+            .map(|(_, known)| Ident::new(known, Span::call_site()));
+        match known {
+            // spanned so type errors point at the value
+            Some(known) => quote_spanned! {expr.span()=>
+                (stringify!(#ident), typed_meta::<known_meta::#known, _>(#expr)),
+            },
+            None => quote! {
+                (stringify!(#ident), IntoValue::into_value(#expr)),
+            },
         }
     });
     quote! {
@@ -203,25 +628,49 @@ fn tokenize_meta(meta: &BTreeMap<Ident, Expr>) -> TokenStream {
 
 pub(super) struct AttrsDatabase<T> {
     map: BTreeMap<T, InnerAttrs>,
+    rename_all: Option<RenameRule>,
 }
 
 impl AttrsDatabase<Ident> {
-    pub(super) fn new_from_named(fields: &FieldsNamed) -> syn::Result<Self> {
+    pub(super) fn new_from_named(
+        fields: &FieldsNamed,
+        rename_all: Option<RenameRule>,
+    ) -> syn::Result<Self> {
         let map = fields
             .named
             .iter()
             .map(|field| {
-                let attrs = InnerAttrs::parse(&field.attrs)?;
+                let mut attrs = InnerAttrs::parse(&field.attrs)?;
+                attrs.default_into_meta(&field.ty)?;
                 Ok((field.ident.clone().unwrap(), attrs))
             })
             .collect::<syn::Result<BTreeMap<_, _>>>()?;
 
-        Ok(Self { map })
+        Ok(Self { map, rename_all })
     }
 
     pub(super) fn filter_out_skipped_named(&self) -> impl Fn(&&Field) -> bool + '_ {
         move |field| !self.skip(field.ident.as_ref().unwrap())
     }
+
+    /// Whether the field is `#[reflect(flatten)]`, making its fields appear as fields of the
+    /// parent.
+    pub(super) fn flatten(&self, ident: &Ident) -> bool {
+        self.map
+            .get(ident)
+            .map(|attrs| attrs.flatten.is_some())
+            .unwrap_or_default()
+    }
+
+    pub(super) fn any_flattened(&self) -> bool {
+        self.map.values().any(|attrs| attrs.flatten.is_some())
+    }
+
+    /// The name of the field as seen through reflection.
+    pub(super) fn name(&self, ident: &Ident) -> TokenStream {
+        let rename = self.map.get(ident).and_then(|attrs| attrs.rename.as_ref());
+        reflect_name(ident, rename, self.rename_all)
+    }
 }
 
 impl AttrsDatabase<usize> {
@@ -231,12 +680,16 @@ impl AttrsDatabase<usize> {
             .iter()
             .enumerate()
             .map(|(index, field)| {
-                let attrs = InnerAttrs::parse(&field.attrs)?;
+                let mut attrs = InnerAttrs::parse_unnamed(&field.attrs)?;
+                attrs.default_into_meta(&field.ty)?;
                 Ok((index, attrs))
             })
             .collect::<syn::Result<BTreeMap<_, _>>>()?;
 
-        Ok(Self { map })
+        Ok(Self {
+            map,
+            rename_all: None,
+        })
     }
 
     pub(super) fn filter_out_skipped_unnamed<T>(&self) -> impl Fn(&(usize, T)) -> bool + '_ {
@@ -266,15 +719,39 @@ where
             })
     }
 
+    /// The `#[reflect(validate_with(...))]` validator of the field, if any.
+    pub(super) fn validate_with(&self, key: &T) -> Option<&Path> {
+        self.map.get(key)?.validate_with.as_ref()
+    }
+
+    pub(super) fn any_validated(&self) -> bool {
+        self.map.values().any(|attrs| attrs.validate_with.is_some())
+    }
+
     pub(super) fn docs(&self, key: &T) -> TokenStream {
         let docs = self.map.get(key).into_iter().flat_map(|attrs| &attrs.docs);
         quote! { &[#(#docs,)*] }
     }
 
+    /// Leave out the docs of all fields, for types with `#[reflect(docs(skip))]`.
+    pub(super) fn skip_docs(&mut self) {
+        for attrs in self.map.values_mut() {
+            attrs.docs.clear();
+        }
+    }
+
     #[allow(clippy::wrong_self_convention)]
     pub(super) fn from_reflect_with(&self, key: &T) -> Option<&Ident> {
         self.map.get(key)?.from_reflect_with.as_ref()
     }
+
+    /// The value of a skipped field in `FromReflect`.
+    pub(super) fn skipped_value(&self, key: &T) -> TokenStream {
+        match self.map.get(key) {
+            Some(attrs) => attrs.skipped_value(),
+            None => quote! { ::core::default::Default::default() },
+        }
+    }
 }
 
 pub(super) struct InnerAttrs {
@@ -282,6 +759,10 @@ pub(super) struct InnerAttrs {
     pub(super) meta: BTreeMap<Ident, Expr>,
     pub(super) docs: Vec<LitStr>,
     pub(super) from_reflect_with: Option<Ident>,
+    pub(super) rename: Option<LitStr>,
+    pub(super) default: Option<Expr>,
+    pub(super) flatten: Option<kw::flatten>,
+    pub(super) validate_with: Option<Path>,
 }
 
 impl InnerAttrs {
@@ -290,10 +771,32 @@ impl InnerAttrs {
             skip: Default::default(),
             meta: Default::default(),
             from_reflect_with: Default::default(),
+            rename: Default::default(),
+            default: Default::default(),
+            flatten: Default::default(),
+            validate_with: Default::default(),
             docs,
         }
     }
 
+    /// Like [`InnerAttrs::parse`] but for unnamed fields, which cannot be renamed.
+    pub(super) fn parse_unnamed(attrs: &[Attribute]) -> syn::Result<Self> {
+        let parsed = Self::parse(attrs)?;
+        if let Some(rename) = &parsed.rename {
+            return Err(syn::Error::new_spanned(
+                rename,
+                "`rename` is only supported on named fields and variants",
+            ));
+        }
+        parsed.reject_flatten()?;
+        Ok(parsed)
+    }
+
+    /// The name of the field or variant as seen through reflection.
+    pub(super) fn name(&self, ident: &Ident, rename_all: Option<RenameRule>) -> TokenStream {
+        reflect_name(ident, self.rename.as_ref(), rename_all)
+    }
+
     pub(super) fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
         let docs = parse_docs(attrs);
 
@@ -338,12 +841,30 @@ impl InnerAttrs {
 
                         let _ = content.parse::<Token![,]>();
                     }
+                } else if lh.peek(kw::sensitive) {
+                    let keyword = input.parse::<kw::sensitive>()?;
+                    insert_sensitive(&mut field_attrs.meta, keyword)?;
                 } else if lh.peek(kw::from_reflect_with) {
                     input.parse::<kw::from_reflect_with>()?;
                     let content;
                     syn::parenthesized!(content in input);
                     field_attrs.from_reflect_with = Some(content.parse()?);
                     let _ = content.parse::<Token![,]>();
+                } else if lh.peek(kw::rename) {
+                    input.parse::<kw::rename>()?;
+                    input.parse::<Token![=]>()?;
+                    field_attrs.rename = Some(input.parse()?);
+                } else if lh.peek(kw::default) {
+                    input.parse::<kw::default>()?;
+                    input.parse::<Token![=]>()?;
+                    field_attrs.default = Some(input.parse()?);
+                } else if lh.peek(kw::flatten) {
+                    field_attrs.flatten = Some(input.parse()?);
+                } else if lh.peek(kw::validate_with) {
+                    field_attrs.validate_with = Some(parse_validate_with(input)?);
+                } else if lh.peek(kw::docs) {
+                    parse_docs_skip(input)?;
+                    field_attrs.docs.clear();
                 } else {
                     return Err(lh.error());
                 }
@@ -351,10 +872,69 @@ impl InnerAttrs {
                 let _ = input.parse::<Token![,]>();
             }
 
+            field_attrs.check_flatten()?;
+            field_attrs.check_validate_with()?;
+
             Ok(field_attrs)
         })
     }
 
+    /// Flattened fields have no name or value of their own, so they can't be combined with
+    /// attributes that apply to one.
+    fn check_flatten(&self) -> syn::Result<()> {
+        let Some(flatten) = &self.flatten else {
+            return Ok(());
+        };
+        let conflicting = [
+            ("skip", self.skip),
+            ("rename", self.rename.is_some()),
+            ("default", self.default.is_some()),
+            ("from_reflect_with", self.from_reflect_with.is_some()),
+            ("meta", !self.meta.is_empty()),
+            ("validate_with", self.validate_with.is_some()),
+        ];
+        match conflicting.into_iter().find(|(_, conflicts)| *conflicts) {
+            Some((name, _)) => Err(syn::Error::new_spanned(
+                flatten,
+                format!("`flatten` can't be combined with `{name}`"),
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Skipped fields aren't reflected so there is nothing to report a violation against.
+    fn check_validate_with(&self) -> syn::Result<()> {
+        match &self.validate_with {
+            Some(validator) if self.skip => Err(syn::Error::new_spanned(
+                validator,
+                "`validate_with` can't be combined with `skip`",
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Reject `#[reflect(validate_with(...))]` on variants and their fields.
+    pub(super) fn reject_validate_with(&self) -> syn::Result<()> {
+        match &self.validate_with {
+            Some(validator) => Err(syn::Error::new_spanned(
+                validator,
+                "`validate_with` is only supported on types and fields of structs",
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Reject `#[reflect(flatten)]` outside of named fields of structs.
+    pub(super) fn reject_flatten(&self) -> syn::Result<()> {
+        match &self.flatten {
+            Some(flatten) => Err(syn::Error::new_spanned(
+                flatten,
+                "`flatten` is only supported on named fields of structs",
+            )),
+            None => Ok(()),
+        }
+    }
+
     pub(super) fn meta(&self) -> TokenStream {
         tokenize_meta(&self.meta)
     }
@@ -363,4 +943,39 @@ impl InnerAttrs {
         let docs = &self.docs;
         quote! { &[#(#docs,)*] }
     }
+
+    /// Store `#[reflect(default = ...)]` of a reflected field in its metadata, converted to the
+    /// field's type so literals get the right type.
+    ///
+    /// Skipped fields have no type info so their default is only used by `FromReflect`.
+    pub(super) fn default_into_meta(&mut self, ty: &Type) -> syn::Result<()> {
+        let Some(default) = self.default.as_ref().filter(|_| !self.skip) else {
+            return Ok(());
+        };
+        let ident = Ident::new("default", default.span());
+        let expr = syn::parse_quote!({
+            let default: #ty = #default;
+            default
+        });
+        insert_default(&mut self.meta, ident, expr)
+    }
+
+    /// Reject `#[reflect(default = ...)]` on variants, where it has no meaning.
+    pub(super) fn reject_default(&self) -> syn::Result<()> {
+        match &self.default {
+            Some(default) => Err(syn::Error::new_spanned(
+                default,
+                "`default` is only supported on fields",
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// The value of the field in `FromReflect` if it's skipped.
+    pub(super) fn skipped_value(&self) -> TokenStream {
+        match &self.default {
+            Some(default) => quote! { #default },
+            None => quote! { ::core::default::Default::default() },
+        }
+    }
 }
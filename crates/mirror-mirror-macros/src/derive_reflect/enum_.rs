@@ -1,24 +1,31 @@
 use alloc::borrow::Cow;
 
+use proc_macro2::Literal;
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::DataEnum;
 use syn::Fields;
 use syn::Ident;
+use syn::Path;
 use syn::Type;
 
+use super::attrs::fn_from_reflect_tokens;
+use super::attrs::fn_patch_tokens;
 use super::attrs::InnerAttrs;
 use super::attrs::ItemAttrs;
 use super::Generics;
-use crate::stringify;
 
 pub(super) fn expand(
-    ident: &Ident,
+    ident: &Path,
     enum_: DataEnum,
     attrs: ItemAttrs,
     generics: &Generics<'_>,
 ) -> syn::Result<TokenStream> {
-    let variants = VariantData::try_from_enum(&enum_)?;
+    if enum_.variants.is_empty() {
+        return Ok(expand_zero_variants(ident, &attrs, generics));
+    }
+
+    let variants = VariantData::try_from_enum(&enum_, &attrs)?;
 
     let describe_type = expand_describe_type(ident, &variants, &attrs, generics);
     let reflect = expand_reflect(ident, &variants, &attrs, generics)?;
@@ -34,22 +41,159 @@ pub(super) fn expand(
     })
 }
 
+/// Enums without variants, such as `Infallible`, can never be constructed so every method can
+/// just match on `*self`. We cannot use the regular expansion since that matches on `self` which
+/// is a reference, and references are always considered inhabited.
+fn expand_zero_variants(ident: &Path, attrs: &ItemAttrs, generics: &Generics<'_>) -> TokenStream {
+    let meta = attrs.meta();
+    let docs = attrs.docs();
+    let crate_name = &attrs.crate_name;
+    let (with_type_name, fn_type_name) = attrs.rename_tokens();
+
+    let Generics {
+        impl_generics,
+        type_generics,
+        where_clause,
+    } = generics;
+
+    let from_reflect = (!attrs.from_reflect_opt_out).then(|| {
+        quote! {
+            impl #impl_generics FromReflect for #ident #type_generics #where_clause {
+                fn from_reflect(_reflect: &dyn Reflect) -> Option<Self> {
+                    None
+                }
+            }
+        }
+    });
+
+    quote! {
+        impl #impl_generics DescribeType for #ident #type_generics #where_clause {
+            fn build(graph: &mut TypeGraph) -> NodeId {
+                let variants = &[];
+                graph.get_or_build_node_with::<Self, _>(|_graph| {
+                    EnumNode::new::<Self>(variants, #meta, #docs)#with_type_name
+                })
+            }
+        }
+
+        impl #impl_generics Reflect for #ident #type_generics #where_clause {
+            fn as_any(&self) -> &dyn Any {
+                match *self {}
+            }
+
+            fn as_any_mut(&mut self) -> &mut dyn Any {
+                match *self {}
+            }
+
+            fn as_reflect(&self) -> &dyn Reflect {
+                match *self {}
+            }
+
+            fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+                match *self {}
+            }
+
+            fn type_descriptor(&self) -> Cow<'static, TypeDescriptor> {
+                <Self as DescribeType>::type_descriptor()
+            }
+
+            #fn_type_name
+
+            fn patch(&mut self, _value: &dyn Reflect) {
+                match *self {}
+            }
+
+            fn to_value(&self) -> Value {
+                match *self {}
+            }
+
+            fn clone_reflect(&self) -> Box<dyn Reflect> {
+                match *self {}
+            }
+
+            fn debug(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                match *self {}
+            }
+
+            fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+                match *self {}
+            }
+
+            fn reflect_ref(&self) -> ReflectRef<'_> {
+                match *self {}
+            }
+
+            fn reflect_mut(&mut self) -> ReflectMut<'_> {
+                match *self {}
+            }
+        }
+
+        #from_reflect
+
+        impl #impl_generics Enum for #ident #type_generics #where_clause {
+            fn variant_name(&self) -> &str {
+                match *self {}
+            }
+
+            fn variant_kind(&self) -> VariantKind {
+                match *self {}
+            }
+
+            fn field(&self, _name: &str) -> Option<&dyn Reflect> {
+                match *self {}
+            }
+
+            fn field_mut(&mut self, _name: &str) -> Option<&mut dyn Reflect> {
+                match *self {}
+            }
+
+            fn field_at(&self, _index: usize) -> Option<&dyn Reflect> {
+                match *self {}
+            }
+
+            fn field_at_mut(&mut self, _index: usize) -> Option<&mut dyn Reflect> {
+                match *self {}
+            }
+
+            fn fields(&self) -> #crate_name::enum_::VariantFieldIter<'_> {
+                match *self {}
+            }
+
+            fn fields_mut(&mut self) -> VariantFieldIterMut<'_> {
+                match *self {}
+            }
+
+            fn variants_len(&self) -> usize {
+                match *self {}
+            }
+
+            fn fields_len(&self) -> usize {
+                match *self {}
+            }
+
+            fn name_at(&self, _index: usize) -> Option<&str> {
+                match *self {}
+            }
+        }
+    }
+}
+
 fn expand_describe_type(
-    ident: &Ident,
+    ident: &Path,
     variants: &[VariantData<'_>],
     attrs: &ItemAttrs,
     generics: &Generics<'_>,
 ) -> TokenStream {
     let code_for_variants = variants.iter().filter(filter_out_skipped).map(|variant| {
-        let variant_ident_string = stringify(&variant.ident);
+        let variant_ident_string = &variant.name;
         let meta = variant.attrs.meta();
         let docs = variant.attrs.docs();
+        let with_discriminant = variant.with_discriminant();
 
         match &variant.fields {
             FieldsData::Named(fields) => {
                 let fields = fields.iter().filter(filter_out_skipped).map(|field| {
-                    let ident = &field.ident;
-                    let field_name = stringify(ident);
+                    let field_name = &field.name;
                     let field_ty = &field.ty;
                     let meta = field.attrs.meta();
                     let docs = field.attrs.docs();
@@ -65,7 +209,7 @@ fn expand_describe_type(
                             &[#(#fields),*],
                             #meta,
                             #docs,
-                        )
+                        )#with_discriminant
                     )
                 }
             }
@@ -86,7 +230,7 @@ fn expand_describe_type(
                             &[#(#fields),*],
                             #meta,
                             #docs,
-                        )
+                        )#with_discriminant
                     )
                 }
             }
@@ -95,13 +239,18 @@ fn expand_describe_type(
                     #variant_ident_string,
                     #meta,
                     #docs,
-                ))
+                )#with_discriminant)
             },
         }
     });
 
     let meta = attrs.meta();
     let docs = attrs.docs();
+    let (with_type_name, _) = attrs.rename_tokens();
+    let with_repr = attrs.repr.as_ref().map(|repr| {
+        let repr = repr.to_string();
+        quote! { .with_repr(#repr) }
+    });
 
     let Generics {
         impl_generics,
@@ -114,7 +263,7 @@ fn expand_describe_type(
             fn build(graph: &mut TypeGraph) -> NodeId {
                 let variants = &[#(#code_for_variants),*];
                 graph.get_or_build_node_with::<Self, _>(|graph| {
-                    EnumNode::new::<Self>(variants, #meta, #docs)
+                    EnumNode::new::<Self>(variants, #meta, #docs)#with_type_name #with_repr
                 })
             }
         }
@@ -122,7 +271,7 @@ fn expand_describe_type(
 }
 
 fn expand_reflect(
-    ident: &Ident,
+    ident: &Path,
     variants: &[VariantData<'_>],
     attrs: &ItemAttrs,
     generics: &Generics<'_>,
@@ -136,7 +285,7 @@ fn expand_reflect(
                 FieldsData::Named(fields) => {
                     let set_fields = fields.iter().filter(filter_out_skipped).map(|field| {
                         let ident = field.ident;
-                        let ident_string = stringify(ident);
+                        let ident_string = &field.name;
                         quote! {
                             if let Some(new_value) = enum_.field(#ident_string) {
                                 #ident.patch(new_value);
@@ -182,54 +331,54 @@ fn expand_reflect(
             }
         });
 
-        if attrs.clone_opt_out {
+        let body = if attrs.clone_opt_out {
             quote! {
-                fn patch(&mut self, value: &dyn Reflect) {
-                    if let Some(enum_) = value.reflect_ref().as_enum() {
-                        if let Some(new) = FromReflect::from_reflect(value) {
-                            *self = new;
-                        } else {
-                            let variant_matches = self.variant_name() == enum_.variant_name();
-                            match self {
-                                #(#match_arms)*
-                                _ => {}
-                            }
+                if let Some(enum_) = value.reflect_ref().as_enum() {
+                    if let Some(new) = FromReflect::from_reflect(value) {
+                        *self = new;
+                    } else {
+                        let variant_matches = self.variant_name() == enum_.variant_name();
+                        match self {
+                            #(#match_arms)*
+                            _ => {}
                         }
                     }
                 }
             }
         } else {
             quote! {
-                fn patch(&mut self, value: &dyn Reflect) {
-                    if let Some(new) = value.downcast_ref::<Self>() {
-                        *self = new.clone();
-                    } else if let Some(enum_) = value.reflect_ref().as_enum() {
-                        if let Some(new) = FromReflect::from_reflect(value) {
-                            *self = new;
-                        } else {
-                            let variant_matches = self.variant_name() == enum_.variant_name();
-                            match self {
-                                #(#match_arms)*
-                                _ => {}
-                            }
+                if let Some(new) = value.downcast_ref::<Self>() {
+                    *self = new.clone();
+                } else if let Some(enum_) = value.reflect_ref().as_enum() {
+                    if let Some(new) = FromReflect::from_reflect(value) {
+                        *self = new;
+                    } else {
+                        let variant_matches = self.variant_name() == enum_.variant_name();
+                        match self {
+                            #(#match_arms)*
+                            _ => {}
                         }
                     }
                 }
             }
-        }
+        };
+        fn_patch_tokens(attrs.validate_with.is_some(), body)
     };
+    let fn_validate_self = attrs.fn_validate_self_tokens(Vec::new());
 
+    let type_name = attrs.type_name();
     let fn_to_value = {
         let match_arms = variants.iter().filter(filter_out_skipped).map(|variant| {
             let variant_ident = &variant.ident;
-            let variant_ident_string = stringify(variant_ident);
+            let variant_ident_string = &variant.name;
             let field_names = variant.field_names();
+            let with_discriminant = variant.with_discriminant();
 
             match &variant.fields {
                 FieldsData::Named(fields) => {
                     let set_fields = fields.iter().filter(filter_out_skipped).map(|field| {
                         let ident = &field.ident;
-                        let ident_string = stringify(ident);
+                        let ident_string = &field.name;
                         quote! {
                             value.set_struct_field(#ident_string, #ident.to_value());
                         }
@@ -241,7 +390,7 @@ fn expand_reflect(
                         Self::#variant_ident { #(#field_names,)* } => {
                             let mut value = EnumValue::new_struct_variant_with_capacity(#variant_ident_string, #fields_len);
                             #(#set_fields)*
-                            value.finish().into()
+                            value.finish().with_type_name(#type_name).with_origin::<Self>()#with_discriminant.into()
                         }
                     }
                 }
@@ -261,14 +410,17 @@ fn expand_reflect(
                             #(
                                 value.push_tuple_field(#included_fields.to_value());
                             )*
-                            value.finish().into()
+                            value.finish().with_type_name(#type_name).with_origin::<Self>()#with_discriminant.into()
                         }
                     }
                 }
                 FieldsData::Unit => {
                     quote! {
                         Self::#variant_ident => {
-                            EnumValue::new_unit_variant(#variant_ident_string).into()
+                            EnumValue::new_unit_variant(#variant_ident_string)
+                                .with_type_name(#type_name).with_origin::<Self>()
+                                #with_discriminant
+                                .into()
                         }
                     }
                 }
@@ -278,9 +430,7 @@ fn expand_reflect(
         let has_skipped_variant = variants.iter().any(|variant| variant.skip());
         let catch_all_branch = has_skipped_variant.then(|| {
             quote! {
-                other => {
-                    panic!("`Reflection::to_value` called on `{:?}` which doesn't suport reflection", other.as_reflect())
-                }
+                other => skipped_variant_to_value(other),
             }
         });
 
@@ -302,6 +452,7 @@ fn expand_reflect(
 
     let fn_debug = attrs.fn_debug_tokens();
     let fn_clone_reflect = attrs.fn_clone_reflect_tokens();
+    let (_, fn_type_name) = attrs.rename_tokens();
 
     let Generics {
         impl_generics,
@@ -328,7 +479,9 @@ fn expand_reflect(
             }
 
             #fn_type_info
+            #fn_type_name
             #fn_patch
+            #fn_validate_self
             #fn_to_value
             #fn_clone_reflect
             #fn_debug
@@ -349,14 +502,14 @@ fn expand_reflect(
 }
 
 fn expand_from_reflect(
-    ident: &Ident,
+    ident: &Path,
     variants: &[VariantData<'_>],
     attrs: &ItemAttrs,
     generics: &Generics<'_>,
 ) -> TokenStream {
     let match_arms = variants.iter().filter(filter_out_skipped).map(|variant| {
         let variant_ident = &variant.ident;
-        let variant_ident_string = stringify(&variant.ident);
+        let variant_ident_string = &variant.name;
 
         let expr = match &variant.fields {
             FieldsData::Named(fields) => {
@@ -364,11 +517,12 @@ fn expand_from_reflect(
                     let ident = &field.ident;
 
                     if field.skip() {
+                        let value = field.attrs.skipped_value();
                         quote! {
-                            #ident: ::core::default::Default::default(),
+                            #ident: #value,
                         }
                     } else {
-                        let ident_string = stringify(ident);
+                        let ident_string = &field.name;
                         let ty = &field.ty;
                         if let Some(from_reflect_with) = field.from_reflect_with() {
                             quote! {
@@ -377,7 +531,7 @@ fn expand_from_reflect(
                                     #from_reflect_with(value)?
                                 },
                             }
-                        } else if attrs.clone_opt_out {
+                        } else if !attrs.use_from_reflect_fast_path() {
                             quote! {
                                 #ident: {
                                     let value = enum_.field(#ident_string)?;
@@ -408,8 +562,9 @@ fn expand_from_reflect(
             FieldsData::Unnamed(fields) => {
                 let set_fields = fields.iter().enumerate().map(|(idx, field)| {
                     if field.skip() {
+                        let value = field.attrs.skipped_value();
                         quote! {
-                            ::core::default::Default::default(),
+                            #value,
                         }
                     } else {
                         let ty = &field.ty;
@@ -420,7 +575,7 @@ fn expand_from_reflect(
                                     #from_reflect_with(value)?
                                 },
                             }
-                        } else if attrs.clone_opt_out {
+                        } else if !attrs.use_from_reflect_fast_path() {
                             quote! {
                                 {
                                     let value = enum_.field_at(#idx)?;
@@ -464,21 +619,26 @@ fn expand_from_reflect(
         where_clause,
     } = generics;
 
+    let fn_from_reflect = fn_from_reflect_tokens(
+        attrs.validate_with.is_some(),
+        quote! {
+            let enum_ = reflect.reflect_ref().as_enum()?;
+            match enum_.variant_name() {
+                #(#match_arms)*
+                _ => None,
+            }
+        },
+    );
+
     quote! {
         impl #impl_generics FromReflect for #ident #type_generics #where_clause {
-            fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
-                let enum_ = reflect.reflect_ref().as_enum()?;
-                match enum_.variant_name() {
-                    #(#match_arms)*
-                    _ => None,
-                }
-            }
+            #fn_from_reflect
         }
     }
 }
 
 fn expand_enum(
-    ident: &Ident,
+    ident: &Path,
     variants: &[VariantData<'_>],
     attrs: &ItemAttrs,
     generics: &Generics<'_>,
@@ -486,7 +646,7 @@ fn expand_enum(
     let fn_variant_name = {
         let match_arms = variants.iter().map(|variant| {
             let ident = &variant.ident;
-            let ident_string = stringify(ident);
+            let ident_string = &variant.name;
             quote! {
                 Self::#ident { .. } => #ident_string,
             }
@@ -536,7 +696,7 @@ fn expand_enum(
                     let return_if_name_matches =
                         fields.iter().filter(filter_out_skipped).map(|field| {
                             let ident = &field.ident;
-                            let ident_string = stringify(ident);
+                            let ident_string = &field.name;
                             quote! {
                                 if name == #ident_string {
                                     return Some(#ident);
@@ -584,7 +744,7 @@ fn expand_enum(
                     let return_if_name_matches =
                         fields.iter().filter(filter_out_skipped).map(|field| {
                             let ident = &field.ident;
-                            let ident_string = stringify(ident);
+                            let ident_string = &field.name;
                             quote! {
                                 if name == #ident_string {
                                     return Some(#ident);
@@ -768,7 +928,7 @@ fn expand_enum(
                 FieldsData::Named(fields) => {
                     let code_for_fields = fields.iter().filter(filter_out_skipped).map(|field| {
                         let ident = &field.ident;
-                        let field = stringify(ident);
+                        let field = &field.name;
                         quote! {
                             (#field, #ident.as_reflect_mut()),
                         }
@@ -872,10 +1032,10 @@ fn expand_enum(
                         let return_if_index_matches =
                             fields.iter().enumerate().filter(filter_out_skipped).map(
                                 |(idx, field)| {
-                                    let field_name = &field.ident;
+                                    let field_name = &field.name;
                                     quote! {
                                         if #idx == index {
-                                            return Some(::core::stringify!(#field_name));
+                                            return Some(#field_name);
                                         }
                                     }
                                 },
@@ -948,16 +1108,38 @@ fn expand_enum(
 
 struct VariantData<'a> {
     ident: &'a Ident,
+    /// The name of the variant as seen through reflection.
+    name: TokenStream,
     attrs: InnerAttrs,
     fields: FieldsData<'a>,
+    /// An expression evaluating to the discriminant as an `i128`. Only set for enums with an
+    /// integer `#[repr(...)]`.
+    discriminant: Option<TokenStream>,
 }
 
 impl<'a> VariantData<'a> {
-    fn try_from_enum(enum_: &'a DataEnum) -> syn::Result<Vec<Self>> {
+    fn try_from_enum(enum_: &'a DataEnum, item_attrs: &ItemAttrs) -> syn::Result<Vec<Self>> {
+        // discriminants without an explicit value are one more than the previous one
+        let mut previous_explicit = None;
+        let mut offset = 0_u128;
+
         enum_
             .variants
             .iter()
             .map(|variant| -> syn::Result<VariantData<'_>> {
+                if let Some((_, expr)) = &variant.discriminant {
+                    previous_explicit = Some(expr);
+                    offset = 0;
+                }
+                let discriminant = item_attrs.repr.is_some().then(|| {
+                    let offset = Literal::u128_unsuffixed(offset);
+                    match previous_explicit {
+                        Some(expr) => quote! { ((#expr) as i128 + #offset) },
+                        None => quote! { #offset },
+                    }
+                });
+                offset += 1;
+
                 let fields: FieldsData<'a> = match &variant.fields {
                     Fields::Named(fields) => {
                         let fields = fields
@@ -966,9 +1148,21 @@ impl<'a> VariantData<'a> {
                             .map(|field| {
                                 let ident = field.ident.as_ref().unwrap();
                                 let ty = &field.ty;
-                                let attrs = InnerAttrs::parse(&field.attrs)?;
-
-                                Ok(NamedField { ident, ty, attrs })
+                                let mut attrs = InnerAttrs::parse(&field.attrs)?;
+                                if item_attrs.skip_docs {
+                                    attrs.docs.clear();
+                                }
+                                attrs.reject_flatten()?;
+                                attrs.reject_validate_with()?;
+                                attrs.default_into_meta(ty)?;
+                                let name = attrs.name(ident, None);
+
+                                Ok(NamedField {
+                                    ident,
+                                    name,
+                                    ty,
+                                    attrs,
+                                })
                             })
                             .collect::<syn::Result<Vec<_>>>()?;
 
@@ -981,7 +1175,12 @@ impl<'a> VariantData<'a> {
                             .enumerate()
                             .map(|(index, field)| {
                                 let ty = &field.ty;
-                                let attrs = InnerAttrs::parse(&field.attrs)?;
+                                let mut attrs = InnerAttrs::parse_unnamed(&field.attrs)?;
+                                if item_attrs.skip_docs {
+                                    attrs.docs.clear();
+                                }
+                                attrs.reject_validate_with()?;
+                                attrs.default_into_meta(ty)?;
                                 let fake_ident = quote::format_ident!("field_{index}");
 
                                 Ok(UnnamedField {
@@ -997,12 +1196,21 @@ impl<'a> VariantData<'a> {
                     Fields::Unit => FieldsData::Unit,
                 };
 
-                let attrs = InnerAttrs::parse(&variant.attrs)?;
+                let mut attrs = InnerAttrs::parse(&variant.attrs)?;
+                if item_attrs.skip_docs {
+                    attrs.docs.clear();
+                }
+                attrs.reject_default()?;
+                attrs.reject_flatten()?;
+                attrs.reject_validate_with()?;
+                let name = attrs.name(&variant.ident, item_attrs.rename_all);
 
                 Ok(VariantData {
                     ident: &variant.ident,
+                    name,
                     fields,
                     attrs,
+                    discriminant,
                 })
             })
             .collect::<syn::Result<Vec<_>>>()
@@ -1010,6 +1218,11 @@ impl<'a> VariantData<'a> {
 }
 
 impl<'a> VariantData<'a> {
+    fn with_discriminant(&self) -> Option<TokenStream> {
+        let discriminant = self.discriminant.as_ref()?;
+        Some(quote! { .with_discriminant(#discriminant) })
+    }
+
     fn field_names<'this>(&'this self) -> Box<dyn Iterator<Item = Cow<'a, Ident>> + 'this> {
         match &self.fields {
             FieldsData::Named(fields) => {
@@ -1034,6 +1247,8 @@ enum FieldsData<'a> {
 
 struct NamedField<'a> {
     ident: &'a Ident,
+    /// The name of the field as seen through reflection.
+    name: TokenStream,
     ty: &'a Type,
     attrs: InnerAttrs,
 }
@@ -1,8 +1,13 @@
+use proc_macro2::Ident;
+use proc_macro2::Span;
 use proc_macro2::TokenStream;
+use quote::format_ident;
+use quote::quote;
 use quote::quote_spanned;
 use syn::spanned::Spanned;
 use syn::DeriveInput;
 use syn::ImplGenerics;
+use syn::Path;
 use syn::TypeGenerics;
 use syn::WhereClause;
 
@@ -25,12 +30,38 @@ pub(crate) fn expand(item: DeriveInput) -> syn::Result<TokenStream> {
         where_clause,
     };
 
-    let ident = &item.ident;
     let span = item.span();
-    let attrs = attrs::ItemAttrs::parse(&item.attrs)?;
+    let mut attrs = attrs::ItemAttrs::parse(&item.attrs)?;
+    attrs.const_params = item
+        .generics
+        .const_params()
+        .map(|param| param.ident.clone())
+        .collect();
     let crate_name = attrs.crate_name.clone();
 
-    check_for_known_unsupported_types(&item)?;
+    let ident = &item.ident.clone().into();
+
+    check_for_non_static_lifetimes(&item)?;
+    if !attrs.allow_hash_map {
+        check_for_hash_maps(&item)?;
+    }
+
+    let has_named_fields = match &item.data {
+        syn::Data::Struct(data) => !matches!(data.fields, syn::Fields::Unnamed(_)),
+        syn::Data::Enum(_) | syn::Data::Union(_) => false,
+    };
+    if attrs.table_driven && !has_named_fields {
+        return Err(syn::Error::new(
+            span,
+            "`#[reflect(table_driven)]` is only supported on structs with named fields",
+        ));
+    }
+
+    let remote_conversions = attrs
+        .remote
+        .as_ref()
+        .map(|remote| expand_remote_conversions(&item, remote, &generics))
+        .unwrap_or_default();
 
     let tokens = match item.data {
         syn::Data::Struct(data) => match data.fields {
@@ -78,9 +109,14 @@ pub(crate) fn expand(item: DeriveInput) -> syn::Result<TokenStream> {
             use #crate_name::*;
             #[allow(unused_imports)]
             use #crate_name::__private::*;
+            // `#crate_name::fmt` would otherwise be ambiguous with `core::fmt`
+            #[allow(unused_imports)]
+            use ::core::fmt;
 
             #tokens
 
+            #remote_conversions
+
             impl #impl_generics From<#ident #type_generics> for Value #where_clause {
                 fn from(data: #ident #type_generics) -> Value {
                     data.to_value()
@@ -90,7 +126,145 @@ pub(crate) fn expand(item: DeriveInput) -> syn::Result<TokenStream> {
     })
 }
 
-fn check_for_known_unsupported_types(item: &DeriveInput) -> syn::Result<()> {
+/// With `#[reflect(remote = ...)]` the impls are generated for the local mirror type as usual,
+/// and these `From` impls convert between it and the remote type, field by field.
+fn expand_remote_conversions(
+    item: &DeriveInput,
+    remote: &Path,
+    generics: &Generics<'_>,
+) -> TokenStream {
+    let ident = &item.ident;
+    let Generics {
+        impl_generics,
+        type_generics,
+        where_clause,
+    } = generics;
+
+    // `.into()` allows fields to themselves be mirrors of remote types
+    let convert_fields = |path: TokenStream, fields: &syn::Fields, source: Option<&Ident>| {
+        let access = |idx: usize, field: &syn::Field| {
+            let member = field
+                .ident
+                .clone()
+                .map(syn::Member::Named)
+                .unwrap_or_else(|| syn::Member::Unnamed(idx.into()));
+            match source {
+                Some(source) => quote! { #source.#member },
+                None => {
+                    let binding = binding_ident(idx, field);
+                    quote! { #binding }
+                }
+            }
+        };
+        match fields {
+            syn::Fields::Named(named) => {
+                let fields = named.named.iter().enumerate().map(|(idx, field)| {
+                    let name = &field.ident;
+                    let value = access(idx, field);
+                    quote! { #name: #value.into() }
+                });
+                quote! { #path { #(#fields,)* } }
+            }
+            syn::Fields::Unnamed(unnamed) => {
+                let fields = unnamed.unnamed.iter().enumerate().map(|(idx, field)| {
+                    let value = access(idx, field);
+                    quote! { #value.into() }
+                });
+                quote! { #path(#(#fields,)*) }
+            }
+            syn::Fields::Unit => path,
+        }
+    };
+
+    let bind_fields = |path: TokenStream, fields: &syn::Fields| match fields {
+        syn::Fields::Named(named) => {
+            let fields = named.named.iter().map(|field| &field.ident);
+            quote! { #path { #(#fields,)* } }
+        }
+        syn::Fields::Unnamed(unnamed) => {
+            let fields = unnamed
+                .unnamed
+                .iter()
+                .enumerate()
+                .map(|(idx, field)| binding_ident(idx, field));
+            quote! { #path(#(#fields,)*) }
+        }
+        syn::Fields::Unit => path,
+    };
+
+    let (from_remote, into_remote) = match &item.data {
+        syn::Data::Struct(data) => {
+            let source = Ident::new("source", Span::call_site());
+            (
+                convert_fields(quote! { Self }, &data.fields, Some(&source)),
+                convert_fields(quote! { #remote }, &data.fields, Some(&source)),
+            )
+        }
+        syn::Data::Enum(enum_) => {
+            let arms = |from: &dyn Fn(&Ident) -> TokenStream,
+                        to: &dyn Fn(&Ident) -> TokenStream| {
+                let arms = enum_.variants.iter().map(|variant| {
+                    let pattern = bind_fields(from(&variant.ident), &variant.fields);
+                    let expr = convert_fields(to(&variant.ident), &variant.fields, None);
+                    quote! { #pattern => #expr, }
+                });
+                quote! {
+                    match source {
+                        #(#arms)*
+                    }
+                }
+            };
+            (
+                arms(
+                    &|variant| quote! { #remote::#variant },
+                    &|variant| quote! { #ident::#variant },
+                ),
+                arms(
+                    &|variant| quote! { #ident::#variant },
+                    &|variant| quote! { #remote::#variant },
+                ),
+            )
+        }
+        syn::Data::Union(_) => return TokenStream::new(),
+    };
+
+    quote! {
+        impl #impl_generics From<#remote #type_generics> for #ident #type_generics #where_clause {
+            fn from(source: #remote #type_generics) -> Self {
+                #from_remote
+            }
+        }
+
+        impl #impl_generics From<#ident #type_generics> for #remote #type_generics #where_clause {
+            fn from(source: #ident #type_generics) -> Self {
+                #into_remote
+            }
+        }
+    }
+}
+
+fn binding_ident(idx: usize, field: &syn::Field) -> Ident {
+    field
+        .ident
+        .clone()
+        .unwrap_or_else(|| format_ident!("field_{}", idx))
+}
+
+fn check_for_non_static_lifetimes(item: &DeriveInput) -> syn::Result<()> {
+    match item.generics.lifetimes().next() {
+        Some(param) => Err(syn::Error::new_spanned(
+            &param.lifetime,
+            "`#[derive(Reflect)]` doesn't support lifetime parameters since `Reflect` requires \
+            `'static`. Use owned data such as `String`, `Vec<T>` or `Arc<T>`, or `&'static` \
+            references, instead.",
+        )),
+        None => Ok(()),
+    }
+}
+
+/// `HashMap`s are reflected but iterate in an unstable order, so they need to be opted into with
+/// `#[reflect(allow_hash_map)]`.
+fn check_for_hash_maps(item: &DeriveInput) -> syn::Result<()> {
     #[derive(Default)]
     struct Visitor(Option<syn::Error>);
 
@@ -99,7 +273,9 @@ fn check_for_known_unsupported_types(item: &DeriveInput) -> syn::Result<()> {
             if i == "HashMap" && self.0.is_none() {
                 self.0 = Some(syn::Error::new_spanned(
                     i,
-                    "`#[derive(Reflect)]` doesn't support `HashMap`. Use a `BTreeMap` instead.",
+                    "reflected `HashMap`s iterate in an unstable order. Use a `BTreeMap` or a \
+                    `mirror_mirror::map::SortedHashMap` instead, or allow it with \
+                    `#[reflect(allow_hash_map)]`.",
                 ));
             }
         }
@@ -6,32 +6,61 @@ use syn::spanned::Spanned;
 use syn::Field;
 use syn::FieldsNamed;
 use syn::Ident;
+use syn::Path;
 use syn::Token;
 
+use super::attrs::fn_from_reflect_tokens;
+use super::attrs::fn_patch_tokens;
+use super::attrs::validate_field_tokens;
 use super::attrs::AttrsDatabase;
 use super::attrs::ItemAttrs;
 use super::Generics;
-use crate::stringify;
 
 type Fields = Punctuated<Field, Token![,]>;
 
 pub(super) fn expand(
-    ident: &Ident,
+    ident: &Path,
     fields: FieldsNamed,
     attrs: ItemAttrs,
     generics: &Generics<'_>,
 ) -> syn::Result<TokenStream> {
-    let field_attrs = AttrsDatabase::new_from_named(&fields)?;
+    let mut field_attrs = AttrsDatabase::new_from_named(&fields, attrs.rename_all)?;
+    if attrs.skip_docs {
+        field_attrs.skip_docs();
+    }
 
     let fields = fields.named;
 
+    if attrs.table_driven {
+        if let Some(field) = fields
+            .iter()
+            .find(|field| field_attrs.flatten(field.ident.as_ref().unwrap()))
+        {
+            return Err(syn::Error::new_spanned(
+                field,
+                "`flatten` isn't supported together with `#[reflect(table_driven)]`",
+            ));
+        }
+    }
+
     let describe_type = expand_describe_type(ident, &fields, &attrs, &field_attrs, generics);
     let reflect = expand_reflect(ident, &fields, &attrs, &field_attrs, generics);
     let from_reflect = (!attrs.from_reflect_opt_out)
         .then(|| expand_from_reflect(ident, &attrs, &fields, &field_attrs, generics));
     let struct_ = expand_struct(ident, &fields, &attrs, &field_attrs, generics);
 
+    let field_names = attrs.table_driven.then(|| {
+        let names = fields
+            .iter()
+            .filter(field_attrs.filter_out_skipped_named())
+            .map(|field| field_attrs.name(field.ident.as_ref().unwrap()));
+        quote! {
+            const FIELD_NAMES: &[&str] = &[#(#names),*];
+        }
+    });
+
     Ok(quote! {
+        #field_names
         #describe_type
         #reflect
         #from_reflect
@@ -40,7 +69,7 @@ pub(super) fn expand(
 }
 
 fn expand_describe_type(
-    ident: &Ident,
+    ident: &Path,
     fields: &Fields,
     attrs: &ItemAttrs,
     field_attrs: &AttrsDatabase<Ident>,
@@ -50,7 +79,7 @@ fn expand_describe_type(
         .iter()
         .filter(field_attrs.filter_out_skipped_named())
         .map(|field| {
-            let name = stringify(&field.ident);
+            let name = field_attrs.name(field.ident.as_ref().unwrap());
             let field_ty = &field.ty;
             let ident = field.ident.as_ref().unwrap();
             let meta = field_attrs.meta(ident);
@@ -62,18 +91,43 @@ fn expand_describe_type(
 
     let meta = attrs.meta();
     let docs = attrs.docs();
+    let (with_type_name, _) = attrs.rename_tokens();
     let Generics {
         impl_generics,
         type_generics,
         where_clause,
     } = generics;
 
+    // the number of fields of flattened fields isn't known until their nodes are built, so the
+    // fields are collected rather than put in an array
+    let fields = if field_attrs.any_flattened() {
+        let segments = fields
+            .iter()
+            .filter(field_attrs.filter_out_skipped_named())
+            .zip(code_for_fields)
+            .map(|(field, code)| {
+                if field_attrs.flatten(field.ident.as_ref().unwrap()) {
+                    let field_ty = &field.ty;
+                    quote! { .chain(NamedFieldNode::flattened::<#field_ty>(graph)) }
+                } else {
+                    quote! { .chain([#code]) }
+                }
+            });
+        quote! {
+            &::core::iter::empty()
+                #(#segments)*
+                .collect::<Box<[_]>>()
+        }
+    } else {
+        quote! { &[#(#code_for_fields),*] }
+    };
+
     quote! {
         impl #impl_generics DescribeType for #ident #type_generics #where_clause {
             fn build(graph: &mut TypeGraph) -> NodeId {
                 graph.get_or_build_node_with::<Self, _>(|graph| {
-                    let fields = &[#(#code_for_fields),*];
-                    StructNode::new::<Self>(fields, #meta, #docs)
+                    let fields = #fields;
+                    StructNode::new::<Self>(fields, #meta, #docs)#with_type_name
                 })
             }
         }
@@ -81,18 +135,37 @@ fn expand_describe_type(
 }
 
 fn expand_reflect(
-    ident: &Ident,
+    ident: &Path,
     fields: &Fields,
     attrs: &ItemAttrs,
     field_attrs: &AttrsDatabase<Ident>,
     generics: &Generics<'_>,
 ) -> TokenStream {
-    let fn_patch = {
+    let validated = attrs.validate_with.is_some() || field_attrs.any_validated();
+
+    let patch_body = if attrs.table_driven {
+        quote! {
+            if let Some(value) = value.reflect_ref().as_struct() {
+                for (name, field) in self.fields_mut() {
+                    if let Some(new_value) = value.field(name) {
+                        field.patch(new_value);
+                    }
+                }
+            }
+        }
+    } else {
         let code_for_fields = fields
             .iter()
             .filter(field_attrs.filter_out_skipped_named())
             .map(|field| {
-                let field = stringify(&field.ident);
+                let ident = field.ident.as_ref().unwrap();
+                // flattened fields find their fields directly in `value`
+                if field_attrs.flatten(ident) {
+                    return quote! {
+                        self.#ident.patch(value.as_reflect());
+                    };
+                }
+                let field = field_attrs.name(ident);
                 quote! {
                     if let Some(field) = value.field(#field) {
                         self.field_mut(#field).unwrap().patch(field);
@@ -101,21 +174,64 @@ fn expand_reflect(
             });
 
         quote! {
-            fn patch(&mut self, value: &dyn Reflect) {
-                if let Some(value) = value.reflect_ref().as_struct() {
-                    #(#code_for_fields)*
-                }
+            if let Some(value) = value.reflect_ref().as_struct() {
+                #(#code_for_fields)*
             }
         }
     };
+    let fn_patch = fn_patch_tokens(validated, patch_body);
+
+    let fn_validate_self = {
+        let field_checks = fields
+            .iter()
+            .filter_map(|field| {
+                let ident = field.ident.as_ref().unwrap();
+                let validator = field_attrs.validate_with(ident)?;
+                let name = field_attrs.name(ident);
+                Some(validate_field_tokens(
+                    validator,
+                    quote! { self.#ident },
+                    name,
+                ))
+            })
+            .collect();
+        attrs.fn_validate_self_tokens(field_checks)
+    };
 
-    let fn_to_value = {
+    let type_name = attrs.type_name();
+    let fn_to_value = if attrs.table_driven {
+        let fields_len = fields
+            .iter()
+            .filter(field_attrs.filter_out_skipped_named())
+            .count();
+
+        quote! {
+            fn to_value(&self) -> Value {
+                let mut value = StructValue::with_capacity(#fields_len)
+                    .with_type_name(#type_name).with_origin::<Self>();
+                for (name, field) in self.fields() {
+                    value.set_field(name, field.to_value());
+                }
+                value.into()
+            }
+        }
+    } else {
         let code_for_fields = fields
             .iter()
             .filter(field_attrs.filter_out_skipped_named())
             .map(|field| {
                 let ident = &field.ident;
-                let field = stringify(ident);
+                if field_attrs.flatten(ident.as_ref().unwrap()) {
+                    return quote! {
+                        let mut value = value;
+                        if let Some(struct_) = self.#ident.reflect_ref().as_struct() {
+                            for (name, field) in struct_.fields() {
+                                value.set_field(name, field.to_value());
+                            }
+                        }
+                    };
+                }
+                let field = field_attrs.name(ident.as_ref().unwrap());
                 quote! {
                     let value = value.with_field(#field, self.#ident.to_value());
                 }
@@ -125,7 +241,8 @@ fn expand_reflect(
 
         quote! {
             fn to_value(&self) -> Value {
-                let value = StructValue::with_capacity(#fields_len);
+                let value = StructValue::with_capacity(#fields_len)
+                    .with_type_name(#type_name).with_origin::<Self>();
                 #(#code_for_fields)*
                 value.into()
             }
@@ -140,6 +257,7 @@ fn expand_reflect(
 
     let fn_debug = attrs.fn_debug_tokens();
     let fn_clone_reflect = attrs.fn_clone_reflect_tokens();
+    let (_, fn_type_name) = attrs.rename_tokens();
 
     let Generics {
         impl_generics,
@@ -166,7 +284,9 @@ fn expand_reflect(
             }
 
             #fn_type_info
+            #fn_type_name
             #fn_patch
+            #fn_validate_self
             #fn_to_value
             #fn_clone_reflect
             #fn_debug
@@ -187,7 +307,7 @@ fn expand_reflect(
 }
 
 fn expand_from_reflect(
-    ident: &Ident,
+    ident: &Path,
     attrs: &ItemAttrs,
     fields: &Fields,
     field_attrs: &AttrsDatabase<Ident>,
@@ -200,12 +320,18 @@ fn expand_from_reflect(
             let span = field.ty.span();
 
             if skip {
+                let value = field_attrs.skipped_value(ident);
+                quote_spanned! {span=>
+                    #ident: #value,
+                }
+            } else if field_attrs.flatten(ident) {
+                let ty = &field.ty;
                 quote_spanned! {span=>
-                    #ident: ::core::default::Default::default(),
+                    #ident: <#ty as FromReflect>::from_reflect(reflect)?,
                 }
             } else {
                 let ty = &field.ty;
-                let field = stringify(ident);
+                let field = field_attrs.name(ident);
                 if let Some(from_reflect_with) = field_attrs.from_reflect_with(ident) {
                     quote_spanned! {span=>
                         #ident: {
@@ -213,7 +339,7 @@ fn expand_from_reflect(
                             #from_reflect_with(value)?
                         },
                     }
-                } else if attrs.clone_opt_out {
+                } else if !attrs.use_from_reflect_fast_path() {
                     quote_spanned! {span=>
                         #ident: {
                             let value = struct_.field(#field)?;
@@ -225,7 +351,7 @@ fn expand_from_reflect(
                         #ident: {
                             let value = struct_.field(#field)?;
                             if let Some(value) = value.downcast_ref::<#ty>() {
-                                value.clone()
+                                value.to_owned()
                             } else {
                                 <#ty as FromReflect>::from_reflect(value)?
                             }
@@ -235,14 +361,16 @@ fn expand_from_reflect(
             }
         });
 
-        quote! {
-            fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        let validated = attrs.validate_with.is_some() || field_attrs.any_validated();
+        fn_from_reflect_tokens(
+            validated,
+            quote! {
                 let struct_ = reflect.reflect_ref().as_struct()?;
                 Some(Self {
                     #(#code_for_fields)*
                 })
-            }
-        }
+            },
+        )
     };
 
     let Generics {
@@ -259,19 +387,26 @@ fn expand_from_reflect(
 }
 
 fn expand_struct(
-    ident: &Ident,
+    ident: &Path,
     fields: &Fields,
     attrs: &ItemAttrs,
     field_attrs: &AttrsDatabase<Ident>,
     generics: &Generics<'_>,
 ) -> TokenStream {
+    if attrs.table_driven {
+        return expand_struct_table_driven(ident, fields, attrs, field_attrs, generics);
+    }
+    if field_attrs.any_flattened() {
+        return expand_struct_flattened(ident, fields, attrs, field_attrs, generics);
+    }
+
     let fn_field = {
         let code_for_fields = fields
             .iter()
             .filter(field_attrs.filter_out_skipped_named())
             .map(|field| {
                 let ident = &field.ident;
-                let field = stringify(ident);
+                let field = field_attrs.name(ident.as_ref().unwrap());
                 quote! {
                     if name == #field {
                         return Some(&self.#ident);
@@ -293,7 +428,7 @@ fn expand_struct(
             .filter(field_attrs.filter_out_skipped_named())
             .map(|field| {
                 let ident = &field.ident;
-                let field = stringify(ident);
+                let field = field_attrs.name(ident.as_ref().unwrap());
                 quote! {
                     if name == #field {
                         return Some(&mut self.#ident);
@@ -359,10 +494,10 @@ fn expand_struct(
             .filter(field_attrs.filter_out_skipped_named())
             .enumerate()
             .map(|(index, field)| {
-                let ident = &field.ident;
+                let name = field_attrs.name(field.ident.as_ref().unwrap());
                 quote! {
                     if index == #index {
-                        return Some(::core::stringify!(#ident));
+                        return Some(#name);
                     }
                 }
             });
@@ -391,7 +526,7 @@ fn expand_struct(
             .filter(field_attrs.filter_out_skipped_named())
             .map(|field| {
                 let ident = &field.ident;
-                let field = stringify(ident);
+                let field = field_attrs.name(ident.as_ref().unwrap());
                 quote! {
                     (#field, self.#ident.as_reflect_mut()),
                 }
@@ -437,3 +572,314 @@ fn expand_struct(
         }
     }
 }
+
+// Like `expand_struct` but rather than generating code per field for every method, fields are
+// accessed by index and the field names looked up in `FIELD_NAMES`. Makes for less code for
+// types with many fields.
+fn expand_struct_table_driven(
+    ident: &Path,
+    fields: &Fields,
+    attrs: &ItemAttrs,
+    field_attrs: &AttrsDatabase<Ident>,
+    generics: &Generics<'_>,
+) -> TokenStream {
+    let idents = fields
+        .iter()
+        .filter(field_attrs.filter_out_skipped_named())
+        .map(|field| &field.ident)
+        .collect::<Vec<_>>();
+    let len = idents.len();
+
+    let crate_name = &attrs.crate_name;
+
+    let Generics {
+        impl_generics,
+        type_generics,
+        where_clause,
+    } = generics;
+
+    quote! {
+        impl #impl_generics Struct for #ident #type_generics #where_clause {
+            fn field(&self, name: &str) -> Option<&dyn Reflect> {
+                let index = FIELD_NAMES.iter().position(|field| *field == name)?;
+                self.field_at(index)
+            }
+
+            fn field_mut(&mut self, name: &str) -> Option<&mut dyn Reflect> {
+                let index = FIELD_NAMES.iter().position(|field| *field == name)?;
+                self.field_at_mut(index)
+            }
+
+            fn field_at(&self, index: usize) -> Option<&dyn Reflect> {
+                let fields: [&dyn Reflect; #len] = [#(&self.#idents),*];
+                fields.get(index).copied()
+            }
+
+            fn field_at_mut(&mut self, index: usize) -> Option<&mut dyn Reflect> {
+                let fields: [&mut dyn Reflect; #len] = [#(&mut self.#idents),*];
+                IntoIterator::into_iter(fields).nth(index)
+            }
+
+            fn name_at(&self, index: usize) -> Option<&str> {
+                FIELD_NAMES.get(index).copied()
+            }
+
+            fn fields(&self) -> #crate_name::struct_::Iter<'_> {
+                #crate_name::struct_::Iter::new(self)
+            }
+
+            fn fields_mut(&mut self) -> PairIterMut<'_> {
+                let fields: [&mut dyn Reflect; #len] = [#(&mut self.#idents),*];
+                Box::new(FIELD_NAMES.iter().copied().zip(fields))
+            }
+
+            fn fields_len(&self) -> usize {
+                #len
+            }
+        }
+    }
+}
+
+// Like `expand_struct` but for structs with `#[reflect(flatten)]` fields, whose number of fields
+// is only known at runtime. Fields are visited in order, with the fields of flattened fields
+// looked up through their `Struct` impls.
+fn expand_struct_flattened(
+    ident: &Path,
+    fields: &Fields,
+    attrs: &ItemAttrs,
+    field_attrs: &AttrsDatabase<Ident>,
+    generics: &Generics<'_>,
+) -> TokenStream {
+    let fields = fields
+        .iter()
+        .filter(field_attrs.filter_out_skipped_named())
+        .map(|field| {
+            let ident = field.ident.as_ref().unwrap();
+            (ident, field_attrs.flatten(ident), field_attrs.name(ident))
+        })
+        .collect::<Vec<_>>();
+
+    let fn_field = {
+        let code_for_fields = fields.iter().map(|(ident, flatten, name)| {
+            if *flatten {
+                quote! {
+                    let value = self.#ident
+                        .reflect_ref()
+                        .as_struct()
+                        .and_then(|struct_| struct_.field(name));
+                    if let Some(value) = value {
+                        return Some(value);
+                    }
+                }
+            } else {
+                quote! {
+                    if name == #name {
+                        return Some(&self.#ident);
+                    }
+                }
+            }
+        });
+
+        quote! {
+            fn field(&self, name: &str) -> Option<&dyn Reflect> {
+                #(#code_for_fields)*
+                None
+            }
+        }
+    };
+
+    let fn_field_mut = {
+        let code_for_fields = fields.iter().map(|(ident, flatten, name)| {
+            if *flatten {
+                // look the field up before borrowing mutably, otherwise the borrow would outlive
+                // the `if` when nothing is found
+                quote! {
+                    let found = self.#ident
+                        .reflect_ref()
+                        .as_struct()
+                        .and_then(|struct_| struct_.field(name))
+                        .is_some();
+                    if found {
+                        return self.#ident.reflect_mut().as_struct_mut()?.field_mut(name);
+                    }
+                }
+            } else {
+                quote! {
+                    if name == #name {
+                        return Some(&mut self.#ident);
+                    }
+                }
+            }
+        });
+
+        quote! {
+            fn field_mut(&mut self, name: &str) -> Option<&mut dyn Reflect> {
+                #(#code_for_fields)*
+                None
+            }
+        }
+    };
+
+    let fn_field_at = {
+        let code_for_fields = fields.iter().map(|(ident, flatten, _)| {
+            if *flatten {
+                quote! {
+                    if let Some(struct_) = self.#ident.reflect_ref().as_struct() {
+                        if index < struct_.fields_len() {
+                            return struct_.field_at(index);
+                        }
+                        index -= struct_.fields_len();
+                    }
+                }
+            } else {
+                quote! {
+                    if index == 0 {
+                        return Some(&self.#ident);
+                    }
+                    index -= 1;
+                }
+            }
+        });
+
+        quote! {
+            fn field_at(&self, mut index: usize) -> Option<&dyn Reflect> {
+                #(#code_for_fields)*
+                None
+            }
+        }
+    };
+
+    let fn_field_at_mut = {
+        let code_for_fields = fields.iter().map(|(ident, flatten, _)| {
+            if *flatten {
+                quote! {
+                    let len = self.#ident
+                        .reflect_ref()
+                        .as_struct()
+                        .map_or(0, |struct_| struct_.fields_len());
+                    if index < len {
+                        return self.#ident.reflect_mut().as_struct_mut()?.field_at_mut(index);
+                    }
+                    index -= len;
+                }
+            } else {
+                quote! {
+                    if index == 0 {
+                        return Some(&mut self.#ident);
+                    }
+                    index -= 1;
+                }
+            }
+        });
+
+        quote! {
+            fn field_at_mut(&mut self, mut index: usize) -> Option<&mut dyn Reflect> {
+                #(#code_for_fields)*
+                None
+            }
+        }
+    };
+
+    let fn_name_at = {
+        let code_for_fields = fields.iter().map(|(ident, flatten, name)| {
+            if *flatten {
+                quote! {
+                    if let Some(struct_) = self.#ident.reflect_ref().as_struct() {
+                        if index < struct_.fields_len() {
+                            return struct_.name_at(index);
+                        }
+                        index -= struct_.fields_len();
+                    }
+                }
+            } else {
+                quote! {
+                    if index == 0 {
+                        return Some(#name);
+                    }
+                    index -= 1;
+                }
+            }
+        });
+
+        quote! {
+            fn name_at(&self, mut index: usize) -> Option<&str> {
+                #(#code_for_fields)*
+                None
+            }
+        }
+    };
+
+    let fn_fields = {
+        let crate_name = &attrs.crate_name;
+
+        quote! {
+            fn fields(&self) -> #crate_name::struct_::Iter<'_> {
+                #crate_name::struct_::Iter::new(self)
+            }
+        }
+    };
+
+    let fn_fields_mut = {
+        let code_for_fields = fields.iter().map(|(ident, flatten, name)| {
+            if *flatten {
+                quote! {
+                    .chain(
+                        self.#ident
+                            .reflect_mut()
+                            .as_struct_mut()
+                            .into_iter()
+                            .flat_map(|struct_| struct_.fields_mut()),
+                    )
+                }
+            } else {
+                quote! {
+                    .chain([(#name, self.#ident.as_reflect_mut())])
+                }
+            }
+        });
+
+        quote! {
+            fn fields_mut(&mut self) -> PairIterMut<'_> {
+                let iter = ::core::iter::empty()#(#code_for_fields)*;
+                Box::new(iter)
+            }
+        }
+    };
+
+    let fn_fields_len = {
+        let code_for_fields = fields.iter().map(|(ident, flatten, _)| {
+            if *flatten {
+                quote! {
+                    self.#ident.reflect_ref().as_struct().map_or(0, |struct_| struct_.fields_len())
+                }
+            } else {
+                quote! { 1 }
+            }
+        });
+
+        quote! {
+            fn fields_len(&self) -> usize {
+                0 #(+ #code_for_fields)*
+            }
+        }
+    };
+
+    let Generics {
+        impl_generics,
+        type_generics,
+        where_clause,
+    } = generics;
+
+    quote! {
+        impl #impl_generics Struct for #ident #type_generics #where_clause {
+            #fn_field
+            #fn_field_mut
+            #fn_field_at
+            #fn_field_at_mut
+            #fn_name_at
+            #fn_fields
+            #fn_fields_mut
+            #fn_fields_len
+        }
+    }
+}
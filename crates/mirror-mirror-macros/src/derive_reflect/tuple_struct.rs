@@ -5,10 +5,13 @@ use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::Field;
 use syn::FieldsUnnamed;
-use syn::Ident;
 use syn::Index;
+use syn::Path;
 use syn::Token;
 
+use super::attrs::fn_from_reflect_tokens;
+use super::attrs::fn_patch_tokens;
+use super::attrs::validate_field_tokens;
 use super::attrs::AttrsDatabase;
 use super::attrs::ItemAttrs;
 use super::Generics;
@@ -16,12 +19,15 @@ use super::Generics;
 type Fields = Punctuated<Field, Token![,]>;
 
 pub(super) fn expand(
-    ident: &Ident,
+    ident: &Path,
     fields: FieldsUnnamed,
     attrs: ItemAttrs,
     generics: &Generics<'_>,
 ) -> syn::Result<TokenStream> {
-    let field_attrs = AttrsDatabase::new_from_unnamed(&fields)?;
+    let mut field_attrs = AttrsDatabase::new_from_unnamed(&fields)?;
+    if attrs.skip_docs {
+        field_attrs.skip_docs();
+    }
 
     let fields = fields.unnamed;
 
@@ -40,7 +46,7 @@ pub(super) fn expand(
 }
 
 fn expand_describe_type(
-    ident: &Ident,
+    ident: &Path,
     fields: &Fields,
     attrs: &ItemAttrs,
     field_attrs: &AttrsDatabase<usize>,
@@ -61,6 +67,7 @@ fn expand_describe_type(
 
     let meta = attrs.meta();
     let docs = attrs.docs();
+    let (with_type_name, _) = attrs.rename_tokens();
     let Generics {
         impl_generics,
         type_generics,
@@ -72,7 +79,7 @@ fn expand_describe_type(
             fn build(graph: &mut TypeGraph) -> NodeId {
                 let fields = &[#(#code_for_fields),*];
                 graph.get_or_build_node_with::<Self, _>(|graph| {
-                    TupleStructNode::new::<Self>(fields, #meta, #docs)
+                    TupleStructNode::new::<Self>(fields, #meta, #docs)#with_type_name
                 })
             }
         }
@@ -80,7 +87,7 @@ fn expand_describe_type(
 }
 
 fn expand_reflect(
-    ident: &Ident,
+    ident: &Path,
     fields: &Fields,
     attrs: &ItemAttrs,
     field_attrs: &AttrsDatabase<usize>,
@@ -99,13 +106,35 @@ fn expand_reflect(
                 }
             });
 
-        quote! {
-            fn patch(&mut self, value: &dyn Reflect) {
+        let validated = attrs.validate_with.is_some() || field_attrs.any_validated();
+        fn_patch_tokens(
+            validated,
+            quote! {
                 if let Some(tuple_struct) = value.reflect_ref().as_tuple_struct() {
                     #(#code_for_fields)*
                 }
-            }
-        }
+            },
+        )
+    };
+
+    let fn_validate_self = {
+        let field_checks = fields
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, field)| {
+                let validator = field_attrs.validate_with(&idx)?;
+                let field_index = Index {
+                    index: idx as u32,
+                    span: field.span(),
+                };
+                Some(validate_field_tokens(
+                    validator,
+                    quote! { self.#field_index },
+                    quote! { #idx },
+                ))
+            })
+            .collect();
+        attrs.fn_validate_self_tokens(field_checks)
     };
 
     let fn_to_value = {
@@ -124,10 +153,12 @@ fn expand_reflect(
             });
 
         let fields_len = fields.len();
+        let type_name = attrs.type_name();
 
         quote! {
             fn to_value(&self) -> Value {
-                let value = TupleStructValue::with_capacity(#fields_len);
+                let value = TupleStructValue::with_capacity(#fields_len)
+                    .with_type_name(#type_name).with_origin::<Self>();
                 #(#code_for_fields)*
                 value.into()
             }
@@ -142,6 +173,7 @@ fn expand_reflect(
 
     let fn_debug = attrs.fn_debug_tokens();
     let fn_clone_reflect = attrs.fn_clone_reflect_tokens();
+    let (_, fn_type_name) = attrs.rename_tokens();
     let Generics {
         impl_generics,
         type_generics,
@@ -167,7 +199,9 @@ fn expand_reflect(
             }
 
             #fn_type_info
+            #fn_type_name
             #fn_patch
+            #fn_validate_self
             #fn_to_value
             #fn_clone_reflect
             #fn_debug
@@ -188,7 +222,7 @@ fn expand_reflect(
 }
 
 fn expand_from_reflect(
-    ident: &Ident,
+    ident: &Path,
     attrs: &ItemAttrs,
     fields: &Fields,
     field_attrs: &AttrsDatabase<usize>,
@@ -203,8 +237,9 @@ fn expand_from_reflect(
             let ty = &field.ty;
             let span = ty.span();
             if field_attrs.skip(&idx) {
+                let value = field_attrs.skipped_value(&idx);
                 quote_spanned! {span=>
-                    #field_index: ::core::default::Default::default(),
+                    #field_index: #value,
                 }
             } else if let Some(from_reflect_with) = field_attrs.from_reflect_with(&idx) {
                 quote_spanned! {span=>
@@ -213,7 +248,7 @@ fn expand_from_reflect(
                         #from_reflect_with(value)?
                     }
                 }
-            } else if attrs.clone_opt_out {
+            } else if !attrs.use_from_reflect_fast_path() {
                 quote_spanned! {span=>
                     #field_index: {
                         let value = tuple_struct.field_at(#field_index)?;
@@ -234,14 +269,16 @@ fn expand_from_reflect(
             }
         });
 
-        quote! {
-            fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        let validated = attrs.validate_with.is_some() || field_attrs.any_validated();
+        fn_from_reflect_tokens(
+            validated,
+            quote! {
                 let tuple_struct = reflect.reflect_ref().as_tuple_struct()?;
                 Some(Self {
                     #(#code_for_fields)*
                 })
-            }
-        }
+            },
+        )
     };
 
     let Generics {
@@ -258,7 +295,7 @@ fn expand_from_reflect(
 }
 
 fn expand_tuple_struct(
-    ident: &Ident,
+    ident: &Path,
     fields: &Fields,
     attrs: &ItemAttrs,
     field_attrs: &AttrsDatabase<usize>,
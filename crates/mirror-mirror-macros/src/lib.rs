@@ -130,7 +130,8 @@ mod derive_reflect;
 /// ## `skip`
 ///
 /// You can exclude fields or variants from being reflected with `#[reflect(skip)]`. The type of the skipped field/variant is
-/// required to implement `Default` by the default `FromReflect` implementation.
+/// required to implement `Default` by the default `FromReflect` implementation, unless the field
+/// sets its value with [`default`](#default-and-default_with).
 ///
 /// ```
 /// use mirror_mirror::{Reflect, FromReflect};
@@ -162,6 +163,72 @@ mod derive_reflect;
 /// struct NotReflect;
 /// ```
 ///
+/// Skipped fields are invisible to reflection and are reset to their default value by
+/// `FromReflect`. To keep such a field, wrap its type in `mirror_mirror::Opaque` instead, which
+/// only requires `Clone` and `PartialEq` and reflects the value as `ReflectRef::Opaque`.
+///
+/// ## `default` and `default_with`
+///
+/// By default the value returned by `TypeDescriptor::default_value` is built from the default
+/// values of the fields' types, so numbers are zero and strings empty. Fields can override this
+/// with `#[reflect(default = ...)]`, and the whole type with
+/// `#[reflect(default_with(function))]`, where `function` returns a value of the type.
+///
+/// On skipped fields `#[reflect(default = ...)]` instead sets the value `FromReflect` uses, so
+/// the field's type doesn't have to implement `Default`.
+///
+/// ```
+/// use mirror_mirror::{Reflect, FromReflect, DescribeType};
+///
+/// #[derive(Reflect, Debug, Clone, PartialEq)]
+/// struct Player {
+///     #[reflect(default = 100)]
+///     health: u32,
+///     #[reflect(default = "Unnamed".to_owned())]
+///     name: String,
+///     #[reflect(skip, default = Cache::empty())]
+///     cache: Cache,
+/// }
+///
+/// #[derive(Reflect, Debug, Clone, PartialEq)]
+/// #[reflect(default_with(Difficulty::normal))]
+/// enum Difficulty {
+///     Easy,
+///     Normal,
+///     Hard,
+/// }
+///
+/// impl Difficulty {
+///     fn normal() -> Self {
+///         Self::Normal
+///     }
+/// }
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// struct Cache(Vec<u32>);
+///
+/// impl Cache {
+///     fn empty() -> Self {
+///         Self(Vec::new())
+///     }
+/// }
+///
+/// let default = <Player as DescribeType>::type_descriptor().default_value().unwrap();
+/// assert_eq!(
+///     Player::from_reflect(&default).unwrap(),
+///     Player {
+///         health: 100,
+///         name: "Unnamed".to_owned(),
+///         cache: Cache::empty(),
+///     },
+/// );
+///
+/// let default = <Difficulty as DescribeType>::type_descriptor().default_value().unwrap();
+/// assert_eq!(Difficulty::from_reflect(&default).unwrap(), Difficulty::Normal);
+/// ```
+///
+/// Both are stored in the `default` metadata of the field or type.
+///
 /// ## `from_reflect_with`
 ///
 /// You can override `FromReflect` for a single field by specifying a function to do the
@@ -227,6 +294,290 @@ mod derive_reflect;
 /// );
 /// ```
 ///
+/// The values of well-known keys, such as `range` and `tooltip`, are checked to have the right
+/// type. See `mirror_mirror::meta::known` for the full list.
+///
+/// ## `remote`
+///
+/// Types from other crates can't implement the traits directly because of Rust's orphan rules.
+/// Instead derive on a local type that mirrors their definition and point to the real type with
+/// `#[reflect(remote = path::To::Type)]`. The impls are generated for the local type as usual,
+/// along with `From` impls that convert to and from the remote type, similarly to serde's
+/// `remote`.
+///
+/// ```
+/// # mod other_crate {
+/// #     #[derive(Debug, Clone)]
+/// #     pub struct Point {
+/// #         pub x: f32,
+/// #         pub y: f32,
+/// #     }
+/// # }
+/// use mirror_mirror::{Reflect, FromReflect};
+///
+/// #[derive(Reflect, Debug, Clone)]
+/// #[reflect(remote = other_crate::Point)]
+/// struct PointDef {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// let point = other_crate::Point { x: 1.0, y: 2.0 };
+/// let value = PointDef::from(point).to_value();
+/// let point: other_crate::Point = PointDef::from_reflect(&value).unwrap().into();
+/// ```
+///
+/// The field names must match those of the remote type and the fields must be visible where the
+/// derive is. Each field is converted with `Into` so fields can themselves be mirrors of remote
+/// types. If the remote type has private fields, write the `From` impls by hand instead.
+///
+/// ## `no_from_reflect_fast_path`
+///
+/// The derived `FromReflect` implementation first tries downcasting each field to its concrete
+/// type and cloning it, and only if that fails does it fall back to `FromReflect`. That is faster
+/// but generates more code per field. `#[reflect(no_from_reflect_fast_path)]` always uses
+/// `FromReflect` instead:
+///
+/// ```
+/// use mirror_mirror::Reflect;
+///
+/// #[derive(Reflect, Debug, Clone)]
+/// #[reflect(no_from_reflect_fast_path)]
+/// struct Foo {
+///     n: i32,
+/// }
+/// ```
+///
+/// ## `table_driven`
+///
+/// By default the methods of `Struct` are generated with a branch per field, which adds up
+/// for types with hundreds of fields. With `#[reflect(table_driven)]` the field names are instead
+/// stored in a table that the methods loop over, and fields are accessed by index through an
+/// array of references to them, trading a bit of performance for less generated code and faster
+/// compile times. `patch` and `to_value` loop over the fields the same way:
+///
+/// ```
+/// use mirror_mirror::Reflect;
+///
+/// #[derive(Reflect, Debug, Clone)]
+/// #[reflect(table_driven, no_from_reflect_fast_path)]
+/// struct Config {
+///     width: u32,
+///     height: u32,
+///     title: String,
+///     // ...
+/// }
+/// ```
+///
+/// Only supported for structs with named fields.
+///
+/// ## `rename` and `rename_all`
+///
+/// Fields, variants, and types can be given different names for reflection with
+/// `#[reflect(rename = "...")]`, for example to match an external data format. On a type,
+/// `#[reflect(rename_all = "...")]` renames all fields of a struct, or all variants of an enum,
+/// using one of the same rules as serde: `lowercase`, `UPPERCASE`, `PascalCase`, `camelCase`,
+/// `snake_case`, `SCREAMING_SNAKE_CASE`, `kebab-case`, or `SCREAMING-KEBAB-CASE`.
+///
+/// ```
+/// use mirror_mirror::{Reflect, Struct, Enum, GetField};
+///
+/// #[derive(Reflect, Debug, Clone)]
+/// #[reflect(rename = "Player", rename_all = "camelCase")]
+/// struct PlayerData {
+///     display_name: String,
+///     #[reflect(rename = "hp")]
+///     health: u32,
+/// }
+///
+/// #[derive(Reflect, Debug, Clone)]
+/// #[reflect(rename_all = "snake_case")]
+/// enum Status {
+///     InGame,
+///     #[reflect(rename = "away")]
+///     AwayFromKeyboard,
+/// }
+///
+/// let player = PlayerData {
+///     display_name: "Alice".to_owned(),
+///     health: 100,
+/// };
+///
+/// assert_eq!(player.type_name(), "Player");
+/// assert_eq!(player.get_field::<String>("displayName").unwrap(), "Alice");
+/// assert_eq!(player.get_field::<u32>("hp").unwrap(), &100);
+///
+/// assert_eq!(Status::InGame.variant_name(), "in_game");
+/// assert_eq!(Status::AwayFromKeyboard.variant_name(), "away");
+/// ```
+///
+/// Renamed types with const generic parameters include them in their name, so
+/// `#[reflect(rename = "Grid")]` on `struct Grid<const W: usize>` gives `Grid<3>` for `Grid<3>`.
+///
+/// ## `sensitive`
+///
+/// Fields, variants, and types that hold secrets can be marked with `#[reflect(sensitive)]`.
+/// Their values are then replaced by `<redacted>` in `mirror_mirror::logging::LogValue` and, if
+/// enabled, in `mirror_mirror::serde::TypedSerializer`. The mark is stored as the metadata
+/// `sensitive = true` and can be checked with `GetMeta::is_sensitive`.
+///
+/// ```
+/// use mirror_mirror::Reflect;
+/// use mirror_mirror::logging::{LogOptions, LogValue};
+///
+/// #[derive(Reflect, Debug, Clone)]
+/// struct Login {
+///     user: String,
+///     #[reflect(sensitive)]
+///     password: String,
+/// }
+///
+/// let login = Login {
+///     user: "alice".to_owned(),
+///     password: "hunter2".to_owned(),
+/// };
+///
+/// let formatted = LogValue::new(&login, &LogOptions::default()).to_string();
+/// assert!(formatted.ends_with(r#"Login { user: "alice", password: <redacted> }"#));
+/// ```
+///
+/// This doesn't affect `Debug` implementations, which aren't generated by this derive.
+///
+/// ## `flatten`
+///
+/// The fields of a struct field marked with `#[reflect(flatten)]` appear as fields of the parent
+/// in `Struct`, `DescribeType`, and `FromReflect`, in place of the field itself. Useful for
+/// splitting large types into smaller ones without changing how they look through reflection:
+///
+/// ```
+/// use mirror_mirror::{Reflect, Struct, GetField};
+///
+/// #[derive(Reflect, Debug, Clone)]
+/// struct Graphics {
+///     vsync: bool,
+/// }
+///
+/// #[derive(Reflect, Debug, Clone)]
+/// struct Settings {
+///     name: String,
+///     #[reflect(flatten)]
+///     graphics: Graphics,
+/// }
+///
+/// let settings = Settings {
+///     name: "default".to_owned(),
+///     graphics: Graphics { vsync: true },
+/// };
+///
+/// assert_eq!(settings.get_field::<bool>("vsync").unwrap(), &true);
+/// assert_eq!(
+///     settings.fields().map(|(name, _)| name).collect::<Vec<_>>(),
+///     ["name", "vsync"],
+/// );
+/// ```
+///
+/// Only supported on named fields of structs whose type is a struct, and not together with
+/// `table_driven`.
+///
+/// ## `validate_with`
+///
+/// Types and fields of structs can be checked with `#[reflect(validate_with(function))]`, where
+/// `function` takes a reference to the value and returns `Result<(), E>` with `E: Into<String>`
+/// explaining what's wrong:
+///
+/// ```
+/// use mirror_mirror::{FromReflect, Reflect, key_path};
+/// use mirror_mirror::struct_::StructValue;
+///
+/// #[derive(Reflect, Debug, Clone, PartialEq)]
+/// #[reflect(validate_with(Light::check))]
+/// struct Light {
+///     #[reflect(validate_with(non_negative))]
+///     intensity: f32,
+///     min_range: f32,
+///     max_range: f32,
+/// }
+///
+/// impl Light {
+///     fn check(&self) -> Result<(), &'static str> {
+///         if self.min_range <= self.max_range {
+///             Ok(())
+///         } else {
+///             Err("`min_range` is larger than `max_range`")
+///         }
+///     }
+/// }
+///
+/// fn non_negative(n: &f32) -> Result<(), &'static str> {
+///     if *n >= 0.0 { Ok(()) } else { Err("negative") }
+/// }
+///
+/// let mut light = Light { intensity: 1.0, min_range: 1.0, max_range: 10.0 };
+///
+/// // patches that would make the value invalid are ignored
+/// light.patch(&StructValue::new().with_field("intensity", -1.0_f32));
+/// assert_eq!(light.intensity, 1.0);
+///
+/// // and reported by `try_patch`
+/// let error = light
+///     .try_patch(&StructValue::new().with_field("min_range", 20.0_f32))
+///     .unwrap_err();
+/// assert_eq!(error.report().skipped()[0].reason(), Some("`min_range` is larger than `max_range`"));
+///
+/// // `FromReflect` rejects invalid values
+/// let value = StructValue::new()
+///     .with_field("intensity", -1.0_f32)
+///     .with_field("min_range", 1.0_f32)
+///     .with_field("max_range", 10.0_f32);
+/// assert!(Light::from_reflect(&value).is_none());
+/// assert_eq!(Light::try_from_reflect(&value).unwrap_err().path(), &key_path!(.intensity));
+/// ```
+///
+/// The validators are run by `Reflect::validate_self`, and `mirror_mirror::validate::validate`
+/// checks a value and everything inside it.
+///
+/// ## `docs`
+///
+/// Doc comments are captured in the type descriptor, available through `GetMeta::docs`.
+/// `#[reflect(docs(skip))]` leaves them out, for a field or variant or, when put on the type, for
+/// the type and everything in it:
+///
+/// ```
+/// use mirror_mirror::{DescribeType, Reflect};
+/// use mirror_mirror::type_info::GetMeta;
+///
+/// /// A long explanation that doesn't need to be in the binary.
+/// #[derive(Reflect, Debug, Clone)]
+/// #[reflect(docs(skip))]
+/// struct Foo {
+///     /// More docs.
+///     n: i32,
+/// }
+///
+/// assert!(<Foo as DescribeType>::type_descriptor().get_type().docs().is_empty());
+/// ```
+///
+/// ## `allow_hash_map`
+///
+/// `HashMap`s are reflected but iterate in an unstable order, so visiting, debug printing, or
+/// otherwise iterating a value can differ between runs. Fields of type `HashMap` are therefore
+/// rejected unless the type opts in with `#[reflect(allow_hash_map)]`. This requires the `std`
+/// feature of `mirror_mirror`:
+///
+/// ```ignore
+/// use std::collections::HashMap;
+/// use mirror_mirror::Reflect;
+///
+/// #[derive(Reflect, Debug, Clone)]
+/// #[reflect(allow_hash_map)]
+/// struct Foo {
+///     scores: HashMap<String, u32>,
+/// }
+/// ```
+///
+/// `mirror_mirror::map::SortedHashMap` wraps a `HashMap` and iterates it in order of its keys, and
+/// doesn't need the attribute.
+///
 /// ## `crate_name`
 ///
 /// You can specify a "use path" for `mirror_mirror` with `crate_name`. This is useful if you're
@@ -276,21 +627,3 @@ where
         Err(err) => err.into_compile_error().into(),
     }
 }
-
-fn stringify<T>(token: T) -> Stringify<T> {
-    Stringify(token)
-}
-
-struct Stringify<T>(T);
-
-impl<T: ToTokens> ToTokens for Stringify<T>
-where
-    T: ToTokens,
-{
-    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
-        let token = &self.0;
-        tokens.extend(quote::quote! {
-            ::core::stringify!(#token)
-        })
-    }
-}
@@ -0,0 +1,5 @@
+#![no_main]
+
+libfuzzer_sys::fuzz_target!(|bytes: &[u8]| {
+    mirror_mirror::fuzz::fuzz_roundtrip(bytes);
+});
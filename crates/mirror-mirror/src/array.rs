@@ -1,3 +1,6 @@
+use alloc::boxed::Box;
+use alloc::vec;
+use alloc::vec::Vec;
 use core::fmt;
 use core::iter::FusedIterator;
 
@@ -45,12 +48,270 @@ impl<'a> Iterator for Iter<'a> {
         self.index += 1;
         Some(value)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
 }
 
 impl<'a> ExactSizeIterator for Iter<'a> {
     fn len(&self) -> usize {
-        self.array.len()
+        self.array.len().saturating_sub(self.index)
     }
 }
 
 impl<'a> FusedIterator for Iter<'a> {}
+
+/// Add the numeric elements of `b` to those of `a`, element-wise.
+///
+/// Only elements that are scalars of the same numeric type in both arrays are added. Other
+/// elements, and extra elements in the longer array, are left untouched. Integers wrap on
+/// overflow.
+///
+/// Returns the number of elements that were updated.
+///
+/// # Example
+///
+/// ```
+/// use mirror_mirror::{array::add_assign_array, Reflect};
+///
+/// let mut a = [1.0_f32, 2.0, 3.0];
+/// let b = [10.0_f32, 20.0, 30.0];
+///
+/// let updated = add_assign_array(a.as_reflect_mut().as_array_mut().unwrap(), &b);
+///
+/// assert_eq!(updated, 3);
+/// assert_eq!(a, [11.0, 22.0, 33.0]);
+/// ```
+pub fn add_assign_array(a: &mut dyn Array, b: &dyn Array) -> usize {
+    numeric::add_assign(a.iter_mut(), b.iter())
+}
+
+/// Multiply the numeric elements of `array` by `factor`.
+///
+/// Non-numeric elements are left untouched. Integers are converted to `f64`, multiplied, and
+/// converted back, rounding towards zero and saturating at the bounds of the integer type.
+///
+/// Returns the number of elements that were updated.
+///
+/// # Example
+///
+/// ```
+/// use mirror_mirror::{array::scale, Reflect};
+///
+/// let mut array = [1.0_f32, 2.0, 3.0];
+///
+/// scale(array.as_reflect_mut().as_array_mut().unwrap(), 2.0);
+///
+/// assert_eq!(array, [2.0, 4.0, 6.0]);
+/// ```
+pub fn scale(array: &mut dyn Array, factor: f64) -> usize {
+    numeric::scale(array.iter_mut(), factor)
+}
+
+/// The lengths of `array` and the arrays nested directly within it, outermost first.
+///
+/// `[[f32; 4]; 3]` has the shape `[3, 4]`. The nested lengths are taken from the first element,
+/// so an empty array has the shape `[0]` regardless of its element type. Use
+/// [`ArrayType::shape`](crate::type_info::ArrayType::shape) to get the shape from the type
+/// instead.
+pub fn shape(array: &dyn Array) -> Vec<usize> {
+    let mut shape = vec![array.len()];
+    let mut first = array.get(0);
+    while let Some(inner) = first.and_then(|value| value.reflect_ref().as_array()) {
+        shape.push(inner.len());
+        first = inner.get(0);
+    }
+    shape
+}
+
+/// The total number of innermost elements of `array`, that is the product of [`shape`].
+pub fn flat_len(array: &dyn Array) -> usize {
+    shape(array).into_iter().product()
+}
+
+/// Iterate over the innermost elements of `array` and its nested arrays, in row-major order.
+///
+/// # Example
+///
+/// ```
+/// use mirror_mirror::{array::flat_iter, Reflect};
+///
+/// let matrix = [[1.0_f32, 2.0], [3.0, 4.0]];
+///
+/// let flat = flat_iter(matrix.as_reflect().as_array().unwrap())
+///     .map(|value| *value.downcast_ref::<f32>().unwrap())
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(flat, [1.0, 2.0, 3.0, 4.0]);
+/// ```
+pub fn flat_iter(array: &dyn Array) -> Box<dyn Iterator<Item = &dyn Reflect> + '_> {
+    Box::new(
+        array
+            .iter()
+            .flat_map(|value| match value.reflect_ref().as_array() {
+                Some(inner) => flat_iter(inner),
+                None => Box::new(core::iter::once(value)),
+            }),
+    )
+}
+
+/// Iterate mutably over the innermost elements of `array` and its nested arrays, in row-major
+/// order.
+pub fn flat_iter_mut(array: &mut dyn Array) -> ValueIterMut<'_> {
+    Box::new(array.iter_mut().flat_map(|value| {
+        if value.reflect_ref().as_array().is_some() {
+            flat_iter_mut(value.reflect_mut().as_array_mut().unwrap())
+        } else {
+            Box::new(core::iter::once(value)) as ValueIterMut<'_>
+        }
+    }))
+}
+
+/// Copy the innermost elements of `array` into a `Vec`, in row-major order.
+///
+/// Returns `None` if any of the elements isn't a `T`.
+///
+/// # Example
+///
+/// ```
+/// use mirror_mirror::{array::to_flat_vec, Reflect};
+///
+/// let matrix = [[1.0_f32, 0.0], [0.0, 1.0]];
+///
+/// let flat = to_flat_vec::<f32>(matrix.as_reflect().as_array().unwrap()).unwrap();
+/// assert_eq!(flat, [1.0, 0.0, 0.0, 1.0]);
+/// ```
+pub fn to_flat_vec<T>(array: &dyn Array) -> Option<Vec<T>>
+where
+    T: Reflect + Clone,
+{
+    let mut out = Vec::with_capacity(flat_len(array));
+    for value in flat_iter(array) {
+        out.push(value.downcast_ref::<T>()?.clone());
+    }
+    Some(out)
+}
+
+/// Overwrite the innermost elements of `array`, in row-major order, with those of `values`.
+///
+/// Elements that aren't a `T`, and extra elements in the longer of `array` and `values`, are
+/// left untouched. Returns the number of elements that were updated.
+///
+/// # Example
+///
+/// ```
+/// use mirror_mirror::{array::copy_from_flat_slice, Reflect};
+///
+/// let mut matrix = [[0.0_f32; 2]; 2];
+///
+/// let updated = copy_from_flat_slice(
+///     matrix.as_reflect_mut().as_array_mut().unwrap(),
+///     &[1.0_f32, 2.0, 3.0, 4.0],
+/// );
+///
+/// assert_eq!(updated, 4);
+/// assert_eq!(matrix, [[1.0, 2.0], [3.0, 4.0]]);
+/// ```
+pub fn copy_from_flat_slice<T>(array: &mut dyn Array, values: &[T]) -> usize
+where
+    T: Reflect + Clone,
+{
+    let mut updated = 0;
+    for (element, value) in flat_iter_mut(array).zip(values) {
+        if let Some(element) = element.downcast_mut::<T>() {
+            *element = value.clone();
+            updated += 1;
+        }
+    }
+    updated
+}
+
+// shared with `list`
+pub(crate) mod numeric {
+    use crate::Reflect;
+    use crate::ReflectMut;
+    use crate::ReflectRef;
+    use crate::ScalarMut;
+    use crate::ScalarRef;
+
+    pub(crate) fn add_assign<'a, 'b>(
+        a: impl Iterator<Item = &'a mut dyn Reflect>,
+        b: impl Iterator<Item = &'b dyn Reflect>,
+    ) -> usize {
+        let mut updated = 0;
+        for (a, b) in a.zip(b) {
+            if add_assign_scalar(a, b) {
+                updated += 1;
+            }
+        }
+        updated
+    }
+
+    pub(crate) fn scale<'a>(
+        values: impl Iterator<Item = &'a mut dyn Reflect>,
+        factor: f64,
+    ) -> usize {
+        let mut updated = 0;
+        for value in values {
+            if scale_scalar(value, factor) {
+                updated += 1;
+            }
+        }
+        updated
+    }
+
+    fn add_assign_scalar(a: &mut dyn Reflect, b: &dyn Reflect) -> bool {
+        let (ReflectMut::Scalar(a), ReflectRef::Scalar(b)) = (a.reflect_mut(), b.reflect_ref())
+        else {
+            return false;
+        };
+
+        macro_rules! add_assign {
+            (
+                ints: [$($int:ident)*],
+                floats: [$($float:ident)*],
+            ) => {
+                match (a, b) {
+                    $(
+                        (ScalarMut::$int(a), ScalarRef::$int(b)) => *a = a.wrapping_add(b),
+                    )*
+                    $(
+                        (ScalarMut::$float(a), ScalarRef::$float(b)) => *a += b,
+                    )*
+                    _ => return false,
+                }
+            };
+        }
+
+        add_assign! {
+            ints: [usize u8 u16 u32 u64 u128 i8 i16 i32 i64 i128],
+            floats: [f32 f64],
+        }
+
+        true
+    }
+
+    fn scale_scalar(value: &mut dyn Reflect, factor: f64) -> bool {
+        let ReflectMut::Scalar(value) = value.reflect_mut() else {
+            return false;
+        };
+
+        macro_rules! scale {
+            ($($ty:ident)*) => {
+                match value {
+                    $(
+                        ScalarMut::$ty(value) => *value = (*value as f64 * factor) as $ty,
+                    )*
+                    ScalarMut::f64(value) => *value *= factor,
+                    ScalarMut::bool(_) | ScalarMut::char(_) | ScalarMut::String(_) => return false,
+                }
+            };
+        }
+
+        scale!(usize u8 u16 u32 u64 u128 i8 i16 i32 i64 i128 f32);
+
+        true
+    }
+}
@@ -0,0 +1,187 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+use crate::TypeDescriptor;
+
+/// Assert that the reflected shape of a type matches a given shape.
+///
+/// This protects wire-formats built on top of reflection (such as serialized [`Value`]s) from
+/// accidentally drifting when a type is changed. Adding, removing, renaming, or changing the type
+/// of a field makes the assertion panic, so put it in a test:
+///
+/// ```
+/// use mirror_mirror::{assert_type_shape, Reflect};
+///
+/// #[derive(Reflect, Clone, Debug)]
+/// struct Player {
+///     name: String,
+///     position: (f32, f32),
+/// }
+///
+/// #[derive(Reflect, Clone, Debug)]
+/// struct Health(u32);
+///
+/// // typically in a `#[test]`
+/// assert_type_shape!(Player, { name: String, position: (f32, f32) });
+/// assert_type_shape!(Health, (u32));
+/// ```
+///
+/// The shape is checked against the type's [`DescribeType::type_descriptor`] rather than its Rust
+/// definition, so it matches what gets serialized. Fields marked with `#[reflect(skip)]` are left
+/// out, renamed fields are listed by their reflected name (quoted if it isn't an identifier), and
+/// the fields of `#[reflect(flatten)]` fields are listed in place of the field itself:
+///
+/// ```
+/// use mirror_mirror::{assert_type_shape, Reflect};
+///
+/// #[derive(Reflect, Clone, Debug, Default)]
+/// struct Audio {
+///     volume: f32,
+/// }
+///
+/// #[derive(Reflect, Clone, Debug)]
+/// #[reflect(rename_all = "kebab-case")]
+/// struct Settings {
+///     player_name: String,
+///     #[reflect(flatten)]
+///     audio: Audio,
+///     #[reflect(skip)]
+///     dirty: bool,
+/// }
+///
+/// assert_type_shape!(Settings, { "player-name": String, volume: f32 });
+/// ```
+///
+/// The field names and types must match exactly, though fields may be listed in any order for
+/// structs with named fields:
+///
+/// ```should_panic
+/// use mirror_mirror::{assert_type_shape, Reflect};
+///
+/// #[derive(Reflect, Clone, Debug)]
+/// struct Player {
+///     name: String,
+///     position: (f32, f32),
+/// }
+///
+/// // `position` is missing
+/// assert_type_shape!(Player, { name: String });
+/// ```
+///
+/// ```should_panic
+/// use mirror_mirror::{assert_type_shape, Reflect};
+///
+/// #[derive(Reflect, Clone, Debug)]
+/// struct Health(u32);
+///
+/// // wrong type
+/// assert_type_shape!(Health, (i32));
+/// ```
+///
+/// [`Value`]: crate::Value
+/// [`DescribeType::type_descriptor`]: crate::DescribeType::type_descriptor
+#[macro_export]
+macro_rules! assert_type_shape {
+    ($ty:ty, { $($field:tt : $field_ty:ty),* $(,)? }) => {
+        $crate::__private::assert_named_shape(
+            &<$ty as $crate::DescribeType>::type_descriptor(),
+            &[$(
+                (
+                    $crate::assert_type_shape!(@name $field),
+                    <$field_ty as $crate::DescribeType>::type_descriptor
+                        as fn() -> $crate::__private::Cow<'static, $crate::TypeDescriptor>,
+                ),
+            )*],
+        )
+    };
+
+    ($ty:ty, ( $($field_ty:ty),* $(,)? )) => {
+        $crate::__private::assert_unnamed_shape(
+            &<$ty as $crate::DescribeType>::type_descriptor(),
+            &[$(
+                <$field_ty as $crate::DescribeType>::type_descriptor
+                    as fn() -> $crate::__private::Cow<'static, $crate::TypeDescriptor>,
+            )*],
+        )
+    };
+
+    (@name $field:ident) => {
+        stringify!($field)
+    };
+
+    (@name $field:literal) => {
+        $field
+    };
+}
+
+type DescriptorFn = fn() -> Cow<'static, TypeDescriptor>;
+
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_named_shape(descriptor: &TypeDescriptor, fields: &[(&str, DescriptorFn)]) {
+    let Some(struct_type) = descriptor.as_struct() else {
+        panic!(
+            "`{}` is not a struct with named fields",
+            descriptor.type_name()
+        );
+    };
+
+    let mut errors = String::new();
+    for (name, field_descriptor) in fields {
+        let expected = field_descriptor();
+        match struct_type.field_type(name) {
+            Some(field) => {
+                let found = field.get_type().type_name();
+                if found != expected.type_name() {
+                    let _ = writeln!(
+                        errors,
+                        "field `{name}` has type `{found}`, expected `{}`",
+                        expected.type_name()
+                    );
+                }
+            }
+            None => {
+                let _ = writeln!(errors, "field `{name}` is missing");
+            }
+        }
+    }
+    for field in struct_type.field_types() {
+        if !fields.iter().any(|(name, _)| *name == field.name()) {
+            let _ = writeln!(errors, "field `{}` isn't listed", field.name());
+        }
+    }
+
+    if !errors.is_empty() {
+        panic!(
+            "`{}` doesn't have the expected shape:\n{errors}",
+            descriptor.type_name()
+        );
+    }
+}
+
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_unnamed_shape(descriptor: &TypeDescriptor, fields: &[DescriptorFn]) {
+    let Some(tuple_struct_type) = descriptor.as_tuple_struct() else {
+        panic!("`{}` is not a tuple struct", descriptor.type_name());
+    };
+
+    let found = tuple_struct_type
+        .field_types()
+        .map(|field| field.get_type().type_name())
+        .collect::<Vec<_>>();
+    let expected = fields.iter().map(|field| field()).collect::<Vec<_>>();
+    let expected = expected
+        .iter()
+        .map(|field| field.type_name())
+        .collect::<Vec<_>>();
+
+    if found != expected {
+        panic!(
+            "`{}` doesn't have the expected shape: its fields are {found:?}, expected {expected:?}",
+            descriptor.type_name()
+        );
+    }
+}
@@ -0,0 +1,593 @@
+use alloc::borrow::ToOwned;
+use alloc::collections::BTreeMap;
+use alloc::collections::BTreeSet;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+use crate::enum_::EnumValue;
+use crate::key_path::KeyPath;
+use crate::struct_::StructValue;
+use crate::tuple::TupleValue;
+use crate::tuple_struct::TupleStructValue;
+use crate::type_info::EnumType;
+use crate::type_info::ScalarType;
+use crate::type_info::Type;
+use crate::type_info::Variant;
+use crate::DescribeType;
+use crate::FromReflect;
+use crate::Reflect;
+use crate::ReflectError;
+use crate::ReflectRef;
+use crate::ScalarRef;
+use crate::Value;
+
+/// An implicit conversion that [`Coercions`] can be configured to apply.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum Coercion {
+    /// Convert between numeric types if the value fits in the target type. Floats are only
+    /// converted to integers if they don't have a fractional part.
+    NumberToNumber,
+    /// Parse strings into numbers, using [`ScalarType::parse_str`].
+    StringToNumber,
+    /// Parse `true`, `false`, `1`, or `0` into bools, ignoring case.
+    StringToBool,
+    /// Convert strings containing exactly one character into chars.
+    StringToChar,
+    /// Format numbers, bools, and chars as strings.
+    ToString,
+    /// Zero and NaN are `false`, every other number is `true`.
+    NumberToBool,
+    /// `true` is `1` and `false` is `0`.
+    BoolToNumber,
+    /// Convert the name of a variant into that variant, with its fields set to their default
+    /// values.
+    StringToVariant,
+    /// Convert an integer into the variant with that discriminant, if the enum has an integer
+    /// `#[repr(...)]`, otherwise the variant at that index.
+    NumberToVariant,
+}
+
+impl Coercion {
+    /// All the coercions.
+    pub const ALL: [Coercion; 9] = [
+        Coercion::NumberToNumber,
+        Coercion::StringToNumber,
+        Coercion::StringToBool,
+        Coercion::StringToChar,
+        Coercion::ToString,
+        Coercion::NumberToBool,
+        Coercion::BoolToNumber,
+        Coercion::StringToVariant,
+        Coercion::NumberToVariant,
+    ];
+}
+
+/// A custom coercion rule. Returns `None` if the rule doesn't apply.
+pub type CoercionRule = fn(&dyn Reflect, Type<'_>) -> Option<Value>;
+
+/// A table of implicit conversions, so scripting bridges and editors convert loosely typed input
+/// into reflected types consistently.
+///
+/// Values that already have the target type are never converted. Custom rules added with
+/// [`Coercions::with_rule`] are tried, in the order they were added, before the built-in
+/// [`Coercion`]s.
+///
+/// # Example
+///
+/// ```
+/// use mirror_mirror::{coerce::{Coercion, Coercions}, Reflect};
+///
+/// #[derive(Reflect, Clone, Debug, PartialEq)]
+/// enum Quality {
+///     Low,
+///     High,
+/// }
+///
+/// let coercions = Coercions::new()
+///     .with(Coercion::StringToNumber)
+///     .with(Coercion::StringToVariant);
+///
+/// assert_eq!(coercions.coerce_to::<f32>(&"0.5".to_owned()), Some(0.5));
+/// assert_eq!(coercions.coerce_to::<Quality>(&"High".to_owned()), Some(Quality::High));
+///
+/// // not enabled
+/// assert_eq!(coercions.coerce_to::<bool>(&1_i32), None);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Coercions {
+    enabled: BTreeSet<Coercion>,
+    rules: Vec<CoercionRule>,
+}
+
+impl Coercions {
+    /// Create a table without any coercions enabled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a table with every [`Coercion`] enabled.
+    pub fn all() -> Self {
+        Self {
+            enabled: Coercion::ALL.into_iter().collect(),
+            rules: Vec::new(),
+        }
+    }
+
+    pub fn with(mut self, coercion: Coercion) -> Self {
+        self.enable(coercion);
+        self
+    }
+
+    pub fn without(mut self, coercion: Coercion) -> Self {
+        self.disable(coercion);
+        self
+    }
+
+    pub fn enable(&mut self, coercion: Coercion) {
+        self.enabled.insert(coercion);
+    }
+
+    pub fn disable(&mut self, coercion: Coercion) {
+        self.enabled.remove(&coercion);
+    }
+
+    pub fn is_enabled(&self, coercion: Coercion) -> bool {
+        self.enabled.contains(&coercion)
+    }
+
+    pub fn with_rule(mut self, rule: CoercionRule) -> Self {
+        self.add_rule(rule);
+        self
+    }
+
+    pub fn add_rule(&mut self, rule: CoercionRule) {
+        self.rules.push(rule);
+    }
+
+    /// Convert `value` into a value of type `ty`.
+    ///
+    /// Returns `None` if no enabled coercion applies. Values of types other than scalars and
+    /// enums are returned as is, since there is nothing to coerce them into.
+    pub fn coerce(&self, value: &dyn Reflect, ty: Type<'_>) -> Option<Value> {
+        for rule in &self.rules {
+            if let Some(value) = rule(value, ty) {
+                return Some(value);
+            }
+        }
+
+        match ty {
+            Type::Scalar(scalar_type) => self.coerce_scalar(value, scalar_type),
+            Type::Enum(enum_type) => {
+                let ReflectRef::Scalar(scalar) = value.reflect_ref() else {
+                    return Some(value.to_value());
+                };
+                let variant = match scalar {
+                    ScalarRef::String(name) if self.is_enabled(Coercion::StringToVariant) => {
+                        enum_type.variant(name)?
+                    }
+                    _ if self.is_enabled(Coercion::NumberToVariant) => match number(scalar)? {
+                        Number::Float(_) => return None,
+                        Number::Signed(n) => variant_for_number(enum_type, n)?,
+                        Number::Unsigned(n) => {
+                            variant_for_number(enum_type, i128::try_from(n).ok()?)?
+                        }
+                    },
+                    _ => return None,
+                };
+                variant.default_value()
+            }
+            Type::Struct(_)
+            | Type::TupleStruct(_)
+            | Type::Tuple(_)
+            | Type::List(_)
+            | Type::Array(_)
+            | Type::Map(_)
+            | Type::Set(_)
+            | Type::Opaque(_) => Some(value.to_value()),
+        }
+    }
+
+    /// Convert `value` into a `T`.
+    pub fn coerce_to<T>(&self, value: &dyn Reflect) -> Option<T>
+    where
+        T: FromReflect + DescribeType,
+    {
+        if let Some(value) = value.downcast_ref::<T>() {
+            return T::from_reflect(value);
+        }
+        let descriptor = <T as DescribeType>::type_descriptor();
+        T::from_reflect(&self.coerce(value, descriptor.get_type())?)
+    }
+
+    /// Convert `value` into a value of type `ty`, coercing the fields and elements nested within
+    /// it as well.
+    ///
+    /// Fails with an error pointing at the first nested value that no enabled coercion applies
+    /// to, such as a number that doesn't fit in the target type. Fields the type doesn't have are
+    /// dropped, and missing fields are left missing.
+    pub fn coerce_deep(&self, value: &dyn Reflect, ty: Type<'_>) -> Result<Value, ReflectError> {
+        self.coerce_deep_at(value, ty, &mut KeyPath::default())
+    }
+
+    /// Convert `value` into a `T`, coercing the fields and elements nested within it as well.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mirror_mirror::{coerce::{Coercion, Coercions}, key_path, Reflect};
+    /// use mirror_mirror::struct_::StructValue;
+    ///
+    /// #[derive(Reflect, Clone, Debug, PartialEq)]
+    /// struct Window {
+    ///     width: u16,
+    ///     scale: f32,
+    /// }
+    ///
+    /// let coercions = Coercions::new().with(Coercion::NumberToNumber);
+    ///
+    /// // as parsed from JSON
+    /// let value = StructValue::new()
+    ///     .with_field("width", 1920_i64)
+    ///     .with_field("scale", 1.5_f64);
+    /// assert_eq!(
+    ///     coercions.try_coerce_to::<Window>(&value),
+    ///     Ok(Window { width: 1920, scale: 1.5 }),
+    /// );
+    ///
+    /// let value = StructValue::new()
+    ///     .with_field("width", 100_000_i64)
+    ///     .with_field("scale", 1.5_f64);
+    /// let error = coercions.try_coerce_to::<Window>(&value).unwrap_err();
+    /// assert_eq!(error.expected(), "u16");
+    /// assert_eq!(error.found(), Some("i64"));
+    /// assert_eq!(error.path(), &key_path!(.width));
+    /// ```
+    pub fn try_coerce_to<T>(&self, value: &dyn Reflect) -> Result<T, ReflectError>
+    where
+        T: FromReflect + DescribeType,
+    {
+        if let Some(value) = T::from_reflect(value) {
+            return Ok(value);
+        }
+        let descriptor = <T as DescribeType>::type_descriptor();
+        let value = self.coerce_deep(value, descriptor.get_type())?;
+        T::try_from_reflect(&value)
+    }
+
+    fn coerce_deep_at(
+        &self,
+        value: &dyn Reflect,
+        ty: Type<'_>,
+        path: &mut KeyPath,
+    ) -> Result<Value, ReflectError> {
+        let error =
+            |path: &KeyPath| ReflectError::new(ty.type_name(), Some(value)).at(path.clone());
+
+        Ok(match (ty, value.reflect_ref()) {
+            (Type::Struct(struct_type), ReflectRef::Struct(struct_)) => {
+                let mut out = StructValue::with_capacity(struct_type.fields_len())
+                    .with_type_name(struct_type.type_name().to_owned());
+                for field in struct_type.field_types() {
+                    let Some(value) = struct_.field(field.name()) else {
+                        continue;
+                    };
+                    path.push_field(field.name());
+                    out.set_field(
+                        field.name(),
+                        self.coerce_deep_at(value, field.get_type(), path)?,
+                    );
+                    path.pop();
+                }
+                out.into()
+            }
+            (Type::TupleStruct(tuple_struct_type), ReflectRef::TupleStruct(tuple_struct)) => {
+                let mut out = TupleStructValue::with_capacity(tuple_struct_type.fields_len())
+                    .with_type_name(tuple_struct_type.type_name().to_owned());
+                for (index, field) in tuple_struct_type.field_types().enumerate() {
+                    let Some(value) = tuple_struct.field_at(index) else {
+                        break;
+                    };
+                    path.push_field(index);
+                    out.push_field(self.coerce_deep_at(value, field.get_type(), path)?);
+                    path.pop();
+                }
+                out.into()
+            }
+            (Type::Tuple(tuple_type), ReflectRef::Tuple(tuple)) => {
+                let mut out = TupleValue::with_capacity(tuple_type.fields_len());
+                for (index, field) in tuple_type.field_types().enumerate() {
+                    let Some(value) = tuple.field_at(index) else {
+                        break;
+                    };
+                    path.push_field(index);
+                    out.push_field(self.coerce_deep_at(value, field.get_type(), path)?);
+                    path.pop();
+                }
+                out.into()
+            }
+            (Type::Enum(enum_type), ReflectRef::Enum(enum_)) => {
+                let Some(variant) = enum_type.variant(enum_.variant_name()) else {
+                    return Err(error(path));
+                };
+                path.push_variant(variant.name());
+                let mut out = match variant {
+                    Variant::Struct(variant) => {
+                        let mut out = EnumValue::new_struct_variant(variant.name());
+                        for field in variant.field_types() {
+                            let Some(value) = enum_.field(field.name()) else {
+                                continue;
+                            };
+                            path.push_field(field.name());
+                            let value = self.coerce_deep_at(value, field.get_type(), path)?;
+                            out.set_struct_field(field.name(), value);
+                            path.pop();
+                        }
+                        out.finish()
+                    }
+                    Variant::Tuple(variant) => {
+                        let mut out = EnumValue::new_tuple_variant(variant.name());
+                        for (index, field) in variant.field_types().enumerate() {
+                            let Some(value) = enum_.field_at(index) else {
+                                break;
+                            };
+                            path.push_field(index);
+                            out.push_tuple_field(self.coerce_deep_at(
+                                value,
+                                field.get_type(),
+                                path,
+                            )?);
+                            path.pop();
+                        }
+                        out.finish()
+                    }
+                    Variant::Unit(variant) => EnumValue::new_unit_variant(variant.name()),
+                };
+                path.pop();
+                out.set_type_name(enum_type.type_name().to_owned());
+                out.set_discriminant(variant.discriminant());
+                out.into()
+            }
+            (Type::List(list_type), ReflectRef::List(list)) => {
+                self.coerce_elements(list.iter(), list_type.element_type(), path)?
+            }
+            (Type::List(list_type), ReflectRef::Array(array)) => {
+                self.coerce_elements(array.iter(), list_type.element_type(), path)?
+            }
+            (Type::Array(array_type), ReflectRef::List(list)) => {
+                self.coerce_elements(list.iter(), array_type.element_type(), path)?
+            }
+            (Type::Array(array_type), ReflectRef::Array(array)) => {
+                self.coerce_elements(array.iter(), array_type.element_type(), path)?
+            }
+            (Type::Map(map_type), ReflectRef::Map(map)) => {
+                let mut out = BTreeMap::new();
+                for (key, value) in map.iter() {
+                    let key = self
+                        .coerce_deep_at(key, map_type.key_type(), &mut KeyPath::default())
+                        .map_err(|_| error(path))?;
+                    path.push_get(key.clone());
+                    let value = self.coerce_deep_at(value, map_type.value_type(), path)?;
+                    path.pop();
+                    out.insert(key, value);
+                }
+                Value::Map(out)
+            }
+            (Type::Set(set_type), ReflectRef::Set(set)) => {
+                let mut out = BTreeSet::new();
+                for element in set.iter() {
+                    let element = self
+                        .coerce_deep_at(element, set_type.element_type(), &mut KeyPath::default())
+                        .map_err(|_| error(path))?;
+                    out.insert(element);
+                }
+                Value::Set(out)
+            }
+            (Type::Scalar(_) | Type::Enum(_), _) => {
+                self.coerce(value, ty).ok_or_else(|| error(path))?
+            }
+            (Type::Opaque(_), _) => value.to_value(),
+            _ => return Err(error(path)),
+        })
+    }
+
+    fn coerce_elements<'a>(
+        &self,
+        elements: impl Iterator<Item = &'a dyn Reflect>,
+        ty: Type<'_>,
+        path: &mut KeyPath,
+    ) -> Result<Value, ReflectError> {
+        let mut out = Vec::with_capacity(elements.size_hint().0);
+        for (index, element) in elements.enumerate() {
+            path.push_get(index);
+            out.push(self.coerce_deep_at(element, ty, path)?);
+            path.pop();
+        }
+        Ok(Value::List(out))
+    }
+
+    fn coerce_scalar(&self, value: &dyn Reflect, ty: ScalarType) -> Option<Value> {
+        let ReflectRef::Scalar(scalar) = value.reflect_ref() else {
+            return None;
+        };
+        if scalar_type(scalar) == ty {
+            return Some(value.to_value());
+        }
+
+        match (scalar, ty) {
+            (ScalarRef::String(s), ScalarType::bool) if self.is_enabled(Coercion::StringToBool) => {
+                ty.parse_str(s).ok()
+            }
+            (ScalarRef::String(s), ScalarType::char) if self.is_enabled(Coercion::StringToChar) => {
+                let mut chars = s.chars();
+                let c = chars.next()?;
+                chars.next().is_none().then(|| c.into())
+            }
+            (ScalarRef::String(s), _) if is_number(ty) => {
+                if self.is_enabled(Coercion::StringToNumber) {
+                    ty.parse_str(s).ok()
+                } else {
+                    None
+                }
+            }
+            (ScalarRef::bool(b), _) if is_number(ty) => {
+                if self.is_enabled(Coercion::BoolToNumber) {
+                    number_to_scalar(Number::Unsigned(b.into()), ty)
+                } else {
+                    None
+                }
+            }
+            (scalar, ScalarType::String) if self.is_enabled(Coercion::ToString) => match scalar {
+                ScalarRef::usize(n) => Some(n.to_string().into()),
+                ScalarRef::u8(n) => Some(n.to_string().into()),
+                ScalarRef::u16(n) => Some(n.to_string().into()),
+                ScalarRef::u32(n) => Some(n.to_string().into()),
+                ScalarRef::u64(n) => Some(n.to_string().into()),
+                ScalarRef::u128(n) => Some(n.to_string().into()),
+                ScalarRef::i8(n) => Some(n.to_string().into()),
+                ScalarRef::i16(n) => Some(n.to_string().into()),
+                ScalarRef::i32(n) => Some(n.to_string().into()),
+                ScalarRef::i64(n) => Some(n.to_string().into()),
+                ScalarRef::i128(n) => Some(n.to_string().into()),
+                ScalarRef::f32(n) => Some(n.to_string().into()),
+                ScalarRef::f64(n) => Some(n.to_string().into()),
+                ScalarRef::bool(b) => Some(b.to_string().into()),
+                ScalarRef::char(c) => Some(c.to_string().into()),
+                ScalarRef::String(_) => None,
+            },
+            (scalar, ScalarType::bool) if self.is_enabled(Coercion::NumberToBool) => Some(
+                match number(scalar)? {
+                    Number::Signed(n) => n != 0,
+                    Number::Unsigned(n) => n != 0,
+                    Number::Float(n) => n != 0.0 && !n.is_nan(),
+                }
+                .into(),
+            ),
+            (scalar, _) if self.is_enabled(Coercion::NumberToNumber) => {
+                number_to_scalar(number(scalar)?, ty)
+            }
+            _ => None,
+        }
+    }
+}
+
+enum Number {
+    Signed(i128),
+    Unsigned(u128),
+    Float(f64),
+}
+
+fn number(scalar: ScalarRef<'_>) -> Option<Number> {
+    Some(match scalar {
+        ScalarRef::usize(n) => Number::Unsigned(n as u128),
+        ScalarRef::u8(n) => Number::Unsigned(n.into()),
+        ScalarRef::u16(n) => Number::Unsigned(n.into()),
+        ScalarRef::u32(n) => Number::Unsigned(n.into()),
+        ScalarRef::u64(n) => Number::Unsigned(n.into()),
+        ScalarRef::u128(n) => Number::Unsigned(n),
+        ScalarRef::i8(n) => Number::Signed(n.into()),
+        ScalarRef::i16(n) => Number::Signed(n.into()),
+        ScalarRef::i32(n) => Number::Signed(n.into()),
+        ScalarRef::i64(n) => Number::Signed(n.into()),
+        ScalarRef::i128(n) => Number::Signed(n),
+        ScalarRef::f32(n) => Number::Float(n.into()),
+        ScalarRef::f64(n) => Number::Float(n),
+        ScalarRef::bool(_) | ScalarRef::char(_) | ScalarRef::String(_) => return None,
+    })
+}
+
+fn number_to_scalar(number: Number, ty: ScalarType) -> Option<Value> {
+    // floats become integers only if they have no fractional part and are in range
+    let number = match number {
+        Number::Float(n) if !matches!(ty, ScalarType::f32 | ScalarType::f64) => {
+            // `as` saturates, so out of range values don't round trip
+            if n < 0.0 && n >= i128::MIN as f64 && (n as i128) as f64 == n {
+                Number::Signed(n as i128)
+            } else if n >= 0.0 && n < u128::MAX as f64 && (n as u128) as f64 == n {
+                Number::Unsigned(n as u128)
+            } else {
+                return None;
+            }
+        }
+        number => number,
+    };
+
+    macro_rules! int {
+        ($ty:ident) => {
+            match number {
+                Number::Signed(n) => $ty::try_from(n).ok()?.into(),
+                Number::Unsigned(n) => $ty::try_from(n).ok()?.into(),
+                Number::Float(_) => return None,
+            }
+        };
+    }
+
+    macro_rules! float {
+        ($ty:ident) => {{
+            let n = match number {
+                Number::Signed(n) => n as $ty,
+                Number::Unsigned(n) => n as $ty,
+                Number::Float(n) => {
+                    let converted = n as $ty;
+                    if n.is_finite() && !converted.is_finite() {
+                        return None;
+                    }
+                    converted
+                }
+            };
+            n.into()
+        }};
+    }
+
+    Some(match ty {
+        ScalarType::usize => int!(usize),
+        ScalarType::u8 => int!(u8),
+        ScalarType::u16 => int!(u16),
+        ScalarType::u32 => int!(u32),
+        ScalarType::u64 => int!(u64),
+        ScalarType::u128 => int!(u128),
+        ScalarType::i8 => int!(i8),
+        ScalarType::i16 => int!(i16),
+        ScalarType::i32 => int!(i32),
+        ScalarType::i64 => int!(i64),
+        ScalarType::i128 => int!(i128),
+        ScalarType::f32 => float!(f32),
+        ScalarType::f64 => float!(f64),
+        ScalarType::bool | ScalarType::char | ScalarType::String => return None,
+    })
+}
+
+fn variant_for_number(enum_type: EnumType<'_>, n: i128) -> Option<Variant<'_>> {
+    if enum_type.repr().is_some() {
+        enum_type
+            .variants()
+            .find(|variant| variant.discriminant() == Some(n))
+    } else {
+        enum_type.variant_at(usize::try_from(n).ok()?)
+    }
+}
+
+fn is_number(ty: ScalarType) -> bool {
+    !matches!(ty, ScalarType::bool | ScalarType::char | ScalarType::String)
+}
+
+fn scalar_type(scalar: ScalarRef<'_>) -> ScalarType {
+    match scalar {
+        ScalarRef::usize(_) => ScalarType::usize,
+        ScalarRef::u8(_) => ScalarType::u8,
+        ScalarRef::u16(_) => ScalarType::u16,
+        ScalarRef::u32(_) => ScalarType::u32,
+        ScalarRef::u64(_) => ScalarType::u64,
+        ScalarRef::u128(_) => ScalarType::u128,
+        ScalarRef::i8(_) => ScalarType::i8,
+        ScalarRef::i16(_) => ScalarType::i16,
+        ScalarRef::i32(_) => ScalarType::i32,
+        ScalarRef::i64(_) => ScalarType::i64,
+        ScalarRef::i128(_) => ScalarType::i128,
+        ScalarRef::bool(_) => ScalarType::bool,
+        ScalarRef::char(_) => ScalarType::char,
+        ScalarRef::f32(_) => ScalarType::f32,
+        ScalarRef::f64(_) => ScalarType::f64,
+        ScalarRef::String(_) => ScalarType::String,
+    }
+}
@@ -0,0 +1,324 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::string::ToString;
+use core::fmt;
+
+use crate::enum_::VariantField;
+use crate::error;
+use crate::key_path::GetPath;
+use crate::key_path::GetTypePath;
+use crate::key_path::KeyPath;
+use crate::DescribeType;
+use crate::FromReflect;
+use crate::Reflect;
+use crate::ReflectRef;
+use crate::TypeDescriptor;
+use crate::Value;
+
+/// Check that the reflection impls of `T` behave like derived ones, for every sample.
+///
+/// Useful in tests of hand written impls. For each sample this checks that:
+///
+/// - `FromReflect` accepts the sample itself, and its [`Value`], and gives back an equal value.
+/// - Patching a clone with the sample's value gives back an equal value.
+/// - The sample and its value have the shape the type descriptor describes, and the [default
+///   value], if the type has one, can be converted with `FromReflect` and patched with the
+///   sample's value.
+/// - Every key path into the value resolves in both the sample and the type descriptor.
+/// - The value round-trips through speedy, if that feature is enabled.
+///
+/// Values are compared by their [`Value`]. Use [`check_roundtrip_through`] to also round-trip
+/// through other formats, such as those supported by serde.
+///
+/// # Example
+///
+/// ```
+/// use mirror_mirror::{Reflect, conformance::check_roundtrip};
+///
+/// #[derive(Reflect, Clone, Debug)]
+/// struct Player {
+///     name: String,
+///     inventory: Vec<Option<u32>>,
+/// }
+///
+/// check_roundtrip([
+///     Player {
+///         name: "Alice".to_owned(),
+///         inventory: Vec::from([Some(1), None]),
+///     },
+/// ])
+/// .unwrap();
+/// ```
+///
+/// [default value]: TypeDescriptor::default_value
+pub fn check_roundtrip<T>(samples: impl IntoIterator<Item = T>) -> Result<(), ConformanceError>
+where
+    T: Reflect + FromReflect + DescribeType,
+{
+    let descriptor = <T as DescribeType>::type_descriptor();
+    for (index, sample) in samples.into_iter().enumerate() {
+        let fail = |check, details| ConformanceError {
+            sample: index,
+            check,
+            details,
+        };
+        check_sample(&sample, &descriptor).map_err(|(check, details)| fail(check, details))?;
+    }
+    Ok(())
+}
+
+/// Like [`check_roundtrip`] but also check that the value of each sample round-trips through
+/// `through`.
+///
+/// # Example
+///
+/// ```
+/// use mirror_mirror::{Reflect, Value, conformance::check_roundtrip_through};
+///
+/// #[derive(Reflect, Clone, Debug)]
+/// enum Shape {
+///     Circle { radius: f32 },
+///     Point,
+/// }
+///
+/// check_roundtrip_through([Shape::Circle { radius: 1.0 }, Shape::Point], |value| {
+///     let json = serde_json::to_string(value)?;
+///     serde_json::from_str::<Value>(&json)
+/// })
+/// .unwrap();
+/// ```
+pub fn check_roundtrip_through<T, E>(
+    samples: impl IntoIterator<Item = T>,
+    mut through: impl FnMut(&Value) -> Result<Value, E>,
+) -> Result<(), ConformanceError>
+where
+    T: Reflect + FromReflect + DescribeType,
+    E: fmt::Display,
+{
+    let descriptor = <T as DescribeType>::type_descriptor();
+    for (index, sample) in samples.into_iter().enumerate() {
+        let fail = |check, details| ConformanceError {
+            sample: index,
+            check,
+            details,
+        };
+        check_sample(&sample, &descriptor).map_err(|(check, details)| fail(check, details))?;
+
+        let value = sample.to_value();
+        let output = through(&value).map_err(|err| fail(Check::Format, err.to_string()))?;
+        expect_from_value::<T>(&output, &value).map_err(|details| fail(Check::Format, details))?;
+    }
+    Ok(())
+}
+
+fn check_sample<T>(sample: &T, descriptor: &TypeDescriptor) -> Result<(), (Check, String)>
+where
+    T: Reflect + FromReflect + DescribeType,
+{
+    let value = sample.to_value();
+
+    let copy = T::from_reflect(sample)
+        .ok_or_else(|| (Check::FromReflect, "`from_reflect` returned `None`".into()))?;
+    expect_eq(&copy, &value).map_err(|details| (Check::FromReflect, details))?;
+
+    expect_from_value::<T>(&value, &value).map_err(|details| (Check::ValueRoundtrip, details))?;
+
+    let mut patched = sample.clone_reflect();
+    patched.patch(&value);
+    expect_eq(patched.as_reflect(), &value).map_err(|details| (Check::Patch, details))?;
+
+    if let Some(default) = descriptor.default_value() {
+        let mut patched = T::from_reflect(&default).ok_or_else(|| {
+            (
+                Check::Descriptor,
+                "`from_reflect` of the default value returned `None`".into(),
+            )
+        })?;
+        // lists and maps don't grow when patched so the result isn't compared, this only checks
+        // that patching doesn't panic
+        patched.patch(&value);
+    }
+
+    for reflect in [sample as &dyn Reflect, &value] {
+        if let Some(error) = error::find_mismatch(descriptor.get_type(), reflect) {
+            return Err((Check::Descriptor, error.to_string()));
+        }
+    }
+
+    check_paths(sample, &value, descriptor, &mut KeyPath::default())
+        .map_err(|path| (Check::Path, format!("`{path}` doesn't resolve")))?;
+
+    #[cfg(feature = "speedy")]
+    {
+        use speedy::Readable;
+        use speedy::Writable;
+
+        let bytes = value
+            .write_to_vec()
+            .map_err(|err| (Check::Speedy, err.to_string()))?;
+        let output =
+            Value::read_from_buffer(&bytes).map_err(|err| (Check::Speedy, err.to_string()))?;
+        expect_from_value::<T>(&output, &value).map_err(|details| (Check::Speedy, details))?;
+    }
+
+    Ok(())
+}
+
+fn expect_from_value<T>(value: &Value, expected: &Value) -> Result<(), String>
+where
+    T: FromReflect,
+{
+    let output = T::from_reflect(value)
+        .ok_or_else(|| String::from("`from_reflect` of the value returned `None`"))?;
+    expect_eq(&output, expected)
+}
+
+fn expect_eq(actual: &dyn Reflect, expected: &Value) -> Result<(), String> {
+    let actual = actual.to_value();
+    if actual == *expected {
+        Ok(())
+    } else {
+        Err(format!("expected `{expected:?}`, got `{actual:?}`"))
+    }
+}
+
+/// Check that every key path into `value` resolves in `sample` and `descriptor`, returning the
+/// first one that doesn't.
+fn check_paths(
+    sample: &dyn Reflect,
+    value: &dyn Reflect,
+    descriptor: &TypeDescriptor,
+    path: &mut KeyPath,
+) -> Result<(), KeyPath> {
+    if sample.at(path).is_none() || descriptor.type_at(path).is_none() {
+        return Err(path.clone());
+    }
+
+    macro_rules! nested {
+        ($push:expr, $value:expr) => {{
+            $push;
+            check_paths(sample, $value, descriptor, path)?;
+            path.pop();
+        }};
+    }
+
+    match value.reflect_ref() {
+        ReflectRef::Struct(struct_) => {
+            for (name, field) in struct_.fields() {
+                nested!(path.push_field(name), field);
+            }
+        }
+        ReflectRef::TupleStruct(tuple_struct) => {
+            for (index, field) in tuple_struct.fields().enumerate() {
+                nested!(path.push_field(index), field);
+            }
+        }
+        ReflectRef::Tuple(tuple) => {
+            for (index, field) in tuple.fields().enumerate() {
+                nested!(path.push_field(index), field);
+            }
+        }
+        ReflectRef::Enum(enum_) => {
+            path.push_variant(enum_.variant_name());
+            for (index, field) in enum_.fields().enumerate() {
+                match field {
+                    VariantField::Struct(name, field) => nested!(path.push_field(name), field),
+                    VariantField::Tuple(field) => nested!(path.push_field(index), field),
+                }
+            }
+            path.pop();
+        }
+        ReflectRef::Array(array) => {
+            for (index, element) in array.iter().enumerate() {
+                nested!(path.push_get(index), element);
+            }
+        }
+        ReflectRef::List(list) => {
+            for (index, element) in list.iter().enumerate() {
+                nested!(path.push_get(index), element);
+            }
+        }
+        ReflectRef::Map(map) => {
+            for (key, element) in map.iter() {
+                nested!(path.push_get(key.to_value()), element);
+            }
+        }
+        ReflectRef::Set(_) | ReflectRef::Scalar(_) | ReflectRef::Opaque(_) => {}
+    }
+
+    Ok(())
+}
+
+/// Error returned by [`check_roundtrip`] and [`check_roundtrip_through`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConformanceError {
+    sample: usize,
+    check: Check,
+    details: String,
+}
+
+impl ConformanceError {
+    /// The index of the sample that failed.
+    pub fn sample(&self) -> usize {
+        self.sample
+    }
+
+    /// The check that failed.
+    pub fn check(&self) -> Check {
+        self.check
+    }
+
+    /// What went wrong.
+    pub fn details(&self) -> &str {
+        &self.details
+    }
+}
+
+impl fmt::Display for ConformanceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "sample {} failed the {} check: {}",
+            self.sample, self.check, self.details
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ConformanceError {}
+
+/// The checks done by [`check_roundtrip`] and [`check_roundtrip_through`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Check {
+    /// Converting the sample itself with `FromReflect`.
+    FromReflect,
+    /// Converting the sample's value back with `FromReflect`.
+    ValueRoundtrip,
+    /// Patching a clone with the sample's value.
+    Patch,
+    /// Comparing the sample with its type descriptor.
+    Descriptor,
+    /// Resolving key paths into the sample.
+    Path,
+    /// Round-tripping the sample's value through speedy.
+    Speedy,
+    /// Round-tripping the sample's value through the format given to
+    /// [`check_roundtrip_through`].
+    Format,
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::FromReflect => "from reflect",
+            Self::ValueRoundtrip => "value roundtrip",
+            Self::Patch => "patch",
+            Self::Descriptor => "descriptor",
+            Self::Path => "path",
+            Self::Speedy => "speedy",
+            Self::Format => "format",
+        };
+        f.write_str(name)
+    }
+}
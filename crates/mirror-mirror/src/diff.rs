@@ -0,0 +1,312 @@
+use alloc::vec::Vec;
+
+use crate::enum_::VariantField;
+use crate::key_path::GetPath;
+use crate::key_path::KeyPath;
+use crate::reflect_eq;
+use crate::Reflect;
+use crate::ReflectMut;
+use crate::ReflectRef;
+use crate::Value;
+
+/// A list of changes that turns one value into another.
+///
+/// Created with [`diff`] and applied with [`apply`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Diff {
+    changes: Vec<Change>,
+}
+
+impl Diff {
+    /// The changes in the order they should be applied.
+    pub fn changes(&self) -> &[Change] {
+        &self.changes
+    }
+
+    pub fn iter(&self) -> core::slice::Iter<'_, Change> {
+        self.changes.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.changes.len()
+    }
+
+    /// Returns `true` if the two values that were diffed are equal.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+impl<'a> IntoIterator for &'a Diff {
+    type Item = &'a Change;
+    type IntoIter = core::slice::Iter<'a, Change>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for Diff {
+    type Item = Change;
+    type IntoIter = alloc::vec::IntoIter<Change>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.changes.into_iter()
+    }
+}
+
+impl FromIterator<Change> for Diff {
+    fn from_iter<T>(iter: T) -> Self
+    where
+        T: IntoIterator<Item = Change>,
+    {
+        Self {
+            changes: iter.into_iter().collect(),
+        }
+    }
+}
+
+/// A single change in a [`Diff`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Change {
+    /// Patch the value at `path` with `value`.
+    Set { path: KeyPath, value: Value },
+    /// Push `value` onto the end of the list at `path`.
+    Push { path: KeyPath, value: Value },
+    /// Pop elements off the end of the list at `path` until it has `len` elements.
+    Truncate { path: KeyPath, len: usize },
+    /// Insert `key` with `value` into the map at `path`.
+    Insert {
+        path: KeyPath,
+        key: Value,
+        value: Value,
+    },
+    /// Remove `key` from the map at `path`.
+    Remove { path: KeyPath, key: Value },
+}
+
+impl Change {
+    /// The path of the value the change applies to.
+    pub fn path(&self) -> &KeyPath {
+        match self {
+            Change::Set { path, .. }
+            | Change::Push { path, .. }
+            | Change::Truncate { path, .. }
+            | Change::Insert { path, .. }
+            | Change::Remove { path, .. } => path,
+        }
+    }
+}
+
+/// Compute the changes needed to turn `a` into `b`.
+///
+/// Structs, tuples, arrays, and enums with the same variant are diffed field by field. Lists are
+/// diffed element by element, with elements pushed or popped at the end. Maps are diffed by key.
+/// Everything else, including sets, enums with different variants, and values whose shapes
+/// don't match, is replaced wholesale when not equal.
+///
+/// # Example
+///
+/// ```
+/// use mirror_mirror::diff::{apply, diff};
+/// use mirror_mirror::Reflect;
+///
+/// #[derive(Reflect, Clone, Debug, PartialEq)]
+/// struct Player {
+///     name: String,
+///     position: [f32; 2],
+///     inventory: Vec<String>,
+/// }
+///
+/// let before = Player {
+///     name: "Alice".to_owned(),
+///     position: [0.0, 0.0],
+///     inventory: Vec::new(),
+/// };
+///
+/// let after = Player {
+///     name: "Alice".to_owned(),
+///     position: [1.0, 0.0],
+///     inventory: vec!["sword".to_owned()],
+/// };
+///
+/// let changes = diff(&before, &after);
+///
+/// // only the first position component and the inventory changed
+/// assert_eq!(changes.len(), 2);
+///
+/// let mut value = before.clone();
+/// apply(&mut value, &changes);
+/// assert_eq!(value, after);
+/// ```
+pub fn diff(a: &dyn Reflect, b: &dyn Reflect) -> Diff {
+    let mut changes = Vec::new();
+    go(a, b, &mut KeyPath::default(), &mut changes);
+    Diff { changes }
+}
+
+fn go(a: &dyn Reflect, b: &dyn Reflect, path: &mut KeyPath, changes: &mut Vec<Change>) {
+    macro_rules! field {
+        ($key:expr, $a:expr, $b:expr) => {{
+            path.push_field($key);
+            go($a, $b, path, changes);
+            path.pop();
+        }};
+    }
+
+    match (a.reflect_ref(), b.reflect_ref()) {
+        (ReflectRef::Struct(a), ReflectRef::Struct(b))
+            if a.fields_len() == b.fields_len()
+                && b.fields().all(|(name, _)| a.field(name).is_some()) =>
+        {
+            for (name, b) in b.fields() {
+                field!(name, a.field(name).unwrap(), b);
+            }
+        }
+        (ReflectRef::TupleStruct(a), ReflectRef::TupleStruct(b))
+            if a.fields_len() == b.fields_len() =>
+        {
+            for (index, (a, b)) in a.fields().zip(b.fields()).enumerate() {
+                field!(index, a, b);
+            }
+        }
+        (ReflectRef::Tuple(a), ReflectRef::Tuple(b)) if a.fields_len() == b.fields_len() => {
+            for (index, (a, b)) in a.fields().zip(b.fields()).enumerate() {
+                field!(index, a, b);
+            }
+        }
+        (ReflectRef::Enum(enum_a), ReflectRef::Enum(enum_b))
+            if enum_a.variant_name() == enum_b.variant_name()
+                && enum_a.fields_len() == enum_b.fields_len() =>
+        {
+            for (index, field) in enum_b.fields().enumerate() {
+                match field {
+                    VariantField::Struct(name, field_b) => match enum_a.field(name) {
+                        Some(field_a) => field!(name, field_a, field_b),
+                        None => return set(b, path, changes),
+                    },
+                    VariantField::Tuple(field_b) => match enum_a.field_at(index) {
+                        Some(field_a) => field!(index, field_a, field_b),
+                        None => return set(b, path, changes),
+                    },
+                }
+            }
+        }
+        (ReflectRef::Array(a), ReflectRef::Array(b)) if a.len() == b.len() => {
+            for (index, (a, b)) in a.iter().zip(b.iter()).enumerate() {
+                path.push_get(index);
+                go(a, b, path, changes);
+                path.pop();
+            }
+        }
+        // arrays are lists once converted to values
+        (ReflectRef::Array(a), ReflectRef::List(b)) if a.len() == b.len() => {
+            for (index, (a, b)) in a.iter().zip(b.iter()).enumerate() {
+                path.push_get(index);
+                go(a, b, path, changes);
+                path.pop();
+            }
+        }
+        (ReflectRef::List(a), ReflectRef::List(b)) => {
+            for (index, (a, b)) in a.iter().zip(b.iter()).enumerate() {
+                path.push_get(index);
+                go(a, b, path, changes);
+                path.pop();
+            }
+            if a.len() > b.len() {
+                changes.push(Change::Truncate {
+                    path: path.clone(),
+                    len: b.len(),
+                });
+            }
+            for b in b.iter().skip(a.len()) {
+                changes.push(Change::Push {
+                    path: path.clone(),
+                    value: b.to_value(),
+                });
+            }
+        }
+        (ReflectRef::Map(a), ReflectRef::Map(b)) => {
+            for (key, _) in a.iter() {
+                if b.get(key).is_none() {
+                    changes.push(Change::Remove {
+                        path: path.clone(),
+                        key: key.to_value(),
+                    });
+                }
+            }
+            for (key, b) in b.iter() {
+                match a.get(key) {
+                    Some(a) => {
+                        path.push_get(key.to_value());
+                        go(a, b, path, changes);
+                        path.pop();
+                    }
+                    None => changes.push(Change::Insert {
+                        path: path.clone(),
+                        key: key.to_value(),
+                        value: b.to_value(),
+                    }),
+                }
+            }
+        }
+        _ => {
+            if reflect_eq(a, b) != Some(true) {
+                set(b, path, changes);
+            }
+        }
+    }
+}
+
+fn set(value: &dyn Reflect, path: &KeyPath, changes: &mut Vec<Change>) {
+    changes.push(Change::Set {
+        path: path.clone(),
+        value: value.to_value(),
+    });
+}
+
+/// Apply the changes in `diff` to `value`.
+///
+/// Changes whose path doesn't exist in `value`, or that don't fit the kind of value found at the
+/// path, are ignored.
+pub fn apply(value: &mut dyn Reflect, diff: &Diff) {
+    for change in diff {
+        let path = change.path();
+        let target = if path.is_empty() {
+            Some(&mut *value)
+        } else {
+            value.at_mut(path)
+        };
+        let Some(target) = target else {
+            continue;
+        };
+
+        match change {
+            Change::Set { value, .. } => target.patch(value),
+            Change::Push { value, .. } => {
+                if let ReflectMut::List(list) = target.reflect_mut() {
+                    list.push(value);
+                }
+            }
+            Change::Truncate { len, .. } => {
+                if let ReflectMut::List(list) = target.reflect_mut() {
+                    list.truncate(*len);
+                }
+            }
+            Change::Insert { key, value, .. } => {
+                if let ReflectMut::Map(map) = target.reflect_mut() {
+                    map.insert(key, value);
+                }
+            }
+            Change::Remove { key, .. } => {
+                if let ReflectMut::Map(map) = target.reflect_mut() {
+                    map.remove(key);
+                }
+            }
+        }
+    }
+}
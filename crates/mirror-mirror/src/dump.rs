@@ -0,0 +1,529 @@
+use alloc::collections::BTreeMap;
+use alloc::collections::BTreeSet;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use std::path::Path;
+
+use speedy::LittleEndian;
+use speedy::Readable;
+use speedy::Writable;
+
+use crate::key_path::GetPath;
+use crate::key_path::KeyPath;
+use crate::reflect_hash_with;
+use crate::type_info::stable_hash::StableHasher;
+use crate::versioned::stable_type_hash;
+use crate::DescribeType;
+use crate::NanHandling;
+use crate::Reflect;
+use crate::TypeDescriptor;
+use crate::Value;
+
+/// Written at the start of every dump.
+const MAGIC: &[u8; 6] = b"MMDUMP";
+
+/// The number of bytes in the header. The magic bytes followed by a 4 byte format version.
+const HEADER_LEN: usize = MAGIC.len() + 4;
+
+/// A snapshot of named reflected values, with everything needed to inspect them offline.
+///
+/// Each entry stores the value's [`Value`], its [`TypeDescriptor`], a [stable hash] of its type,
+/// and a stable hash of its contents. Dumps can be written to files and read back by a process
+/// that doesn't have the types compiled in, and then compared with [`diff`] to find what changed
+/// between two snapshots, such as the state of two processes or of one process over time.
+///
+/// # Example
+///
+/// ```
+/// use mirror_mirror::{dump::{self, Dump}, Reflect};
+///
+/// #[derive(Reflect, Clone, Debug)]
+/// struct World {
+///     names: Vec<String>,
+///     seed: u64,
+/// }
+///
+/// let before = Dump::new().with_value(
+///     "world",
+///     &World { names: Vec::from(["a".to_owned()]), seed: 1 },
+/// );
+/// let after = Dump::new().with_value(
+///     "world",
+///     &World { names: Vec::from(["a".to_owned(), "b".to_owned()]), seed: 1 },
+/// );
+///
+/// // dumps are usually written to files by the processes being inspected
+/// let bytes = after.write_to_vec().unwrap();
+/// let after = Dump::read_from_buffer(&bytes).unwrap();
+///
+/// let diff = dump::diff(&before, &after);
+/// assert_eq!(diff.len(), 1);
+/// println!("{diff}");
+/// ```
+///
+/// [stable hash]: stable_type_hash
+#[derive(Debug, Clone, Default, PartialEq, Readable, Writable)]
+pub struct Dump {
+    entries: BTreeMap<String, DumpEntry>,
+}
+
+impl Dump {
+    /// The version of the format dumps are written in.
+    ///
+    /// Bumped whenever the encoding changes. Dumps written with another version are rejected
+    /// when read.
+    pub const FORMAT_VERSION: u32 = 1;
+
+    /// Create an empty dump.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a value, replacing any previous value with the same name.
+    pub fn with_value<T>(mut self, name: impl Into<String>, value: &T) -> Self
+    where
+        T: Reflect + DescribeType,
+    {
+        self.insert(name, value);
+        self
+    }
+
+    /// Add a value, replacing any previous value with the same name.
+    pub fn insert<T>(&mut self, name: impl Into<String>, value: &T)
+    where
+        T: Reflect + DescribeType,
+    {
+        let descriptor = <T as DescribeType>::type_descriptor().into_owned();
+        let value = value.to_value();
+        let entry = DumpEntry {
+            type_hash: stable_type_hash(&descriptor),
+            content_hash: content_hash(&value),
+            descriptor,
+            value,
+        };
+        self.entries.insert(name.into(), entry);
+    }
+
+    /// Get the entry with the given name.
+    pub fn get(&self, name: &str) -> Option<&DumpEntry> {
+        self.entries.get(name)
+    }
+
+    /// Iterate over the entries, ordered by name.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &DumpEntry)> {
+        self.entries
+            .iter()
+            .map(|(name, entry)| (name.as_str(), entry))
+    }
+
+    /// The number of entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the dump has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Write the header followed by the entries.
+    pub fn write_to_vec(&self) -> Result<Vec<u8>, speedy::Error> {
+        let mut bytes = Vec::with_capacity(HEADER_LEN);
+        bytes.extend_from_slice(MAGIC);
+        bytes.extend_from_slice(&Self::FORMAT_VERSION.to_le_bytes());
+        bytes.extend(Writable::<LittleEndian>::write_to_vec(self)?);
+        Ok(bytes)
+    }
+
+    /// Read a dump written with [`Dump::write_to_vec`].
+    pub fn read_from_buffer(bytes: &[u8]) -> Result<Self, DumpError> {
+        if bytes.len() < HEADER_LEN || !bytes.starts_with(MAGIC) {
+            return Err(DumpError::MissingHeader);
+        }
+        let (header, payload) = bytes.split_at(HEADER_LEN);
+        let version = u32::from_le_bytes(header[MAGIC.len()..].try_into().unwrap());
+        if version != Self::FORMAT_VERSION {
+            return Err(DumpError::UnsupportedVersion(version));
+        }
+        Ok(<Self as Readable<'_, LittleEndian>>::read_from_buffer(
+            payload,
+        )?)
+    }
+
+    /// Write the dump to a file, replacing it if it exists.
+    pub fn write_to_file(&self, path: impl AsRef<Path>) -> Result<(), DumpError> {
+        std::fs::write(path, self.write_to_vec()?)?;
+        Ok(())
+    }
+
+    /// Read a dump written with [`Dump::write_to_file`].
+    pub fn read_from_file(path: impl AsRef<Path>) -> Result<Self, DumpError> {
+        Self::read_from_buffer(&std::fs::read(path)?)
+    }
+}
+
+/// A single value in a [`Dump`].
+#[derive(Debug, Clone, PartialEq, Readable, Writable)]
+pub struct DumpEntry {
+    descriptor: TypeDescriptor,
+    type_hash: u64,
+    content_hash: Option<u64>,
+    value: Value,
+}
+
+impl DumpEntry {
+    /// The type descriptor of the value.
+    pub fn descriptor(&self) -> &TypeDescriptor {
+        &self.descriptor
+    }
+
+    /// The [stable hash](stable_type_hash) of the value's type.
+    pub fn type_hash(&self) -> u64 {
+        self.type_hash
+    }
+
+    /// A hash of the value's contents, computed with [`reflect_hash`] and a hasher that is
+    /// stable across platforms.
+    ///
+    /// `None` if the value contains opaque values, which can't be hashed.
+    ///
+    /// [`reflect_hash`]: crate::reflect_hash
+    pub fn content_hash(&self) -> Option<u64> {
+        self.content_hash
+    }
+
+    /// The value itself.
+    pub fn value(&self) -> &Value {
+        &self.value
+    }
+
+    /// The number of bytes the value takes up when encoded with speedy.
+    pub fn size(&self) -> usize {
+        encoded_size(&self.value)
+    }
+}
+
+fn content_hash(value: &Value) -> Option<u64> {
+    let mut hasher = StableHasher::new();
+    reflect_hash_with(value, &mut hasher, NanHandling::Canonicalize).ok()?;
+    Some(core::hash::Hasher::finish(&hasher))
+}
+
+fn encoded_size(value: &Value) -> usize {
+    Writable::<LittleEndian>::bytes_needed(value).unwrap_or(0)
+}
+
+/// Compare two dumps, returning the differences between entries with the same name and the
+/// entries only in one of them.
+///
+/// Entries with equal content hashes are assumed to be unchanged. Other entries of the same type
+/// are compared with [`diff::diff`], and the changes are reported per key path, along with the
+/// encoded size of the value at that path in each dump.
+///
+/// [`diff::diff`]: crate::diff::diff
+pub fn diff(old: &Dump, new: &Dump) -> DumpDiff {
+    let names = old
+        .entries
+        .keys()
+        .chain(new.entries.keys())
+        .collect::<BTreeSet<_>>();
+
+    let entries = names
+        .into_iter()
+        .filter_map(|name| {
+            let name = name.clone();
+            match (old.get(&name), new.get(&name)) {
+                (Some(old), Some(new)) => diff_entry(name, old, new),
+                (Some(old), None) => Some(EntryDiff::Removed {
+                    name,
+                    size: old.size(),
+                }),
+                (None, Some(new)) => Some(EntryDiff::Added {
+                    name,
+                    size: new.size(),
+                }),
+                (None, None) => unreachable!("name comes from one of the dumps"),
+            }
+        })
+        .collect();
+
+    DumpDiff { entries }
+}
+
+fn diff_entry(name: String, old: &DumpEntry, new: &DumpEntry) -> Option<EntryDiff> {
+    if old.type_hash != new.type_hash {
+        return Some(EntryDiff::TypeChanged {
+            name,
+            old_type_hash: old.type_hash,
+            new_type_hash: new.type_hash,
+            old_size: old.size(),
+            new_size: new.size(),
+        });
+    }
+
+    if old.content_hash.is_some() && old.content_hash == new.content_hash {
+        return None;
+    }
+
+    let changes = crate::diff::diff(&old.value, &new.value);
+    if changes.is_empty() {
+        return None;
+    }
+
+    let mut paths = Vec::<PathDelta>::new();
+    for change in changes.iter() {
+        let path = change.path();
+        if paths.iter().any(|delta| delta.path == *path) {
+            continue;
+        }
+        paths.push(PathDelta {
+            path: path.clone(),
+            old: old.value.at(path).map(Reflect::to_value),
+            new: new.value.at(path).map(Reflect::to_value),
+        });
+    }
+
+    Some(EntryDiff::Changed {
+        name,
+        old_size: old.size(),
+        new_size: new.size(),
+        paths,
+    })
+}
+
+/// The differences between two [`Dump`]s, returned by [`diff`].
+///
+/// The `Display` impl prints a report with a line per entry and changed key path.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DumpDiff {
+    entries: Vec<EntryDiff>,
+}
+
+impl DumpDiff {
+    /// The entries that differ, ordered by name.
+    pub fn entries(&self) -> &[EntryDiff] {
+        &self.entries
+    }
+
+    /// Iterate over the entries that differ, ordered by name.
+    pub fn iter(&self) -> core::slice::Iter<'_, EntryDiff> {
+        self.entries.iter()
+    }
+
+    /// The number of entries that differ.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the dumps are the same.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl<'a> IntoIterator for &'a DumpDiff {
+    type Item = &'a EntryDiff;
+    type IntoIter = core::slice::Iter<'a, EntryDiff>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl fmt::Display for DumpDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for entry in &self.entries {
+            match entry {
+                EntryDiff::Added { name, size } => writeln!(f, "+ {name} ({size} bytes)")?,
+                EntryDiff::Removed { name, size } => writeln!(f, "- {name} ({size} bytes)")?,
+                EntryDiff::TypeChanged {
+                    name,
+                    old_type_hash,
+                    new_type_hash,
+                    old_size,
+                    new_size,
+                } => writeln!(
+                    f,
+                    "~ {name}: type changed ({old_type_hash:#x} -> {new_type_hash:#x}), {}",
+                    SizeDelta(Some(*old_size), Some(*new_size)),
+                )?,
+                EntryDiff::Changed {
+                    name,
+                    old_size,
+                    new_size,
+                    paths,
+                } => {
+                    writeln!(
+                        f,
+                        "~ {name}: {}",
+                        SizeDelta(Some(*old_size), Some(*new_size))
+                    )?;
+                    for delta in paths {
+                        writeln!(
+                            f,
+                            "    {name}{}: {}",
+                            delta.path,
+                            SizeDelta(delta.old_size(), delta.new_size())
+                        )?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+struct SizeDelta(Option<usize>, Option<usize>);
+
+impl fmt::Display for SizeDelta {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let size = |size: Option<usize>| size.map(|size| size as i128);
+        match (size(self.0), size(self.1)) {
+            (Some(old), Some(new)) => write!(f, "{old} -> {new} bytes ({:+})", new - old),
+            (None, Some(new)) => write!(f, "added, {new} bytes"),
+            (Some(old), None) => write!(f, "removed, {old} bytes"),
+            (None, None) => write!(f, "changed"),
+        }
+    }
+}
+
+/// A named entry that differs between two [`Dump`]s.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum EntryDiff {
+    /// The entry is only in the new dump.
+    Added { name: String, size: usize },
+    /// The entry is only in the old dump.
+    Removed { name: String, size: usize },
+    /// The entry's type changed, so its values aren't compared.
+    TypeChanged {
+        name: String,
+        old_type_hash: u64,
+        new_type_hash: u64,
+        old_size: usize,
+        new_size: usize,
+    },
+    /// The entry's value changed.
+    Changed {
+        name: String,
+        old_size: usize,
+        new_size: usize,
+        paths: Vec<PathDelta>,
+    },
+}
+
+impl EntryDiff {
+    /// The name of the entry.
+    pub fn name(&self) -> &str {
+        match self {
+            EntryDiff::Added { name, .. }
+            | EntryDiff::Removed { name, .. }
+            | EntryDiff::TypeChanged { name, .. }
+            | EntryDiff::Changed { name, .. } => name,
+        }
+    }
+
+    /// How many more bytes the entry takes up in the new dump.
+    pub fn size_delta(&self) -> i128 {
+        match self {
+            EntryDiff::Added { size, .. } => *size as i128,
+            EntryDiff::Removed { size, .. } => -(*size as i128),
+            EntryDiff::TypeChanged {
+                old_size, new_size, ..
+            }
+            | EntryDiff::Changed {
+                old_size, new_size, ..
+            } => *new_size as i128 - *old_size as i128,
+        }
+    }
+}
+
+/// A key path whose value changed between two dumps.
+///
+/// For lists and maps that had elements added or removed, the path is that of the whole list or
+/// map.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PathDelta {
+    path: KeyPath,
+    old: Option<Value>,
+    new: Option<Value>,
+}
+
+impl PathDelta {
+    /// The key path into the entry's value.
+    pub fn path(&self) -> &KeyPath {
+        &self.path
+    }
+
+    /// The value at the path in the old dump, if the path exists there.
+    pub fn old_value(&self) -> Option<&Value> {
+        self.old.as_ref()
+    }
+
+    /// The value at the path in the new dump, if the path exists there.
+    pub fn new_value(&self) -> Option<&Value> {
+        self.new.as_ref()
+    }
+
+    /// The encoded size of the value at the path in the old dump.
+    pub fn old_size(&self) -> Option<usize> {
+        self.old.as_ref().map(encoded_size)
+    }
+
+    /// The encoded size of the value at the path in the new dump.
+    pub fn new_size(&self) -> Option<usize> {
+        self.new.as_ref().map(encoded_size)
+    }
+}
+
+/// Errors that can happen when reading or writing [`Dump`]s.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum DumpError {
+    /// The data doesn't start with a dump header.
+    MissingHeader,
+    /// The dump was written in another [format version](Dump::FORMAT_VERSION).
+    UnsupportedVersion(u32),
+    /// The entries couldn't be encoded or decoded.
+    Speedy(speedy::Error),
+    /// The file couldn't be read or written.
+    Io(std::io::Error),
+}
+
+impl fmt::Display for DumpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DumpError::MissingHeader => write!(f, "data doesn't start with a dump header"),
+            DumpError::UnsupportedVersion(version) => write!(
+                f,
+                "unsupported dump format version {version}, expected {}",
+                Dump::FORMAT_VERSION
+            ),
+            DumpError::Speedy(err) => write!(f, "{err}"),
+            DumpError::Io(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for DumpError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DumpError::Speedy(err) => Some(err),
+            DumpError::Io(err) => Some(err),
+            DumpError::MissingHeader | DumpError::UnsupportedVersion(_) => None,
+        }
+    }
+}
+
+impl From<speedy::Error> for DumpError {
+    fn from(err: speedy::Error) -> Self {
+        Self::Speedy(err)
+    }
+}
+
+impl From<std::io::Error> for DumpError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
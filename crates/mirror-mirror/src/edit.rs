@@ -0,0 +1,279 @@
+use alloc::vec::Vec;
+
+use crate::diff;
+use crate::error;
+use crate::key_path::GetPath;
+use crate::key_path::KeyPath;
+use crate::Reflect;
+use crate::ReflectError;
+use crate::Value;
+
+/// A change to the value at some path, which remembers what it replaced so it can be undone.
+///
+/// # Example
+///
+/// ```
+/// use mirror_mirror::{key_path, Reflect};
+/// use mirror_mirror::edit::EditCommand;
+///
+/// #[derive(Reflect, Clone, Debug)]
+/// struct Player {
+///     name: String,
+///     inventory: Vec<String>,
+/// }
+///
+/// let mut player = Player {
+///     name: "alice".to_owned(),
+///     inventory: Vec::new(),
+/// };
+///
+/// let command = EditCommand::new(
+///     &player,
+///     key_path!(.inventory),
+///     Vec::from(["sword".to_owned()]),
+/// )
+/// .unwrap();
+///
+/// command.apply(&mut player).unwrap();
+/// assert_eq!(player.inventory, ["sword"]);
+///
+/// command.revert(&mut player).unwrap();
+/// assert!(player.inventory.is_empty());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EditCommand {
+    /// The path of the value that is changed.
+    pub path: KeyPath,
+    /// The value before the change, restored by [`revert`](Self::revert).
+    pub old: Value,
+    /// The value after the change, set by [`apply`](Self::apply).
+    pub new: Value,
+}
+
+impl EditCommand {
+    /// Create a command that replaces the value at `path` in `target` with `new`.
+    ///
+    /// The current value is remembered as the old value, and `target` isn't changed. Fails if
+    /// there is no value at `path`.
+    pub fn new(
+        target: &dyn Reflect,
+        path: KeyPath,
+        new: impl Into<Value>,
+    ) -> Result<Self, ReflectError> {
+        let old = target.try_at(&path)?.to_value();
+        Ok(Self {
+            path,
+            old,
+            new: new.into(),
+        })
+    }
+
+    /// Replace the value at the path with the new value.
+    ///
+    /// Lists and maps are replaced entirely, not just patched, so elements and keys not in the
+    /// new value are removed. Fails, without changing anything, if there is no value at the path
+    /// or the new value doesn't have its type.
+    pub fn apply(&self, target: &mut dyn Reflect) -> Result<(), ReflectError> {
+        replace(target, &self.path, &self.new)
+    }
+
+    /// Replace the value at the path with the old value, undoing [`apply`](Self::apply).
+    pub fn revert(&self, target: &mut dyn Reflect) -> Result<(), ReflectError> {
+        replace(target, &self.path, &self.old)
+    }
+
+    /// The command that undoes this one.
+    pub fn inverse(&self) -> Self {
+        Self {
+            path: self.path.clone(),
+            old: self.new.clone(),
+            new: self.old.clone(),
+        }
+    }
+}
+
+fn replace(target: &mut dyn Reflect, path: &KeyPath, value: &Value) -> Result<(), ReflectError> {
+    let target = if path.is_empty() {
+        target
+    } else {
+        target.try_at_mut(path)?
+    };
+    if let Some(error) = error::find_mismatch(target.type_descriptor().get_type(), value) {
+        return Err(error.under(path));
+    }
+    let changes = diff::diff(target, value);
+    diff::apply(target, &changes);
+    Ok(())
+}
+
+/// A list of [`EditCommand`]s that are applied and reverted together, such as one step of undo
+/// in an editor.
+///
+/// # Example
+///
+/// ```
+/// use mirror_mirror::{key_path, Reflect};
+/// use mirror_mirror::edit::Transaction;
+///
+/// #[derive(Reflect, Clone, Debug)]
+/// struct Light {
+///     color: [f32; 3],
+///     intensity: f32,
+/// }
+///
+/// let mut light = Light { color: [1.0, 1.0, 1.0], intensity: 1.0 };
+///
+/// // `record` applies each edit as it's made
+/// let mut transaction = Transaction::new();
+/// transaction.record(&mut light, key_path!(.color[0_usize]), 0.5_f32).unwrap();
+/// transaction.record(&mut light, key_path!(.intensity), 2.0_f32).unwrap();
+/// assert_eq!(light.color, [0.5, 1.0, 1.0]);
+/// assert_eq!(light.intensity, 2.0);
+///
+/// // undo
+/// transaction.revert(&mut light).unwrap();
+/// assert_eq!(light.color, [1.0, 1.0, 1.0]);
+/// assert_eq!(light.intensity, 1.0);
+///
+/// // redo
+/// transaction.apply(&mut light).unwrap();
+/// assert_eq!(light.intensity, 2.0);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Transaction {
+    commands: Vec<EditCommand>,
+}
+
+impl Transaction {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a command to the end of the transaction, without applying it.
+    pub fn push(&mut self, command: EditCommand) {
+        self.commands.push(command);
+    }
+
+    /// Replace the value at `path` in `target` with `new`, and add the command that did it.
+    ///
+    /// Nothing is added if the command fails.
+    pub fn record(
+        &mut self,
+        target: &mut dyn Reflect,
+        path: KeyPath,
+        new: impl Into<Value>,
+    ) -> Result<(), ReflectError> {
+        let command = EditCommand::new(target, path, new)?;
+        command.apply(target)?;
+        self.commands.push(command);
+        Ok(())
+    }
+
+    /// Apply each command in order.
+    ///
+    /// If a command fails the ones before it are reverted, so either all of the transaction is
+    /// applied or none of it.
+    pub fn apply(&self, target: &mut dyn Reflect) -> Result<(), ReflectError> {
+        for (index, command) in self.commands.iter().enumerate() {
+            if let Err(error) = command.apply(target) {
+                for command in self.commands[..index].iter().rev() {
+                    let _ = command.revert(target);
+                }
+                return Err(error);
+            }
+        }
+        Ok(())
+    }
+
+    /// Revert each command in reverse order, undoing [`apply`](Self::apply).
+    ///
+    /// If a command fails the ones reverted before it are applied again, so either all of the
+    /// transaction is reverted or none of it.
+    pub fn revert(&self, target: &mut dyn Reflect) -> Result<(), ReflectError> {
+        for (index, command) in self.commands.iter().enumerate().rev() {
+            if let Err(error) = command.revert(target) {
+                for command in &self.commands[index + 1..] {
+                    let _ = command.apply(target);
+                }
+                return Err(error);
+            }
+        }
+        Ok(())
+    }
+
+    /// The transaction that undoes this one.
+    pub fn inverse(&self) -> Self {
+        self.commands
+            .iter()
+            .rev()
+            .map(EditCommand::inverse)
+            .collect()
+    }
+
+    /// The commands in the order they are applied.
+    pub fn commands(&self) -> &[EditCommand] {
+        &self.commands
+    }
+
+    pub fn iter(&self) -> core::slice::Iter<'_, EditCommand> {
+        self.commands.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.commands.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+}
+
+impl From<EditCommand> for Transaction {
+    fn from(command: EditCommand) -> Self {
+        Self {
+            commands: Vec::from([command]),
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a Transaction {
+    type Item = &'a EditCommand;
+    type IntoIter = core::slice::Iter<'a, EditCommand>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for Transaction {
+    type Item = EditCommand;
+    type IntoIter = alloc::vec::IntoIter<EditCommand>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.commands.into_iter()
+    }
+}
+
+impl FromIterator<EditCommand> for Transaction {
+    fn from_iter<T>(iter: T) -> Self
+    where
+        T: IntoIterator<Item = EditCommand>,
+    {
+        Self {
+            commands: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl Extend<EditCommand> for Transaction {
+    fn extend<T>(&mut self, iter: T)
+    where
+        T: IntoIterator<Item = EditCommand>,
+    {
+        self.commands.extend(iter);
+    }
+}
@@ -1,8 +1,13 @@
+use alloc::borrow::Cow;
 use alloc::borrow::ToOwned;
 use alloc::boxed::Box;
 use alloc::string::String;
+use alloc::vec::Vec;
 use core::any::Any;
+use core::cmp::Ordering;
 use core::fmt;
+use core::hash::Hash;
+use core::hash::Hasher;
 
 use crate::iter::PairIterMut;
 use crate::iter::ValueIterMut;
@@ -11,6 +16,10 @@ use crate::tuple::TupleValue;
 use crate::type_info::graph::NodeId;
 use crate::type_info::graph::OpaqueNode;
 use crate::type_info::graph::TypeGraph;
+use crate::type_info::value_builder::validate_shape;
+use crate::type_info::ShapeError;
+use crate::type_info::TypeDescriptor;
+use crate::value::Origin;
 use crate::DescribeType;
 use crate::FromReflect;
 use crate::Reflect;
@@ -46,6 +55,60 @@ pub trait Enum: Reflect {
     fn variants_len(&self) -> usize;
 
     fn fields_len(&self) -> usize;
+
+    /// The index of the current variant, in declaration order.
+    ///
+    /// Returns `None` if the type info doesn't describe an enum, as is the case for
+    /// [`EnumValue`]s, which don't know the type they were created from.
+    fn variant_index(&self) -> Option<usize> {
+        let descriptor = self.type_descriptor();
+        descriptor.as_enum()?.variant_index(self.variant_name())
+    }
+
+    /// A value of the variant at `index`, with every field set to its default value.
+    ///
+    /// Returns `None` if there is no variant at `index`, if some field has no default value, or if
+    /// the type info doesn't describe an enum.
+    fn variant_at(&self, index: usize) -> Option<Value> {
+        let descriptor = self.type_descriptor();
+        descriptor.as_enum()?.variant_at(index)?.default_value()
+    }
+
+    /// Switch to the variant called `name`, with every field set to its default value.
+    ///
+    /// Does nothing if `name` is already the current variant, so its fields are kept.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mirror_mirror::{Enum, Reflect};
+    ///
+    /// let mut value = Some(1337);
+    /// value.as_enum_mut().unwrap().set_variant("None").unwrap();
+    /// assert_eq!(value, None);
+    ///
+    /// value.as_enum_mut().unwrap().set_variant("Some").unwrap();
+    /// assert_eq!(value, Some(0));
+    /// ```
+    fn set_variant(&mut self, name: &str) -> Result<(), SetVariantError> {
+        if self.variant_name() == name {
+            return Ok(());
+        }
+        let descriptor = self.type_descriptor();
+        let enum_type = descriptor.as_enum().ok_or(SetVariantError::NotAnEnum)?;
+        let variant = enum_type
+            .variant(name)
+            .ok_or_else(|| SetVariantError::UnknownVariant(name.to_owned()))?;
+        let value = variant
+            .default_value()
+            .ok_or_else(|| SetVariantError::NoDefaultValue(name.to_owned()))?;
+        self.patch(&value);
+        if self.variant_name() == name {
+            Ok(())
+        } else {
+            Err(SetVariantError::NoDefaultValue(name.to_owned()))
+        }
+    }
 }
 
 impl fmt::Debug for dyn Enum {
@@ -54,6 +117,34 @@ impl fmt::Debug for dyn Enum {
     }
 }
 
+/// Error returned by [`Enum::set_variant`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SetVariantError {
+    /// The type info of the value doesn't describe an enum, such as for [`EnumValue`]s.
+    NotAnEnum,
+    /// The enum has no variant with this name.
+    UnknownVariant(String),
+    /// Some field of the variant has no default value, or the default value couldn't be
+    /// converted into the enum.
+    NoDefaultValue(String),
+}
+
+impl fmt::Display for SetVariantError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotAnEnum => write!(f, "type info doesn't describe an enum"),
+            Self::UnknownVariant(name) => write!(f, "unknown variant `{name}`"),
+            Self::NoDefaultValue(name) => {
+                write!(f, "variant `{name}` has no default value")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SetVariantError {}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum VariantKind {
     Struct,
@@ -61,12 +152,56 @@ pub enum VariantKind {
     Unit,
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[derive(Debug, Clone)]
 #[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EnumValue {
     name: String,
     kind: EnumValueKind,
+    // not encoded, so values of enums with an integer `#[repr(...)]` decode and compare like
+    // those of other enums. Only used for ordering when both values have one
+    #[cfg_attr(feature = "speedy", speedy(skip))]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    discriminant: Option<i128>,
+    #[cfg_attr(feature = "speedy", speedy(skip))]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) origin: Origin,
+}
+
+// values of enums with an integer `#[repr(...)]` are ordered by discriminant, like
+// `#[derive(Ord)]` does, rather than by name. Values without one, such as those built by hand or
+// decoded, fall back to comparing names so they still equal the values they were made from
+impl PartialEq for EnumValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for EnumValue {}
+
+impl PartialOrd for EnumValue {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for EnumValue {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let discriminants = match (self.discriminant, other.discriminant) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            _ => Ordering::Equal,
+        };
+        discriminants
+            .then_with(|| self.name.cmp(&other.name))
+            .then_with(|| self.kind.cmp(&other.kind))
+    }
+}
+
+impl Hash for EnumValue {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.kind.hash(state);
+    }
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
@@ -89,8 +224,10 @@ impl EnumValue {
     ) -> StructVariantBuilder {
         StructVariantBuilder {
             inner: Self {
+                discriminant: None,
                 name: name.into(),
                 kind: EnumValueKind::Struct(StructValue::with_capacity(capacity)),
+                origin: Default::default(),
             },
         }
     }
@@ -105,17 +242,89 @@ impl EnumValue {
     ) -> TupleVariantBuilder {
         TupleVariantBuilder {
             inner: Self {
+                discriminant: None,
                 name: name.into(),
                 kind: EnumValueKind::Tuple(TupleValue::with_capacity(capacity)),
+                origin: Default::default(),
             },
         }
     }
 
     pub fn new_unit_variant(name: impl Into<String>) -> Self {
         Self {
+            discriminant: None,
             name: name.into(),
             kind: EnumValueKind::Unit,
+            origin: Default::default(),
+        }
+    }
+
+    /// Set the name of the type this value was created from.
+    ///
+    /// Returned by [`Reflect::type_name`] instead of the name of this type. Set by
+    /// `#[derive(Reflect)]`.
+    pub fn with_type_name(mut self, type_name: impl Into<Cow<'static, str>>) -> Self {
+        self.set_type_name(type_name);
+        self
+    }
+
+    pub fn set_type_name(&mut self, type_name: impl Into<Cow<'static, str>>) {
+        self.origin.set_type_name(type_name);
+    }
+
+    /// Set the type this value was created from, whose stable hash is returned by
+    /// [`type_hash`](Self::type_hash). Also sets the type name to `T`'s, unless one is set
+    /// already. Set by `#[derive(Reflect)]`.
+    pub fn with_origin<T>(mut self) -> Self
+    where
+        T: DescribeType,
+    {
+        self.origin.set_type::<T>();
+        self
+    }
+
+    /// The stable hash of the type this value was created from, if known.
+    ///
+    /// This is the hash that type descriptors use to identify the type, so it can be used to look
+    /// the type up in a registry.
+    pub fn type_hash(&self) -> Option<u64> {
+        self.origin.type_hash()
+    }
+
+    /// Set the discriminant of the variant.
+    ///
+    /// Set by `#[derive(Reflect)]` for enums with an integer `#[repr(...)]`. Values with
+    /// discriminants are ordered by them, so maps keyed by such enums keep the same order as
+    /// their typed counterparts.
+    pub fn with_discriminant(mut self, discriminant: i128) -> Self {
+        self.set_discriminant(Some(discriminant));
+        self
+    }
+
+    pub fn set_discriminant(&mut self, discriminant: Option<i128>) {
+        self.discriminant = discriminant;
+    }
+
+    pub fn discriminant(&self) -> Option<i128> {
+        self.discriminant
+    }
+
+    /// Check that the value has the shape of the enum described by `descriptor`.
+    ///
+    /// Finds unknown variants, unknown, duplicate, or missing fields, and fields of the wrong
+    /// type, including in nested values. See [`StructValue::validate_against`].
+    pub fn validate_against(&self, descriptor: &TypeDescriptor) -> Result<(), Vec<ShapeError>> {
+        validate_shape(descriptor.get_type(), self)
+    }
+
+    pub(crate) fn compact(&mut self) {
+        self.name.shrink_to_fit();
+        match &mut self.kind {
+            EnumValueKind::Struct(inner) => inner.compact(),
+            EnumValueKind::Tuple(inner) => inner.compact(),
+            EnumValueKind::Unit => {}
         }
+        self.origin.compact();
     }
 
     #[track_caller]
@@ -170,6 +379,11 @@ impl StructVariantBuilder {
         self.inner.set_struct_field(name, value);
     }
 
+    pub fn with_type_name(mut self, type_name: impl Into<Cow<'static, str>>) -> Self {
+        self.inner.set_type_name(type_name);
+        self
+    }
+
     pub fn finish(self) -> EnumValue {
         self.inner
     }
@@ -190,6 +404,11 @@ impl TupleVariantBuilder {
         self.inner.push_tuple_field(value);
     }
 
+    pub fn with_type_name(mut self, type_name: impl Into<Cow<'static, str>>) -> Self {
+        self.inner.set_type_name(type_name);
+        self
+    }
+
     pub fn finish(self) -> EnumValue {
         self.inner
     }
@@ -206,6 +425,12 @@ impl DescribeType for EnumValue {
 impl Reflect for EnumValue {
     trivial_reflect_methods!();
 
+    fn type_name(&self) -> &str {
+        self.origin
+            .type_name()
+            .unwrap_or_else(|| core::any::type_name::<Self>())
+    }
+
     fn patch(&mut self, value: &dyn Reflect) {
         if let Some(enum_) = value.reflect_ref().as_enum() {
             if self.variant_name() == enum_.variant_name() {
@@ -347,38 +572,51 @@ impl FromReflect for EnumValue {
 
         let kind = match enum_.variant_kind() {
             VariantKind::Struct => {
-                let struct_ = enum_
-                    .fields()
-                    .fold(StructValue::default(), |builder, field| match field {
+                let mut struct_ = StructValue::default();
+                for field in enum_.fields() {
+                    match field {
                         VariantField::Struct(name, value) => {
-                            builder.with_field(name, value.to_value())
+                            struct_.set_field(name, value.to_value());
                         }
-                        VariantField::Tuple(_) => {
-                            panic!("iterator over fields in struct variant yielded a tuple field")
-                        }
-                    });
+                        VariantField::Tuple(_) => panic_or!(
+                            return None,
+                            "iterator over fields in struct variant yielded a tuple field"
+                        ),
+                    }
+                }
                 EnumValueKind::Struct(struct_)
             }
             VariantKind::Tuple => {
-                let tuple =
-                    enum_
-                        .fields()
-                        .fold(TupleValue::default(), |builder, field| match field {
-                            VariantField::Struct(_, _) => {
-                                panic!(
-                                    "iterator over fields in tuple variant yielded a struct field"
-                                )
-                            }
-                            VariantField::Tuple(value) => builder.with_field(value.to_value()),
-                        });
+                let mut tuple = TupleValue::default();
+                for field in enum_.fields() {
+                    match field {
+                        VariantField::Struct(_, _) => panic_or!(
+                            return None,
+                            "iterator over fields in tuple variant yielded a struct field"
+                        ),
+                        VariantField::Tuple(value) => tuple.push_field(value.to_value()),
+                    }
+                }
                 EnumValueKind::Tuple(tuple)
             }
             VariantKind::Unit => EnumValueKind::Unit,
         };
 
+        let discriminant = match reflect.downcast_ref::<EnumValue>() {
+            Some(value) => value.discriminant,
+            None => reflect
+                .type_descriptor()
+                .get_type()
+                .as_enum()
+                .and_then(|ty| ty.variant(enum_.variant_name()))
+                .and_then(|variant| variant.discriminant()),
+        };
+
         Some(EnumValue {
+            discriminant,
             name: enum_.variant_name().to_owned(),
             kind,
+            origin: Origin::of::<Self>(reflect),
         })
     }
 }
@@ -0,0 +1,344 @@
+use alloc::format;
+use alloc::string::String;
+use core::fmt;
+
+use crate::enum_::VariantField;
+use crate::key_path::KeyPath;
+use crate::type_info::Type;
+use crate::DescribeType;
+use crate::Reflect;
+use crate::ReflectRef;
+
+/// Error returned by the fallible `try_*` versions of reflection conversions and lookups, such as
+/// [`FromReflect::try_from_reflect`] and [`GetPath::try_at`].
+///
+/// Describes what type was expected, what was found instead, and where in the value that
+/// happened. Values of the right type that are rejected by a validator, see
+/// [`Reflect::validate_self`], also have a [`reason`](Self::reason).
+///
+/// [`FromReflect::try_from_reflect`]: crate::FromReflect::try_from_reflect
+/// [`GetPath::try_at`]: crate::key_path::GetPath::try_at
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ReflectError {
+    expected: String,
+    found: Option<String>,
+    reason: Option<String>,
+    path: KeyPath,
+}
+
+impl ReflectError {
+    pub(crate) fn new(expected: impl Into<String>, found: Option<&dyn Reflect>) -> Self {
+        Self {
+            expected: expected.into(),
+            found: found.map(|found| found.type_name().into()),
+            reason: None,
+            path: KeyPath::default(),
+        }
+    }
+
+    pub(crate) fn from_parts(expected: String, found: Option<String>) -> Self {
+        Self {
+            expected,
+            found,
+            reason: None,
+            path: KeyPath::default(),
+        }
+    }
+
+    /// `value` has the right type but was rejected by a validator.
+    pub(crate) fn invalid(value: &dyn Reflect, reason: String) -> Self {
+        Self {
+            reason: Some(reason),
+            ..Self::new(value.type_name(), Some(value))
+        }
+    }
+
+    pub(crate) fn at(mut self, path: KeyPath) -> Self {
+        self.path = path;
+        self
+    }
+
+    /// Prepend `prefix` to the path of the error.
+    pub(crate) fn under(mut self, prefix: &KeyPath) -> Self {
+        let mut path = prefix.clone();
+        path.extend(self.path);
+        self.path = path;
+        self
+    }
+
+    /// The name of the type that was expected.
+    ///
+    /// If a key path couldn't be followed and there is no type information for the key, this is
+    /// instead a description of the key that was expected.
+    pub fn expected(&self) -> &str {
+        &self.expected
+    }
+
+    /// The name of the type that was found instead, or `None` if nothing was found, for example
+    /// because a field was missing.
+    pub fn found(&self) -> Option<&str> {
+        self.found.as_deref()
+    }
+
+    /// Why a value of the right type was rejected by a validator, or `None` if the error isn't
+    /// about validation.
+    pub fn reason(&self) -> Option<&str> {
+        self.reason.as_deref()
+    }
+
+    /// The path, relative to the value being converted or looked up, where the error happened.
+    pub fn path(&self) -> &KeyPath {
+        &self.path
+    }
+}
+
+impl fmt::Display for ReflectError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(reason) = &self.reason {
+            write!(f, "invalid `{}`: {reason}", self.expected)?;
+        } else {
+            write!(f, "expected `{}`, ", self.expected)?;
+            match &self.found {
+                Some(found) => write!(f, "found `{found}`")?,
+                None => f.write_str("found nothing")?,
+            }
+        }
+        if !self.path.is_empty() {
+            write!(f, " at `{}`", self.path)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ReflectError {}
+
+/// Explain why `T::from_reflect(reflect)` failed, for [`FromReflect::try_from_reflect`].
+///
+/// [`FromReflect::try_from_reflect`]: crate::FromReflect::try_from_reflect
+pub(crate) fn from_reflect_error<T>(reflect: &dyn Reflect) -> ReflectError
+where
+    T: DescribeType,
+{
+    let descriptor = T::type_descriptor();
+    find_mismatch(descriptor.get_type(), reflect)
+        .unwrap_or_else(|| ReflectError::new(core::any::type_name::<T>(), Some(reflect)))
+}
+
+/// Find the first place where `value` doesn't have the shape of `ty`.
+///
+/// Used to explain why a conversion failed. Opaque types are assumed to match since there is
+/// nothing to compare them against.
+pub(crate) fn find_mismatch(ty: Type<'_>, value: &dyn Reflect) -> Option<ReflectError> {
+    let mut path = KeyPath::default();
+    go(ty, value, &mut path)
+}
+
+fn go(ty: Type<'_>, value: &dyn Reflect, path: &mut KeyPath) -> Option<ReflectError> {
+    macro_rules! mismatch {
+        () => {
+            Some(ReflectError::new(ty.type_name(), Some(value)).at(path.clone()))
+        };
+    }
+
+    macro_rules! field {
+        ($key:expr, $field_ty:expr, $field:expr) => {{
+            path.push_field($key);
+            let error = match $field {
+                Some(field) => go($field_ty, field, path),
+                None => Some(ReflectError::new($field_ty.type_name(), None).at(path.clone())),
+            };
+            if error.is_some() {
+                return error;
+            }
+            path.pop();
+        }};
+    }
+
+    match (ty, value.reflect_ref()) {
+        (Type::Struct(struct_type), ReflectRef::Struct(struct_)) => {
+            for field in struct_type.field_types() {
+                field!(field.name(), field.get_type(), struct_.field(field.name()));
+            }
+        }
+        (Type::TupleStruct(tuple_struct_type), ReflectRef::TupleStruct(tuple_struct)) => {
+            for (index, field) in tuple_struct_type.field_types().enumerate() {
+                field!(index, field.get_type(), tuple_struct.field_at(index));
+            }
+        }
+        (Type::Tuple(tuple_type), ReflectRef::Tuple(tuple)) => {
+            for (index, field) in tuple_type.field_types().enumerate() {
+                field!(index, field.get_type(), tuple.field_at(index));
+            }
+        }
+        (Type::Enum(enum_type), ReflectRef::Enum(enum_)) => {
+            let Some(variant) = enum_type.variant(enum_.variant_name()) else {
+                return mismatch!();
+            };
+            path.push_variant(variant.name());
+            for (index, field) in variant.field_types().enumerate() {
+                match field.name() {
+                    Some(name) => field!(name, field.get_type(), enum_.field(name)),
+                    None => field!(index, field.get_type(), enum_.field_at(index)),
+                }
+            }
+            path.pop();
+        }
+        (Type::Array(array_type), ReflectRef::Array(array)) => {
+            if array.len() != array_type.len() {
+                return mismatch!();
+            }
+            return go_elements(array_type.element_type(), array.iter(), path);
+        }
+        (Type::Array(array_type), ReflectRef::List(list)) => {
+            if list.len() != array_type.len() {
+                return mismatch!();
+            }
+            return go_elements(array_type.element_type(), list.iter(), path);
+        }
+        (Type::List(list_type), ReflectRef::List(list)) => {
+            return go_elements(list_type.element_type(), list.iter(), path);
+        }
+        (Type::Map(map_type), ReflectRef::Map(map)) => {
+            for (key, value) in map.iter() {
+                if let Some(error) = go(map_type.key_type(), key, &mut KeyPath::default()) {
+                    return Some(error.at(path.clone()));
+                }
+                path.push_get(key.to_value());
+                if let Some(error) = go(map_type.value_type(), value, path) {
+                    return Some(error);
+                }
+                path.pop();
+            }
+        }
+        (Type::Set(set_type), ReflectRef::Set(set)) => {
+            for element in set.iter() {
+                if let Some(error) = go(set_type.element_type(), element, &mut KeyPath::default()) {
+                    return Some(error.at(path.clone()));
+                }
+            }
+        }
+        (Type::Scalar(scalar_type), ReflectRef::Scalar(scalar)) => {
+            if scalar.as_reflect().type_name() != scalar_type.type_name() {
+                return mismatch!();
+            }
+        }
+        (Type::Opaque(_), _) => {}
+        _ => return mismatch!(),
+    }
+
+    None
+}
+
+fn go_elements<'a>(
+    ty: Type<'_>,
+    elements: impl Iterator<Item = &'a dyn Reflect>,
+    path: &mut KeyPath,
+) -> Option<ReflectError> {
+    for (index, element) in elements.enumerate() {
+        path.push_get(index);
+        if let Some(error) = go(ty, element, path) {
+            return Some(error);
+        }
+        path.pop();
+    }
+    None
+}
+
+/// Find the first enum variant marked `#[reflect(skip)]` in `value`, for
+/// [`Reflect::try_to_value`].
+pub(crate) fn find_skipped_variant(value: &dyn Reflect) -> Option<ReflectError> {
+    let mut path = KeyPath::default();
+    go_skipped(value, &mut path)
+}
+
+fn go_skipped(value: &dyn Reflect, path: &mut KeyPath) -> Option<ReflectError> {
+    macro_rules! field {
+        ($key:expr, $field:expr) => {{
+            path.push_field($key);
+            let error = go_skipped($field, path);
+            if error.is_some() {
+                return error;
+            }
+            path.pop();
+        }};
+    }
+
+    match value.reflect_ref() {
+        ReflectRef::Struct(struct_) => {
+            for (name, field) in struct_.fields() {
+                field!(name, field);
+            }
+        }
+        ReflectRef::TupleStruct(tuple_struct) => {
+            for (index, field) in tuple_struct.fields().enumerate() {
+                field!(index, field);
+            }
+        }
+        ReflectRef::Tuple(tuple) => {
+            for (index, field) in tuple.fields().enumerate() {
+                field!(index, field);
+            }
+        }
+        ReflectRef::Enum(enum_) => {
+            // skipped variants are left out of the descriptor
+            let descriptor = value.type_descriptor();
+            let skipped = descriptor.as_enum().map_or(false, |enum_type| {
+                enum_type.variant(enum_.variant_name()).is_none()
+            });
+            if skipped {
+                let reason = format!(
+                    "variant `{}` is marked `#[reflect(skip)]` and can't be converted to a `Value`",
+                    enum_.variant_name()
+                );
+                return Some(ReflectError::invalid(value, reason).at(path.clone()));
+            }
+            path.push_variant(enum_.variant_name());
+            for (index, field) in enum_.fields().enumerate() {
+                match field {
+                    VariantField::Struct(name, field) => field!(name, field),
+                    VariantField::Tuple(field) => field!(index, field),
+                }
+            }
+            path.pop();
+        }
+        ReflectRef::Array(array) => return go_skipped_elements(array.iter(), path),
+        ReflectRef::List(list) => return go_skipped_elements(list.iter(), path),
+        ReflectRef::Map(map) => {
+            for (key, value) in map.iter() {
+                if let Some(error) = go_skipped(key, &mut KeyPath::default()) {
+                    return Some(error.at(path.clone()));
+                }
+                path.push_get(key.to_value());
+                if let Some(error) = go_skipped(value, path) {
+                    return Some(error);
+                }
+                path.pop();
+            }
+        }
+        ReflectRef::Set(set) => {
+            for element in set.iter() {
+                if let Some(error) = go_skipped(element, &mut KeyPath::default()) {
+                    return Some(error.at(path.clone()));
+                }
+            }
+        }
+        ReflectRef::Scalar(_) | ReflectRef::Opaque(_) => {}
+    }
+
+    None
+}
+
+fn go_skipped_elements<'a>(
+    elements: impl Iterator<Item = &'a dyn Reflect>,
+    path: &mut KeyPath,
+) -> Option<ReflectError> {
+    for (index, element) in elements.enumerate() {
+        path.push_get(index);
+        if let Some(error) = go_skipped(element, path) {
+            return Some(error);
+        }
+        path.pop();
+    }
+    None
+}
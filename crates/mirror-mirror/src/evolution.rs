@@ -0,0 +1,722 @@
+use alloc::borrow::ToOwned;
+use alloc::collections::BTreeMap;
+use alloc::collections::BTreeSet;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::fmt;
+use core::hash::Hasher;
+
+use crate::enum_::EnumValue;
+use crate::enum_::VariantField;
+use crate::enum_::VariantKind;
+use crate::struct_::StructValue;
+use crate::tuple::TupleValue;
+use crate::tuple_struct::TupleStructValue;
+use crate::type_info::stable_hash::StableHasher;
+use crate::type_info::Type;
+use crate::type_info::Variant;
+use crate::DescribeType;
+use crate::FromReflect;
+use crate::Reflect;
+use crate::ReflectRef;
+use crate::ScalarRef;
+use crate::TypeDescriptor;
+use crate::Value;
+
+/// How deeply values can be nested in data decoded with [`read_value`].
+///
+/// Deeper data is rejected with [`EvolutionError::TooDeep`], rather than overflowing the stack
+/// while decoding untrusted input.
+pub const MAX_DEPTH: usize = 128;
+
+/// The version of the encoding, written as the first byte.
+///
+/// Bumped whenever the encoding changes. Data written with another version is rejected when read.
+pub const FORMAT_VERSION: u8 = 1;
+
+mod tag {
+    pub(super) const USIZE: u8 = 0;
+    pub(super) const U8: u8 = 1;
+    pub(super) const U16: u8 = 2;
+    pub(super) const U32: u8 = 3;
+    pub(super) const U64: u8 = 4;
+    pub(super) const U128: u8 = 5;
+    pub(super) const I8: u8 = 6;
+    pub(super) const I16: u8 = 7;
+    pub(super) const I32: u8 = 8;
+    pub(super) const I64: u8 = 9;
+    pub(super) const I128: u8 = 10;
+    pub(super) const BOOL: u8 = 11;
+    pub(super) const CHAR: u8 = 12;
+    pub(super) const F32: u8 = 13;
+    pub(super) const F64: u8 = 14;
+    pub(super) const STRING: u8 = 15;
+    pub(super) const STRUCT: u8 = 16;
+    pub(super) const TUPLE_STRUCT: u8 = 17;
+    pub(super) const TUPLE: u8 = 18;
+    pub(super) const ENUM: u8 = 19;
+    pub(super) const LIST: u8 = 20;
+    pub(super) const MAP: u8 = 21;
+    pub(super) const SET: u8 = 22;
+}
+
+mod variant_tag {
+    pub(super) const STRUCT: u8 = 0;
+    pub(super) const TUPLE: u8 = 1;
+    pub(super) const UNIT: u8 = 2;
+}
+
+/// Encode a value in a format that tolerates changes to its type.
+///
+/// Every value is tagged with its kind. Struct fields and variants are identified by stable
+/// hashes of their names rather than by position, and every field is prefixed with its length so
+/// readers can skip fields they don't know about. This makes the data larger than `speedy`'s
+/// encoding of [`Value`], but data written by older versions of a type can still be read after
+/// fields are added, removed, or reordered.
+///
+/// Opaque values are encoded as their [`Value`].
+///
+/// Use [`read_value`] to decode the data, or [`Evolving`] to embed values in other `speedy`
+/// types.
+pub fn write_value(value: &dyn Reflect) -> Vec<u8> {
+    let mut bytes = Vec::from([FORMAT_VERSION]);
+    encode(value, &mut bytes);
+    bytes
+}
+
+/// Decode a value written with [`write_value`], in the shape of `descriptor`.
+///
+/// The descriptor is needed to turn the hashes of field and variant names back into names.
+/// Fields in the data that the type doesn't have are skipped, and fields the data doesn't have
+/// are filled in with the field type's [default value]. The result can then be converted with
+/// [`FromReflect`].
+///
+/// # Example
+///
+/// ```
+/// use mirror_mirror::{evolution, DescribeType, FromReflect, Reflect};
+///
+/// #[derive(Reflect, Clone, Debug)]
+/// struct PlayerV1 {
+///     name: String,
+///     legacy: bool,
+/// }
+///
+/// #[derive(Reflect, Clone, Debug, PartialEq)]
+/// struct Player {
+///     name: String,
+///     score: u32,
+/// }
+///
+/// let bytes = evolution::write_value(&PlayerV1 {
+///     name: "Alice".to_owned(),
+///     legacy: true,
+/// });
+///
+/// let value = evolution::read_value(&bytes, &<Player as DescribeType>::type_descriptor())
+///     .unwrap();
+///
+/// assert_eq!(
+///     Player::from_reflect(&value).unwrap(),
+///     Player { name: "Alice".to_owned(), score: 0 },
+/// );
+/// ```
+///
+/// Values nested more than [`MAX_DEPTH`] levels deep are rejected.
+///
+/// [default value]: TypeDescriptor::default_value
+pub fn read_value(bytes: &[u8], descriptor: &TypeDescriptor) -> Result<Value, EvolutionError> {
+    let mut decoder = Decoder { bytes, depth: 0 };
+    let version = decoder.u8()?;
+    if version != FORMAT_VERSION {
+        return Err(EvolutionError::UnsupportedVersion(version));
+    }
+    let value = decoder.value(Some(descriptor.get_type()))?;
+    if !decoder.bytes.is_empty() {
+        return Err(EvolutionError::TrailingBytes);
+    }
+    Ok(value)
+}
+
+/// A wrapper that encodes a value with [`write_value`] when written with `speedy`.
+///
+/// Unlike `speedy`'s own encoding, data written by older versions of `T` can still be read
+/// after fields are added, removed, or reordered. The encoded value is prefixed with its length,
+/// so `Evolving` fields of other `speedy` types can be skipped as well.
+///
+/// # Example
+///
+/// ```
+/// use mirror_mirror::{evolution::Evolving, Reflect};
+/// use speedy::{Readable, Writable};
+///
+/// #[derive(Reflect, Clone, Debug)]
+/// struct SettingsV1 {
+///     volume: f32,
+/// }
+///
+/// #[derive(Reflect, Clone, Debug, PartialEq)]
+/// struct Settings {
+///     volume: f32,
+///     fullscreen: bool,
+/// }
+///
+/// let bytes = Evolving(SettingsV1 { volume: 0.5 }).write_to_vec().unwrap();
+/// let Evolving(settings) = Evolving::<Settings>::read_from_buffer(&bytes).unwrap();
+/// assert_eq!(settings, Settings { volume: 0.5, fullscreen: false });
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Evolving<T>(pub T);
+
+impl<C, T> speedy::Writable<C> for Evolving<T>
+where
+    C: speedy::Context,
+    T: Reflect,
+{
+    fn write_to<W: ?Sized + speedy::Writer<C>>(&self, writer: &mut W) -> Result<(), C::Error> {
+        write_value(&self.0).write_to(writer)
+    }
+}
+
+impl<'a, C, T> speedy::Readable<'a, C> for Evolving<T>
+where
+    C: speedy::Context,
+    T: FromReflect + DescribeType,
+{
+    fn read_from<R: speedy::Reader<'a, C>>(reader: &mut R) -> Result<Self, C::Error> {
+        let bytes = Vec::<u8>::read_from(reader)?;
+        let descriptor = <T as DescribeType>::type_descriptor();
+        let value = read_value(&bytes, &descriptor)
+            .map_err(|err| speedy::Error::custom(format_args!("{err}")))?;
+        let value = T::from_reflect(&value).ok_or_else(|| {
+            speedy::Error::custom(format_args!(
+                "`from_reflect` returned `None` for `{}`",
+                descriptor.type_name()
+            ))
+        })?;
+        Ok(Self(value))
+    }
+
+    fn minimum_bytes_needed() -> usize {
+        <Vec<u8> as speedy::Readable<'a, C>>::minimum_bytes_needed()
+    }
+}
+
+/// Errors that can happen when decoding values with [`read_value`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EvolutionError {
+    /// The data was written with another [format version](FORMAT_VERSION).
+    UnsupportedVersion(u8),
+    /// The data ended in the middle of a value.
+    UnexpectedEnd,
+    /// The data continues after the value.
+    TrailingBytes,
+    /// The data contains something other than a value, such as an unknown tag or a string that
+    /// isn't valid UTF-8.
+    Invalid,
+    /// The data contains a struct or enum where the type doesn't, so its field and variant names
+    /// can't be recovered. Holds the type name.
+    Unresolved(String),
+    /// The data contains a variant the enum doesn't have. Holds the type name of the enum.
+    UnknownVariant(String),
+    /// A field is missing from the data and its type doesn't have a default value. Holds the
+    /// field's name, or index for tuple fields.
+    MissingField(String),
+    /// Values are nested more than [`MAX_DEPTH`] levels deep.
+    TooDeep,
+}
+
+impl fmt::Display for EvolutionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedVersion(version) => write!(
+                f,
+                "unsupported format version {version}, expected {FORMAT_VERSION}"
+            ),
+            Self::UnexpectedEnd => write!(f, "unexpected end of data"),
+            Self::TrailingBytes => write!(f, "data continues after the value"),
+            Self::Invalid => write!(f, "invalid data"),
+            Self::Unresolved(type_name) => write!(
+                f,
+                "can't resolve field names of a struct or enum in place of `{type_name}`"
+            ),
+            Self::UnknownVariant(type_name) => write!(f, "unknown variant of `{type_name}`"),
+            Self::MissingField(field) => {
+                write!(f, "missing field `{field}` which has no default value")
+            }
+            Self::TooDeep => write!(f, "values are nested more than {MAX_DEPTH} levels deep"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for EvolutionError {}
+
+fn name_hash(name: &str) -> u64 {
+    let mut hasher = StableHasher::new();
+    hasher.write(name.as_bytes());
+    hasher.finish()
+}
+
+fn encode(value: &dyn Reflect, out: &mut Vec<u8>) {
+    match value.reflect_ref() {
+        ReflectRef::Scalar(scalar) => encode_scalar(scalar, out),
+        ReflectRef::Struct(struct_) => {
+            out.push(tag::STRUCT);
+            write_len(struct_.fields_len(), out);
+            for (name, field) in struct_.fields() {
+                out.extend(name_hash(name).to_le_bytes());
+                encode_field(field, out);
+            }
+        }
+        ReflectRef::TupleStruct(tuple_struct) => {
+            out.push(tag::TUPLE_STRUCT);
+            write_len(tuple_struct.fields_len(), out);
+            for field in tuple_struct.fields() {
+                encode_field(field, out);
+            }
+        }
+        ReflectRef::Tuple(tuple) => {
+            out.push(tag::TUPLE);
+            write_len(tuple.fields_len(), out);
+            for field in tuple.fields() {
+                encode_field(field, out);
+            }
+        }
+        ReflectRef::Enum(enum_) => {
+            out.push(tag::ENUM);
+            out.extend(name_hash(enum_.variant_name()).to_le_bytes());
+            out.push(match enum_.variant_kind() {
+                VariantKind::Struct => variant_tag::STRUCT,
+                VariantKind::Tuple => variant_tag::TUPLE,
+                VariantKind::Unit => variant_tag::UNIT,
+            });
+            write_len(enum_.fields_len(), out);
+            for field in enum_.fields() {
+                match field {
+                    VariantField::Struct(name, field) => {
+                        out.extend(name_hash(name).to_le_bytes());
+                        encode_field(field, out);
+                    }
+                    VariantField::Tuple(field) => encode_field(field, out),
+                }
+            }
+        }
+        ReflectRef::Array(array) => {
+            out.push(tag::LIST);
+            write_len(array.len(), out);
+            for element in array.iter() {
+                encode(element, out);
+            }
+        }
+        ReflectRef::List(list) => {
+            out.push(tag::LIST);
+            write_len(list.len(), out);
+            for element in list.iter() {
+                encode(element, out);
+            }
+        }
+        ReflectRef::Map(map) => {
+            out.push(tag::MAP);
+            write_len(map.len(), out);
+            for (key, value) in map.iter() {
+                encode(key, out);
+                encode(value, out);
+            }
+        }
+        ReflectRef::Set(set) => {
+            out.push(tag::SET);
+            write_len(set.len(), out);
+            for element in set.iter() {
+                encode(element, out);
+            }
+        }
+        ReflectRef::Opaque(opaque) => encode(&opaque.to_value(), out),
+    }
+}
+
+/// Encode a field prefixed with its length, so it can be skipped.
+fn encode_field(value: &dyn Reflect, out: &mut Vec<u8>) {
+    let start = out.len();
+    out.extend(0_u32.to_le_bytes());
+    encode(value, out);
+    let len = out.len() - start - 4;
+    out[start..start + 4].copy_from_slice(&len_u32(len).to_le_bytes());
+}
+
+fn encode_scalar(scalar: ScalarRef<'_>, out: &mut Vec<u8>) {
+    match scalar {
+        ScalarRef::usize(n) => {
+            out.push(tag::USIZE);
+            out.extend((n as u64).to_le_bytes());
+        }
+        ScalarRef::u8(n) => {
+            out.push(tag::U8);
+            out.push(n);
+        }
+        ScalarRef::u16(n) => {
+            out.push(tag::U16);
+            out.extend(n.to_le_bytes());
+        }
+        ScalarRef::u32(n) => {
+            out.push(tag::U32);
+            out.extend(n.to_le_bytes());
+        }
+        ScalarRef::u64(n) => {
+            out.push(tag::U64);
+            out.extend(n.to_le_bytes());
+        }
+        ScalarRef::u128(n) => {
+            out.push(tag::U128);
+            out.extend(n.to_le_bytes());
+        }
+        ScalarRef::i8(n) => {
+            out.push(tag::I8);
+            out.extend(n.to_le_bytes());
+        }
+        ScalarRef::i16(n) => {
+            out.push(tag::I16);
+            out.extend(n.to_le_bytes());
+        }
+        ScalarRef::i32(n) => {
+            out.push(tag::I32);
+            out.extend(n.to_le_bytes());
+        }
+        ScalarRef::i64(n) => {
+            out.push(tag::I64);
+            out.extend(n.to_le_bytes());
+        }
+        ScalarRef::i128(n) => {
+            out.push(tag::I128);
+            out.extend(n.to_le_bytes());
+        }
+        ScalarRef::bool(b) => {
+            out.push(tag::BOOL);
+            out.push(b.into());
+        }
+        ScalarRef::char(c) => {
+            out.push(tag::CHAR);
+            out.extend(u32::from(c).to_le_bytes());
+        }
+        ScalarRef::f32(n) => {
+            out.push(tag::F32);
+            out.extend(n.to_le_bytes());
+        }
+        ScalarRef::f64(n) => {
+            out.push(tag::F64);
+            out.extend(n.to_le_bytes());
+        }
+        ScalarRef::String(s) => {
+            out.push(tag::STRING);
+            write_len(s.len(), out);
+            out.extend(s.as_bytes());
+        }
+    }
+}
+
+fn write_len(len: usize, out: &mut Vec<u8>) {
+    out.extend(len_u32(len).to_le_bytes());
+}
+
+fn len_u32(len: usize) -> u32 {
+    len.try_into()
+        .expect("lengths over `u32::MAX` aren't supported")
+}
+
+struct Decoder<'a> {
+    bytes: &'a [u8],
+    // how many values are being decoded, including those of the decoders this was split off from
+    depth: usize,
+}
+
+impl<'a> Decoder<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], EvolutionError> {
+        if self.bytes.len() < len {
+            return Err(EvolutionError::UnexpectedEnd);
+        }
+        let (taken, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Ok(taken)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], EvolutionError> {
+        Ok(self.take(N)?.try_into().unwrap())
+    }
+
+    fn u8(&mut self) -> Result<u8, EvolutionError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u32(&mut self) -> Result<u32, EvolutionError> {
+        self.array().map(u32::from_le_bytes)
+    }
+
+    fn u64(&mut self) -> Result<u64, EvolutionError> {
+        self.array().map(u64::from_le_bytes)
+    }
+
+    fn len(&mut self) -> Result<usize, EvolutionError> {
+        Ok(self.u32()? as usize)
+    }
+
+    /// Split off a length prefixed field.
+    fn field(&mut self) -> Result<Decoder<'a>, EvolutionError> {
+        let len = self.len()?;
+        Ok(Decoder {
+            bytes: self.take(len)?,
+            depth: self.depth,
+        })
+    }
+
+    /// Decode a length prefixed field, requiring that it's consumed entirely.
+    fn field_value(&mut self, ty: Option<Type<'_>>) -> Result<Value, EvolutionError> {
+        let mut field = self.field()?;
+        let value = field.value(ty)?;
+        if !field.bytes.is_empty() {
+            return Err(EvolutionError::Invalid);
+        }
+        Ok(value)
+    }
+
+    fn value(&mut self, ty: Option<Type<'_>>) -> Result<Value, EvolutionError> {
+        if self.depth >= MAX_DEPTH {
+            return Err(EvolutionError::TooDeep);
+        }
+        self.depth += 1;
+        let value = self.value_inner(ty);
+        self.depth -= 1;
+        value
+    }
+
+    fn value_inner(&mut self, ty: Option<Type<'_>>) -> Result<Value, EvolutionError> {
+        Ok(match self.u8()? {
+            tag::USIZE => Value::usize(
+                self.u64()?
+                    .try_into()
+                    .map_err(|_| EvolutionError::Invalid)?,
+            ),
+            tag::U8 => Value::u8(self.u8()?),
+            tag::U16 => Value::u16(u16::from_le_bytes(self.array()?)),
+            tag::U32 => Value::u32(self.u32()?),
+            tag::U64 => Value::u64(self.u64()?),
+            tag::U128 => Value::u128(u128::from_le_bytes(self.array()?)),
+            tag::I8 => Value::i8(i8::from_le_bytes(self.array()?)),
+            tag::I16 => Value::i16(i16::from_le_bytes(self.array()?)),
+            tag::I32 => Value::i32(i32::from_le_bytes(self.array()?)),
+            tag::I64 => Value::i64(i64::from_le_bytes(self.array()?)),
+            tag::I128 => Value::i128(i128::from_le_bytes(self.array()?)),
+            tag::BOOL => match self.u8()? {
+                0 => Value::bool(false),
+                1 => Value::bool(true),
+                _ => return Err(EvolutionError::Invalid),
+            },
+            tag::CHAR => Value::char(char::from_u32(self.u32()?).ok_or(EvolutionError::Invalid)?),
+            tag::F32 => Value::f32(f32::from_le_bytes(self.array()?)),
+            tag::F64 => Value::f64(f64::from_le_bytes(self.array()?)),
+            tag::STRING => {
+                let len = self.len()?;
+                let s =
+                    core::str::from_utf8(self.take(len)?).map_err(|_| EvolutionError::Invalid)?;
+                Value::String(s.to_owned())
+            }
+            tag::STRUCT => {
+                let Some(struct_type) = ty.and_then(Type::as_struct) else {
+                    return Err(unresolved(ty));
+                };
+                let fields = struct_type
+                    .field_types()
+                    .map(|field| (field.name(), field.get_type()));
+                let fields = self.named_fields(fields)?;
+                let mut value = StructValue::with_capacity(fields.len())
+                    .with_type_name(struct_type.type_name().to_owned());
+                for (name, field) in fields {
+                    value.set_field(name, field);
+                }
+                value.into()
+            }
+            tag::TUPLE_STRUCT => {
+                let types = ty
+                    .and_then(Type::as_tuple_struct)
+                    .map(|ty| ty.field_types().map(|field| field.get_type()).collect());
+                let fields = self.unnamed_fields(types)?;
+                let mut value = TupleStructValue::with_capacity(fields.len());
+                if let Some(ty) = ty.and_then(Type::as_tuple_struct) {
+                    value.set_type_name(ty.type_name().to_owned());
+                }
+                for field in fields {
+                    value.push_field(field);
+                }
+                value.into()
+            }
+            tag::TUPLE => {
+                let types = ty
+                    .and_then(Type::as_tuple)
+                    .map(|ty| ty.field_types().map(|field| field.get_type()).collect());
+                let fields = self.unnamed_fields(types)?;
+                let mut value = TupleValue::with_capacity(fields.len());
+                for field in fields {
+                    value.push_field(field);
+                }
+                value.into()
+            }
+            tag::ENUM => self.enum_value(ty)?,
+            tag::LIST => {
+                let element_type = ty.and_then(|ty| match ty {
+                    Type::List(list_type) => Some(list_type.element_type()),
+                    Type::Array(array_type) => Some(array_type.element_type()),
+                    _ => None,
+                });
+                let len = self.len()?;
+                let mut elements = Vec::with_capacity(len.min(self.bytes.len()));
+                for _ in 0..len {
+                    elements.push(self.value(element_type)?);
+                }
+                Value::List(elements)
+            }
+            tag::MAP => {
+                let map_type = ty.and_then(Type::as_map);
+                let key_type = map_type.map(|map_type| map_type.key_type());
+                let value_type = map_type.map(|map_type| map_type.value_type());
+                let mut map = BTreeMap::new();
+                for _ in 0..self.len()? {
+                    let key = self.value(key_type)?;
+                    let value = self.value(value_type)?;
+                    map.insert(key, value);
+                }
+                Value::Map(map)
+            }
+            tag::SET => {
+                let element_type = ty
+                    .and_then(Type::as_set)
+                    .map(|set_type| set_type.element_type());
+                let mut set = BTreeSet::new();
+                for _ in 0..self.len()? {
+                    set.insert(self.value(element_type)?);
+                }
+                Value::Set(set)
+            }
+            _ => return Err(EvolutionError::Invalid),
+        })
+    }
+
+    /// Decode fields identified by name hashes into the fields of the type, in the type's order.
+    /// Fields the type doesn't have are skipped and missing fields are filled in with defaults.
+    fn named_fields<'t>(
+        &mut self,
+        types: impl Iterator<Item = (&'t str, Type<'t>)>,
+    ) -> Result<Vec<(&'t str, Value)>, EvolutionError> {
+        let mut fields = types.map(|(name, ty)| (name, ty, None)).collect::<Vec<_>>();
+        let indices = fields
+            .iter()
+            .enumerate()
+            .map(|(index, (name, _, _))| (name_hash(name), index))
+            .collect::<BTreeMap<_, _>>();
+
+        for _ in 0..self.len()? {
+            let hash = self.u64()?;
+            match indices.get(&hash) {
+                Some(&index) => {
+                    let ty = fields[index].1;
+                    fields[index].2 = Some(self.field_value(Some(ty))?);
+                }
+                None => {
+                    self.field()?;
+                }
+            }
+        }
+
+        fields
+            .into_iter()
+            .map(|(name, ty, value)| {
+                let value = value
+                    .or_else(|| ty.default_value())
+                    .ok_or_else(|| EvolutionError::MissingField(name.to_owned()))?;
+                Ok((name, value))
+            })
+            .collect()
+    }
+
+    /// Decode fields identified by position. Extra fields in the data are skipped and missing
+    /// fields are filled in with defaults.
+    fn unnamed_fields(
+        &mut self,
+        types: Option<Vec<Type<'_>>>,
+    ) -> Result<Vec<Value>, EvolutionError> {
+        let len = self.len()?;
+        let mut fields = Vec::with_capacity(len.min(self.bytes.len()));
+        for index in 0..len {
+            match &types {
+                Some(types) if index >= types.len() => {
+                    self.field()?;
+                }
+                Some(types) => fields.push(self.field_value(Some(types[index]))?),
+                None => fields.push(self.field_value(None)?),
+            }
+        }
+        if let Some(types) = types {
+            for (index, ty) in types.iter().enumerate().skip(fields.len()) {
+                let value = ty
+                    .default_value()
+                    .ok_or_else(|| EvolutionError::MissingField(index.to_string()))?;
+                fields.push(value);
+            }
+        }
+        Ok(fields)
+    }
+
+    fn enum_value(&mut self, ty: Option<Type<'_>>) -> Result<Value, EvolutionError> {
+        let Some(enum_type) = ty.and_then(Type::as_enum) else {
+            return Err(unresolved(ty));
+        };
+        let hash = self.u64()?;
+        let kind = self.u8()?;
+        let variant = enum_type
+            .variants()
+            .find(|variant| name_hash(variant.name()) == hash)
+            .ok_or_else(|| EvolutionError::UnknownVariant(enum_type.type_name().to_owned()))?;
+
+        let mut value = match (variant, kind) {
+            (Variant::Struct(variant), variant_tag::STRUCT) => {
+                let fields = variant
+                    .field_types()
+                    .map(|field| (field.name(), field.get_type()));
+                let mut value = EnumValue::new_struct_variant(variant.name());
+                for (name, field) in self.named_fields(fields)? {
+                    value.set_struct_field(name, field);
+                }
+                value.finish()
+            }
+            (Variant::Tuple(variant), variant_tag::TUPLE) => {
+                let types = variant
+                    .field_types()
+                    .map(|field| field.get_type())
+                    .collect();
+                let mut value = EnumValue::new_tuple_variant(variant.name());
+                for field in self.unnamed_fields(Some(types))? {
+                    value.push_tuple_field(field);
+                }
+                value.finish()
+            }
+            (Variant::Unit(variant), variant_tag::UNIT) => {
+                if self.len()? != 0 {
+                    return Err(EvolutionError::Invalid);
+                }
+                EnumValue::new_unit_variant(variant.name())
+            }
+            _ => {
+                return Err(EvolutionError::UnknownVariant(
+                    enum_type.type_name().to_owned(),
+                ))
+            }
+        };
+        value.set_type_name(enum_type.type_name().to_owned());
+        value.set_discriminant(variant.discriminant());
+        Ok(value.into())
+    }
+}
+
+fn unresolved(ty: Option<Type<'_>>) -> EvolutionError {
+    EvolutionError::Unresolved(ty.map_or("unknown", Type::type_name).to_owned())
+}
@@ -0,0 +1,353 @@
+use alloc::boxed::Box;
+use core::fmt;
+
+use crate::enum_::VariantField;
+use crate::enum_::VariantKind;
+use crate::Reflect;
+use crate::ReflectRef;
+use crate::ScalarRef;
+
+/// Formats any reflected value, with options for how much of it to show and how.
+///
+/// Unlike [`reflect_debug`](crate::reflect_debug), which always mirrors `#[derive(Debug)]`,
+/// `PrettyPrinter` can be configured to fit debug overlays and logs:
+///
+/// - [`with_max_depth`](Self::with_max_depth) replaces values nested deeper than some level with
+///   `..`.
+/// - [`with_field_filter`](Self::with_field_filter) leaves out named fields.
+/// - [`with_hex_integers`](Self::with_hex_integers) formats integers in hexadecimal.
+/// - [`with_compact_lists`](Self::with_compact_lists) keeps lists, arrays, and sets on one line
+///   when formatting multiple lines.
+/// - [`with_type_names`](Self::with_type_names) leaves out the names of structs and tuple
+///   structs.
+/// - [`with_multiline`](Self::with_multiline) formats values over multiple lines, like `{:#?}`.
+///
+/// # Example
+///
+/// ```
+/// use mirror_mirror::{fmt::PrettyPrinter, Reflect};
+///
+/// #[derive(Reflect, Clone, Debug)]
+/// struct Player {
+///     name: String,
+///     flags: u32,
+///     inventory: Vec<Item>,
+///     secret: String,
+/// }
+///
+/// #[derive(Reflect, Clone, Debug)]
+/// struct Item {
+///     id: u8,
+/// }
+///
+/// let player = Player {
+///     name: "alice".to_owned(),
+///     flags: 255,
+///     inventory: Vec::from([Item { id: 1 }, Item { id: 2 }]),
+///     secret: "hunter2".to_owned(),
+/// };
+///
+/// let printer = PrettyPrinter::new()
+///     .with_max_depth(2)
+///     .with_field_filter(|name| name != "secret")
+///     .with_hex_integers(true)
+///     .with_type_names(false);
+///
+/// assert_eq!(
+///     printer.display(&player).to_string(),
+///     r#"{name: "alice", flags: 0xff, inventory: [.., ..]}"#,
+/// );
+/// ```
+pub struct PrettyPrinter {
+    max_depth: usize,
+    field_filter: Option<Box<dyn Fn(&str) -> bool + Send + Sync>>,
+    hex_integers: bool,
+    compact_lists: bool,
+    type_names: bool,
+    multiline: bool,
+}
+
+impl PrettyPrinter {
+    /// Create a printer that formats values like [`reflect_debug`](crate::reflect_debug).
+    pub fn new() -> Self {
+        Self {
+            max_depth: usize::MAX,
+            field_filter: None,
+            hex_integers: false,
+            compact_lists: false,
+            type_names: true,
+            multiline: false,
+        }
+    }
+
+    /// How many levels of nested values to format. Values nested deeper are replaced by `..`.
+    ///
+    /// Scalars are always formatted.
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Only format the named fields of structs and struct variants for which `filter` returns
+    /// `true`.
+    pub fn with_field_filter<F>(mut self, filter: F) -> Self
+    where
+        F: Fn(&str) -> bool + Send + Sync + 'static,
+    {
+        self.field_filter = Some(Box::new(filter));
+        self
+    }
+
+    /// Format integers in hexadecimal, such as `0xff`.
+    ///
+    /// Negative integers are formatted in two's complement, like `{:#x}` does.
+    pub fn with_hex_integers(mut self, hex_integers: bool) -> Self {
+        self.hex_integers = hex_integers;
+        self
+    }
+
+    /// Format lists, arrays, and sets on a single line, even when formatting multiple lines.
+    pub fn with_compact_lists(mut self, compact_lists: bool) -> Self {
+        self.compact_lists = compact_lists;
+        self
+    }
+
+    /// Whether to include the names of structs and tuple structs. Enabled by default.
+    ///
+    /// Without them structs are formatted like maps, such as `{x: 1, y: 2}`, and tuple structs
+    /// like tuples.
+    pub fn with_type_names(mut self, type_names: bool) -> Self {
+        self.type_names = type_names;
+        self
+    }
+
+    /// Format values over multiple lines, like `{:#?}` does.
+    ///
+    /// The alternate flag, `{:#}`, also formats multiple lines.
+    pub fn with_multiline(mut self, multiline: bool) -> Self {
+        self.multiline = multiline;
+        self
+    }
+
+    /// Format `value` with these options.
+    pub fn display<'a>(&'a self, value: &'a dyn Reflect) -> PrettyValue<'a> {
+        PrettyValue {
+            value,
+            printer: self,
+        }
+    }
+}
+
+impl Default for PrettyPrinter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for PrettyPrinter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PrettyPrinter")
+            .field("max_depth", &self.max_depth)
+            .field("field_filter", &self.field_filter.is_some())
+            .field("hex_integers", &self.hex_integers)
+            .field("compact_lists", &self.compact_lists)
+            .field("type_names", &self.type_names)
+            .field("multiline", &self.multiline)
+            .finish()
+    }
+}
+
+/// A value formatted by a [`PrettyPrinter`].
+///
+/// `Display` and `Debug` produce the same output.
+pub struct PrettyValue<'a> {
+    value: &'a dyn Reflect,
+    printer: &'a PrettyPrinter,
+}
+
+impl fmt::Display for PrettyValue<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+impl fmt::Debug for PrettyValue<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let node = Node {
+            value: self.value,
+            printer: self.printer,
+            depth: 0,
+        };
+        if self.printer.multiline && !f.alternate() {
+            write!(f, "{node:#?}")
+        } else {
+            node.fmt(f)
+        }
+    }
+}
+
+/// A value nested somewhere inside a [`PrettyValue`].
+struct Node<'a> {
+    value: &'a dyn Reflect,
+    printer: &'a PrettyPrinter,
+    depth: usize,
+}
+
+impl<'a> Node<'a> {
+    fn child(&self, value: &'a dyn Reflect) -> Self {
+        Self {
+            value,
+            printer: self.printer,
+            depth: self.depth + 1,
+        }
+    }
+
+    fn show_field(&self, name: &str) -> bool {
+        self.printer
+            .field_filter
+            .as_ref()
+            .map_or(true, |filter| filter(name))
+    }
+
+    fn fmt_list<'b>(
+        &self,
+        elements: impl Iterator<Item = &'b dyn Reflect>,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result
+    where
+        'a: 'b,
+    {
+        let mut f = f.debug_list();
+        for element in elements {
+            f.entry(&self.child(element));
+        }
+        f.finish()
+    }
+}
+
+/// Writes a field name without quotes, for structs formatted like maps.
+struct FieldName<'a>(&'a str);
+
+impl fmt::Debug for FieldName<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+impl fmt::Debug for Node<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let printer = self.printer;
+        let value = self.value;
+
+        #[cfg(feature = "std")]
+        if let Some(formatter) = crate::registry::global_debug_formatter(value) {
+            return formatter(value, f);
+        }
+
+        match value.reflect_ref() {
+            ReflectRef::Scalar(scalar) if printer.hex_integers => match scalar {
+                ScalarRef::usize(inner) => write!(f, "{inner:#x}"),
+                ScalarRef::u8(inner) => write!(f, "{inner:#x}"),
+                ScalarRef::u16(inner) => write!(f, "{inner:#x}"),
+                ScalarRef::u32(inner) => write!(f, "{inner:#x}"),
+                ScalarRef::u64(inner) => write!(f, "{inner:#x}"),
+                ScalarRef::u128(inner) => write!(f, "{inner:#x}"),
+                ScalarRef::i8(inner) => write!(f, "{inner:#x}"),
+                ScalarRef::i16(inner) => write!(f, "{inner:#x}"),
+                ScalarRef::i32(inner) => write!(f, "{inner:#x}"),
+                ScalarRef::i64(inner) => write!(f, "{inner:#x}"),
+                ScalarRef::i128(inner) => write!(f, "{inner:#x}"),
+                ScalarRef::bool(_)
+                | ScalarRef::char(_)
+                | ScalarRef::f32(_)
+                | ScalarRef::f64(_)
+                | ScalarRef::String(_) => crate::reflect_debug(value, f),
+            },
+            ReflectRef::Scalar(_) | ReflectRef::Opaque(_) => crate::reflect_debug(value, f),
+            _ if self.depth >= printer.max_depth => f.write_str(".."),
+            // formatting without the alternate flag keeps everything inside on one line
+            ReflectRef::Array(_) | ReflectRef::List(_) | ReflectRef::Set(_)
+                if printer.compact_lists && f.alternate() =>
+            {
+                #[allow(clippy::recursive_format_impl)]
+                {
+                    write!(f, "{self:?}")
+                }
+            }
+            ReflectRef::Struct(inner) => {
+                let fields = inner.fields().filter(|(name, _)| self.show_field(name));
+                if printer.type_names {
+                    let mut f = f.debug_struct(inner.type_name());
+                    for (name, field) in fields {
+                        f.field(name, &self.child(field));
+                    }
+                    f.finish()
+                } else {
+                    let mut f = f.debug_map();
+                    for (name, field) in fields {
+                        f.entry(&FieldName(name), &self.child(field));
+                    }
+                    f.finish()
+                }
+            }
+            ReflectRef::TupleStruct(inner) => {
+                let name = if printer.type_names {
+                    inner.type_name()
+                } else {
+                    ""
+                };
+                let mut f = f.debug_tuple(name);
+                for field in inner.fields() {
+                    f.field(&self.child(field));
+                }
+                f.finish()
+            }
+            ReflectRef::Tuple(inner) => {
+                let mut f = f.debug_tuple("");
+                for field in inner.fields() {
+                    f.field(&self.child(field));
+                }
+                f.finish()
+            }
+            ReflectRef::Enum(inner) => match inner.variant_kind() {
+                VariantKind::Struct => {
+                    let mut f = f.debug_struct(inner.variant_name());
+                    for field in inner.fields() {
+                        if let VariantField::Struct(name, field) = field {
+                            if self.show_field(name) {
+                                f.field(name, &self.child(field));
+                            }
+                        }
+                    }
+                    f.finish()
+                }
+                VariantKind::Tuple => {
+                    let mut f = f.debug_tuple(inner.variant_name());
+                    for field in inner.fields() {
+                        if let VariantField::Tuple(field) = field {
+                            f.field(&self.child(field));
+                        }
+                    }
+                    f.finish()
+                }
+                VariantKind::Unit => f.write_str(inner.variant_name()),
+            },
+            ReflectRef::Array(inner) => self.fmt_list(inner.iter(), f),
+            ReflectRef::List(inner) => self.fmt_list(inner.iter(), f),
+            ReflectRef::Map(inner) => {
+                let mut f = f.debug_map();
+                for (key, value) in inner.iter() {
+                    f.entry(&self.child(key), &self.child(value));
+                }
+                f.finish()
+            }
+            ReflectRef::Set(inner) => {
+                let mut f = f.debug_set();
+                for element in inner.iter() {
+                    f.entry(&self.child(element));
+                }
+                f.finish()
+            }
+        }
+    }
+}
@@ -108,6 +108,16 @@ where
     T: FromReflect + DescribeType,
 {
     fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        if let Some(array) = reflect.reflect_ref().as_array() {
+            if array.len() != N {
+                return None;
+            }
+            let mut out = Vec::with_capacity(N);
+            for value in array.iter() {
+                out.push(T::from_reflect(value)?);
+            }
+            return out.try_into().ok();
+        }
         Vec::<T>::from_reflect(reflect)?.try_into().ok()
     }
 }
@@ -0,0 +1,177 @@
+use alloc::boxed::Box;
+use core::any::Any;
+
+use arrayvec::ArrayVec;
+
+use crate::array::Array;
+use crate::iter::ValueIterMut;
+use crate::type_info::graph::ListNode;
+use crate::type_info::graph::NodeId;
+use crate::type_info::graph::TypeGraph;
+use crate::DescribeType;
+use crate::FromReflect;
+use crate::List;
+use crate::Reflect;
+use crate::ReflectMut;
+use crate::ReflectOwned;
+use crate::ReflectRef;
+use crate::Value;
+
+impl<T, const CAP: usize> List for ArrayVec<T, CAP>
+where
+    T: FromReflect + DescribeType,
+{
+    fn push(&mut self, value: &dyn Reflect) {
+        if let Some(value) = T::from_reflect(value) {
+            // values that don't fit are ignored, like values of the wrong type
+            let _ = ArrayVec::try_push(self, value);
+        }
+    }
+
+    fn pop(&mut self) -> Option<Box<dyn Reflect>> {
+        let value = ArrayVec::pop(self)?;
+        Some(Box::new(value))
+    }
+
+    fn try_remove(&mut self, index: usize) -> Option<Box<dyn Reflect>> {
+        if index < self.len() {
+            let value = ArrayVec::remove(self, index);
+            Some(Box::new(value))
+        } else {
+            None
+        }
+    }
+
+    fn insert(&mut self, index: usize, value: &dyn Reflect) {
+        if let Some(value) = T::from_reflect(value) {
+            let _ = ArrayVec::try_insert(self, index, value);
+        }
+    }
+
+    fn truncate(&mut self, len: usize) {
+        ArrayVec::truncate(self, len);
+    }
+
+    fn clear(&mut self) {
+        ArrayVec::clear(self);
+    }
+
+    fn retain(&mut self, f: &mut dyn FnMut(&dyn Reflect) -> bool) {
+        ArrayVec::retain(self, |value| f(value));
+    }
+
+    // the capacity is fixed
+    fn reserve(&mut self, _additional: usize) {}
+}
+
+impl<T, const CAP: usize> Array for ArrayVec<T, CAP>
+where
+    T: FromReflect + DescribeType,
+{
+    fn get(&self, index: usize) -> Option<&dyn Reflect> {
+        self.as_slice().get(index).map(|value| value.as_reflect())
+    }
+
+    fn get_mut(&mut self, index: usize) -> Option<&mut dyn Reflect> {
+        self.as_mut_slice()
+            .get_mut(index)
+            .map(|value| value.as_reflect_mut())
+    }
+
+    fn len(&self) -> usize {
+        ArrayVec::len(self)
+    }
+
+    fn is_empty(&self) -> bool {
+        ArrayVec::is_empty(self)
+    }
+
+    fn iter(&self) -> crate::array::Iter<'_> {
+        crate::array::Iter::new(self)
+    }
+
+    fn iter_mut(&mut self) -> ValueIterMut<'_> {
+        let iter = self
+            .as_mut_slice()
+            .iter_mut()
+            .map(|value| value.as_reflect_mut());
+        Box::new(iter)
+    }
+}
+
+impl<T, const CAP: usize> DescribeType for ArrayVec<T, CAP>
+where
+    T: DescribeType,
+{
+    fn build(graph: &mut TypeGraph) -> NodeId {
+        graph.get_or_build_node_with::<Self, _>(|graph| ListNode::new::<Self, T>(graph))
+    }
+}
+
+impl<T, const CAP: usize> Reflect for ArrayVec<T, CAP>
+where
+    T: FromReflect + DescribeType,
+{
+    trivial_reflect_methods!();
+
+    fn patch(&mut self, value: &dyn Reflect) {
+        if let Some(list) = value.reflect_ref().as_list() {
+            for (idx, new_value) in list.iter().enumerate() {
+                if let Some(value) = self.get_mut(idx) {
+                    value.patch(new_value);
+                }
+            }
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        let data = self.iter().map(Reflect::to_value).collect();
+        Value::List(data)
+    }
+
+    fn clone_reflect(&self) -> Box<dyn Reflect> {
+        let value = self.to_value();
+        Box::new(Self::from_reflect(&value).unwrap())
+    }
+
+    fn debug(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        ReflectOwned::List(self)
+    }
+
+    fn reflect_ref(&self) -> ReflectRef<'_> {
+        ReflectRef::List(self)
+    }
+
+    fn reflect_mut(&mut self) -> ReflectMut<'_> {
+        ReflectMut::List(self)
+    }
+}
+
+impl<T, const CAP: usize> FromReflect for ArrayVec<T, CAP>
+where
+    T: FromReflect + DescribeType,
+{
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        let list = reflect.reflect_ref().as_list()?;
+        // lists longer than the capacity can't be converted
+        let mut out = ArrayVec::new();
+        for value in list.iter() {
+            out.try_push(T::from_reflect(value)?).ok()?;
+        }
+        Some(out)
+    }
+}
+
+impl<T, const CAP: usize> From<ArrayVec<T, CAP>> for Value
+where
+    T: Reflect,
+{
+    fn from(list: ArrayVec<T, CAP>) -> Self {
+        let list = list.into_iter().map(|value| value.to_value()).collect();
+        Value::List(list)
+    }
+}
@@ -1,5 +1,6 @@
 use alloc::borrow::Cow;
 use alloc::boxed::Box;
+use alloc::sync::Arc;
 use core::any::Any;
 use core::fmt;
 
@@ -9,6 +10,7 @@ use crate::type_info::graph::TypeGraph;
 use crate::DescribeType;
 use crate::FromReflect;
 use crate::Reflect;
+use crate::ReflectError;
 use crate::ReflectMut;
 use crate::ReflectOwned;
 use crate::ReflectRef;
@@ -64,6 +66,10 @@ where
         <T as Reflect>::patch(self, value)
     }
 
+    fn validate_self(&self) -> Result<(), ReflectError> {
+        <T as Reflect>::validate_self(self)
+    }
+
     fn to_value(&self) -> Value {
         <T as Reflect>::to_value(self)
     }
@@ -94,3 +100,91 @@ where
         (*boxed).into()
     }
 }
+
+// `Arc<T>` is reflected as `T`, like `Box<T>`. Mutation is clone-on-write, so reflecting mutably
+// clones the inner value if it's shared. `Rc<T>` isn't `Send` so can't implement `Reflect`.
+
+impl<T> DescribeType for Arc<T>
+where
+    T: DescribeType,
+{
+    fn build(graph: &mut TypeGraph) -> NodeId {
+        T::build(graph)
+    }
+}
+
+impl<T> Reflect for Arc<T>
+where
+    T: Reflect + DescribeType + Clone + Sync,
+{
+    fn type_descriptor(&self) -> Cow<'static, TypeDescriptor> {
+        <T as DescribeType>::type_descriptor()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        <T as Reflect>::as_any(self)
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        <T as Reflect>::as_any_mut(Arc::make_mut(self))
+    }
+
+    fn as_reflect(&self) -> &dyn Reflect {
+        <T as Reflect>::as_reflect(self)
+    }
+
+    fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+        <T as Reflect>::as_reflect_mut(Arc::make_mut(self))
+    }
+
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        let value = Arc::try_unwrap(*self).unwrap_or_else(|arc| T::clone(&arc));
+        <T as Reflect>::reflect_owned(Box::new(value))
+    }
+
+    fn reflect_ref(&self) -> ReflectRef<'_> {
+        <T as Reflect>::reflect_ref(self)
+    }
+
+    fn reflect_mut(&mut self) -> ReflectMut<'_> {
+        <T as Reflect>::reflect_mut(Arc::make_mut(self))
+    }
+
+    fn patch(&mut self, value: &dyn Reflect) {
+        <T as Reflect>::patch(Arc::make_mut(self), value)
+    }
+
+    fn validate_self(&self) -> Result<(), ReflectError> {
+        <T as Reflect>::validate_self(self)
+    }
+
+    fn to_value(&self) -> Value {
+        <T as Reflect>::to_value(self)
+    }
+
+    fn clone_reflect(&self) -> Box<dyn Reflect> {
+        <T as Reflect>::clone_reflect(self)
+    }
+
+    fn debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        reflect_debug(self, f)
+    }
+}
+
+impl<T> FromReflect for Arc<T>
+where
+    T: FromReflect + DescribeType + Clone + Sync,
+{
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        Some(Arc::new(T::from_reflect(reflect)?))
+    }
+}
+
+impl<T> From<Arc<T>> for Value
+where
+    T: Reflect,
+{
+    fn from(arc: Arc<T>) -> Self {
+        arc.to_value()
+    }
+}
@@ -1,9 +1,16 @@
 use alloc::boxed::Box;
+use alloc::collections::btree_map;
 use alloc::collections::BTreeMap;
+use alloc::string::String;
 use core::any::Any;
 use core::fmt;
 
+use crate::iter::IntoPairIter;
 use crate::iter::PairIterMut;
+use crate::key_path::KeyPath;
+use crate::map;
+use crate::map::MapEntry;
+use crate::map::VacantEntry;
 use crate::type_info::graph::MapNode;
 use crate::type_info::graph::NodeId;
 use crate::type_info::graph::TypeGraph;
@@ -11,6 +18,7 @@ use crate::DescribeType;
 use crate::FromReflect;
 use crate::Map;
 use crate::Reflect;
+use crate::ReflectError;
 use crate::ReflectMut;
 use crate::ReflectOwned;
 use crate::ReflectRef;
@@ -22,14 +30,30 @@ where
     V: FromReflect + DescribeType,
 {
     fn get(&self, key: &dyn Reflect) -> Option<&dyn Reflect> {
-        let key = K::from_reflect(key)?;
-        let value = self.get(&key)?;
+        if let Some(key) = key.downcast_ref::<K>() {
+            return Some(self.get(key)?.as_reflect());
+        }
+        if let (Some(map), Some(key)) = (
+            self.as_any().downcast_ref::<BTreeMap<String, V>>(),
+            map::key_as_str(key),
+        ) {
+            return Some(map.get(key)?.as_reflect());
+        }
+        let value = self.get(&K::from_reflect(key)?)?;
         Some(value.as_reflect())
     }
 
     fn get_mut(&mut self, key: &dyn Reflect) -> Option<&mut dyn Reflect> {
-        let key = K::from_reflect(key)?;
-        let value = self.get_mut(&key)?;
+        if let Some(key) = key.downcast_ref::<K>() {
+            return Some(self.get_mut(key)?.as_reflect_mut());
+        }
+        if let Some(key) = map::key_as_str(key) {
+            if self.as_any().is::<BTreeMap<String, V>>() {
+                let map = self.as_any_mut().downcast_mut::<BTreeMap<String, V>>()?;
+                return Some(map.get_mut(key)?.as_reflect_mut());
+            }
+        }
+        let value = self.get_mut(&K::from_reflect(key)?)?;
         Some(value.as_reflect_mut())
     }
 
@@ -40,6 +64,47 @@ where
         Some(Box::new(previous))
     }
 
+    fn try_insert(
+        &mut self,
+        key: &dyn Reflect,
+        value: &dyn Reflect,
+    ) -> Result<Option<Box<dyn Reflect>>, ReflectError> {
+        let value = V::try_from_reflect(value)
+            .map_err(|error| error.under(&KeyPath::default().get(key.to_value())))?;
+        let key = K::try_from_reflect(key)?;
+        let previous = BTreeMap::insert(self, key, value);
+        Ok(previous.map(|previous| Box::new(previous) as _))
+    }
+
+    fn drain(&mut self) -> IntoPairIter<'_, Box<dyn Reflect>> {
+        let iter = core::mem::take(self)
+            .into_iter()
+            .map(|(key, value)| (Box::new(key) as _, Box::new(value) as _));
+        Box::new(iter)
+    }
+
+    fn into_entries(self: Box<Self>) -> IntoPairIter<'static, Box<dyn Reflect>> {
+        let iter = (*self)
+            .into_iter()
+            .map(|(key, value)| (Box::new(key) as _, Box::new(value) as _));
+        Box::new(iter)
+    }
+
+    fn entry(&mut self, key: &dyn Reflect) -> MapEntry<'_> {
+        let Some(key) = K::from_reflect(key) else {
+            return MapEntry::Vacant(VacantEntry::new(|_| None));
+        };
+        match BTreeMap::entry(self, key) {
+            btree_map::Entry::Occupied(entry) => {
+                MapEntry::Occupied(entry.into_mut().as_reflect_mut())
+            }
+            btree_map::Entry::Vacant(entry) => MapEntry::Vacant(VacantEntry::new(move |value| {
+                let value = V::from_reflect(value)?;
+                Some(entry.insert(value).as_reflect_mut())
+            })),
+        }
+    }
+
     fn remove(&mut self, key: &dyn Reflect) -> Option<Box<dyn Reflect>> {
         let key = K::from_reflect(key)?;
         let previous = BTreeMap::remove(self, &key)?;
@@ -154,3 +219,29 @@ where
         Value::Map(map)
     }
 }
+
+/// If a key or value can't be converted the error's [`path`](ReflectError::path) starts with the
+/// key of that entry.
+impl<K, V> TryFrom<Value> for BTreeMap<K, V>
+where
+    K: FromReflect + DescribeType + Ord,
+    V: FromReflect + DescribeType,
+{
+    type Error = ReflectError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        let Value::Map(map) = value else {
+            return Err(ReflectError::new(
+                core::any::type_name::<Self>(),
+                Some(&value),
+            ));
+        };
+        map.into_iter()
+            .map(|(key, value)| {
+                K::try_from_reflect(&key)
+                    .and_then(|k| Ok((k, V::try_from_reflect(&value)?)))
+                    .map_err(|error| error.under(&KeyPath::default().get(key)))
+            })
+            .collect()
+    }
+}
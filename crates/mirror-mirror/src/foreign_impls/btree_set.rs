@@ -0,0 +1,148 @@
+use alloc::boxed::Box;
+use alloc::collections::BTreeSet;
+use core::any::Any;
+use core::fmt;
+
+use crate::key_path::KeyPath;
+use crate::type_info::graph::NodeId;
+use crate::type_info::graph::SetNode;
+use crate::type_info::graph::TypeGraph;
+use crate::DescribeType;
+use crate::FromReflect;
+use crate::Reflect;
+use crate::ReflectError;
+use crate::ReflectMut;
+use crate::ReflectOwned;
+use crate::ReflectRef;
+use crate::Set;
+use crate::Value;
+
+impl<T> Set for BTreeSet<T>
+where
+    T: FromReflect + DescribeType + Ord,
+{
+    fn contains(&self, value: &dyn Reflect) -> bool {
+        if let Some(value) = value.downcast_ref::<T>() {
+            return BTreeSet::contains(self, value);
+        }
+        T::from_reflect(value).map_or(false, |value| BTreeSet::contains(self, &value))
+    }
+
+    fn insert(&mut self, value: &dyn Reflect) -> bool {
+        T::from_reflect(value).map_or(false, |value| BTreeSet::insert(self, value))
+    }
+
+    fn remove(&mut self, value: &dyn Reflect) -> bool {
+        if let Some(value) = value.downcast_ref::<T>() {
+            return BTreeSet::remove(self, value);
+        }
+        T::from_reflect(value).map_or(false, |value| BTreeSet::remove(self, &value))
+    }
+
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.is_empty()
+    }
+
+    fn iter(&self) -> crate::set::Iter<'_> {
+        Box::new(self.iter().map(|value| value.as_reflect()))
+    }
+}
+
+impl<T> DescribeType for BTreeSet<T>
+where
+    T: DescribeType,
+{
+    fn build(graph: &mut TypeGraph) -> NodeId {
+        graph.get_or_build_node_with::<Self, _>(|graph| SetNode::new::<Self, T>(graph))
+    }
+}
+
+impl<T> Reflect for BTreeSet<T>
+where
+    T: FromReflect + DescribeType + Ord,
+{
+    trivial_reflect_methods!();
+
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        ReflectOwned::Set(self)
+    }
+
+    fn reflect_ref(&self) -> ReflectRef<'_> {
+        ReflectRef::Set(self)
+    }
+
+    fn reflect_mut(&mut self) -> ReflectMut<'_> {
+        ReflectMut::Set(self)
+    }
+
+    fn patch(&mut self, value: &dyn Reflect) {
+        // elements cannot be patched in place, so patching replaces the whole set
+        if let Some(new_value) = Self::from_reflect(value) {
+            *self = new_value;
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        Value::Set(self.iter().map(Reflect::to_value).collect())
+    }
+
+    fn clone_reflect(&self) -> Box<dyn Reflect> {
+        let value = self.to_value();
+        Box::new(Self::from_reflect(&value).unwrap())
+    }
+
+    fn debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(Set::iter(self)).finish()
+    }
+}
+
+impl<T> FromReflect for BTreeSet<T>
+where
+    T: FromReflect + DescribeType + Ord,
+{
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        reflect
+            .reflect_ref()
+            .as_set()?
+            .iter()
+            .map(T::from_reflect)
+            .collect()
+    }
+}
+
+impl<T> From<BTreeSet<T>> for Value
+where
+    T: Reflect,
+{
+    fn from(set: BTreeSet<T>) -> Self {
+        Value::Set(set.into_iter().map(|value| value.to_value()).collect())
+    }
+}
+
+/// If an element can't be converted the error's [`path`](ReflectError::path) starts with that
+/// element, used as a key.
+impl<T> TryFrom<Value> for BTreeSet<T>
+where
+    T: FromReflect + DescribeType + Ord,
+{
+    type Error = ReflectError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        let Value::Set(set) = value else {
+            return Err(ReflectError::new(
+                core::any::type_name::<Self>(),
+                Some(&value),
+            ));
+        };
+        set.into_iter()
+            .map(|element| {
+                T::try_from_reflect(&element)
+                    .map_err(|error| error.under(&KeyPath::default().get(element)))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,174 @@
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::any::Any;
+
+use crate::array::Array;
+use crate::iter::ValueIterMut;
+use crate::type_info::graph::ListNode;
+use crate::type_info::graph::NodeId;
+use crate::type_info::graph::TypeGraph;
+use crate::DescribeType;
+use crate::FromReflect;
+use crate::List;
+use crate::Reflect;
+use crate::ReflectMut;
+use crate::ReflectOwned;
+use crate::ReflectRef;
+use crate::Value;
+
+// Borrowed slices are copied into an owned `Vec` the first time they're mutated.
+
+impl<T> List for Cow<'static, [T]>
+where
+    T: FromReflect + DescribeType + Clone + Sync,
+{
+    fn push(&mut self, value: &dyn Reflect) {
+        if let Some(value) = T::from_reflect(value) {
+            self.to_mut().push(value);
+        }
+    }
+
+    fn pop(&mut self) -> Option<Box<dyn Reflect>> {
+        let value = self.to_mut().pop()?;
+        Some(Box::new(value))
+    }
+
+    fn try_remove(&mut self, index: usize) -> Option<Box<dyn Reflect>> {
+        if index < self.len() {
+            let value = self.to_mut().remove(index);
+            Some(Box::new(value))
+        } else {
+            None
+        }
+    }
+
+    fn insert(&mut self, index: usize, value: &dyn Reflect) {
+        if let Some(value) = T::from_reflect(value) {
+            self.to_mut().insert(index, value);
+        }
+    }
+
+    fn truncate(&mut self, len: usize) {
+        if len < self.len() {
+            self.to_mut().truncate(len);
+        }
+    }
+
+    fn clear(&mut self) {
+        *self = Cow::Owned(Vec::new());
+    }
+
+    fn retain(&mut self, f: &mut dyn FnMut(&dyn Reflect) -> bool) {
+        self.to_mut().retain(|value| f(value));
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.to_mut().reserve(additional);
+    }
+}
+
+impl<T> Array for Cow<'static, [T]>
+where
+    T: FromReflect + DescribeType + Clone + Sync,
+{
+    fn get(&self, index: usize) -> Option<&dyn Reflect> {
+        self.as_ref().get(index).map(|value| value.as_reflect())
+    }
+
+    fn get_mut(&mut self, index: usize) -> Option<&mut dyn Reflect> {
+        if index >= self.len() {
+            return None;
+        }
+        self.to_mut()
+            .get_mut(index)
+            .map(|value| value.as_reflect_mut())
+    }
+
+    fn len(&self) -> usize {
+        self.as_ref().len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.as_ref().is_empty()
+    }
+
+    fn iter(&self) -> crate::array::Iter<'_> {
+        crate::array::Iter::new(self)
+    }
+
+    fn iter_mut(&mut self) -> ValueIterMut<'_> {
+        let iter = self.to_mut().iter_mut().map(|value| value.as_reflect_mut());
+        Box::new(iter)
+    }
+}
+
+impl<T> DescribeType for Cow<'static, [T]>
+where
+    T: DescribeType + Clone,
+{
+    fn build(graph: &mut TypeGraph) -> NodeId {
+        graph.get_or_build_node_with::<Self, _>(|graph| ListNode::new::<Self, T>(graph))
+    }
+}
+
+impl<T> Reflect for Cow<'static, [T]>
+where
+    T: FromReflect + DescribeType + Clone + Sync,
+{
+    trivial_reflect_methods!();
+
+    fn patch(&mut self, value: &dyn Reflect) {
+        if let Some(list) = value.reflect_ref().as_list() {
+            for (idx, new_value) in list.iter().enumerate() {
+                if let Some(value) = self.get_mut(idx) {
+                    value.patch(new_value);
+                }
+            }
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        let data = self.iter().map(Reflect::to_value).collect();
+        Value::List(data)
+    }
+
+    fn clone_reflect(&self) -> Box<dyn Reflect> {
+        Box::new(self.clone())
+    }
+
+    fn debug(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        ReflectOwned::List(self)
+    }
+
+    fn reflect_ref(&self) -> ReflectRef<'_> {
+        ReflectRef::List(self)
+    }
+
+    fn reflect_mut(&mut self) -> ReflectMut<'_> {
+        ReflectMut::List(self)
+    }
+}
+
+impl<T> FromReflect for Cow<'static, [T]>
+where
+    T: FromReflect + DescribeType + Clone + Sync,
+{
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        Vec::<T>::from_reflect(reflect).map(Cow::Owned)
+    }
+}
+
+impl<T> From<Cow<'static, [T]>> for Value
+where
+    T: Reflect + Clone,
+{
+    fn from(list: Cow<'static, [T]>) -> Self {
+        let list = list.iter().map(|value| value.to_value()).collect();
+        Value::List(list)
+    }
+}
@@ -0,0 +1,114 @@
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use core::any::type_name;
+use core::any::Any;
+use core::fmt;
+
+use crate::tuple::TupleValue;
+use crate::type_info::graph::NodeId;
+use crate::type_info::graph::OpaqueNode;
+use crate::type_info::graph::TypeGraph;
+use crate::DescribeType;
+use crate::FromReflect;
+use crate::Reflect;
+use crate::ReflectMut;
+use crate::ReflectOwned;
+use crate::ReflectRef;
+use crate::Value;
+
+// Function pointers are reflected as opaque types with their signature stored in the `signature`
+// meta. Only safe Rust ABI functions taking up to eight arguments by value are supported, that is
+// `fn(T1, ..., T8) -> R`. Signatures with references such as `fn(&T)`, `unsafe fn`s, and
+// `extern "C" fn`s are not.
+//
+// Function pointers cannot be serialized so `to_value` returns an empty tuple. `FromReflect`ing
+// that gives a placeholder function that panics when called, so types containing function
+// pointers can still be converted back from their own values.
+macro_rules! impl_fn_ptr {
+    ($($ident:ident),* $(,)?) => {
+        impl<$($ident,)* R> DescribeType for fn($($ident),*) -> R
+        where
+            $($ident: 'static,)*
+            R: 'static,
+        {
+            fn build(graph: &mut TypeGraph) -> NodeId {
+                graph.get_or_build_node_with::<Self, _>(|graph| {
+                    OpaqueNode::new::<Self>(
+                        BTreeMap::from([("signature", Value::from(type_name::<Self>()))]),
+                        graph,
+                    )
+                })
+            }
+        }
+
+        impl<$($ident,)* R> Reflect for fn($($ident),*) -> R
+        where
+            $($ident: 'static,)*
+            R: 'static,
+        {
+            trivial_reflect_methods!();
+
+            fn patch(&mut self, value: &dyn Reflect) {
+                if let Some(value) = value.downcast_ref::<Self>() {
+                    *self = *value;
+                }
+            }
+
+            fn to_value(&self) -> Value {
+                TupleValue::new().into()
+            }
+
+            fn clone_reflect(&self) -> Box<dyn Reflect> {
+                Box::new(*self)
+            }
+
+            fn debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}", type_name::<Self>())
+            }
+
+            fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+                ReflectOwned::Opaque(self)
+            }
+
+            fn reflect_ref(&self) -> ReflectRef<'_> {
+                ReflectRef::Opaque(self)
+            }
+
+            fn reflect_mut(&mut self) -> ReflectMut<'_> {
+                ReflectMut::Opaque(self)
+            }
+        }
+
+        impl<$($ident,)* R> FromReflect for fn($($ident),*) -> R
+        where
+            $($ident: 'static,)*
+            R: 'static,
+        {
+            fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+                if let Some(f) = reflect.downcast_ref::<Self>() {
+                    return Some(*f);
+                }
+                if reflect.as_tuple()?.fields_len() != 0 {
+                    return None;
+                }
+                let placeholder: Self = |$(_: $ident),*| {
+                    panic!(
+                        "`{}` was converted from a value and has no function to call",
+                        type_name::<Self>()
+                    )
+                };
+                Some(placeholder)
+            }
+        }
+    };
+}
+
+impl_fn_ptr!();
+impl_fn_ptr!(T1);
+impl_fn_ptr!(T1, T2);
+impl_fn_ptr!(T1, T2, T3);
+impl_fn_ptr!(T1, T2, T3, T4);
+impl_fn_ptr!(T1, T2, T3, T4, T5);
+impl_fn_ptr!(T1, T2, T3, T4, T5, T6);
+impl_fn_ptr!(T1, T2, T3, T4, T5, T6, T7);
+impl_fn_ptr!(T1, T2, T3, T4, T5, T6, T7, T8);
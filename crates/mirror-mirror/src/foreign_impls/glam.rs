@@ -1,4 +1,4 @@
-use glam::{Mat3, Vec2, Vec3};
+use glam::{Affine3A, Mat3, Mat3A, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4};
 use mirror_mirror_macros::__private_derive_reflect_foreign;
 
 __private_derive_reflect_foreign! {
@@ -18,11 +18,232 @@ __private_derive_reflect_foreign! {
     }
 }
 
-// `Vec4`, `Quat`, and `Mat2` are left out because glam uses bad hacks which changes the struct
-// definitions for different architectures (simd vs no simd) and cargo features. So we'd have
-// to use the same hacks in mirror-mirror which I'd like to avoid.
+// `Vec3A`, `Vec4`, and `Quat` are SIMD registers on some architectures and plain structs on
+// others, depending on target and cargo features, so we cannot name their fields in patterns or
+// borrow several of them at once in a way that compiles for both. Instead `Vec3A` and `Vec4` are
+// reflected through their `AsRef`/`AsMut` array views which glam provides everywhere.
 
-// `Mat4` is left out because it contains `Vec4` which we don't support.
+macro_rules! impl_reflect_via_array {
+    ($ty:ident, [$($field:ident),*], $new_fn:expr $(,)?) => {
+        const _: () = {
+            use $crate::__private::*;
+
+            const FIELD_NAMES: &[&str] = &[$(stringify!($field)),*];
+
+            impl DescribeType for $ty {
+                fn build(graph: &mut TypeGraph) -> NodeId {
+                    graph.get_or_build_node_with::<Self, _>(|graph| {
+                        let fields = &[$(
+                            NamedFieldNode::new::<f32>(
+                                stringify!($field),
+                                Default::default(),
+                                &[],
+                                graph,
+                            )
+                        ),*];
+                        StructNode::new::<Self>(fields, Default::default(), &[])
+                    })
+                }
+            }
+
+            impl Reflect for $ty {
+                trivial_reflect_methods!();
+
+                fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+                    ReflectOwned::Struct(self)
+                }
+
+                fn reflect_ref(&self) -> ReflectRef<'_> {
+                    ReflectRef::Struct(self)
+                }
+
+                fn reflect_mut(&mut self) -> ReflectMut<'_> {
+                    ReflectMut::Struct(self)
+                }
+
+                fn patch(&mut self, value: &dyn Reflect) {
+                    if let Some(struct_) = value.reflect_ref().as_struct() {
+                        for (name, value) in self.fields_mut() {
+                            if let Some(new_value) = struct_.field(name) {
+                                value.patch(new_value);
+                            }
+                        }
+                    }
+                }
+
+                fn to_value(&self) -> Value {
+                    let mut value = StructValue::with_capacity(FIELD_NAMES.len())
+                        .with_origin::<Self>();
+                    for (name, field) in FIELD_NAMES.iter().zip(self.as_ref()) {
+                        value.set_field(*name, *field);
+                    }
+                    value.into()
+                }
+
+                fn clone_reflect(&self) -> Box<dyn Reflect> {
+                    Box::new(*self)
+                }
+
+                fn debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    if f.alternate() {
+                        write!(f, "{self:#?}")
+                    } else {
+                        write!(f, "{self:?}")
+                    }
+                }
+            }
+
+            impl Struct for $ty {
+                fn field(&self, name: &str) -> Option<&dyn Reflect> {
+                    let index = FIELD_NAMES.iter().position(|field| *field == name)?;
+                    self.field_at(index)
+                }
+
+                fn field_mut(&mut self, name: &str) -> Option<&mut dyn Reflect> {
+                    let index = FIELD_NAMES.iter().position(|field| *field == name)?;
+                    self.field_at_mut(index)
+                }
+
+                fn field_at(&self, index: usize) -> Option<&dyn Reflect> {
+                    let array: &[f32] = self.as_ref();
+                    Some(array.get(index)?)
+                }
+
+                fn field_at_mut(&mut self, index: usize) -> Option<&mut dyn Reflect> {
+                    let array: &mut [f32] = self.as_mut();
+                    Some(array.get_mut(index)?)
+                }
+
+                fn name_at(&self, index: usize) -> Option<&str> {
+                    FIELD_NAMES.get(index).copied()
+                }
+
+                fn fields(&self) -> $crate::struct_::Iter<'_> {
+                    $crate::struct_::Iter::new(self)
+                }
+
+                fn fields_mut(&mut self) -> PairIterMut<'_> {
+                    let array: &mut [f32] = self.as_mut();
+                    Box::new(
+                        FIELD_NAMES
+                            .iter()
+                            .copied()
+                            .zip(array.iter_mut().map(|field| field as &mut dyn Reflect)),
+                    )
+                }
+
+                fn fields_len(&self) -> usize {
+                    FIELD_NAMES.len()
+                }
+            }
+
+            impl FromReflect for $ty {
+                fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+                    if let Some(value) = reflect.downcast_ref::<Self>() {
+                        return Some(*value);
+                    }
+                    let struct_ = reflect.reflect_ref().as_struct()?;
+                    Some($new_fn($(
+                        f32::from_reflect(struct_.field(stringify!($field))?)?
+                    ),*))
+                }
+            }
+
+            impl From<$ty> for Value {
+                fn from(value: $ty) -> Self {
+                    value.to_value()
+                }
+            }
+        };
+    };
+}
+
+impl_reflect_via_array! { Vec3A, [x, y, z], Vec3A::new }
+impl_reflect_via_array! { Vec4, [x, y, z, w], Vec4::new }
+
+// `Quat` has no mutable array view so its components cannot be borrowed mutably at the same
+// time. It is therefore opaque, both in place and in its descriptor, and converts to and from
+// struct values with `x`, `y`, `z`, and `w` fields. Changing it means building a new value and
+// patching it in, which copies all four components.
+
+const _: () = {
+    use crate::__private::*;
+
+    const FIELD_NAMES: [&str; 4] = ["x", "y", "z", "w"];
+
+    impl DescribeType for Quat {
+        fn build(graph: &mut TypeGraph) -> NodeId {
+            graph.get_or_build_node_with::<Self, _>(|graph| {
+                OpaqueNode::new::<Self>(Default::default(), graph).default_value(Quat::IDENTITY)
+            })
+        }
+    }
+
+    impl Reflect for Quat {
+        trivial_reflect_methods!();
+
+        fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+            ReflectOwned::Opaque(self)
+        }
+
+        fn reflect_ref(&self) -> ReflectRef<'_> {
+            ReflectRef::Opaque(self)
+        }
+
+        fn reflect_mut(&mut self) -> ReflectMut<'_> {
+            ReflectMut::Opaque(self)
+        }
+
+        fn patch(&mut self, value: &dyn Reflect) {
+            if let Some(quat) = Self::from_reflect(value) {
+                *self = quat;
+            }
+        }
+
+        fn to_value(&self) -> Value {
+            let mut value = StructValue::with_capacity(FIELD_NAMES.len()).with_origin::<Self>();
+            for (name, field) in FIELD_NAMES.iter().zip(self.to_array()) {
+                value.set_field(*name, field);
+            }
+            value.into()
+        }
+
+        fn clone_reflect(&self) -> Box<dyn Reflect> {
+            Box::new(*self)
+        }
+
+        fn debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            if f.alternate() {
+                write!(f, "{self:#?}")
+            } else {
+                write!(f, "{self:?}")
+            }
+        }
+    }
+
+    impl FromReflect for Quat {
+        fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+            if let Some(quat) = reflect.downcast_ref::<Self>() {
+                return Some(*quat);
+            }
+            let struct_ = reflect.reflect_ref().as_struct()?;
+            let [x, y, z, w] = [
+                struct_.field("x")?,
+                struct_.field("y")?,
+                struct_.field("z")?,
+                struct_.field("w")?,
+            ]
+            .map(f32::from_reflect);
+            Some(Self::from_xyzw(x?, y?, z?, w?))
+        }
+    }
+
+    impl From<Quat> for Value {
+        fn from(quat: Quat) -> Self {
+            quat.to_value()
+        }
+    }
+};
 
 __private_derive_reflect_foreign! {
     #[reflect(crate_name(crate))]
@@ -32,3 +253,30 @@ __private_derive_reflect_foreign! {
         pub z_axis: Vec3,
     }
 }
+
+__private_derive_reflect_foreign! {
+    #[reflect(crate_name(crate))]
+    pub struct Mat3A {
+        pub x_axis: Vec3A,
+        pub y_axis: Vec3A,
+        pub z_axis: Vec3A,
+    }
+}
+
+__private_derive_reflect_foreign! {
+    #[reflect(crate_name(crate))]
+    pub struct Mat4 {
+        pub x_axis: Vec4,
+        pub y_axis: Vec4,
+        pub z_axis: Vec4,
+        pub w_axis: Vec4,
+    }
+}
+
+__private_derive_reflect_foreign! {
+    #[reflect(crate_name(crate))]
+    pub struct Affine3A {
+        pub matrix3: Mat3A,
+        pub translation: Vec3A,
+    }
+}
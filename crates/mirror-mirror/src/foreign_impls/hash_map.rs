@@ -0,0 +1,484 @@
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::any::Any;
+use core::fmt;
+use core::hash::BuildHasher;
+use core::hash::Hash;
+use core::ops::Deref;
+use core::ops::DerefMut;
+use std::collections::hash_map;
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+
+use crate::iter::IntoPairIter;
+use crate::iter::PairIterMut;
+use crate::key_path::KeyPath;
+use crate::map;
+use crate::map::MapEntry;
+use crate::map::VacantEntry;
+use crate::type_info::graph::MapNode;
+use crate::type_info::graph::NodeId;
+use crate::type_info::graph::TypeGraph;
+use crate::DescribeType;
+use crate::FromReflect;
+use crate::Map;
+use crate::Reflect;
+use crate::ReflectError;
+use crate::ReflectMut;
+use crate::ReflectOwned;
+use crate::ReflectRef;
+use crate::Value;
+
+impl<K, V, S> Map for HashMap<K, V, S>
+where
+    K: FromReflect + DescribeType + Hash + Eq,
+    V: FromReflect + DescribeType,
+    S: BuildHasher + Default + Send + 'static,
+{
+    fn get(&self, key: &dyn Reflect) -> Option<&dyn Reflect> {
+        if let Some(key) = key.downcast_ref::<K>() {
+            return Some(HashMap::get(self, key)?.as_reflect());
+        }
+        if let (Some(map), Some(key)) = (
+            self.as_any().downcast_ref::<HashMap<String, V, S>>(),
+            map::key_as_str(key),
+        ) {
+            return Some(map.get(key)?.as_reflect());
+        }
+        let value = HashMap::get(self, &K::from_reflect(key)?)?;
+        Some(value.as_reflect())
+    }
+
+    fn get_mut(&mut self, key: &dyn Reflect) -> Option<&mut dyn Reflect> {
+        if let Some(key) = key.downcast_ref::<K>() {
+            return Some(HashMap::get_mut(self, key)?.as_reflect_mut());
+        }
+        if let Some(key) = map::key_as_str(key) {
+            if self.as_any().is::<HashMap<String, V, S>>() {
+                let map = self.as_any_mut().downcast_mut::<HashMap<String, V, S>>()?;
+                return Some(map.get_mut(key)?.as_reflect_mut());
+            }
+        }
+        let value = HashMap::get_mut(self, &K::from_reflect(key)?)?;
+        Some(value.as_reflect_mut())
+    }
+
+    fn insert(&mut self, key: &dyn Reflect, value: &dyn Reflect) -> Option<Box<dyn Reflect>> {
+        let key = K::from_reflect(key)?;
+        let value = V::from_reflect(value)?;
+        let previous = HashMap::insert(self, key, value)?;
+        Some(Box::new(previous))
+    }
+
+    fn try_insert(
+        &mut self,
+        key: &dyn Reflect,
+        value: &dyn Reflect,
+    ) -> Result<Option<Box<dyn Reflect>>, ReflectError> {
+        let value = V::try_from_reflect(value)
+            .map_err(|error| error.under(&KeyPath::default().get(key.to_value())))?;
+        let key = K::try_from_reflect(key)?;
+        let previous = HashMap::insert(self, key, value);
+        Ok(previous.map(|previous| Box::new(previous) as _))
+    }
+
+    fn drain(&mut self) -> IntoPairIter<'_, Box<dyn Reflect>> {
+        let iter =
+            HashMap::drain(self).map(|(key, value)| (Box::new(key) as _, Box::new(value) as _));
+        Box::new(iter)
+    }
+
+    fn into_entries(self: Box<Self>) -> IntoPairIter<'static, Box<dyn Reflect>> {
+        let iter = (*self)
+            .into_iter()
+            .map(|(key, value)| (Box::new(key) as _, Box::new(value) as _));
+        Box::new(iter)
+    }
+
+    fn entry(&mut self, key: &dyn Reflect) -> MapEntry<'_> {
+        let Some(key) = K::from_reflect(key) else {
+            return MapEntry::Vacant(VacantEntry::new(|_| None));
+        };
+        match HashMap::entry(self, key) {
+            hash_map::Entry::Occupied(entry) => {
+                MapEntry::Occupied(entry.into_mut().as_reflect_mut())
+            }
+            hash_map::Entry::Vacant(entry) => MapEntry::Vacant(VacantEntry::new(move |value| {
+                let value = V::from_reflect(value)?;
+                Some(entry.insert(value).as_reflect_mut())
+            })),
+        }
+    }
+
+    fn remove(&mut self, key: &dyn Reflect) -> Option<Box<dyn Reflect>> {
+        let key = K::from_reflect(key)?;
+        let previous = HashMap::remove(self, &key)?;
+        Some(Box::new(previous))
+    }
+
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.is_empty()
+    }
+
+    /// Iterates in an unstable order, which can differ between maps with the same entries. See
+    /// [`SortedHashMap`] for a map that iterates in order of its keys.
+    fn iter(&self) -> crate::map::Iter<'_> {
+        let iter = self
+            .iter()
+            .map(|(key, value)| (key.as_reflect(), value.as_reflect()));
+        Box::new(iter)
+    }
+
+    fn iter_mut(&mut self) -> PairIterMut<'_, dyn Reflect> {
+        let iter = self
+            .iter_mut()
+            .map(|(key, value)| (key.as_reflect(), value.as_reflect_mut()));
+        Box::new(iter)
+    }
+}
+
+impl<K, V, S> DescribeType for HashMap<K, V, S>
+where
+    K: DescribeType,
+    V: DescribeType,
+    S: 'static,
+{
+    fn build(graph: &mut TypeGraph) -> NodeId {
+        graph.get_or_build_node_with::<Self, _>(|graph| MapNode::new::<Self, K, V>(graph))
+    }
+}
+
+impl<K, V, S> Reflect for HashMap<K, V, S>
+where
+    K: FromReflect + DescribeType + Hash + Eq,
+    V: FromReflect + DescribeType,
+    S: BuildHasher + Default + Send + 'static,
+{
+    trivial_reflect_methods!();
+
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        ReflectOwned::Map(self)
+    }
+
+    fn reflect_ref(&self) -> ReflectRef<'_> {
+        ReflectRef::Map(self)
+    }
+
+    fn reflect_mut(&mut self) -> ReflectMut<'_> {
+        ReflectMut::Map(self)
+    }
+
+    fn patch(&mut self, value: &dyn Reflect) {
+        if let Some(map) = value.reflect_ref().as_map() {
+            for (key, new_value) in map.iter() {
+                if let Some(value) = Map::get_mut(self, key) {
+                    value.patch(new_value);
+                }
+            }
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        // `Value::Map` is ordered by key, so values don't depend on the iteration order
+        let data = self
+            .iter()
+            .map(|(key, value)| (key.to_value(), value.to_value()))
+            .collect();
+        Value::Map(data)
+    }
+
+    fn clone_reflect(&self) -> Box<dyn Reflect> {
+        let value = self.to_value();
+        Box::new(Self::from_reflect(&value).unwrap())
+    }
+
+    fn debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(Map::iter(self)).finish()
+    }
+}
+
+impl<K, V, S> FromReflect for HashMap<K, V, S>
+where
+    K: FromReflect + DescribeType + Hash + Eq,
+    V: FromReflect + DescribeType,
+    S: BuildHasher + Default + Send + 'static,
+{
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        let map = reflect.as_reflect().as_map()?;
+        let mut out = HashMap::with_capacity_and_hasher(map.len(), S::default());
+        for (key, value) in map.iter() {
+            out.insert(K::from_reflect(key)?, V::from_reflect(value)?);
+        }
+        Some(out)
+    }
+}
+
+impl<K, V, S> From<HashMap<K, V, S>> for Value
+where
+    K: Reflect,
+    V: Reflect,
+{
+    fn from(map: HashMap<K, V, S>) -> Self {
+        let map = map
+            .into_iter()
+            .map(|(key, value)| (key.to_value(), value.to_value()))
+            .collect();
+        Value::Map(map)
+    }
+}
+
+/// A [`HashMap`] whose reflected entries are iterated in order of their keys.
+///
+/// Reflecting a plain `HashMap` iterates its entries in an unstable order, so things like
+/// [`Map::iter`], [`visit::walk`], and debug output can differ between runs and between maps with
+/// the same entries. This wrapper sorts the entries whenever they're iterated through reflection,
+/// which costs an allocation and a sort per iteration.
+///
+/// Derefs to the `HashMap` so it can otherwise be used like one.
+///
+/// # Example
+///
+/// ```
+/// use std::collections::HashMap;
+/// use mirror_mirror::{Map, Reflect};
+/// use mirror_mirror::map::SortedHashMap;
+///
+/// let map = SortedHashMap::from(HashMap::from([(3, true), (1, false), (2, true)]));
+///
+/// let keys = Map::iter(&map)
+///     .map(|(key, _)| *key.downcast_ref::<i32>().unwrap())
+///     .collect::<Vec<_>>();
+/// assert_eq!(keys, [1, 2, 3]);
+/// ```
+///
+/// [`visit::walk`]: crate::visit::walk
+#[derive(Clone)]
+pub struct SortedHashMap<K, V, S = RandomState>(pub HashMap<K, V, S>);
+
+impl<K, V, S> SortedHashMap<K, V, S> {
+    /// Returns the wrapped `HashMap`.
+    pub fn into_inner(self) -> HashMap<K, V, S> {
+        self.0
+    }
+}
+
+impl<K, V, S> Default for SortedHashMap<K, V, S>
+where
+    S: Default,
+{
+    fn default() -> Self {
+        Self(HashMap::default())
+    }
+}
+
+impl<K, V, S> PartialEq for SortedHashMap<K, V, S>
+where
+    K: Eq + Hash,
+    V: PartialEq,
+    S: BuildHasher,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<K, V, S> Eq for SortedHashMap<K, V, S>
+where
+    K: Eq + Hash,
+    V: Eq,
+    S: BuildHasher,
+{
+}
+
+impl<K, V, S> Deref for SortedHashMap<K, V, S> {
+    type Target = HashMap<K, V, S>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<K, V, S> DerefMut for SortedHashMap<K, V, S> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<K, V, S> From<HashMap<K, V, S>> for SortedHashMap<K, V, S> {
+    fn from(map: HashMap<K, V, S>) -> Self {
+        Self(map)
+    }
+}
+
+impl<K, V, S> fmt::Debug for SortedHashMap<K, V, S>
+where
+    K: fmt::Debug + Ord,
+    V: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(sorted(self.0.iter())).finish()
+    }
+}
+
+fn sorted<K, T>(entries: impl Iterator<Item = (K, T)>) -> Vec<(K, T)>
+where
+    K: Ord,
+{
+    let mut entries = entries.collect::<Vec<_>>();
+    entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+    entries
+}
+
+impl<K, V, S> Map for SortedHashMap<K, V, S>
+where
+    K: FromReflect + DescribeType + Hash + Ord,
+    V: FromReflect + DescribeType,
+    S: BuildHasher + Default + Send + 'static,
+{
+    fn get(&self, key: &dyn Reflect) -> Option<&dyn Reflect> {
+        Map::get(&self.0, key)
+    }
+
+    fn get_mut(&mut self, key: &dyn Reflect) -> Option<&mut dyn Reflect> {
+        Map::get_mut(&mut self.0, key)
+    }
+
+    fn get_str(&self, key: &str) -> Option<&dyn Reflect> {
+        Map::get_str(&self.0, key)
+    }
+
+    fn get_str_mut(&mut self, key: &str) -> Option<&mut dyn Reflect> {
+        Map::get_str_mut(&mut self.0, key)
+    }
+
+    fn insert(&mut self, key: &dyn Reflect, value: &dyn Reflect) -> Option<Box<dyn Reflect>> {
+        Map::insert(&mut self.0, key, value)
+    }
+
+    fn try_insert(
+        &mut self,
+        key: &dyn Reflect,
+        value: &dyn Reflect,
+    ) -> Result<Option<Box<dyn Reflect>>, ReflectError> {
+        Map::try_insert(&mut self.0, key, value)
+    }
+
+    fn drain(&mut self) -> IntoPairIter<'_, Box<dyn Reflect>> {
+        let iter = sorted(self.0.drain())
+            .into_iter()
+            .map(|(key, value)| (Box::new(key) as _, Box::new(value) as _));
+        Box::new(iter)
+    }
+
+    fn into_entries(self: Box<Self>) -> IntoPairIter<'static, Box<dyn Reflect>> {
+        let iter = sorted(self.0.into_iter())
+            .into_iter()
+            .map(|(key, value)| (Box::new(key) as _, Box::new(value) as _));
+        Box::new(iter)
+    }
+
+    fn entry(&mut self, key: &dyn Reflect) -> MapEntry<'_> {
+        Map::entry(&mut self.0, key)
+    }
+
+    fn remove(&mut self, key: &dyn Reflect) -> Option<Box<dyn Reflect>> {
+        Map::remove(&mut self.0, key)
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn iter(&self) -> crate::map::Iter<'_> {
+        let iter = sorted(self.0.iter())
+            .into_iter()
+            .map(|(key, value)| (key.as_reflect(), value.as_reflect()));
+        Box::new(iter)
+    }
+
+    fn iter_mut(&mut self) -> PairIterMut<'_, dyn Reflect> {
+        let iter = sorted(self.0.iter_mut())
+            .into_iter()
+            .map(|(key, value)| (key.as_reflect(), value.as_reflect_mut()));
+        Box::new(iter)
+    }
+}
+
+impl<K, V, S> DescribeType for SortedHashMap<K, V, S>
+where
+    K: DescribeType,
+    V: DescribeType,
+    S: 'static,
+{
+    fn build(graph: &mut TypeGraph) -> NodeId {
+        graph.get_or_build_node_with::<Self, _>(|graph| MapNode::new::<Self, K, V>(graph))
+    }
+}
+
+impl<K, V, S> Reflect for SortedHashMap<K, V, S>
+where
+    K: FromReflect + DescribeType + Hash + Ord,
+    V: FromReflect + DescribeType,
+    S: BuildHasher + Default + Send + 'static,
+{
+    trivial_reflect_methods!();
+
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        ReflectOwned::Map(self)
+    }
+
+    fn reflect_ref(&self) -> ReflectRef<'_> {
+        ReflectRef::Map(self)
+    }
+
+    fn reflect_mut(&mut self) -> ReflectMut<'_> {
+        ReflectMut::Map(self)
+    }
+
+    fn patch(&mut self, value: &dyn Reflect) {
+        self.0.patch(value);
+    }
+
+    fn to_value(&self) -> Value {
+        self.0.to_value()
+    }
+
+    fn clone_reflect(&self) -> Box<dyn Reflect> {
+        let value = self.to_value();
+        Box::new(Self::from_reflect(&value).unwrap())
+    }
+
+    fn debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(Map::iter(self)).finish()
+    }
+}
+
+impl<K, V, S> FromReflect for SortedHashMap<K, V, S>
+where
+    K: FromReflect + DescribeType + Hash + Ord,
+    V: FromReflect + DescribeType,
+    S: BuildHasher + Default + Send + 'static,
+{
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        HashMap::from_reflect(reflect).map(Self)
+    }
+}
+
+impl<K, V, S> From<SortedHashMap<K, V, S>> for Value
+where
+    K: Reflect,
+    V: Reflect,
+{
+    fn from(map: SortedHashMap<K, V, S>) -> Self {
+        map.0.into()
+    }
+}
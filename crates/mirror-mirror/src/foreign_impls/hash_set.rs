@@ -0,0 +1,128 @@
+use alloc::boxed::Box;
+use core::any::Any;
+use core::fmt;
+use core::hash::BuildHasher;
+use core::hash::Hash;
+use std::collections::HashSet;
+
+use crate::type_info::graph::NodeId;
+use crate::type_info::graph::SetNode;
+use crate::type_info::graph::TypeGraph;
+use crate::DescribeType;
+use crate::FromReflect;
+use crate::Reflect;
+use crate::ReflectMut;
+use crate::ReflectOwned;
+use crate::ReflectRef;
+use crate::Set;
+use crate::Value;
+
+impl<T, S> Set for HashSet<T, S>
+where
+    T: FromReflect + DescribeType + Hash + Eq,
+    S: BuildHasher + Default + Send + 'static,
+{
+    fn contains(&self, value: &dyn Reflect) -> bool {
+        if let Some(value) = value.downcast_ref::<T>() {
+            return HashSet::contains(self, value);
+        }
+        T::from_reflect(value).map_or(false, |value| HashSet::contains(self, &value))
+    }
+
+    fn insert(&mut self, value: &dyn Reflect) -> bool {
+        T::from_reflect(value).map_or(false, |value| HashSet::insert(self, value))
+    }
+
+    fn remove(&mut self, value: &dyn Reflect) -> bool {
+        if let Some(value) = value.downcast_ref::<T>() {
+            return HashSet::remove(self, value);
+        }
+        T::from_reflect(value).map_or(false, |value| HashSet::remove(self, &value))
+    }
+
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.is_empty()
+    }
+
+    fn iter(&self) -> crate::set::Iter<'_> {
+        Box::new(self.iter().map(|value| value.as_reflect()))
+    }
+}
+
+impl<T, S> DescribeType for HashSet<T, S>
+where
+    T: DescribeType,
+    S: 'static,
+{
+    fn build(graph: &mut TypeGraph) -> NodeId {
+        graph.get_or_build_node_with::<Self, _>(|graph| SetNode::new::<Self, T>(graph))
+    }
+}
+
+impl<T, S> Reflect for HashSet<T, S>
+where
+    T: FromReflect + DescribeType + Hash + Eq,
+    S: BuildHasher + Default + Send + 'static,
+{
+    trivial_reflect_methods!();
+
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        ReflectOwned::Set(self)
+    }
+
+    fn reflect_ref(&self) -> ReflectRef<'_> {
+        ReflectRef::Set(self)
+    }
+
+    fn reflect_mut(&mut self) -> ReflectMut<'_> {
+        ReflectMut::Set(self)
+    }
+
+    fn patch(&mut self, value: &dyn Reflect) {
+        // elements cannot be patched in place, so patching replaces the whole set
+        if let Some(new_value) = Self::from_reflect(value) {
+            *self = new_value;
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        Value::Set(self.iter().map(Reflect::to_value).collect())
+    }
+
+    fn clone_reflect(&self) -> Box<dyn Reflect> {
+        let value = self.to_value();
+        Box::new(Self::from_reflect(&value).unwrap())
+    }
+
+    fn debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(Set::iter(self)).finish()
+    }
+}
+
+impl<T, S> FromReflect for HashSet<T, S>
+where
+    T: FromReflect + DescribeType + Hash + Eq,
+    S: BuildHasher + Default + Send + 'static,
+{
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        reflect
+            .reflect_ref()
+            .as_set()?
+            .iter()
+            .map(T::from_reflect)
+            .collect()
+    }
+}
+
+impl<T, S> From<HashSet<T, S>> for Value
+where
+    T: Reflect,
+{
+    fn from(set: HashSet<T, S>) -> Self {
+        Value::Set(set.into_iter().map(|value| value.to_value()).collect())
+    }
+}
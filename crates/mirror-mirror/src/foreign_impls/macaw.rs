@@ -1,7 +1,144 @@
+use glam::Quat;
+use glam::Vec3;
+use glam::Vec3A;
+use glam::Vec4;
+use macaw::BoundingBox;
 use macaw::ColorRgba8;
+use macaw::Conformal3;
+use macaw::IsoTransform;
+use macaw::Plane3;
 use mirror_mirror_macros::__private_derive_reflect_foreign;
 
+use crate::FromReflect;
+use crate::Reflect;
+
+// Besides the struct values produced by `to_value`, `FromReflect` for these types also accepts
+// plain arrays of numbers in place of the glam types and of the types themselves, since that is
+// how they are commonly written by hand in config files.
+
 __private_derive_reflect_foreign! {
-    #[reflect(crate_name(crate))]
+    #[reflect(crate_name(crate), opt_out(FromReflect))]
     pub struct ColorRgba8(pub [u8; 4]);
 }
+
+impl FromReflect for ColorRgba8 {
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        if let Some(color) = reflect.downcast_ref::<Self>() {
+            return Some(*color);
+        }
+        if let Some(tuple_struct) = reflect.reflect_ref().as_tuple_struct() {
+            return Some(Self(FromReflect::from_reflect(tuple_struct.field_at(0)?)?));
+        }
+        Some(Self(FromReflect::from_reflect(reflect)?))
+    }
+}
+
+__private_derive_reflect_foreign! {
+    #[reflect(crate_name(crate), opt_out(FromReflect))]
+    pub struct BoundingBox {
+        pub min: Vec3,
+        pub max: Vec3,
+    }
+}
+
+impl FromReflect for BoundingBox {
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        if let Some(bounding_box) = reflect.downcast_ref::<Self>() {
+            return Some(*bounding_box);
+        }
+        if let Some(struct_) = reflect.reflect_ref().as_struct() {
+            return Some(Self {
+                min: vec3(struct_.field("min")?)?,
+                max: vec3(struct_.field("max")?)?,
+            });
+        }
+        let [min, max] = <[[f32; 3]; 2]>::from_reflect(reflect)?;
+        Some(Self {
+            min: Vec3::from(min),
+            max: Vec3::from(max),
+        })
+    }
+}
+
+__private_derive_reflect_foreign! {
+    #[reflect(crate_name(crate), opt_out(FromReflect))]
+    pub struct Conformal3 {
+        pub translation_and_scale: Vec4,
+        pub rotation: Quat,
+    }
+}
+
+impl FromReflect for Conformal3 {
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        if let Some(conformal) = reflect.downcast_ref::<Self>() {
+            return Some(*conformal);
+        }
+        let struct_ = reflect.reflect_ref().as_struct()?;
+        Some(Self {
+            translation_and_scale: vec4(struct_.field("translation_and_scale")?)?,
+            rotation: quat(struct_.field("rotation")?)?,
+        })
+    }
+}
+
+__private_derive_reflect_foreign! {
+    #[reflect(crate_name(crate), opt_out(FromReflect))]
+    pub struct IsoTransform {
+        pub rotation: Quat,
+        pub translation: Vec3A,
+    }
+}
+
+impl FromReflect for IsoTransform {
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        if let Some(transform) = reflect.downcast_ref::<Self>() {
+            return Some(*transform);
+        }
+        let struct_ = reflect.reflect_ref().as_struct()?;
+        Some(Self {
+            rotation: quat(struct_.field("rotation")?)?,
+            translation: vec3(struct_.field("translation")?)?.into(),
+        })
+    }
+}
+
+__private_derive_reflect_foreign! {
+    #[reflect(crate_name(crate), opt_out(FromReflect))]
+    pub struct Plane3 {
+        pub normal: Vec3,
+        pub d: f32,
+    }
+}
+
+impl FromReflect for Plane3 {
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        if let Some(plane) = reflect.downcast_ref::<Self>() {
+            return Some(*plane);
+        }
+        if let Some(struct_) = reflect.reflect_ref().as_struct() {
+            return Some(Self {
+                normal: vec3(struct_.field("normal")?)?,
+                d: f32::from_reflect(struct_.field("d")?)?,
+            });
+        }
+        let [x, y, z, d] = <[f32; 4]>::from_reflect(reflect)?;
+        Some(Self {
+            normal: Vec3::new(x, y, z),
+            d,
+        })
+    }
+}
+
+fn vec3(reflect: &dyn Reflect) -> Option<Vec3> {
+    Vec3::from_reflect(reflect)
+        .or_else(|| Vec3A::from_reflect(reflect).map(Vec3::from))
+        .or_else(|| <[f32; 3]>::from_reflect(reflect).map(Vec3::from))
+}
+
+fn vec4(reflect: &dyn Reflect) -> Option<Vec4> {
+    Vec4::from_reflect(reflect).or_else(|| <[f32; 4]>::from_reflect(reflect).map(Vec4::from))
+}
+
+fn quat(reflect: &dyn Reflect) -> Option<Quat> {
+    Quat::from_reflect(reflect).or_else(|| <[f32; 4]>::from_reflect(reflect).map(Quat::from_array))
+}
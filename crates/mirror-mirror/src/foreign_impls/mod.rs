@@ -1,23 +1,42 @@
 use core::convert::Infallible;
+use core::num::FpCategory;
+use core::num::Wrapping;
+use core::ops::Bound;
 use core::ops::Range;
 use core::ops::RangeFrom;
 use core::ops::RangeFull;
 use core::ops::RangeTo;
 use core::ops::RangeToInclusive;
 
-use crate::__private::*;
 use mirror_mirror_macros::__private_derive_reflect_foreign;
 
 mod array;
 mod boxed;
 mod btree_map;
+mod btree_set;
+mod cow;
+mod fn_ptr;
+mod range_inclusive;
+mod reference;
 mod vec;
+mod vec_deque;
 mod via_scalar;
+mod via_string;
 
+#[cfg(feature = "arrayvec")]
+mod arrayvec;
 #[cfg(feature = "glam")]
 mod glam;
+#[cfg(feature = "std")]
+pub(crate) mod hash_map;
+#[cfg(feature = "std")]
+mod hash_set;
 #[cfg(feature = "macaw")]
 mod macaw;
+#[cfg(feature = "smallvec")]
+mod smallvec;
+#[cfg(feature = "tinyvec")]
+mod tinyvec;
 
 __private_derive_reflect_foreign! {
     #[reflect(opt_out(Clone, Debug), crate_name(crate))]
@@ -88,119 +107,37 @@ __private_derive_reflect_foreign! {
     }
 }
 
-impl DescribeType for Infallible {
-    fn build(graph: &mut TypeGraph) -> NodeId {
-        let variants = &[];
-        graph.get_or_build_node_with::<Self, _>(|_graph| {
-            EnumNode::new::<Self>(variants, BTreeMap::from([]), &[])
-        })
-    }
-}
-
-impl Reflect for Infallible {
-    fn as_any(&self) -> &dyn Any {
-        match *self {}
-    }
-
-    fn as_any_mut(&mut self) -> &mut dyn Any {
-        match *self {}
-    }
-
-    fn as_reflect(&self) -> &dyn Reflect {
-        match *self {}
-    }
-
-    fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
-        match *self {}
-    }
-
-    fn type_descriptor(&self) -> Cow<'static, TypeDescriptor> {
-        <Self as DescribeType>::type_descriptor()
-    }
-
-    fn patch(&mut self, _value: &dyn Reflect) {
-        match *self {}
-    }
-
-    fn to_value(&self) -> Value {
-        match *self {}
-    }
-
-    fn clone_reflect(&self) -> Box<dyn Reflect> {
-        match *self {}
-    }
-
-    fn debug(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match *self {}
-    }
-
-    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
-        match *self {}
-    }
-
-    fn reflect_ref(&self) -> ReflectRef<'_> {
-        match *self {}
-    }
-
-    fn reflect_mut(&mut self) -> ReflectMut<'_> {
-        match *self {}
+__private_derive_reflect_foreign! {
+    #[reflect(opt_out(Clone, Debug), crate_name(crate))]
+    enum Bound<T>
+    where
+        T: FromReflect + DescribeType,
+    {
+        Included(T),
+        Excluded(T),
+        Unbounded,
     }
 }
 
-impl FromReflect for Infallible {
-    fn from_reflect(_reflect: &dyn Reflect) -> Option<Self> {
-        None
-    }
+__private_derive_reflect_foreign! {
+    #[reflect(crate_name(crate))]
+    enum Infallible {}
 }
 
-impl Enum for Infallible {
-    fn variant_name(&self) -> &str {
-        match *self {}
-    }
-
-    fn variant_kind(&self) -> VariantKind {
-        match *self {}
-    }
-
-    fn field(&self, _name: &str) -> Option<&dyn Reflect> {
-        match *self {}
-    }
-
-    fn field_mut(&mut self, _name: &str) -> Option<&mut dyn Reflect> {
-        match *self {}
-    }
-
-    fn field_at(&self, _index: usize) -> Option<&dyn Reflect> {
-        match *self {}
-    }
-
-    fn field_at_mut(&mut self, _index: usize) -> Option<&mut dyn Reflect> {
-        match *self {}
-    }
-
-    fn fields(&self) -> crate::enum_::VariantFieldIter<'_> {
-        match *self {}
-    }
-
-    fn fields_mut(&mut self) -> VariantFieldIterMut<'_> {
-        match *self {}
-    }
-
-    fn variants_len(&self) -> usize {
-        match *self {}
-    }
-
-    fn fields_len(&self) -> usize {
-        match *self {}
-    }
-
-    fn name_at(&self, _index: usize) -> Option<&str> {
-        match *self {}
-    }
+__private_derive_reflect_foreign! {
+    #[reflect(opt_out(Clone, Debug), crate_name(crate))]
+    struct Wrapping<T>(T)
+    where
+        T: FromReflect + DescribeType;
 }
 
-impl From<Infallible> for Value {
-    fn from(value: Infallible) -> Value {
-        match value {}
+__private_derive_reflect_foreign! {
+    #[reflect(crate_name(crate))]
+    enum FpCategory {
+        Nan,
+        Infinite,
+        Zero,
+        Subnormal,
+        Normal,
     }
 }
@@ -0,0 +1,168 @@
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use core::any::Any;
+use core::fmt;
+use core::iter;
+use core::ops::RangeInclusive;
+
+use crate::iter::PairIterMut;
+use crate::reflect_debug;
+use crate::struct_::Iter;
+use crate::struct_::StructValue;
+use crate::type_info::graph::NamedFieldNode;
+use crate::type_info::graph::NodeId;
+use crate::type_info::graph::StructNode;
+use crate::type_info::graph::TypeGraph;
+use crate::DescribeType;
+use crate::FromReflect;
+use crate::Reflect;
+use crate::ReflectMut;
+use crate::ReflectOwned;
+use crate::ReflectRef;
+use crate::Struct;
+use crate::Value;
+
+// `RangeInclusive` has private fields so it cannot use `__private_derive_reflect_foreign!` like
+// the other range types. It is reflected as a struct with `start` and `end` fields, but since
+// there is no way to get mutable references to those, `field_mut` and friends return nothing and
+// patching instead rebuilds the range with `RangeInclusive::new`.
+
+impl<Idx> DescribeType for RangeInclusive<Idx>
+where
+    Idx: DescribeType,
+{
+    fn build(graph: &mut TypeGraph) -> NodeId {
+        graph.get_or_build_node_with::<Self, _>(|graph| {
+            let fields = &[
+                NamedFieldNode::new::<Idx>("start", BTreeMap::new(), &[], graph),
+                NamedFieldNode::new::<Idx>("end", BTreeMap::new(), &[], graph),
+            ];
+            StructNode::new::<Self>(fields, BTreeMap::new(), &[])
+        })
+    }
+}
+
+impl<Idx> Reflect for RangeInclusive<Idx>
+where
+    Idx: FromReflect + DescribeType,
+{
+    trivial_reflect_methods!();
+
+    fn patch(&mut self, value: &dyn Reflect) {
+        let Some(value) = value.reflect_ref().as_struct() else {
+            return;
+        };
+        let (Some(mut start), Some(mut end)) = (
+            Idx::from_reflect(self.start()),
+            Idx::from_reflect(self.end()),
+        ) else {
+            return;
+        };
+        if let Some(new_start) = value.field("start") {
+            start.patch(new_start);
+        }
+        if let Some(new_end) = value.field("end") {
+            end.patch(new_end);
+        }
+        *self = RangeInclusive::new(start, end);
+    }
+
+    fn to_value(&self) -> Value {
+        StructValue::with_capacity(2)
+            .with_origin::<Self>()
+            .with_field("start", self.start().to_value())
+            .with_field("end", self.end().to_value())
+            .into()
+    }
+
+    fn clone_reflect(&self) -> Box<dyn Reflect> {
+        let value = self.to_value();
+        Box::new(Self::from_reflect(&value).unwrap())
+    }
+
+    fn debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        reflect_debug(self, f)
+    }
+
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        ReflectOwned::Struct(self)
+    }
+
+    fn reflect_ref(&self) -> ReflectRef<'_> {
+        ReflectRef::Struct(self)
+    }
+
+    fn reflect_mut(&mut self) -> ReflectMut<'_> {
+        ReflectMut::Struct(self)
+    }
+}
+
+impl<Idx> FromReflect for RangeInclusive<Idx>
+where
+    Idx: FromReflect + DescribeType,
+{
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        let struct_ = reflect.reflect_ref().as_struct()?;
+        let start = Idx::from_reflect(struct_.field("start")?)?;
+        let end = Idx::from_reflect(struct_.field("end")?)?;
+        Some(RangeInclusive::new(start, end))
+    }
+}
+
+impl<Idx> Struct for RangeInclusive<Idx>
+where
+    Idx: FromReflect + DescribeType,
+{
+    fn field(&self, name: &str) -> Option<&dyn Reflect> {
+        match name {
+            "start" => Some(self.start()),
+            "end" => Some(self.end()),
+            _ => None,
+        }
+    }
+
+    fn field_mut(&mut self, _name: &str) -> Option<&mut dyn Reflect> {
+        None
+    }
+
+    fn field_at(&self, index: usize) -> Option<&dyn Reflect> {
+        match index {
+            0 => Some(self.start()),
+            1 => Some(self.end()),
+            _ => None,
+        }
+    }
+
+    fn field_at_mut(&mut self, _index: usize) -> Option<&mut dyn Reflect> {
+        None
+    }
+
+    fn name_at(&self, index: usize) -> Option<&str> {
+        match index {
+            0 => Some("start"),
+            1 => Some("end"),
+            _ => None,
+        }
+    }
+
+    fn fields(&self) -> Iter<'_> {
+        Iter::new(self)
+    }
+
+    fn fields_mut(&mut self) -> PairIterMut<'_> {
+        Box::new(iter::empty())
+    }
+
+    fn fields_len(&self) -> usize {
+        2
+    }
+}
+
+impl<Idx> From<RangeInclusive<Idx>> for Value
+where
+    Idx: FromReflect + DescribeType,
+{
+    fn from(range: RangeInclusive<Idx>) -> Self {
+        range.to_value()
+    }
+}
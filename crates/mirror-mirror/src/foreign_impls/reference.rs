@@ -0,0 +1,84 @@
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use core::any::type_name;
+use core::any::Any;
+use core::fmt;
+
+use crate::type_info::graph::NodeId;
+use crate::type_info::graph::OpaqueNode;
+use crate::type_info::graph::TypeGraph;
+use crate::DescribeType;
+use crate::FromReflect;
+use crate::Reflect;
+use crate::ReflectMut;
+use crate::ReflectOwned;
+use crate::ReflectRef;
+use crate::Value;
+
+// `&'static` references, as found in baked static data tables, are reflected as read-only opaque
+// leaves. The type info carries `read_only = true` and the name of the referenced type in the
+// `referent` meta. `to_value` returns the value of the referenced data, but since there is no way
+// to get a `'static` reference back from a `Value`, `FromReflect` only succeeds when given the
+// reference itself. Patching is a no-op.
+//
+// `&'static str` is not covered since `&str` has dedicated `GetField` and `IntoValue` impls that
+// would overlap with those for `Reflect` types.
+
+impl<T> DescribeType for &'static T
+where
+    T: Reflect + DescribeType + Sync,
+{
+    fn build(graph: &mut TypeGraph) -> NodeId {
+        graph.get_or_build_node_with::<Self, _>(|graph| {
+            OpaqueNode::new::<Self>(
+                BTreeMap::from([
+                    ("read_only", Value::from(true)),
+                    ("referent", Value::from(type_name::<T>())),
+                ]),
+                graph,
+            )
+        })
+    }
+}
+
+impl<T> Reflect for &'static T
+where
+    T: Reflect + DescribeType + Sync,
+{
+    trivial_reflect_methods!();
+
+    fn patch(&mut self, _value: &dyn Reflect) {}
+
+    fn to_value(&self) -> Value {
+        T::to_value(*self)
+    }
+
+    fn clone_reflect(&self) -> Box<dyn Reflect> {
+        Box::new(*self)
+    }
+
+    fn debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        T::debug(*self, f)
+    }
+
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        ReflectOwned::Opaque(self)
+    }
+
+    fn reflect_ref(&self) -> ReflectRef<'_> {
+        ReflectRef::Opaque(self)
+    }
+
+    fn reflect_mut(&mut self) -> ReflectMut<'_> {
+        ReflectMut::Opaque(self)
+    }
+}
+
+impl<T> FromReflect for &'static T
+where
+    T: Reflect + DescribeType + Sync,
+{
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        reflect.downcast_ref::<Self>().copied()
+    }
+}
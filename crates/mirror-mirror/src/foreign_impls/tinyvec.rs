@@ -0,0 +1,182 @@
+use alloc::boxed::Box;
+use core::any::Any;
+
+use tinyvec::TinyVec;
+
+use crate::array::Array;
+use crate::iter::ValueIterMut;
+use crate::type_info::graph::ListNode;
+use crate::type_info::graph::NodeId;
+use crate::type_info::graph::TypeGraph;
+use crate::DescribeType;
+use crate::FromReflect;
+use crate::List;
+use crate::Reflect;
+use crate::ReflectMut;
+use crate::ReflectOwned;
+use crate::ReflectRef;
+use crate::Value;
+
+impl<A> List for TinyVec<A>
+where
+    A: tinyvec::Array + Send + 'static,
+    A::Item: FromReflect + DescribeType,
+{
+    fn push(&mut self, value: &dyn Reflect) {
+        if let Some(value) = A::Item::from_reflect(value) {
+            TinyVec::push(self, value);
+        }
+    }
+
+    fn pop(&mut self) -> Option<Box<dyn Reflect>> {
+        let value = TinyVec::pop(self)?;
+        Some(Box::new(value))
+    }
+
+    fn try_remove(&mut self, index: usize) -> Option<Box<dyn Reflect>> {
+        if index < self.len() {
+            let value = TinyVec::remove(self, index);
+            Some(Box::new(value))
+        } else {
+            None
+        }
+    }
+
+    fn insert(&mut self, index: usize, value: &dyn Reflect) {
+        if let Some(value) = A::Item::from_reflect(value) {
+            TinyVec::insert(self, index, value);
+        }
+    }
+
+    fn truncate(&mut self, len: usize) {
+        TinyVec::truncate(self, len);
+    }
+
+    fn clear(&mut self) {
+        TinyVec::clear(self);
+    }
+
+    fn retain(&mut self, f: &mut dyn FnMut(&dyn Reflect) -> bool) {
+        TinyVec::retain(self, |value| f(value));
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        TinyVec::reserve(self, additional);
+    }
+}
+
+impl<A> Array for TinyVec<A>
+where
+    A: tinyvec::Array + Send + 'static,
+    A::Item: FromReflect + DescribeType,
+{
+    fn get(&self, index: usize) -> Option<&dyn Reflect> {
+        self.as_slice().get(index).map(|value| value.as_reflect())
+    }
+
+    fn get_mut(&mut self, index: usize) -> Option<&mut dyn Reflect> {
+        self.as_mut_slice()
+            .get_mut(index)
+            .map(|value| value.as_reflect_mut())
+    }
+
+    fn len(&self) -> usize {
+        TinyVec::len(self)
+    }
+
+    fn is_empty(&self) -> bool {
+        TinyVec::is_empty(self)
+    }
+
+    fn iter(&self) -> crate::array::Iter<'_> {
+        crate::array::Iter::new(self)
+    }
+
+    fn iter_mut(&mut self) -> ValueIterMut<'_> {
+        let iter = self
+            .as_mut_slice()
+            .iter_mut()
+            .map(|value| value.as_reflect_mut());
+        Box::new(iter)
+    }
+}
+
+impl<A> DescribeType for TinyVec<A>
+where
+    A: tinyvec::Array + 'static,
+    A::Item: DescribeType,
+{
+    fn build(graph: &mut TypeGraph) -> NodeId {
+        graph.get_or_build_node_with::<Self, _>(ListNode::new::<Self, A::Item>)
+    }
+}
+
+impl<A> Reflect for TinyVec<A>
+where
+    A: tinyvec::Array + Send + 'static,
+    A::Item: FromReflect + DescribeType,
+{
+    trivial_reflect_methods!();
+
+    fn patch(&mut self, value: &dyn Reflect) {
+        if let Some(list) = value.reflect_ref().as_list() {
+            for (idx, new_value) in list.iter().enumerate() {
+                if let Some(value) = self.get_mut(idx) {
+                    value.patch(new_value);
+                }
+            }
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        let data = self.iter().map(Reflect::to_value).collect();
+        Value::List(data)
+    }
+
+    fn clone_reflect(&self) -> Box<dyn Reflect> {
+        let value = self.to_value();
+        Box::new(Self::from_reflect(&value).unwrap())
+    }
+
+    fn debug(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        ReflectOwned::List(self)
+    }
+
+    fn reflect_ref(&self) -> ReflectRef<'_> {
+        ReflectRef::List(self)
+    }
+
+    fn reflect_mut(&mut self) -> ReflectMut<'_> {
+        ReflectMut::List(self)
+    }
+}
+
+impl<A> FromReflect for TinyVec<A>
+where
+    A: tinyvec::Array + Send + 'static,
+    A::Item: FromReflect + DescribeType,
+{
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        let list = reflect.reflect_ref().as_list()?;
+        let mut out = TinyVec::with_capacity(list.len());
+        for value in list.iter() {
+            out.push(A::Item::from_reflect(value)?);
+        }
+        Some(out)
+    }
+}
+
+impl<A> From<TinyVec<A>> for Value
+where
+    A: tinyvec::Array,
+    A::Item: Reflect,
+{
+    fn from(list: TinyVec<A>) -> Self {
+        let list = list.into_iter().map(|value| value.to_value()).collect();
+        Value::List(list)
+    }
+}
@@ -3,7 +3,9 @@ use alloc::vec::Vec;
 use core::any::Any;
 
 use crate::array::Array;
+use crate::iter::IntoIter;
 use crate::iter::ValueIterMut;
+use crate::key_path::KeyPath;
 use crate::type_info::graph::ListNode;
 use crate::type_info::graph::NodeId;
 use crate::type_info::graph::TypeGraph;
@@ -11,6 +13,7 @@ use crate::DescribeType;
 use crate::FromReflect;
 use crate::List;
 use crate::Reflect;
+use crate::ReflectError;
 use crate::ReflectMut;
 use crate::ReflectOwned;
 use crate::ReflectRef;
@@ -39,6 +42,38 @@ where
             None
         }
     }
+
+    fn insert(&mut self, index: usize, value: &dyn Reflect) {
+        if let Some(value) = T::from_reflect(value) {
+            Vec::insert(self, index, value);
+        }
+    }
+
+    fn truncate(&mut self, len: usize) {
+        Vec::truncate(self, len);
+    }
+
+    fn clear(&mut self) {
+        Vec::clear(self);
+    }
+
+    fn retain(&mut self, f: &mut dyn FnMut(&dyn Reflect) -> bool) {
+        Vec::retain(self, |value| f(value));
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        Vec::reserve(self, additional);
+    }
+
+    fn drain(&mut self) -> IntoIter<'_> {
+        let iter = Vec::drain(self, ..).map(|value| Box::new(value) as _);
+        Box::new(iter)
+    }
+
+    fn into_elements(self: Box<Self>) -> IntoIter<'static> {
+        let iter = (*self).into_iter().map(|value| Box::new(value) as _);
+        Box::new(iter)
+    }
 }
 
 impl<T> Array for Vec<T>
@@ -134,7 +169,7 @@ where
 {
     fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
         let list = reflect.reflect_ref().as_list()?;
-        let mut out = Vec::new();
+        let mut out = Vec::with_capacity(list.len());
         for value in list.iter() {
             out.push(T::from_reflect(value)?);
         }
@@ -154,3 +189,28 @@ where
         Value::List(list)
     }
 }
+
+/// If an element can't be converted the error's [`path`](ReflectError::path) starts with the
+/// index of that element.
+impl<T> TryFrom<Value> for Vec<T>
+where
+    T: FromReflect + DescribeType,
+{
+    type Error = ReflectError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        let Value::List(list) = value else {
+            return Err(ReflectError::new(
+                core::any::type_name::<Self>(),
+                Some(&value),
+            ));
+        };
+        list.iter()
+            .enumerate()
+            .map(|(index, element)| {
+                T::try_from_reflect(element)
+                    .map_err(|error| error.under(&KeyPath::default().get(index)))
+            })
+            .collect()
+    }
+}
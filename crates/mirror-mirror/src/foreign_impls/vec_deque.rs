@@ -0,0 +1,208 @@
+use alloc::boxed::Box;
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+use core::any::Any;
+
+use crate::array::Array;
+use crate::iter::IntoIter;
+use crate::iter::ValueIterMut;
+use crate::key_path::KeyPath;
+use crate::type_info::graph::ListNode;
+use crate::type_info::graph::NodeId;
+use crate::type_info::graph::TypeGraph;
+use crate::DescribeType;
+use crate::FromReflect;
+use crate::List;
+use crate::Reflect;
+use crate::ReflectError;
+use crate::ReflectMut;
+use crate::ReflectOwned;
+use crate::ReflectRef;
+use crate::Value;
+
+impl<T> List for VecDeque<T>
+where
+    T: FromReflect + DescribeType,
+{
+    fn push(&mut self, value: &dyn Reflect) {
+        if let Some(value) = T::from_reflect(value) {
+            VecDeque::push_back(self, value);
+        }
+    }
+
+    fn pop(&mut self) -> Option<Box<dyn Reflect>> {
+        let value = VecDeque::pop_back(self)?;
+        Some(Box::new(value))
+    }
+
+    fn try_remove(&mut self, index: usize) -> Option<Box<dyn Reflect>> {
+        let value = VecDeque::remove(self, index)?;
+        Some(Box::new(value))
+    }
+
+    fn insert(&mut self, index: usize, value: &dyn Reflect) {
+        if let Some(value) = T::from_reflect(value) {
+            VecDeque::insert(self, index, value);
+        }
+    }
+
+    fn truncate(&mut self, len: usize) {
+        VecDeque::truncate(self, len);
+    }
+
+    fn clear(&mut self) {
+        VecDeque::clear(self);
+    }
+
+    fn retain(&mut self, f: &mut dyn FnMut(&dyn Reflect) -> bool) {
+        VecDeque::retain(self, |value| f(value));
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        VecDeque::reserve(self, additional);
+    }
+
+    fn drain(&mut self) -> IntoIter<'_> {
+        let iter = VecDeque::drain(self, ..).map(|value| Box::new(value) as _);
+        Box::new(iter)
+    }
+
+    fn into_elements(self: Box<Self>) -> IntoIter<'static> {
+        let iter = (*self).into_iter().map(|value| Box::new(value) as _);
+        Box::new(iter)
+    }
+}
+
+impl<T> Array for VecDeque<T>
+where
+    T: FromReflect + DescribeType,
+{
+    fn get(&self, index: usize) -> Option<&dyn Reflect> {
+        VecDeque::get(self, index).map(|value| value.as_reflect())
+    }
+
+    fn get_mut(&mut self, index: usize) -> Option<&mut dyn Reflect> {
+        VecDeque::get_mut(self, index).map(|value| value.as_reflect_mut())
+    }
+
+    fn len(&self) -> usize {
+        VecDeque::len(self)
+    }
+
+    fn is_empty(&self) -> bool {
+        VecDeque::is_empty(self)
+    }
+
+    fn iter(&self) -> crate::array::Iter<'_> {
+        crate::array::Iter::new(self)
+    }
+
+    fn iter_mut(&mut self) -> ValueIterMut<'_> {
+        let iter = VecDeque::iter_mut(self).map(|value| value.as_reflect_mut());
+        Box::new(iter)
+    }
+}
+
+impl<T> DescribeType for VecDeque<T>
+where
+    T: DescribeType,
+{
+    fn build(graph: &mut TypeGraph) -> NodeId {
+        graph.get_or_build_node_with::<Self, _>(|graph| ListNode::new::<Self, T>(graph))
+    }
+}
+
+impl<T> Reflect for VecDeque<T>
+where
+    T: FromReflect + DescribeType,
+{
+    trivial_reflect_methods!();
+
+    fn patch(&mut self, value: &dyn Reflect) {
+        if let Some(list) = value.reflect_ref().as_list() {
+            for (idx, new_value) in list.iter().enumerate() {
+                if let Some(value) = self.get_mut(idx) {
+                    value.patch(new_value);
+                }
+            }
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        let data = self.iter().map(Reflect::to_value).collect();
+        Value::List(data)
+    }
+
+    fn clone_reflect(&self) -> Box<dyn Reflect> {
+        let value = self.to_value();
+        Box::new(Self::from_reflect(&value).unwrap())
+    }
+
+    fn debug(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(Array::iter(self)).finish()
+    }
+
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        ReflectOwned::List(self)
+    }
+
+    fn reflect_ref(&self) -> ReflectRef<'_> {
+        ReflectRef::List(self)
+    }
+
+    fn reflect_mut(&mut self) -> ReflectMut<'_> {
+        ReflectMut::List(self)
+    }
+}
+
+impl<T> FromReflect for VecDeque<T>
+where
+    T: FromReflect + DescribeType,
+{
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        let list = reflect.reflect_ref().as_list()?;
+        let mut out = VecDeque::with_capacity(list.len());
+        for value in list.iter() {
+            out.push_back(T::from_reflect(value)?);
+        }
+        Some(out)
+    }
+}
+
+impl<T> From<VecDeque<T>> for Value
+where
+    T: Reflect,
+{
+    fn from(list: VecDeque<T>) -> Self {
+        let list = list
+            .into_iter()
+            .map(|value| value.to_value())
+            .collect::<Vec<_>>();
+        Value::List(list)
+    }
+}
+
+/// If an element can't be converted the error's [`path`](ReflectError::path) starts with the
+/// index of that element.
+impl<T> TryFrom<Value> for VecDeque<T>
+where
+    T: FromReflect + DescribeType,
+{
+    type Error = ReflectError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        let Value::List(list) = value else {
+            return Err(ReflectError::new(
+                core::any::type_name::<Self>(),
+                Some(&value),
+            ));
+        };
+        list.iter()
+            .enumerate()
+            .map(|(index, element)| {
+                T::try_from_reflect(element)
+                    .map_err(|error| error.under(&KeyPath::default().get(index)))
+            })
+            .collect()
+    }
+}
@@ -10,6 +10,8 @@ use core::num::NonZeroU64;
 use core::num::NonZeroU8;
 use core::num::NonZeroUsize;
 use core::time::Duration;
+#[cfg(feature = "std")]
+use std::time::SystemTime;
 
 macro_rules! impl_reflect_via_scalar {
     ($ty:ty, $via_ty:ty, $get_fn:expr, $new_fn:expr $(,)?) => {
@@ -99,6 +101,105 @@ impl_reflect_via_scalar! { NonZeroI128,  i128,  |n: &NonZeroI128| n.get(),  Self
 
 impl_reflect_via_scalar! { Duration, f32, |d: &Duration| d.as_secs_f32(), Self::from_secs_f32 }
 
+// points in time are reflected as the number of nanoseconds since the unix epoch
+#[cfg(feature = "std")]
+impl_reflect_via_scalar! { SystemTime, i128, system_time_to_nanos, system_time_from_nanos }
+
+#[cfg(feature = "chrono")]
+impl_reflect_via_scalar! {
+    chrono::DateTime<chrono::Utc>,
+    i128,
+    |d: &chrono::DateTime<chrono::Utc>| join_nanos(d.timestamp(), d.timestamp_subsec_nanos()),
+    |n| {
+        let (secs, nanos) = split_nanos(n)?;
+        chrono::DateTime::from_timestamp(secs, nanos)
+    },
+}
+
+#[cfg(feature = "chrono")]
+impl_reflect_via_scalar! {
+    chrono::NaiveDateTime,
+    i128,
+    |d: &chrono::NaiveDateTime| {
+        let d = d.and_utc();
+        join_nanos(d.timestamp(), d.timestamp_subsec_nanos())
+    },
+    |n| {
+        let (secs, nanos) = split_nanos(n)?;
+        Some(chrono::DateTime::from_timestamp(secs, nanos)?.naive_utc())
+    },
+}
+
+#[cfg(feature = "chrono")]
+impl_reflect_via_scalar! {
+    chrono::TimeDelta,
+    i128,
+    |d: &chrono::TimeDelta| {
+        // `subsec_nanos` has the same sign as the whole duration
+        i128::from(d.num_seconds()) * NANOS_PER_SEC + i128::from(d.subsec_nanos())
+    },
+    |n| {
+        let (secs, nanos) = split_nanos(n)?;
+        chrono::TimeDelta::new(secs, nanos)
+    },
+}
+
+// offsets aren't kept, values are read back as UTC
+#[cfg(feature = "time")]
+impl_reflect_via_scalar! {
+    time::OffsetDateTime,
+    i128,
+    |d: &time::OffsetDateTime| d.unix_timestamp_nanos(),
+    |n| time::OffsetDateTime::from_unix_timestamp_nanos(n).ok(),
+}
+
+#[cfg(feature = "time")]
+impl_reflect_via_scalar! {
+    time::Duration,
+    i128,
+    |d: &time::Duration| d.whole_nanoseconds(),
+    |n| {
+        let (secs, nanos) = split_nanos(n)?;
+        Some(time::Duration::new(secs, nanos as i32))
+    },
+}
+
+#[cfg(any(feature = "std", feature = "chrono", feature = "time"))]
+const NANOS_PER_SEC: i128 = 1_000_000_000;
+
+#[cfg(feature = "std")]
+fn system_time_to_nanos(time: &SystemTime) -> i128 {
+    let nanos = |duration: Duration| i128::try_from(duration.as_nanos()).unwrap_or(i128::MAX);
+    match time.duration_since(SystemTime::UNIX_EPOCH) {
+        Ok(after) => nanos(after),
+        Err(before) => -nanos(before.duration()),
+    }
+}
+
+#[cfg(feature = "std")]
+fn system_time_from_nanos(nanos: i128) -> Option<SystemTime> {
+    let (secs, subsec_nanos) = split_nanos(nanos)?;
+    let secs_duration = Duration::from_secs(secs.unsigned_abs());
+    let time = if secs >= 0 {
+        SystemTime::UNIX_EPOCH.checked_add(secs_duration)?
+    } else {
+        SystemTime::UNIX_EPOCH.checked_sub(secs_duration)?
+    };
+    time.checked_add(Duration::from_nanos(subsec_nanos.into()))
+}
+
+#[cfg(feature = "chrono")]
+fn join_nanos(secs: i64, nanos: u32) -> i128 {
+    i128::from(secs) * NANOS_PER_SEC + i128::from(nanos)
+}
+
+/// Split nanoseconds into whole seconds, rounded down, and the remaining nanoseconds.
+#[cfg(any(feature = "std", feature = "chrono", feature = "time"))]
+fn split_nanos(nanos: i128) -> Option<(i64, u32)> {
+    let secs = i64::try_from(nanos.div_euclid(NANOS_PER_SEC)).ok()?;
+    Some((secs, nanos.rem_euclid(NANOS_PER_SEC) as u32))
+}
+
 trait IntoOption<T> {
     fn into_option(self) -> Option<T>;
 }
@@ -136,4 +237,87 @@ mod tests {
             "core::time::Duration"
         );
     }
+
+    #[test]
+    fn non_zero_patch() {
+        use crate::Reflect;
+
+        // non-zero integers are patched through `Reflect::patch`, since handing out mutable
+        // references to the integer inside could break their invariant
+        let mut n = NonZeroU8::new(1).unwrap();
+        assert!(n.as_reflect_mut().as_scalar_mut().is_none());
+
+        n.patch(&2_u8);
+        assert_eq!(n.get(), 2);
+
+        n.patch(&NonZeroU8::new(3).unwrap());
+        assert_eq!(n.get(), 3);
+
+        n.patch(&0_u8);
+        assert_eq!(n.get(), 3);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn system_time() {
+        use crate::FromReflect;
+        use crate::Reflect;
+
+        let after = SystemTime::UNIX_EPOCH + Duration::new(1_700_000_000, 123);
+        let before = SystemTime::UNIX_EPOCH - Duration::new(10, 1);
+
+        assert_eq!(after.to_value(), 1_700_000_000_000_000_123_i128.into());
+        assert_eq!(before.to_value(), (-10_000_000_001_i128).into());
+
+        for time in [after, before, SystemTime::UNIX_EPOCH] {
+            assert_eq!(SystemTime::from_reflect(&time.to_value()), Some(time));
+        }
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono() {
+        use crate::FromReflect;
+        use crate::Reflect;
+
+        let time = chrono::DateTime::from_timestamp(-10, 5).unwrap();
+        assert_eq!(time.to_value(), (-9_999_999_995_i128).into());
+        assert_eq!(
+            chrono::DateTime::<chrono::Utc>::from_reflect(&time.to_value()),
+            Some(time)
+        );
+
+        let naive = time.naive_utc();
+        assert_eq!(
+            chrono::NaiveDateTime::from_reflect(&naive.to_value()),
+            Some(naive)
+        );
+
+        let delta = chrono::TimeDelta::milliseconds(-1500);
+        assert_eq!(delta.to_value(), (-1_500_000_000_i128).into());
+        assert_eq!(
+            chrono::TimeDelta::from_reflect(&delta.to_value()),
+            Some(delta)
+        );
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time() {
+        use crate::FromReflect;
+        use crate::Reflect;
+
+        let time = time::OffsetDateTime::from_unix_timestamp_nanos(-9_999_999_995).unwrap();
+        assert_eq!(
+            time::OffsetDateTime::from_reflect(&time.to_value()),
+            Some(time)
+        );
+
+        let duration = time::Duration::milliseconds(-1500);
+        assert_eq!(duration.to_value(), (-1_500_000_000_i128).into());
+        assert_eq!(
+            time::Duration::from_reflect(&duration.to_value()),
+            Some(duration)
+        );
+    }
 }
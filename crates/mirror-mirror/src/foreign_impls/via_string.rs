@@ -0,0 +1,186 @@
+use alloc::string::ToString;
+#[cfg(feature = "std")]
+use std::path::PathBuf;
+
+// Types that are edited as text are reflected as opaque values whose `to_value` is a string. They
+// can't be reflected as string scalars since `ScalarRef::String` must borrow a `String`.
+macro_rules! impl_reflect_via_string {
+    ($ty:ty, $to_string:expr, $from_str:expr, $default:expr $(,)?) => {
+        const _: () = {
+            use $crate::__private::*;
+
+            impl DescribeType for $ty {
+                fn build(graph: &mut TypeGraph) -> NodeId {
+                    graph.get_or_build_node_with::<Self, _>(|graph| {
+                        #[allow(clippy::redundant_closure_call)]
+                        OpaqueNode::new::<Self>(Default::default(), graph)
+                            .default_value($to_string(&$default))
+                    })
+                }
+            }
+
+            impl Reflect for $ty {
+                trivial_reflect_methods!();
+
+                fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+                    ReflectOwned::Opaque(self)
+                }
+
+                fn reflect_ref(&self) -> ReflectRef<'_> {
+                    ReflectRef::Opaque(self)
+                }
+
+                fn reflect_mut(&mut self) -> ReflectMut<'_> {
+                    ReflectMut::Opaque(self)
+                }
+
+                fn patch(&mut self, value: &dyn Reflect) {
+                    if let Some(value) = Self::from_reflect(value) {
+                        *self = value;
+                    }
+                }
+
+                #[allow(clippy::redundant_closure_call)]
+                fn to_value(&self) -> Value {
+                    $to_string(self).to_value()
+                }
+
+                fn clone_reflect(&self) -> Box<dyn Reflect> {
+                    Box::new(self.clone())
+                }
+
+                fn debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    if f.alternate() {
+                        write!(f, "{:#?}", self)
+                    } else {
+                        write!(f, "{:?}", self)
+                    }
+                }
+            }
+
+            impl FromReflect for $ty {
+                #[allow(clippy::redundant_closure_call)]
+                fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+                    if let Some(value) = reflect.downcast_ref::<Self>() {
+                        Some(value.clone())
+                    } else {
+                        $from_str(reflect.downcast_ref::<String>()?)
+                    }
+                }
+            }
+
+            impl From<$ty> for Value {
+                fn from(value: $ty) -> Self {
+                    value.to_value()
+                }
+            }
+        };
+    };
+}
+
+impl_reflect_via_string! {
+    Cow<'static, str>,
+    |s: &Cow<'static, str>| s.to_string(),
+    |s: &String| Some(Cow::Owned(s.clone())),
+    Cow::Borrowed(""),
+}
+
+// paths that aren't valid UTF-8 are converted lossily
+#[cfg(feature = "std")]
+impl_reflect_via_string! {
+    PathBuf,
+    |path: &PathBuf| path.to_string_lossy().into_owned(),
+    |s: &String| Some(PathBuf::from(s)),
+    PathBuf::new(),
+}
+
+#[cfg(feature = "camino")]
+impl_reflect_via_string! {
+    camino::Utf8PathBuf,
+    |path: &camino::Utf8PathBuf| path.to_string(),
+    |s: &String| Some(camino::Utf8PathBuf::from(s)),
+    camino::Utf8PathBuf::new(),
+}
+
+#[cfg(feature = "uuid")]
+impl_reflect_via_string! {
+    uuid::Uuid,
+    |uuid: &uuid::Uuid| uuid.to_string(),
+    |s: &String| uuid::Uuid::parse_str(s).ok(),
+    uuid::Uuid::nil(),
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::borrow::ToOwned;
+
+    use crate::DescribeType;
+    use crate::FromReflect;
+    use crate::Reflect;
+    use crate::Value;
+
+    #[test]
+    fn cow_str() {
+        use alloc::borrow::Cow;
+
+        let mut s: Cow<'static, str> = Cow::Borrowed("grass");
+        assert_eq!(s.to_value(), Value::from("grass"));
+        assert_eq!(
+            Cow::<'static, str>::from_reflect(&s.to_value()),
+            Some(s.clone())
+        );
+
+        s.patch(&"sand".to_owned());
+        assert_eq!(s, "sand");
+
+        let descriptor = <Cow<'static, str> as DescribeType>::type_descriptor();
+        assert_eq!(descriptor.default_value(), Some(Value::from("")));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn path_buf() {
+        use std::path::PathBuf;
+
+        let path = PathBuf::from("assets/textures/grass.png");
+        assert_eq!(path.to_value(), Value::from("assets/textures/grass.png"));
+        assert_eq!(PathBuf::from_reflect(&path.to_value()), Some(path.clone()));
+
+        let mut patched = PathBuf::new();
+        patched.patch(&"other.png".to_owned());
+        assert_eq!(patched, PathBuf::from("other.png"));
+
+        let descriptor = <PathBuf as DescribeType>::type_descriptor();
+        assert_eq!(descriptor.default_value(), Some(Value::from("")));
+    }
+
+    #[cfg(feature = "camino")]
+    #[test]
+    fn utf8_path_buf() {
+        use camino::Utf8PathBuf;
+
+        let path = Utf8PathBuf::from("assets/grass.png");
+        assert_eq!(path.to_value(), Value::from("assets/grass.png"));
+        assert_eq!(Utf8PathBuf::from_reflect(&path.to_value()), Some(path));
+    }
+
+    #[cfg(feature = "uuid")]
+    #[test]
+    fn uuid() {
+        use uuid::Uuid;
+
+        let uuid = Uuid::from_u128(0x67e5_5044_10b1_426f_9247_bb68_0e5f_e0c8);
+        assert_eq!(
+            uuid.to_value(),
+            Value::from("67e55044-10b1-426f-9247-bb680e5fe0c8")
+        );
+        assert_eq!(Uuid::from_reflect(&uuid.to_value()), Some(uuid));
+        assert_eq!(Uuid::from_reflect(&"not a uuid".to_owned()), None);
+
+        let descriptor = <Uuid as DescribeType>::type_descriptor();
+        assert_eq!(
+            descriptor.default_value(),
+            Some(Value::from("00000000-0000-0000-0000-000000000000"))
+        );
+    }
+}
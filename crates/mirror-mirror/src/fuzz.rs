@@ -0,0 +1,179 @@
+use serde::de::DeserializeSeed;
+use speedy::Readable;
+use speedy::Writable;
+
+use crate::evolution;
+use crate::reflect_eq;
+use crate::reflect_hash_with;
+use crate::serde::TypedDeserializer;
+use crate::serde::TypedSerializer;
+use crate::type_info::stable_hash::StableHasher;
+use crate::versioned::stable_type_hash;
+use crate::visit::walk;
+use crate::visit::ReflectVisitor;
+use crate::NanHandling;
+use crate::Reflect;
+use crate::TypeDescriptor;
+use crate::Value;
+
+/// Run every other entry point in this module on `bytes`.
+///
+/// The bytes are also tried as a `speedy` encoded [`TypeDescriptor`] followed by a value of that
+/// type, either as JSON, which is checked with [`typed_json`], or in the [`evolution`] encoding,
+/// which is checked with [`evolution_value`].
+///
+/// Each entry point decodes untrusted bytes and, if that succeeds, checks that the result
+/// survives being encoded and decoded again. Malformed input must be rejected with an error, so
+/// any panic, either from decoding or from a failed check, is a bug.
+///
+/// Decoding with `speedy` has no limit on how deeply values are nested, so very large inputs can
+/// overflow the stack. Limit the size of the inputs, for example with `-max_len`. The
+/// [`evolution`] encoding rejects values nested more than [`evolution::MAX_DEPTH`] levels deep.
+///
+/// # Example
+///
+/// A fuzz target for [cargo-fuzz]:
+///
+/// ```ignore
+/// #![no_main]
+///
+/// libfuzzer_sys::fuzz_target!(|bytes: &[u8]| {
+///     mirror_mirror::fuzz::fuzz_roundtrip(bytes);
+/// });
+/// ```
+///
+/// [cargo-fuzz]: https://github.com/rust-fuzz/cargo-fuzz
+pub fn fuzz_roundtrip(bytes: &[u8]) {
+    speedy_value(bytes);
+    json_value(bytes);
+    speedy_type_descriptor(bytes);
+    json_type_descriptor(bytes);
+
+    if let (Ok(descriptor), len) = TypeDescriptor::read_with_length_from_buffer(bytes) {
+        typed_json(&descriptor, &bytes[len..]);
+        evolution_value(&descriptor, &bytes[len..]);
+    }
+}
+
+/// Decode a [`Value`] with `speedy` and check that it encodes back to the same bytes.
+pub fn speedy_value(bytes: &[u8]) {
+    let Ok(value) = Value::read_from_buffer(bytes) else {
+        return;
+    };
+    check_value(&value);
+
+    let encoded = value.write_to_vec().expect("failed to encode value");
+    let decoded = Value::read_from_buffer(&encoded).expect("failed to decode encoded value");
+    assert_eq!(
+        decoded.write_to_vec().expect("failed to encode value"),
+        encoded,
+        "value changed after encoding and decoding it"
+    );
+}
+
+/// Decode a [`Value`] from JSON and check that it encodes back to the same JSON.
+pub fn json_value(bytes: &[u8]) {
+    let Ok(value) = serde_json::from_slice::<Value>(bytes) else {
+        return;
+    };
+    check_value(&value);
+
+    let encoded = serde_json::to_vec(&value).expect("failed to encode value");
+    let decoded =
+        serde_json::from_slice::<Value>(&encoded).expect("failed to decode encoded value");
+    assert_eq!(
+        serde_json::to_vec(&decoded).expect("failed to encode value"),
+        encoded,
+        "value changed after encoding and decoding it"
+    );
+}
+
+/// Decode a [`TypeDescriptor`] with `speedy` and check that it encodes back to an equal
+/// descriptor.
+pub fn speedy_type_descriptor(bytes: &[u8]) {
+    let Ok(descriptor) = TypeDescriptor::read_from_buffer(bytes) else {
+        return;
+    };
+    let hash = stable_type_hash(&descriptor);
+
+    let encoded = descriptor
+        .write_to_vec()
+        .expect("failed to encode descriptor");
+    let decoded =
+        TypeDescriptor::read_from_buffer(&encoded).expect("failed to decode encoded descriptor");
+    assert_eq!(decoded, descriptor, "descriptor changed after a roundtrip");
+    assert_eq!(stable_type_hash(&decoded), hash);
+}
+
+/// Decode a [`TypeDescriptor`] from JSON and check that it encodes back to an equal descriptor.
+pub fn json_type_descriptor(bytes: &[u8]) {
+    let Ok(descriptor) = serde_json::from_slice::<TypeDescriptor>(bytes) else {
+        return;
+    };
+    let hash = stable_type_hash(&descriptor);
+
+    let encoded = serde_json::to_vec(&descriptor).expect("failed to encode descriptor");
+    let decoded = serde_json::from_slice::<TypeDescriptor>(&encoded)
+        .expect("failed to decode encoded descriptor");
+    assert_eq!(decoded, descriptor, "descriptor changed after a roundtrip");
+    assert_eq!(stable_type_hash(&decoded), hash);
+}
+
+/// Decode JSON for a value of the type described by `descriptor` and check that it encodes back
+/// to the same JSON.
+///
+/// Use this to fuzz the formats of your own types, with their descriptors from
+/// [`DescribeType::type_descriptor`].
+///
+/// [`DescribeType::type_descriptor`]: crate::DescribeType::type_descriptor
+pub fn typed_json(descriptor: &TypeDescriptor, bytes: &[u8]) {
+    let mut deserializer = serde_json::Deserializer::from_slice(bytes);
+    let Ok(value) = TypedDeserializer::new(descriptor).deserialize(&mut deserializer) else {
+        return;
+    };
+    check_value(&value);
+
+    let encoded = serde_json::to_vec(&TypedSerializer::new(&value, descriptor))
+        .expect("failed to encode value");
+    let mut deserializer = serde_json::Deserializer::from_slice(&encoded);
+    let decoded = TypedDeserializer::new(descriptor)
+        .deserialize(&mut deserializer)
+        .expect("failed to decode encoded value");
+    assert_eq!(
+        serde_json::to_vec(&TypedSerializer::new(&decoded, descriptor))
+            .expect("failed to encode value"),
+        encoded,
+        "value changed after encoding and decoding it"
+    );
+}
+
+/// Decode a value written with [`evolution::write_value`] in the shape of `descriptor` and check
+/// that it encodes back to the same bytes.
+///
+/// Like [`typed_json`], use this to fuzz the formats of your own types.
+pub fn evolution_value(descriptor: &TypeDescriptor, bytes: &[u8]) {
+    let Ok(value) = evolution::read_value(bytes, descriptor) else {
+        return;
+    };
+    check_value(&value);
+
+    let encoded = evolution::write_value(&value);
+    let decoded =
+        evolution::read_value(&encoded, descriptor).expect("failed to decode encoded value");
+    assert_eq!(
+        evolution::write_value(&decoded),
+        encoded,
+        "value changed after encoding and decoding it"
+    );
+}
+
+/// Check that the value can be walked and converted without panicking.
+fn check_value(value: &Value) {
+    struct Noop;
+
+    impl ReflectVisitor for Noop {}
+
+    walk(value, &mut Noop);
+    let _ = reflect_eq(value, &value.to_value());
+    let _ = reflect_hash_with(value, &mut StableHasher::new(), NanHandling::Canonicalize);
+}
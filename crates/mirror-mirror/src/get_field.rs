@@ -1,6 +1,9 @@
-use alloc::borrow::ToOwned;
 use alloc::string::String;
 
+use crate::key_path::value_at_key;
+use crate::key_path::GetPath;
+use crate::key_path::Key;
+use crate::key_path::KeyPath;
 use crate::Array;
 use crate::Enum;
 use crate::Map;
@@ -13,6 +16,37 @@ use crate::TupleStruct;
 use crate::Value;
 
 /// Helper trait for accessing and downcasting fields on reflected values.
+///
+/// Nested fields can be accessed with a tuple of keys, one per level, without building a
+/// [`KeyPath`]. See [`ChainKey`] for which keys can be chained.
+///
+/// # Example
+///
+/// ```
+/// use std::collections::BTreeMap;
+/// use mirror_mirror::{GetField, Reflect};
+///
+/// #[derive(Reflect, Clone, Debug)]
+/// struct Level {
+///     rooms: Vec<Room>,
+///     spawns: BTreeMap<String, (f32, f32)>,
+/// }
+///
+/// #[derive(Reflect, Clone, Debug)]
+/// enum Room {
+///     Empty,
+///     Boss { health: u32 },
+/// }
+///
+/// let level = Level {
+///     rooms: Vec::from([Room::Empty, Room::Boss { health: 100 }]),
+///     spawns: BTreeMap::from([("start".to_owned(), (1.0, 2.0))]),
+/// };
+///
+/// assert_eq!(level.get_field::<u32>(("rooms", 1, "health")), Some(&100));
+/// assert_eq!(level.get_field::<f32>(("spawns", "start", 1)), Some(&2.0));
+/// assert_eq!(level.get_field::<u32>(("rooms", 0, "health")), None);
+/// ```
 pub trait GetField<'a, K, M> {
     fn get_field<T>(self, key: K) -> Option<&'a T>
     where
@@ -20,6 +54,8 @@ pub trait GetField<'a, K, M> {
 }
 
 /// Helper trait for mutably accessing and downcasting fields on reflected values.
+///
+/// Like [`GetField`], nested fields can be accessed with a tuple of keys.
 pub trait GetFieldMut<'a, K, M> {
     fn get_field_mut<T>(self, key: K) -> Option<&'a mut T>
     where
@@ -52,6 +88,7 @@ impl<'a> GetField<'a, &str, private::Value> for &'a Value {
             | ReflectRef::Tuple(_)
             | ReflectRef::List(_)
             | ReflectRef::Array(_)
+            | ReflectRef::Set(_)
             | ReflectRef::Opaque(_)
             | ReflectRef::Scalar(_) => None,
         }
@@ -71,6 +108,7 @@ impl<'a> GetFieldMut<'a, &str, private::Value> for &'a mut Value {
             | ReflectMut::Tuple(_)
             | ReflectMut::List(_)
             | ReflectMut::Array(_)
+            | ReflectMut::Set(_)
             | ReflectMut::Opaque(_)
             | ReflectMut::Scalar(_) => None,
         }
@@ -93,17 +131,21 @@ where
                 ReflectRef::Array(inner) => inner.get_field(key),
                 ReflectRef::List(inner) => inner.get_field(key),
                 ReflectRef::Map(inner) => inner.get_field(key),
-                ReflectRef::Struct(_) | ReflectRef::Scalar(_) | ReflectRef::Opaque(_) => None,
+                ReflectRef::Struct(_)
+                | ReflectRef::Scalar(_)
+                | ReflectRef::Set(_)
+                | ReflectRef::Opaque(_) => None,
             }
         } else if let Some(key) = key.as_any().downcast_ref::<String>() {
             match self.reflect_ref() {
-                ReflectRef::Map(inner) => inner.get_field(key.to_owned()),
-                ReflectRef::Struct(inner) => inner.get_field(key),
+                ReflectRef::Map(inner) => inner.get_field(key.as_str()),
+                ReflectRef::Struct(inner) => inner.get_field(key.as_str()),
                 ReflectRef::TupleStruct(_)
                 | ReflectRef::Tuple(_)
                 | ReflectRef::Enum(_)
                 | ReflectRef::List(_)
                 | ReflectRef::Array(_)
+                | ReflectRef::Set(_)
                 | ReflectRef::Opaque(_)
                 | ReflectRef::Scalar(_) => None,
             }
@@ -116,6 +158,7 @@ where
                 | ReflectRef::Array(_)
                 | ReflectRef::List(_)
                 | ReflectRef::Struct(_)
+                | ReflectRef::Set(_)
                 | ReflectRef::Opaque(_)
                 | ReflectRef::Scalar(_) => None,
             }
@@ -139,17 +182,21 @@ where
                 ReflectMut::List(inner) => inner.get_field_mut(key),
                 ReflectMut::Array(inner) => inner.get_field_mut(key),
                 ReflectMut::Map(inner) => inner.get_field_mut(key),
-                ReflectMut::Struct(_) | ReflectMut::Scalar(_) | ReflectMut::Opaque(_) => None,
+                ReflectMut::Struct(_)
+                | ReflectMut::Scalar(_)
+                | ReflectMut::Set(_)
+                | ReflectMut::Opaque(_) => None,
             }
         } else if let Some(key) = key.as_any().downcast_ref::<String>() {
             match self.reflect_mut() {
-                ReflectMut::Map(inner) => inner.get_field_mut(key.to_owned()),
-                ReflectMut::Struct(inner) => inner.get_field_mut(key),
+                ReflectMut::Map(inner) => inner.get_field_mut(key.as_str()),
+                ReflectMut::Struct(inner) => inner.get_field_mut(key.as_str()),
                 ReflectMut::TupleStruct(_)
                 | ReflectMut::Tuple(_)
                 | ReflectMut::Enum(_)
                 | ReflectMut::List(_)
                 | ReflectMut::Array(_)
+                | ReflectMut::Set(_)
                 | ReflectMut::Opaque(_)
                 | ReflectMut::Scalar(_) => None,
             }
@@ -162,6 +209,7 @@ where
                 | ReflectMut::List(_)
                 | ReflectMut::Array(_)
                 | ReflectMut::Struct(_)
+                | ReflectMut::Set(_)
                 | ReflectMut::Opaque(_)
                 | ReflectMut::Scalar(_) => None,
             }
@@ -348,7 +396,7 @@ where
     where
         T: Reflect,
     {
-        self.get(&key.to_owned())?.downcast_ref()
+        self.get_str(key)?.downcast_ref()
     }
 }
 
@@ -360,15 +408,126 @@ where
     where
         T: Reflect,
     {
-        self.get_mut(&key.to_owned())?.downcast_mut()
+        self.get_str_mut(key)?.downcast_mut()
     }
 }
 
+/// A key that can be chained in a tuple to access nested fields with [`GetField`] and
+/// [`GetFieldMut`].
+///
+/// What a key refers to depends on the value it's used on:
+///
+/// - `&str` is a field of a struct or struct variant, or a key of a map with `String` keys.
+/// - `usize` is a field of a tuple, tuple struct, or tuple variant, an element of a list or
+///   array, or a key of a map with `usize` keys.
+///
+/// Maps with other keys, and tuples of keys that are themselves map keys, such as looking up
+/// `(0, 1)` in a map with `(usize, usize)` keys, need [`GetPath`] instead.
+pub trait ChainKey: private::Sealed {
+    /// The key this refers to when used on `value`, or `None` if it can't be used on `value`.
+    fn key_for(&self, value: &dyn Reflect) -> Option<Key>;
+}
+
+impl ChainKey for &str {
+    fn key_for(&self, value: &dyn Reflect) -> Option<Key> {
+        match value.reflect_ref() {
+            ReflectRef::Struct(_) | ReflectRef::Enum(_) => Some(Key::named_field(*self)),
+            ReflectRef::Map(_) => Some(Key::get(*self)),
+            ReflectRef::TupleStruct(_)
+            | ReflectRef::Tuple(_)
+            | ReflectRef::List(_)
+            | ReflectRef::Array(_)
+            | ReflectRef::Set(_)
+            | ReflectRef::Opaque(_)
+            | ReflectRef::Scalar(_) => None,
+        }
+    }
+}
+
+impl ChainKey for usize {
+    fn key_for(&self, value: &dyn Reflect) -> Option<Key> {
+        match value.reflect_ref() {
+            ReflectRef::TupleStruct(_) | ReflectRef::Tuple(_) | ReflectRef::Enum(_) => {
+                Some(Key::numbered_field(*self))
+            }
+            ReflectRef::List(_) | ReflectRef::Array(_) | ReflectRef::Map(_) => {
+                Some(Key::get(*self))
+            }
+            ReflectRef::Struct(_)
+            | ReflectRef::Set(_)
+            | ReflectRef::Opaque(_)
+            | ReflectRef::Scalar(_) => None,
+        }
+    }
+}
+
+/// Follow `keys` from `root`, returning the key path they resolve to and the value at it.
+fn follow<'a>(root: &'a dyn Reflect, keys: &[&dyn ChainKey]) -> Option<(KeyPath, &'a dyn Reflect)> {
+    let mut path = KeyPath::default();
+    let mut value = root;
+    for key in keys {
+        let key = key.key_for(value)?;
+        value = value_at_key(value, &key)?;
+        path.push(key);
+    }
+    Some((path, value))
+}
+
+macro_rules! impl_chained {
+    ($($ident:ident),*) => {
+        #[allow(non_snake_case)]
+        impl<'a, R, $($ident,)*> GetField<'a, ($($ident,)*), private::Chain> for &'a R
+        where
+            R: Reflect + ?Sized,
+            $($ident: ChainKey,)*
+        {
+            fn get_field<T>(self, key: ($($ident,)*)) -> Option<&'a T>
+            where
+                T: Reflect,
+            {
+                let ($($ident,)*) = key;
+                let (_, value) = follow(self.as_reflect(), &[$(&$ident),*])?;
+                value.downcast_ref()
+            }
+        }
+
+        #[allow(non_snake_case)]
+        impl<'a, R, $($ident,)*> GetFieldMut<'a, ($($ident,)*), private::Chain> for &'a mut R
+        where
+            R: Reflect + ?Sized,
+            $($ident: ChainKey,)*
+        {
+            fn get_field_mut<T>(self, key: ($($ident,)*)) -> Option<&'a mut T>
+            where
+                T: Reflect,
+            {
+                let ($($ident,)*) = key;
+                let (path, _) = follow(self.as_reflect(), &[$(&$ident),*])?;
+                self.at_mut(&path)?.downcast_mut()
+            }
+        }
+    };
+}
+
+impl_chained!(K1, K2);
+impl_chained!(K1, K2, K3);
+impl_chained!(K1, K2, K3, K4);
+impl_chained!(K1, K2, K3, K4, K5);
+impl_chained!(K1, K2, K3, K4, K5, K6);
+impl_chained!(K1, K2, K3, K4, K5, K6, K7);
+impl_chained!(K1, K2, K3, K4, K5, K6, K7, K8);
+
 mod private {
     #![allow(missing_debug_implementations)]
 
+    pub trait Sealed {}
+
+    impl Sealed for &str {}
+    impl Sealed for usize {}
+
     /// Types used to disambiguate otherwise overlapping trait impls
 
+    pub struct Chain;
     pub struct Struct;
     pub struct TupleStruct;
     pub struct Enum;
@@ -1,4 +1,5 @@
 use alloc::boxed::Box;
+use alloc::string::String;
 
 use crate::Reflect;
 
@@ -13,3 +14,9 @@ pub type ValueIterMut<'a> = Box<dyn Iterator<Item = &'a mut dyn Reflect> + 'a>;
 //
 // Its a type alias to make it clear that it allocates
 pub type PairIterMut<'a, T = str> = Box<dyn Iterator<Item = (&'a T, &'a mut dyn Reflect)> + 'a>;
+
+// Its a type alias to make it clear that it allocates
+pub type IntoIter<'a> = Box<dyn Iterator<Item = Box<dyn Reflect>> + 'a>;
+
+// Its a type alias to make it clear that it allocates
+pub type IntoPairIter<'a, K = String> = Box<dyn Iterator<Item = (K, Box<dyn Reflect>)> + 'a>;
@@ -1,13 +1,21 @@
 use alloc::borrow::ToOwned;
+use alloc::collections::BTreeMap;
+use alloc::format;
 use alloc::string::String;
 use alloc::vec::Vec;
 use core::fmt;
 use core::iter::FusedIterator;
 use core::iter::Peekable;
+use core::str::FromStr;
 
+use crate::coerce::Coercions;
+use crate::enum_::VariantField;
 use crate::enum_::VariantKind;
 use crate::type_info::TypeAtPath;
+use crate::DescribeType;
+use crate::FromReflect;
 use crate::Reflect;
+use crate::ReflectError;
 use crate::ReflectMut;
 use crate::ReflectRef;
 use crate::Value;
@@ -22,6 +30,53 @@ pub trait GetPath {
         self.at(key_path)?.downcast_ref()
     }
 
+    /// Get an owned `T` at `key_path`, or `T`'s default value if nothing is at the path, such as
+    /// when an option along the way is `None` or a map doesn't contain the key.
+    ///
+    /// The default value comes from `T`'s [`TypeDescriptor`]. Returns `None` if the value at the
+    /// path can't be converted into a `T`, or if nothing is at the path and `T` has no default
+    /// value.
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use mirror_mirror::{key_path, Reflect};
+    /// use mirror_mirror::key_path::GetPath;
+    ///
+    /// #[derive(Reflect, Clone, Debug)]
+    /// struct Config {
+    ///     volumes: BTreeMap<String, f32>,
+    ///     window: Option<Window>,
+    /// }
+    ///
+    /// #[derive(Reflect, Clone, Debug)]
+    /// struct Window {
+    ///     width: u32,
+    /// }
+    ///
+    /// let config = Config {
+    ///     volumes: BTreeMap::from([("music".to_owned(), 0.5)]),
+    ///     window: None,
+    /// };
+    ///
+    /// assert_eq!(config.get_at_or_default::<f32>(&key_path!(.volumes["music"])), Some(0.5));
+    /// assert_eq!(config.get_at_or_default::<f32>(&key_path!(.volumes["sfx"])), Some(0.0));
+    /// assert_eq!(config.get_at_or_default::<u32>(&key_path!(.window::Some.0.width)), Some(0));
+    ///
+    /// // the value at the path isn't a `String`
+    /// assert_eq!(config.get_at_or_default::<String>(&key_path!(.volumes["music"])), None);
+    /// ```
+    ///
+    /// [`TypeDescriptor`]: crate::TypeDescriptor
+    fn get_at_or_default<T>(&self, key_path: &KeyPath) -> Option<T>
+    where
+        T: FromReflect + DescribeType,
+    {
+        match self.at(key_path) {
+            Some(value) => T::from_reflect(value),
+            None => T::from_reflect(&<T as DescribeType>::type_descriptor().default_value()?),
+        }
+    }
+
     fn at_mut(&mut self, key_path: &KeyPath) -> Option<&mut dyn Reflect>;
 
     fn get_at_mut<T>(&mut self, key_path: &KeyPath) -> Option<&mut T>
@@ -30,6 +85,394 @@ pub trait GetPath {
     {
         self.at_mut(key_path)?.downcast_mut()
     }
+
+    /// Like [`GetPath::at`] but on failure returns an error with the part of the path that
+    /// couldn't be followed.
+    fn try_at(&self, key_path: &KeyPath) -> Result<&dyn Reflect, ReflectError> {
+        match self.at(key_path) {
+            Some(value) => Ok(value),
+            None => Err(path_error(self.at(&KeyPath::default()), key_path)),
+        }
+    }
+
+    /// Like [`GetPath::get_at`] but on failure returns an error with the part of the path that
+    /// couldn't be followed, or the type that was found at the path.
+    fn try_get_at<T>(&self, key_path: &KeyPath) -> Result<&T, ReflectError>
+    where
+        T: Reflect,
+    {
+        self.try_at(key_path)?
+            .try_downcast_ref()
+            .map_err(|error| error.at(key_path.clone()))
+    }
+
+    /// Like [`GetPath::at_mut`] but on failure returns an error with the part of the path that
+    /// couldn't be followed.
+    fn try_at_mut(&mut self, key_path: &KeyPath) -> Result<&mut dyn Reflect, ReflectError> {
+        if self.at(key_path).is_none() {
+            return Err(path_error(self.at(&KeyPath::default()), key_path));
+        }
+        self.at_mut(key_path).ok_or_else(|| {
+            ReflectError::from_parts(format!("`{key_path}`"), None).at(key_path.clone())
+        })
+    }
+
+    /// Like [`GetPath::get_at_mut`] but on failure returns an error with the part of the path
+    /// that couldn't be followed, or the type that was found at the path.
+    fn try_get_at_mut<T>(&mut self, key_path: &KeyPath) -> Result<&mut T, ReflectError>
+    where
+        T: Reflect,
+    {
+        self.try_at_mut(key_path)?
+            .try_downcast_mut()
+            .map_err(|error| error.at(key_path.clone()))
+    }
+
+    /// Replace the value at `key_path` with `value`.
+    ///
+    /// Fails if the path can't be followed or if the value at the path isn't a `T`.
+    ///
+    /// ```
+    /// use mirror_mirror::{key_path, Reflect};
+    /// use mirror_mirror::key_path::GetPath;
+    ///
+    /// #[derive(Reflect, Clone, Debug)]
+    /// struct Player {
+    ///     stats: Stats,
+    /// }
+    ///
+    /// #[derive(Reflect, Clone, Debug)]
+    /// struct Stats {
+    ///     health: u32,
+    /// }
+    ///
+    /// let mut player = Player { stats: Stats { health: 10 } };
+    ///
+    /// player.set_at(&key_path!(.stats.health), 5_u32).unwrap();
+    /// assert_eq!(player.stats.health, 5);
+    ///
+    /// assert!(player.set_at(&key_path!(.stats.health), "full".to_owned()).is_err());
+    /// ```
+    fn set_at<T>(&mut self, key_path: &KeyPath, value: T) -> Result<(), ReflectError>
+    where
+        T: Reflect,
+    {
+        *self.try_get_at_mut(key_path)? = value;
+        Ok(())
+    }
+
+    /// Replace the value at `key_path` with `value`, converted into the type at the path with
+    /// `coercions`.
+    ///
+    /// Fails if the path can't be followed or if no enabled coercion applies.
+    ///
+    /// ```
+    /// use mirror_mirror::{key_path, Reflect};
+    /// use mirror_mirror::coerce::Coercions;
+    /// use mirror_mirror::key_path::GetPath;
+    ///
+    /// #[derive(Reflect, Clone, Debug)]
+    /// struct Player {
+    ///     health: u32,
+    ///     alive: bool,
+    /// }
+    ///
+    /// let mut player = Player { health: 10, alive: true };
+    /// let coercions = Coercions::all();
+    ///
+    /// player.set_at_coerced(&key_path!(.health), &"5".to_owned(), &coercions).unwrap();
+    /// player.set_at_coerced(&key_path!(.alive), &0.0_f64, &coercions).unwrap();
+    /// assert_eq!(player.health, 5);
+    /// assert!(!player.alive);
+    ///
+    /// assert!(player.set_at_coerced(&key_path!(.health), &-1_i32, &coercions).is_err());
+    /// ```
+    fn set_at_coerced(
+        &mut self,
+        key_path: &KeyPath,
+        value: &dyn Reflect,
+        coercions: &Coercions,
+    ) -> Result<(), ReflectError> {
+        let target = self.try_at_mut(key_path)?;
+        let descriptor = target.type_descriptor();
+        let coerced = coercions
+            .coerce(value, descriptor.get_type())
+            .ok_or_else(|| {
+                ReflectError::new(descriptor.type_name(), Some(value)).at(key_path.clone())
+            })?;
+        target.patch(&coerced);
+        Ok(())
+    }
+
+    /// Find all values at key paths matched by `pattern`, along with their paths.
+    ///
+    /// Values are visited depth first, in order of their fields and elements. Sets are skipped
+    /// since their elements can't be accessed by key.
+    ///
+    /// ```
+    /// use mirror_mirror::{key_path, Reflect};
+    /// use mirror_mirror::key_path::{GetPath, KeyPathPattern};
+    ///
+    /// #[derive(Reflect, Clone, Debug)]
+    /// struct Scene {
+    ///     player: Unit,
+    ///     enemies: Vec<Unit>,
+    /// }
+    ///
+    /// #[derive(Reflect, Clone, Debug)]
+    /// struct Unit {
+    ///     health: u32,
+    /// }
+    ///
+    /// let scene = Scene {
+    ///     player: Unit { health: 100 },
+    ///     enemies: Vec::from([Unit { health: 10 }, Unit { health: 20 }]),
+    /// };
+    ///
+    /// let pattern = KeyPathPattern::parse("[**].health").unwrap();
+    /// let healths = scene
+    ///     .get_all_at(&pattern)
+    ///     .map(|(path, value)| (path, *value.downcast_ref::<u32>().unwrap()))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(
+    ///     healths,
+    ///     [
+    ///         (key_path!(.player.health), 100),
+    ///         (key_path!(.enemies[0_usize].health), 10),
+    ///         (key_path!(.enemies[1_usize].health), 20),
+    ///     ],
+    /// );
+    /// ```
+    fn get_all_at(&self, pattern: &KeyPathPattern) -> AllAt<'_> {
+        let mut matches = Vec::new();
+        if let Some(root) = self.at(&KeyPath::default()) {
+            collect_matches(root, pattern, &mut KeyPath::default(), false, &mut matches);
+        }
+        AllAt(matches.into_iter())
+    }
+}
+
+fn collect_matches<'a>(
+    value: &'a dyn Reflect,
+    pattern: &KeyPathPattern,
+    path: &mut KeyPath,
+    in_variant: bool,
+    matches: &mut Vec<(KeyPath, &'a dyn Reflect)>,
+) {
+    if pattern.matches(path) {
+        matches.push((path.clone(), value));
+    }
+    if !pattern.matches_descendant_of(path) {
+        return;
+    }
+
+    match value.reflect_ref() {
+        ReflectRef::Struct(inner) => {
+            for (name, field) in inner.fields() {
+                path.push_field(name);
+                collect_matches(field, pattern, path, false, matches);
+                path.pop();
+            }
+        }
+        ReflectRef::TupleStruct(inner) => {
+            for (index, field) in inner.fields().enumerate() {
+                path.push_field(index);
+                collect_matches(field, pattern, path, false, matches);
+                path.pop();
+            }
+        }
+        ReflectRef::Tuple(inner) => {
+            for (index, field) in inner.fields().enumerate() {
+                path.push_field(index);
+                collect_matches(field, pattern, path, false, matches);
+                path.pop();
+            }
+        }
+        // the variant key selects the same value, so visit it again with the variant pushed
+        ReflectRef::Enum(inner) if !in_variant => {
+            path.push_variant(inner.variant_name());
+            collect_matches(value, pattern, path, true, matches);
+            path.pop();
+        }
+        ReflectRef::Enum(inner) => {
+            for (index, field) in inner.fields().enumerate() {
+                let field = match field {
+                    VariantField::Struct(name, field) => {
+                        path.push_field(name);
+                        field
+                    }
+                    VariantField::Tuple(field) => {
+                        path.push_field(index);
+                        field
+                    }
+                };
+                collect_matches(field, pattern, path, false, matches);
+                path.pop();
+            }
+        }
+        ReflectRef::Array(inner) => {
+            for (index, element) in inner.iter().enumerate() {
+                path.push_get(index);
+                collect_matches(element, pattern, path, false, matches);
+                path.pop();
+            }
+        }
+        ReflectRef::List(inner) => {
+            for (index, element) in inner.iter().enumerate() {
+                path.push_get(index);
+                collect_matches(element, pattern, path, false, matches);
+                path.pop();
+            }
+        }
+        ReflectRef::Map(inner) => {
+            for (key, value) in inner.iter() {
+                path.push_get(key.to_value());
+                collect_matches(value, pattern, path, false, matches);
+                path.pop();
+            }
+        }
+        ReflectRef::Set(_) | ReflectRef::Scalar(_) | ReflectRef::Opaque(_) => {}
+    }
+}
+
+/// Iterator returned by [`GetPath::get_all_at`].
+#[derive(Debug)]
+pub struct AllAt<'a>(alloc::vec::IntoIter<(KeyPath, &'a dyn Reflect)>);
+
+impl<'a> Iterator for AllAt<'a> {
+    type Item = (KeyPath, &'a dyn Reflect);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<'a> DoubleEndedIterator for AllAt<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back()
+    }
+}
+
+impl<'a> ExactSizeIterator for AllAt<'a> {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<'a> FusedIterator for AllAt<'a> {}
+
+// find the first key in `key_path` that can't be followed from `root`
+/// Look up the value directly inside `value` at `key`.
+pub(crate) fn value_at_key<'a>(value: &'a dyn Reflect, key: &Key) -> Option<&'a dyn Reflect> {
+    let value = match key {
+        // .foo
+        Key::Field(NamedOrNumbered::Named(key)) => match value.reflect_ref() {
+            ReflectRef::Struct(inner) => inner.field(key)?,
+            ReflectRef::Enum(inner) => match inner.variant_kind() {
+                VariantKind::Struct => inner.field(key)?,
+                VariantKind::Tuple | VariantKind::Unit => return None,
+            },
+            ReflectRef::TupleStruct(_)
+            | ReflectRef::Tuple(_)
+            | ReflectRef::Array(_)
+            | ReflectRef::List(_)
+            | ReflectRef::Map(_)
+            | ReflectRef::Scalar(_)
+            | ReflectRef::Set(_)
+            | ReflectRef::Opaque(_) => return None,
+        },
+        // .0
+        Key::Field(NamedOrNumbered::Numbered(index)) => match value.reflect_ref() {
+            ReflectRef::TupleStruct(inner) => inner.field_at(*index)?,
+            ReflectRef::Tuple(inner) => inner.field_at(*index)?,
+            ReflectRef::Enum(inner) => match inner.variant_kind() {
+                VariantKind::Tuple => inner.field_at(*index)?,
+                VariantKind::Struct | VariantKind::Unit => return None,
+            },
+            ReflectRef::Map(_)
+            | ReflectRef::Struct(_)
+            | ReflectRef::Array(_)
+            | ReflectRef::List(_)
+            | ReflectRef::Scalar(_)
+            | ReflectRef::Set(_)
+            | ReflectRef::Opaque(_) => return None,
+        },
+        // ["foo"] or [0]
+        Key::Get(key) => match value.reflect_ref() {
+            ReflectRef::Map(inner) => inner.get(key)?,
+            ReflectRef::Array(inner) => inner.get(value_to_usize(key)?)?,
+            ReflectRef::List(inner) => inner.get(value_to_usize(key)?)?,
+            ReflectRef::Struct(_)
+            | ReflectRef::TupleStruct(_)
+            | ReflectRef::Tuple(_)
+            | ReflectRef::Enum(_)
+            | ReflectRef::Scalar(_)
+            | ReflectRef::Set(_)
+            | ReflectRef::Opaque(_) => return None,
+        },
+        // ::Some
+        Key::Variant(variant) => match value.reflect_ref() {
+            ReflectRef::Enum(enum_) => {
+                if enum_.variant_name() == variant {
+                    enum_.as_reflect()
+                } else {
+                    return None;
+                }
+            }
+            ReflectRef::Struct(_)
+            | ReflectRef::TupleStruct(_)
+            | ReflectRef::Tuple(_)
+            | ReflectRef::List(_)
+            | ReflectRef::Array(_)
+            | ReflectRef::Map(_)
+            | ReflectRef::Set(_)
+            | ReflectRef::Opaque(_)
+            | ReflectRef::Scalar(_) => return None,
+        },
+    };
+    Some(value)
+}
+
+fn path_error(root: Option<&dyn Reflect>, key_path: &KeyPath) -> ReflectError {
+    let mut value = root;
+    let mut prefix = KeyPath::default();
+
+    for key in key_path {
+        let Some(parent) = value else { break };
+        prefix.push(key.clone());
+        let key_path = KeyPath::from(key.clone());
+        value = parent.at(&key_path);
+        if value.is_some() {
+            continue;
+        }
+
+        let error = match key {
+            Key::Variant(variant) => {
+                let found = match parent.reflect_ref() {
+                    ReflectRef::Enum(enum_) => {
+                        format!("{}::{}", parent.type_name(), enum_.variant_name())
+                    }
+                    _ => parent.type_name().to_owned(),
+                };
+                ReflectError::from_parts(format!("{}::{variant}", parent.type_name()), Some(found))
+            }
+            Key::Field(_) | Key::Get(_) => {
+                let expected = match parent.type_descriptor().type_at(&key_path) {
+                    Some(ty) => ty.type_name().to_owned(),
+                    None => format!("`{key}` on `{}`", parent.type_name()),
+                };
+                ReflectError::from_parts(expected, None)
+            }
+        };
+        return error.at(prefix);
+    }
+
+    ReflectError::from_parts(format!("`{key_path}`"), None).at(key_path.clone())
 }
 
 pub trait GetTypePath<'a> {
@@ -50,68 +493,7 @@ where
         {
             let head = stack.next()?;
 
-            let value_at_key = match head {
-                // .foo
-                Key::Field(NamedOrNumbered::Named(key)) => match value.reflect_ref() {
-                    ReflectRef::Struct(inner) => inner.field(key)?,
-                    ReflectRef::Enum(inner) => match inner.variant_kind() {
-                        VariantKind::Struct => inner.field(key)?,
-                        VariantKind::Tuple | VariantKind::Unit => return None,
-                    },
-                    ReflectRef::TupleStruct(_)
-                    | ReflectRef::Tuple(_)
-                    | ReflectRef::Array(_)
-                    | ReflectRef::List(_)
-                    | ReflectRef::Map(_)
-                    | ReflectRef::Scalar(_)
-                    | ReflectRef::Opaque(_) => return None,
-                },
-                // .0
-                Key::Field(NamedOrNumbered::Numbered(index)) => match value.reflect_ref() {
-                    ReflectRef::TupleStruct(inner) => inner.field_at(*index)?,
-                    ReflectRef::Tuple(inner) => inner.field_at(*index)?,
-                    ReflectRef::Enum(inner) => match inner.variant_kind() {
-                        VariantKind::Tuple => inner.field_at(*index)?,
-                        VariantKind::Struct | VariantKind::Unit => return None,
-                    },
-                    ReflectRef::Map(_)
-                    | ReflectRef::Struct(_)
-                    | ReflectRef::Array(_)
-                    | ReflectRef::List(_)
-                    | ReflectRef::Scalar(_)
-                    | ReflectRef::Opaque(_) => return None,
-                },
-                // ["foo"] or [0]
-                Key::Get(key) => match value.reflect_ref() {
-                    ReflectRef::Map(inner) => inner.get(key)?,
-                    ReflectRef::Array(inner) => inner.get(value_to_usize(key)?)?,
-                    ReflectRef::List(inner) => inner.get(value_to_usize(key)?)?,
-                    ReflectRef::Struct(_)
-                    | ReflectRef::TupleStruct(_)
-                    | ReflectRef::Tuple(_)
-                    | ReflectRef::Enum(_)
-                    | ReflectRef::Scalar(_)
-                    | ReflectRef::Opaque(_) => return None,
-                },
-                // ::Some
-                Key::Variant(variant) => match value.reflect_ref() {
-                    ReflectRef::Enum(enum_) => {
-                        if enum_.variant_name() == variant {
-                            enum_.as_reflect()
-                        } else {
-                            return None;
-                        }
-                    }
-                    ReflectRef::Struct(_)
-                    | ReflectRef::TupleStruct(_)
-                    | ReflectRef::Tuple(_)
-                    | ReflectRef::List(_)
-                    | ReflectRef::Array(_)
-                    | ReflectRef::Map(_)
-                    | ReflectRef::Opaque(_)
-                    | ReflectRef::Scalar(_) => return None,
-                },
-            };
+            let value_at_key = value_at_key(value.as_reflect(), head)?;
 
             if stack.peek().is_none() {
                 Some(value_at_key)
@@ -151,6 +533,7 @@ where
                     | ReflectMut::List(_)
                     | ReflectMut::Map(_)
                     | ReflectMut::Scalar(_)
+                    | ReflectMut::Set(_)
                     | ReflectMut::Opaque(_) => return None,
                 },
                 // .0
@@ -166,6 +549,7 @@ where
                     | ReflectMut::Array(_)
                     | ReflectMut::List(_)
                     | ReflectMut::Scalar(_)
+                    | ReflectMut::Set(_)
                     | ReflectMut::Opaque(_) => return None,
                 },
                 // ["foo"] or [0]
@@ -178,6 +562,7 @@ where
                     | ReflectMut::Tuple(_)
                     | ReflectMut::Enum(_)
                     | ReflectMut::Scalar(_)
+                    | ReflectMut::Set(_)
                     | ReflectMut::Opaque(_) => return None,
                 },
                 // ::Some
@@ -195,6 +580,7 @@ where
                     | ReflectMut::List(_)
                     | ReflectMut::Array(_)
                     | ReflectMut::Map(_)
+                    | ReflectMut::Set(_)
                     | ReflectMut::Opaque(_)
                     | ReflectMut::Scalar(_) => return None,
                 },
@@ -215,7 +601,7 @@ where
     }
 }
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, PartialOrd, Ord)]
 #[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct KeyPath {
@@ -276,6 +662,332 @@ impl KeyPath {
             index: 1,
         }
     }
+
+    /// Parse a key path from a string.
+    ///
+    /// Uses the same syntax as [`key_path!`] so paths can come from config files or command line
+    /// arguments. The output of [`KeyPath`]'s `Display` implementation can be parsed back.
+    ///
+    /// As with the macro, integers without a suffix are `i32` and floats without a suffix are
+    /// `f64`. Use a suffix such as `[1_u64]` for map keys of other types.
+    ///
+    /// ```
+    /// use mirror_mirror::key_path;
+    /// use mirror_mirror::key_path::KeyPath;
+    ///
+    /// assert_eq!(
+    ///     KeyPath::parse(".employer::Some.0.countries[0].name").unwrap(),
+    ///     key_path!(.employer::Some.0.countries[0].name),
+    /// );
+    ///
+    /// let error = KeyPath::parse(".countries[0.name").unwrap_err();
+    /// assert_eq!(error.position(), 12);
+    /// assert_eq!(error.to_string(), "expected `]` at position 12");
+    /// ```
+    pub fn parse(input: &str) -> Result<Self, ParseKeyPathError> {
+        let pattern = Parser { input, position: 0 }.pattern(false)?;
+        Ok(pattern
+            .pattern
+            .into_iter()
+            .map(|pattern| match pattern {
+                KeyPattern::Key(key) => key,
+                KeyPattern::Any | KeyPattern::AnyDepth => {
+                    unreachable!("parsed wildcard without allowing them")
+                }
+            })
+            .collect())
+    }
+}
+
+impl FromStr for KeyPath {
+    type Err = ParseKeyPathError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// Error returned by [`KeyPath::parse`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ParseKeyPathError {
+    position: usize,
+    expected: &'static str,
+}
+
+impl ParseKeyPathError {
+    /// The byte offset into the input where parsing failed.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// A description of what was expected at [`position`](Self::position).
+    pub fn expected(&self) -> &str {
+        self.expected
+    }
+}
+
+impl fmt::Display for ParseKeyPathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "expected {} at position {}",
+            self.expected, self.position
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseKeyPathError {}
+
+fn error_at(position: usize, expected: &'static str) -> ParseKeyPathError {
+    ParseKeyPathError { position, expected }
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn pattern(mut self, wildcards: bool) -> Result<KeyPathPattern, ParseKeyPathError> {
+        let mut pattern = KeyPathPattern::default();
+        loop {
+            self.skip_whitespace();
+            if self.peek().is_none() {
+                return Ok(pattern);
+            }
+
+            if self.eat("::") {
+                self.skip_whitespace();
+                pattern = pattern.variant(self.ident()?);
+            } else if self.eat(".") {
+                self.skip_whitespace();
+                pattern = match self.peek() {
+                    Some('"') => pattern.field(&self.string()?),
+                    Some(c) if c.is_ascii_digit() => pattern.field(self.index()?),
+                    _ => pattern.field(self.ident()?),
+                };
+            } else if self.eat("[") {
+                self.skip_whitespace();
+                pattern = if wildcards && self.eat("**") {
+                    pattern.any_depth()
+                } else if wildcards && self.eat("*") {
+                    pattern.any()
+                } else {
+                    pattern.get(self.literal()?)
+                };
+                self.skip_whitespace();
+                if !self.eat("]") {
+                    return Err(self.error("`]`"));
+                }
+            } else {
+                return Err(self.error("`.`, `::`, or `[`"));
+            }
+        }
+    }
+
+    fn ident(&mut self) -> Result<&'a str, ParseKeyPathError> {
+        let start = self.position;
+        if !self.peek_is(|c| c == '_' || c.is_alphabetic()) {
+            return Err(self.error("identifier"));
+        }
+        while self.peek_is(|c| c == '_' || c.is_alphanumeric()) {
+            self.bump();
+        }
+        Ok(&self.input[start..self.position])
+    }
+
+    fn index(&mut self) -> Result<usize, ParseKeyPathError> {
+        let start = self.position;
+        while self.peek_is(|c| c.is_ascii_digit()) {
+            self.bump();
+        }
+        self.input[start..self.position]
+            .parse()
+            .map_err(|_| error_at(start, "field index"))
+    }
+
+    fn literal(&mut self) -> Result<Value, ParseKeyPathError> {
+        match self.peek() {
+            Some('"') => Ok(self.string()?.into()),
+            Some('\'') => Ok(self.char()?.into()),
+            Some(c) if c == '-' || c.is_ascii_digit() => self.number(),
+            Some(c) if c.is_alphabetic() => {
+                let start = self.position;
+                match self.ident()? {
+                    "true" => Ok(true.into()),
+                    "false" => Ok(false.into()),
+                    _ => Err(error_at(start, "literal")),
+                }
+            }
+            _ => Err(self.error("literal")),
+        }
+    }
+
+    fn number(&mut self) -> Result<Value, ParseKeyPathError> {
+        let start = self.position;
+        let mut digits = String::new();
+        if self.eat("-") {
+            digits.push('-');
+        }
+        if !self.peek_is(|c| c.is_ascii_digit()) {
+            return Err(self.error("number"));
+        }
+        let mut is_float = false;
+        while let Some(c) = self.peek() {
+            if c.is_ascii_digit() {
+                digits.push(c);
+            } else if c == '.'
+                && !is_float
+                && self.rest()[1..].starts_with(|c: char| c.is_ascii_digit())
+            {
+                is_float = true;
+                digits.push(c);
+            } else if c != '_' {
+                break;
+            }
+            self.bump();
+        }
+
+        let suffix_start = self.position;
+        let suffix = if self.peek_is(char::is_alphabetic) {
+            self.ident()?
+        } else {
+            ""
+        };
+
+        fn parse<T>(digits: &str) -> Option<Value>
+        where
+            T: FromStr + Into<Value>,
+        {
+            digits.parse::<T>().ok().map(Into::into)
+        }
+
+        let value = match suffix {
+            "" if is_float => parse::<f64>(&digits),
+            "" => parse::<i32>(&digits),
+            "f32" => parse::<f32>(&digits),
+            "f64" => parse::<f64>(&digits),
+            _ if is_float => return Err(error_at(suffix_start, "`f32` or `f64` suffix")),
+            "u8" => parse::<u8>(&digits),
+            "u16" => parse::<u16>(&digits),
+            "u32" => parse::<u32>(&digits),
+            "u64" => parse::<u64>(&digits),
+            "u128" => parse::<u128>(&digits),
+            "usize" => parse::<usize>(&digits),
+            "i8" => parse::<i8>(&digits),
+            "i16" => parse::<i16>(&digits),
+            "i32" => parse::<i32>(&digits),
+            "i64" => parse::<i64>(&digits),
+            "i128" => parse::<i128>(&digits),
+            _ => return Err(error_at(suffix_start, "numeric suffix")),
+        };
+        value.ok_or_else(|| error_at(start, "number in range of its type"))
+    }
+
+    fn string(&mut self) -> Result<String, ParseKeyPathError> {
+        if !self.eat("\"") {
+            return Err(self.error("`\"`"));
+        }
+        let mut string = String::new();
+        loop {
+            match self.peek() {
+                Some('"') => {
+                    self.bump();
+                    return Ok(string);
+                }
+                Some('\\') => string.push(self.escape()?),
+                Some(c) => {
+                    self.bump();
+                    string.push(c);
+                }
+                None => return Err(self.error("`\"`")),
+            }
+        }
+    }
+
+    fn char(&mut self) -> Result<char, ParseKeyPathError> {
+        if !self.eat("'") {
+            return Err(self.error("`'`"));
+        }
+        let c = match self.peek() {
+            Some('\\') => self.escape()?,
+            Some(c) if c != '\'' => {
+                self.bump();
+                c
+            }
+            _ => return Err(self.error("character")),
+        };
+        if !self.eat("'") {
+            return Err(self.error("`'`"));
+        }
+        Ok(c)
+    }
+
+    fn escape(&mut self) -> Result<char, ParseKeyPathError> {
+        let start = self.position;
+        self.bump();
+        let c = match self.bump() {
+            Some('n') => '\n',
+            Some('r') => '\r',
+            Some('t') => '\t',
+            Some('0') => '\0',
+            Some(c @ ('\\' | '\'' | '"')) => c,
+            Some('u') if self.eat("{") => {
+                let digits_start = self.position;
+                while self.peek_is(|c| c.is_ascii_hexdigit()) {
+                    self.bump();
+                }
+                let digits = &self.input[digits_start..self.position];
+                if !self.eat("}") {
+                    return Err(self.error("`}`"));
+                }
+                u32::from_str_radix(digits, 16)
+                    .ok()
+                    .and_then(char::from_u32)
+                    .ok_or_else(|| error_at(start, "unicode escape"))?
+            }
+            _ => return Err(error_at(start, "escape sequence")),
+        };
+        Ok(c)
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.input[self.position..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.position += c.len_utf8();
+        Some(c)
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        if self.rest().starts_with(token) {
+            self.position += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn peek_is(&self, f: impl FnOnce(char) -> bool) -> bool {
+        self.peek().map_or(false, f)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek_is(char::is_whitespace) {
+            self.bump();
+        }
+    }
+
+    fn error(&self, expected: &'static str) -> ParseKeyPathError {
+        error_at(self.position, expected)
+    }
 }
 
 impl From<Key> for KeyPath {
@@ -373,7 +1085,7 @@ mod private {
     impl Sealed for usize {}
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash, PartialOrd, Ord)]
 #[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Key {
@@ -414,7 +1126,7 @@ impl fmt::Display for Key {
     }
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash, PartialOrd, Ord)]
 #[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NamedOrNumbered {
@@ -588,7 +1300,8 @@ pub(crate) fn value_to_usize(value: &Value) -> Option<usize> {
         | Value::TupleStructValue(_)
         | Value::TupleValue(_)
         | Value::List(_)
-        | Value::Map(_) => None,
+        | Value::Map(_)
+        | Value::Set(_) => None,
     }
 }
 
@@ -618,3 +1331,736 @@ impl<'a> Iterator for Breadcrumbs<'a> {
         Some(keys)
     }
 }
+
+/// A pattern that matches [`KeyPath`]s.
+///
+/// Patterns are built the same way as key paths but can also contain wildcards, which match any
+/// single key.
+///
+/// ```
+/// use mirror_mirror::key_path;
+/// use mirror_mirror::key_path::KeyPathPattern;
+///
+/// let pattern = KeyPathPattern::default().field("players").any().field("name");
+///
+/// assert!(pattern.matches(&key_path!(.players[0].name)));
+/// assert!(pattern.matches(&key_path!(.players[1].name)));
+/// assert!(!pattern.matches(&key_path!(.players[1].score)));
+/// ```
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KeyPathPattern {
+    pub(crate) pattern: Vec<KeyPattern>,
+}
+
+/// A single segment of a [`KeyPathPattern`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum KeyPattern {
+    /// Matches exactly this key.
+    Key(Key),
+    /// Matches any single key.
+    Any,
+    /// Matches any number of keys, including none.
+    AnyDepth,
+}
+
+impl KeyPattern {
+    fn matches(&self, key: &Key) -> bool {
+        match (self, key) {
+            (KeyPattern::Any | KeyPattern::AnyDepth, _) => true,
+            (KeyPattern::Key(Key::Get(lhs)), Key::Get(rhs)) => {
+                match (value_to_usize(lhs), value_to_usize(rhs)) {
+                    (Some(lhs), Some(rhs)) => lhs == rhs,
+                    _ => lhs == rhs,
+                }
+            }
+            (KeyPattern::Key(lhs), rhs) => lhs == rhs,
+        }
+    }
+}
+
+impl KeyPathPattern {
+    pub fn field(mut self, field: impl IntoKeyOrIndex) -> Self {
+        self.push(KeyPattern::Key(Key::Field(field.into_key_or_index())));
+        self
+    }
+
+    pub fn get(mut self, field: impl Into<Value>) -> Self {
+        self.push(KeyPattern::Key(Key::Get(field.into())));
+        self
+    }
+
+    pub fn variant(mut self, variant: impl Into<String>) -> Self {
+        self.push(KeyPattern::Key(Key::Variant(variant.into())));
+        self
+    }
+
+    /// Add a wildcard that matches any single key.
+    pub fn any(mut self) -> Self {
+        self.push(KeyPattern::Any);
+        self
+    }
+
+    /// Add a wildcard that matches any number of keys, including none.
+    ///
+    /// ```
+    /// use mirror_mirror::key_path;
+    /// use mirror_mirror::key_path::KeyPathPattern;
+    ///
+    /// let pattern = KeyPathPattern::default().any_depth().field("health");
+    ///
+    /// assert!(pattern.matches(&key_path!(.health)));
+    /// assert!(pattern.matches(&key_path!(.players[0].health)));
+    /// assert!(!pattern.matches(&key_path!(.players[0].health.max)));
+    /// ```
+    pub fn any_depth(mut self) -> Self {
+        self.push(KeyPattern::AnyDepth);
+        self
+    }
+
+    /// Parse a pattern from a string.
+    ///
+    /// Uses the same syntax as [`KeyPath::parse`], with `[*]` for [`KeyPattern::Any`] and `[**]`
+    /// for [`KeyPattern::AnyDepth`], which is also how patterns are displayed.
+    ///
+    /// ```
+    /// use mirror_mirror::key_path::KeyPathPattern;
+    ///
+    /// let pattern = KeyPathPattern::parse("[**].players[*].name").unwrap();
+    /// assert_eq!(
+    ///     pattern,
+    ///     KeyPathPattern::default().any_depth().field("players").any().field("name"),
+    /// );
+    /// ```
+    pub fn parse(input: &str) -> Result<Self, ParseKeyPathError> {
+        Parser { input, position: 0 }.pattern(true)
+    }
+
+    pub fn push(&mut self, pattern: KeyPattern) {
+        self.pattern.push(pattern);
+    }
+
+    pub fn len(&self) -> usize {
+        self.pattern.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pattern.is_empty()
+    }
+
+    /// Does the pattern match the whole key path?
+    pub fn matches(&self, key_path: &KeyPath) -> bool {
+        fn go(pattern: &[KeyPattern], keys: &[Key]) -> bool {
+            match pattern.split_first() {
+                None => keys.is_empty(),
+                Some((KeyPattern::AnyDepth, rest)) => {
+                    (0..=keys.len()).any(|skip| go(rest, &keys[skip..]))
+                }
+                Some((pattern, rest)) => match keys.split_first() {
+                    Some((key, keys)) => pattern.matches(key) && go(rest, keys),
+                    None => false,
+                },
+            }
+        }
+
+        go(&self.pattern, &key_path.path)
+    }
+
+    /// Does the pattern match the start of the key path?
+    ///
+    /// That is, is the key path the same as or nested inside a path matched by the pattern.
+    pub fn matches_prefix_of(&self, key_path: &KeyPath) -> bool {
+        fn go(pattern: &[KeyPattern], keys: &[Key]) -> bool {
+            match pattern.split_first() {
+                None => true,
+                Some((KeyPattern::AnyDepth, rest)) => {
+                    (0..=keys.len()).any(|skip| go(rest, &keys[skip..]))
+                }
+                Some((pattern, rest)) => match keys.split_first() {
+                    Some((key, keys)) => pattern.matches(key) && go(rest, keys),
+                    None => false,
+                },
+            }
+        }
+
+        go(&self.pattern, &key_path.path)
+    }
+
+    /// Could the pattern match a path nested inside the key path?
+    ///
+    /// That is, is the key path a strict prefix of a path matched by the pattern.
+    pub fn matches_descendant_of(&self, key_path: &KeyPath) -> bool {
+        fn go(pattern: &[KeyPattern], keys: &[Key]) -> bool {
+            match pattern.split_first() {
+                None => false,
+                // a trailing `AnyDepth` matches everything below the key path
+                Some((KeyPattern::AnyDepth, [])) => true,
+                Some((KeyPattern::AnyDepth, rest)) => {
+                    (0..=keys.len()).any(|skip| go(rest, &keys[skip..]))
+                }
+                Some((pattern, rest)) => match keys.split_first() {
+                    Some((key, keys)) => pattern.matches(key) && go(rest, keys),
+                    None => true,
+                },
+            }
+        }
+
+        go(&self.pattern, &key_path.path)
+    }
+}
+
+impl FromStr for KeyPathPattern {
+    type Err = ParseKeyPathError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl From<KeyPath> for KeyPathPattern {
+    fn from(key_path: KeyPath) -> Self {
+        key_path.into_iter().map(KeyPattern::Key).collect()
+    }
+}
+
+impl From<&KeyPath> for KeyPathPattern {
+    fn from(key_path: &KeyPath) -> Self {
+        key_path.iter().cloned().map(KeyPattern::Key).collect()
+    }
+}
+
+impl FromIterator<KeyPattern> for KeyPathPattern {
+    fn from_iter<T>(iter: T) -> Self
+    where
+        T: IntoIterator<Item = KeyPattern>,
+    {
+        Self {
+            pattern: Vec::from_iter(iter),
+        }
+    }
+}
+
+impl fmt::Display for KeyPathPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for pattern in &self.pattern {
+            match pattern {
+                KeyPattern::Key(key) => write!(f, "{key}")?,
+                KeyPattern::Any => write!(f, "[*]")?,
+                KeyPattern::AnyDepth => write!(f, "[**]")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Generate `n` random key paths that are valid for the type described by `descriptor`.
+///
+/// The paths are valid in the sense that they follow the shape of the type, for example by only
+/// accessing fields that exist. Whether a path actually resolves on some value still depends on
+/// the value, for example which enum variant it holds or how many elements a list has. For lists
+/// and maps the indices and keys used are placeholders in the range `0..4`, and paths into
+/// recursive types are capped at a depth of 16.
+///
+/// Useful for fuzzing code that works with key paths, such as [`GetPath`] implementations or
+/// editors.
+///
+/// # Example
+///
+/// ```
+/// use mirror_mirror::{key_path::sample_paths, DescribeType, Reflect};
+/// use rand_core::{RngCore, impls};
+///
+/// #[derive(Reflect, Clone, Debug)]
+/// struct Foo {
+///     list: Vec<Bar>,
+/// }
+///
+/// #[derive(Reflect, Clone, Debug)]
+/// struct Bar {
+///     n: i32,
+/// }
+///
+/// # struct Rng(u64);
+/// # impl RngCore for Rng {
+/// #     fn next_u32(&mut self) -> u32 { self.next_u64() as u32 }
+/// #     fn next_u64(&mut self) -> u64 {
+/// #         self.0 ^= self.0 << 13;
+/// #         self.0 ^= self.0 >> 7;
+/// #         self.0 ^= self.0 << 17;
+/// #         self.0
+/// #     }
+/// #     fn fill_bytes(&mut self, dest: &mut [u8]) { impls::fill_bytes_via_next(self, dest) }
+/// #     fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+/// #         self.fill_bytes(dest);
+/// #         Ok(())
+/// #     }
+/// # }
+/// let mut rng = Rng(1337);
+/// let paths = sample_paths(&<Foo as DescribeType>::type_descriptor(), 10, &mut rng);
+///
+/// // generates paths such as `.list`, `.list[2]`, and `.list[0].n`
+/// assert_eq!(paths.len(), 10);
+/// ```
+#[cfg(feature = "rand")]
+pub fn sample_paths<R>(descriptor: &crate::TypeDescriptor, n: usize, rng: &mut R) -> Vec<KeyPath>
+where
+    R: rand_core::RngCore + ?Sized,
+{
+    (0..n)
+        .map(|_| sample::sample_path(descriptor.get_type(), rng))
+        .collect()
+}
+
+#[cfg(feature = "rand")]
+mod sample {
+    use alloc::string::ToString;
+
+    use rand_core::RngCore;
+
+    use super::KeyPath;
+    use crate::type_info::ScalarType;
+    use crate::type_info::Type;
+    use crate::type_info::VariantField;
+    use crate::Value;
+
+    const MAX_DEPTH: usize = 16;
+
+    const PLACEHOLDER_INDICES: usize = 4;
+
+    pub(super) fn sample_path<R>(mut ty: Type<'_>, rng: &mut R) -> KeyPath
+    where
+        R: RngCore + ?Sized,
+    {
+        let mut path = KeyPath::default();
+
+        for _ in 0..MAX_DEPTH {
+            // stop at random so paths of all lengths are generated, not just the longest ones
+            if below(rng, 4) == 0 {
+                break;
+            }
+
+            ty = match ty {
+                Type::Struct(struct_) => {
+                    if struct_.fields_len() == 0 {
+                        break;
+                    }
+                    let field = struct_
+                        .field_type_at(below(rng, struct_.fields_len()))
+                        .unwrap();
+                    path.push_field(field.name());
+                    field.get_type()
+                }
+                Type::TupleStruct(tuple_struct) => {
+                    if tuple_struct.fields_len() == 0 {
+                        break;
+                    }
+                    let index = below(rng, tuple_struct.fields_len());
+                    path.push_field(index);
+                    tuple_struct.field_type_at(index).unwrap().get_type()
+                }
+                Type::Tuple(tuple) => {
+                    if tuple.fields_len() == 0 {
+                        break;
+                    }
+                    let index = below(rng, tuple.fields_len());
+                    path.push_field(index);
+                    tuple.field_type_at(index).unwrap().get_type()
+                }
+                Type::Enum(enum_) => {
+                    if enum_.variants_len() == 0 {
+                        break;
+                    }
+                    let variant = enum_
+                        .variants()
+                        .nth(below(rng, enum_.variants_len()))
+                        .unwrap();
+                    path.push_variant(variant.name());
+                    if variant.fields_len() == 0 {
+                        break;
+                    }
+                    let index = below(rng, variant.fields_len());
+                    let field = variant.field_type_at(index).unwrap();
+                    match field {
+                        VariantField::Named(field) => path.push_field(field.name()),
+                        VariantField::Unnamed(_) => path.push_field(index),
+                    }
+                    field.get_type()
+                }
+                Type::Array(array) => {
+                    if array.is_empty() {
+                        break;
+                    }
+                    path.push_get(below(rng, array.len()));
+                    array.element_type()
+                }
+                Type::List(list) => {
+                    path.push_get(below(rng, PLACEHOLDER_INDICES));
+                    list.element_type()
+                }
+                Type::Map(map) => {
+                    let Some(key) = placeholder_key(map.key_type(), rng) else {
+                        break;
+                    };
+                    path.push_get(key);
+                    map.value_type()
+                }
+                Type::Set(_) | Type::Scalar(_) | Type::Opaque(_) => break,
+            };
+        }
+
+        path
+    }
+
+    fn placeholder_key<R>(ty: Type<'_>, rng: &mut R) -> Option<Value>
+    where
+        R: RngCore + ?Sized,
+    {
+        let Type::Scalar(scalar) = ty else {
+            return ty.default_value();
+        };
+
+        let index = below(rng, PLACEHOLDER_INDICES);
+        let key = match scalar {
+            ScalarType::usize => Value::from(index),
+            ScalarType::u8 => Value::from(index as u8),
+            ScalarType::u16 => Value::from(index as u16),
+            ScalarType::u32 => Value::from(index as u32),
+            ScalarType::u64 => Value::from(index as u64),
+            ScalarType::u128 => Value::from(index as u128),
+            ScalarType::i8 => Value::from(index as i8),
+            ScalarType::i16 => Value::from(index as i16),
+            ScalarType::i32 => Value::from(index as i32),
+            ScalarType::i64 => Value::from(index as i64),
+            ScalarType::i128 => Value::from(index as i128),
+            ScalarType::String => Value::from(index.to_string()),
+            ScalarType::bool | ScalarType::char | ScalarType::f32 | ScalarType::f64 => {
+                scalar.default_value()
+            }
+        };
+        Some(key)
+    }
+
+    // a random number in `0..n`. The modulo bias doesn't matter for this
+    fn below<R>(rng: &mut R, n: usize) -> usize
+    where
+        R: RngCore + ?Sized,
+    {
+        (rng.next_u64() % n as u64) as usize
+    }
+}
+
+/// A deterministic mapping between the static key paths of a type and compact integer ids.
+///
+/// Useful for replication, where network messages can reference fields with a couple of bytes
+/// rather than a whole [`KeyPath`].
+///
+/// The paths are enumerated depth first, in declaration order, starting with the empty path
+/// (the value itself) which always has id `0`. Given the same type the ids are the same on every
+/// machine and in every build, but changing the type changes the ids.
+///
+/// Only static paths are included. That is fields of structs, tuple structs, and tuples, fields
+/// of enum variants, and elements of arrays. Elements of lists and maps depend on the value so
+/// they're not descended into, nor are types that are already being visited further up the path,
+/// which would otherwise make recursive types infinite.
+///
+/// # Example
+///
+/// ```
+/// use mirror_mirror::{key_path, key_path::KeyPathIds, DescribeType, Reflect};
+///
+/// #[derive(Reflect, Clone, Debug)]
+/// struct Player {
+///     position: [f32; 2],
+///     name: String,
+/// }
+///
+/// let ids = KeyPathIds::new(&<Player as DescribeType>::type_descriptor());
+///
+/// let path = key_path!(.position[1_usize]);
+/// let id = ids.id(&path).unwrap();
+/// assert_eq!(id, 3);
+/// assert_eq!(ids.path(id).unwrap(), &path);
+///
+/// // `.position`, `.position[0]`, `.position[1]`, and `.name`, plus the empty path
+/// assert_eq!(ids.len(), 5);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct KeyPathIds {
+    paths: Vec<KeyPath>,
+    ids: BTreeMap<KeyPath, u32>,
+}
+
+impl KeyPathIds {
+    pub fn new(descriptor: &crate::TypeDescriptor) -> Self {
+        let mut paths = Vec::new();
+        ids::collect(
+            descriptor.get_type(),
+            &mut KeyPath::default(),
+            &mut Vec::new(),
+            &mut paths,
+        );
+
+        let ids = paths
+            .iter()
+            .enumerate()
+            .map(|(id, path)| (path.clone(), id as u32))
+            .collect();
+
+        Self { paths, ids }
+    }
+
+    /// Get the id of a path, if it's a static path of the type.
+    pub fn id(&self, key_path: &KeyPath) -> Option<u32> {
+        self.ids.get(key_path).copied()
+    }
+
+    /// Get the path with the given id.
+    pub fn path(&self, id: u32) -> Option<&KeyPath> {
+        self.paths.get(id as usize)
+    }
+
+    pub fn len(&self) -> usize {
+        self.paths.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.paths.is_empty()
+    }
+
+    /// Iterate over all paths in order of their ids.
+    pub fn iter(&self) -> impl Iterator<Item = (u32, &KeyPath)> + '_ {
+        self.paths
+            .iter()
+            .enumerate()
+            .map(|(id, path)| (id as u32, path))
+    }
+}
+
+/// A set of key paths, such as pinned properties or the layout of an editor, that can be checked
+/// against a [`TypeDescriptor`](crate::TypeDescriptor) in bulk.
+///
+/// Paths are kept in the order they were first inserted. When the type changes,
+/// [`PathSet::invalidated`] reports which paths no longer lead anywhere so saved paths can be
+/// repaired rather than silently pointing at nothing, and [`PathSet::retain_valid`] removes them.
+///
+/// # Example
+///
+/// ```
+/// use mirror_mirror::{key_path, key_path::PathSet, DescribeType, Reflect};
+///
+/// #[derive(Reflect, Clone, Debug)]
+/// struct Before {
+///     position: [f32; 2],
+///     speed: f32,
+/// }
+///
+/// #[derive(Reflect, Clone, Debug)]
+/// struct After {
+///     position: [f32; 2],
+///     velocity: [f32; 2],
+/// }
+///
+/// let mut pinned = PathSet::from_iter([key_path!(.position[1_usize]), key_path!(.speed)]);
+///
+/// let before = <Before as DescribeType>::type_descriptor();
+/// let after = <After as DescribeType>::type_descriptor();
+/// assert!(pinned.invalid(&before).is_empty());
+/// assert_eq!(pinned.invalidated(&before, &after), [&key_path!(.speed)]);
+///
+/// assert_eq!(pinned.retain_valid(&after), [key_path!(.speed)]);
+/// assert_eq!(pinned.len(), 1);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PathSet {
+    paths: Vec<KeyPath>,
+}
+
+impl PathSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Insert a path, returning `false` if it was already in the set.
+    pub fn insert(&mut self, key_path: KeyPath) -> bool {
+        if self.contains(&key_path) {
+            return false;
+        }
+        self.paths.push(key_path);
+        true
+    }
+
+    /// Remove a path, returning `false` if it wasn't in the set.
+    pub fn remove(&mut self, key_path: &KeyPath) -> bool {
+        let len = self.paths.len();
+        self.paths.retain(|path| path != key_path);
+        self.paths.len() != len
+    }
+
+    pub fn contains(&self, key_path: &KeyPath) -> bool {
+        self.paths.contains(key_path)
+    }
+
+    pub fn len(&self) -> usize {
+        self.paths.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.paths.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.paths.clear();
+    }
+
+    /// Iterate over the paths in the order they were inserted.
+    pub fn iter(&self) -> alloc::slice::Iter<'_, KeyPath> {
+        self.paths.iter()
+    }
+
+    /// Whether `key_path` leads to a type in `descriptor`.
+    ///
+    /// Elements of lists and maps depend on the value, so any index or key into them is valid as
+    /// long as the rest of the path is.
+    pub fn is_valid(key_path: &KeyPath, descriptor: &crate::TypeDescriptor) -> bool {
+        descriptor.type_at(key_path).is_some()
+    }
+
+    /// The paths that don't lead to a type in `descriptor`.
+    pub fn invalid(&self, descriptor: &crate::TypeDescriptor) -> Vec<&KeyPath> {
+        self.paths
+            .iter()
+            .filter(|path| !Self::is_valid(path, descriptor))
+            .collect()
+    }
+
+    /// The paths that were valid for `old` but aren't for `new`, such as after a field was renamed
+    /// or removed.
+    ///
+    /// Paths that were already invalid for `old` aren't included.
+    pub fn invalidated(
+        &self,
+        old: &crate::TypeDescriptor,
+        new: &crate::TypeDescriptor,
+    ) -> Vec<&KeyPath> {
+        self.paths
+            .iter()
+            .filter(|path| Self::is_valid(path, old) && !Self::is_valid(path, new))
+            .collect()
+    }
+
+    /// Remove the paths that don't lead to a type in `descriptor`, returning them in the order
+    /// they were inserted.
+    pub fn retain_valid(&mut self, descriptor: &crate::TypeDescriptor) -> Vec<KeyPath> {
+        let (valid, invalid) = core::mem::take(&mut self.paths)
+            .into_iter()
+            .partition(|path| Self::is_valid(path, descriptor));
+        self.paths = valid;
+        invalid
+    }
+}
+
+impl FromIterator<KeyPath> for PathSet {
+    fn from_iter<T>(iter: T) -> Self
+    where
+        T: IntoIterator<Item = KeyPath>,
+    {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<KeyPath> for PathSet {
+    fn extend<T>(&mut self, iter: T)
+    where
+        T: IntoIterator<Item = KeyPath>,
+    {
+        for key_path in iter {
+            self.insert(key_path);
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a PathSet {
+    type Item = &'a KeyPath;
+    type IntoIter = alloc::slice::Iter<'a, KeyPath>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+mod ids {
+    use alloc::vec::Vec;
+
+    use super::KeyPath;
+    use crate::type_info::Type;
+    use crate::type_info::VariantField;
+
+    pub(super) fn collect<'a>(
+        ty: Type<'a>,
+        path: &mut KeyPath,
+        stack: &mut Vec<&'a str>,
+        out: &mut Vec<KeyPath>,
+    ) {
+        out.push(path.clone());
+
+        if stack.contains(&ty.type_name()) {
+            return;
+        }
+        stack.push(ty.type_name());
+
+        match ty {
+            Type::Struct(struct_) => {
+                for field in struct_.field_types() {
+                    path.push_field(field.name());
+                    collect(field.get_type(), path, stack, out);
+                    path.pop();
+                }
+            }
+            Type::TupleStruct(tuple_struct) => {
+                for (index, field) in tuple_struct.field_types().enumerate() {
+                    path.push_field(index);
+                    collect(field.get_type(), path, stack, out);
+                    path.pop();
+                }
+            }
+            Type::Tuple(tuple) => {
+                for (index, field) in tuple.field_types().enumerate() {
+                    path.push_field(index);
+                    collect(field.get_type(), path, stack, out);
+                    path.pop();
+                }
+            }
+            Type::Enum(enum_) => {
+                for variant in enum_.variants() {
+                    path.push_variant(variant.name());
+                    for (index, field) in variant.field_types().enumerate() {
+                        match field {
+                            VariantField::Named(field) => path.push_field(field.name()),
+                            VariantField::Unnamed(_) => path.push_field(index),
+                        }
+                        collect(field.get_type(), path, stack, out);
+                        path.pop();
+                    }
+                    path.pop();
+                }
+            }
+            Type::Array(array) => {
+                for index in 0..array.len() {
+                    path.push_get(index);
+                    collect(array.element_type(), path, stack, out);
+                    path.pop();
+                }
+            }
+            Type::List(_) | Type::Map(_) | Type::Set(_) | Type::Scalar(_) | Type::Opaque(_) => {}
+        }
+
+        stack.pop();
+    }
+}
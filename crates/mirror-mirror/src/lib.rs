@@ -198,16 +198,65 @@
 //! ---|---|---
 //! `std` | Enables using the standard library (`core` and `alloc` are always required) | Yes
 //! `speedy` | Enables [`speedy`] support for most types | Yes
+//! `speedy_evolution` | Enables the [`evolution`] encoding, which tolerates changes to types | No
 //! `serde` | Enables [`serde`] support for most types | Yes
 //! `glam` | Enables impls for [`glam`] | No
 //! `macaw` | Enables impls for [`macaw`] | No
+//! `chrono` | Enables impls for [`chrono`] dates, times, and durations | No
+//! `time` | Enables impls for [`time`] dates, times, and durations | No
+//! `uuid` | Enables impls for [`uuid`] | No
+//! `camino` | Enables impls for [`camino`] paths | No
+//! `smallvec` | Enables impls for [`smallvec`] | No
+//! `arrayvec` | Enables impls for [`arrayvec`] | No
+//! `tinyvec` | Enables impls for [`tinyvec`] | No
+//! `log` | Enables logging reflected values with [`log`] | No
+//! `schema` | Enables generating [JSON Schema] from type descriptors, using [`serde_json`] | No
+//! `json` | Enables reading and writing values as JSON text with [`value::to_json_string`] and [`value::from_json_str`] | No
+//! `ron` | Enables reading and writing values as [RON] text with [`value::to_ron_string`] and [`value::from_ron_str`] | No
+//! `fuzz` | Enables the [`fuzz`] entry points for fuzzing decoding, and `speedy_evolution` | No
+//! `rand` | Enables [`key_path::sample_paths`] for generating random key paths, using [`rand_core`] | No
+//! `no_panic` | Don't panic on faulty `Enum` implementations, see below | No
+//!
+//! ## `no_panic`
+//!
+//! Some conversions panic when they're given data that breaks their invariants, such as a
+//! faulty [`Enum`] implementation whose fields don't match its variant kind. With `no_panic`
+//! these cases are instead reported through [`FromReflect::try_from_reflect`] and the other
+//! fallible APIs rather than bringing down the process. Useful when reflecting on untrusted
+//! content:
+//!
+//! - [`EnumValue`](enum_::EnumValue)'s [`FromReflect`] returns `None` for enums whose fields don't
+//!   match their variant kind, and [`reflect_debug`] prints them as they are.
+//!
+//! Other cases have fallible versions that are available regardless of the feature:
+//!
+//! - [`Reflect::try_to_value`] returns an error for values containing enum variants marked
+//!   `#[reflect(skip)]`, where [`to_value`](Reflect::to_value) panics.
+//! - [`TypeGraph::try_get`](type_info::graph::TypeGraph::try_get) returns `None` for nodes missing
+//!   from a graph. Descriptors read with `speedy` or `serde` are validated, so this only happens
+//!   when a descriptor was constructed incorrectly.
+//!
+//! Panics that document misuse of an API, such as [`List::remove`] with an index out of bounds,
+//! are unaffected.
 //!
 //! [`speedy`]: https://crates.io/crates/speedy
 //! [`serde`]: https://crates.io/crates/serde
 //! [`bevy_reflect`]: https://crates.io/crates/bevy_reflect
+//! [`rand_core`]: https://crates.io/crates/rand_core
 //! [`bevy`]: https://crates.io/crates/bevy
 //! [`glam`]: https://crates.io/crates/glam
+//! [`chrono`]: https://crates.io/crates/chrono
+//! [`time`]: https://crates.io/crates/time
+//! [`uuid`]: https://crates.io/crates/uuid
+//! [`camino`]: https://crates.io/crates/camino
+//! [`smallvec`]: https://crates.io/crates/smallvec
+//! [`arrayvec`]: https://crates.io/crates/arrayvec
+//! [`tinyvec`]: https://crates.io/crates/tinyvec
 //! [`macaw`]: https://crates.io/crates/macaw
+//! [`log`]: https://crates.io/crates/log
+//! [JSON Schema]: https://json-schema.org
+//! [`serde_json`]: https://crates.io/crates/serde_json
+//! [RON]: https://github.com/ron-rs/ron
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![warn(
@@ -264,10 +313,29 @@ use alloc::boxed::Box;
 use alloc::string::String;
 use core::any::Any;
 use core::any::TypeId;
-use core::fmt;
 
 use crate::enum_::VariantField;
 use crate::enum_::VariantKind;
+use crate::option::OptionMut;
+use crate::option::OptionRef;
+use crate::patch::PatchError;
+use crate::patch::PatchReport;
+
+/// Panic with the given message or, with the `no_panic` feature, evaluate `$fallback` instead.
+///
+/// Used where malformed data or a faulty `Reflect` implementation would otherwise cause a panic.
+macro_rules! panic_or {
+    ($fallback:expr, $($arg:tt)*) => {{
+        #[cfg(feature = "no_panic")]
+        {
+            $fallback
+        }
+        #[cfg(not(feature = "no_panic"))]
+        {
+            panic!($($arg)*)
+        }
+    }};
+}
 
 macro_rules! trivial_reflect_methods {
     () => {
@@ -298,9 +366,36 @@ macro_rules! trivial_reflect_methods {
 /// Reflected array types.
 pub mod array;
 
+/// Configurable implicit conversions between reflected types, for scripting bridges.
+pub mod coerce;
+
+/// Checking that reflection impls behave like derived ones.
+pub mod conformance;
+
+/// Structural diffs between reflected values, for replication and undo.
+pub mod diff;
+
+/// Dumping reflected values to files and diffing dumps offline.
+#[cfg(feature = "speedy")]
+pub mod dump;
+
+/// Undoable edits of reflected values.
+pub mod edit;
+
 /// Reflected enum types.
 pub mod enum_;
 
+/// A binary encoding of reflected values that tolerates changes to their types.
+#[cfg(feature = "speedy_evolution")]
+pub mod evolution;
+
+/// Configurable formatting of reflected values, for debug overlays and logs.
+pub mod fmt;
+
+/// Entry points for fuzzing the decoding of values and type descriptors.
+#[cfg(feature = "fuzz")]
+pub mod fuzz;
+
 /// Helper traits for accessing fields on reflected values.
 pub mod get_field;
 
@@ -313,12 +408,56 @@ pub mod key_path;
 /// Reflected list types.
 pub mod list;
 
+/// Reflection for values that aren't `Send`.
+pub mod local;
+
+/// Extracting localizable strings from reflected values.
+pub mod localize;
+
+/// Truncated and redacted formatting of reflected values for logs.
+pub mod logging;
+
 /// Reflected map types.
 pub mod map;
 
+/// Metadata keys with known types.
+pub mod meta;
+
+/// Views of reflected [`Option`]s.
+pub mod option;
+
+/// Patching reflected values with reports of what was applied.
+pub mod patch;
+
+/// Key path based access control for reflected values.
+pub mod permissions;
+
+/// Finding values nested inside reflected values by type, metadata, or predicate.
+pub mod query;
+
+/// Looking up types and converting values into them by name at runtime.
+pub mod registry;
+
+/// Generating JSON Schemas from type descriptors.
+#[cfg(feature = "schema")]
+pub mod schema;
+
+/// `serde` support driven by type descriptors, for loading data from formats like JSON or RON.
+#[cfg(feature = "serde")]
+pub mod serde;
+
+/// Reflected set types.
+pub mod set;
+
 /// Reflected struct types.
 pub mod struct_;
 
+/// Flattening reflected values into tables, such as CSV files.
+pub mod table;
+
+/// Change detection for reflected values.
+pub mod tracked;
+
 /// Reflected tuple types.
 pub mod tuple;
 
@@ -328,15 +467,45 @@ pub mod tuple_struct;
 /// Type information.
 pub mod type_info;
 
+/// Layout hints for inspectors, read from type metadata.
+pub mod ui_hints;
+
+/// Checking reflected values with the validators attached by `#[reflect(validate_with(...))]`.
+pub mod validate;
+
 /// Type erased value types.
 pub mod value;
 
+/// Borrowed views of reflected values that serialize like [`Value`] without cloning.
+pub mod value_ref;
+
+/// Schema versioned binary encoding of reflected types.
+#[cfg(feature = "speedy")]
+pub mod versioned;
+
+/// Deep traversal of reflected values with visitors.
+pub mod visit;
+
 pub mod try_visit;
 
+mod assert_type_shape;
+mod error;
 mod foreign_impls;
+mod opaque;
+mod reflect_cmp;
 mod reflect_eq;
+mod reflect_hash;
+mod scratch;
 
+pub use error::ReflectError;
+pub use opaque::Opaque;
+pub use reflect_cmp::reflect_cmp;
 pub use reflect_eq::reflect_eq;
+pub use reflect_eq::reflect_eq_widening;
+pub use reflect_hash::reflect_hash;
+pub use reflect_hash::reflect_hash_with;
+pub use reflect_hash::NanHandling;
+pub use reflect_hash::ReflectHashError;
 
 #[cfg(feature = "std")]
 #[cfg(test)]
@@ -356,7 +525,12 @@ pub use self::get_field::GetFieldMut;
 #[doc(inline)]
 pub use self::list::List;
 #[doc(inline)]
+pub use self::local::LocalReflect;
+#[doc(inline)]
 pub use self::map::Map;
+
+#[doc(inline)]
+pub use self::set::Set;
 #[doc(inline)]
 pub use self::struct_::Struct;
 #[doc(inline)]
@@ -369,6 +543,8 @@ pub use self::type_info::DescribeType;
 pub use self::type_info::TypeDescriptor;
 #[doc(inline)]
 pub use self::value::Value;
+#[doc(inline)]
+pub use self::value_ref::ValueRef;
 
 pub(crate) static STATIC_RANDOM_STATE: ahash::RandomState = ahash::RandomState::with_seeds(
     0x86c11a44c63f4f2f,
@@ -379,6 +555,34 @@ pub(crate) static STATIC_RANDOM_STATE: ahash::RandomState = ahash::RandomState::
 
 /// A reflected type.
 pub trait Reflect: Any + Send + 'static {
+    /// The [`TypeDescriptor`] of the value's concrete type.
+    ///
+    /// Unlike [`DescribeType::type_descriptor`] this doesn't require naming the type, so it also
+    /// works for `dyn Reflect`s whose type isn't known to the caller, such as values handed over
+    /// by plugins. The descriptor can then be used with APIs that need the full type, such as
+    /// typed serialization or generating schemas.
+    ///
+    /// [`Value`]s describe themselves as opaque values, not as the type they were created from.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mirror_mirror::{DescribeType, Reflect};
+    /// use mirror_mirror::type_info::Type;
+    ///
+    /// #[derive(Reflect, Clone, Debug)]
+    /// struct Player {
+    ///     name: String,
+    /// }
+    ///
+    /// let value: Box<dyn Reflect> = Box::new(Player {
+    ///     name: "Alice".to_owned(),
+    /// });
+    ///
+    /// let descriptor = value.type_descriptor();
+    /// assert_eq!(descriptor, <Player as DescribeType>::type_descriptor());
+    /// assert!(matches!(descriptor.get_type(), Type::Struct(_)));
+    /// ```
     fn type_descriptor(&self) -> Cow<'static, TypeDescriptor>;
 
     fn as_any(&self) -> &dyn Any;
@@ -397,11 +601,130 @@ pub trait Reflect: Any + Send + 'static {
 
     fn patch(&mut self, value: &dyn Reflect);
 
+    /// Like [`patch`](Self::patch) but reports which parts of `value` were applied, and returns
+    /// an error if any of them couldn't be.
+    ///
+    /// Parts that don't match the shape of `self`, such as fields `self` doesn't have or scalars
+    /// of the wrong type, are skipped and everything else is still applied. Elements are only
+    /// patched in place, so elements of lists and entries of maps that `self` doesn't have are
+    /// skipped.
+    ///
+    /// If the patched values, or the values containing them, are then rejected by their
+    /// validators, see [`validate_self`](Self::validate_self), the whole patch is undone and the
+    /// violation is reported.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mirror_mirror::{key_path, Reflect, Value};
+    /// use mirror_mirror::struct_::StructValue;
+    ///
+    /// #[derive(Reflect, Clone, Debug)]
+    /// struct Player {
+    ///     name: String,
+    ///     health: f32,
+    /// }
+    ///
+    /// let mut player = Player {
+    ///     name: "Alice".to_owned(),
+    ///     health: 100.0,
+    /// };
+    ///
+    /// let patch = StructValue::new()
+    ///     .with_field("name", "Bob")
+    ///     .with_field("health", 50_i32);
+    ///
+    /// let error = player.try_patch(&patch).unwrap_err();
+    ///
+    /// // the name was still patched
+    /// assert_eq!(player.name, "Bob");
+    /// assert_eq!(error.report().applied(), [key_path!(.name)]);
+    ///
+    /// let skipped = &error.report().skipped()[0];
+    /// assert_eq!(skipped.path(), &key_path!(.health));
+    /// assert_eq!(skipped.expected(), "f32");
+    /// assert_eq!(skipped.found(), Some("i32"));
+    /// ```
+    fn try_patch(&mut self, value: &dyn Reflect) -> Result<PatchReport, PatchError> {
+        patch::try_patch(self.as_reflect_mut(), value)
+    }
+
+    /// Check this value with the validators attached to its type and fields with
+    /// `#[reflect(validate_with(...))]`.
+    ///
+    /// Values nested inside this one aren't checked, see [`validate::validate`] for checking a
+    /// whole value. Types without validators accept every value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mirror_mirror::{key_path, Reflect};
+    ///
+    /// #[derive(Reflect, Debug, Clone)]
+    /// struct Player {
+    ///     #[reflect(validate_with(non_negative))]
+    ///     health: f32,
+    /// }
+    ///
+    /// fn non_negative(n: &f32) -> Result<(), String> {
+    ///     if *n >= 0.0 {
+    ///         Ok(())
+    ///     } else {
+    ///         Err(format!("{n} is negative"))
+    ///     }
+    /// }
+    ///
+    /// let error = Player { health: -1.0 }.validate_self().unwrap_err();
+    /// assert_eq!(error.reason(), Some("-1 is negative"));
+    /// assert_eq!(error.path(), &key_path!(.health));
+    /// ```
+    fn validate_self(&self) -> Result<(), ReflectError> {
+        Ok(())
+    }
+
     fn to_value(&self) -> Value;
 
+    /// Like [`to_value`](Self::to_value) but returns an error, rather than panicking, if the value
+    /// contains an enum variant marked `#[reflect(skip)]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mirror_mirror::{Reflect, key_path};
+    ///
+    /// #[derive(Reflect, Debug, Clone)]
+    /// enum Shape {
+    ///     Circle(f32),
+    ///     #[reflect(skip)]
+    ///     Custom(NotReflect),
+    /// }
+    ///
+    /// #[derive(Debug, Clone, Default)]
+    /// struct NotReflect;
+    ///
+    /// assert!(Shape::Circle(1.0).try_to_value().is_ok());
+    ///
+    /// let error = vec![Shape::Custom(NotReflect)].try_to_value().unwrap_err();
+    /// assert_eq!(error.path(), &key_path!([0_usize]));
+    /// ```
+    fn try_to_value(&self) -> Result<Value, ReflectError> {
+        match error::find_skipped_variant(self.as_reflect()) {
+            Some(error) => Err(error),
+            None => Ok(self.to_value()),
+        }
+    }
+
+    /// A borrowed view of this value that serializes exactly like [`Reflect::to_value`] without
+    /// cloning it first.
+    ///
+    /// See [`ValueRef`].
+    fn to_value_ref(&self) -> ValueRef<'_> {
+        ValueRef::new(self.as_reflect())
+    }
+
     fn clone_reflect(&self) -> Box<dyn Reflect>;
 
-    fn debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+    fn debug(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result;
 
     fn type_id(&self) -> TypeId {
         TypeId::of::<Self>()
@@ -459,6 +782,24 @@ pub trait Reflect: Any + Send + 'static {
         self.reflect_mut().as_enum_mut()
     }
 
+    /// View this value as an [`Option`], if it is one.
+    ///
+    /// Only works for actual `Option`s, not [`EnumValue`]s created from them.
+    ///
+    /// [`EnumValue`]: crate::enum_::EnumValue
+    fn as_option(&self) -> Option<OptionRef<'_>> {
+        OptionRef::new(self.as_reflect())
+    }
+
+    /// View this value as a mutable [`Option`], if it is one.
+    ///
+    /// Only works for actual `Option`s, not [`EnumValue`]s created from them.
+    ///
+    /// [`EnumValue`]: crate::enum_::EnumValue
+    fn as_option_mut(&mut self) -> Option<OptionMut<'_>> {
+        OptionMut::new(self.as_reflect_mut())
+    }
+
     fn into_list(self: Box<Self>) -> Option<Box<dyn List>> {
         self.reflect_owned().into_list()
     }
@@ -495,6 +836,18 @@ pub trait Reflect: Any + Send + 'static {
         self.reflect_mut().as_map_mut()
     }
 
+    fn into_set(self: Box<Self>) -> Option<Box<dyn Set>> {
+        self.reflect_owned().into_set()
+    }
+
+    fn as_set(&self) -> Option<&dyn Set> {
+        self.reflect_ref().as_set()
+    }
+
+    fn as_set_mut(&mut self) -> Option<&mut dyn Set> {
+        self.reflect_mut().as_set_mut()
+    }
+
     fn into_scalar(self: Box<Self>) -> Option<ScalarOwned> {
         self.reflect_owned().into_scalar()
     }
@@ -522,6 +875,25 @@ impl dyn Reflect {
     {
         self.as_any_mut().downcast_mut::<T>()
     }
+
+    pub fn try_downcast_ref<T>(&self) -> Result<&T, ReflectError>
+    where
+        T: Reflect,
+    {
+        self.downcast_ref::<T>()
+            .ok_or_else(|| ReflectError::new(core::any::type_name::<T>(), Some(self)))
+    }
+
+    pub fn try_downcast_mut<T>(&mut self) -> Result<&mut T, ReflectError>
+    where
+        T: Reflect,
+    {
+        if self.as_any().is::<T>() {
+            Ok(self.downcast_mut::<T>().unwrap())
+        } else {
+            Err(ReflectError::new(core::any::type_name::<T>(), Some(self)))
+        }
+    }
 }
 
 impl ToOwned for dyn Reflect {
@@ -532,8 +904,8 @@ impl ToOwned for dyn Reflect {
     }
 }
 
-impl fmt::Debug for dyn Reflect {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl core::fmt::Debug for dyn Reflect {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         self.debug(f)
     }
 }
@@ -552,7 +924,7 @@ macro_rules! impl_for_core_types {
 
                 fn patch(&mut self, value: &dyn Reflect) {
                     if let Some(value) = value.as_any().downcast_ref::<Self>() {
-                        *self = value.clone();
+                        self.clone_from(value);
                     }
                 }
 
@@ -576,7 +948,7 @@ macro_rules! impl_for_core_types {
                     ReflectMut::Scalar(ScalarMut::from(self))
                 }
 
-                fn debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fn debug(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
                     if f.alternate() {
                         write!(f, "{:#?}", self)
                     } else {
@@ -624,7 +996,7 @@ impl Reflect for String {
 
     fn patch(&mut self, value: &dyn Reflect) {
         if let Some(value) = value.as_any().downcast_ref::<Self>() {
-            *self = value.clone();
+            self.clone_from(value);
         }
     }
 
@@ -648,7 +1020,7 @@ impl Reflect for String {
         ReflectMut::Scalar(ScalarMut::String(self))
     }
 
-    fn debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fn debug(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         if f.alternate() {
             write!(f, "{self:#?}")
         } else {
@@ -686,6 +1058,86 @@ impl<'a> From<&'a mut String> for ScalarMut<'a> {
 /// Will be implemented by `#[derive(Reflect)]`.
 pub trait FromReflect: Reflect + Sized {
     fn from_reflect(reflect: &dyn Reflect) -> Option<Self>;
+
+    /// Like [`from_reflect`](FromReflect::from_reflect) but on failure returns an error
+    /// describing where in `reflect` the conversion failed.
+    ///
+    /// The error is found by comparing `reflect` against the type's [`TypeDescriptor`]. If the
+    /// shape matches, for example because a custom [`FromReflect`] implementation rejected the
+    /// value, the error points at `reflect` itself.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mirror_mirror::{FromReflect, Reflect, key_path};
+    /// use mirror_mirror::struct_::StructValue;
+    ///
+    /// #[derive(Reflect, Debug, Clone)]
+    /// struct Foo {
+    ///     bar: Bar,
+    /// }
+    ///
+    /// #[derive(Reflect, Debug, Clone)]
+    /// struct Bar {
+    ///     n: i32,
+    /// }
+    ///
+    /// let value = StructValue::new().with_field("bar", StructValue::new().with_field("n", 1.0_f32));
+    ///
+    /// let error = Foo::try_from_reflect(&value).unwrap_err();
+    /// assert_eq!(error.expected(), "i32");
+    /// assert_eq!(error.found(), Some("f32"));
+    /// assert_eq!(error.path(), &key_path!(.bar.n));
+    /// ```
+    fn try_from_reflect(reflect: &dyn Reflect) -> Result<Self, ReflectError>
+    where
+        Self: DescribeType,
+    {
+        Self::from_reflect(reflect).ok_or_else(|| error::from_reflect_error::<Self>(reflect))
+    }
+
+    /// Like [`try_from_reflect`](FromReflect::try_from_reflect) but numbers, including those
+    /// nested in fields and elements, are converted to the numeric type they're expected to
+    /// have.
+    ///
+    /// Useful for values originating from formats like JSON, which only have `i64` and `f64`.
+    /// Numbers that don't fit in the target type, and floats with a fractional part expected to
+    /// be integers, are reported as errors. Uses [`Coercion::NumberToNumber`], see
+    /// [`Coercions::try_coerce_to`] for enabling other coercions.
+    ///
+    /// [`Coercion::NumberToNumber`]: coerce::Coercion::NumberToNumber
+    /// [`Coercions::try_coerce_to`]: coerce::Coercions::try_coerce_to
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mirror_mirror::{FromReflect, Reflect};
+    /// use mirror_mirror::struct_::StructValue;
+    ///
+    /// #[derive(Reflect, Debug, Clone, PartialEq)]
+    /// struct Color {
+    ///     rgb: [u8; 3],
+    ///     alpha: f32,
+    /// }
+    ///
+    /// let json_like = StructValue::new()
+    ///     .with_field("rgb", vec![255_i64, 128, 0])
+    ///     .with_field("alpha", 0.5_f64);
+    ///
+    /// assert!(Color::from_reflect(&json_like).is_none());
+    /// assert_eq!(
+    ///     Color::from_reflect_coerced(&json_like),
+    ///     Ok(Color { rgb: [255, 128, 0], alpha: 0.5 }),
+    /// );
+    /// ```
+    fn from_reflect_coerced(reflect: &dyn Reflect) -> Result<Self, ReflectError>
+    where
+        Self: DescribeType,
+    {
+        coerce::Coercions::new()
+            .with(coerce::Coercion::NumberToNumber)
+            .try_coerce_to(reflect)
+    }
 }
 
 /// An owned reflected value.
@@ -700,6 +1152,7 @@ pub enum ReflectOwned {
     Array(Box<dyn Array>),
     List(Box<dyn List>),
     Map(Box<dyn Map>),
+    Set(Box<dyn Set>),
     Scalar(ScalarOwned),
     /// Not all `Reflect` implementations allow access to the underlying value. This variant can be
     /// used for such types.
@@ -716,6 +1169,7 @@ impl ReflectOwned {
             ReflectOwned::Array(inner) => inner.as_reflect_mut(),
             ReflectOwned::List(inner) => inner.as_reflect_mut(),
             ReflectOwned::Map(inner) => inner.as_reflect_mut(),
+            ReflectOwned::Set(inner) => inner.as_reflect_mut(),
             ReflectOwned::Scalar(inner) => inner.as_reflect_mut(),
             ReflectOwned::Opaque(inner) => inner.as_reflect_mut(),
         }
@@ -730,6 +1184,7 @@ impl ReflectOwned {
             ReflectOwned::Array(inner) => inner.as_reflect(),
             ReflectOwned::List(inner) => inner.as_reflect(),
             ReflectOwned::Map(inner) => inner.as_reflect(),
+            ReflectOwned::Set(inner) => inner.as_reflect(),
             ReflectOwned::Scalar(inner) => inner.as_reflect(),
             ReflectOwned::Opaque(inner) => inner.as_reflect(),
         }
@@ -784,6 +1239,13 @@ impl ReflectOwned {
         }
     }
 
+    pub fn into_set(self) -> Option<Box<dyn Set>> {
+        match self {
+            Self::Set(inner) => Some(inner),
+            _ => None,
+        }
+    }
+
     pub fn into_scalar(self) -> Option<ScalarOwned> {
         match self {
             Self::Scalar(inner) => Some(inner),
@@ -809,6 +1271,7 @@ impl Clone for ReflectOwned {
             Self::Array(inner) => inner.clone_reflect().reflect_owned(),
             Self::List(inner) => inner.clone_reflect().reflect_owned(),
             Self::Map(inner) => inner.clone_reflect().reflect_owned(),
+            Self::Set(inner) => inner.clone_reflect().reflect_owned(),
             Self::Opaque(inner) => inner.clone_reflect().reflect_owned(),
             Self::Scalar(inner) => Self::Scalar(inner.clone()),
         }
@@ -819,7 +1282,7 @@ impl Clone for ReflectOwned {
 #[derive(Debug, Clone)]
 #[allow(non_camel_case_types)]
 #[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub enum ScalarOwned {
     usize(usize),
     u8(u8),
@@ -895,6 +1358,7 @@ pub enum ReflectRef<'a> {
     Array(&'a dyn Array),
     List(&'a dyn List),
     Map(&'a dyn Map),
+    Set(&'a dyn Set),
     Scalar(ScalarRef<'a>),
     /// Not all `Reflect` implementations allow access to the underlying value. This variant can be
     /// used for such types.
@@ -911,6 +1375,7 @@ impl<'a> ReflectRef<'a> {
             ReflectRef::Array(inner) => inner.as_reflect(),
             ReflectRef::List(inner) => inner.as_reflect(),
             ReflectRef::Map(inner) => inner.as_reflect(),
+            ReflectRef::Set(inner) => inner.as_reflect(),
             ReflectRef::Scalar(inner) => inner.as_reflect(),
             ReflectRef::Opaque(inner) => inner.as_reflect(),
         }
@@ -965,6 +1430,13 @@ impl<'a> ReflectRef<'a> {
         }
     }
 
+    pub fn as_set(self) -> Option<&'a dyn Set> {
+        match self {
+            Self::Set(inner) => Some(inner),
+            _ => None,
+        }
+    }
+
     pub fn as_scalar(self) -> Option<ScalarRef<'a>> {
         match self {
             Self::Scalar(inner) => Some(inner),
@@ -1037,6 +1509,7 @@ pub enum ReflectMut<'a> {
     Array(&'a mut dyn Array),
     List(&'a mut dyn List),
     Map(&'a mut dyn Map),
+    Set(&'a mut dyn Set),
     Scalar(ScalarMut<'a>),
     /// Not all `Reflect` implementations allow mutable access to the underlying value (such as
     /// [`core::num::NonZeroU8`]). This variant can be used for such types.
@@ -1053,6 +1526,7 @@ impl<'a> ReflectMut<'a> {
             ReflectMut::Array(inner) => inner.as_reflect_mut(),
             ReflectMut::List(inner) => inner.as_reflect_mut(),
             ReflectMut::Map(inner) => inner.as_reflect_mut(),
+            ReflectMut::Set(inner) => inner.as_reflect_mut(),
             ReflectMut::Scalar(inner) => inner.as_reflect_mut(),
             ReflectMut::Opaque(inner) => inner.as_reflect_mut(),
         }
@@ -1067,6 +1541,7 @@ impl<'a> ReflectMut<'a> {
             ReflectMut::Array(inner) => inner.as_reflect(),
             ReflectMut::List(inner) => inner.as_reflect(),
             ReflectMut::Map(inner) => inner.as_reflect(),
+            ReflectMut::Set(inner) => inner.as_reflect(),
             ReflectMut::Scalar(inner) => inner.as_reflect(),
             ReflectMut::Opaque(inner) => inner.as_reflect(),
         }
@@ -1121,6 +1596,13 @@ impl<'a> ReflectMut<'a> {
         }
     }
 
+    pub fn as_set_mut(self) -> Option<&'a mut dyn Set> {
+        match self {
+            Self::Set(inner) => Some(inner),
+            _ => None,
+        }
+    }
+
     pub fn as_scalar_mut(self) -> Option<ScalarMut<'a>> {
         match self {
             Self::Scalar(inner) => Some(inner),
@@ -1203,11 +1685,22 @@ impl<'a> ScalarMut<'a> {
 }
 
 /// Debug formatter for any reflection value.
+///
+/// Values whose types have a formatter registered in the global registry, with
+/// [`TypeRegistry::register_debug_formatter`], are formatted with that instead, including values
+/// nested in `value`.
+///
+/// [`TypeRegistry::register_debug_formatter`]: crate::registry::TypeRegistry::register_debug_formatter
 pub fn reflect_debug(value: &dyn Reflect, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    #[cfg(feature = "std")]
+    if let Some(formatter) = registry::global_debug_formatter(value) {
+        return formatter(value, f);
+    }
+
     fn scalar_debug(
         scalar: &dyn core::fmt::Debug,
         f: &mut core::fmt::Formatter<'_>,
-    ) -> fmt::Result {
+    ) -> core::fmt::Result {
         if f.alternate() {
             write!(f, "{scalar:#?}")
         } else {
@@ -1219,21 +1712,21 @@ pub fn reflect_debug(value: &dyn Reflect, f: &mut core::fmt::Formatter<'_>) -> c
         ReflectRef::Struct(inner) => {
             let mut f = f.debug_struct(inner.type_name());
             for (name, value) in inner.fields() {
-                f.field(name, &value as &dyn ::core::fmt::Debug);
+                f.field(name, &WithFormatter(value));
             }
             f.finish()
         }
         ReflectRef::TupleStruct(inner) => {
             let mut f = f.debug_tuple(inner.type_name());
             for field in inner.fields() {
-                f.field(&field as &dyn ::core::fmt::Debug);
+                f.field(&WithFormatter(field));
             }
             f.finish()
         }
         ReflectRef::Tuple(inner) => {
             let mut f = f.debug_tuple("");
             for field in inner.fields() {
-                f.field(&field as &dyn ::core::fmt::Debug);
+                f.field(&WithFormatter(field));
             }
             f.finish()
         }
@@ -1243,11 +1736,14 @@ pub fn reflect_debug(value: &dyn Reflect, f: &mut core::fmt::Formatter<'_>) -> c
                 for field in inner.fields() {
                     match field {
                         VariantField::Struct(name, value) => {
-                            f.field(name, &value as &dyn ::core::fmt::Debug);
-                        }
-                        VariantField::Tuple { .. } => {
-                            unreachable!("unit variant yielded struct field")
+                            f.field(name, &WithFormatter(value));
                         }
+                        VariantField::Tuple(value) => panic_or!(
+                            {
+                                f.field("_", &WithFormatter(value));
+                            },
+                            "struct variant yielded tuple field `{value:?}`"
+                        ),
                     }
                 }
                 f.finish()
@@ -1256,11 +1752,14 @@ pub fn reflect_debug(value: &dyn Reflect, f: &mut core::fmt::Formatter<'_>) -> c
                 let mut f = f.debug_tuple(inner.variant_name());
                 for field in inner.fields() {
                     match field {
-                        VariantField::Struct { .. } => {
-                            unreachable!("unit variant yielded struct field")
-                        }
+                        VariantField::Struct(_, value) => panic_or!(
+                            {
+                                f.field(&WithFormatter(value));
+                            },
+                            "tuple variant yielded struct field `{value:?}`"
+                        ),
                         VariantField::Tuple(value) => {
-                            f.field(&value as &dyn ::core::fmt::Debug);
+                            f.field(&WithFormatter(value));
                         }
                     }
                 }
@@ -1268,9 +1767,26 @@ pub fn reflect_debug(value: &dyn Reflect, f: &mut core::fmt::Formatter<'_>) -> c
             }
             VariantKind::Unit => write!(f, "{}", inner.variant_name()),
         },
-        ReflectRef::Array(inner) => f.debug_list().entries(inner.iter()).finish(),
-        ReflectRef::List(inner) => f.debug_list().entries(inner.iter()).finish(),
-        ReflectRef::Map(inner) => f.debug_map().entries(inner.iter()).finish(),
+        ReflectRef::Array(inner) => f
+            .debug_list()
+            .entries(inner.iter().map(WithFormatter))
+            .finish(),
+        ReflectRef::List(inner) => f
+            .debug_list()
+            .entries(inner.iter().map(WithFormatter))
+            .finish(),
+        ReflectRef::Map(inner) => f
+            .debug_map()
+            .entries(
+                inner
+                    .iter()
+                    .map(|(key, value)| (WithFormatter(key), WithFormatter(value))),
+            )
+            .finish(),
+        ReflectRef::Set(inner) => f
+            .debug_set()
+            .entries(inner.iter().map(WithFormatter))
+            .finish(),
         ReflectRef::Scalar(inner) => match inner {
             ScalarRef::usize(inner) => scalar_debug(&inner, f),
             ScalarRef::u8(inner) => scalar_debug(&inner, f),
@@ -1295,6 +1811,34 @@ pub fn reflect_debug(value: &dyn Reflect, f: &mut core::fmt::Formatter<'_>) -> c
     }
 }
 
+// Formats nested values with the formatter registered for their type, if there is one.
+//
+// Collections and tuples are formatted with `reflect_debug` so the formatters also apply to their
+// elements. That gives the same output as their own `Debug` impls. Other types use their own
+// `Reflect::debug`.
+struct WithFormatter<'a>(&'a dyn Reflect);
+
+impl core::fmt::Debug for WithFormatter<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        #[cfg(feature = "std")]
+        if let Some(formatter) = registry::global_debug_formatter(self.0) {
+            return formatter(self.0, f);
+        }
+        match self.0.reflect_ref() {
+            ReflectRef::Tuple(_)
+            | ReflectRef::Array(_)
+            | ReflectRef::List(_)
+            | ReflectRef::Map(_)
+            | ReflectRef::Set(_) => reflect_debug(self.0, f),
+            ReflectRef::Struct(_)
+            | ReflectRef::TupleStruct(_)
+            | ReflectRef::Enum(_)
+            | ReflectRef::Scalar(_)
+            | ReflectRef::Opaque(_) => self.0.debug(f),
+        }
+    }
+}
+
 /// Private. Used by macros
 #[doc(hidden)]
 pub mod __private {
@@ -1306,6 +1850,7 @@ pub mod __private {
 
     pub use once_cell::race::OnceBox;
 
+    pub use self::assert_type_shape::{assert_named_shape, assert_unnamed_shape};
     pub use self::enum_::*;
     pub use self::key_path::{
         field, get, variant, Breadcrumbs, GetPath, GetTypePath, IntoKeyOrIndex, Key, KeyPath,
@@ -1337,4 +1882,86 @@ pub mod __private {
             self.to_owned().into_value()
         }
     }
+
+    /// The name of a renamed type with const generic parameters, such as `Grid<3>`.
+    pub fn const_generic_type_name(name: &str, params: &[&dyn fmt::Display]) -> String {
+        use core::fmt::Write;
+
+        let mut out = String::from(name);
+        out.push('<');
+        for (idx, param) in params.iter().enumerate() {
+            if idx != 0 {
+                out.push_str(", ");
+            }
+            let _ = write!(out, "{param}");
+        }
+        out.push('>');
+        out
+    }
+
+    /// The value of a well-known metadata key, which fails to compile if it has the wrong type.
+    ///
+    /// Values are converted with `Into` so string literals can be used for `String`s.
+    pub fn typed_meta<K, V>(value: V) -> Value
+    where
+        K: crate::meta::MetaKey,
+        V: Into<K::Value>,
+    {
+        value.into().to_value()
+    }
+
+    /// Error for a value rejected by a `#[reflect(validate_with(...))]` validator.
+    pub fn invalid_value(value: &dyn Reflect, reason: impl Into<String>) -> ReflectError {
+        ReflectError::invalid(value, reason.into())
+    }
+
+    /// Like [`invalid_value`] but for the value of a field.
+    pub fn invalid_field(
+        value: &dyn Reflect,
+        reason: impl Into<String>,
+        field: impl IntoKeyOrIndex,
+    ) -> ReflectError {
+        let mut path = KeyPath::default();
+        path.push_field(field);
+        ReflectError::invalid(value, reason.into()).at(path)
+    }
+
+    /// `FromReflect::try_from_reflect` for values that couldn't be converted.
+    pub fn from_reflect_error<T>(reflect: &dyn Reflect) -> ReflectError
+    where
+        T: DescribeType,
+    {
+        crate::error::from_reflect_error::<T>(reflect)
+    }
+
+    /// Well-known metadata keys, for checking their values' types.
+    pub mod known_meta {
+        pub use crate::meta::known::*;
+    }
+
+    /// `Reflect::to_value` of enum variants marked `#[reflect(skip)]`.
+    ///
+    /// `Reflect::try_to_value` reports these as errors instead.
+    #[track_caller]
+    pub fn skipped_variant_to_value(value: &dyn Enum) -> Value {
+        panic!(
+            "`Reflection::to_value` called on `{:?}` which doesn't suport reflection",
+            value.as_reflect()
+        )
+    }
+
+    /// The type name from `T`'s type descriptor, for renamed types whose names aren't known until
+    /// their const generic parameters are.
+    ///
+    /// Type descriptors are only cached with `std`, so without it this falls back to
+    /// `core::any::type_name`.
+    pub fn described_type_name<T>() -> &'static str
+    where
+        T: DescribeType,
+    {
+        match T::type_descriptor() {
+            Cow::Borrowed(descriptor) => descriptor.type_name(),
+            Cow::Owned(_) => core::any::type_name::<T>(),
+        }
+    }
 }
@@ -1,8 +1,16 @@
 use alloc::boxed::Box;
+use alloc::vec::Vec;
 use core::fmt;
 
+use crate::array::numeric;
 use crate::array::Array;
+use crate::iter::IntoIter;
+use crate::key_path::KeyPath;
+use crate::DescribeType;
+use crate::FromReflect;
 use crate::Reflect;
+use crate::ReflectError;
+use crate::ReflectRef;
 
 /// A reflected list type.
 pub trait List: Array {
@@ -11,6 +19,68 @@ pub trait List: Array {
     fn pop(&mut self) -> Option<Box<dyn Reflect>>;
 
     fn try_remove(&mut self, index: usize) -> Option<Box<dyn Reflect>>;
+
+    /// Insert `value` at `index`, shifting all elements after it to the right.
+    ///
+    /// Like [`push`](Self::push), nothing is inserted if `value` can't be converted into the
+    /// element type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    fn insert(&mut self, index: usize, value: &dyn Reflect);
+
+    /// Remove and return the element at `index`, shifting all elements after it to the left.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds. See [`try_remove`](Self::try_remove) for a version
+    /// that doesn't panic.
+    fn remove(&mut self, index: usize) -> Box<dyn Reflect> {
+        let len = self.len();
+        self.try_remove(index)
+            .unwrap_or_else(|| panic!("removal index (is {index}) should be < len (is {len})"))
+    }
+
+    /// Shorten the list to `len` elements, dropping the rest.
+    ///
+    /// Does nothing if the list is already shorter.
+    fn truncate(&mut self, len: usize);
+
+    /// Remove all elements.
+    fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Keep only the elements for which `f` returns `true`, in their original order.
+    fn retain(&mut self, f: &mut dyn FnMut(&dyn Reflect) -> bool);
+
+    /// Reserve capacity for at least `additional` more elements.
+    ///
+    /// Call this before pushing many elements to avoid reallocating repeatedly. Does nothing for
+    /// lists that can't preallocate.
+    #[allow(unused_variables)]
+    fn reserve(&mut self, additional: usize) {}
+
+    /// Remove all elements and return them in order, moving them out of the list rather than
+    /// cloning them.
+    ///
+    /// The elements are removed even if the iterator isn't consumed.
+    fn drain(&mut self) -> IntoIter<'_> {
+        let mut elements = Vec::with_capacity(self.len());
+        while let Some(element) = self.pop() {
+            elements.push(element);
+        }
+        elements.reverse();
+        Box::new(elements.into_iter())
+    }
+
+    /// Consume the list and return its elements in order, moving them out rather than cloning
+    /// them.
+    fn into_elements(mut self: Box<Self>) -> IntoIter<'static> {
+        let elements = self.drain().collect::<Vec<_>>();
+        Box::new(elements.into_iter())
+    }
 }
 
 impl fmt::Debug for dyn List {
@@ -18,3 +88,88 @@ impl fmt::Debug for dyn List {
         self.as_reflect().debug(f)
     }
 }
+
+/// Add the numeric elements of `b` to those of `a`, element-wise.
+///
+/// See [`array::add_assign_array`](crate::array::add_assign_array) for details.
+///
+/// # Example
+///
+/// ```
+/// use mirror_mirror::{list::add_assign_list, Reflect};
+///
+/// let mut a = vec![1_i32, 2, 3];
+/// let b = vec![10_i32, 20];
+///
+/// let updated = add_assign_list(a.as_reflect_mut().as_list_mut().unwrap(), &b);
+///
+/// assert_eq!(updated, 2);
+/// assert_eq!(a, [11, 22, 3]);
+/// ```
+pub fn add_assign_list(a: &mut dyn List, b: &dyn List) -> usize {
+    numeric::add_assign(a.iter_mut(), b.iter())
+}
+
+/// Multiply the numeric elements of `list` by `factor`.
+///
+/// See [`array::scale`](crate::array::scale) for details.
+///
+/// # Example
+///
+/// ```
+/// use mirror_mirror::{list::scale, Reflect};
+///
+/// let mut list = vec![0.5_f64, 1.0];
+///
+/// scale(list.as_reflect_mut().as_list_mut().unwrap(), 4.0);
+///
+/// assert_eq!(list, [2.0, 4.0]);
+/// ```
+pub fn scale(list: &mut dyn List, factor: f64) -> usize {
+    numeric::scale(list.iter_mut(), factor)
+}
+
+/// Convert the elements of a reflected list or array, skipping the ones that can't be converted.
+///
+/// Returns the converted elements along with the index and error of every element that was
+/// skipped, or `None` if `reflect` isn't a list or an array. Useful when partial data is preferable
+/// to failing entirely, such as when ingesting legacy assets.
+///
+/// # Example
+///
+/// ```
+/// use mirror_mirror::{key_path, list::from_reflect_lossy, Value};
+///
+/// let value = Value::List(Vec::from([1_i32.into(), "two".into(), 3_i32.into()]));
+///
+/// let (list, skipped): (Vec<i32>, _) = from_reflect_lossy(&value).unwrap();
+///
+/// assert_eq!(list, [1, 3]);
+/// assert_eq!(skipped.len(), 1);
+/// assert_eq!(skipped[0].0, 1);
+/// assert_eq!(skipped[0].1.path(), &key_path!([1_usize]));
+/// ```
+pub fn from_reflect_lossy<C, T>(reflect: &dyn Reflect) -> Option<(C, Vec<(usize, ReflectError)>)>
+where
+    C: FromIterator<T>,
+    T: FromReflect + DescribeType,
+{
+    let elements = match reflect.reflect_ref() {
+        ReflectRef::List(list) => list.iter(),
+        ReflectRef::Array(array) => array.iter(),
+        _ => return None,
+    };
+
+    let mut skipped = Vec::new();
+    let collection = elements
+        .enumerate()
+        .filter_map(|(index, element)| match T::try_from_reflect(element) {
+            Ok(element) => Some(element),
+            Err(error) => {
+                skipped.push((index, error.under(&KeyPath::default().get(index))));
+                None
+            }
+        })
+        .collect();
+    Some((collection, skipped))
+}
@@ -0,0 +1,198 @@
+use alloc::borrow::Cow;
+use alloc::rc::Rc;
+use core::any::Any;
+use core::cell::RefCell;
+use core::fmt;
+
+use crate::Reflect;
+use crate::TypeDescriptor;
+use crate::Value;
+
+/// Reflection for values that aren't `Send`, such as `Rc<T>` and `RefCell<T>`.
+///
+/// [`Reflect`] requires `Send`, so single-threaded code like editors can use `LocalReflect` to
+/// reach reflected values behind non-`Send` handles. Every `Reflect` type implements
+/// `LocalReflect`, and the reflected value is reached through [`LocalReflect::with_reflect`] and
+/// [`LocalReflect::with_reflect_mut`].
+///
+/// The methods are named differently from those on `Reflect`, so calls are never ambiguous when
+/// both traits are in scope. `dyn LocalReflect` has inherent methods like
+/// [`to_value`](#method.to_value) and [`patch`](#method.patch) built on top of them.
+///
+/// # Example
+///
+/// ```
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+/// use mirror_mirror::{LocalReflect, Reflect};
+///
+/// #[derive(Reflect, Clone, Debug)]
+/// struct Node {
+///     name: String,
+/// }
+///
+/// let node = Rc::new(RefCell::new(Node { name: "root".to_owned() }));
+/// let handle = Rc::clone(&node);
+///
+/// let mut local: Box<dyn LocalReflect> = Box::new(handle);
+/// local.patch(&Node { name: "renamed".to_owned() });
+///
+/// assert_eq!(node.borrow().name, "renamed");
+/// assert_eq!(local.to_value(), Some(node.borrow().to_value()));
+/// ```
+pub trait LocalReflect: Any {
+    fn as_local_any(&self) -> &dyn Any;
+
+    fn as_local_any_mut(&mut self) -> &mut dyn Any;
+
+    /// Call `f` with the reflected value.
+    ///
+    /// Returns `false`, without calling `f`, if the value can't be borrowed, such as a `RefCell`
+    /// that is already borrowed mutably.
+    fn with_reflect(&self, f: &mut dyn FnMut(&dyn Reflect)) -> bool;
+
+    /// Call `f` with the reflected value, mutably.
+    ///
+    /// Returns `false`, without calling `f`, if the value can't be borrowed mutably.
+    fn with_reflect_mut(&mut self, f: &mut dyn FnMut(&mut dyn Reflect)) -> bool;
+
+    /// Call `f` with the reflected value, mutably, through a shared reference.
+    ///
+    /// Only types with interior mutability, such as `RefCell<T>`, support this. It's what allows
+    /// mutating the value behind a shared `Rc<RefCell<T>>`. Returns `false`, without calling `f`,
+    /// for other types.
+    fn with_reflect_shared_mut(&self, f: &mut dyn FnMut(&mut dyn Reflect)) -> bool {
+        let _ = f;
+        false
+    }
+}
+
+impl<T> LocalReflect for T
+where
+    T: Reflect,
+{
+    fn as_local_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_local_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn with_reflect(&self, f: &mut dyn FnMut(&dyn Reflect)) -> bool {
+        f(self.as_reflect());
+        true
+    }
+
+    fn with_reflect_mut(&mut self, f: &mut dyn FnMut(&mut dyn Reflect)) -> bool {
+        f(self.as_reflect_mut());
+        true
+    }
+}
+
+// Shared `Rc`s can only be mutated through interior mutability, like `Rc<RefCell<T>>`.
+impl<T> LocalReflect for Rc<T>
+where
+    T: LocalReflect,
+{
+    fn as_local_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_local_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn with_reflect(&self, f: &mut dyn FnMut(&dyn Reflect)) -> bool {
+        T::with_reflect(self, f)
+    }
+
+    fn with_reflect_mut(&mut self, f: &mut dyn FnMut(&mut dyn Reflect)) -> bool {
+        match Rc::get_mut(self) {
+            Some(inner) => inner.with_reflect_mut(f),
+            None => T::with_reflect_shared_mut(self, f),
+        }
+    }
+
+    fn with_reflect_shared_mut(&self, f: &mut dyn FnMut(&mut dyn Reflect)) -> bool {
+        T::with_reflect_shared_mut(self, f)
+    }
+}
+
+impl<T> LocalReflect for RefCell<T>
+where
+    T: LocalReflect,
+{
+    fn as_local_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_local_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn with_reflect(&self, f: &mut dyn FnMut(&dyn Reflect)) -> bool {
+        match self.try_borrow() {
+            Ok(inner) => inner.with_reflect(f),
+            Err(_) => false,
+        }
+    }
+
+    fn with_reflect_mut(&mut self, f: &mut dyn FnMut(&mut dyn Reflect)) -> bool {
+        self.get_mut().with_reflect_mut(f)
+    }
+
+    fn with_reflect_shared_mut(&self, f: &mut dyn FnMut(&mut dyn Reflect)) -> bool {
+        match self.try_borrow_mut() {
+            Ok(mut inner) => inner.with_reflect_mut(f),
+            Err(_) => false,
+        }
+    }
+}
+
+impl dyn LocalReflect {
+    pub fn downcast_ref<T>(&self) -> Option<&T>
+    where
+        T: LocalReflect,
+    {
+        self.as_local_any().downcast_ref::<T>()
+    }
+
+    pub fn downcast_mut<T>(&mut self) -> Option<&mut T>
+    where
+        T: LocalReflect,
+    {
+        self.as_local_any_mut().downcast_mut::<T>()
+    }
+
+    /// The type descriptor of the reflected value, or `None` if it can't be borrowed.
+    pub fn type_descriptor(&self) -> Option<Cow<'static, TypeDescriptor>> {
+        let mut descriptor = None;
+        self.with_reflect(&mut |value| descriptor = Some(value.type_descriptor()));
+        descriptor
+    }
+
+    /// The reflected value as a [`Value`], or `None` if it can't be borrowed.
+    pub fn to_value(&self) -> Option<Value> {
+        let mut out = None;
+        self.with_reflect(&mut |value| out = Some(value.to_value()));
+        out
+    }
+
+    /// Patch the reflected value with `value`.
+    ///
+    /// Returns `false` if the value can't be borrowed mutably.
+    pub fn patch(&mut self, value: &dyn Reflect) -> bool {
+        self.with_reflect_mut(&mut |inner| inner.patch(value))
+    }
+}
+
+impl fmt::Debug for dyn LocalReflect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut result = Ok(());
+        if !self.with_reflect(&mut |value| result = value.debug(f)) {
+            return f.write_str("<borrowed>");
+        }
+        result
+    }
+}
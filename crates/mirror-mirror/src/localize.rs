@@ -0,0 +1,164 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::key_path::KeyPath;
+use crate::type_info::GetMeta;
+use crate::type_info::Type;
+use crate::type_info::VariantField;
+use crate::Reflect;
+use crate::ReflectRef;
+use crate::ScalarRef;
+
+/// Collect all strings in `value` that are tagged with the metadata `key`, along with the key
+/// paths to them.
+///
+/// A string is tagged if the field, variant, or type it's nested in has metadata with the given
+/// key, regardless of its value. Everything nested inside a tagged field is tagged as well, so
+/// tagging an `Option<String>` or `Vec<String>` field collects all the strings in it.
+///
+/// Useful for extracting localizable text from game data into translation catalogs. The
+/// translated strings can be written back with [`GetPath::at_mut`].
+///
+/// Strings in map keys and sets are skipped since they cannot be written back. Type information
+/// comes from [`Reflect::type_descriptor`] so this finds nothing in [`Value`]s, which don't
+/// carry metadata.
+///
+/// # Example
+///
+/// ```
+/// use mirror_mirror::{key_path, Reflect};
+/// use mirror_mirror::localize::collect_strings_with_meta;
+///
+/// #[derive(Reflect, Clone, Debug)]
+/// struct Item {
+///     id: String,
+///     #[reflect(meta(localize = true))]
+///     name: String,
+///     #[reflect(meta(localize = true))]
+///     description: Option<String>,
+/// }
+///
+/// let item = Item {
+///     id: "sword_01".to_owned(),
+///     name: "Sword".to_owned(),
+///     description: Some("Sharp".to_owned()),
+/// };
+///
+/// assert_eq!(
+///     collect_strings_with_meta(&item, "localize"),
+///     [
+///         (key_path!(.name), "Sword".to_owned()),
+///         (key_path!(.description::Some.0), "Sharp".to_owned()),
+///     ],
+/// );
+/// ```
+///
+/// [`GetPath::at_mut`]: crate::key_path::GetPath::at_mut
+/// [`Value`]: crate::Value
+pub fn collect_strings_with_meta(value: &dyn Reflect, key: &str) -> Vec<(KeyPath, String)> {
+    let descriptor = value.type_descriptor();
+    let mut strings = Vec::new();
+    go(
+        value,
+        descriptor.get_type(),
+        key,
+        false,
+        &mut KeyPath::default(),
+        &mut strings,
+    );
+    strings
+}
+
+fn go(
+    value: &dyn Reflect,
+    ty: Type<'_>,
+    key: &str,
+    tagged: bool,
+    path: &mut KeyPath,
+    strings: &mut Vec<(KeyPath, String)>,
+) {
+    let tagged = tagged || ty.meta(key).is_some();
+
+    match (ty, value.reflect_ref()) {
+        (Type::Struct(struct_type), ReflectRef::Struct(struct_)) => {
+            for field_type in struct_type.field_types() {
+                if let Some(field) = struct_.field(field_type.name()) {
+                    let tagged = tagged || field_type.meta(key).is_some();
+                    path.push_field(field_type.name());
+                    go(field, field_type.get_type(), key, tagged, path, strings);
+                    path.pop();
+                }
+            }
+        }
+        (Type::TupleStruct(tuple_struct_type), ReflectRef::TupleStruct(tuple_struct)) => {
+            for (index, field_type) in tuple_struct_type.field_types().enumerate() {
+                if let Some(field) = tuple_struct.field_at(index) {
+                    let tagged = tagged || field_type.meta(key).is_some();
+                    path.push_field(index);
+                    go(field, field_type.get_type(), key, tagged, path, strings);
+                    path.pop();
+                }
+            }
+        }
+        (Type::Tuple(tuple_type), ReflectRef::Tuple(tuple)) => {
+            for (index, field_type) in tuple_type.field_types().enumerate() {
+                if let Some(field) = tuple.field_at(index) {
+                    let tagged = tagged || field_type.meta(key).is_some();
+                    path.push_field(index);
+                    go(field, field_type.get_type(), key, tagged, path, strings);
+                    path.pop();
+                }
+            }
+        }
+        (Type::Enum(enum_type), ReflectRef::Enum(enum_)) => {
+            let Some(variant) = enum_type.variant(enum_.variant_name()) else {
+                return;
+            };
+            let tagged = tagged || variant.meta(key).is_some();
+            path.push_variant(variant.name());
+            for (index, field_type) in variant.field_types().enumerate() {
+                let field = match field_type {
+                    VariantField::Named(field_type) => enum_.field(field_type.name()),
+                    VariantField::Unnamed(_) => enum_.field_at(index),
+                };
+                if let Some(field) = field {
+                    let tagged = tagged || field_type.meta(key).is_some();
+                    match field_type.name() {
+                        Some(name) => path.push_field(name),
+                        None => path.push_field(index),
+                    }
+                    go(field, field_type.get_type(), key, tagged, path, strings);
+                    path.pop();
+                }
+            }
+            path.pop();
+        }
+        (Type::Array(array_type), ReflectRef::Array(array)) => {
+            let element_type = array_type.element_type();
+            for (index, element) in array.iter().enumerate() {
+                path.push_get(index);
+                go(element, element_type, key, tagged, path, strings);
+                path.pop();
+            }
+        }
+        (Type::List(list_type), ReflectRef::List(list)) => {
+            let element_type = list_type.element_type();
+            for (index, element) in list.iter().enumerate() {
+                path.push_get(index);
+                go(element, element_type, key, tagged, path, strings);
+                path.pop();
+            }
+        }
+        (Type::Map(map_type), ReflectRef::Map(map)) => {
+            for (map_key, value) in map.iter() {
+                path.push_get(map_key.to_value());
+                go(value, map_type.value_type(), key, tagged, path, strings);
+                path.pop();
+            }
+        }
+        (Type::Scalar(_), ReflectRef::Scalar(ScalarRef::String(string))) if tagged => {
+            strings.push((path.clone(), string.clone()));
+        }
+        _ => {}
+    }
+}
@@ -0,0 +1,360 @@
+use core::fmt;
+
+use crate::enum_::VariantField;
+use crate::enum_::VariantKind;
+use crate::key_path::KeyPath;
+use crate::key_path::KeyPathPattern;
+use crate::type_info::GetMeta;
+use crate::type_info::MapType;
+use crate::type_info::NamedField;
+use crate::type_info::Type;
+use crate::type_info::UnnamedField;
+use crate::type_info::VariantField as VariantFieldType;
+use crate::Reflect;
+use crate::ReflectRef;
+
+/// Options that control how much of a value is formatted by [`LogValue`] and [`log_value`].
+#[derive(Debug, Clone, Copy)]
+pub struct LogOptions<'a> {
+    /// How many levels of nested values to format. Values nested deeper are replaced by `..`.
+    pub max_depth: usize,
+    /// How many elements to format from each list, array, map, or set. The remaining elements
+    /// are replaced by a count.
+    pub max_elems: usize,
+    /// Values at key paths matched by any of these patterns, and everything nested inside them,
+    /// are replaced by `<redacted>`.
+    pub redact: &'a [KeyPathPattern],
+}
+
+impl Default for LogOptions<'_> {
+    fn default() -> Self {
+        Self {
+            max_depth: 8,
+            max_elems: 16,
+            redact: &[],
+        }
+    }
+}
+
+/// Log a truncated and redacted representation of `value` with the [`log`] crate.
+///
+/// Requires the `log` feature.
+///
+/// See [`LogValue`] for details about the format.
+///
+/// [`log`]: https://crates.io/crates/log
+#[cfg(feature = "log")]
+pub fn log_value(level: log::Level, value: &dyn Reflect, options: &LogOptions<'_>) {
+    log::log!(level, "{}", LogValue::new(value, options));
+}
+
+/// Formats a value like its `Debug` representation, but limited according to some
+/// [`LogOptions`].
+///
+/// Useful for logging structured state without dumping secrets or megabytes of data. Fields,
+/// variants, and types marked with `#[reflect(sensitive)]` are always redacted, in addition to
+/// the key paths in [`LogOptions::redact`].
+///
+/// `Display` and `Debug` produce the same output. Alternate mode (`{:#}`) pretty prints the
+/// value.
+///
+/// # Example
+///
+/// ```
+/// use mirror_mirror::{key_path, Reflect};
+/// use mirror_mirror::logging::{LogOptions, LogValue};
+///
+/// #[derive(Reflect, Clone, Debug)]
+/// struct Session {
+///     user: String,
+///     token: String,
+///     history: Vec<u32>,
+/// }
+///
+/// let session = Session {
+///     user: "alice".to_owned(),
+///     token: "hunter2".to_owned(),
+///     history: (0..100).collect(),
+/// };
+///
+/// let options = LogOptions {
+///     max_elems: 3,
+///     redact: &[key_path!(.token).into()],
+///     ..Default::default()
+/// };
+///
+/// let formatted = LogValue::new(&session, &options).to_string();
+/// assert!(formatted.ends_with(
+///     r#"Session { user: "alice", token: <redacted>, history: [0, 1, 2, ..97 more] }"#
+/// ));
+/// ```
+pub struct LogValue<'a> {
+    value: &'a dyn Reflect,
+    options: &'a LogOptions<'a>,
+}
+
+impl<'a> LogValue<'a> {
+    pub fn new(value: &'a dyn Reflect, options: &'a LogOptions<'a>) -> Self {
+        Self { value, options }
+    }
+}
+
+impl fmt::Display for LogValue<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+impl fmt::Debug for LogValue<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let descriptor = self.value.type_descriptor();
+        Node {
+            value: self.value,
+            ty: Some(descriptor.get_type()),
+            sensitive: false,
+            options: self.options,
+            path: KeyPath::default(),
+            depth: 0,
+        }
+        .fmt(f)
+    }
+}
+
+/// A value nested somewhere inside a [`LogValue`], along with its type if known.
+struct Node<'a> {
+    value: &'a dyn Reflect,
+    ty: Option<Type<'a>>,
+    sensitive: bool,
+    options: &'a LogOptions<'a>,
+    path: KeyPath,
+    depth: usize,
+}
+
+impl<'a> Node<'a> {
+    fn child(
+        &self,
+        value: &'a dyn Reflect,
+        path: KeyPath,
+        ty: Option<Type<'a>>,
+        sensitive: bool,
+    ) -> Self {
+        Self {
+            value,
+            ty,
+            sensitive,
+            options: self.options,
+            path,
+            depth: self.depth + 1,
+        }
+    }
+
+    fn is_redacted(&self) -> bool {
+        self.sensitive
+            || self.ty.map_or(false, GetMeta::is_sensitive)
+            || self
+                .options
+                .redact
+                .iter()
+                .any(|pattern| pattern.matches_prefix_of(&self.path))
+    }
+
+    fn element_type(&self) -> Option<Type<'a>> {
+        match self.ty? {
+            Type::Array(ty) => Some(ty.element_type()),
+            Type::List(ty) => Some(ty.element_type()),
+            Type::Set(ty) => Some(ty.element_type()),
+            Type::Struct(_)
+            | Type::TupleStruct(_)
+            | Type::Tuple(_)
+            | Type::Enum(_)
+            | Type::Map(_)
+            | Type::Scalar(_)
+            | Type::Opaque(_) => None,
+        }
+    }
+}
+
+impl fmt::Debug for Node<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_redacted() {
+            return f.write_str("<redacted>");
+        }
+
+        let options = self.options;
+        let value = self.value;
+
+        #[cfg(feature = "std")]
+        if let Some(formatter) = crate::registry::global_debug_formatter(value) {
+            return formatter(value, f);
+        }
+
+        match value.reflect_ref() {
+            ReflectRef::Scalar(_) | ReflectRef::Opaque(_) => crate::reflect_debug(value, f),
+            _ if self.depth >= options.max_depth => f.write_str(".."),
+            ReflectRef::Struct(inner) => {
+                let ty = self.ty.and_then(Type::as_struct);
+                let mut f = f.debug_struct(inner.type_name());
+                for (name, field) in inner.fields() {
+                    let field_type = ty.and_then(|ty| ty.field_type(name));
+                    f.field(
+                        name,
+                        &self.child(
+                            field,
+                            self.path.clone().field(name),
+                            field_type.map(NamedField::get_type),
+                            field_type.map_or(false, GetMeta::is_sensitive),
+                        ),
+                    );
+                }
+                f.finish()
+            }
+            ReflectRef::TupleStruct(inner) => {
+                let ty = self.ty.and_then(Type::as_tuple_struct);
+                let mut f = f.debug_tuple(inner.type_name());
+                for (index, field) in inner.fields().enumerate() {
+                    let field_type = ty.and_then(|ty| ty.field_type_at(index));
+                    f.field(&self.child(
+                        field,
+                        self.path.clone().field(index),
+                        field_type.map(UnnamedField::get_type),
+                        field_type.map_or(false, GetMeta::is_sensitive),
+                    ));
+                }
+                f.finish()
+            }
+            ReflectRef::Tuple(inner) => {
+                let ty = self.ty.and_then(Type::as_tuple);
+                let mut f = f.debug_tuple("");
+                for (index, field) in inner.fields().enumerate() {
+                    let field_type = ty.and_then(|ty| ty.field_type_at(index));
+                    f.field(&self.child(
+                        field,
+                        self.path.clone().field(index),
+                        field_type.map(UnnamedField::get_type),
+                        false,
+                    ));
+                }
+                f.finish()
+            }
+            ReflectRef::Enum(inner) => {
+                let variant = self
+                    .ty
+                    .and_then(Type::as_enum)
+                    .and_then(|ty| ty.variant(inner.variant_name()));
+                let sensitive = variant.map_or(false, GetMeta::is_sensitive);
+                let path = self.path.clone().variant(inner.variant_name());
+                match inner.variant_kind() {
+                    VariantKind::Struct => {
+                        let mut f = f.debug_struct(inner.variant_name());
+                        for field in inner.fields() {
+                            if let VariantField::Struct(name, field) = field {
+                                let field_type = variant.and_then(|ty| ty.field_type(name));
+                                f.field(
+                                    name,
+                                    &self.child(
+                                        field,
+                                        path.clone().field(name),
+                                        field_type.map(NamedField::get_type),
+                                        sensitive
+                                            || field_type.map_or(false, GetMeta::is_sensitive),
+                                    ),
+                                );
+                            }
+                        }
+                        f.finish()
+                    }
+                    VariantKind::Tuple => {
+                        let mut f = f.debug_tuple(inner.variant_name());
+                        for (index, field) in inner.fields().enumerate() {
+                            if let VariantField::Tuple(field) = field {
+                                let field_type = variant.and_then(|ty| ty.field_type_at(index));
+                                f.field(&self.child(
+                                    field,
+                                    path.clone().field(index),
+                                    field_type.map(VariantFieldType::get_type),
+                                    sensitive || field_type.map_or(false, GetMeta::is_sensitive),
+                                ));
+                            }
+                        }
+                        f.finish()
+                    }
+                    VariantKind::Unit => f.write_str(inner.variant_name()),
+                }
+            }
+            ReflectRef::Array(inner) => {
+                let element_type = self.element_type();
+                let mut f = f.debug_list();
+                for (index, element) in inner.iter().enumerate().take(options.max_elems) {
+                    let path = self.path.clone().get(index);
+                    f.entry(&self.child(element, path, element_type, false));
+                }
+                if let Some(more) = More::new(inner.len(), options.max_elems) {
+                    f.entry(&more);
+                }
+                f.finish()
+            }
+            ReflectRef::List(inner) => {
+                let element_type = self.element_type();
+                let mut f = f.debug_list();
+                for (index, element) in inner.iter().enumerate().take(options.max_elems) {
+                    let path = self.path.clone().get(index);
+                    f.entry(&self.child(element, path, element_type, false));
+                }
+                if let Some(more) = More::new(inner.len(), options.max_elems) {
+                    f.entry(&more);
+                }
+                f.finish()
+            }
+            ReflectRef::Map(inner) => {
+                let ty = self.ty.and_then(Type::as_map);
+                let key_type = ty.map(MapType::key_type);
+                let value_type = ty.map(MapType::value_type);
+                let mut f = f.debug_map();
+                for (key, value) in inner.iter().take(options.max_elems) {
+                    let path = self.path.clone().get(key.to_value());
+                    f.entry(
+                        &self.child(key, self.path.clone(), key_type, false),
+                        &self.child(value, path, value_type, false),
+                    );
+                }
+                if let Some(more) = More::new(inner.len(), options.max_elems) {
+                    f.key(&more).value(&More::ELIDED);
+                }
+                f.finish()
+            }
+            ReflectRef::Set(inner) => {
+                let element_type = self.element_type();
+                let mut f = f.debug_set();
+                for element in inner.iter().take(options.max_elems) {
+                    f.entry(&self.child(element, self.path.clone(), element_type, false));
+                }
+                if let Some(more) = More::new(inner.len(), options.max_elems) {
+                    f.entry(&more);
+                }
+                f.finish()
+            }
+        }
+    }
+}
+
+/// Placeholder for elements left out because of [`LogOptions::max_elems`].
+struct More(usize);
+
+impl More {
+    const ELIDED: Self = Self(0);
+
+    fn new(len: usize, max_elems: usize) -> Option<Self> {
+        let remaining = len.saturating_sub(max_elems);
+        (remaining != 0).then_some(Self(remaining))
+    }
+}
+
+impl fmt::Debug for More {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0 == 0 {
+            f.write_str("..")
+        } else {
+            write!(f, "..{} more", self.0)
+        }
+    }
+}
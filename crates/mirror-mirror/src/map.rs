@@ -1,20 +1,35 @@
 use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
 use core::fmt;
 
+use crate::error;
+use crate::iter::IntoPairIter;
 use crate::iter::PairIterMut;
+use crate::key_path::KeyPath;
+use crate::scratch;
+use crate::type_info::Type;
+use crate::DescribeType;
+use crate::FromReflect;
 use crate::Reflect;
+use crate::ReflectError;
+use crate::Value;
+
+#[cfg(feature = "std")]
+pub use crate::foreign_impls::hash_map::SortedHashMap;
 
 /// A reflected map type.
 ///
-/// Note this is only implemented for [`BTreeMap`] and _not_ [`HashMap`] due to technical
-/// limitations.
+/// Implemented for [`BTreeMap`] and, with the `std` feature, [`HashMap`]. A `HashMap` iterates its
+/// entries in an unstable order, so [`Map::iter`] can yield them differently between runs. Use a
+/// [`SortedHashMap`] where that matters.
 ///
 /// [`BTreeMap`]: alloc::collections::BTreeMap
 /// [`HashMap`]: std::collections::HashMap
-// HashMap isn't supported because we need a `Value` variant for map values. The most obvious
-// choice is `enum Value { Map(HashMap<Value, Value>) }`. However now `Value` is used as the key in
-// a `HashMap` so it most implement `Hash + Eq` but it can't since it contains a `HashMap` which
-// doesn't implement `Hash + Eq`, because there is no stable iteration order.
+/// [`SortedHashMap`]: crate::map::SortedHashMap
+// `Value::Map` is always a `BTreeMap`. A `HashMap<Value, Value>` would require `Value: Hash + Eq`,
+// which it can't be while containing a `HashMap`, because there is no stable iteration order.
+// Converting `HashMap`s into `Value`s therefore sorts them, which keeps values deterministic.
 pub trait Map: Reflect {
     fn get(&self, key: &dyn Reflect) -> Option<&dyn Reflect>;
 
@@ -31,6 +46,114 @@ pub trait Map: Reflect {
     fn iter(&self) -> Iter<'_>;
 
     fn iter_mut(&mut self) -> PairIterMut<'_, dyn Reflect>;
+
+    /// Get the value for a string key, without allocating an owned key.
+    ///
+    /// Equivalent to `map.get(&key.to_owned())` but avoids allocating a `String` per call when
+    /// possible.
+    fn get_str(&self, key: &str) -> Option<&dyn Reflect> {
+        scratch::with_string_value(key, |key| self.get(key))
+    }
+
+    /// Get the value for a string key mutably, without allocating an owned key.
+    ///
+    /// See [`Map::get_str`].
+    fn get_str_mut(&mut self, key: &str) -> Option<&mut dyn Reflect> {
+        scratch::with_string_value(key, |key| self.get_mut(key))
+    }
+
+    /// Like [`Map::insert`] but returns an error if `key` or `value` couldn't be converted into
+    /// the key or value type of the map.
+    ///
+    /// Paths in errors for values are relative to the map, so start with the key.
+    fn try_insert(
+        &mut self,
+        key: &dyn Reflect,
+        value: &dyn Reflect,
+    ) -> Result<Option<Box<dyn Reflect>>, ReflectError> {
+        let descriptor = self.type_descriptor();
+        if let Type::Map(map_type) = descriptor.get_type() {
+            if let Some(error) = error::find_mismatch(map_type.key_type(), key) {
+                return Err(error);
+            }
+            if let Some(error) = error::find_mismatch(map_type.value_type(), value) {
+                return Err(error.under(&KeyPath::default().get(key.to_value())));
+            }
+        }
+        Ok(self.insert(key, value))
+    }
+
+    /// Get the entry for `key` for in-place updates.
+    ///
+    /// Implementations should override this to look `key` up only once. The default implementation
+    /// looks it up again for every step.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use mirror_mirror::{Map, Reflect};
+    ///
+    /// let mut counts = BTreeMap::<String, i32>::new();
+    /// let map = counts.as_reflect_mut().as_map_mut().unwrap();
+    ///
+    /// for word in ["a", "b", "a"] {
+    ///     map.entry(&word.to_owned())
+    ///         .and_modify(|count| *count.downcast_mut::<i32>().unwrap() += 1)
+    ///         .or_insert(&1_i32);
+    /// }
+    ///
+    /// assert_eq!(counts, BTreeMap::from([("a".to_owned(), 2), ("b".to_owned(), 1)]));
+    /// ```
+    fn entry(&mut self, key: &dyn Reflect) -> MapEntry<'_> {
+        if self.get(key).is_none() {
+            let key = key.to_value();
+            return MapEntry::Vacant(VacantEntry::new(move |value| {
+                self.insert(&key, value);
+                self.get_mut(&key)
+            }));
+        }
+        let value = self
+            .get_mut(key)
+            .expect("`Map::get` found a value but `Map::get_mut` didn't");
+        MapEntry::Occupied(value)
+    }
+
+    /// Remove all entries and return them, moving them out of the map rather than cloning them.
+    ///
+    /// The entries are removed even if the iterator isn't consumed.
+    fn drain(&mut self) -> IntoPairIter<'_, Box<dyn Reflect>> {
+        let keys = self
+            .iter()
+            .map(|(key, _)| key.to_value())
+            .collect::<Vec<_>>();
+        let entries = keys
+            .into_iter()
+            .filter_map(|key| {
+                let value = self.remove(&key)?;
+                Some((Box::new(key) as Box<dyn Reflect>, value))
+            })
+            .collect::<Vec<_>>();
+        Box::new(entries.into_iter())
+    }
+
+    /// Consume the map and return its entries, moving them out rather than cloning them.
+    fn into_entries(mut self: Box<Self>) -> IntoPairIter<'static, Box<dyn Reflect>> {
+        let entries = self.drain().collect::<Vec<_>>();
+        Box::new(entries.into_iter())
+    }
+}
+
+/// The string in `key`, if it's a `String` or a `Value::String`.
+///
+/// Maps with `String` keys use this to look such keys up directly, rather than converting them
+/// with `FromReflect` first.
+pub(crate) fn key_as_str(key: &dyn Reflect) -> Option<&str> {
+    match key.downcast_ref::<Value>() {
+        Some(Value::String(key)) => Some(key),
+        Some(_) => None,
+        None => key.downcast_ref::<String>().map(String::as_str),
+    }
 }
 
 impl fmt::Debug for dyn Map {
@@ -39,4 +162,147 @@ impl fmt::Debug for dyn Map {
     }
 }
 
+/// Convert the entries of a reflected map, skipping the ones whose key or value can't be
+/// converted.
+///
+/// Returns the converted entries along with the key and error of every entry that was skipped, or
+/// `None` if `reflect` isn't a map. See [`list::from_reflect_lossy`](crate::list::from_reflect_lossy).
+///
+/// # Example
+///
+/// ```
+/// use std::collections::BTreeMap;
+/// use mirror_mirror::{map::from_reflect_lossy, Value};
+///
+/// let value = Value::Map(BTreeMap::from([
+///     ("a".into(), 1_i32.into()),
+///     ("b".into(), 2.0_f32.into()),
+/// ]));
+///
+/// let (map, skipped): (BTreeMap<String, i32>, _) = from_reflect_lossy(&value).unwrap();
+///
+/// assert_eq!(map, BTreeMap::from([("a".to_owned(), 1)]));
+/// assert_eq!(skipped.len(), 1);
+/// assert_eq!(skipped[0].0, Value::from("b"));
+/// assert_eq!(skipped[0].1.expected(), "i32");
+/// ```
+pub fn from_reflect_lossy<C, K, V>(reflect: &dyn Reflect) -> Option<(C, Vec<(Value, ReflectError)>)>
+where
+    C: FromIterator<(K, V)>,
+    K: FromReflect + DescribeType,
+    V: FromReflect + DescribeType,
+{
+    let map = reflect.reflect_ref().as_map()?;
+
+    let mut skipped = Vec::new();
+    let collection = map
+        .iter()
+        .filter_map(|(key, value)| {
+            let entry = K::try_from_reflect(key).and_then(|k| Ok((k, V::try_from_reflect(value)?)));
+            match entry {
+                Ok(entry) => Some(entry),
+                Err(error) => {
+                    let key = key.to_value();
+                    let error = error.under(&KeyPath::default().get(key.clone()));
+                    skipped.push((key, error));
+                    None
+                }
+            }
+        })
+        .collect();
+    Some((collection, skipped))
+}
+
+/// An entry in a reflected [`Map`], returned by [`Map::entry`].
+pub enum MapEntry<'a> {
+    Occupied(&'a mut dyn Reflect),
+    Vacant(VacantEntry<'a>),
+}
+
+impl<'a> MapEntry<'a> {
+    /// Get the value, inserting `default` first if the entry is vacant.
+    ///
+    /// Returns `None` if `default` or the key couldn't be converted into the key or value type of
+    /// the map.
+    pub fn or_insert(self, default: &dyn Reflect) -> Option<&'a mut dyn Reflect> {
+        match self {
+            MapEntry::Occupied(value) => Some(value),
+            MapEntry::Vacant(entry) => entry.insert(default),
+        }
+    }
+
+    /// Like [`MapEntry::or_insert`] but only computes the default if the entry is vacant.
+    pub fn or_insert_with<F, T>(self, default: F) -> Option<&'a mut dyn Reflect>
+    where
+        F: FnOnce() -> T,
+        T: Reflect,
+    {
+        match self {
+            MapEntry::Occupied(value) => Some(value),
+            MapEntry::Vacant(entry) => entry.insert(&default()),
+        }
+    }
+
+    /// Call `f` with the value if the entry is occupied.
+    pub fn and_modify<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&mut dyn Reflect),
+    {
+        match self {
+            MapEntry::Occupied(value) => {
+                f(value);
+                MapEntry::Occupied(value)
+            }
+            MapEntry::Vacant(entry) => MapEntry::Vacant(entry),
+        }
+    }
+
+    pub fn is_occupied(&self) -> bool {
+        matches!(self, MapEntry::Occupied(_))
+    }
+}
+
+impl fmt::Debug for MapEntry<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MapEntry::Occupied(value) => f.debug_tuple("Occupied").field(value).finish(),
+            MapEntry::Vacant(entry) => f.debug_tuple("Vacant").field(entry).finish(),
+        }
+    }
+}
+
+/// A vacant entry in a reflected [`Map`].
+pub struct VacantEntry<'a> {
+    insert: Box<dyn FnOnce(&dyn Reflect) -> Option<&'a mut dyn Reflect> + 'a>,
+}
+
+impl<'a> VacantEntry<'a> {
+    /// Create a vacant entry from a function that inserts a value and returns it.
+    ///
+    /// This is for implementing [`Map::entry`]. The function should return `None` if the value
+    /// couldn't be inserted.
+    pub fn new<F>(insert: F) -> Self
+    where
+        F: FnOnce(&dyn Reflect) -> Option<&'a mut dyn Reflect> + 'a,
+    {
+        Self {
+            insert: Box::new(insert),
+        }
+    }
+
+    /// Insert `value` into the map and get it back.
+    ///
+    /// Returns `None` if `value` or the key couldn't be converted into the key or value type of
+    /// the map.
+    pub fn insert(self, value: &dyn Reflect) -> Option<&'a mut dyn Reflect> {
+        (self.insert)(value)
+    }
+}
+
+impl fmt::Debug for VacantEntry<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VacantEntry").finish_non_exhaustive()
+    }
+}
+
 pub type Iter<'a> = Box<dyn Iterator<Item = (&'a dyn Reflect, &'a dyn Reflect)> + 'a>;
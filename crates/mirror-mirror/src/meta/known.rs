@@ -0,0 +1,91 @@
+//! Well-known metadata keys.
+//!
+//! `#[derive(Reflect)]` checks the values of these keys have the right type:
+//!
+//! Key | Type | Value
+//! ---|---|---
+//! `read_only` | [`ReadOnly`] | `bool`
+//! `range` | [`Range`] | `RangeInclusive<f64>`
+//! `tooltip` | [`Tooltip`] | `String`
+//! `category` | [`Category`] | `String`
+//!
+//! # Example
+//!
+//! ```
+//! use mirror_mirror::{DescribeType, Reflect};
+//! use mirror_mirror::meta::known::{Range, ReadOnly};
+//! use mirror_mirror::type_info::GetMeta;
+//!
+//! #[derive(Reflect, Clone, Debug)]
+//! struct Light {
+//!     #[reflect(meta(range = 0.0..=1.0))]
+//!     intensity: f32,
+//!     #[reflect(meta(read_only = true))]
+//!     id: u32,
+//! }
+//!
+//! let descriptor = <Light as DescribeType>::type_descriptor();
+//! let light = descriptor.as_struct().unwrap();
+//!
+//! let intensity = light.field_type("intensity").unwrap();
+//! assert_eq!(intensity.typed_meta::<Range>(), Some(0.0..=1.0));
+//!
+//! let id = light.field_type("id").unwrap();
+//! assert_eq!(id.typed_meta::<ReadOnly>(), Some(true));
+//! ```
+//!
+//! Values of the wrong type fail to compile:
+//!
+//! ```compile_fail
+//! use mirror_mirror::Reflect;
+//!
+//! #[derive(Reflect, Clone, Debug)]
+//! struct Light {
+//!     // `range` is a range of `f64`s
+//!     #[reflect(meta(range = 0..=1))]
+//!     intensity: f32,
+//! }
+//! ```
+
+use alloc::string::String;
+use core::ops::RangeInclusive;
+
+use super::MetaKey;
+
+/// Whether a value shouldn't be edited, such as in an inspector.
+#[derive(Debug, Clone, Copy)]
+pub struct ReadOnly;
+
+impl MetaKey for ReadOnly {
+    const KEY: &'static str = "read_only";
+    type Value = bool;
+}
+
+/// The range of values a number may have, such as `0.0..=1.0`.
+#[derive(Debug, Clone, Copy)]
+pub struct Range;
+
+impl MetaKey for Range {
+    const KEY: &'static str = "range";
+    type Value = RangeInclusive<f64>;
+}
+
+/// Text shown when hovering a value, such as in an inspector.
+///
+/// [`UiHints`](crate::ui_hints::UiHints) falls back to the doc comment if this isn't set.
+#[derive(Debug, Clone, Copy)]
+pub struct Tooltip;
+
+impl MetaKey for Tooltip {
+    const KEY: &'static str = "tooltip";
+    type Value = String;
+}
+
+/// The name of the category a value belongs to, for grouping and filtering.
+#[derive(Debug, Clone, Copy)]
+pub struct Category;
+
+impl MetaKey for Category {
+    const KEY: &'static str = "category";
+    type Value = String;
+}
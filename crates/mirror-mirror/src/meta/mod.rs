@@ -0,0 +1,44 @@
+use crate::FromReflect;
+
+pub mod known;
+
+/// A metadata key with a known type of value.
+///
+/// Metadata is stored under string keys, so reading it with [`GetMeta::get_meta`] relies on every
+/// type using the same spelling and value type for a key. Reading it with
+/// [`GetMeta::typed_meta`] and a `MetaKey` instead keeps both in one place.
+///
+/// The keys in [`known`] are checked by `#[derive(Reflect)]`, so `#[reflect(meta(range = 0..=1))]`
+/// fails to compile because [`known::Range`] is a range of `f64`s.
+///
+/// # Example
+///
+/// ```
+/// use mirror_mirror::{DescribeType, Reflect};
+/// use mirror_mirror::meta::MetaKey;
+/// use mirror_mirror::type_info::GetMeta;
+///
+/// struct Unit;
+///
+/// impl MetaKey for Unit {
+///     const KEY: &'static str = "unit";
+///     type Value = String;
+/// }
+///
+/// #[derive(Reflect, Clone, Debug)]
+/// #[reflect(meta(unit = "m/s"))]
+/// struct Speed(f32);
+///
+/// let descriptor = <Speed as DescribeType>::type_descriptor();
+/// assert_eq!(descriptor.typed_meta::<Unit>().as_deref(), Some("m/s"));
+/// ```
+///
+/// [`GetMeta::get_meta`]: crate::type_info::GetMeta::get_meta
+/// [`GetMeta::typed_meta`]: crate::type_info::GetMeta::typed_meta
+pub trait MetaKey {
+    /// The key the metadata is stored under.
+    const KEY: &'static str;
+
+    /// The type of the metadata's value.
+    type Value: FromReflect;
+}
@@ -0,0 +1,166 @@
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use core::any::type_name;
+use core::any::Any;
+use core::any::TypeId;
+use core::fmt;
+use core::ops::Deref;
+use core::ops::DerefMut;
+
+use crate::registry::OpaqueCodecFns;
+#[cfg(feature = "std")]
+use crate::registry::TypeRegistry;
+use crate::tuple::TupleValue;
+use crate::type_info::graph::NodeId;
+use crate::type_info::graph::OpaqueNode;
+use crate::type_info::graph::TypeGraph;
+use crate::DescribeType;
+use crate::FromReflect;
+use crate::Reflect;
+use crate::ReflectMut;
+use crate::ReflectOwned;
+use crate::ReflectRef;
+use crate::Value;
+
+/// Wrapper that reflects any `Clone + PartialEq` type as an opaque leaf.
+///
+/// Useful for fields whose types don't implement [`Reflect`], such as handles to external
+/// resources, but that should still be carried along when the containing value is cloned or
+/// converted with [`FromReflect`]. Unlike `#[reflect(skip)]` the field stays visible through
+/// reflection, as [`ReflectRef::Opaque`], and the name of the wrapped type is recorded in the
+/// `inner` meta of its type info.
+///
+/// The wrapped value cannot be represented as a [`Value`], so `to_value` returns an empty tuple and
+/// [`FromReflect`] only succeeds when given an `Opaque<T>`. That is unless an [`OpaqueCodec`] is
+/// registered for `T` in the [global registry](TypeRegistry::global), in which case it is used
+/// for both.
+///
+/// [`OpaqueCodec`]: crate::registry::OpaqueCodec
+/// [`TypeRegistry::global`]: crate::registry::TypeRegistry::global
+///
+/// # Example
+///
+/// ```
+/// use mirror_mirror::{FromReflect, GetField, Opaque, Reflect, ReflectRef};
+///
+/// #[derive(Clone, PartialEq, Debug)]
+/// struct TextureHandle(u64);
+///
+/// #[derive(Reflect, Clone, Debug)]
+/// struct Material {
+///     texture: Opaque<TextureHandle>,
+///     roughness: f32,
+/// }
+///
+/// let material = Material {
+///     texture: Opaque(TextureHandle(1337)),
+///     roughness: 0.5,
+/// };
+///
+/// let texture = material.as_struct().unwrap().field("texture").unwrap();
+/// assert!(matches!(texture.reflect_ref(), ReflectRef::Opaque(_)));
+///
+/// let copy = Material::from_reflect(&material).unwrap();
+/// assert_eq!(*copy.texture, TextureHandle(1337));
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Debug)]
+pub struct Opaque<T>(pub T);
+
+impl<T> Deref for Opaque<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Opaque<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T> From<T> for Opaque<T> {
+    fn from(inner: T) -> Self {
+        Self(inner)
+    }
+}
+
+impl<T> DescribeType for Opaque<T>
+where
+    T: Clone + PartialEq + Send + 'static,
+{
+    fn build(graph: &mut TypeGraph) -> NodeId {
+        graph.get_or_build_node_with::<Self, _>(|graph| {
+            OpaqueNode::new::<Self>(
+                BTreeMap::from([("inner", Value::from(type_name::<T>()))]),
+                graph,
+            )
+        })
+    }
+}
+
+impl<T> Reflect for Opaque<T>
+where
+    T: Clone + PartialEq + Send + 'static,
+{
+    trivial_reflect_methods!();
+
+    fn patch(&mut self, value: &dyn Reflect) {
+        if let Some(value) = value.downcast_ref::<Self>() {
+            if self != value {
+                self.clone_from(value);
+            }
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        global_codec(TypeId::of::<T>())
+            .and_then(|codec| codec.encode(&self.0))
+            .unwrap_or_else(|| TupleValue::new().into())
+    }
+
+    fn clone_reflect(&self) -> Box<dyn Reflect> {
+        Box::new(self.clone())
+    }
+
+    fn debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Opaque<{}>", type_name::<T>())
+    }
+
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        ReflectOwned::Opaque(self)
+    }
+
+    fn reflect_ref(&self) -> ReflectRef<'_> {
+        ReflectRef::Opaque(self)
+    }
+
+    fn reflect_mut(&mut self) -> ReflectMut<'_> {
+        ReflectMut::Opaque(self)
+    }
+}
+
+impl<T> FromReflect for Opaque<T>
+where
+    T: Clone + PartialEq + Send + 'static,
+{
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        if let Some(opaque) = reflect.downcast_ref::<Self>() {
+            return Some(opaque.clone());
+        }
+        global_codec(TypeId::of::<T>())?.decode(reflect).map(Self)
+    }
+}
+
+// the lock is released before the codec runs, so codecs can convert nested opaque values
+#[cfg(feature = "std")]
+fn global_codec(type_id: TypeId) -> Option<OpaqueCodecFns> {
+    let registry = TypeRegistry::global().read().ok()?;
+    registry.opaque_codec(type_id)
+}
+
+#[cfg(not(feature = "std"))]
+fn global_codec(_: TypeId) -> Option<OpaqueCodecFns> {
+    None
+}
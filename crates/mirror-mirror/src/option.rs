@@ -0,0 +1,174 @@
+use core::fmt;
+
+use crate::enum_::EnumValue;
+use crate::error;
+use crate::Enum;
+use crate::Reflect;
+use crate::ReflectError;
+
+/// A borrowed view of an [`Option`], returned by [`Reflect::as_option`].
+///
+/// Saves going through [`Enum`] with the variant names `"Some"` and `"None"`.
+///
+/// # Example
+///
+/// ```
+/// use mirror_mirror::Reflect;
+///
+/// let value: &dyn Reflect = &Some(1337);
+///
+/// let option = value.as_option().unwrap();
+/// assert!(option.is_some());
+/// assert_eq!(option.get().unwrap().downcast_ref::<i32>(), Some(&1337));
+///
+/// // other types aren't options, even if they are enums
+/// assert!(1337.as_option().is_none());
+/// ```
+#[derive(Clone, Copy)]
+pub struct OptionRef<'a> {
+    inner: &'a dyn Enum,
+}
+
+impl<'a> OptionRef<'a> {
+    pub(crate) fn new(value: &'a dyn Reflect) -> Option<Self> {
+        let inner = value.as_enum().filter(|_| is_option_value(value))?;
+        Some(Self { inner })
+    }
+
+    pub fn is_some(&self) -> bool {
+        self.inner.variant_name() == "Some"
+    }
+
+    pub fn is_none(&self) -> bool {
+        !self.is_some()
+    }
+
+    /// The value inside `Some`, or `None`.
+    pub fn get(&self) -> Option<&'a dyn Reflect> {
+        if self.is_some() {
+            self.inner.field_at(0)
+        } else {
+            None
+        }
+    }
+
+    pub fn as_enum(&self) -> &'a dyn Enum {
+        self.inner
+    }
+}
+
+impl fmt::Debug for OptionRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inner.as_reflect().debug(f)
+    }
+}
+
+/// A mutable view of an [`Option`], returned by [`Reflect::as_option_mut`].
+///
+/// # Example
+///
+/// ```
+/// use mirror_mirror::Reflect;
+///
+/// let mut value = Some(String::from("foo"));
+///
+/// let mut option = value.as_option_mut().unwrap();
+/// option.set_none();
+/// assert!(option.is_none());
+///
+/// option.set_some(&String::from("bar")).unwrap();
+/// assert_eq!(value.as_deref(), Some("bar"));
+///
+/// // values of the wrong type are rejected
+/// let mut option = value.as_option_mut().unwrap();
+/// assert!(option.set_some(&1337).is_err());
+/// assert_eq!(value.as_deref(), Some("bar"));
+/// ```
+pub struct OptionMut<'a> {
+    inner: &'a mut dyn Enum,
+}
+
+impl<'a> OptionMut<'a> {
+    pub(crate) fn new(value: &'a mut dyn Reflect) -> Option<Self> {
+        if !is_option_value(value) {
+            return None;
+        }
+        let inner = value.as_enum_mut()?;
+        Some(Self { inner })
+    }
+
+    pub fn is_some(&self) -> bool {
+        self.inner.variant_name() == "Some"
+    }
+
+    pub fn is_none(&self) -> bool {
+        !self.is_some()
+    }
+
+    /// The value inside `Some`, or `None`.
+    pub fn get(&self) -> Option<&dyn Reflect> {
+        if self.is_some() {
+            self.inner.field_at(0)
+        } else {
+            None
+        }
+    }
+
+    /// The value inside `Some`, or `None`.
+    pub fn get_mut(&mut self) -> Option<&mut dyn Reflect> {
+        if self.is_some() {
+            self.inner.field_at_mut(0)
+        } else {
+            None
+        }
+    }
+
+    /// Set the option to `None`.
+    pub fn set_none(&mut self) {
+        self.inner.patch(&EnumValue::new_unit_variant("None"));
+    }
+
+    /// Set the option to `Some(value)`, replacing any previous value.
+    ///
+    /// Returns an error, and leaves the option unchanged, if `value` doesn't have the type of the
+    /// value inside the option.
+    pub fn set_some(&mut self, value: &dyn Reflect) -> Result<(), ReflectError> {
+        let descriptor = self.inner.type_descriptor();
+        let field_type = descriptor
+            .as_enum()
+            .and_then(|enum_type| enum_type.variant("Some"))
+            .and_then(|variant| variant.field_types().next())
+            .ok_or_else(|| ReflectError::new(self.inner.type_name(), Some(value)))?;
+        if let Some(error) = error::find_mismatch(field_type.get_type(), value) {
+            return Err(error);
+        }
+
+        // patching `Some` with `Some` would patch the old value in place, which for example keeps
+        // elements of a longer list, so switch variant twice to replace it entirely
+        self.set_none();
+        self.inner.patch(
+            &EnumValue::new_tuple_variant_with_capacity("Some", 1)
+                .with_tuple_field(value.to_value())
+                .finish(),
+        );
+        Ok(())
+    }
+
+    pub fn as_enum(&self) -> &dyn Enum {
+        self.inner
+    }
+
+    pub fn as_enum_mut(&mut self) -> &mut dyn Enum {
+        self.inner
+    }
+}
+
+impl fmt::Debug for OptionMut<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inner.as_reflect().debug(f)
+    }
+}
+
+fn is_option_value(value: &dyn Reflect) -> bool {
+    value.type_name().starts_with("core::option::Option<")
+}
@@ -0,0 +1,309 @@
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::enum_::VariantField;
+use crate::error;
+use crate::key_path::value_at_key;
+use crate::key_path::GetPath;
+use crate::key_path::KeyPath;
+use crate::validate::validate;
+use crate::Reflect;
+use crate::ReflectError;
+use crate::ReflectMut;
+use crate::ReflectRef;
+
+/// What was applied by [`Reflect::try_patch`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct PatchReport {
+    applied: Vec<KeyPath>,
+    skipped: Vec<ReflectError>,
+}
+
+impl PatchReport {
+    /// The paths of the values that were patched.
+    ///
+    /// Only the outermost values that were patched as a whole are included, such as scalars,
+    /// sets, and enums that switched variant, not the structs and lists containing them.
+    pub fn applied(&self) -> &[KeyPath] {
+        &self.applied
+    }
+
+    /// The parts of the patch that couldn't be applied, and why.
+    ///
+    /// The path of each error is where in the patch the value was.
+    pub fn skipped(&self) -> &[ReflectError] {
+        &self.skipped
+    }
+
+    /// Whether every part of the patch was applied.
+    pub fn is_complete(&self) -> bool {
+        self.skipped.is_empty()
+    }
+}
+
+/// Error returned by [`Reflect::try_patch`] if some part of the patch couldn't be applied.
+///
+/// The parts that could be applied still are, so the report lists both, unless the patch left
+/// the value invalid in which case nothing is applied.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PatchError {
+    report: PatchReport,
+}
+
+impl PatchError {
+    pub fn report(&self) -> &PatchReport {
+        &self.report
+    }
+
+    pub fn into_report(self) -> PatchReport {
+        self.report
+    }
+}
+
+impl fmt::Display for PatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let skipped = self.report.skipped();
+        write!(
+            f,
+            "{} part(s) of the patch couldn't be applied",
+            skipped.len()
+        )?;
+        if let Some(first) = skipped.first() {
+            write!(f, ", first: {first}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PatchError {}
+
+pub(crate) fn try_patch(
+    target: &mut dyn Reflect,
+    patch: &dyn Reflect,
+) -> Result<PatchReport, PatchError> {
+    let mut report = PatchReport::default();
+    let mut undo = Vec::new();
+    go(
+        target,
+        patch,
+        &mut KeyPath::default(),
+        &mut report,
+        &mut undo,
+    );
+    if let Some(error) = find_violation(target, &report.applied) {
+        // patches that leave the value invalid are undone as a whole, since validators can
+        // depend on several fields
+        for (path, previous) in undo.into_iter().rev() {
+            if let Some(target) = target.at_mut(&path) {
+                target.patch(&*previous);
+            }
+        }
+        report.applied.clear();
+        report.skipped.push(error);
+    }
+    if report.is_complete() {
+        Ok(report)
+    } else {
+        Err(PatchError { report })
+    }
+}
+
+/// The values replaced by a patch, with their paths, so they can be put back if the patch leaves
+/// the value invalid.
+///
+/// Only the outermost patched values are copied, not the whole target, and since each is put back
+/// by patching it with a value of its own type it is replaced as a whole.
+type Undo = Vec<(KeyPath, Box<dyn Reflect>)>;
+
+fn go(
+    target: &mut dyn Reflect,
+    patch: &dyn Reflect,
+    path: &mut KeyPath,
+    report: &mut PatchReport,
+    undo: &mut Undo,
+) {
+    macro_rules! field {
+        ($key:expr, $target:expr, $patch:expr) => {{
+            path.push_field($key);
+            match $target {
+                Some(target) => go(target, $patch, path, report, undo),
+                None => missing($patch, path, report),
+            }
+            path.pop();
+        }};
+    }
+
+    macro_rules! elements {
+        ($target:expr, $patch:expr) => {{
+            let target = $target;
+            for (index, element) in $patch.iter().enumerate() {
+                path.push_get(index);
+                match target.get_mut(index) {
+                    Some(target) => go(target, element, path, report, undo),
+                    None => missing(element, path, report),
+                }
+                path.pop();
+            }
+            return;
+        }};
+    }
+
+    // structs without mutable access to their fields, such as `RangeInclusive`, are patched as a
+    // whole once each field of the patch has been checked
+    let read_only_fields = target
+        .reflect_mut()
+        .as_struct_mut()
+        .map_or(false, |target| {
+            target.fields_len() > 0 && target.field_at_mut(0).is_none()
+        });
+
+    match (target.reflect_mut(), patch.reflect_ref()) {
+        (ReflectMut::Struct(target), ReflectRef::Struct(patch)) if read_only_fields => {
+            let descriptor = target.type_descriptor();
+            let skipped = report.skipped.len();
+            for (name, field) in patch.fields() {
+                path.push_field(name);
+                let field_type = descriptor
+                    .as_struct()
+                    .and_then(|struct_type| struct_type.field_type(name));
+                match field_type {
+                    Some(field_type) => {
+                        if let Some(error) = error::find_mismatch(field_type.get_type(), field) {
+                            report.skipped.push(error.under(path));
+                        }
+                    }
+                    None => missing(field, path, report),
+                }
+                path.pop();
+            }
+            if report.skipped.len() == skipped {
+                apply(
+                    target.as_reflect_mut(),
+                    patch.as_reflect(),
+                    path,
+                    report,
+                    undo,
+                );
+            }
+            return;
+        }
+        (ReflectMut::Struct(target), ReflectRef::Struct(patch)) => {
+            for (name, field) in patch.fields() {
+                field!(name, target.field_mut(name), field);
+            }
+            return;
+        }
+        (ReflectMut::TupleStruct(target), ReflectRef::TupleStruct(patch)) => {
+            for (index, field) in patch.fields().enumerate() {
+                field!(index, target.field_at_mut(index), field);
+            }
+            return;
+        }
+        (ReflectMut::Tuple(target), ReflectRef::Tuple(patch)) => {
+            for (index, field) in patch.fields().enumerate() {
+                field!(index, target.field_at_mut(index), field);
+            }
+            return;
+        }
+        (ReflectMut::Enum(target), ReflectRef::Enum(patch))
+            if target.variant_name() == patch.variant_name() =>
+        {
+            path.push_variant(patch.variant_name());
+            for (index, field) in patch.fields().enumerate() {
+                match field {
+                    VariantField::Struct(name, field) => {
+                        field!(name, target.field_mut(name), field)
+                    }
+                    VariantField::Tuple(field) => {
+                        field!(index, target.field_at_mut(index), field)
+                    }
+                }
+            }
+            path.pop();
+            return;
+        }
+        (ReflectMut::Array(target), ReflectRef::Array(patch)) => elements!(target, patch),
+        (ReflectMut::Array(target), ReflectRef::List(patch)) => elements!(target, patch),
+        (ReflectMut::List(target), ReflectRef::List(patch)) => elements!(target, patch),
+        (ReflectMut::List(target), ReflectRef::Array(patch)) => elements!(target, patch),
+        (ReflectMut::Map(target), ReflectRef::Map(patch)) => {
+            for (key, value) in patch.iter() {
+                path.push_get(key.to_value());
+                match target.get_mut(key) {
+                    Some(target) => go(target, value, path, report, undo),
+                    None => missing(value, path, report),
+                }
+                path.pop();
+            }
+            return;
+        }
+        // opaque values have nothing to compare against, so they are assumed to be compatible
+        (ReflectMut::Opaque(target), _) => {
+            apply(target, patch, path, report, undo);
+            return;
+        }
+        // scalars, sets, and enums switching variant are patched as a whole, so check that the
+        // patch has the right shape before applying it
+        (ReflectMut::Scalar(_), ReflectRef::Scalar(_))
+        | (ReflectMut::Set(_), ReflectRef::Set(_))
+        | (ReflectMut::Enum(_), ReflectRef::Enum(_)) => {}
+        _ => {
+            report
+                .skipped
+                .push(ReflectError::new(target.type_name(), Some(patch)).at(path.clone()));
+            return;
+        }
+    }
+
+    let descriptor = target.type_descriptor();
+    match error::find_mismatch(descriptor.get_type(), patch) {
+        Some(error) => report.skipped.push(error.under(path)),
+        None => {
+            apply(target, patch, path, report, undo);
+        }
+    }
+}
+
+/// Patch `target` as a whole with `patch`, keeping a copy of it in `undo`.
+fn apply(
+    target: &mut dyn Reflect,
+    patch: &dyn Reflect,
+    path: &KeyPath,
+    report: &mut PatchReport,
+    undo: &mut Undo,
+) {
+    undo.push((path.clone(), target.clone_reflect()));
+    target.patch(patch);
+    report.applied.push(path.clone());
+}
+
+/// Find a value rejected by its validator among the patched values, the values inside them, and
+/// the values containing them.
+fn find_violation(target: &dyn Reflect, applied: &[KeyPath]) -> Option<ReflectError> {
+    for path in applied {
+        let mut value = target;
+        let mut prefix = KeyPath::default();
+        for key in path {
+            if let Err(error) = value.validate_self() {
+                return Some(error.under(&prefix));
+            }
+            value = value_at_key(value, key)?;
+            prefix.push(key.clone());
+        }
+        if let Err(error) = validate(value) {
+            return Some(error.under(path));
+        }
+    }
+    None
+}
+
+/// Report that there is nothing at `path` in the target to patch with `patch`.
+fn missing(patch: &dyn Reflect, path: &KeyPath, report: &mut PatchReport) {
+    report.skipped.push(
+        ReflectError::from_parts(format!("`{path}`"), Some(patch.type_name().into()))
+            .at(path.clone()),
+    );
+}
@@ -0,0 +1,402 @@
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+use crate::enum_::EnumValue;
+use crate::enum_::VariantField;
+use crate::enum_::VariantKind;
+use crate::key_path::GetPath;
+use crate::key_path::Key;
+use crate::key_path::KeyPath;
+use crate::key_path::KeyPathPattern;
+use crate::struct_::StructValue;
+use crate::tuple::TupleValue;
+use crate::tuple_struct::TupleStructValue;
+use crate::Reflect;
+use crate::ReflectRef;
+use crate::Value;
+
+/// Allow and deny lists of [`KeyPathPattern`]s that control which parts of a value can be
+/// accessed.
+///
+/// Everything is hidden by default. Paths must be explicitly allowed to be visible. Allowing a
+/// path also allows everything nested inside it, unless denied or made read-only.
+///
+/// ```
+/// use mirror_mirror::key_path;
+/// use mirror_mirror::key_path::KeyPathPattern;
+/// use mirror_mirror::permissions::{Access, Permissions};
+///
+/// let permissions = Permissions::new()
+///     .allow(key_path!(.player))
+///     .deny(key_path!(.player.password))
+///     .read_only(key_path!(.player.name));
+///
+/// assert_eq!(permissions.access(&key_path!(.player.score)), Access::ReadWrite);
+/// assert_eq!(permissions.access(&key_path!(.player.name)), Access::ReadOnly);
+/// assert_eq!(permissions.access(&key_path!(.player.password)), Access::Hidden);
+/// assert_eq!(permissions.access(&key_path!(.server)), Access::Hidden);
+///
+/// // allow everything
+/// let permissions = Permissions::new().allow(KeyPathPattern::default());
+/// assert_eq!(permissions.access(&key_path!(.server)), Access::ReadWrite);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Permissions {
+    allow: Vec<KeyPathPattern>,
+    deny: Vec<KeyPathPattern>,
+    read_only: Vec<KeyPathPattern>,
+}
+
+/// How a key path can be accessed according to some [`Permissions`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Access {
+    /// The path cannot be read nor written.
+    Hidden,
+    /// The path can be read but not written.
+    ///
+    /// This is also the case for paths that are only visible because something nested inside
+    /// them is allowed.
+    ReadOnly,
+    /// The path can be read and written.
+    ReadWrite,
+}
+
+impl Permissions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allow accessing paths matched by the pattern, and everything nested inside them.
+    pub fn allow(mut self, pattern: impl Into<KeyPathPattern>) -> Self {
+        self.push_allow(pattern);
+        self
+    }
+
+    pub fn push_allow(&mut self, pattern: impl Into<KeyPathPattern>) {
+        self.allow.push(pattern.into());
+    }
+
+    /// Hide paths matched by the pattern, and everything nested inside them.
+    ///
+    /// Takes precedence over [`allow`](Self::allow).
+    pub fn deny(mut self, pattern: impl Into<KeyPathPattern>) -> Self {
+        self.push_deny(pattern);
+        self
+    }
+
+    pub fn push_deny(&mut self, pattern: impl Into<KeyPathPattern>) {
+        self.deny.push(pattern.into());
+    }
+
+    /// Make paths matched by the pattern, and everything nested inside them, read-only.
+    pub fn read_only(mut self, pattern: impl Into<KeyPathPattern>) -> Self {
+        self.push_read_only(pattern);
+        self
+    }
+
+    pub fn push_read_only(&mut self, pattern: impl Into<KeyPathPattern>) {
+        self.read_only.push(pattern.into());
+    }
+
+    /// Get how the key path can be accessed.
+    pub fn access(&self, key_path: &KeyPath) -> Access {
+        if any_prefix_of(&self.deny, key_path) {
+            return Access::Hidden;
+        }
+
+        if any_prefix_of(&self.allow, key_path) {
+            if any_prefix_of(&self.read_only, key_path) {
+                Access::ReadOnly
+            } else {
+                Access::ReadWrite
+            }
+        } else if any_descendant_of(&self.allow, key_path) {
+            Access::ReadOnly
+        } else {
+            Access::Hidden
+        }
+    }
+
+    /// Can the value at the key path, including everything nested inside it, be read?
+    fn can_read_all(&self, key_path: &KeyPath) -> bool {
+        any_prefix_of(&self.allow, key_path)
+            && !any_prefix_of(&self.deny, key_path)
+            && !any_descendant_of(&self.deny, key_path)
+    }
+
+    /// Can the value at the key path, including everything nested inside it, be written?
+    fn can_write_all(&self, key_path: &KeyPath) -> bool {
+        self.can_read_all(key_path)
+            && !any_prefix_of(&self.read_only, key_path)
+            && !any_descendant_of(&self.read_only, key_path)
+    }
+}
+
+fn any_prefix_of(patterns: &[KeyPathPattern], key_path: &KeyPath) -> bool {
+    patterns
+        .iter()
+        .any(|pattern| pattern.matches_prefix_of(key_path))
+}
+
+fn any_descendant_of(patterns: &[KeyPathPattern], key_path: &KeyPath) -> bool {
+    patterns
+        .iter()
+        .any(|pattern| pattern.matches_descendant_of(key_path))
+}
+
+/// Create a read-only view of `value` that only exposes what `permissions` allow.
+pub fn restricted_view<'a>(
+    value: &'a dyn Reflect,
+    permissions: &'a Permissions,
+) -> RestrictedView<'a> {
+    RestrictedView { value, permissions }
+}
+
+/// Create a mutable view of `value` that only exposes what `permissions` allow.
+pub fn restricted_view_mut<'a>(
+    value: &'a mut dyn Reflect,
+    permissions: &'a Permissions,
+) -> RestrictedViewMut<'a> {
+    RestrictedViewMut { value, permissions }
+}
+
+/// A view of a reflected value that hides paths according to some [`Permissions`].
+///
+/// The view doesn't implement [`Reflect`] itself. Values can only be read through it with
+/// [`at`](Self::at), which requires everything at the path to be readable, or as [`Value`] copies
+/// with the hidden parts removed with [`value_at`](Self::value_at) and
+/// [`to_value`](Self::to_value).
+///
+/// Constructed with [`restricted_view`].
+#[derive(Debug, Clone, Copy)]
+pub struct RestrictedView<'a> {
+    value: &'a dyn Reflect,
+    permissions: &'a Permissions,
+}
+
+impl<'a> RestrictedView<'a> {
+    /// Get how the key path can be accessed.
+    pub fn access(&self, key_path: &KeyPath) -> Access {
+        self.permissions.access(key_path)
+    }
+
+    /// Get the value at the key path.
+    ///
+    /// Returns `None` if anything at or nested inside the path is hidden, since the returned
+    /// value would otherwise expose it. Use [`value_at`](Self::value_at) to get a copy with the
+    /// hidden parts removed.
+    pub fn at(&self, key_path: &KeyPath) -> Option<&'a dyn Reflect> {
+        if !self.permissions.can_read_all(key_path) {
+            return None;
+        }
+        self.value.at(key_path)
+    }
+
+    pub fn get_at<T>(&self, key_path: &KeyPath) -> Option<&'a T>
+    where
+        T: Reflect,
+    {
+        self.at(key_path)?.downcast_ref()
+    }
+
+    /// Convert the visible parts of the value at the key path into a [`Value`].
+    ///
+    /// Hidden fields and map entries are left out. Hidden elements of lists, arrays, and tuples
+    /// are replaced by `()` so the other elements keep their indices. Sets and opaque values are
+    /// left out unless everything inside them is visible, since it can't be addressed by key paths.
+    pub fn value_at(&self, key_path: &KeyPath) -> Option<Value> {
+        let value = self.value.at(key_path)?;
+        let mut key_path = key_path.clone();
+        filtered_value(value, self.permissions, &mut key_path)
+    }
+
+    /// Convert the visible parts of the whole value into a [`Value`].
+    pub fn to_value(&self) -> Option<Value> {
+        self.value_at(&KeyPath::default())
+    }
+}
+
+/// A mutable view of a reflected value that hides and protects paths according to some
+/// [`Permissions`].
+///
+/// Constructed with [`restricted_view_mut`].
+#[derive(Debug)]
+pub struct RestrictedViewMut<'a> {
+    value: &'a mut dyn Reflect,
+    permissions: &'a Permissions,
+}
+
+impl<'a> RestrictedViewMut<'a> {
+    /// Get a read-only view of the value.
+    pub fn as_view(&self) -> RestrictedView<'_> {
+        RestrictedView {
+            value: self.value,
+            permissions: self.permissions,
+        }
+    }
+
+    /// Get how the key path can be accessed.
+    pub fn access(&self, key_path: &KeyPath) -> Access {
+        self.permissions.access(key_path)
+    }
+
+    /// See [`RestrictedView::at`].
+    pub fn at(&self, key_path: &KeyPath) -> Option<&dyn Reflect> {
+        self.as_view().at(key_path)
+    }
+
+    pub fn get_at<T>(&self, key_path: &KeyPath) -> Option<&T>
+    where
+        T: Reflect,
+    {
+        self.at(key_path)?.downcast_ref()
+    }
+
+    /// Mutably get the value at the key path.
+    ///
+    /// Returns `None` if anything at or nested inside the path is hidden or read-only.
+    pub fn at_mut(&mut self, key_path: &KeyPath) -> Option<&mut dyn Reflect> {
+        if !self.permissions.can_write_all(key_path) {
+            return None;
+        }
+        self.value.at_mut(key_path)
+    }
+
+    pub fn get_at_mut<T>(&mut self, key_path: &KeyPath) -> Option<&mut T>
+    where
+        T: Reflect,
+    {
+        self.at_mut(key_path)?.downcast_mut()
+    }
+
+    /// See [`RestrictedView::value_at`].
+    pub fn value_at(&self, key_path: &KeyPath) -> Option<Value> {
+        self.as_view().value_at(key_path)
+    }
+
+    /// See [`RestrictedView::to_value`].
+    pub fn to_value(&self) -> Option<Value> {
+        self.as_view().to_value()
+    }
+}
+
+fn filtered_value(
+    value: &dyn Reflect,
+    permissions: &Permissions,
+    key_path: &mut KeyPath,
+) -> Option<Value> {
+    match permissions.access(key_path) {
+        Access::Hidden => return None,
+        Access::ReadOnly | Access::ReadWrite => {}
+    }
+
+    if permissions.can_read_all(key_path) {
+        return Some(value.to_value());
+    }
+
+    let mut visit = |key: Key, value: &dyn Reflect| {
+        key_path.push(key);
+        let value = filtered_value(value, permissions, key_path);
+        key_path.pop();
+        value
+    };
+
+    // hidden elements of positional values are replaced, rather than left out, so the indices of
+    // the following elements don't change
+    let placeholder = || Value::TupleValue(TupleValue::new());
+
+    let value = match value.reflect_ref() {
+        ReflectRef::Struct(inner) => {
+            let mut out = StructValue::with_capacity(inner.fields_len());
+            for (name, value) in inner.fields() {
+                if let Some(value) = visit(Key::named_field(name), value) {
+                    out.set_field(name, value);
+                }
+            }
+            out.into()
+        }
+        ReflectRef::TupleStruct(inner) => {
+            let mut out = TupleStructValue::with_capacity(inner.fields_len());
+            for (index, value) in inner.fields().enumerate() {
+                let value = visit(Key::numbered_field(index), value);
+                out.push_field(value.unwrap_or_else(placeholder));
+            }
+            out.into()
+        }
+        ReflectRef::Tuple(inner) => {
+            let mut out = TupleValue::with_capacity(inner.fields_len());
+            for (index, value) in inner.fields().enumerate() {
+                let value = visit(Key::numbered_field(index), value);
+                out.push_field(value.unwrap_or_else(placeholder));
+            }
+            out.into()
+        }
+        ReflectRef::Enum(inner) => {
+            let variant_name = inner.variant_name();
+            key_path.push(Key::variant(variant_name));
+            let out = match inner.variant_kind() {
+                VariantKind::Struct => {
+                    let mut out = EnumValue::new_struct_variant(variant_name).finish();
+                    for field in inner.fields() {
+                        if let VariantField::Struct(name, value) = field {
+                            key_path.push(Key::named_field(name));
+                            let value = filtered_value(value, permissions, key_path);
+                            key_path.pop();
+                            if let Some(value) = value {
+                                out.set_struct_field(name, value);
+                            }
+                        }
+                    }
+                    out
+                }
+                VariantKind::Tuple => {
+                    let mut out = EnumValue::new_tuple_variant(variant_name).finish();
+                    for (index, field) in inner.fields().enumerate() {
+                        if let VariantField::Tuple(value) = field {
+                            key_path.push(Key::numbered_field(index));
+                            let value = filtered_value(value, permissions, key_path);
+                            key_path.pop();
+                            out.push_tuple_field(value.unwrap_or_else(placeholder));
+                        }
+                    }
+                    out
+                }
+                VariantKind::Unit => EnumValue::new_unit_variant(variant_name),
+            };
+            key_path.pop();
+            out.into()
+        }
+        ReflectRef::Array(inner) => {
+            let out: Vec<Value> = inner
+                .iter()
+                .enumerate()
+                .map(|(index, value)| visit(Key::get(index), value).unwrap_or_else(placeholder))
+                .collect();
+            out.into()
+        }
+        ReflectRef::List(inner) => {
+            let out: Vec<Value> = inner
+                .iter()
+                .enumerate()
+                .map(|(index, value)| visit(Key::get(index), value).unwrap_or_else(placeholder))
+                .collect();
+            out.into()
+        }
+        ReflectRef::Map(inner) => {
+            let mut out = BTreeMap::new();
+            for (key, value) in inner.iter() {
+                let key = key.to_value();
+                if let Some(value) = visit(Key::Get(key.clone()), value) {
+                    out.insert(key, value);
+                }
+            }
+            out.into()
+        }
+        // set elements and the insides of opaque values can't be addressed by key paths, so they
+        // are hidden entirely unless everything inside them is readable
+        ReflectRef::Set(_) | ReflectRef::Opaque(_) => return None,
+        ReflectRef::Scalar(_) => value.to_value(),
+    };
+
+    Some(value)
+}
@@ -0,0 +1,217 @@
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::key_path::GetPath;
+use crate::key_path::KeyPath;
+use crate::type_info::GetMeta;
+use crate::visit::walk;
+use crate::visit::ReflectVisitor;
+use crate::Reflect;
+use crate::TypeDescriptor;
+
+type Predicate = Box<dyn Fn(&dyn Reflect) -> bool + Send + Sync>;
+
+/// Finds the values nested inside a reflected value that match some filters.
+///
+/// A value matches if it passes every filter:
+///
+/// - [`of_type`](Self::of_type) matches values of one type.
+/// - [`with_meta`](Self::with_meta) matches fields and variants with some metadata, or whose type
+///   has it.
+/// - [`matching`](Self::matching) matches values for which a predicate returns `true`.
+///
+/// The root value and everything inside it is checked, except map keys, and matches are
+/// returned outermost first, in the order [`walk`] visits them.
+///
+/// # Example
+///
+/// ```
+/// use mirror_mirror::{key_path, Reflect};
+/// use mirror_mirror::query::Query;
+///
+/// #[derive(Reflect, Clone, Debug)]
+/// struct Scene {
+///     bodies: Vec<Body>,
+/// }
+///
+/// #[derive(Reflect, Clone, Debug)]
+/// struct Body {
+///     #[reflect(meta(editable = true))]
+///     mass: f32,
+///     radius: f32,
+/// }
+///
+/// let mut scene = Scene {
+///     bodies: Vec::from([
+///         Body { mass: 1.0, radius: 1.0 },
+///         Body { mass: 0.0, radius: 2.0 },
+///     ]),
+/// };
+///
+/// let query = Query::new()
+///     .of_type::<f32>()
+///     .with_meta("editable")
+///     .matching(|value| value.downcast_ref::<f32>() != Some(&0.0));
+///
+/// assert_eq!(query.find_paths(&scene), [key_path!(.bodies[0_usize].mass)]);
+///
+/// // multiply the mass of every body that has one by 2
+/// query.for_each_mut(&mut scene, |_path, value| {
+///     *value.downcast_mut::<f32>().unwrap() *= 2.0;
+/// });
+/// assert_eq!(scene.bodies[0].mass, 2.0);
+/// assert_eq!(scene.bodies[1].mass, 0.0);
+/// ```
+#[derive(Default)]
+pub struct Query {
+    of_type: Option<fn(&dyn Reflect) -> bool>,
+    meta: Vec<String>,
+    predicates: Vec<Predicate>,
+}
+
+impl Query {
+    /// Create a query that matches every value.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only match values of type `T`, replacing any type given before.
+    pub fn of_type<T>(mut self) -> Self
+    where
+        T: Reflect,
+    {
+        self.of_type = Some(is::<T>);
+        self
+    }
+
+    /// Only match fields or variants with the metadata `key`, or whose type has it.
+    ///
+    /// See [`GetMeta::inherited_meta_at`].
+    pub fn with_meta(mut self, key: impl Into<String>) -> Self {
+        self.meta.push(key.into());
+        self
+    }
+
+    /// Only match values for which `predicate` returns `true`.
+    pub fn matching<F>(mut self, predicate: F) -> Self
+    where
+        F: Fn(&dyn Reflect) -> bool + Send + Sync + 'static,
+    {
+        self.predicates.push(Box::new(predicate));
+        self
+    }
+
+    /// Find the paths of the values in `root` that match.
+    pub fn find_paths(&self, root: &dyn Reflect) -> Vec<KeyPath> {
+        let mut visitor = QueryVisitor {
+            query: self,
+            descriptor: self.needs_descriptor().then(|| root.type_descriptor()),
+            matches: Vec::new(),
+        };
+        visitor.check(&KeyPath::default(), root);
+        walk(root, &mut visitor);
+        visitor.matches
+    }
+
+    /// Find the values in `root` that match, along with their paths.
+    pub fn find<'a>(&self, root: &'a dyn Reflect) -> Vec<(KeyPath, &'a dyn Reflect)> {
+        self.find_paths(root)
+            .into_iter()
+            .filter_map(|path| {
+                let value = root.at(&path)?;
+                Some((path, value))
+            })
+            .collect()
+    }
+
+    /// Call `f` with each value in `root` that matches, along with its path.
+    ///
+    /// The matches are found before `f` is called, so changes made by `f` don't affect which
+    /// values are matched. Matches that `f` removes, such as elements of a list it shortened,
+    /// are skipped.
+    pub fn for_each_mut<F>(&self, root: &mut dyn Reflect, mut f: F)
+    where
+        F: FnMut(&KeyPath, &mut dyn Reflect),
+    {
+        for path in self.find_paths(root) {
+            let value = if path.is_empty() {
+                Some(&mut *root)
+            } else {
+                root.at_mut(&path)
+            };
+            if let Some(value) = value {
+                f(&path, value);
+            }
+        }
+    }
+
+    fn needs_descriptor(&self) -> bool {
+        !self.meta.is_empty()
+    }
+
+    fn is_match(
+        &self,
+        path: &KeyPath,
+        value: &dyn Reflect,
+        descriptor: Option<&TypeDescriptor>,
+    ) -> bool {
+        if let Some(of_type) = self.of_type {
+            if !of_type(value) {
+                return false;
+            }
+        }
+
+        if let Some(descriptor) = descriptor {
+            let has_meta = |key: &String| descriptor.inherited_meta_at(path, key).is_some();
+            if !self.meta.iter().all(has_meta) {
+                return false;
+            }
+        }
+
+        self.predicates.iter().all(|predicate| predicate(value))
+    }
+}
+
+fn is<T>(value: &dyn Reflect) -> bool
+where
+    T: Reflect,
+{
+    value.downcast_ref::<T>().is_some()
+}
+
+impl fmt::Debug for Query {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Query")
+            .field("of_type", &self.of_type.is_some())
+            .field("meta", &self.meta)
+            .field("predicates", &self.predicates.len())
+            .finish()
+    }
+}
+
+struct QueryVisitor<'a> {
+    query: &'a Query,
+    descriptor: Option<Cow<'static, TypeDescriptor>>,
+    matches: Vec<KeyPath>,
+}
+
+impl QueryVisitor<'_> {
+    fn check(&mut self, path: &KeyPath, value: &dyn Reflect) {
+        if self.query.is_match(path, value, self.descriptor.as_deref()) {
+            self.matches.push(path.clone());
+        }
+    }
+}
+
+impl ReflectVisitor for QueryVisitor<'_> {
+    fn visit_field(&mut self, path: &KeyPath, value: &dyn Reflect) {
+        self.check(path, value);
+    }
+
+    fn visit_element(&mut self, path: &KeyPath, value: &dyn Reflect) {
+        self.check(path, value);
+    }
+}
@@ -0,0 +1,367 @@
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use crate::enum_::VariantField;
+use crate::Enum;
+use crate::Map;
+use crate::Reflect;
+use crate::ReflectRef;
+use crate::ScalarRef;
+use crate::Set;
+use crate::Struct;
+
+/// Compare two reflected values, in a canonical order.
+///
+/// This is a total order that agrees with [`reflect_eq`], except that all `NaN`s are equal to
+/// each other. Values are ordered as follows:
+///
+/// - Values of different kinds are ordered by kind: scalars (ordered by their type, in the order
+///   of [`ScalarRef`]'s variants), structs, tuple structs, tuples, enums, arrays and lists, maps,
+///   and finally sets.
+/// - Numbers, `bool`s, `char`s, and `String`s compare as normal. `-0.0` is equal to `0.0` and
+///   `NaN`s are greater than all other numbers.
+/// - Structs compare their fields ordered by name, comparing the name and then the value of each
+///   field.
+/// - Tuple structs and tuples compare their fields in order.
+/// - Enums compare the variant name and then the fields, as for structs or tuples.
+/// - Arrays and lists compare their elements in order.
+/// - Maps compare their entries ordered by key, and sets their elements ordered by themselves.
+///
+/// Sequences of fields, elements, or entries are compared lexicographically, so a sequence that
+/// is a prefix of another is less than it.
+///
+/// Type names are ignored, so a value compares equal to its [`Value`].
+///
+/// Returns `None` if a `ReflectRef::Opaque` has to be compared. Values of map entries and fields
+/// are only compared when their keys or names are equal, so opaque values elsewhere don't matter.
+///
+/// # Example
+///
+/// ```
+/// use core::cmp::Ordering;
+/// use mirror_mirror::{reflect_cmp, Reflect};
+///
+/// #[derive(Reflect, Clone, Debug)]
+/// struct Foo {
+///     a: i32,
+///     b: Vec<String>,
+/// }
+///
+/// let foo = Foo { a: 1, b: Vec::from(["x".to_owned()]) };
+///
+/// assert_eq!(reflect_cmp(&foo, &foo.to_value()), Some(Ordering::Equal));
+/// assert_eq!(reflect_cmp(&foo, &Foo { a: 2, b: Vec::new() }), Some(Ordering::Less));
+/// ```
+///
+/// [`reflect_eq`]: crate::reflect_eq
+/// [`Value`]: crate::Value
+pub fn reflect_cmp(a: &dyn Reflect, b: &dyn Reflect) -> Option<Ordering> {
+    let ordering = kind(a.reflect_ref())?.cmp(&kind(b.reflect_ref())?);
+    if ordering.is_ne() {
+        return Some(ordering);
+    }
+
+    match (a.reflect_ref(), b.reflect_ref()) {
+        (ReflectRef::Scalar(a), ReflectRef::Scalar(b)) => Some(cmp_scalar(a, b)),
+        (ReflectRef::Struct(a), ReflectRef::Struct(b)) => {
+            cmp_named(struct_fields(a), struct_fields(b))
+        }
+        (ReflectRef::TupleStruct(a), ReflectRef::TupleStruct(b)) => {
+            cmp_elements(a.fields(), b.fields())
+        }
+        (ReflectRef::Tuple(a), ReflectRef::Tuple(b)) => cmp_elements(a.fields(), b.fields()),
+        (ReflectRef::Enum(a), ReflectRef::Enum(b)) => {
+            let ordering = a.variant_name().cmp(b.variant_name());
+            if ordering.is_ne() {
+                return Some(ordering);
+            }
+            cmp_named(variant_fields(a), variant_fields(b))
+        }
+        (ReflectRef::Array(a), ReflectRef::Array(b)) => cmp_elements(a.iter(), b.iter()),
+        (ReflectRef::Array(a), ReflectRef::List(b)) => cmp_elements(a.iter(), b.iter()),
+        (ReflectRef::List(a), ReflectRef::Array(b)) => cmp_elements(a.iter(), b.iter()),
+        (ReflectRef::List(a), ReflectRef::List(b)) => cmp_elements(a.iter(), b.iter()),
+        (ReflectRef::Map(a), ReflectRef::Map(b)) => {
+            let a = sorted_entries(a);
+            let b = sorted_entries(b);
+            for ((key_a, value_a), (key_b, value_b)) in a.iter().zip(&b) {
+                // values are only compared when the keys are equal
+                match reflect_cmp(*key_a, *key_b)? {
+                    Ordering::Equal => {}
+                    ordering => return Some(ordering),
+                }
+                match reflect_cmp(*value_a, *value_b)? {
+                    Ordering::Equal => {}
+                    ordering => return Some(ordering),
+                }
+            }
+            Some(a.len().cmp(&b.len()))
+        }
+        (ReflectRef::Set(a), ReflectRef::Set(b)) => cmp_elements(
+            sorted_elements(a).into_iter(),
+            sorted_elements(b).into_iter(),
+        ),
+        _ => unreachable!("values of the same kind"),
+    }
+}
+
+/// The position of a kind of value in the canonical order, or `None` for opaque values.
+pub(crate) fn kind(value: ReflectRef<'_>) -> Option<u8> {
+    Some(match value {
+        ReflectRef::Scalar(scalar) => match scalar {
+            ScalarRef::usize(_) => 0,
+            ScalarRef::u8(_) => 1,
+            ScalarRef::u16(_) => 2,
+            ScalarRef::u32(_) => 3,
+            ScalarRef::u64(_) => 4,
+            ScalarRef::u128(_) => 5,
+            ScalarRef::i8(_) => 6,
+            ScalarRef::i16(_) => 7,
+            ScalarRef::i32(_) => 8,
+            ScalarRef::i64(_) => 9,
+            ScalarRef::i128(_) => 10,
+            ScalarRef::bool(_) => 11,
+            ScalarRef::char(_) => 12,
+            ScalarRef::f32(_) => 13,
+            ScalarRef::f64(_) => 14,
+            ScalarRef::String(_) => 15,
+        },
+        ReflectRef::Struct(_) => 16,
+        ReflectRef::TupleStruct(_) => 17,
+        ReflectRef::Tuple(_) => 18,
+        ReflectRef::Enum(_) => 19,
+        // arrays are converted to lists by `to_value`
+        ReflectRef::Array(_) | ReflectRef::List(_) => 20,
+        ReflectRef::Map(_) => 21,
+        ReflectRef::Set(_) => 22,
+        ReflectRef::Opaque(_) => return None,
+    })
+}
+
+fn cmp_scalar(a: ScalarRef<'_>, b: ScalarRef<'_>) -> Ordering {
+    match (a, b) {
+        (ScalarRef::usize(a), ScalarRef::usize(b)) => a.cmp(&b),
+        (ScalarRef::u8(a), ScalarRef::u8(b)) => a.cmp(&b),
+        (ScalarRef::u16(a), ScalarRef::u16(b)) => a.cmp(&b),
+        (ScalarRef::u32(a), ScalarRef::u32(b)) => a.cmp(&b),
+        (ScalarRef::u64(a), ScalarRef::u64(b)) => a.cmp(&b),
+        (ScalarRef::u128(a), ScalarRef::u128(b)) => a.cmp(&b),
+        (ScalarRef::i8(a), ScalarRef::i8(b)) => a.cmp(&b),
+        (ScalarRef::i16(a), ScalarRef::i16(b)) => a.cmp(&b),
+        (ScalarRef::i32(a), ScalarRef::i32(b)) => a.cmp(&b),
+        (ScalarRef::i64(a), ScalarRef::i64(b)) => a.cmp(&b),
+        (ScalarRef::i128(a), ScalarRef::i128(b)) => a.cmp(&b),
+        (ScalarRef::bool(a), ScalarRef::bool(b)) => a.cmp(&b),
+        (ScalarRef::char(a), ScalarRef::char(b)) => a.cmp(&b),
+        (ScalarRef::f32(a), ScalarRef::f32(b)) => cmp_float(a.into(), b.into()),
+        (ScalarRef::f64(a), ScalarRef::f64(b)) => cmp_float(a, b),
+        (ScalarRef::String(a), ScalarRef::String(b)) => a.cmp(b),
+        _ => unreachable!("scalars of the same kind"),
+    }
+}
+
+fn cmp_float(a: f64, b: f64) -> Ordering {
+    match (a.is_nan(), b.is_nan()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        // neither is NaN, and `-0.0 == 0.0`
+        (false, false) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+    }
+}
+
+fn cmp_elements<'a>(
+    mut a: impl Iterator<Item = &'a dyn Reflect>,
+    mut b: impl Iterator<Item = &'a dyn Reflect>,
+) -> Option<Ordering> {
+    loop {
+        match (a.next(), b.next()) {
+            (Some(a), Some(b)) => {
+                let ordering = reflect_cmp(a, b)?;
+                if ordering.is_ne() {
+                    return Some(ordering);
+                }
+            }
+            (Some(_), None) => return Some(Ordering::Greater),
+            (None, Some(_)) => return Some(Ordering::Less),
+            (None, None) => return Some(Ordering::Equal),
+        }
+    }
+}
+
+fn cmp_named(a: NamedFields<'_>, b: NamedFields<'_>) -> Option<Ordering> {
+    match (a, b) {
+        (NamedFields::Named(a), NamedFields::Named(b)) => {
+            for ((name_a, value_a), (name_b, value_b)) in a.iter().zip(&b) {
+                // values are only compared when the names are equal
+                match name_a.cmp(name_b) {
+                    Ordering::Equal => {}
+                    ordering => return Some(ordering),
+                }
+                match reflect_cmp(*value_a, *value_b)? {
+                    Ordering::Equal => {}
+                    ordering => return Some(ordering),
+                }
+            }
+            Some(a.len().cmp(&b.len()))
+        }
+        (NamedFields::Unnamed(a), NamedFields::Unnamed(b)) => {
+            cmp_elements(a.into_iter(), b.into_iter())
+        }
+        (NamedFields::Named(_), NamedFields::Unnamed(_)) => Some(Ordering::Less),
+        (NamedFields::Unnamed(_), NamedFields::Named(_)) => Some(Ordering::Greater),
+    }
+}
+
+/// The fields of a struct or a variant.
+pub(crate) enum NamedFields<'a> {
+    /// Struct fields or struct variant fields, ordered by name.
+    Named(Vec<(&'a str, &'a dyn Reflect)>),
+    /// Tuple variant fields, in order.
+    Unnamed(Vec<&'a dyn Reflect>),
+}
+
+pub(crate) fn struct_fields(struct_: &dyn Struct) -> NamedFields<'_> {
+    let mut fields = struct_.fields().collect::<Vec<_>>();
+    fields.sort_by_key(|(name, _)| *name);
+    NamedFields::Named(fields)
+}
+
+pub(crate) fn variant_fields(enum_: &dyn Enum) -> NamedFields<'_> {
+    let mut named = Vec::new();
+    let mut unnamed = Vec::new();
+    for field in enum_.fields() {
+        match field {
+            VariantField::Struct(name, value) => named.push((name, value)),
+            VariantField::Tuple(value) => unnamed.push(value),
+        }
+    }
+    if unnamed.is_empty() {
+        named.sort_by_key(|(name, _)| *name);
+        NamedFields::Named(named)
+    } else {
+        NamedFields::Unnamed(unnamed)
+    }
+}
+
+/// The entries of a map ordered by key.
+///
+/// Opaque keys are left in place, callers are expected to fail when they get to them.
+pub(crate) fn sorted_entries(map: &dyn Map) -> Vec<(&dyn Reflect, &dyn Reflect)> {
+    let mut entries = map.iter().collect::<Vec<_>>();
+    entries.sort_by(|(a, _), (b, _)| reflect_cmp(*a, *b).unwrap_or(Ordering::Equal));
+    entries
+}
+
+/// The elements of a set in order.
+///
+/// Opaque elements are left in place, callers are expected to fail when they get to them.
+pub(crate) fn sorted_elements(set: &dyn Set) -> Vec<&dyn Reflect> {
+    let mut elements = set.iter().collect::<Vec<_>>();
+    elements.sort_by(|a, b| reflect_cmp(*a, *b).unwrap_or(Ordering::Equal));
+    elements
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::collections::BTreeMap;
+    use alloc::collections::BTreeSet;
+    use alloc::string::String;
+    use alloc::vec;
+    use core::cmp::Ordering;
+
+    use super::*;
+    use crate::enum_::EnumValue;
+    use crate::struct_::StructValue;
+    use crate::Opaque;
+
+    #[test]
+    fn scalars() {
+        assert_eq!(reflect_cmp(&1_i32, &2_i32), Some(Ordering::Less));
+        assert_eq!(reflect_cmp(&2_i32, &2_i32), Some(Ordering::Equal));
+        assert_eq!(reflect_cmp(&3_u8, &1_i32), Some(Ordering::Less));
+        assert_eq!(
+            reflect_cmp(&String::from("b"), &String::from("a")),
+            Some(Ordering::Greater)
+        );
+
+        assert_eq!(reflect_cmp(&-0.0_f32, &0.0_f32), Some(Ordering::Equal));
+        assert_eq!(reflect_cmp(&f32::NAN, &f32::NAN), Some(Ordering::Equal));
+        assert_eq!(
+            reflect_cmp(&f64::NAN, &f64::INFINITY),
+            Some(Ordering::Greater)
+        );
+    }
+
+    #[test]
+    fn structs() {
+        #[derive(Reflect, Debug, Clone)]
+        #[reflect(crate_name(crate))]
+        struct A {
+            foo: i32,
+            bar: bool,
+        }
+
+        assert_eq!(
+            reflect_cmp(
+                &A { foo: 1, bar: true },
+                &StructValue::new()
+                    .with_field("bar", true)
+                    .with_field("foo", 1),
+            ),
+            Some(Ordering::Equal)
+        );
+        // `bar` is compared first
+        assert_eq!(
+            reflect_cmp(&A { foo: 2, bar: false }, &A { foo: 1, bar: true }),
+            Some(Ordering::Less)
+        );
+    }
+
+    #[test]
+    fn enums() {
+        #[derive(Reflect, Debug, Clone)]
+        #[reflect(crate_name(crate))]
+        enum B {
+            X(i32),
+            Y { a: i32 },
+        }
+
+        assert_eq!(reflect_cmp(&B::X(10), &B::Y { a: 1 }), Some(Ordering::Less));
+        assert_eq!(reflect_cmp(&B::X(1), &B::X(2)), Some(Ordering::Less));
+        assert_eq!(
+            reflect_cmp(
+                &B::Y { a: 1 },
+                &EnumValue::new_struct_variant("Y")
+                    .with_struct_field("a", 1)
+                    .finish(),
+            ),
+            Some(Ordering::Equal)
+        );
+    }
+
+    #[test]
+    fn collections() {
+        assert_eq!(reflect_cmp(&[1, 2], &vec![1, 2]), Some(Ordering::Equal));
+        assert_eq!(
+            reflect_cmp(&vec![1, 2], &vec![1, 2, 0]),
+            Some(Ordering::Less)
+        );
+        assert_eq!(
+            reflect_cmp(&vec![1, 3], &vec![1, 2, 0]),
+            Some(Ordering::Greater)
+        );
+
+        #[cfg(feature = "std")]
+        {
+            let hash_set = (0..100).collect::<std::collections::HashSet<_>>();
+            let btree_set = hash_set.iter().copied().collect::<BTreeSet<_>>();
+            assert_eq!(reflect_cmp(&hash_set, &btree_set), Some(Ordering::Equal));
+        }
+
+        let map = BTreeMap::from([(1, String::from("a")), (2, String::from("b"))]);
+        assert_eq!(reflect_cmp(&map, &map.to_value()), Some(Ordering::Equal));
+
+        assert_eq!(reflect_cmp(&vec![Opaque(1)], &vec![Opaque(1)]), None);
+    }
+}
@@ -1,21 +1,64 @@
 use crate::{
     enum_::{VariantField, VariantKind},
-    Array, Enum, List, Map, Reflect, ReflectRef, Struct, Tuple, TupleStruct,
+    Enum, Map, Reflect, ReflectRef, ScalarRef, Set, Struct, Tuple, TupleStruct,
 };
 
 /// Compare two reflected values for equality.
 ///
 /// Returns `None` if either value contains a `ReflectRef::Opaque`.
 pub fn reflect_eq(a: &dyn Reflect, b: &dyn Reflect) -> Option<bool> {
+    eq(a, b, false)
+}
+
+/// Compare two reflected values for equality, allowing numbers of different types.
+///
+/// Like [`reflect_eq`] except integers of different types are equal if they have the same
+/// value, and so are `f32`s and `f64`s, as long as the `f32` converts to the `f64` exactly. This
+/// is useful when comparing values that have gone through formats that don't preserve the exact
+/// number types, or against hand written [`Value`]s.
+///
+/// Returns `None` if either value contains a `ReflectRef::Opaque`.
+///
+/// # Example
+///
+/// ```
+/// use mirror_mirror::{reflect_eq, reflect_eq_widening};
+///
+/// assert_eq!(reflect_eq(&1_u8, &1_i64), Some(false));
+/// assert_eq!(reflect_eq_widening(&1_u8, &1_i64), Some(true));
+/// assert_eq!(reflect_eq_widening(&[1_u8, 2], &Vec::from([1_u32, 2])), Some(true));
+/// ```
+///
+/// [`Value`]: crate::Value
+pub fn reflect_eq_widening(a: &dyn Reflect, b: &dyn Reflect) -> Option<bool> {
+    eq(a, b, true)
+}
+
+fn eq(a: &dyn Reflect, b: &dyn Reflect, widen: bool) -> Option<bool> {
     match (a.reflect_ref(), b.reflect_ref()) {
+        (ReflectRef::Scalar(a), ReflectRef::Scalar(b)) if widen => Some(scalar_eq_widening(a, b)),
         (ReflectRef::Scalar(a), ReflectRef::Scalar(b)) => Some(a == b),
-        (ReflectRef::Struct(a), ReflectRef::Struct(b)) => reflect_eq_struct(a, b),
-        (ReflectRef::TupleStruct(a), ReflectRef::TupleStruct(b)) => reflect_eq_tuple_struct(a, b),
-        (ReflectRef::Tuple(a), ReflectRef::Tuple(b)) => reflect_eq_tuple(a, b),
-        (ReflectRef::Enum(a), ReflectRef::Enum(b)) => reflect_eq_enum(a, b),
-        (ReflectRef::Array(a), ReflectRef::Array(b)) => reflect_eq_array(a, b),
-        (ReflectRef::List(a), ReflectRef::List(b)) => reflect_eq_list(a, b),
-        (ReflectRef::Map(a), ReflectRef::Map(b)) => reflect_eq_map(a, b),
+        (ReflectRef::Struct(a), ReflectRef::Struct(b)) => reflect_eq_struct(a, b, widen),
+        (ReflectRef::TupleStruct(a), ReflectRef::TupleStruct(b)) => {
+            reflect_eq_tuple_struct(a, b, widen)
+        }
+        (ReflectRef::Tuple(a), ReflectRef::Tuple(b)) => reflect_eq_tuple(a, b, widen),
+        (ReflectRef::Enum(a), ReflectRef::Enum(b)) => reflect_eq_enum(a, b, widen),
+        (ReflectRef::Array(a), ReflectRef::Array(b)) => {
+            reflect_eq_elements(a.iter(), b.iter(), widen)
+        }
+        (ReflectRef::List(a), ReflectRef::List(b)) => {
+            reflect_eq_elements(a.iter(), b.iter(), widen)
+        }
+        // arrays are converted to lists by `to_value`
+        (ReflectRef::Array(a), ReflectRef::List(b)) => {
+            reflect_eq_elements(a.iter(), b.iter(), widen)
+        }
+        (ReflectRef::List(a), ReflectRef::Array(b)) => {
+            reflect_eq_elements(a.iter(), b.iter(), widen)
+        }
+        (ReflectRef::Map(a), ReflectRef::Map(b)) => reflect_eq_map(a, b, widen),
+        (ReflectRef::Set(a), ReflectRef::Set(b)) => reflect_eq_set(a, b),
         (ReflectRef::Opaque(_), _) | (_, ReflectRef::Opaque(_)) => None,
 
         (
@@ -25,6 +68,7 @@ pub fn reflect_eq(a: &dyn Reflect, b: &dyn Reflect) -> Option<bool> {
             | ReflectRef::Array(_)
             | ReflectRef::List(_)
             | ReflectRef::Map(_)
+            | ReflectRef::Set(_)
             | ReflectRef::Scalar(_),
             ReflectRef::TupleStruct(_),
         )
@@ -35,6 +79,7 @@ pub fn reflect_eq(a: &dyn Reflect, b: &dyn Reflect) -> Option<bool> {
             | ReflectRef::Array(_)
             | ReflectRef::List(_)
             | ReflectRef::Map(_)
+            | ReflectRef::Set(_)
             | ReflectRef::Scalar(_),
             ReflectRef::Tuple(_),
         )
@@ -45,6 +90,7 @@ pub fn reflect_eq(a: &dyn Reflect, b: &dyn Reflect) -> Option<bool> {
             | ReflectRef::Array(_)
             | ReflectRef::List(_)
             | ReflectRef::Map(_)
+            | ReflectRef::Set(_)
             | ReflectRef::Scalar(_),
             ReflectRef::Enum(_),
         )
@@ -53,10 +99,10 @@ pub fn reflect_eq(a: &dyn Reflect, b: &dyn Reflect) -> Option<bool> {
             | ReflectRef::TupleStruct(_)
             | ReflectRef::Tuple(_)
             | ReflectRef::Enum(_)
-            | ReflectRef::List(_)
             | ReflectRef::Map(_)
+            | ReflectRef::Set(_)
             | ReflectRef::Scalar(_),
-            ReflectRef::Array(_),
+            ReflectRef::Array(_) | ReflectRef::List(_),
         )
         | (
             ReflectRef::Struct(_)
@@ -64,9 +110,10 @@ pub fn reflect_eq(a: &dyn Reflect, b: &dyn Reflect) -> Option<bool> {
             | ReflectRef::Tuple(_)
             | ReflectRef::Enum(_)
             | ReflectRef::Array(_)
-            | ReflectRef::Map(_)
+            | ReflectRef::List(_)
+            | ReflectRef::Set(_)
             | ReflectRef::Scalar(_),
-            ReflectRef::List(_),
+            ReflectRef::Map(_),
         )
         | (
             ReflectRef::Struct(_)
@@ -75,8 +122,9 @@ pub fn reflect_eq(a: &dyn Reflect, b: &dyn Reflect) -> Option<bool> {
             | ReflectRef::Enum(_)
             | ReflectRef::Array(_)
             | ReflectRef::List(_)
+            | ReflectRef::Map(_)
             | ReflectRef::Scalar(_),
-            ReflectRef::Map(_),
+            ReflectRef::Set(_),
         )
         | (
             ReflectRef::Struct(_)
@@ -85,7 +133,8 @@ pub fn reflect_eq(a: &dyn Reflect, b: &dyn Reflect) -> Option<bool> {
             | ReflectRef::Enum(_)
             | ReflectRef::Array(_)
             | ReflectRef::List(_)
-            | ReflectRef::Map(_),
+            | ReflectRef::Map(_)
+            | ReflectRef::Set(_),
             ReflectRef::Scalar(_),
         )
         | (
@@ -95,20 +144,21 @@ pub fn reflect_eq(a: &dyn Reflect, b: &dyn Reflect) -> Option<bool> {
             | ReflectRef::Array(_)
             | ReflectRef::List(_)
             | ReflectRef::Map(_)
+            | ReflectRef::Set(_)
             | ReflectRef::Scalar(_),
             ReflectRef::Struct(_),
         ) => Some(false),
     }
 }
 
-fn reflect_eq_struct(a: &dyn Struct, b: &dyn Struct) -> Option<bool> {
+fn reflect_eq_struct(a: &dyn Struct, b: &dyn Struct, widen: bool) -> Option<bool> {
     Some(
         a.fields_len() == b.fields_len() && {
             for (name, value_a) in a.fields() {
                 let Some(value_b) = b.field(name) else {
                     return Some(false);
                 };
-                match reflect_eq(value_a, value_b) {
+                match eq(value_a, value_b, widen) {
                     Some(true) => {}
                     Some(false) => {
                         return Some(false);
@@ -121,11 +171,11 @@ fn reflect_eq_struct(a: &dyn Struct, b: &dyn Struct) -> Option<bool> {
     )
 }
 
-fn reflect_eq_tuple_struct(a: &dyn TupleStruct, b: &dyn TupleStruct) -> Option<bool> {
+fn reflect_eq_tuple_struct(a: &dyn TupleStruct, b: &dyn TupleStruct, widen: bool) -> Option<bool> {
     Some(
         a.fields_len() == b.fields_len() && {
             for (value_a, value_b) in a.fields().zip(b.fields()) {
-                match reflect_eq(value_a, value_b) {
+                match eq(value_a, value_b, widen) {
                     Some(true) => {}
                     Some(false) => {
                         return Some(false);
@@ -138,11 +188,11 @@ fn reflect_eq_tuple_struct(a: &dyn TupleStruct, b: &dyn TupleStruct) -> Option<b
     )
 }
 
-fn reflect_eq_tuple(a: &dyn Tuple, b: &dyn Tuple) -> Option<bool> {
+fn reflect_eq_tuple(a: &dyn Tuple, b: &dyn Tuple, widen: bool) -> Option<bool> {
     Some(
         a.fields_len() == b.fields_len() && {
             for (value_a, value_b) in a.fields().zip(b.fields()) {
-                match reflect_eq(value_a, value_b) {
+                match eq(value_a, value_b, widen) {
                     Some(true) => {}
                     Some(false) => {
                         return Some(false);
@@ -155,7 +205,7 @@ fn reflect_eq_tuple(a: &dyn Tuple, b: &dyn Tuple) -> Option<bool> {
     )
 }
 
-fn reflect_eq_enum(a: &dyn Enum, b: &dyn Enum) -> Option<bool> {
+fn reflect_eq_enum(a: &dyn Enum, b: &dyn Enum, widen: bool) -> Option<bool> {
     Some(
         a.variant_name() == b.variant_name() && a.fields_len() == b.fields_len() && {
             match (a.variant_kind(), b.variant_kind()) {
@@ -166,7 +216,7 @@ fn reflect_eq_enum(a: &dyn Enum, b: &dyn Enum) -> Option<bool> {
                                 let Some(value_b) = b.field(name) else {
                                     return Some(false);
                                 };
-                                match reflect_eq(value_a, value_b) {
+                                match eq(value_a, value_b, widen) {
                                     Some(true) => {}
                                     Some(false) => {
                                         return Some(false);
@@ -184,7 +234,7 @@ fn reflect_eq_enum(a: &dyn Enum, b: &dyn Enum) -> Option<bool> {
                     for (field_a, field_b) in a.fields().zip(b.fields()) {
                         match (field_a, field_b) {
                             (VariantField::Tuple(value_a), VariantField::Tuple(value_b)) => {
-                                match reflect_eq(value_a, value_b) {
+                                match eq(value_a, value_b, widen) {
                                     Some(true) => {}
                                     Some(false) => {
                                         return Some(false);
@@ -218,11 +268,15 @@ fn reflect_eq_enum(a: &dyn Enum, b: &dyn Enum) -> Option<bool> {
     )
 }
 
-fn reflect_eq_array(a: &dyn Array, b: &dyn Array) -> Option<bool> {
+fn reflect_eq_elements<'a>(
+    a: impl ExactSizeIterator<Item = &'a dyn Reflect>,
+    b: impl ExactSizeIterator<Item = &'a dyn Reflect>,
+    widen: bool,
+) -> Option<bool> {
     Some(
         a.len() == b.len() && {
-            for (value_a, value_b) in a.iter().zip(b.iter()) {
-                match reflect_eq(value_a, value_b) {
+            for (value_a, value_b) in a.zip(b) {
+                match eq(value_a, value_b, widen) {
                     Some(true) => {}
                     Some(false) => {
                         return Some(false);
@@ -235,31 +289,14 @@ fn reflect_eq_array(a: &dyn Array, b: &dyn Array) -> Option<bool> {
     )
 }
 
-fn reflect_eq_list(a: &dyn List, b: &dyn List) -> Option<bool> {
-    Some(
-        a.len() == b.len() && {
-            for (value_a, value_b) in a.iter().zip(b.iter()) {
-                match reflect_eq(value_a, value_b) {
-                    Some(true) => {}
-                    Some(false) => {
-                        return Some(false);
-                    }
-                    None => return None,
-                }
-            }
-            true
-        },
-    )
-}
-
-fn reflect_eq_map(a: &dyn Map, b: &dyn Map) -> Option<bool> {
+fn reflect_eq_map(a: &dyn Map, b: &dyn Map, widen: bool) -> Option<bool> {
     Some(
         a.len() == b.len() && {
             for (key, value_a) in a.iter() {
                 let Some(value_b) = b.get(key) else {
                     return Some(false);
                 };
-                match reflect_eq(value_a, value_b) {
+                match eq(value_a, value_b, widen) {
                     Some(true) => {}
                     Some(false) => {
                         return Some(false);
@@ -272,9 +309,52 @@ fn reflect_eq_map(a: &dyn Map, b: &dyn Map) -> Option<bool> {
     )
 }
 
+fn reflect_eq_set(a: &dyn Set, b: &dyn Set) -> Option<bool> {
+    Some(a.len() == b.len() && a.iter().all(|value| b.contains(value)))
+}
+
+fn scalar_eq_widening(a: ScalarRef<'_>, b: ScalarRef<'_>) -> bool {
+    enum Number {
+        Signed(i128),
+        Unsigned(u128),
+        Float(f64),
+    }
+
+    fn number(scalar: ScalarRef<'_>) -> Option<Number> {
+        Some(match scalar {
+            ScalarRef::usize(n) => Number::Unsigned(n as u128),
+            ScalarRef::u8(n) => Number::Unsigned(n.into()),
+            ScalarRef::u16(n) => Number::Unsigned(n.into()),
+            ScalarRef::u32(n) => Number::Unsigned(n.into()),
+            ScalarRef::u64(n) => Number::Unsigned(n.into()),
+            ScalarRef::u128(n) => Number::Unsigned(n),
+            ScalarRef::i8(n) => Number::Signed(n.into()),
+            ScalarRef::i16(n) => Number::Signed(n.into()),
+            ScalarRef::i32(n) => Number::Signed(n.into()),
+            ScalarRef::i64(n) => Number::Signed(n.into()),
+            ScalarRef::i128(n) => Number::Signed(n),
+            ScalarRef::f32(n) => Number::Float(n.into()),
+            ScalarRef::f64(n) => Number::Float(n),
+            ScalarRef::bool(_) | ScalarRef::char(_) | ScalarRef::String(_) => return None,
+        })
+    }
+
+    match (number(a), number(b)) {
+        (Some(Number::Signed(a)), Some(Number::Signed(b))) => a == b,
+        (Some(Number::Unsigned(a)), Some(Number::Unsigned(b))) => a == b,
+        (Some(Number::Signed(a)), Some(Number::Unsigned(b)))
+        | (Some(Number::Unsigned(b)), Some(Number::Signed(a))) => u128::try_from(a) == Ok(b),
+        (Some(Number::Float(a)), Some(Number::Float(b))) => a == b,
+        // integers and floats are never equal
+        (Some(_), Some(_)) => false,
+        _ => a == b,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use alloc::collections::BTreeMap;
+    use alloc::collections::BTreeSet;
 
     use crate::{
         enum_::EnumValue, struct_::StructValue, tuple::TupleValue, tuple_struct::TupleStructValue,
@@ -514,6 +594,10 @@ mod tests {
         assert!(!reflect_eq(&[1, 2, 3], &[1, 2, 3, 4]).unwrap());
         assert!(!reflect_eq(&[1, 2, 3, 4], &[1, 2, 3]).unwrap());
         assert!(!reflect_eq(&[1, 2, 3], &[1, 2, 4]).unwrap());
+
+        assert!(reflect_eq(&[1, 2, 3], &[1, 2, 3].to_value()).unwrap());
+        assert!(reflect_eq(&vec![1, 2, 3], &[1, 2, 3]).unwrap());
+        assert!(!reflect_eq(&[1, 2, 3], &vec![1, 2]).unwrap());
     }
 
     #[test]
@@ -547,4 +631,34 @@ mod tests {
         )
         .unwrap());
     }
+
+    #[test]
+    fn reflect_eq_set() {
+        assert!(reflect_eq(&BTreeSet::from([1, 2]), &BTreeSet::from([2, 1])).unwrap());
+        assert!(!reflect_eq(&BTreeSet::from([1, 2]), &BTreeSet::from([1, 3])).unwrap());
+        assert!(!reflect_eq(&BTreeSet::from([1, 2]), &BTreeSet::from([1])).unwrap());
+    }
+
+    #[test]
+    fn reflect_eq_widening() {
+        assert!(!reflect_eq(&1_u8, &1_u64).unwrap());
+        assert!(super::reflect_eq_widening(&1_u8, &1_u64).unwrap());
+        assert!(super::reflect_eq_widening(&1_i8, &1_u128).unwrap());
+        assert!(super::reflect_eq_widening(&u128::MAX, &u128::MAX).unwrap());
+        assert!(!super::reflect_eq_widening(&-1_i8, &u128::MAX).unwrap());
+        assert!(!super::reflect_eq_widening(&1_u8, &2_u64).unwrap());
+
+        assert!(super::reflect_eq_widening(&0.5_f32, &0.5_f64).unwrap());
+        assert!(!super::reflect_eq_widening(&0.1_f32, &0.1_f64).unwrap());
+        assert!(!super::reflect_eq_widening(&1_i32, &1.0_f64).unwrap());
+        assert!(!super::reflect_eq_widening(&1_i32, &true).unwrap());
+        assert!(super::reflect_eq_widening(&'a', &'a').unwrap());
+
+        assert!(super::reflect_eq_widening(
+            &BTreeMap::from([("a".to_owned(), 1_u8)]),
+            &BTreeMap::from([("a".to_owned(), 1_i32)]),
+        )
+        .unwrap());
+        assert!(super::reflect_eq_widening(&(1_u16, [2_u8]), &(1_u32, vec![2_i64])).unwrap());
+    }
 }
@@ -0,0 +1,335 @@
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use core::fmt;
+use core::hash::Hasher;
+
+use crate::reflect_cmp::kind;
+use crate::reflect_cmp::sorted_elements;
+use crate::reflect_cmp::sorted_entries;
+use crate::reflect_cmp::struct_fields;
+use crate::reflect_cmp::variant_fields;
+use crate::reflect_cmp::NamedFields;
+use crate::Reflect;
+use crate::ReflectRef;
+use crate::ScalarRef;
+
+/// Feed a reflected value into a [`Hasher`], in a canonical order.
+///
+/// Values that are equal according to [`reflect_eq`] produce the same hash, so a value hashes the
+/// same as its [`Value`], and maps and sets hash the same regardless of their iteration order.
+/// Values are traversed in the same order [`reflect_cmp`] compares them in.
+///
+/// Everything is written to the hasher as little endian bytes, with lengths written as `u64`s,
+/// so with a deterministic hasher the hash is the same on all platforms. This makes it suitable
+/// for content hashes of assets.
+///
+/// Returns an error if the value contains a `ReflectRef::Opaque` or a `NaN`. Use
+/// [`reflect_hash_with`] to hash `NaN`s instead.
+///
+/// # Example
+///
+/// ```
+/// use std::collections::hash_map::DefaultHasher;
+/// use std::hash::Hasher;
+/// use mirror_mirror::{reflect_hash, Reflect};
+///
+/// #[derive(Reflect, Clone, Debug)]
+/// struct Foo {
+///     a: i32,
+///     b: Vec<String>,
+/// }
+///
+/// let foo = Foo { a: 1, b: Vec::from(["x".to_owned()]) };
+///
+/// let hash = |value: &dyn Reflect| {
+///     let mut hasher = DefaultHasher::new();
+///     reflect_hash(value, &mut hasher).unwrap();
+///     hasher.finish()
+/// };
+///
+/// assert_eq!(hash(&foo), hash(&foo.to_value()));
+/// ```
+///
+/// [`reflect_eq`]: crate::reflect_eq
+/// [`reflect_cmp`]: crate::reflect_cmp
+/// [`Value`]: crate::Value
+pub fn reflect_hash(value: &dyn Reflect, state: &mut dyn Hasher) -> Result<(), ReflectHashError> {
+    reflect_hash_with(value, state, NanHandling::Error)
+}
+
+/// Like [`reflect_hash`] but with control over how `NaN`s are hashed.
+pub fn reflect_hash_with(
+    value: &dyn Reflect,
+    state: &mut dyn Hasher,
+    nan: NanHandling,
+) -> Result<(), ReflectHashError> {
+    let Some(kind) = kind(value.reflect_ref()) else {
+        return Err(ReflectHashError::Opaque(value.type_name().to_owned()));
+    };
+    state.write_u8(kind);
+
+    match value.reflect_ref() {
+        ReflectRef::Scalar(scalar) => hash_scalar(scalar, state, nan)?,
+        ReflectRef::Struct(struct_) => hash_named(struct_fields(struct_), state, nan)?,
+        ReflectRef::TupleStruct(tuple_struct) => {
+            write_len(state, tuple_struct.fields_len());
+            for field in tuple_struct.fields() {
+                reflect_hash_with(field, state, nan)?;
+            }
+        }
+        ReflectRef::Tuple(tuple) => {
+            write_len(state, tuple.fields_len());
+            for field in tuple.fields() {
+                reflect_hash_with(field, state, nan)?;
+            }
+        }
+        ReflectRef::Enum(enum_) => {
+            write_str(state, enum_.variant_name());
+            hash_named(variant_fields(enum_), state, nan)?;
+        }
+        ReflectRef::Array(array) => {
+            write_len(state, array.len());
+            for element in array.iter() {
+                reflect_hash_with(element, state, nan)?;
+            }
+        }
+        ReflectRef::List(list) => {
+            write_len(state, list.len());
+            for element in list.iter() {
+                reflect_hash_with(element, state, nan)?;
+            }
+        }
+        ReflectRef::Map(map) => {
+            let entries = sorted_entries(map);
+            write_len(state, entries.len());
+            for (key, value) in entries {
+                reflect_hash_with(key, state, nan)?;
+                reflect_hash_with(value, state, nan)?;
+            }
+        }
+        ReflectRef::Set(set) => {
+            let elements = sorted_elements(set);
+            write_len(state, elements.len());
+            for element in elements {
+                reflect_hash_with(element, state, nan)?;
+            }
+        }
+        ReflectRef::Opaque(_) => unreachable!("opaque values don't have a kind"),
+    }
+
+    Ok(())
+}
+
+/// How [`reflect_hash_with`] handles `NaN`s.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NanHandling {
+    /// Return [`ReflectHashError::NaN`].
+    #[default]
+    Error,
+    /// Hash all `NaN`s the same, regardless of their sign and payload.
+    Canonicalize,
+}
+
+/// Error returned by [`reflect_hash`] and [`reflect_hash_with`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ReflectHashError {
+    /// The value contains an opaque value, which can't be inspected. Holds the type name of the
+    /// opaque value.
+    Opaque(String),
+    /// The value contains a `NaN` and [`NanHandling::Error`] was used.
+    NaN,
+}
+
+impl fmt::Display for ReflectHashError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Opaque(type_name) => write!(f, "Can't hash opaque value of type `{type_name}`"),
+            Self::NaN => write!(f, "Can't hash `NaN`"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ReflectHashError {}
+
+fn hash_named(
+    fields: NamedFields<'_>,
+    state: &mut dyn Hasher,
+    nan: NanHandling,
+) -> Result<(), ReflectHashError> {
+    match fields {
+        NamedFields::Named(fields) => {
+            state.write_u8(0);
+            write_len(state, fields.len());
+            for (name, value) in fields {
+                write_str(state, name);
+                reflect_hash_with(value, state, nan)?;
+            }
+        }
+        NamedFields::Unnamed(fields) => {
+            state.write_u8(1);
+            write_len(state, fields.len());
+            for value in fields {
+                reflect_hash_with(value, state, nan)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn hash_scalar(
+    scalar: ScalarRef<'_>,
+    state: &mut dyn Hasher,
+    nan: NanHandling,
+) -> Result<(), ReflectHashError> {
+    match scalar {
+        ScalarRef::usize(n) => state.write(&(n as u64).to_le_bytes()),
+        ScalarRef::u8(n) => state.write(&n.to_le_bytes()),
+        ScalarRef::u16(n) => state.write(&n.to_le_bytes()),
+        ScalarRef::u32(n) => state.write(&n.to_le_bytes()),
+        ScalarRef::u64(n) => state.write(&n.to_le_bytes()),
+        ScalarRef::u128(n) => state.write(&n.to_le_bytes()),
+        ScalarRef::i8(n) => state.write(&n.to_le_bytes()),
+        ScalarRef::i16(n) => state.write(&n.to_le_bytes()),
+        ScalarRef::i32(n) => state.write(&n.to_le_bytes()),
+        ScalarRef::i64(n) => state.write(&n.to_le_bytes()),
+        ScalarRef::i128(n) => state.write(&n.to_le_bytes()),
+        ScalarRef::bool(b) => state.write_u8(b.into()),
+        ScalarRef::char(c) => state.write(&u32::from(c).to_le_bytes()),
+        ScalarRef::f32(n) => {
+            let bits = if n.is_nan() {
+                check_nan(nan)?;
+                f32::NAN.to_bits()
+            } else if n == 0.0 {
+                // `-0.0 == 0.0`
+                0
+            } else {
+                n.to_bits()
+            };
+            state.write(&bits.to_le_bytes());
+        }
+        ScalarRef::f64(n) => {
+            let bits = if n.is_nan() {
+                check_nan(nan)?;
+                f64::NAN.to_bits()
+            } else if n == 0.0 {
+                0
+            } else {
+                n.to_bits()
+            };
+            state.write(&bits.to_le_bytes());
+        }
+        ScalarRef::String(s) => write_str(state, s),
+    }
+    Ok(())
+}
+
+fn check_nan(nan: NanHandling) -> Result<(), ReflectHashError> {
+    match nan {
+        NanHandling::Error => Err(ReflectHashError::NaN),
+        NanHandling::Canonicalize => Ok(()),
+    }
+}
+
+fn write_len(state: &mut dyn Hasher, len: usize) {
+    state.write(&(len as u64).to_le_bytes());
+}
+
+fn write_str(state: &mut dyn Hasher, s: &str) {
+    write_len(state, s.len());
+    state.write(s.as_bytes());
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::collections::BTreeMap;
+    use alloc::collections::BTreeSet;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use super::*;
+    use crate::struct_::StructValue;
+    use crate::Opaque;
+
+    /// Records everything written so hashes can be compared exactly.
+    #[derive(Default)]
+    struct Bytes(Vec<u8>);
+
+    impl Hasher for Bytes {
+        fn finish(&self) -> u64 {
+            0
+        }
+
+        fn write(&mut self, bytes: &[u8]) {
+            self.0.extend_from_slice(bytes);
+        }
+    }
+
+    fn hash(value: &dyn Reflect) -> Result<Vec<u8>, ReflectHashError> {
+        let mut bytes = Bytes::default();
+        reflect_hash(value, &mut bytes)?;
+        Ok(bytes.0)
+    }
+
+    #[test]
+    fn equal_values_hash_the_same() {
+        #[derive(Reflect, Debug, Clone)]
+        #[reflect(crate_name(crate))]
+        struct A {
+            foo: i32,
+            bar: Vec<f32>,
+        }
+
+        let a = A {
+            foo: 1,
+            bar: vec![-0.0, 1.5],
+        };
+        assert_eq!(hash(&a).unwrap(), hash(&a.to_value()).unwrap());
+        assert_eq!(
+            hash(&a).unwrap(),
+            hash(
+                &StructValue::new()
+                    .with_field("bar", vec![0.0_f32, 1.5])
+                    .with_field("foo", 1)
+            )
+            .unwrap()
+        );
+        assert_ne!(hash(&a).unwrap(), hash(&A { foo: 2, ..a }).unwrap());
+
+        assert_eq!(hash(&[1, 2]).unwrap(), hash(&vec![1, 2]).unwrap());
+        assert_ne!(hash(&1_u8).unwrap(), hash(&1_u16).unwrap());
+        assert_ne!(
+            hash(&(vec![1], vec![2, 3])).unwrap(),
+            hash(&(vec![1, 2], vec![3])).unwrap()
+        );
+
+        #[cfg(feature = "std")]
+        {
+            let hash_set = (0..100).collect::<std::collections::HashSet<_>>();
+            assert_eq!(
+                hash(&hash_set).unwrap(),
+                hash(&hash_set.iter().copied().collect::<BTreeSet<_>>()).unwrap()
+            );
+        }
+
+        let map = BTreeMap::from([(1, 2), (3, 4)]);
+        assert_eq!(hash(&map).unwrap(), hash(&map.to_value()).unwrap());
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(hash(&vec![1.0, f64::NAN]), Err(ReflectHashError::NaN));
+        assert_eq!(
+            hash(&Opaque(1)),
+            Err(ReflectHashError::Opaque(Opaque(1).type_name().to_owned()))
+        );
+
+        let mut a = Bytes::default();
+        let mut b = Bytes::default();
+        reflect_hash_with(&f32::NAN, &mut a, NanHandling::Canonicalize).unwrap();
+        reflect_hash_with(&-f32::NAN, &mut b, NanHandling::Canonicalize).unwrap();
+        assert_eq!(a.0, b.0);
+    }
+}
@@ -0,0 +1,402 @@
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use core::any::Any;
+use core::any::TypeId;
+use core::fmt;
+
+use crate::DescribeType;
+use crate::FromReflect;
+use crate::Reflect;
+use crate::TypeDescriptor;
+use crate::Value;
+
+/// A collection of types that can be looked up by name at runtime.
+///
+/// Types register their [`TypeDescriptor`] and [`FromReflect`] implementation, so that code that
+/// only knows the name of a type, such as a deserializer or an editor, can turn a reflected value
+/// back into an instance of the concrete type.
+///
+/// Types are keyed by their [`type_name`](TypeDescriptor::type_name), which respects
+/// `#[reflect(rename = "...")]`, and by [`TypeId`].
+///
+/// # Example
+///
+/// ```
+/// use mirror_mirror::{Reflect, FromReflect};
+/// use mirror_mirror::registry::TypeRegistry;
+///
+/// #[derive(Reflect, Clone, Debug, PartialEq, Default)]
+/// #[reflect(rename = "my_game::Player")]
+/// struct Player {
+///     name: String,
+///     score: u32,
+/// }
+///
+/// let registry = TypeRegistry::new().with::<Player>();
+///
+/// let player = Player {
+///     name: "Alice".to_owned(),
+///     score: 10,
+/// };
+/// let value = player.to_value();
+///
+/// // later, somewhere that doesn't know about `Player`
+/// let registration = registry.get("my_game::Player").unwrap();
+/// let reflect = registration.from_reflect(&value).unwrap();
+///
+/// assert_eq!(reflect.downcast_ref::<Player>().unwrap(), &player);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TypeRegistry {
+    registrations: BTreeMap<String, Registration>,
+    type_names: BTreeMap<TypeId, String>,
+    opaque_codecs: BTreeMap<TypeId, OpaqueCodecFns>,
+    debug_formatters: BTreeMap<u64, DebugFormatter>,
+}
+
+impl TypeRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A registry shared by the whole process.
+    ///
+    /// Libraries can register their types here so tools can find them without every registry
+    /// having to be passed around explicitly.
+    #[cfg(feature = "std")]
+    pub fn global() -> &'static std::sync::RwLock<TypeRegistry> {
+        static GLOBAL: once_cell::race::OnceBox<std::sync::RwLock<TypeRegistry>> =
+            once_cell::race::OnceBox::new();
+        GLOBAL.get_or_init(Default::default)
+    }
+
+    /// Register `T`.
+    ///
+    /// Registering a type again, or a type with the same name as one already registered,
+    /// replaces the previous registration.
+    pub fn with<T>(mut self) -> Self
+    where
+        T: FromReflect + DescribeType,
+    {
+        self.register::<T>();
+        self
+    }
+
+    /// Register `T`.
+    ///
+    /// Registering a type again, or a type with the same name as one already registered,
+    /// replaces the previous registration.
+    pub fn register<T>(&mut self)
+    where
+        T: FromReflect + DescribeType,
+    {
+        let registration = Registration::of::<T>();
+        let type_name = String::from(registration.type_name());
+
+        if let Some(previous) = self.registrations.remove(&type_name) {
+            self.type_names.remove(&previous.type_id);
+        }
+        if let Some(previous_name) = self
+            .type_names
+            .insert(registration.type_id, type_name.clone())
+        {
+            self.registrations.remove(&previous_name);
+        }
+        self.registrations.insert(type_name, registration);
+    }
+
+    pub fn get(&self, type_name: &str) -> Option<&Registration> {
+        self.registrations.get(type_name)
+    }
+
+    pub fn get_by_type_id(&self, type_id: TypeId) -> Option<&Registration> {
+        self.get(self.type_names.get(&type_id)?)
+    }
+
+    pub fn contains(&self, type_name: &str) -> bool {
+        self.registrations.contains_key(type_name)
+    }
+
+    /// Convert `value` into the type registered with the name `type_name`.
+    ///
+    /// Returns `None` if no such type is registered, or if the conversion fails.
+    pub fn from_reflect(&self, type_name: &str, value: &dyn Reflect) -> Option<Box<dyn Reflect>> {
+        self.get(type_name)?.from_reflect(value)
+    }
+
+    /// Register how to convert `T` to and from a [`Value`].
+    ///
+    /// [`Opaque<T>`](crate::Opaque) consults the codecs in the [global](Self::global) registry
+    /// when converted to a value and in `FromReflect`, so the wrapped data survives
+    /// serialization of the value.
+    pub fn with_opaque_codec<T>(mut self) -> Self
+    where
+        T: OpaqueCodec,
+    {
+        self.register_opaque_codec::<T>();
+        self
+    }
+
+    /// Register how to convert `T` to and from a [`Value`].
+    ///
+    /// See [`with_opaque_codec`](Self::with_opaque_codec) for more details.
+    pub fn register_opaque_codec<T>(&mut self)
+    where
+        T: OpaqueCodec,
+    {
+        self.opaque_codecs
+            .insert(TypeId::of::<T>(), OpaqueCodecFns::of::<T>());
+    }
+
+    /// Encode `value` with the codec registered for `T`.
+    ///
+    /// Returns `None` if no codec is registered for `T`.
+    pub fn encode_opaque<T>(&self, value: &T) -> Option<Value>
+    where
+        T: Any,
+    {
+        self.opaque_codec(TypeId::of::<T>())?.encode(value)
+    }
+
+    /// Decode a `T` with the codec registered for it.
+    ///
+    /// Returns `None` if no codec is registered for `T`, or if decoding fails.
+    pub fn decode_opaque<T>(&self, value: &dyn Reflect) -> Option<T>
+    where
+        T: Any,
+    {
+        self.opaque_codec(TypeId::of::<T>())?.decode(value)
+    }
+
+    pub(crate) fn opaque_codec(&self, type_id: TypeId) -> Option<OpaqueCodecFns> {
+        self.opaque_codecs.get(&type_id).copied()
+    }
+
+    /// Register a function for formatting values of type `T` with `Debug`.
+    ///
+    /// [`reflect_debug`](crate::reflect_debug) and the [`logging`](crate::logging) module
+    /// consult the formatters in the [global](Self::global) registry, for the value itself and
+    /// every value nested in it, so large or noisy types can be rendered compactly without
+    /// changing their `Reflect` impls.
+    ///
+    /// Formatters are keyed by the [stable hash](crate::versioned::stable_type_hash) of `T`'s
+    /// type descriptor. A formatter must not call `reflect_debug` on the value it's given, since
+    /// that would call the formatter again.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::fmt;
+    /// use mirror_mirror::{reflect_debug, Reflect};
+    /// use mirror_mirror::registry::TypeRegistry;
+    ///
+    /// #[derive(Reflect, Clone, Debug)]
+    /// struct Mesh {
+    ///     vertices: Vec<[f32; 3]>,
+    /// }
+    ///
+    /// #[derive(Reflect, Clone, Debug)]
+    /// #[reflect(opt_out(Debug))]
+    /// struct Model {
+    ///     name: String,
+    ///     mesh: Mesh,
+    /// }
+    ///
+    /// fn debug_mesh(value: &dyn Reflect, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///     let mesh = value.downcast_ref::<Mesh>().unwrap();
+    ///     write!(f, "Mesh(<{} vertices>)", mesh.vertices.len())
+    /// }
+    ///
+    /// TypeRegistry::global()
+    ///     .write()
+    ///     .unwrap()
+    ///     .register_debug_formatter::<Mesh>(debug_mesh);
+    ///
+    /// let model = Model {
+    ///     name: "cube".to_owned(),
+    ///     mesh: Mesh { vertices: vec![[0.0; 3]; 8] },
+    /// };
+    /// assert!(format!("{:?}", model.as_reflect())
+    ///     .ends_with(r#"Model { name: "cube", mesh: Mesh(<8 vertices>) }"#));
+    /// ```
+    pub fn with_debug_formatter<T>(mut self, formatter: DebugFormatter) -> Self
+    where
+        T: DescribeType,
+    {
+        self.register_debug_formatter::<T>(formatter);
+        self
+    }
+
+    /// Register a function for formatting values of type `T` with `Debug`.
+    ///
+    /// See [`with_debug_formatter`](Self::with_debug_formatter) for more details.
+    pub fn register_debug_formatter<T>(&mut self, formatter: DebugFormatter)
+    where
+        T: DescribeType,
+    {
+        let type_hash = <T as DescribeType>::type_descriptor().type_hash();
+        self.register_debug_formatter_by_hash(type_hash, formatter);
+    }
+
+    /// Register a function for formatting values whose type has the stable hash `type_hash`
+    /// with `Debug`.
+    ///
+    /// This allows registering formatters for types that are only known from their type
+    /// descriptors, such as those read from files.
+    pub fn register_debug_formatter_by_hash(&mut self, type_hash: u64, formatter: DebugFormatter) {
+        self.debug_formatters.insert(type_hash, formatter);
+    }
+
+    /// The formatter registered for the type described by `descriptor`.
+    pub fn debug_formatter(&self, descriptor: &TypeDescriptor) -> Option<DebugFormatter> {
+        self.debug_formatters.get(&descriptor.type_hash()).copied()
+    }
+
+    /// Iterate over all registrations, sorted by type name.
+    pub fn iter(&self) -> impl Iterator<Item = &Registration> {
+        self.registrations.values()
+    }
+
+    pub fn len(&self) -> usize {
+        self.registrations.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.registrations.is_empty()
+    }
+}
+
+/// A function that formats a reflected value with `Debug`.
+///
+/// Register formatters with [`TypeRegistry::register_debug_formatter`].
+pub type DebugFormatter = fn(&dyn Reflect, &mut fmt::Formatter<'_>) -> fmt::Result;
+
+/// The formatter registered for `value`'s type in the global registry.
+#[cfg(feature = "std")]
+pub(crate) fn global_debug_formatter(value: &dyn Reflect) -> Option<DebugFormatter> {
+    let registry = TypeRegistry::global().read().ok()?;
+    if registry.debug_formatters.is_empty() {
+        return None;
+    }
+    registry.debug_formatter(&value.type_descriptor())
+}
+
+/// A type registered in a [`TypeRegistry`].
+#[derive(Debug, Clone)]
+pub struct Registration {
+    type_id: TypeId,
+    descriptor: Cow<'static, TypeDescriptor>,
+    from_reflect: fn(&dyn Reflect) -> Option<Box<dyn Reflect>>,
+}
+
+impl Registration {
+    fn of<T>() -> Self
+    where
+        T: FromReflect + DescribeType,
+    {
+        Self {
+            type_id: TypeId::of::<T>(),
+            descriptor: <T as DescribeType>::type_descriptor(),
+            from_reflect: |value| Some(Box::new(T::from_reflect(value)?)),
+        }
+    }
+
+    pub fn type_id(&self) -> TypeId {
+        self.type_id
+    }
+
+    pub fn type_name(&self) -> &str {
+        self.descriptor.type_name()
+    }
+
+    pub fn type_descriptor(&self) -> &TypeDescriptor {
+        &self.descriptor
+    }
+
+    /// Convert `value` into the registered type using its [`FromReflect`] implementation.
+    pub fn from_reflect(&self, value: &dyn Reflect) -> Option<Box<dyn Reflect>> {
+        (self.from_reflect)(value)
+    }
+
+    pub fn default_value(&self) -> Option<Value> {
+        self.descriptor.default_value()
+    }
+
+    /// Create an instance of the registered type from its default value.
+    pub fn default_instance(&self) -> Option<Box<dyn Reflect>> {
+        self.from_reflect(&self.default_value()?)
+    }
+}
+
+/// Conversion to and from [`Value`] for data that is otherwise opaque to reflection.
+///
+/// Register implementations with [`TypeRegistry::register_opaque_codec`].
+///
+/// # Example
+///
+/// ```
+/// use mirror_mirror::{FromReflect, Opaque, Reflect, Value};
+/// use mirror_mirror::registry::{OpaqueCodec, TypeRegistry};
+///
+/// #[derive(Clone, PartialEq, Debug)]
+/// struct TextureHandle(u64);
+///
+/// impl OpaqueCodec for TextureHandle {
+///     fn encode(&self) -> Value {
+///         self.0.to_value()
+///     }
+///
+///     fn decode(value: &dyn Reflect) -> Option<Self> {
+///         u64::from_reflect(value).map(Self)
+///     }
+/// }
+///
+/// TypeRegistry::global()
+///     .write()
+///     .unwrap()
+///     .register_opaque_codec::<TextureHandle>();
+///
+/// let texture = Opaque(TextureHandle(1337));
+/// let value = texture.to_value();
+/// assert_eq!(value, Value::u64(1337));
+/// assert_eq!(Opaque::<TextureHandle>::from_reflect(&value).unwrap(), texture);
+/// ```
+pub trait OpaqueCodec: Any + Sized {
+    fn encode(&self) -> Value;
+
+    fn decode(value: &dyn Reflect) -> Option<Self>;
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct OpaqueCodecFns {
+    encode: fn(&dyn Any) -> Option<Value>,
+    decode: fn(&dyn Reflect) -> Option<Box<dyn Any>>,
+}
+
+impl OpaqueCodecFns {
+    fn of<T>() -> Self
+    where
+        T: OpaqueCodec,
+    {
+        Self {
+            encode: |value| Some(value.downcast_ref::<T>()?.encode()),
+            decode: |value| Some(Box::new(T::decode(value)?)),
+        }
+    }
+
+    pub(crate) fn encode<T>(self, value: &T) -> Option<Value>
+    where
+        T: Any,
+    {
+        (self.encode)(value)
+    }
+
+    pub(crate) fn decode<T>(self, value: &dyn Reflect) -> Option<T>
+    where
+        T: Any,
+    {
+        (self.decode)(value)?.downcast().ok().map(|value| *value)
+    }
+}
@@ -0,0 +1,410 @@
+use alloc::borrow::ToOwned;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use serde_json::json;
+use serde_json::Map;
+use serde_json::Value as Json;
+
+use crate::serde::is_option;
+use crate::type_info::GetMeta;
+use crate::type_info::ScalarType;
+use crate::type_info::Type;
+use crate::type_info::Variant;
+use crate::Reflect;
+use crate::ReflectRef;
+use crate::ScalarRef;
+use crate::TypeDescriptor;
+
+/// Generate a [JSON Schema] for the data a [`TypedDeserializer`] accepts for a type.
+///
+/// The schema follows the 2020-12 draft and describes the same shapes [`TypedSerializer`]
+/// produces, so data written by hand can be validated before it's loaded:
+///
+/// - Structs are objects. Fields whose type doesn't have a default value are required.
+/// - Enums use `oneOf`, with unit variants as their name and other variants as an object with
+///   a single key. `Option`s are either `null` or the inner value.
+/// - Lists, arrays, sets, tuples, and tuple structs are arrays, except tuple structs with a single
+///   field which are that field.
+/// - Maps are objects, except maps whose keys aren't scalars which are arrays of `[key, value]`
+///   pairs.
+/// - Integers are bounded by the range of their type.
+/// - Opaque types accept anything.
+///
+/// Structs, tuple structs, and enums are placed in `$defs`, keyed by their type name, and
+/// referenced with `$ref`, so recursive types are supported.
+///
+/// Docs are included as `description`, and metadata is included under the non-standard
+/// `x-meta` keyword.
+///
+/// # Example
+///
+/// ```
+/// use mirror_mirror::{DescribeType, Reflect};
+/// use mirror_mirror::schema::to_json_schema;
+/// use serde_json::json;
+///
+/// /// A player.
+/// #[derive(Reflect, Clone, Debug)]
+/// struct Player {
+///     #[reflect(meta(max_len = 16_usize))]
+///     name: String,
+///     guild: Option<String>,
+/// }
+///
+/// let descriptor = <Player as DescribeType>::type_descriptor();
+/// let schema = to_json_schema(&descriptor);
+///
+/// let player = &schema["$defs"][descriptor.type_name()];
+/// assert_eq!(player["description"], "A player.");
+/// assert_eq!(
+///     player["properties"]["name"],
+///     json!({ "type": "string", "x-meta": { "max_len": 16 } }),
+/// );
+/// assert_eq!(
+///     player["properties"]["guild"],
+///     json!({ "anyOf": [{ "type": "null" }, { "type": "string" }] }),
+/// );
+/// ```
+///
+/// [JSON Schema]: https://json-schema.org
+/// [`TypedSerializer`]: crate::serde::TypedSerializer
+/// [`TypedDeserializer`]: crate::serde::TypedDeserializer
+pub fn to_json_schema(descriptor: &TypeDescriptor) -> Json {
+    let mut generator = Generator::default();
+    let mut schema = generator.schema(descriptor.get_type());
+
+    let object = schema.as_object_mut().expect("schemas are always objects");
+    object.insert(
+        "$schema".to_owned(),
+        Json::from("https://json-schema.org/draft/2020-12/schema"),
+    );
+    if !generator.defs.is_empty() {
+        object.insert("$defs".to_owned(), Json::Object(generator.defs));
+    }
+
+    schema
+}
+
+#[derive(Default)]
+struct Generator {
+    defs: Map<String, Json>,
+}
+
+impl Generator {
+    fn schema(&mut self, ty: Type<'_>) -> Json {
+        match ty {
+            Type::Struct(_) | Type::TupleStruct(_) => self.definition(ty),
+            Type::Enum(enum_type) if !is_option(enum_type) => self.definition(ty),
+            Type::Enum(enum_type) => {
+                let inner = enum_type
+                    .variant("Some")
+                    .and_then(|variant| variant.field_type_at(0))
+                    .map(|field| self.schema(field.get_type()))
+                    .unwrap_or_else(|| json!({}));
+                json!({ "anyOf": [{ "type": "null" }, inner] })
+            }
+            Type::Tuple(tuple_type) if tuple_type.is_unit() => json!({ "type": "null" }),
+            Type::Tuple(tuple_type) => {
+                let fields = tuple_type
+                    .field_types()
+                    .map(|field| self.schema(field.get_type()))
+                    .collect();
+                fixed_len_array(fields)
+            }
+            Type::List(list_type) => {
+                json!({ "type": "array", "items": self.schema(list_type.element_type()) })
+            }
+            Type::Array(array_type) => json!({
+                "type": "array",
+                "items": self.schema(array_type.element_type()),
+                "minItems": array_type.len(),
+                "maxItems": array_type.len(),
+            }),
+            Type::Set(set_type) => json!({
+                "type": "array",
+                "items": self.schema(set_type.element_type()),
+                "uniqueItems": true,
+            }),
+            // JSON only supports string keys so other keys are written as `[key, value]` pairs,
+            // like `TypedSerializer::with_maps_as_entries` does
+            Type::Map(map_type) if !matches!(map_type.key_type(), Type::Scalar(_)) => json!({
+                "type": "array",
+                "items": fixed_len_array(Vec::from([
+                    self.schema(map_type.key_type()),
+                    self.schema(map_type.value_type()),
+                ])),
+            }),
+            Type::Map(map_type) => {
+                let mut schema = json!({
+                    "type": "object",
+                    "additionalProperties": self.schema(map_type.value_type()),
+                });
+                // formats like JSON only support string keys so integers are written as strings
+                let pattern = match map_type.key_type() {
+                    Type::Scalar(
+                        ScalarType::usize
+                        | ScalarType::u8
+                        | ScalarType::u16
+                        | ScalarType::u32
+                        | ScalarType::u64
+                        | ScalarType::u128,
+                    ) => Some("^[0-9]+$"),
+                    Type::Scalar(
+                        ScalarType::i8
+                        | ScalarType::i16
+                        | ScalarType::i32
+                        | ScalarType::i64
+                        | ScalarType::i128,
+                    ) => Some("^-?[0-9]+$"),
+                    _ => None,
+                };
+                if let Some(pattern) = pattern {
+                    schema["propertyNames"] = json!({ "pattern": pattern });
+                }
+                schema
+            }
+            Type::Scalar(scalar_type) => scalar_schema(scalar_type),
+            Type::Opaque(opaque_type) => {
+                let mut schema = json!({ "title": opaque_type.type_name() });
+                annotate(&mut schema, ty);
+                schema
+            }
+        }
+    }
+
+    /// Add the schema of a named type to `$defs`, if it isn't there already, and return a
+    /// reference to it.
+    fn definition(&mut self, ty: Type<'_>) -> Json {
+        let name = ty.type_name();
+        let reference = json!({ "$ref": format!("#/$defs/{}", escape_ref(name)) });
+        if self.defs.contains_key(name) {
+            return reference;
+        }
+
+        // insert a placeholder first so recursive types refer to it rather than recursing forever
+        self.defs.insert(name.to_owned(), Json::Null);
+
+        let mut schema = match ty {
+            Type::Struct(struct_type) => {
+                let fields = struct_type
+                    .field_types()
+                    .map(|field| (field.name(), field.get_type(), self.field_schema(field)));
+                object(fields.collect())
+            }
+            Type::TupleStruct(tuple_struct_type) => {
+                let mut fields = tuple_struct_type
+                    .field_types()
+                    .map(|field| self.field_schema(field))
+                    .collect::<Vec<_>>();
+                if fields.len() == 1 {
+                    fields.remove(0)
+                } else {
+                    fixed_len_array(fields)
+                }
+            }
+            Type::Enum(enum_type) => {
+                let variants = enum_type
+                    .variants()
+                    .map(|variant| self.variant_schema(variant))
+                    .collect::<Vec<_>>();
+                json!({ "oneOf": variants })
+            }
+            _ => unreachable!("only structs, tuple structs, and enums have definitions"),
+        };
+        schema["title"] = Json::from(name);
+        annotate(&mut schema, ty);
+
+        self.defs.insert(name.to_owned(), schema);
+        reference
+    }
+
+    fn variant_schema(&mut self, variant: Variant<'_>) -> Json {
+        let name = variant.name();
+        let mut schema = match variant {
+            Variant::Unit(_) => json!({ "const": name }),
+            Variant::Struct(struct_variant) => {
+                let fields = struct_variant
+                    .field_types()
+                    .map(|field| (field.name(), field.get_type(), self.field_schema(field)));
+                let fields = object(fields.collect());
+                single_key_object(name, fields)
+            }
+            Variant::Tuple(tuple_variant) => {
+                let mut fields = tuple_variant
+                    .field_types()
+                    .map(|field| self.field_schema(field))
+                    .collect::<Vec<_>>();
+                let fields = if fields.len() == 1 {
+                    fields.remove(0)
+                } else {
+                    fixed_len_array(fields)
+                };
+                single_key_object(name, fields)
+            }
+        };
+        annotate(&mut schema, variant);
+        schema
+    }
+
+    fn field_schema<'a, F>(&mut self, field: F) -> Json
+    where
+        F: FieldLike<'a>,
+    {
+        let mut schema = self.schema(field.field_type());
+        annotate(&mut schema, field);
+        schema
+    }
+}
+
+fn object(fields: Vec<(&str, Type<'_>, Json)>) -> Json {
+    let mut properties = Map::new();
+    let mut required = Vec::new();
+    for (name, ty, schema) in fields {
+        // missing fields are filled in with their type's default value when deserializing
+        if !ty.has_default_value() {
+            required.push(Json::from(name));
+        }
+        properties.insert(name.to_owned(), schema);
+    }
+    json!({
+        "type": "object",
+        "properties": properties,
+        "required": required,
+    })
+}
+
+/// Fields of structs, tuple structs, and variants.
+trait FieldLike<'a>: GetMeta<'a> + Copy {
+    fn field_type(self) -> Type<'a>;
+}
+
+macro_rules! impl_field_like {
+    ($($ident:ident)*) => {
+        $(
+            impl<'a> FieldLike<'a> for crate::type_info::$ident<'a> {
+                fn field_type(self) -> Type<'a> {
+                    self.get_type()
+                }
+            }
+        )*
+    };
+}
+
+impl_field_like! {
+    NamedField
+    UnnamedField
+    VariantField
+}
+
+fn scalar_schema(scalar_type: ScalarType) -> Json {
+    fn integer<T>(min: T, max: T) -> Json
+    where
+        Json: From<T>,
+    {
+        json!({ "type": "integer", "minimum": Json::from(min), "maximum": Json::from(max) })
+    }
+
+    match scalar_type {
+        ScalarType::usize => json!({ "type": "integer", "minimum": 0 }),
+        ScalarType::u8 => integer(u8::MIN, u8::MAX),
+        ScalarType::u16 => integer(u16::MIN, u16::MAX),
+        ScalarType::u32 => integer(u32::MIN, u32::MAX),
+        ScalarType::u64 => integer(u64::MIN, u64::MAX),
+        ScalarType::u128 => json!({ "type": "integer", "minimum": 0 }),
+        ScalarType::i8 => integer(i8::MIN, i8::MAX),
+        ScalarType::i16 => integer(i16::MIN, i16::MAX),
+        ScalarType::i32 => integer(i32::MIN, i32::MAX),
+        ScalarType::i64 => integer(i64::MIN, i64::MAX),
+        ScalarType::i128 => json!({ "type": "integer" }),
+        ScalarType::bool => json!({ "type": "boolean" }),
+        ScalarType::char => json!({ "type": "string", "minLength": 1, "maxLength": 1 }),
+        ScalarType::f32 | ScalarType::f64 => json!({ "type": "number" }),
+        ScalarType::String => json!({ "type": "string" }),
+    }
+}
+
+fn fixed_len_array(items: Vec<Json>) -> Json {
+    let len = items.len();
+    json!({
+        "type": "array",
+        "prefixItems": items,
+        "minItems": len,
+        "maxItems": len,
+    })
+}
+
+fn single_key_object(key: &str, value: Json) -> Json {
+    let mut properties = Map::new();
+    properties.insert(key.to_owned(), value);
+    json!({
+        "type": "object",
+        "properties": properties,
+        "required": [key],
+        "additionalProperties": false,
+    })
+}
+
+/// Add docs and metadata to a schema.
+fn annotate<'a>(schema: &mut Json, meta: impl GetMeta<'a> + Copy) {
+    let docs = meta.docs();
+    if !docs.is_empty() {
+        let docs = docs.iter().map(|line| line.trim()).collect::<Vec<_>>();
+        schema["description"] = Json::from(docs.join("\n"));
+    }
+
+    let meta = meta
+        .meta_iter()
+        .map(|(key, value)| (key.to_owned(), meta_to_json(value)))
+        .collect::<Map<_, _>>();
+    if !meta.is_empty() {
+        schema["x-meta"] = Json::Object(meta);
+    }
+}
+
+fn meta_to_json(value: &dyn Reflect) -> Json {
+    match value.reflect_ref() {
+        ReflectRef::Scalar(scalar) => match scalar {
+            ScalarRef::usize(n) => Json::from(n),
+            ScalarRef::u8(n) => Json::from(n),
+            ScalarRef::u16(n) => Json::from(n),
+            ScalarRef::u32(n) => Json::from(n),
+            ScalarRef::u64(n) => Json::from(n),
+            ScalarRef::u128(n) => serde_json::to_value(n).unwrap_or(Json::Null),
+            ScalarRef::i8(n) => Json::from(n),
+            ScalarRef::i16(n) => Json::from(n),
+            ScalarRef::i32(n) => Json::from(n),
+            ScalarRef::i64(n) => Json::from(n),
+            ScalarRef::i128(n) => serde_json::to_value(n).unwrap_or(Json::Null),
+            ScalarRef::bool(b) => Json::from(b),
+            ScalarRef::char(c) => Json::from(String::from(c)),
+            ScalarRef::f32(n) => Json::from(n),
+            ScalarRef::f64(n) => Json::from(n),
+            ScalarRef::String(s) => Json::from(s.as_str()),
+        },
+        ReflectRef::List(list) => list.iter().map(meta_to_json).collect(),
+        ReflectRef::Array(array) => array.iter().map(meta_to_json).collect(),
+        // other values have no obvious JSON representation so fall back to `Value`'s
+        _ => serde_json::to_value(value.to_value()).unwrap_or(Json::Null),
+    }
+}
+
+/// Escape a type name for use in a `$ref`, which is a JSON pointer inside a URI fragment.
+fn escape_ref(name: &str) -> String {
+    let mut out = String::with_capacity(name.len());
+    for c in name.chars() {
+        match c {
+            '~' => out.push_str("~0"),
+            '/' => out.push_str("~1"),
+            c if c.is_ascii_alphanumeric() || "-._!$&'()*+,;=:@".contains(c) => out.push(c),
+            c => {
+                let mut buf = [0; 4];
+                for byte in c.encode_utf8(&mut buf).bytes() {
+                    out.push_str(&format!("%{byte:02X}"));
+                }
+            }
+        }
+    }
+    out
+}
@@ -0,0 +1,82 @@
+//! Per-thread scratch buffers used to avoid allocating when looking up map entries by string keys.
+//!
+//! They are only used by [`Map::get_str`](crate::Map::get_str) and
+//! [`Map::get_str_mut`](crate::Map::get_str_mut). Converting to values, patching, and
+//! serialization allocate as usual.
+//!
+//! Rather than allocating a buffer on every call they are taken from a small per-thread pool and
+//! returned to it afterwards. Buffers that have grown larger than [`SCRATCH_CAPACITY`] are dropped
+//! rather than returned to the pool, so a single large key doesn't keep memory alive forever.
+//!
+//! Without the `std` feature there are no thread locals so buffers are never pooled.
+
+use alloc::string::String;
+use core::mem;
+
+use crate::Value;
+
+/// The maximum capacity, in bytes, of buffers kept in the scratch pool.
+#[cfg_attr(not(feature = "std"), allow(dead_code))]
+pub(crate) const SCRATCH_CAPACITY: usize = 1024;
+
+/// Call `f` with an empty `String` taken from the current thread's scratch pool.
+pub(crate) fn with_string<R>(f: impl FnOnce(&mut String) -> R) -> R {
+    imp::with_string(f)
+}
+
+/// Call `f` with a `Value::String` containing `string`, backed by a scratch buffer.
+pub(crate) fn with_string_value<R>(string: &str, f: impl FnOnce(&Value) -> R) -> R {
+    with_string(|buf| {
+        buf.push_str(string);
+        let value = Value::String(mem::take(buf));
+        let out = f(&value);
+        if let Value::String(value) = value {
+            *buf = value;
+        }
+        out
+    })
+}
+
+#[cfg(feature = "std")]
+mod imp {
+    use alloc::string::String;
+    use alloc::vec::Vec;
+    use core::cell::RefCell;
+
+    use super::SCRATCH_CAPACITY;
+
+    // the number of buffers kept per thread. More than one is only needed when calls nest
+    const MAX_POOLED: usize = 4;
+
+    std::thread_local! {
+        static STRINGS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+    }
+
+    pub(super) fn with_string<R>(f: impl FnOnce(&mut String) -> R) -> R {
+        // the buffer is taken out of the pool, rather than borrowed, so `f` is free to use the
+        // pool as well
+        let mut buf = STRINGS
+            .with(|pool| pool.borrow_mut().pop())
+            .unwrap_or_default();
+        let out = f(&mut buf);
+        buf.clear();
+        if buf.capacity() <= SCRATCH_CAPACITY {
+            STRINGS.with(|pool| {
+                let mut pool = pool.borrow_mut();
+                if pool.len() < MAX_POOLED {
+                    pool.push(buf);
+                }
+            });
+        }
+        out
+    }
+}
+
+#[cfg(not(feature = "std"))]
+mod imp {
+    use alloc::string::String;
+
+    pub(super) fn with_string<R>(f: impl FnOnce(&mut String) -> R) -> R {
+        f(&mut String::new())
+    }
+}
@@ -0,0 +1,879 @@
+use alloc::borrow::ToOwned;
+use alloc::collections::BTreeMap;
+use alloc::collections::BTreeSet;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use serde::de;
+use serde::de::DeserializeSeed;
+use serde::de::EnumAccess;
+use serde::de::IgnoredAny;
+use serde::de::MapAccess;
+use serde::de::SeqAccess;
+use serde::de::VariantAccess;
+use serde::de::Visitor;
+use serde::ser;
+use serde::ser::SerializeMap;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde::Serializer;
+
+use crate::enum_::EnumValue;
+use crate::error::ReflectError;
+use crate::struct_::StructValue;
+use crate::tuple::TupleValue;
+use crate::tuple_struct::TupleStructValue;
+use crate::type_info::EnumType;
+use crate::type_info::GetMeta;
+use crate::type_info::ScalarType;
+use crate::type_info::StructType;
+use crate::type_info::StructVariant;
+use crate::type_info::TupleVariant;
+use crate::type_info::Type;
+use crate::type_info::Variant;
+use crate::type_info::VariantField;
+use crate::Reflect;
+use crate::ReflectRef;
+use crate::ScalarRef;
+use crate::TypeDescriptor;
+use crate::Value;
+
+/// Serializes a value in the shape described by a [`TypeDescriptor`].
+///
+/// `Value`'s own `Serialize` implementation writes out its internal representation, which is
+/// awkward to read or write by hand. This instead produces the same data as `serde`'s derives
+/// would for the original type:
+///
+/// - Structs and struct variants are maps from field names to values.
+/// - Tuple structs with one field are serialized as that field, other tuple structs and tuples
+///   as sequences. The unit type `()` is serialized as unit.
+/// - Enums are externally tagged. Unit variants are serialized as their name.
+/// - `Option`s are serialized as `none` or `some`.
+/// - Lists, arrays, and sets are sequences, and maps are maps. See
+///   [`with_maps_as_entries`](Self::with_maps_as_entries) for formats that only support string
+///   keys.
+/// - Opaque values fall back to `Value`'s `Serialize` implementation.
+///
+/// Use [`TypedDeserializer`] to read the data back.
+///
+/// # Example
+///
+/// ```
+/// use mirror_mirror::{Reflect, DescribeType, FromReflect};
+/// use mirror_mirror::serde::{TypedDeserializer, TypedSerializer};
+/// use serde::de::DeserializeSeed;
+///
+/// #[derive(Reflect, Clone, Debug, PartialEq)]
+/// struct Player {
+///     name: String,
+///     class: Class,
+///     guild: Option<String>,
+/// }
+///
+/// #[derive(Reflect, Clone, Debug, PartialEq)]
+/// enum Class {
+///     Warrior,
+///     Mage { mana: u32 },
+/// }
+///
+/// let descriptor = <Player as DescribeType>::type_descriptor();
+///
+/// let player = Player {
+///     name: "Alice".to_owned(),
+///     class: Class::Mage { mana: 100 },
+///     guild: None,
+/// };
+///
+/// let json = serde_json::to_string(&TypedSerializer::new(&player, &descriptor)).unwrap();
+/// assert_eq!(
+///     json,
+///     r#"{"name":"Alice","class":{"Mage":{"mana":100}},"guild":null}"#,
+/// );
+///
+/// let value = TypedDeserializer::new(&descriptor)
+///     .deserialize(&mut serde_json::Deserializer::from_str(&json))
+///     .unwrap();
+/// assert_eq!(Player::from_reflect(&value).unwrap(), player);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct TypedSerializer<'a> {
+    value: &'a dyn Reflect,
+    ty: Type<'a>,
+    redact_sensitive: bool,
+    sensitive: bool,
+    maps_as_entries: bool,
+}
+
+impl<'a> TypedSerializer<'a> {
+    pub fn new(value: &'a dyn Reflect, descriptor: &'a TypeDescriptor) -> Self {
+        Self {
+            value,
+            ty: descriptor.get_type(),
+            redact_sensitive: false,
+            sensitive: false,
+            maps_as_entries: false,
+        }
+    }
+
+    /// Serialize fields, variants, and types marked with `#[reflect(sensitive)]` as the string
+    /// `"<redacted>"`.
+    ///
+    /// Useful for exporting data that must not contain secrets. The output can't be
+    /// deserialized back into the original type if anything was redacted.
+    pub fn with_sensitive_redacted(mut self) -> Self {
+        self.redact_sensitive = true;
+        self
+    }
+
+    /// Serialize maps whose keys aren't scalars, such as structs or tuples, as sequences of
+    /// `[key, value]` pairs.
+    ///
+    /// Formats like JSON only support string keys, and so can't serialize such maps otherwise.
+    /// [`TypedDeserializer`] accepts both forms.
+    pub fn with_maps_as_entries(mut self) -> Self {
+        self.maps_as_entries = true;
+        self
+    }
+
+    fn child(&self, value: &'a dyn Reflect, ty: Type<'a>) -> Self {
+        Self {
+            value,
+            ty,
+            sensitive: false,
+            ..*self
+        }
+    }
+
+    fn field(&self, value: &'a dyn Reflect, ty: Type<'a>, sensitive: bool) -> Self {
+        Self {
+            sensitive,
+            ..self.child(value, ty)
+        }
+    }
+}
+
+impl Serialize for TypedSerializer<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let Self { value, ty, .. } = *self;
+
+        if self.redact_sensitive && (self.sensitive || ty.is_sensitive()) {
+            return serializer.serialize_str("<redacted>");
+        }
+
+        let mismatch =
+            || <S::Error as ser::Error>::custom(ReflectError::new(ty.type_name(), Some(value)));
+
+        match (ty, value.reflect_ref()) {
+            (Type::Struct(struct_type), ReflectRef::Struct(struct_)) => {
+                let mut fields = Vec::with_capacity(struct_type.fields_len());
+                for field in struct_type.field_types() {
+                    let value = struct_.field(field.name()).ok_or_else(mismatch)?;
+                    let value = self.field(value, field.get_type(), field.is_sensitive());
+                    fields.push((field.name(), value));
+                }
+                NamedFields(fields).serialize(serializer)
+            }
+            (Type::TupleStruct(tuple_struct_type), ReflectRef::TupleStruct(tuple_struct)) => {
+                let mut fields = Vec::with_capacity(tuple_struct_type.fields_len());
+                for (index, field) in tuple_struct_type.field_types().enumerate() {
+                    let value = tuple_struct.field_at(index).ok_or_else(mismatch)?;
+                    fields.push(self.field(value, field.get_type(), field.is_sensitive()));
+                }
+                if let [field] = &*fields {
+                    field.serialize(serializer)
+                } else {
+                    fields.serialize(serializer)
+                }
+            }
+            (Type::Tuple(tuple_type), ReflectRef::Tuple(_)) if tuple_type.is_unit() => {
+                serializer.serialize_unit()
+            }
+            (Type::Tuple(tuple_type), ReflectRef::Tuple(tuple)) => {
+                let mut fields = Vec::with_capacity(tuple_type.fields_len());
+                for (index, field) in tuple_type.field_types().enumerate() {
+                    let value = tuple.field_at(index).ok_or_else(mismatch)?;
+                    fields.push(self.child(value, field.get_type()));
+                }
+                fields.serialize(serializer)
+            }
+            (Type::Enum(enum_type), ReflectRef::Enum(enum_)) => {
+                let variant = enum_type
+                    .variant(enum_.variant_name())
+                    .ok_or_else(mismatch)?;
+
+                if is_option(enum_type) {
+                    return match (variant, enum_.field_at(0)) {
+                        (Variant::Tuple(variant), Some(value)) => {
+                            let field = variant.field_type_at(0).ok_or_else(mismatch)?;
+                            let sensitive = variant.is_sensitive() || field.is_sensitive();
+                            serializer.serialize_some(&self.field(
+                                value,
+                                field.get_type(),
+                                sensitive,
+                            ))
+                        }
+                        _ => serializer.serialize_none(),
+                    };
+                }
+
+                match variant {
+                    Variant::Unit(variant) => serializer.serialize_str(variant.name()),
+                    Variant::Struct(variant) => {
+                        let mut fields = Vec::with_capacity(variant.fields_len());
+                        for field in variant.field_types() {
+                            let value = enum_.field(field.name()).ok_or_else(mismatch)?;
+                            let sensitive = variant.is_sensitive() || field.is_sensitive();
+                            let value = self.field(value, field.get_type(), sensitive);
+                            fields.push((field.name(), value));
+                        }
+                        let mut map = serializer.serialize_map(Some(1))?;
+                        map.serialize_entry(variant.name(), &NamedFields(fields))?;
+                        map.end()
+                    }
+                    Variant::Tuple(variant) => {
+                        let mut fields = Vec::with_capacity(variant.fields_len());
+                        for (index, field) in variant.field_types().enumerate() {
+                            let value = enum_.field_at(index).ok_or_else(mismatch)?;
+                            let sensitive = variant.is_sensitive() || field.is_sensitive();
+                            fields.push(self.field(value, field.get_type(), sensitive));
+                        }
+                        let mut map = serializer.serialize_map(Some(1))?;
+                        if let [field] = &*fields {
+                            map.serialize_entry(variant.name(), field)?;
+                        } else {
+                            map.serialize_entry(variant.name(), &fields)?;
+                        }
+                        map.end()
+                    }
+                }
+            }
+            (Type::Array(array_type), ReflectRef::Array(array)) => {
+                let element_type = array_type.element_type();
+                serializer.collect_seq(array.iter().map(|value| self.child(value, element_type)))
+            }
+            (Type::Array(array_type), ReflectRef::List(list)) => {
+                let element_type = array_type.element_type();
+                serializer.collect_seq(list.iter().map(|value| self.child(value, element_type)))
+            }
+            (Type::List(list_type), ReflectRef::List(list)) => {
+                let element_type = list_type.element_type();
+                serializer.collect_seq(list.iter().map(|value| self.child(value, element_type)))
+            }
+            (Type::Set(set_type), ReflectRef::Set(set)) => {
+                let element_type = set_type.element_type();
+                serializer.collect_seq(set.iter().map(|value| self.child(value, element_type)))
+            }
+            (Type::Map(map_type), ReflectRef::Map(map)) => {
+                let key_type = map_type.key_type();
+                let value_type = map_type.value_type();
+                let entries = map
+                    .iter()
+                    .map(|(key, value)| (self.child(key, key_type), self.child(value, value_type)));
+                if self.maps_as_entries && !matches!(key_type, Type::Scalar(_)) {
+                    serializer.collect_seq(entries)
+                } else {
+                    serializer.collect_map(entries)
+                }
+            }
+            (Type::Scalar(scalar_type), ReflectRef::Scalar(scalar)) => {
+                if scalar.as_reflect().type_name() != scalar_type.type_name() {
+                    return Err(mismatch());
+                }
+                match scalar {
+                    ScalarRef::usize(inner) => inner.serialize(serializer),
+                    ScalarRef::u8(inner) => inner.serialize(serializer),
+                    ScalarRef::u16(inner) => inner.serialize(serializer),
+                    ScalarRef::u32(inner) => inner.serialize(serializer),
+                    ScalarRef::u64(inner) => inner.serialize(serializer),
+                    ScalarRef::u128(inner) => inner.serialize(serializer),
+                    ScalarRef::i8(inner) => inner.serialize(serializer),
+                    ScalarRef::i16(inner) => inner.serialize(serializer),
+                    ScalarRef::i32(inner) => inner.serialize(serializer),
+                    ScalarRef::i64(inner) => inner.serialize(serializer),
+                    ScalarRef::i128(inner) => inner.serialize(serializer),
+                    ScalarRef::bool(inner) => inner.serialize(serializer),
+                    ScalarRef::char(inner) => inner.serialize(serializer),
+                    ScalarRef::f32(inner) => inner.serialize(serializer),
+                    ScalarRef::f64(inner) => inner.serialize(serializer),
+                    ScalarRef::String(inner) => inner.serialize(serializer),
+                }
+            }
+            (Type::Opaque(_), _) => value.to_value().serialize(serializer),
+            _ => Err(mismatch()),
+        }
+    }
+}
+
+struct NamedFields<'a>(Vec<(&'a str, TypedSerializer<'a>)>);
+
+impl Serialize for NamedFields<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (name, value) in &self.0 {
+            map.serialize_entry(name, value)?;
+        }
+        map.end()
+    }
+}
+
+/// Deserializes a [`Value`] in the shape described by a [`TypeDescriptor`].
+///
+/// This is the inverse of [`TypedSerializer`] and accepts the same data `serde`'s derives would
+/// for the original type, from any self-describing format such as JSON, RON, or TOML. The
+/// resulting value can be converted into the original type with [`FromReflect`], or used as is
+/// when the type isn't available.
+///
+/// Fields missing from the data are filled in with the field type's default value, if it has
+/// one. Unknown fields are ignored.
+///
+/// Enums are read with [`Deserializer::deserialize_any`], in the externally tagged form
+/// [`TypedSerializer`] writes, rather than each format's own syntax for enums. Maps whose keys
+/// aren't scalars are read either as maps or as sequences of `[key, value]` pairs.
+///
+/// Use [`TypeDescriptor::at`] to deserialize only part of a type.
+///
+/// See [`TypedSerializer`] for an example.
+///
+/// [`FromReflect`]: crate::FromReflect
+#[derive(Debug, Clone, Copy)]
+pub struct TypedDeserializer<'a> {
+    ty: Type<'a>,
+}
+
+impl<'a> TypedDeserializer<'a> {
+    pub fn new(descriptor: &'a TypeDescriptor) -> Self {
+        Self::with_type(descriptor.get_type())
+    }
+
+    pub(crate) fn with_type(ty: Type<'a>) -> Self {
+        Self { ty }
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for TypedDeserializer<'_> {
+    type Value = Value;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match self.ty {
+            Type::Struct(struct_type) => deserializer.deserialize_map(StructVisitor(struct_type)),
+            Type::TupleStruct(tuple_struct_type) => {
+                let types = tuple_struct_type
+                    .field_types()
+                    .map(|field| field.get_type())
+                    .collect::<Vec<_>>();
+                let fields = if let [ty] = &*types {
+                    Vec::from([Self::with_type(*ty).deserialize(deserializer)?])
+                } else {
+                    deserializer.deserialize_seq(FieldsVisitor(types))?
+                };
+                let mut value = TupleStructValue::with_capacity(fields.len())
+                    .with_type_name(tuple_struct_type.type_name().to_owned());
+                for field in fields {
+                    value.push_field(field);
+                }
+                Ok(Value::TupleStructValue(value))
+            }
+            Type::Tuple(tuple_type) if tuple_type.is_unit() => {
+                deserializer.deserialize_unit(UnitVisitor)
+            }
+            Type::Tuple(tuple_type) => {
+                let types = tuple_type.field_types().map(|field| field.get_type());
+                let fields = deserializer.deserialize_seq(FieldsVisitor(types.collect()))?;
+                let mut value = TupleValue::with_capacity(fields.len());
+                for field in fields {
+                    value.push_field(field);
+                }
+                Ok(Value::TupleValue(value))
+            }
+            Type::Enum(enum_type) if is_option(enum_type) => {
+                deserializer.deserialize_option(OptionVisitor(enum_type))
+            }
+            // formats such as RON only accept their own syntax in `deserialize_enum`, not the map
+            // `TypedSerializer` writes
+            Type::Enum(enum_type) => deserializer.deserialize_any(EnumVisitor(enum_type)),
+            Type::Array(array_type) => {
+                let elements = deserializer.deserialize_seq(ElementsVisitor {
+                    element_type: array_type.element_type(),
+                    len: Some(array_type.len()),
+                })?;
+                if elements.len() != array_type.len() {
+                    return Err(de::Error::invalid_length(
+                        elements.len(),
+                        &&*format!("an array of length {}", array_type.len()),
+                    ));
+                }
+                Ok(Value::List(elements))
+            }
+            Type::List(list_type) => deserializer
+                .deserialize_seq(ElementsVisitor {
+                    element_type: list_type.element_type(),
+                    len: None,
+                })
+                .map(Value::List),
+            Type::Set(set_type) => deserializer
+                .deserialize_seq(ElementsVisitor {
+                    element_type: set_type.element_type(),
+                    len: None,
+                })
+                .map(|elements| Value::Set(elements.into_iter().collect::<BTreeSet<_>>())),
+            Type::Map(map_type) => {
+                let visitor = MapVisitor {
+                    key_type: map_type.key_type(),
+                    value_type: map_type.value_type(),
+                };
+                if matches!(map_type.key_type(), Type::Scalar(_)) {
+                    deserializer.deserialize_map(visitor)
+                } else {
+                    deserializer.deserialize_any(visitor)
+                }
+            }
+            Type::Scalar(scalar_type) => deserialize_scalar(scalar_type, deserializer),
+            Type::Opaque(_) => Value::deserialize(deserializer),
+        }
+    }
+}
+
+fn deserialize_scalar<'de, D>(scalar_type: ScalarType, deserializer: D) -> Result<Value, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(match scalar_type {
+        ScalarType::usize => Value::usize(Deserialize::deserialize(deserializer)?),
+        ScalarType::u8 => Value::u8(Deserialize::deserialize(deserializer)?),
+        ScalarType::u16 => Value::u16(Deserialize::deserialize(deserializer)?),
+        ScalarType::u32 => Value::u32(Deserialize::deserialize(deserializer)?),
+        ScalarType::u64 => Value::u64(Deserialize::deserialize(deserializer)?),
+        ScalarType::u128 => Value::u128(Deserialize::deserialize(deserializer)?),
+        ScalarType::i8 => Value::i8(Deserialize::deserialize(deserializer)?),
+        ScalarType::i16 => Value::i16(Deserialize::deserialize(deserializer)?),
+        ScalarType::i32 => Value::i32(Deserialize::deserialize(deserializer)?),
+        ScalarType::i64 => Value::i64(Deserialize::deserialize(deserializer)?),
+        ScalarType::i128 => Value::i128(Deserialize::deserialize(deserializer)?),
+        ScalarType::bool => Value::bool(Deserialize::deserialize(deserializer)?),
+        ScalarType::char => Value::char(Deserialize::deserialize(deserializer)?),
+        ScalarType::f32 => Value::f32(Deserialize::deserialize(deserializer)?),
+        ScalarType::f64 => Value::f64(Deserialize::deserialize(deserializer)?),
+        ScalarType::String => Value::String(Deserialize::deserialize(deserializer)?),
+    })
+}
+
+/// `Option` is reflected as a regular enum, but formats have dedicated support for it which we
+/// want to use.
+pub(crate) fn is_option(enum_type: EnumType<'_>) -> bool {
+    enum_type.type_name().starts_with("core::option::Option<")
+}
+
+/// Read the fields of a struct, or a struct variant, in the order they're declared in
+/// `field_types`, filling in missing fields with their default values.
+fn visit_named_fields<'de, 'a, A>(
+    field_types: Vec<(&'a str, Type<'a>)>,
+    mut map: A,
+    mut set_field: impl FnMut(&'a str, Value),
+) -> Result<(), A::Error>
+where
+    A: MapAccess<'de>,
+{
+    let mut fields = BTreeMap::new();
+    while let Some(name) = map.next_key::<String>()? {
+        let field_type = field_types
+            .iter()
+            .find(|(field_name, _)| *field_name == name);
+        match field_type {
+            Some(&(name, ty)) => {
+                let value = map.next_value_seed(TypedDeserializer::with_type(ty))?;
+                fields.insert(name, value);
+            }
+            None => {
+                map.next_value::<IgnoredAny>()?;
+            }
+        }
+    }
+
+    for (name, ty) in field_types {
+        let value = match fields.remove(name) {
+            Some(value) => value,
+            None => ty
+                .default_value()
+                .ok_or_else(|| de::Error::custom(format!("missing field `{name}`")))?,
+        };
+        set_field(name, value);
+    }
+
+    Ok(())
+}
+
+struct StructVisitor<'a>(StructType<'a>);
+
+impl<'de> Visitor<'de> for StructVisitor<'_> {
+    type Value = Value;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a map of the fields of `{}`", self.0.type_name())
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let struct_type = self.0;
+        let mut value = StructValue::with_capacity(struct_type.fields_len())
+            .with_type_name(struct_type.type_name().to_owned());
+        visit_named_fields(
+            struct_type
+                .field_types()
+                .map(|field| (field.name(), field.get_type()))
+                .collect(),
+            map,
+            |name, field| value.set_field(name, field),
+        )?;
+        Ok(Value::StructValue(value.into()))
+    }
+}
+
+struct FieldsVisitor<'a>(Vec<Type<'a>>);
+
+impl<'de> Visitor<'de> for FieldsVisitor<'_> {
+    type Value = Vec<Value>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a sequence of {} fields", self.0.len())
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut fields = Vec::with_capacity(self.0.len());
+        for (index, ty) in self.0.iter().enumerate() {
+            let field = seq
+                .next_element_seed(TypedDeserializer::with_type(*ty))?
+                .ok_or_else(|| de::Error::invalid_length(index, &self))?;
+            fields.push(field);
+        }
+        if seq.next_element::<IgnoredAny>()?.is_some() {
+            return Err(de::Error::invalid_length(self.0.len() + 1, &self));
+        }
+        Ok(fields)
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for FieldsVisitor<'_> {
+    type Value = Vec<Value>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+struct ElementsVisitor<'a> {
+    element_type: Type<'a>,
+    /// The number of elements, if known from the type.
+    len: Option<usize>,
+}
+
+impl<'de> Visitor<'de> for ElementsVisitor<'_> {
+    type Value = Vec<Value>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a sequence of `{}`", self.element_type.type_name())
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        // the size hint comes from the input, so it's capped to avoid allocating huge amounts of
+        // memory for malicious data
+        const MAX_PREALLOCATION: usize = 1024 * 1024 / core::mem::size_of::<Value>();
+        let capacity = self
+            .len
+            .or_else(|| seq.size_hint())
+            .unwrap_or_default()
+            .min(MAX_PREALLOCATION);
+
+        let mut elements = Vec::with_capacity(capacity);
+        while let Some(element) =
+            seq.next_element_seed(TypedDeserializer::with_type(self.element_type))?
+        {
+            elements.push(element);
+        }
+        Ok(elements)
+    }
+}
+
+struct MapVisitor<'a> {
+    key_type: Type<'a>,
+    value_type: Type<'a>,
+}
+
+impl<'de> Visitor<'de> for MapVisitor<'_> {
+    type Value = Value;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "a map from `{}` to `{}`",
+            self.key_type.type_name(),
+            self.value_type.type_name()
+        )
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut entries = BTreeMap::new();
+        while let Some((key, value)) = map.next_entry_seed(
+            TypedDeserializer::with_type(self.key_type),
+            TypedDeserializer::with_type(self.value_type),
+        )? {
+            entries.insert(key, value);
+        }
+        Ok(Value::Map(entries))
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut entries = BTreeMap::new();
+        while let Some(entry) =
+            seq.next_element_seed(FieldsVisitor(Vec::from([self.key_type, self.value_type])))?
+        {
+            // `FieldsVisitor` only returns sequences of the expected length
+            if let Ok([key, value]) = <[Value; 2]>::try_from(entry) {
+                entries.insert(key, value);
+            }
+        }
+        Ok(Value::Map(entries))
+    }
+}
+
+struct UnitVisitor;
+
+impl<'de> Visitor<'de> for UnitVisitor {
+    type Value = Value;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`()`")
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(Value::unit())
+    }
+}
+
+struct OptionVisitor<'a>(EnumType<'a>);
+
+impl<'de> Visitor<'de> for OptionVisitor<'_> {
+    type Value = Value;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "an `{}`", self.0.type_name())
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(EnumValue::new_unit_variant("None")
+            .with_type_name(self.0.type_name().to_owned())
+            .into())
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.visit_none()
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let ty = self
+            .0
+            .variant("Some")
+            .and_then(|variant| variant.field_type_at(0))
+            .map(VariantField::get_type)
+            .ok_or_else(|| de::Error::custom("`Option` without a `Some` variant"))?;
+        let value = TypedDeserializer::with_type(ty).deserialize(deserializer)?;
+        Ok(EnumValue::new_tuple_variant_with_capacity("Some", 1)
+            .with_tuple_field(value)
+            .with_type_name(self.0.type_name().to_owned())
+            .finish()
+            .into())
+    }
+}
+
+struct EnumVisitor<'a>(EnumType<'a>);
+
+impl<'a> EnumVisitor<'a> {
+    fn variant<E>(&self, name: &str) -> Result<Variant<'a>, E>
+    where
+        E: de::Error,
+    {
+        self.0.variant(name).ok_or_else(|| {
+            de::Error::custom(format!(
+                "unknown variant `{name}` of `{}`",
+                self.0.type_name()
+            ))
+        })
+    }
+
+    fn finish(&self, variant: Variant<'_>, mut value: EnumValue) -> Value {
+        value.set_discriminant(variant.discriminant());
+        value.with_type_name(self.0.type_name().to_owned()).into()
+    }
+}
+
+fn field_types(variant: TupleVariant<'_>) -> Vec<Type<'_>> {
+    variant
+        .field_types()
+        .map(|field| field.get_type())
+        .collect()
+}
+
+fn tuple_variant(variant: TupleVariant<'_>, fields: Vec<Value>) -> EnumValue {
+    let mut value = EnumValue::new_tuple_variant_with_capacity(variant.name(), fields.len());
+    for field in fields {
+        value.push_tuple_field(field);
+    }
+    value.finish()
+}
+
+impl<'de> Visitor<'de> for EnumVisitor<'_> {
+    type Value = Value;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a variant of `{}`", self.0.type_name())
+    }
+
+    fn visit_str<E>(self, name: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        match self.variant(name)? {
+            variant @ Variant::Unit(_) => {
+                let value = EnumValue::new_unit_variant(variant.name());
+                Ok(self.finish(variant, value))
+            }
+            Variant::Struct(_) | Variant::Tuple(_) => {
+                Err(de::Error::invalid_type(de::Unexpected::UnitVariant, &self))
+            }
+        }
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let name = map
+            .next_key::<String>()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let variant = self.variant(&name)?;
+        let value = match variant {
+            Variant::Unit(variant) => {
+                map.next_value::<()>()?;
+                EnumValue::new_unit_variant(variant.name())
+            }
+            Variant::Tuple(variant) => {
+                let types = field_types(variant);
+                let fields = if let [ty] = &*types {
+                    Vec::from([map.next_value_seed(TypedDeserializer::with_type(*ty))?])
+                } else {
+                    map.next_value_seed(FieldsVisitor(types))?
+                };
+                tuple_variant(variant, fields)
+            }
+            Variant::Struct(variant) => map.next_value_seed(StructVariantVisitor(variant))?,
+        };
+        if map.next_key::<IgnoredAny>()?.is_some() {
+            return Err(de::Error::invalid_length(2, &self));
+        }
+        Ok(self.finish(variant, value))
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        let (name, data) = data.variant::<String>()?;
+        let variant = self.variant(&name)?;
+        let value = match variant {
+            Variant::Unit(variant) => {
+                data.unit_variant()?;
+                EnumValue::new_unit_variant(variant.name())
+            }
+            Variant::Tuple(variant) => {
+                let types = field_types(variant);
+                let fields = if let [ty] = &*types {
+                    Vec::from([data.newtype_variant_seed(TypedDeserializer::with_type(*ty))?])
+                } else {
+                    data.tuple_variant(types.len(), FieldsVisitor(types))?
+                };
+                tuple_variant(variant, fields)
+            }
+            Variant::Struct(variant) => data.struct_variant(&[], StructVariantVisitor(variant))?,
+        };
+        Ok(self.finish(variant, value))
+    }
+}
+
+struct StructVariantVisitor<'a>(StructVariant<'a>);
+
+impl<'de> DeserializeSeed<'de> for StructVariantVisitor<'_> {
+    type Value = EnumValue;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de> Visitor<'de> for StructVariantVisitor<'_> {
+    type Value = EnumValue;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a map of the fields of `{}`", self.0.name())
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let variant = self.0;
+        let mut value =
+            EnumValue::new_struct_variant_with_capacity(variant.name(), variant.fields_len());
+        visit_named_fields(
+            variant
+                .field_types()
+                .map(|field| (field.name(), field.get_type()))
+                .collect(),
+            map,
+            |name, field| value.set_struct_field(name, field),
+        )?;
+        Ok(value.finish())
+    }
+}
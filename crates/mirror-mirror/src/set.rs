@@ -0,0 +1,46 @@
+use alloc::boxed::Box;
+use core::fmt;
+
+use crate::Reflect;
+
+/// A reflected set type.
+///
+/// Implemented for [`BTreeSet`] and, with the `std` feature, [`HashSet`]. The value type of sets
+/// is `Value::Set`, which always uses a [`BTreeSet`] regardless of the original type.
+///
+/// [`HashSet`] is supported with any hasher that implements `BuildHasher + Default`, such as a
+/// faster non-cryptographic hasher or a seeded hasher for DoS resistance. Since values always use
+/// a [`BTreeSet`] the hasher doesn't affect `to_value` or comparisons between values.
+///
+/// Elements of sets cannot be accessed mutably, as that could change their ordering or hash.
+///
+/// [`BTreeSet`]: alloc::collections::BTreeSet
+/// [`HashSet`]: std::collections::HashSet
+pub trait Set: Reflect {
+    fn contains(&self, value: &dyn Reflect) -> bool;
+
+    /// Insert a value into the set.
+    ///
+    /// Returns `true` if the value wasn't already in the set. Values that cannot be converted
+    /// into the element type are ignored.
+    fn insert(&mut self, value: &dyn Reflect) -> bool;
+
+    /// Remove a value from the set.
+    ///
+    /// Returns `true` if the value was in the set.
+    fn remove(&mut self, value: &dyn Reflect) -> bool;
+
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool;
+
+    fn iter(&self) -> Iter<'_>;
+}
+
+impl fmt::Debug for dyn Set {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_reflect().debug(f)
+    }
+}
+
+pub type Iter<'a> = Box<dyn Iterator<Item = &'a dyn Reflect> + 'a>;
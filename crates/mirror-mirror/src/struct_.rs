@@ -1,3 +1,4 @@
+use alloc::borrow::Cow;
 use alloc::boxed::Box;
 use alloc::collections::BTreeMap;
 use alloc::string::String;
@@ -6,10 +7,15 @@ use core::any::Any;
 use core::fmt;
 use core::iter::FusedIterator;
 
+use crate::iter::IntoPairIter;
 use crate::iter::PairIterMut;
 use crate::type_info::graph::NodeId;
 use crate::type_info::graph::OpaqueNode;
 use crate::type_info::graph::TypeGraph;
+use crate::type_info::value_builder::validate_shape;
+use crate::type_info::ShapeError;
+use crate::type_info::TypeDescriptor;
+use crate::value::Origin;
 use crate::DescribeType;
 use crate::FromReflect;
 use crate::Reflect;
@@ -37,6 +43,19 @@ pub trait Struct: Reflect {
     fn fields_mut(&mut self) -> PairIterMut<'_>;
 
     fn fields_len(&self) -> usize;
+
+    /// Consume the struct and return its fields in order.
+    ///
+    /// [`StructValue`] moves its fields out rather than cloning them. Other implementations,
+    /// including `#[derive(Reflect)]`, clone them by default, since fields can't be moved out of
+    /// types that implement `Drop`.
+    fn into_fields(self: Box<Self>) -> IntoPairIter<'static> {
+        let fields = self
+            .fields()
+            .map(|(name, value)| (String::from(name), value.clone_reflect()))
+            .collect::<Vec<_>>();
+        Box::new(fields.into_iter())
+    }
 }
 
 impl fmt::Debug for dyn Struct {
@@ -52,6 +71,9 @@ pub struct StructValue {
     field_names: Vec<String>,
     // use a `BTreeMap` because `HashMap` isn't `serde::Serialize`
     fields: BTreeMap<String, Value>,
+    #[cfg_attr(feature = "speedy", speedy(skip))]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) origin: Origin,
 }
 
 impl StructValue {
@@ -64,9 +86,58 @@ impl StructValue {
             field_names: Vec::with_capacity(capacity),
             // there is no `BTreeMap::with_capacity` :(
             fields: BTreeMap::new(),
+            origin: Default::default(),
         }
     }
 
+    /// Set the name of the type this value was created from.
+    ///
+    /// Returned by [`Reflect::type_name`] instead of the name of this type. Set by
+    /// `#[derive(Reflect)]`.
+    pub fn with_type_name(mut self, type_name: impl Into<Cow<'static, str>>) -> Self {
+        self.set_type_name(type_name);
+        self
+    }
+
+    pub fn set_type_name(&mut self, type_name: impl Into<Cow<'static, str>>) {
+        self.origin.set_type_name(type_name);
+    }
+
+    /// Set the type this value was created from, whose stable hash is returned by
+    /// [`type_hash`](Self::type_hash). Also sets the type name to `T`'s, unless one is set
+    /// already. Set by `#[derive(Reflect)]`.
+    pub fn with_origin<T>(mut self) -> Self
+    where
+        T: DescribeType,
+    {
+        self.origin.set_type::<T>();
+        self
+    }
+
+    /// The stable hash of the type this value was created from, if known.
+    ///
+    /// This is the hash that type descriptors use to identify the type, so it can be used to look
+    /// the type up in a registry.
+    pub fn type_hash(&self) -> Option<u64> {
+        self.origin.type_hash()
+    }
+
+    pub(crate) fn compact(&mut self) {
+        for name in &mut self.field_names {
+            name.shrink_to_fit();
+        }
+        self.field_names.shrink_to_fit();
+        self.fields = core::mem::take(&mut self.fields)
+            .into_iter()
+            .map(|(mut name, mut value)| {
+                name.shrink_to_fit();
+                value.compact();
+                (name, value)
+            })
+            .collect();
+        self.origin.compact();
+    }
+
     pub fn with_field(mut self, name: impl Into<String>, value: impl Into<Value>) -> Self {
         self.set_field(name, value);
         self
@@ -77,6 +148,44 @@ impl StructValue {
         self.field_names.push(name.clone());
         self.fields.insert(name, value.into());
     }
+
+    /// Check that the value has the shape of the type described by `descriptor`.
+    ///
+    /// Unlike [`FromReflect::from_reflect`], which stops at the first problem, this finds every
+    /// unknown, duplicate, or missing field and every field of the wrong type, including in
+    /// nested values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mirror_mirror::{key_path, DescribeType, Reflect};
+    /// use mirror_mirror::struct_::StructValue;
+    /// use mirror_mirror::type_info::ShapeError;
+    ///
+    /// #[derive(Reflect, Debug, Clone)]
+    /// struct Player {
+    ///     name: String,
+    ///     health: u32,
+    /// }
+    ///
+    /// let value = StructValue::new()
+    ///     .with_field("name", "Bob")
+    ///     .with_field("mana", 10_u32);
+    ///
+    /// let errors = value
+    ///     .validate_against(&<Player as DescribeType>::type_descriptor())
+    ///     .unwrap_err();
+    /// assert_eq!(
+    ///     errors,
+    ///     [
+    ///         ShapeError::UnknownField { path: key_path!(.mana) },
+    ///         ShapeError::MissingField { path: key_path!(.health) },
+    ///     ]
+    /// );
+    /// ```
+    pub fn validate_against(&self, descriptor: &TypeDescriptor) -> Result<(), Vec<ShapeError>> {
+        validate_shape(descriptor.get_type(), self)
+    }
 }
 
 impl DescribeType for StructValue {
@@ -90,6 +199,12 @@ impl DescribeType for StructValue {
 impl Reflect for StructValue {
     trivial_reflect_methods!();
 
+    fn type_name(&self) -> &str {
+        self.origin
+            .type_name()
+            .unwrap_or_else(|| core::any::type_name::<Self>())
+    }
+
     fn patch(&mut self, value: &dyn Reflect) {
         if let Some(struct_) = value.reflect_ref().as_struct() {
             for (name, value) in self.fields_mut() {
@@ -154,6 +269,19 @@ impl Struct for StructValue {
         self.field_names.len()
     }
 
+    fn into_fields(self: Box<Self>) -> IntoPairIter<'static> {
+        let Self {
+            field_names,
+            mut fields,
+            origin: _,
+        } = *self;
+        let iter = field_names.into_iter().filter_map(move |name| {
+            let value = fields.remove(&name)?;
+            Some((name, Box::new(value) as _))
+        });
+        Box::new(iter)
+    }
+
     fn field_at(&self, index: usize) -> Option<&dyn Reflect> {
         let key = self.field_names.get(index)?;
         Some(self.fields.get(key)?)
@@ -172,11 +300,12 @@ impl Struct for StructValue {
 impl FromReflect for StructValue {
     fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
         let struct_ = reflect.reflect_ref().as_struct()?;
-        let this = struct_
+        let mut this = struct_
             .fields()
             .fold(StructValue::default(), |builder, (name, value)| {
                 builder.with_field(name, value.to_value())
             });
+        this.origin = Origin::of::<Self>(reflect);
         Some(this)
     }
 }
@@ -219,11 +348,16 @@ impl<'a> Iterator for Iter<'a> {
         self.index += 1;
         Some((name, value))
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
 }
 
 impl<'a> ExactSizeIterator for Iter<'a> {
     fn len(&self) -> usize {
-        self.struct_.fields_len()
+        self.struct_.fields_len().saturating_sub(self.index)
     }
 }
 
@@ -0,0 +1,671 @@
+use alloc::borrow::ToOwned;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::enum_::EnumValue;
+use crate::key_path::value_to_usize;
+use crate::key_path::GetTypePath;
+use crate::key_path::Key;
+use crate::key_path::KeyPath;
+use crate::key_path::NamedOrNumbered;
+use crate::key_path::ParseKeyPathError;
+use crate::struct_::StructValue;
+use crate::tuple::TupleValue;
+use crate::tuple_struct::TupleStructValue;
+use crate::type_info::Type;
+use crate::type_info::TypeAtPath;
+use crate::type_info::Variant;
+use crate::visit::walk;
+use crate::visit::ReflectVisitor;
+use crate::Enum;
+use crate::Reflect;
+use crate::ReflectRef;
+use crate::ScalarRef;
+use crate::Set;
+use crate::TypeDescriptor;
+use crate::Value;
+
+/// A table of reflected values, flattened into rows with a column per leaf key path.
+///
+/// Every scalar nested inside the values gets a column, named after the [`KeyPath`] to it, as does
+/// every enum, whose cells contain the name of the variant. Columns are ordered by when they were
+/// first seen, so values of the same type get the same columns in the same order. Cells for paths
+/// a value doesn't have, such as fields of other enum variants or elements past the end of a
+/// shorter list, are empty.
+///
+/// Elements of sets cannot be addressed by key paths and opaque values have nothing to print so
+/// neither are included.
+///
+/// Tables can be read back with [`read_delimited`] and [`rows_to_values`].
+///
+/// # Example
+///
+/// ```
+/// use mirror_mirror::{table::Table, Reflect};
+///
+/// #[derive(Reflect, Clone, Debug)]
+/// struct Weapon {
+///     name: String,
+///     damage: f32,
+///     tags: Vec<String>,
+/// }
+///
+/// let weapons = [
+///     Weapon {
+///         name: "Sword".to_owned(),
+///         damage: 10.5,
+///         tags: Vec::from(["sharp".to_owned()]),
+///     },
+///     Weapon {
+///         name: "Big, heavy \"hammer\"".to_owned(),
+///         damage: 20.0,
+///         tags: Vec::new(),
+///     },
+/// ];
+///
+/// let table = Table::new(weapons.iter().map(|weapon| weapon.as_reflect()));
+///
+/// let mut csv = String::new();
+/// table.write_csv(&mut csv).unwrap();
+///
+/// assert_eq!(
+///     csv,
+///     ".name,.damage,.tags[0]\n\
+///      Sword,10.5,sharp\n\
+///      \"Big, heavy \"\"hammer\"\"\",20,\n",
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Table {
+    columns: Vec<KeyPath>,
+    rows: Vec<Vec<Option<String>>>,
+}
+
+impl Table {
+    /// Flatten `values` into a table with a row per value.
+    pub fn new<'a>(values: impl IntoIterator<Item = &'a dyn Reflect>) -> Self {
+        let mut column_indices = BTreeMap::new();
+        let mut columns = Vec::new();
+        let mut rows = Vec::new();
+
+        for value in values {
+            let mut cells = Cells::default();
+            walk(value, &mut cells);
+
+            let mut row = Vec::new();
+            for (path, cell) in cells.cells {
+                let index = *column_indices.entry(path.clone()).or_insert_with(|| {
+                    columns.push(path);
+                    columns.len() - 1
+                });
+                if row.len() <= index {
+                    row.resize(index + 1, None);
+                }
+                row[index] = Some(cell);
+            }
+            rows.push(row);
+        }
+
+        for row in &mut rows {
+            row.resize(columns.len(), None);
+        }
+
+        Self { columns, rows }
+    }
+
+    /// The key paths of the columns, relative to the values of the rows.
+    pub fn columns(&self) -> &[KeyPath] {
+        &self.columns
+    }
+
+    /// The rows of the table, with a cell per column.
+    pub fn rows(&self) -> impl Iterator<Item = &[Option<String>]> {
+        self.rows.iter().map(|row| &row[..])
+    }
+
+    /// The number of rows.
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Whether the table has no rows.
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Write the table as comma separated values, with the column paths as the header.
+    pub fn write_csv(&self, out: &mut dyn fmt::Write) -> fmt::Result {
+        self.write_delimited(out, ',')
+    }
+
+    /// Write the table as tab separated values, with the column paths as the header.
+    pub fn write_tsv(&self, out: &mut dyn fmt::Write) -> fmt::Result {
+        self.write_delimited(out, '\t')
+    }
+
+    /// Write the table with cells separated by `delimiter` and a row per line.
+    ///
+    /// The first line contains the column paths. Cells containing the delimiter, quotes, or line
+    /// breaks are quoted, with quotes doubled, as described in [RFC 4180].
+    ///
+    /// [RFC 4180]: https://www.rfc-editor.org/rfc/rfc4180
+    pub fn write_delimited(&self, out: &mut dyn fmt::Write, delimiter: char) -> fmt::Result {
+        let header = self.columns.iter().map(|path| Some(path.to_string()));
+        write_row(out, header, delimiter)?;
+        for row in &self.rows {
+            write_row(out, row.iter().cloned(), delimiter)?;
+        }
+        Ok(())
+    }
+}
+
+fn write_row(
+    out: &mut dyn fmt::Write,
+    cells: impl Iterator<Item = Option<String>>,
+    delimiter: char,
+) -> fmt::Result {
+    for (index, cell) in cells.enumerate() {
+        if index != 0 {
+            out.write_char(delimiter)?;
+        }
+        let Some(cell) = cell else { continue };
+        if cell.contains([delimiter, '"', '\n', '\r']) {
+            out.write_char('"')?;
+            out.write_str(&cell.replace('"', "\"\""))?;
+            out.write_char('"')?;
+        } else {
+            out.write_str(&cell)?;
+        }
+    }
+    out.write_char('\n')
+}
+
+/// Split delimited text, such as CSV or TSV, into rows of cells.
+///
+/// Quoted cells may contain delimiters, line breaks, and doubled quotes, as written by
+/// [`Table::write_delimited`]. Empty lines are skipped.
+///
+/// # Example
+///
+/// ```
+/// use mirror_mirror::table::read_delimited;
+///
+/// let rows = read_delimited("a,b\n1,\"x, \"\"y\"\"\"\n", ',');
+///
+/// assert_eq!(rows, [["a", "b"], ["1", "x, \"y\""]]);
+/// ```
+pub fn read_delimited(input: &str, delimiter: char) -> Vec<Vec<String>> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut cell = String::new();
+    let mut quoted = false;
+    let mut chars = input.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if quoted {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    cell.push('"');
+                }
+                '"' => quoted = false,
+                _ => cell.push(c),
+            }
+        } else if c == delimiter {
+            row.push(core::mem::take(&mut cell));
+        } else {
+            match c {
+                '"' => quoted = true,
+                '\r' if chars.peek() == Some(&'\n') => {}
+                '\n' | '\r' => {
+                    row.push(core::mem::take(&mut cell));
+                    if row.len() > 1 || !row[0].is_empty() {
+                        rows.push(core::mem::take(&mut row));
+                    } else {
+                        row.clear();
+                    }
+                }
+                _ => cell.push(c),
+            }
+        }
+    }
+
+    if !row.is_empty() || !cell.is_empty() {
+        row.push(cell);
+        rows.push(row);
+    }
+
+    rows
+}
+
+/// Convert rows of cells into [`Value`]s of the type described by `descriptor`.
+///
+/// Each header is the [`KeyPath`] of a column, as written by [`Table`], and must lead to a scalar
+/// or an enum in the type. Scalars are parsed from their cells, and the cells of enum columns
+/// name the variant. Missing cells, and values without any columns, get their default values.
+/// Lists contain as many elements as there are non-empty cells for, and maps contain the keys
+/// with non-empty cells.
+///
+/// The values can be converted to the actual type with [`FromReflect`](crate::FromReflect).
+///
+/// # Example
+///
+/// ```
+/// use mirror_mirror::{table::{read_delimited, rows_to_values}, DescribeType, FromReflect, Reflect};
+///
+/// #[derive(Reflect, Clone, Debug, PartialEq)]
+/// struct Weapon {
+///     name: String,
+///     damage: f32,
+///     tags: Vec<String>,
+/// }
+///
+/// let csv = "\
+///     .name,.damage,.tags[0],.tags[1]\n\
+///     Sword,10.5,sharp,\n\
+///     Hammer,20,,\n\
+/// ";
+/// let rows = read_delimited(csv, ',');
+///
+/// let values = rows_to_values(&rows[0], &rows[1..], &<Weapon as DescribeType>::type_descriptor())
+///     .unwrap();
+///
+/// let weapons = values
+///     .iter()
+///     .map(|value| Weapon::from_reflect(value).unwrap())
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(
+///     weapons,
+///     [
+///         Weapon {
+///             name: "Sword".to_owned(),
+///             damage: 10.5,
+///             tags: Vec::from(["sharp".to_owned()]),
+///         },
+///         Weapon {
+///             name: "Hammer".to_owned(),
+///             damage: 20.0,
+///             tags: Vec::new(),
+///         },
+///     ],
+/// );
+/// ```
+pub fn rows_to_values<H, R, C>(
+    headers: &[H],
+    rows: impl IntoIterator<Item = R>,
+    descriptor: &TypeDescriptor,
+) -> Result<Vec<Value>, RowError>
+where
+    H: AsRef<str>,
+    R: AsRef<[C]>,
+    C: AsRef<str>,
+{
+    let mut columns = Columns::default();
+    for (column, header) in headers.iter().enumerate() {
+        let path = KeyPath::parse(header.as_ref())
+            .map_err(|error| RowError::InvalidHeader { column, error })?;
+        match descriptor.type_at(&path) {
+            Some(TypeAtPath::Scalar(_) | TypeAtPath::Enum(_)) => {}
+            _ => return Err(RowError::UnknownColumn { column, path }),
+        }
+        columns.insert(path.path, column);
+    }
+
+    rows.into_iter()
+        .enumerate()
+        .map(|(row, cells)| {
+            let cells = cells
+                .as_ref()
+                .iter()
+                .map(|cell| Some(cell.as_ref()).filter(|cell| !cell.is_empty()))
+                .collect::<Vec<_>>();
+            let row = Row { index: row, cells };
+            row.build(descriptor.get_type(), Some(&columns))
+        })
+        .collect()
+}
+
+/// Error returned by [`rows_to_values`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RowError {
+    /// A header isn't a valid key path.
+    InvalidHeader {
+        column: usize,
+        error: ParseKeyPathError,
+    },
+    /// A header doesn't lead to a scalar or an enum in the type.
+    UnknownColumn { column: usize, path: KeyPath },
+    /// A cell couldn't be parsed as the type of its column, or an enum cell doesn't name a
+    /// variant.
+    InvalidCell {
+        row: usize,
+        column: usize,
+        expected: String,
+        cell: String,
+    },
+    /// A value wasn't given by any cells and its type doesn't have a default value.
+    MissingDefaultValue { row: usize, type_name: String },
+}
+
+impl fmt::Display for RowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RowError::InvalidHeader { column, error } => {
+                write!(f, "invalid header in column {column}: {error}")
+            }
+            RowError::UnknownColumn { column, path } => write!(
+                f,
+                "column {column} `{path}` doesn't lead to a scalar or an enum"
+            ),
+            RowError::InvalidCell {
+                row,
+                column,
+                expected,
+                cell,
+            } => write!(
+                f,
+                "expected `{expected}` in row {row}, column {column}, found `{cell}`"
+            ),
+            RowError::MissingDefaultValue { row, type_name } => write!(
+                f,
+                "no cells for `{type_name}` in row {row}, which doesn't have a default value"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RowError {}
+
+/// The columns under a key path, as a tree.
+#[derive(Default)]
+struct Columns {
+    column: Option<usize>,
+    children: Vec<(Key, Columns)>,
+}
+
+impl Columns {
+    fn insert(&mut self, path: Vec<Key>, column: usize) {
+        let mut node = self;
+        for key in path {
+            let index = match node.children.iter().position(|(k, _)| *k == key) {
+                Some(index) => index,
+                None => {
+                    node.children.push((key, Columns::default()));
+                    node.children.len() - 1
+                }
+            };
+            node = &mut node.children[index].1;
+        }
+        node.column = Some(column);
+    }
+
+    fn field(&self, field: NamedOrNumbered) -> Option<&Columns> {
+        let key = Key::Field(field);
+        self.children
+            .iter()
+            .find_map(|(k, node)| (*k == key).then_some(node))
+    }
+
+    fn variant(&self, name: &str) -> Option<&Columns> {
+        self.children.iter().find_map(|(key, node)| match key {
+            Key::Variant(variant) if variant == name => Some(node),
+            _ => None,
+        })
+    }
+
+    fn elements(&self) -> impl Iterator<Item = (&Value, &Columns)> {
+        self.children.iter().filter_map(|(key, node)| match key {
+            Key::Get(key) => Some((key, node)),
+            _ => None,
+        })
+    }
+}
+
+struct Row<'a> {
+    index: usize,
+    cells: Vec<Option<&'a str>>,
+}
+
+impl Row<'_> {
+    fn cell(&self, columns: &Columns) -> Option<(usize, &str)> {
+        let column = columns.column?;
+        Some((column, (*self.cells.get(column)?)?))
+    }
+
+    fn has_cells(&self, columns: &Columns) -> bool {
+        self.cell(columns).is_some()
+            || columns
+                .children
+                .iter()
+                .any(|(_, columns)| self.has_cells(columns))
+    }
+
+    fn invalid_cell(&self, column: usize, expected: &str, cell: &str) -> RowError {
+        RowError::InvalidCell {
+            row: self.index,
+            column,
+            expected: expected.to_owned(),
+            cell: cell.to_owned(),
+        }
+    }
+
+    fn default_value(&self, ty: Type<'_>) -> Result<Value, RowError> {
+        ty.default_value()
+            .ok_or_else(|| RowError::MissingDefaultValue {
+                row: self.index,
+                type_name: ty.type_name().to_owned(),
+            })
+    }
+
+    fn build(&self, ty: Type<'_>, columns: Option<&Columns>) -> Result<Value, RowError> {
+        let Some(columns) = columns.filter(|columns| self.has_cells(columns)) else {
+            return self.default_value(ty);
+        };
+
+        let value = match ty {
+            Type::Scalar(scalar_type) => match self.cell(columns) {
+                Some((column, cell)) => scalar_type
+                    .parse_str(cell)
+                    .map_err(|_| self.invalid_cell(column, scalar_type.type_name(), cell))?,
+                None => scalar_type.default_value(),
+            },
+            Type::Struct(struct_type) => {
+                let mut value = StructValue::with_capacity(struct_type.fields_len())
+                    .with_type_name(struct_type.type_name().to_owned());
+                for field in struct_type.field_types() {
+                    let columns = columns.field(NamedOrNumbered::Named(field.name().to_owned()));
+                    value.set_field(field.name(), self.build(field.get_type(), columns)?);
+                }
+                value.into()
+            }
+            Type::TupleStruct(tuple_struct_type) => {
+                let mut value = TupleStructValue::new()
+                    .with_type_name(tuple_struct_type.type_name().to_owned());
+                for (index, field) in tuple_struct_type.field_types().enumerate() {
+                    let columns = columns.field(NamedOrNumbered::Numbered(index));
+                    value.push_field(self.build(field.get_type(), columns)?);
+                }
+                value.into()
+            }
+            Type::Tuple(tuple_type) => {
+                let mut value = TupleValue::new();
+                for (index, field) in tuple_type.field_types().enumerate() {
+                    let columns = columns.field(NamedOrNumbered::Numbered(index));
+                    value.push_field(self.build(field.get_type(), columns)?);
+                }
+                value.into()
+            }
+            Type::Enum(enum_type) => {
+                let variant = match self.cell(columns) {
+                    Some((column, cell)) => enum_type
+                        .variant(cell)
+                        .ok_or_else(|| self.invalid_cell(column, enum_type.type_name(), cell))?,
+                    // without a cell for the enum itself, use the variant that has cells
+                    None => {
+                        let variant = enum_type.variants().find(|variant| {
+                            columns
+                                .variant(variant.name())
+                                .map_or(false, |columns| self.has_cells(columns))
+                        });
+                        match variant {
+                            Some(variant) => variant,
+                            None => return self.default_value(ty),
+                        }
+                    }
+                };
+                self.build_variant(
+                    enum_type.type_name(),
+                    variant,
+                    columns.variant(variant.name()),
+                )?
+            }
+            Type::List(list_type) => {
+                let mut elements = Vec::new();
+                for (key, columns) in columns.elements() {
+                    let Some(index) = value_to_usize(key) else {
+                        continue;
+                    };
+                    if !self.has_cells(columns) {
+                        continue;
+                    }
+                    if elements.len() <= index {
+                        elements.resize(index + 1, None);
+                    }
+                    elements[index] = Some(columns);
+                }
+                let elements = elements
+                    .into_iter()
+                    .map(|columns| self.build(list_type.element_type(), columns))
+                    .collect::<Result<_, _>>()?;
+                Value::List(elements)
+            }
+            Type::Array(array_type) => {
+                let mut elements = Vec::from_iter(core::iter::repeat(None).take(array_type.len()));
+                for (key, columns) in columns.elements() {
+                    if let Some(element) =
+                        value_to_usize(key).and_then(|index| elements.get_mut(index))
+                    {
+                        *element = Some(columns);
+                    }
+                }
+                let elements = elements
+                    .into_iter()
+                    .map(|columns| self.build(array_type.element_type(), columns))
+                    .collect::<Result<_, _>>()?;
+                Value::List(elements)
+            }
+            Type::Map(map_type) => {
+                let mut map = BTreeMap::new();
+                for (key, columns) in columns.elements() {
+                    if !self.has_cells(columns) {
+                        continue;
+                    }
+                    // keys in headers are parsed as `i32`s or strings, so convert them to the
+                    // key type
+                    let key = match (map_type.key_type(), key.reflect_ref()) {
+                        (Type::Scalar(scalar_type), ReflectRef::Scalar(scalar)) => {
+                            scalar_type.parse_str(&format_scalar(scalar)).ok()
+                        }
+                        _ => Some(key.clone()),
+                    };
+                    let Some(key) = key else { continue };
+                    map.insert(key, self.build(map_type.value_type(), Some(columns))?);
+                }
+                Value::Map(map)
+            }
+            Type::Set(_) | Type::Opaque(_) => self.default_value(ty)?,
+        };
+        Ok(value)
+    }
+
+    fn build_variant(
+        &self,
+        type_name: &str,
+        variant: Variant<'_>,
+        columns: Option<&Columns>,
+    ) -> Result<Value, RowError> {
+        let field = |field: NamedOrNumbered| columns.and_then(|columns| columns.field(field));
+
+        let mut value = match variant {
+            Variant::Struct(variant) => {
+                let mut value = EnumValue::new_struct_variant(variant.name());
+                for field_type in variant.field_types() {
+                    let columns = field(NamedOrNumbered::Named(field_type.name().to_owned()));
+                    value.set_struct_field(
+                        field_type.name(),
+                        self.build(field_type.get_type(), columns)?,
+                    );
+                }
+                value.finish()
+            }
+            Variant::Tuple(variant) => {
+                let mut value = EnumValue::new_tuple_variant(variant.name());
+                for (index, field_type) in variant.field_types().enumerate() {
+                    let columns = field(NamedOrNumbered::Numbered(index));
+                    value.push_tuple_field(self.build(field_type.get_type(), columns)?);
+                }
+                value.finish()
+            }
+            Variant::Unit(variant) => EnumValue::new_unit_variant(variant.name()),
+        };
+        value.set_discriminant(variant.discriminant());
+        value.set_type_name(type_name.to_owned());
+        Ok(value.into())
+    }
+}
+
+/// Format a scalar such that it can be parsed back with [`str::parse`].
+fn format_scalar(scalar: ScalarRef<'_>) -> String {
+    match scalar {
+        ScalarRef::usize(n) => n.to_string(),
+        ScalarRef::u8(n) => n.to_string(),
+        ScalarRef::u16(n) => n.to_string(),
+        ScalarRef::u32(n) => n.to_string(),
+        ScalarRef::u64(n) => n.to_string(),
+        ScalarRef::u128(n) => n.to_string(),
+        ScalarRef::i8(n) => n.to_string(),
+        ScalarRef::i16(n) => n.to_string(),
+        ScalarRef::i32(n) => n.to_string(),
+        ScalarRef::i64(n) => n.to_string(),
+        ScalarRef::i128(n) => n.to_string(),
+        ScalarRef::bool(b) => b.to_string(),
+        ScalarRef::char(c) => c.to_string(),
+        ScalarRef::f32(n) => n.to_string(),
+        ScalarRef::f64(n) => n.to_string(),
+        ScalarRef::String(s) => s.to_owned(),
+    }
+}
+
+#[derive(Default)]
+struct Cells {
+    cells: Vec<(KeyPath, String)>,
+    // elements of sets are visited with the path of the set, so everything under them is ignored
+    sets: Vec<KeyPath>,
+}
+
+impl Cells {
+    fn push(&mut self, path: &KeyPath, cell: String) {
+        if !self.sets.iter().any(|set| path.path.starts_with(&set.path)) {
+            self.cells.push((path.clone(), cell));
+        }
+    }
+}
+
+impl ReflectVisitor for Cells {
+    fn visit_enum(&mut self, path: &KeyPath, value: &dyn Enum) {
+        self.push(path, value.variant_name().to_owned());
+    }
+
+    fn visit_set(&mut self, path: &KeyPath, _value: &dyn Set) {
+        self.sets.push(path.clone());
+    }
+
+    fn visit_scalar(&mut self, path: &KeyPath, value: ScalarRef<'_>) {
+        self.push(path, format_scalar(value));
+    }
+}
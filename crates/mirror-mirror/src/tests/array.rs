@@ -1,3 +1,5 @@
+use alloc::vec::Vec;
+
 use crate::DescribeType;
 use crate::FromReflect;
 use crate::Reflect;
@@ -16,3 +18,60 @@ fn from_default() {
 
     assert_eq!(foo, Foo([0, 0, 0, 0, 0]))
 }
+
+#[test]
+fn arbitrary_len() {
+    let array = [7_u8; 300];
+
+    let descriptor = <[u8; 300] as DescribeType>::type_descriptor();
+    assert_eq!(descriptor.get_type().as_array().unwrap().len(), 300);
+
+    let value = array.to_value();
+    assert_eq!(<[u8; 300]>::from_reflect(&value).unwrap(), array);
+    assert!(<[u8; 299]>::from_reflect(&value).is_none());
+
+    let empty: [u8; 0] = [];
+    assert_eq!(<[u8; 0]>::from_reflect(&empty.to_value()).unwrap(), empty);
+}
+
+#[test]
+fn nested_shape() {
+    use crate::array;
+
+    let descriptor = <[[[u8; 2]; 3]; 4] as DescribeType>::type_descriptor();
+    let array_type = descriptor.get_type().as_array().unwrap();
+    assert_eq!(array_type.shape(), [4, 3, 2]);
+    assert_eq!(array_type.flat_len(), 24);
+    assert_eq!(
+        array_type.flat_element_type().type_name(),
+        <u8 as DescribeType>::type_descriptor().type_name()
+    );
+
+    let descriptor = <[Vec<u8>; 3] as DescribeType>::type_descriptor();
+    assert_eq!(descriptor.get_type().as_array().unwrap().shape(), [3]);
+
+    let mut matrix = [[1_i32, 2, 3], [4, 5, 6]];
+    let value = matrix.as_reflect().as_array().unwrap();
+    assert_eq!(array::shape(value), [2, 3]);
+    assert_eq!(array::flat_len(value), 6);
+    assert_eq!(
+        array::to_flat_vec::<i32>(value).unwrap(),
+        [1, 2, 3, 4, 5, 6]
+    );
+    assert!(array::to_flat_vec::<u8>(value).is_none());
+
+    let updated = array::copy_from_flat_slice(
+        matrix.as_reflect_mut().as_array_mut().unwrap(),
+        &[6, 5, 4, 3],
+    );
+    assert_eq!(updated, 4);
+    assert_eq!(matrix, [[6, 5, 4], [3, 5, 6]]);
+
+    for value in array::flat_iter_mut(matrix.as_reflect_mut().as_array_mut().unwrap()) {
+        *value.downcast_mut::<i32>().unwrap() *= 10;
+    }
+    assert_eq!(matrix, [[60, 50, 40], [30, 50, 60]]);
+
+    let empty: [[f32; 4]; 0] = [];
+    assert_eq!(array::shape(empty.as_reflect().as_array().unwrap()), [0]);
+}
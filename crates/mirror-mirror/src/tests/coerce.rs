@@ -0,0 +1,273 @@
+use alloc::borrow::ToOwned;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::coerce::Coercion;
+use crate::coerce::Coercions;
+use crate::enum_::EnumValue;
+use crate::key_path;
+use crate::key_path::GetPath;
+use crate::struct_::StructValue;
+use crate::type_info::Type;
+use crate::DescribeType;
+use crate::FromReflect;
+use crate::Reflect;
+use crate::Value;
+
+#[derive(Reflect, Clone, Debug, PartialEq)]
+#[reflect(crate_name(crate))]
+struct Player {
+    name: String,
+    health: u8,
+    speed: f32,
+    alive: bool,
+    team: Team,
+    state: State,
+}
+
+#[derive(Reflect, Clone, Debug, PartialEq)]
+#[reflect(crate_name(crate))]
+enum Team {
+    Red,
+    Blue { score: u32 },
+}
+
+#[derive(Reflect, Clone, Debug, PartialEq)]
+#[reflect(crate_name(crate))]
+#[repr(u8)]
+enum State {
+    Idle = 1,
+    Running = 5,
+}
+
+fn player() -> Player {
+    Player {
+        name: "bob".to_owned(),
+        health: 10,
+        speed: 1.0,
+        alive: true,
+        team: Team::Red,
+        state: State::Idle,
+    }
+}
+
+#[test]
+fn numbers() {
+    let coercions = Coercions::new().with(Coercion::NumberToNumber);
+
+    assert_eq!(coercions.coerce_to::<u8>(&200_i64), Some(200));
+    assert_eq!(coercions.coerce_to::<u8>(&256_i64), None);
+    assert_eq!(coercions.coerce_to::<u8>(&-1_i32), None);
+    assert_eq!(coercions.coerce_to::<i32>(&3.0_f64), Some(3));
+    assert_eq!(coercions.coerce_to::<i32>(&3.5_f64), None);
+    assert_eq!(coercions.coerce_to::<i32>(&f64::NAN), None);
+    assert_eq!(coercions.coerce_to::<u64>(&1e30_f64), None);
+    assert_eq!(coercions.coerce_to::<f32>(&3_u8), Some(3.0));
+    assert_eq!(coercions.coerce_to::<f32>(&0.5_f64), Some(0.5));
+    assert_eq!(coercions.coerce_to::<f32>(&1e300_f64), None);
+
+    // other coercions aren't enabled
+    assert_eq!(coercions.coerce_to::<u8>(&"1".to_owned()), None);
+    assert_eq!(coercions.coerce_to::<bool>(&1_u8), None);
+}
+
+#[test]
+fn strings() {
+    let coercions = Coercions::all();
+
+    assert_eq!(coercions.coerce_to::<u8>(&" 42 ".to_owned()), Some(42));
+    assert_eq!(coercions.coerce_to::<u8>(&"-1".to_owned()), None);
+    assert_eq!(coercions.coerce_to::<bool>(&"TRUE".to_owned()), Some(true));
+    assert_eq!(coercions.coerce_to::<bool>(&"yes".to_owned()), None);
+    assert_eq!(coercions.coerce_to::<char>(&"x".to_owned()), Some('x'));
+    assert_eq!(coercions.coerce_to::<char>(&"xy".to_owned()), None);
+
+    assert_eq!(
+        coercions.coerce_to::<String>(&1.5_f32),
+        Some("1.5".to_owned())
+    );
+    assert_eq!(
+        coercions.coerce_to::<String>(&false),
+        Some("false".to_owned())
+    );
+    assert_eq!(coercions.coerce_to::<String>(&'x'), Some("x".to_owned()));
+}
+
+#[test]
+fn bools() {
+    let coercions = Coercions::all();
+
+    assert_eq!(coercions.coerce_to::<bool>(&0_i32), Some(false));
+    assert_eq!(coercions.coerce_to::<bool>(&-3_i32), Some(true));
+    assert_eq!(coercions.coerce_to::<bool>(&f32::NAN), Some(false));
+    assert_eq!(coercions.coerce_to::<u8>(&true), Some(1));
+    assert_eq!(coercions.coerce_to::<f64>(&false), Some(0.0));
+
+    let coercions = coercions.without(Coercion::BoolToNumber);
+    assert_eq!(coercions.coerce_to::<u8>(&true), None);
+}
+
+#[test]
+fn variants() {
+    let coercions = Coercions::all();
+
+    assert_eq!(
+        coercions.coerce_to::<Team>(&"Red".to_owned()),
+        Some(Team::Red)
+    );
+    assert_eq!(
+        coercions.coerce_to::<Team>(&"Blue".to_owned()),
+        Some(Team::Blue { score: 0 })
+    );
+    assert_eq!(coercions.coerce_to::<Team>(&"Green".to_owned()), None);
+    assert_eq!(
+        coercions.coerce_to::<Team>(&1_i32),
+        Some(Team::Blue { score: 0 })
+    );
+    assert_eq!(coercions.coerce_to::<Team>(&2_i32), None);
+
+    // enums with a `#[repr(...)]` use the discriminant
+    assert_eq!(coercions.coerce_to::<State>(&5_u8), Some(State::Running));
+    assert_eq!(coercions.coerce_to::<State>(&0_u8), None);
+
+    assert_eq!(
+        coercions.coerce_to::<Team>(&Team::Blue { score: 3 }),
+        Some(Team::Blue { score: 3 })
+    );
+}
+
+#[test]
+fn custom_rules() {
+    fn percent(value: &dyn Reflect, ty: Type<'_>) -> Option<Value> {
+        let percent = value.downcast_ref::<String>()?.strip_suffix('%')?;
+        let _ = ty.as_scalar()?;
+        let n = percent.parse::<f32>().ok()?;
+        Some((n / 100.0).into())
+    }
+
+    let coercions = Coercions::new().with_rule(percent);
+    assert_eq!(coercions.coerce_to::<f32>(&"50%".to_owned()), Some(0.5));
+    assert_eq!(coercions.coerce_to::<f32>(&"50".to_owned()), None);
+}
+
+#[test]
+fn set_at_coerced() {
+    let coercions = Coercions::all();
+    let mut player = player();
+
+    player
+        .set_at_coerced(&key_path!(.health), &"7".to_owned(), &coercions)
+        .unwrap();
+    player
+        .set_at_coerced(&key_path!(.speed), &2_i64, &coercions)
+        .unwrap();
+    player
+        .set_at_coerced(&key_path!(.alive), &0_u8, &coercions)
+        .unwrap();
+    player
+        .set_at_coerced(&key_path!(.name), &1_u8, &coercions)
+        .unwrap();
+    player
+        .set_at_coerced(&key_path!(.team), &"Blue".to_owned(), &coercions)
+        .unwrap();
+    player
+        .set_at_coerced(&key_path!(.state), &5_i32, &coercions)
+        .unwrap();
+
+    assert_eq!(
+        player,
+        Player {
+            name: "1".to_owned(),
+            health: 7,
+            speed: 2.0,
+            alive: false,
+            team: Team::Blue { score: 0 },
+            state: State::Running,
+        }
+    );
+
+    let error = player
+        .set_at_coerced(&key_path!(.health), &"lots".to_owned(), &coercions)
+        .unwrap_err();
+    assert_eq!(
+        error.expected(),
+        <u8 as DescribeType>::type_descriptor().type_name()
+    );
+    assert_eq!(error.path(), &key_path!(.health));
+
+    assert!(player
+        .set_at_coerced(&key_path!(.missing), &1_u8, &coercions)
+        .is_err());
+
+    // nothing is coerced without coercions
+    assert!(player
+        .set_at_coerced(&key_path!(.health), &1_i32, &Coercions::new())
+        .is_err());
+    assert_eq!(player.health, 7);
+}
+
+#[test]
+fn from_reflect_coerced() {
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    #[reflect(crate_name(crate))]
+    enum Shape {
+        Circle { radius: f32 },
+        Rect(u16, u16),
+    }
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    #[reflect(crate_name(crate))]
+    struct Scene {
+        shapes: Vec<Shape>,
+        weights: BTreeMap<String, f32>,
+        id: (u8, i16),
+    }
+
+    let value = StructValue::new()
+        .with_field(
+            "shapes",
+            Value::List(vec![
+                EnumValue::new_struct_variant("Circle")
+                    .with_struct_field("radius", 2_i64)
+                    .finish()
+                    .into(),
+                EnumValue::new_tuple_variant("Rect")
+                    .with_tuple_field(3_i64)
+                    .with_tuple_field(4.0_f64)
+                    .finish()
+                    .into(),
+            ]),
+        )
+        .with_field("weights", BTreeMap::from([("a".to_owned(), 0.25_f64)]))
+        .with_field("id", (1_u64, -1_i64).to_value());
+
+    assert!(Scene::from_reflect(&value).is_none());
+    assert_eq!(
+        Scene::from_reflect_coerced(&value).unwrap(),
+        Scene {
+            shapes: vec![Shape::Circle { radius: 2.0 }, Shape::Rect(3, 4)],
+            weights: BTreeMap::from([("a".to_owned(), 0.25)]),
+            id: (1, -1),
+        }
+    );
+
+    // overflow
+    let mut overflowing = value.clone();
+    overflowing
+        .set_at(&key_path!(.shapes[1_usize].0), 70_000_i64)
+        .unwrap();
+    let error = Scene::from_reflect_coerced(&overflowing).unwrap_err();
+    assert_eq!(error.expected(), "u16");
+    assert_eq!(error.found(), Some("i64"));
+    assert_eq!(error.path(), &key_path!(.shapes[1_usize]::Rect.0));
+
+    // fractional part
+    let mut fractional = value;
+    fractional
+        .set_at(&key_path!(.shapes[1_usize].1), 4.5_f64)
+        .unwrap();
+    let error = Scene::from_reflect_coerced(&fractional).unwrap_err();
+    assert_eq!(error.path(), &key_path!(.shapes[1_usize]::Rect.1));
+}
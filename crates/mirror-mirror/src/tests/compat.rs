@@ -0,0 +1,334 @@
+use alloc::borrow::ToOwned;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+use crate::key_path;
+use crate::type_info::compat::check;
+use crate::type_info::compat::migrate;
+use crate::type_info::compat::CompatChange;
+use crate::DescribeType;
+use crate::FromReflect;
+use crate::Reflect;
+
+mod v1 {
+    use super::*;
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    #[reflect(crate_name(crate))]
+    pub struct Level {
+        pub name: String,
+        pub units: Vec<Unit>,
+        pub lookup: BTreeMap<String, Unit>,
+        pub mode: Mode,
+        pub size: [u8; 2],
+    }
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    #[reflect(crate_name(crate))]
+    pub struct Unit {
+        pub hp: u32,
+        pub speed: f32,
+        pub label: String,
+    }
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    #[reflect(crate_name(crate))]
+    pub enum Mode {
+        Edit,
+        Play(u32),
+        Replay { file: String },
+    }
+}
+
+mod v2 {
+    use super::*;
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    #[reflect(crate_name(crate))]
+    pub struct Level {
+        pub title: String,
+        pub units: Vec<Unit>,
+        pub lookup: BTreeMap<String, Unit>,
+        pub mode: Mode,
+        pub size: [u8; 3],
+    }
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    #[reflect(crate_name(crate))]
+    pub struct Unit {
+        pub health: u32,
+        pub speed: f64,
+        pub team: u8,
+    }
+
+    #[derive(Reflect, Clone, Debug, PartialEq, Default)]
+    #[reflect(crate_name(crate))]
+    pub enum Mode {
+        #[default]
+        Edit,
+        Play(u32, bool),
+        Replay(String),
+        Spectate,
+    }
+}
+
+#[test]
+fn identical() {
+    let descriptor = <v1::Level as DescribeType>::type_descriptor();
+    let report = check(&descriptor, &descriptor);
+    assert!(report.is_empty());
+    assert!(report.is_migratable());
+}
+
+#[test]
+fn changes() {
+    let old = <v1::Level as DescribeType>::type_descriptor();
+    let new = <v2::Level as DescribeType>::type_descriptor();
+
+    let report = check(&old, &new);
+    assert_eq!(
+        report.changes(),
+        [
+            CompatChange::FieldRenamed {
+                path: key_path!(.name),
+                to: "title".to_owned(),
+            },
+            CompatChange::FieldRenamed {
+                path: key_path!(.units[0_usize].hp),
+                to: "health".to_owned(),
+            },
+            CompatChange::TypeChanged {
+                path: key_path!(.units[0_usize].speed),
+                from: "f32".to_owned(),
+                to: "f64".to_owned(),
+            },
+            CompatChange::FieldRemoved {
+                path: key_path!(.units[0_usize].label),
+            },
+            CompatChange::FieldAdded {
+                path: key_path!(.units[0_usize].team),
+                has_default: true,
+            },
+            CompatChange::FieldRenamed {
+                path: key_path!(.lookup[""].hp),
+                to: "health".to_owned(),
+            },
+            CompatChange::TypeChanged {
+                path: key_path!(.lookup[""].speed),
+                from: "f32".to_owned(),
+                to: "f64".to_owned(),
+            },
+            CompatChange::FieldRemoved {
+                path: key_path!(.lookup[""].label),
+            },
+            CompatChange::FieldAdded {
+                path: key_path!(.lookup[""].team),
+                has_default: true,
+            },
+            CompatChange::FieldAdded {
+                path: key_path!(.mode::Play.1),
+                has_default: true,
+            },
+            CompatChange::VariantKindChanged {
+                path: key_path!(.mode::Replay),
+            },
+            CompatChange::VariantAdded {
+                path: key_path!(.mode::Spectate),
+            },
+            CompatChange::TypeChanged {
+                path: key_path!(.size),
+                from: "[u8; 2]".to_owned(),
+                to: "[u8; 3]".to_owned(),
+            },
+        ]
+    );
+    assert!(!report.is_migratable());
+
+    assert_eq!(
+        report.changes()[0].to_string(),
+        "renamed field `.name` to `title`"
+    );
+
+    // going back
+    let report = check(&new, &old);
+    assert!(report.iter().any(|change| change
+        == &CompatChange::VariantRemoved {
+            path: key_path!(.mode::Spectate),
+        }));
+    assert!(report.iter().any(|change| change
+        == &CompatChange::FieldRemoved {
+            path: key_path!(.mode::Play.1),
+        }));
+}
+
+#[test]
+fn ambiguous_renames_are_not_detected() {
+    #[derive(Reflect, Clone, Debug)]
+    #[reflect(crate_name(crate))]
+    struct Old {
+        a: u32,
+        b: u32,
+        c: f32,
+    }
+
+    #[derive(Reflect, Clone, Debug)]
+    #[reflect(crate_name(crate))]
+    struct New {
+        c: f32,
+        z: f32,
+        x: u32,
+        y: u32,
+    }
+
+    let report = check(
+        &<Old as DescribeType>::type_descriptor(),
+        &<New as DescribeType>::type_descriptor(),
+    );
+    // `a` and `b` could each be `x` or `y`, but `z` is the only new `f32`
+    assert_eq!(
+        report.changes(),
+        [
+            CompatChange::FieldRemoved {
+                path: key_path!(.a),
+            },
+            CompatChange::FieldRemoved {
+                path: key_path!(.b),
+            },
+            CompatChange::FieldAdded {
+                path: key_path!(.z),
+                has_default: true,
+            },
+            CompatChange::FieldAdded {
+                path: key_path!(.x),
+                has_default: true,
+            },
+            CompatChange::FieldAdded {
+                path: key_path!(.y),
+                has_default: true,
+            },
+        ]
+    );
+
+    // renamed fields are paired up by position first
+    #[derive(Reflect, Clone, Debug)]
+    #[reflect(crate_name(crate))]
+    struct Renamed {
+        x: u32,
+        y: u32,
+        c: f32,
+    }
+
+    let report = check(
+        &<Old as DescribeType>::type_descriptor(),
+        &<Renamed as DescribeType>::type_descriptor(),
+    );
+    assert_eq!(
+        report.changes(),
+        [
+            CompatChange::FieldRenamed {
+                path: key_path!(.a),
+                to: "x".to_owned(),
+            },
+            CompatChange::FieldRenamed {
+                path: key_path!(.b),
+                to: "y".to_owned(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn recursive_types() {
+    #[derive(Reflect, Clone, Debug)]
+    #[reflect(crate_name(crate))]
+    struct Old {
+        children: Vec<Old>,
+    }
+
+    #[derive(Reflect, Clone, Debug)]
+    #[reflect(crate_name(crate))]
+    struct New {
+        children: Vec<New>,
+        id: u32,
+    }
+
+    let report = check(
+        &<Old as DescribeType>::type_descriptor(),
+        &<New as DescribeType>::type_descriptor(),
+    );
+    assert_eq!(
+        report.changes(),
+        [CompatChange::FieldAdded {
+            path: key_path!(.id),
+            has_default: true,
+        }]
+    );
+}
+
+#[test]
+fn migrate_values() {
+    let old = <v1::Level as DescribeType>::type_descriptor();
+    let new = <v2::Level as DescribeType>::type_descriptor();
+
+    let unit = v1::Unit {
+        hp: 10,
+        speed: 1.5,
+        label: "a".to_owned(),
+    };
+    let level = v1::Level {
+        name: "level".to_owned(),
+        units: Vec::from([unit.clone()]),
+        lookup: BTreeMap::from([("b".to_owned(), unit)]),
+        mode: v1::Mode::Replay {
+            file: "replay".to_owned(),
+        },
+        size: [1, 2],
+    };
+
+    let migrated = migrate(level.to_value(), &old, &new);
+    let unit = v2::Unit {
+        health: 10,
+        speed: 1.5,
+        team: 0,
+    };
+    assert_eq!(
+        v2::Level::from_reflect(&migrated).unwrap(),
+        v2::Level {
+            title: "level".to_owned(),
+            units: Vec::from([unit.clone()]),
+            lookup: BTreeMap::from([("b".to_owned(), unit)]),
+            // the variant changed, so the default is used
+            mode: v2::Mode::Edit,
+            size: [1, 2, 0],
+        }
+    );
+
+    // fields without default values are left out
+    let migrated = migrate(
+        v1::Mode::Play(1).to_value(),
+        &<v1::Mode as DescribeType>::type_descriptor(),
+        &<v2::Mode as DescribeType>::type_descriptor(),
+    );
+    assert_eq!(
+        v2::Mode::from_reflect(&migrated).unwrap(),
+        v2::Mode::Play(1, false)
+    );
+}
+
+#[test]
+fn migrate_scalars() {
+    let migrate_to = |value: &dyn Reflect| {
+        migrate(
+            value.to_value(),
+            &value.type_descriptor(),
+            &<u8 as DescribeType>::type_descriptor(),
+        )
+    };
+
+    assert_eq!(u8::from_reflect(&migrate_to(&7_i64)), Some(7));
+    assert_eq!(u8::from_reflect(&migrate_to(&"12".to_owned())), Some(12));
+    // doesn't fit, so the default is used
+    assert_eq!(u8::from_reflect(&migrate_to(&1000_i64)), Some(0));
+}
@@ -0,0 +1,93 @@
+use alloc::collections::BTreeMap;
+
+use crate::conformance::check_roundtrip;
+use crate::conformance::check_roundtrip_through;
+use crate::conformance::Check;
+use crate::FromReflect;
+use crate::Reflect;
+use crate::Value;
+
+#[derive(Reflect, Clone, Debug)]
+#[reflect(crate_name(crate))]
+struct Foo {
+    a: i32,
+    b: Vec<String>,
+    c: BTreeMap<String, Bar>,
+}
+
+#[derive(Reflect, Clone, Debug)]
+#[reflect(crate_name(crate))]
+enum Bar {
+    A { x: f32 },
+    B(u8, bool),
+    C,
+}
+
+#[test]
+fn derived_impls_pass() {
+    check_roundtrip([
+        Foo {
+            a: 1,
+            b: Vec::new(),
+            c: BTreeMap::new(),
+        },
+        Foo {
+            a: -1,
+            b: Vec::from(["foo".to_owned(), "bar".to_owned()]),
+            c: BTreeMap::from([
+                ("a".to_owned(), Bar::A { x: 1.0 }),
+                ("b".to_owned(), Bar::B(1, true)),
+                ("c".to_owned(), Bar::C),
+            ]),
+        },
+    ])
+    .unwrap();
+
+    check_roundtrip([(1_u8, 'a'), (2, 'b')]).unwrap();
+    check_roundtrip([[Some(1_i64), None]]).unwrap();
+}
+
+#[derive(Reflect, Clone, Debug)]
+#[reflect(crate_name(crate), opt_out(FromReflect))]
+struct Broken {
+    a: i32,
+    b: i32,
+}
+
+impl FromReflect for Broken {
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        let struct_ = reflect.reflect_ref().as_struct()?;
+        Some(Self {
+            a: i32::from_reflect(struct_.field("a")?)?,
+            b: 0,
+        })
+    }
+}
+
+#[test]
+fn broken_from_reflect_fails() {
+    check_roundtrip([Broken { a: 1, b: 0 }]).unwrap();
+
+    let err = check_roundtrip([Broken { a: 1, b: 0 }, Broken { a: 1, b: 2 }]).unwrap_err();
+    assert_eq!(err.sample(), 1);
+    assert_eq!(err.check(), Check::FromReflect);
+}
+
+#[test]
+fn through_format() {
+    let samples = [Bar::A { x: 1.0 }, Bar::B(1, false), Bar::C];
+
+    check_roundtrip_through(samples.clone(), |value| Ok::<_, &str>(value.clone())).unwrap();
+
+    let err = check_roundtrip_through(samples.clone(), |_| Err("nope")).unwrap_err();
+    assert_eq!(err.sample(), 0);
+    assert_eq!(err.check(), Check::Format);
+    assert_eq!(err.details(), "nope");
+
+    let err = check_roundtrip_through(samples, |value| match value {
+        Value::EnumValue(_) => Ok::<_, &str>(1_i32.to_value()),
+        _ => Ok(value.clone()),
+    })
+    .unwrap_err();
+    assert_eq!(err.check(), Check::Format);
+}
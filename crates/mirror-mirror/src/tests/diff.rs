@@ -0,0 +1,145 @@
+use alloc::collections::BTreeMap;
+use alloc::collections::BTreeSet;
+
+use crate::diff::apply;
+use crate::diff::diff;
+use crate::diff::Change;
+use crate::key_path;
+use crate::Reflect;
+use crate::Value;
+
+#[derive(Reflect, Clone, Debug, PartialEq)]
+#[reflect(crate_name(crate))]
+struct State {
+    health: u32,
+    position: (f32, f32),
+    inventory: Vec<String>,
+    stats: BTreeMap<String, i32>,
+    tags: BTreeSet<String>,
+    mode: Mode,
+}
+
+#[derive(Reflect, Clone, Debug, PartialEq)]
+#[reflect(crate_name(crate))]
+enum Mode {
+    Idle,
+    Moving { speed: f32 },
+}
+
+fn state() -> State {
+    State {
+        health: 100,
+        position: (0.0, 0.0),
+        inventory: Vec::from(["sword".to_owned(), "shield".to_owned()]),
+        stats: BTreeMap::from([("str".to_owned(), 10), ("dex".to_owned(), 5)]),
+        tags: BTreeSet::from(["player".to_owned()]),
+        mode: Mode::Moving { speed: 1.0 },
+    }
+}
+
+#[test]
+fn equal_values_have_empty_diff() {
+    assert!(diff(&state(), &state()).is_empty());
+}
+
+#[test]
+fn only_changed_fields() {
+    let a = state();
+    let mut b = state();
+    b.health = 50;
+    b.position.1 = 2.0;
+    b.mode = Mode::Moving { speed: 2.0 };
+
+    let changes = diff(&a, &b);
+    assert_eq!(
+        changes.changes(),
+        [
+            Change::Set {
+                path: key_path!(.health),
+                value: 50_u32.to_value(),
+            },
+            Change::Set {
+                path: key_path!(.position.1),
+                value: 2.0_f32.to_value(),
+            },
+            Change::Set {
+                path: key_path!(.mode.speed),
+                value: 2.0_f32.to_value(),
+            },
+        ]
+    );
+
+    let mut value = a;
+    apply(&mut value, &changes);
+    assert_eq!(value, b);
+}
+
+#[test]
+fn lists_maps_and_sets() {
+    let a = state();
+    let mut b = state();
+    b.inventory.pop();
+    b.stats.remove("dex");
+    b.stats.insert("int".to_owned(), 3);
+    b.tags.insert("admin".to_owned());
+
+    let changes = diff(&a, &b);
+    assert!(changes.iter().any(|change| matches!(
+        change,
+        Change::Truncate { path, len: 1 } if *path == key_path!(.inventory)
+    )));
+    assert!(changes.iter().any(|change| matches!(
+        change,
+        Change::Remove { key, .. } if *key == Value::from("dex")
+    )));
+    assert!(changes.iter().any(|change| matches!(
+        change,
+        Change::Insert { key, .. } if *key == Value::from("int")
+    )));
+
+    let mut value = a.clone();
+    apply(&mut value, &changes);
+    assert_eq!(value, b);
+
+    // and back again
+    let mut value = b.clone();
+    apply(&mut value, &diff(&b, &a));
+    assert_eq!(value, a);
+}
+
+#[test]
+fn variant_change() {
+    let a = state();
+    let mut b = state();
+    b.mode = Mode::Idle;
+
+    let changes = diff(&a, &b);
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes.changes()[0].path(), &key_path!(.mode));
+
+    let mut value = a;
+    apply(&mut value, &changes);
+    assert_eq!(value, b);
+}
+
+#[test]
+fn root_value() {
+    let mut value = 1_i32;
+    apply(&mut value, &diff(&1_i32, &2_i32));
+    assert_eq!(value, 2);
+}
+
+#[test]
+fn array_against_value() {
+    let mut value = [1_i32, 2, 3];
+    let changes = diff(&value, &[1_i32, 5, 3].to_value());
+    assert_eq!(
+        changes.changes(),
+        [Change::Set {
+            path: key_path!([1_usize]),
+            value: 5_i32.to_value(),
+        }]
+    );
+    apply(&mut value, &changes);
+    assert_eq!(value, [1, 5, 3]);
+}
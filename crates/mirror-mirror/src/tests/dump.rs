@@ -0,0 +1,115 @@
+use alloc::collections::BTreeMap;
+
+use crate::dump::diff;
+use crate::dump::Dump;
+use crate::dump::DumpError;
+use crate::dump::EntryDiff;
+use crate::key_path;
+use crate::Reflect;
+use crate::Value;
+
+#[derive(Reflect, Clone, Debug, PartialEq)]
+#[reflect(crate_name(crate))]
+struct World {
+    seed: u64,
+    names: Vec<String>,
+    scores: BTreeMap<String, i32>,
+}
+
+fn world() -> World {
+    World {
+        seed: 1,
+        names: Vec::from(["alice".to_owned()]),
+        scores: BTreeMap::from([("alice".to_owned(), 10)]),
+    }
+}
+
+#[test]
+fn roundtrip() {
+    let dump = Dump::new()
+        .with_value("world", &world())
+        .with_value("frame", &3_u32);
+    let bytes = dump.write_to_vec().unwrap();
+    let read = Dump::read_from_buffer(&bytes).unwrap();
+    assert_eq!(read, dump);
+
+    let entry = read.get("world").unwrap();
+    assert_eq!(entry.value(), &world().to_value());
+    assert!(entry.content_hash().is_some());
+    assert!(entry.descriptor().as_struct().is_some());
+
+    assert!(matches!(
+        Dump::read_from_buffer(&bytes[..4]),
+        Err(DumpError::MissingHeader)
+    ));
+
+    let mut bytes = bytes;
+    bytes[6] = 2;
+    assert!(matches!(
+        Dump::read_from_buffer(&bytes),
+        Err(DumpError::UnsupportedVersion(2))
+    ));
+}
+
+#[test]
+fn content_hash_is_stable() {
+    let a = Dump::new().with_value("world", &world());
+    let b = Dump::new().with_value("world", &world());
+    assert_eq!(
+        a.get("world").unwrap().content_hash(),
+        b.get("world").unwrap().content_hash()
+    );
+    assert!(diff(&a, &b).is_empty());
+}
+
+#[test]
+fn diffs_entries() {
+    let old = Dump::new()
+        .with_value("world", &world())
+        .with_value("frame", &3_u32)
+        .with_value("removed", &true);
+
+    let mut new_world = world();
+    new_world.seed = 2;
+    new_world.names.push("bob".to_owned());
+    let new = Dump::new()
+        .with_value("world", &new_world)
+        .with_value("frame", &3_u64)
+        .with_value("added", &'a');
+
+    let diff = diff(&old, &new);
+    let names = diff.iter().map(EntryDiff::name).collect::<Vec<_>>();
+    assert_eq!(names, ["added", "frame", "removed", "world"]);
+
+    assert!(matches!(diff.entries()[0], EntryDiff::Added { .. }));
+    assert!(matches!(diff.entries()[1], EntryDiff::TypeChanged { .. }));
+    assert!(matches!(diff.entries()[2], EntryDiff::Removed { .. }));
+
+    let EntryDiff::Changed { paths, .. } = &diff.entries()[3] else {
+        panic!("expected a change, got {:?}", diff.entries()[3]);
+    };
+    assert_eq!(paths.len(), 2);
+
+    assert_eq!(paths[0].path(), &key_path!(.seed));
+    assert_eq!(paths[0].old_value(), Some(&Value::from(1_u64)));
+    assert_eq!(paths[0].new_value(), Some(&Value::from(2_u64)));
+
+    assert_eq!(paths[1].path(), &key_path!(.names));
+    assert!(paths[1].new_size().unwrap() > paths[1].old_size().unwrap());
+    assert!(diff.entries()[3].size_delta() > 0);
+
+    let report = diff.to_string();
+    assert!(report.contains("+ added"));
+    assert!(report.contains("- removed"));
+    assert!(report.contains("world.names"));
+}
+
+#[test]
+fn files() {
+    let path = std::env::temp_dir().join(format!("mirror-mirror-dump-{}", std::process::id()));
+    let dump = Dump::new().with_value("world", &world());
+    dump.write_to_file(&path).unwrap();
+    let read = Dump::read_from_file(&path);
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(read.unwrap(), dump);
+}
@@ -0,0 +1,212 @@
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::edit::EditCommand;
+use crate::edit::Transaction;
+use crate::enum_::EnumValue;
+use crate::key_path;
+use crate::key_path::KeyPath;
+use crate::Reflect;
+use crate::Value;
+
+#[derive(Reflect, Clone, Debug, PartialEq)]
+#[reflect(crate_name(crate))]
+struct Scene {
+    name: String,
+    color: [f32; 3],
+    units: Vec<Unit>,
+    tags: BTreeMap<String, i32>,
+    mode: Mode,
+}
+
+#[derive(Reflect, Clone, Debug, PartialEq)]
+#[reflect(crate_name(crate))]
+struct Unit {
+    health: u32,
+}
+
+#[derive(Reflect, Clone, Debug, PartialEq)]
+#[reflect(crate_name(crate))]
+enum Mode {
+    Edit,
+    Play { speed: f32 },
+}
+
+fn scene() -> Scene {
+    Scene {
+        name: "level".into(),
+        color: [1.0, 1.0, 1.0],
+        units: Vec::from([Unit { health: 10 }, Unit { health: 20 }]),
+        tags: BTreeMap::from([("a".into(), 1)]),
+        mode: Mode::Edit,
+    }
+}
+
+#[test]
+fn apply_and_revert() {
+    let mut value = scene();
+
+    let command = EditCommand::new(&value, key_path!(.units[1_usize].health), 5_u32).unwrap();
+    assert_eq!(command.old, Value::from(20_u32));
+    assert_eq!(command.new, Value::from(5_u32));
+    assert_eq!(value, scene());
+
+    command.apply(&mut value).unwrap();
+    assert_eq!(value.units[1].health, 5);
+
+    command.revert(&mut value).unwrap();
+    assert_eq!(value, scene());
+
+    command.inverse().apply(&mut value).unwrap();
+    assert_eq!(value, scene());
+    command.inverse().revert(&mut value).unwrap();
+    assert_eq!(value.units[1].health, 5);
+}
+
+#[test]
+fn replaces_whole_values() {
+    let mut value = scene();
+
+    let mut transaction = Transaction::new();
+    transaction
+        .record(
+            &mut value,
+            key_path!(.units),
+            Vec::from([Unit { health: 1 }]),
+        )
+        .unwrap();
+    transaction
+        .record(
+            &mut value,
+            key_path!(.tags),
+            BTreeMap::from([(String::from("b"), 2)]),
+        )
+        .unwrap();
+    transaction
+        .record(&mut value, key_path!(.color), [0.0_f32, 0.5, 1.0])
+        .unwrap();
+    transaction
+        .record(
+            &mut value,
+            key_path!(.mode),
+            EnumValue::new_struct_variant("Play")
+                .with_struct_field("speed", 2.0_f32)
+                .finish(),
+        )
+        .unwrap();
+
+    assert_eq!(value.units, [Unit { health: 1 }]);
+    assert_eq!(value.tags, BTreeMap::from([("b".into(), 2)]));
+    assert_eq!(value.color, [0.0, 0.5, 1.0]);
+    assert_eq!(value.mode, Mode::Play { speed: 2.0 });
+
+    transaction.revert(&mut value).unwrap();
+    assert_eq!(value, scene());
+
+    transaction.apply(&mut value).unwrap();
+    assert_eq!(value.units, [Unit { health: 1 }]);
+}
+
+#[test]
+fn root_path() {
+    let mut value = scene();
+    let mut other = scene();
+    other.name = "other".into();
+
+    let command = EditCommand::new(&value, KeyPath::default(), other.to_value()).unwrap();
+    command.apply(&mut value).unwrap();
+    assert_eq!(value, other);
+    command.revert(&mut value).unwrap();
+    assert_eq!(value, scene());
+}
+
+#[test]
+fn errors() {
+    let mut value = scene();
+
+    let error = EditCommand::new(&value, key_path!(.missing), 1_u32).unwrap_err();
+    assert_eq!(error.path(), &key_path!(.missing));
+
+    let command = EditCommand::new(&value, key_path!(.name), 1_u32).unwrap();
+    let error = command.apply(&mut value).unwrap_err();
+    assert_eq!(error.path(), &key_path!(.name));
+    assert_eq!(value, scene());
+
+    let error = command.revert(&mut scene().units).unwrap_err();
+    assert_eq!(error.path(), &key_path!(.name));
+}
+
+#[test]
+fn transactions_are_atomic() {
+    let mut value = scene();
+
+    let transaction = Transaction::from_iter([
+        EditCommand::new(&value, key_path!(.name), "renamed").unwrap(),
+        EditCommand::new(&value, key_path!(.units[0_usize].health), 1_u32).unwrap(),
+        EditCommand::new(&value, key_path!(.units[1_usize].health), "oops").unwrap(),
+    ]);
+    assert_eq!(transaction.len(), 3);
+
+    let error = transaction.apply(&mut value).unwrap_err();
+    assert_eq!(error.path(), &key_path!(.units[1_usize].health));
+    assert_eq!(value, scene());
+
+    // nothing is added when recording fails
+    let mut transaction = Transaction::new();
+    assert!(transaction
+        .record(&mut value, key_path!(.name), 1_u32)
+        .is_err());
+    assert!(transaction.is_empty());
+}
+
+#[test]
+fn inverse() {
+    let mut value = scene();
+
+    let mut transaction = Transaction::new();
+    transaction
+        .record(&mut value, key_path!(.name), "first")
+        .unwrap();
+    transaction
+        .record(&mut value, key_path!(.name), "second")
+        .unwrap();
+
+    let inverse = transaction.inverse();
+    assert_eq!(
+        inverse
+            .iter()
+            .map(|command| &command.new)
+            .collect::<Vec<_>>(),
+        [&Value::from("first"), &Value::from("level")]
+    );
+
+    inverse.apply(&mut value).unwrap();
+    assert_eq!(value, scene());
+}
+
+#[test]
+fn serialization() {
+    let value = scene();
+    let transaction = Transaction::from(
+        EditCommand::new(&value, key_path!(.units[0_usize]), Unit { health: 1 }).unwrap(),
+    );
+
+    #[cfg(feature = "speedy")]
+    {
+        use speedy::Readable;
+        use speedy::Writable;
+
+        let bytes = transaction.write_to_vec().unwrap();
+        assert_eq!(Transaction::read_from_buffer(&bytes).unwrap(), transaction);
+    }
+
+    #[cfg(feature = "serde")]
+    {
+        let json = serde_json::to_string(&transaction).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Transaction>(&json).unwrap(),
+            transaction
+        );
+    }
+}
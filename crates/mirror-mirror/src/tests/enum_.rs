@@ -1,11 +1,17 @@
+use alloc::borrow::ToOwned;
+use alloc::collections::BTreeMap;
+
 use crate::enum_::EnumValue;
+use crate::enum_::SetVariantError;
 use crate::enum_::VariantKind;
 use crate::get_field::GetField;
 use crate::get_field::GetFieldMut;
+use crate::key_path;
 use crate::DescribeType;
 use crate::Enum;
 use crate::FromReflect;
 use crate::Reflect;
+use crate::Value;
 
 #[test]
 fn enum_value() {
@@ -471,3 +477,409 @@ fn default_value_for_enum_variant_type() {
         Foo::C { a: 0.0, b: None },
     );
 }
+
+#[test]
+fn zero_variants() {
+    #[derive(Reflect, Debug, Clone)]
+    #[reflect(crate_name(crate))]
+    enum Never {}
+
+    let type_info = <Never as DescribeType>::type_descriptor();
+    let enum_ = type_info.as_enum().unwrap();
+    assert_eq!(enum_.variants().count(), 0);
+
+    assert!(Never::from_reflect(&1_i32).is_none());
+}
+
+#[test]
+fn fp_category() {
+    use core::num::FpCategory;
+
+    let category = 1.0_f32.classify();
+    let value = category.to_value();
+    assert_eq!(value.as_enum().unwrap().variant_name(), "Normal");
+    assert_eq!(
+        FpCategory::from_reflect(&value).unwrap(),
+        FpCategory::Normal
+    );
+
+    let type_info = <FpCategory as DescribeType>::type_descriptor();
+    assert_eq!(type_info.as_enum().unwrap().variants().count(), 5);
+}
+
+#[test]
+fn remote() {
+    mod remote {
+        #[derive(Debug, Clone, PartialEq)]
+        pub(super) enum Shape {
+            Circle { radius: f32 },
+            Square(f32),
+        }
+    }
+
+    #[derive(Reflect, Debug, Clone)]
+    #[reflect(crate_name(crate), remote = remote::Shape)]
+    enum ShapeDef {
+        Circle { radius: f32 },
+        Square(f32),
+    }
+
+    let shape = ShapeDef::from(remote::Shape::Circle { radius: 1.0 });
+    assert_eq!(shape.variant_name(), "Circle");
+
+    let value = ShapeDef::from(remote::Shape::Square(2.0)).to_value();
+    let shape: remote::Shape = ShapeDef::from_reflect(&value).unwrap().into();
+    assert_eq!(shape, remote::Shape::Square(2.0));
+}
+
+#[test]
+fn build_variant() {
+    use crate::type_info::Type;
+    use crate::type_info::VariantBuilderError;
+
+    #[derive(Reflect, Debug, Clone, PartialEq)]
+    #[reflect(crate_name(crate))]
+    enum Foo {
+        A { a: i32, b: String },
+        B(bool, u8),
+        C,
+    }
+
+    let descriptor = <Foo as DescribeType>::type_descriptor();
+    let Type::Enum(enum_type) = descriptor.get_type() else {
+        panic!()
+    };
+
+    assert!(enum_type.build_variant("D").is_none());
+
+    let value = enum_type
+        .build_variant("A")
+        .unwrap()
+        .with_field("b", "hi")
+        .unwrap()
+        .finish()
+        .unwrap();
+    assert_eq!(
+        value.type_name(),
+        Foo::A {
+            a: 0,
+            b: "".to_owned()
+        }
+        .type_name()
+    );
+    assert_eq!(
+        Foo::from_reflect(&value).unwrap(),
+        Foo::A {
+            a: 0,
+            b: "hi".to_owned()
+        }
+    );
+
+    let value = enum_type
+        .build_variant("B")
+        .unwrap()
+        .with_field_at(1, 42_u8)
+        .unwrap()
+        .finish()
+        .unwrap();
+    assert_eq!(Foo::from_reflect(&value).unwrap(), Foo::B(false, 42));
+
+    let value = enum_type.build_variant("C").unwrap().finish().unwrap();
+    assert_eq!(Foo::from_reflect(&value).unwrap(), Foo::C);
+
+    let mut builder = enum_type.build_variant("A").unwrap();
+    assert_eq!(
+        builder.set_field("c", 1_i32).unwrap_err(),
+        VariantBuilderError::UnknownField("c".to_owned())
+    );
+    assert_eq!(
+        builder.set_field("a", 1_u8).unwrap_err(),
+        VariantBuilderError::TypeMismatch("a".to_owned())
+    );
+    assert_eq!(
+        builder.set_field_at(2, 1_i32).unwrap_err(),
+        VariantBuilderError::IndexOutOfBounds(2)
+    );
+
+    let mut builder = enum_type.build_variant("B").unwrap();
+    assert_eq!(
+        builder.set_field("a", true).unwrap_err(),
+        VariantBuilderError::UnknownField("a".to_owned())
+    );
+    assert_eq!(
+        builder.set_field_at(0, 1_i32).unwrap_err(),
+        VariantBuilderError::TypeMismatch("0".to_owned())
+    );
+}
+
+#[test]
+fn rename() {
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    #[reflect(crate_name(crate), rename = "PlayerStatus", rename_all = "snake_case")]
+    enum Status {
+        InGame {
+            #[reflect(rename = "mapName")]
+            map_name: String,
+        },
+        #[reflect(rename = "away")]
+        AwayFromKeyboard(u32),
+        Offline,
+    }
+
+    let status = Status::InGame {
+        map_name: "dust".to_owned(),
+    };
+    assert_eq!(status.type_name(), "PlayerStatus");
+    assert_eq!(status.variant_name(), "in_game");
+    assert_eq!(status.get_field::<String>("mapName").unwrap(), "dust");
+    assert_eq!(Status::AwayFromKeyboard(1).variant_name(), "away");
+    assert_eq!(Status::Offline.variant_name(), "offline");
+
+    let value = status.to_value();
+    assert_eq!(value.type_name(), "PlayerStatus");
+    assert_eq!(Status::from_reflect(&value).unwrap(), status);
+    assert_eq!(
+        Status::from_reflect(&EnumValue::new_unit_variant("offline")).unwrap(),
+        Status::Offline
+    );
+
+    let mut status = Status::Offline;
+    status.patch(&Status::AwayFromKeyboard(5));
+    assert_eq!(status, Status::AwayFromKeyboard(5));
+
+    let type_info = <Status as DescribeType>::type_descriptor();
+    let enum_type = type_info.as_enum().unwrap();
+    assert_eq!(enum_type.type_name(), "PlayerStatus");
+    assert_eq!(
+        enum_type
+            .variants()
+            .map(|variant| variant.name())
+            .collect::<Vec<_>>(),
+        ["in_game", "away", "offline"]
+    );
+    assert!(enum_type
+        .variant("in_game")
+        .unwrap()
+        .field_types()
+        .any(|field| field.name() == Some("mapName")));
+}
+
+#[test]
+fn repr_discriminants_order_map_keys() {
+    use alloc::collections::BTreeMap;
+
+    #[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+    #[reflect(crate_name(crate))]
+    #[repr(u8)]
+    enum Fruit {
+        Zebra = 2,
+        Apple = 5,
+        Mango,
+        Banana(bool) = 1,
+    }
+
+    let descriptor = <Fruit as DescribeType>::type_descriptor();
+    let enum_type = descriptor.get_type().as_enum().unwrap();
+    assert_eq!(enum_type.repr(), Some("u8"));
+    let discriminants = enum_type
+        .variants()
+        .map(|variant| variant.discriminant())
+        .collect::<Vec<_>>();
+    assert_eq!(discriminants, [Some(2), Some(5), Some(6), Some(1)]);
+
+    let map = BTreeMap::from([
+        (Fruit::Zebra, 1),
+        (Fruit::Apple, 2),
+        (Fruit::Mango, 3),
+        (Fruit::Banana(true), 4),
+    ]);
+    let value = map.to_value();
+    let keys = value
+        .as_map()
+        .unwrap()
+        .iter()
+        .map(|(key, _)| Fruit::from_reflect(key).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(keys, map.keys().copied().collect::<Vec<_>>());
+    assert_eq!(BTreeMap::<Fruit, i32>::from_reflect(&value).unwrap(), map);
+
+    assert_eq!(Fruit::Zebra.to_value(), enum_type.default_value().unwrap());
+    assert_eq!(
+        EnumValue::from_reflect(&Fruit::Mango)
+            .unwrap()
+            .discriminant(),
+        Some(6)
+    );
+
+    // the discriminant isn't part of the value's identity
+    assert_eq!(
+        EnumValue::new_unit_variant("Apple").to_value(),
+        Fruit::Apple.to_value()
+    );
+    let key = EnumValue::new_tuple_variant("Banana")
+        .with_tuple_field(true)
+        .finish();
+    assert_eq!(
+        value
+            .as_map()
+            .unwrap()
+            .get(&key)
+            .unwrap()
+            .downcast_ref::<i32>(),
+        Some(&4)
+    );
+
+    #[derive(Reflect, Debug, Clone)]
+    #[reflect(crate_name(crate))]
+    enum NoRepr {
+        A,
+    }
+
+    let descriptor = <NoRepr as DescribeType>::type_descriptor();
+    let enum_type = descriptor.get_type().as_enum().unwrap();
+    assert_eq!(enum_type.repr(), None);
+    assert_eq!(enum_type.variant("A").unwrap().discriminant(), None);
+}
+
+#[test]
+fn set_variant() {
+    #[derive(Reflect, Debug, Clone, PartialEq)]
+    #[reflect(crate_name(crate))]
+    enum State {
+        Idle,
+        Moving { speed: f32 },
+        Attacking(i32, String),
+    }
+
+    let mut state = State::Moving { speed: 2.0 };
+    let enum_ = state.as_enum_mut().unwrap();
+    assert_eq!(enum_.variant_index(), Some(1));
+    assert_eq!(
+        State::from_reflect(&enum_.variant_at(2).unwrap()).unwrap(),
+        State::Attacking(0, String::new())
+    );
+    assert!(enum_.variant_at(3).is_none());
+
+    // switching to the current variant keeps its fields
+    enum_.set_variant("Moving").unwrap();
+    assert_eq!(state, State::Moving { speed: 2.0 });
+
+    state.as_enum_mut().unwrap().set_variant("Idle").unwrap();
+    assert_eq!(state, State::Idle);
+    assert_eq!(state.as_enum().unwrap().variant_index(), Some(0));
+
+    state
+        .as_enum_mut()
+        .unwrap()
+        .set_variant("Attacking")
+        .unwrap();
+    assert_eq!(state, State::Attacking(0, String::new()));
+
+    assert_eq!(
+        state.as_enum_mut().unwrap().set_variant("Sleeping"),
+        Err(SetVariantError::UnknownVariant("Sleeping".to_owned()))
+    );
+
+    let mut value = EnumValue::new_unit_variant("Idle");
+    assert!(value.variant_index().is_none());
+    assert_eq!(value.set_variant("Moving"), Err(SetVariantError::NotAnEnum));
+}
+
+#[derive(Reflect, Debug, Clone)]
+#[reflect(crate_name(crate))]
+enum WithSkippedVariant {
+    Kept(i32),
+    #[reflect(skip)]
+    Skipped(NotReflect),
+}
+
+#[derive(Debug, Clone, Default)]
+struct NotReflect;
+
+#[test]
+#[should_panic = "doesn't suport reflection"]
+fn skipped_variant_to_value_panics() {
+    WithSkippedVariant::Skipped(NotReflect).to_value();
+}
+
+#[test]
+fn skipped_variant_try_to_value() {
+    let error = WithSkippedVariant::Skipped(NotReflect)
+        .try_to_value()
+        .unwrap_err();
+    assert!(error.reason().unwrap().contains("`Skipped`"));
+    assert!(error.path().is_empty());
+
+    let nested = BTreeMap::from([(
+        "a".to_owned(),
+        (1_i32, WithSkippedVariant::Skipped(NotReflect)),
+    )]);
+    let error = nested.try_to_value().unwrap_err();
+    assert_eq!(error.path(), &key_path!(["a"].1));
+
+    let value = WithSkippedVariant::Kept(1).try_to_value().unwrap();
+    assert!(matches!(
+        WithSkippedVariant::from_reflect(&value),
+        Some(WithSkippedVariant::Kept(1))
+    ));
+}
+
+#[test]
+fn bound() {
+    use core::ops::Bound;
+
+    let mut bound = Bound::Included(1);
+    assert_eq!(bound.get_field::<i32>(0).unwrap(), &1);
+
+    let value = bound.to_value();
+    assert_eq!(Bound::<i32>::from_reflect(&value), Some(Bound::Included(1)));
+
+    bound.patch(&Bound::Excluded(2));
+    assert_eq!(bound, Bound::Excluded(2));
+
+    bound.patch(&Bound::<i32>::Unbounded);
+    assert_eq!(bound, Bound::Unbounded);
+
+    let type_info = <Bound<i32> as DescribeType>::type_descriptor();
+    assert_eq!(
+        type_info
+            .as_enum()
+            .unwrap()
+            .variants()
+            .map(|variant| variant.name())
+            .collect::<Vec<_>>(),
+        ["Included", "Excluded", "Unbounded"]
+    );
+}
+
+#[cfg(feature = "speedy")]
+#[test]
+fn speedy_encoding_has_no_discriminant() {
+    use speedy::Readable;
+    use speedy::Writable;
+
+    #[derive(Reflect, Debug, Clone, PartialEq)]
+    #[reflect(crate_name(crate))]
+    enum Plain {
+        A,
+    }
+
+    #[derive(Reflect, Debug, Clone, PartialEq)]
+    #[reflect(crate_name(crate))]
+    #[repr(u8)]
+    enum WithRepr {
+        A = 3,
+    }
+
+    // the encoding of `Plain::A.to_value()` from before discriminants were added
+    let bytes = [17, 0, 0, 0, 1, 0, 0, 0, 65, 2, 0, 0, 0];
+
+    assert_eq!(Plain::A.to_value().write_to_vec().unwrap(), bytes);
+    assert_eq!(WithRepr::A.to_value().write_to_vec().unwrap(), bytes);
+
+    let value = Value::read_from_buffer(&bytes).unwrap();
+    assert_eq!(value, Plain::A.to_value());
+    assert_eq!(value, WithRepr::A.to_value());
+    assert_eq!(Plain::from_reflect(&value), Some(Plain::A));
+    assert_eq!(WithRepr::from_reflect(&value), Some(WithRepr::A));
+}
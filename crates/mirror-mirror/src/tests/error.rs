@@ -0,0 +1,120 @@
+use alloc::collections::BTreeMap;
+
+use crate::key_path;
+use crate::key_path::GetPath;
+use crate::struct_::StructValue;
+use crate::FromReflect;
+use crate::Map;
+use crate::Reflect;
+use crate::ReflectError;
+
+#[derive(Reflect, Clone, Debug, PartialEq)]
+#[reflect(crate_name(crate))]
+struct Foo {
+    bar: Bar,
+    list: Vec<Option<i32>>,
+}
+
+#[derive(Reflect, Clone, Debug, PartialEq)]
+#[reflect(crate_name(crate))]
+struct Bar {
+    n: i32,
+}
+
+#[test]
+fn try_from_reflect() {
+    let foo = Foo {
+        bar: Bar { n: 1 },
+        list: Vec::from([Some(1), None]),
+    };
+    assert_eq!(Foo::try_from_reflect(&foo).unwrap(), foo);
+
+    let value = StructValue::new()
+        .with_field("bar", Bar { n: 1 })
+        .with_field(
+            "list",
+            Vec::from([Some(1).to_value(), Some("two".to_owned()).to_value()]),
+        );
+
+    let error = Foo::try_from_reflect(&value).unwrap_err();
+    assert_eq!(error.expected(), "i32");
+    assert_eq!(error.found(), Some("alloc::string::String"));
+    assert_eq!(error.path(), &key_path!(.list[1_usize]::Some.0));
+
+    let value = StructValue::new().with_field("list", Vec::<Option<i32>>::new());
+    let error = Foo::try_from_reflect(&value).unwrap_err();
+    assert_eq!(error.found(), None);
+    assert_eq!(error.path(), &key_path!(.bar));
+    assert_eq!(
+        error.to_string(),
+        format!(
+            "expected `{}`, found nothing at `.bar`",
+            Bar { n: 0 }.type_name()
+        )
+    );
+
+    let error = i32::try_from_reflect(&1.0_f32).unwrap_err();
+    assert_eq!(
+        error,
+        ReflectError::from_parts("i32".to_owned(), Some("f32".to_owned()))
+    );
+    assert_eq!(error.to_string(), "expected `i32`, found `f32`");
+}
+
+#[test]
+fn try_get_at() {
+    let mut foo = Foo {
+        bar: Bar { n: 1 },
+        list: Vec::from([Some(1), None]),
+    };
+
+    assert_eq!(foo.try_get_at::<i32>(&key_path!(.bar.n)).unwrap(), &1);
+    *foo.try_get_at_mut::<i32>(&key_path!(.bar.n)).unwrap() = 2;
+    assert_eq!(foo.bar.n, 2);
+
+    let error = foo.try_get_at::<bool>(&key_path!(.bar.n)).unwrap_err();
+    assert_eq!(error.expected(), "bool");
+    assert_eq!(error.found(), Some("i32"));
+    assert_eq!(error.path(), &key_path!(.bar.n));
+
+    let error = foo.try_at(&key_path!(.bar.m.x)).unwrap_err();
+    assert_eq!(error.found(), None);
+    assert_eq!(error.path(), &key_path!(.bar.m));
+
+    let error = foo.try_at(&key_path!(.list[1_usize]::Some)).unwrap_err();
+    assert!(error.expected().ends_with("::Some"));
+    assert!(error.found().unwrap().ends_with("::None"));
+    assert_eq!(error.path(), &key_path!(.list[1_usize]::Some));
+
+    let error = foo.try_at_mut(&key_path!(.list[2_usize])).unwrap_err();
+    assert_eq!(error.expected(), Some(0).type_name());
+    assert_eq!(error.path(), &key_path!(.list[2_usize]));
+}
+
+#[test]
+fn try_insert_and_downcast() {
+    let mut map = BTreeMap::<String, Bar>::new();
+    let map: &mut dyn Map = &mut map;
+
+    assert!(map
+        .try_insert(&"a".to_owned(), &Bar { n: 1 })
+        .unwrap()
+        .is_none());
+
+    let error = map
+        .try_insert(&"b".to_owned(), &StructValue::new().with_field("n", true))
+        .unwrap_err();
+    assert_eq!(error.path(), &key_path!(["b"].n));
+    assert_eq!(error.expected(), "i32");
+
+    let error = map.try_insert(&1_i32, &Bar { n: 1 }).unwrap_err();
+    assert_eq!(error.found(), Some("i32"));
+    assert!(error.path().is_empty());
+
+    let reflect: &dyn Reflect = &1_i32;
+    assert_eq!(reflect.try_downcast_ref::<i32>().unwrap(), &1);
+    assert_eq!(
+        reflect.try_downcast_ref::<bool>().unwrap_err().to_string(),
+        "expected `bool`, found `i32`"
+    );
+}
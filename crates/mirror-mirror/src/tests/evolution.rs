@@ -0,0 +1,234 @@
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+use speedy::Readable;
+use speedy::Writable;
+
+use crate::evolution::read_value;
+use crate::evolution::write_value;
+use crate::evolution::EvolutionError;
+use crate::evolution::Evolving;
+use crate::evolution::FORMAT_VERSION;
+use crate::evolution::MAX_DEPTH;
+use crate::DescribeType;
+use crate::FromReflect;
+use crate::Reflect;
+use crate::Value;
+
+mod v1 {
+    use super::*;
+
+    #[derive(Reflect, Clone, Debug)]
+    #[reflect(crate_name(crate))]
+    pub(super) struct Save {
+        pub(super) name: String,
+        pub(super) removed: bool,
+        pub(super) units: Vec<Unit>,
+        pub(super) flags: BTreeMap<String, Flag>,
+        pub(super) position: Position,
+    }
+
+    #[derive(Reflect, Clone, Debug)]
+    #[reflect(crate_name(crate))]
+    pub(super) struct Unit {
+        pub(super) health: u32,
+    }
+
+    #[derive(Reflect, Clone, Debug)]
+    #[reflect(crate_name(crate))]
+    pub(super) enum Flag {
+        On,
+        Timer { seconds: f32 },
+    }
+
+    #[derive(Reflect, Clone, Debug)]
+    #[reflect(crate_name(crate))]
+    pub(super) struct Position(pub(super) f32, pub(super) f32);
+}
+
+#[derive(Reflect, Clone, Debug, PartialEq)]
+#[reflect(crate_name(crate))]
+struct Save {
+    units: Vec<Unit>,
+    name: String,
+    flags: BTreeMap<String, Flag>,
+    position: Position,
+    score: Option<u64>,
+}
+
+#[derive(Reflect, Clone, Debug, PartialEq)]
+#[reflect(crate_name(crate))]
+struct Unit {
+    health: u32,
+    shield: u32,
+}
+
+#[derive(Reflect, Clone, Debug, PartialEq)]
+#[reflect(crate_name(crate))]
+enum Flag {
+    On,
+    Off,
+    Timer { seconds: f32, repeat: bool },
+}
+
+#[derive(Reflect, Clone, Debug, PartialEq)]
+#[reflect(crate_name(crate))]
+struct Position(f32, f32, f32);
+
+fn old_save() -> v1::Save {
+    v1::Save {
+        name: "save".to_owned(),
+        removed: true,
+        units: Vec::from([v1::Unit { health: 10 }]),
+        flags: BTreeMap::from([
+            ("a".to_owned(), v1::Flag::On),
+            ("b".to_owned(), v1::Flag::Timer { seconds: 1.5 }),
+        ]),
+        position: v1::Position(1.0, 2.0),
+    }
+}
+
+fn read<T>(bytes: &[u8]) -> Result<Value, EvolutionError>
+where
+    T: DescribeType,
+{
+    read_value(bytes, &<T as DescribeType>::type_descriptor())
+}
+
+#[test]
+fn roundtrip() {
+    let save = Save {
+        units: Vec::from([Unit {
+            health: 1,
+            shield: 2,
+        }]),
+        name: "save".to_owned(),
+        flags: BTreeMap::from([("a".to_owned(), Flag::Off)]),
+        position: Position(1.0, 2.0, 3.0),
+        score: Some(100),
+    };
+    let value = read::<Save>(&write_value(&save)).unwrap();
+    assert_eq!(value, save.to_value());
+    assert_eq!(Save::from_reflect(&value).unwrap(), save);
+}
+
+#[test]
+fn reads_older_versions() {
+    let value = read::<Save>(&write_value(&old_save())).unwrap();
+    assert_eq!(
+        Save::from_reflect(&value).unwrap(),
+        Save {
+            units: Vec::from([Unit {
+                health: 10,
+                shield: 0,
+            }]),
+            name: "save".to_owned(),
+            flags: BTreeMap::from([
+                ("a".to_owned(), Flag::On),
+                (
+                    "b".to_owned(),
+                    Flag::Timer {
+                        seconds: 1.5,
+                        repeat: false,
+                    }
+                ),
+            ]),
+            position: Position(1.0, 2.0, 0.0),
+            score: None,
+        }
+    );
+}
+
+#[test]
+fn errors() {
+    let bytes = write_value(&old_save());
+
+    assert_eq!(
+        read::<Save>(&bytes[..bytes.len() - 1]),
+        Err(EvolutionError::UnexpectedEnd)
+    );
+
+    let mut wrong_version = bytes.clone();
+    wrong_version[0] = 2;
+    assert_eq!(
+        read::<Save>(&wrong_version),
+        Err(EvolutionError::UnsupportedVersion(2))
+    );
+
+    #[derive(Reflect, Clone, Debug)]
+    #[reflect(crate_name(crate))]
+    enum Other {
+        Off,
+    }
+    assert!(matches!(
+        read::<Other>(&write_value(&Flag::On)),
+        Err(EvolutionError::UnknownVariant(_))
+    ));
+
+    // `Value` doesn't have a default
+    #[derive(Reflect, Clone, Debug)]
+    #[reflect(crate_name(crate))]
+    struct Required {
+        value: Value,
+    }
+    assert_eq!(
+        read::<Required>(&write_value(&Unit {
+            health: 1,
+            shield: 2
+        })),
+        Err(EvolutionError::MissingField("value".to_owned()))
+    );
+
+    // field names can't be recovered without a struct type
+    assert!(matches!(
+        read::<Value>(&write_value(&old_save())),
+        Err(EvolutionError::Unresolved(_))
+    ));
+}
+
+#[test]
+fn max_depth() {
+    fn nested(depth: usize) -> Value {
+        (1..depth).fold(Value::u8(0), |value, _| Value::List(Vec::from([value])))
+    }
+
+    let value = nested(MAX_DEPTH);
+    assert_eq!(read::<Value>(&write_value(&value)), Ok(value));
+    assert_eq!(
+        read::<Value>(&write_value(&nested(MAX_DEPTH + 1))),
+        Err(EvolutionError::TooDeep)
+    );
+
+    // deeply nested untrusted data is rejected rather than overflowing the stack
+    let mut bytes = Vec::from([FORMAT_VERSION]);
+    for _ in 0..100_000 {
+        bytes.extend([20, 1, 0, 0, 0]);
+    }
+    assert_eq!(read::<Value>(&bytes), Err(EvolutionError::TooDeep));
+}
+
+#[test]
+fn speedy_wrapper() {
+    #[derive(Readable, Writable)]
+    struct OldFile {
+        save: Evolving<v1::Save>,
+        version: u32,
+    }
+
+    #[derive(Readable, Writable)]
+    struct File {
+        save: Evolving<Save>,
+        version: u32,
+    }
+
+    let bytes = OldFile {
+        save: Evolving(old_save()),
+        version: 7,
+    }
+    .write_to_vec()
+    .unwrap();
+
+    let file = File::read_from_buffer(&bytes).unwrap();
+    assert_eq!(file.version, 7);
+    assert_eq!(file.save.0.units[0].health, 10);
+}
@@ -0,0 +1,94 @@
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+use crate::fmt::PrettyPrinter;
+use crate::Reflect;
+
+#[derive(Reflect, Clone, Debug)]
+#[reflect(crate_name(crate))]
+struct Foo {
+    id: Id,
+    values: Vec<i32>,
+    shape: Shape,
+    lookup: BTreeMap<String, u8>,
+}
+
+#[derive(Reflect, Clone, Debug)]
+#[reflect(crate_name(crate))]
+struct Id(u16);
+
+#[derive(Reflect, Clone, Debug)]
+#[reflect(crate_name(crate))]
+enum Shape {
+    Circle { radius: f32, hidden: bool },
+}
+
+fn foo() -> Foo {
+    Foo {
+        id: Id(26),
+        values: Vec::from([1, -1]),
+        shape: Shape::Circle {
+            radius: 1.5,
+            hidden: true,
+        },
+        lookup: BTreeMap::from([("a".to_owned(), 1)]),
+    }
+}
+
+#[test]
+fn type_names() {
+    let foo = foo();
+    let printer = PrettyPrinter::new().with_field_filter(|name| name == "id");
+    assert_eq!(
+        printer.display(&foo).to_string(),
+        "mirror_mirror::tests::fmt::Foo { id: mirror_mirror::tests::fmt::Id(26) }"
+    );
+    assert_eq!(
+        format!("{:#}", printer.display(&foo)),
+        printer.with_multiline(true).display(&foo).to_string(),
+    );
+}
+
+#[test]
+fn options() {
+    let foo = foo();
+
+    let printer = PrettyPrinter::new()
+        .with_type_names(false)
+        .with_hex_integers(true)
+        .with_field_filter(|name| name != "hidden" && name != "lookup");
+    assert_eq!(
+        printer.display(&foo).to_string(),
+        "{id: (0x1a,), values: [0x1, 0xffffffff], shape: Circle { radius: 1.5 }}"
+    );
+
+    let printer = PrettyPrinter::new()
+        .with_type_names(false)
+        .with_max_depth(1);
+    assert_eq!(
+        printer.display(&foo).to_string(),
+        "{id: .., values: .., shape: .., lookup: ..}"
+    );
+
+    let printer = PrettyPrinter::new()
+        .with_type_names(false)
+        .with_compact_lists(true)
+        .with_multiline(true)
+        .with_field_filter(|name| name == "values");
+    assert_eq!(
+        printer.display(&foo).to_string(),
+        "{\n    values: [1, -1],\n}"
+    );
+
+    let lines = PrettyPrinter::new()
+        .with_type_names(false)
+        .with_multiline(true)
+        .with_field_filter(|name| name == "values")
+        .display(&foo)
+        .to_string()
+        .lines()
+        .count();
+    assert_eq!(lines, 6);
+}
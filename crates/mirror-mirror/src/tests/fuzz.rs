@@ -0,0 +1,158 @@
+use alloc::collections::BTreeMap;
+
+use speedy::Writable;
+
+use crate::evolution;
+use crate::fuzz::evolution_value;
+use crate::fuzz::fuzz_roundtrip;
+use crate::fuzz::typed_json;
+use crate::DescribeType;
+use crate::Reflect;
+use crate::TypeDescriptor;
+use crate::Value;
+
+#[derive(Reflect, Clone, Debug)]
+#[reflect(crate_name(crate))]
+struct World {
+    name: String,
+    units: Vec<Unit>,
+    tags: BTreeMap<String, (u8, f32)>,
+}
+
+#[derive(Reflect, Clone, Debug)]
+#[reflect(crate_name(crate))]
+enum Unit {
+    Player { health: i32, children: Vec<Unit> },
+    Npc(Option<char>),
+    Empty,
+}
+
+fn world() -> World {
+    World {
+        name: "world".to_owned(),
+        units: Vec::from([
+            Unit::Player {
+                health: 10,
+                children: Vec::from([Unit::Npc(Some('a')), Unit::Empty]),
+            },
+            Unit::Npc(None),
+        ]),
+        tags: BTreeMap::from([("a".to_owned(), (1, 2.5))]),
+    }
+}
+
+/// JSON maps can only have string keys, so values with maps can't be encoded as JSON.
+fn json_value() -> Value {
+    World {
+        tags: BTreeMap::new(),
+        ..world()
+    }
+    .to_value()
+}
+
+/// Inputs made by corrupting valid encodings in many small ways.
+fn corrupted(seed: &[u8]) -> impl Iterator<Item = Vec<u8>> + '_ {
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+
+    (0..seed.len())
+        .map(|len| seed[..len].to_vec())
+        .chain((0..2000).map(move |_| {
+            let mut bytes = seed.to_vec();
+            for _ in 0..=next() % 4 {
+                let index = (next() % bytes.len() as u64) as usize;
+                bytes[index] = next() as u8;
+            }
+            bytes
+        }))
+}
+
+#[test]
+fn valid_inputs() {
+    let descriptor = <World as DescribeType>::type_descriptor();
+    let value = world().to_value();
+
+    fuzz_roundtrip(&value.write_to_vec().unwrap());
+    fuzz_roundtrip(&serde_json::to_vec(&json_value()).unwrap());
+    fuzz_roundtrip(&descriptor.write_to_vec().unwrap());
+    fuzz_roundtrip(&serde_json::to_vec(&*descriptor).unwrap());
+
+    let mut bytes = descriptor.write_to_vec().unwrap();
+    bytes.extend(
+        serde_json::to_vec(&crate::serde::TypedSerializer::new(&value, &descriptor)).unwrap(),
+    );
+    fuzz_roundtrip(&bytes);
+
+    let mut bytes = descriptor.write_to_vec().unwrap();
+    bytes.extend(evolution::write_value(&world()));
+    fuzz_roundtrip(&bytes);
+}
+
+#[test]
+fn corrupted_values() {
+    let value = world().to_value();
+    for bytes in corrupted(&value.write_to_vec().unwrap()) {
+        fuzz_roundtrip(&bytes);
+    }
+    for bytes in corrupted(&serde_json::to_vec(&json_value()).unwrap()) {
+        fuzz_roundtrip(&bytes);
+    }
+}
+
+#[test]
+fn corrupted_descriptors() {
+    let descriptor: &TypeDescriptor = &<World as DescribeType>::type_descriptor();
+    for bytes in corrupted(&descriptor.write_to_vec().unwrap()) {
+        fuzz_roundtrip(&bytes);
+    }
+    for bytes in corrupted(&serde_json::to_vec(descriptor).unwrap()) {
+        fuzz_roundtrip(&bytes);
+    }
+}
+
+#[test]
+fn corrupted_typed_json() {
+    let descriptor = <World as DescribeType>::type_descriptor();
+    let json =
+        serde_json::to_vec(&crate::serde::TypedSerializer::new(&world(), &descriptor)).unwrap();
+    for bytes in corrupted(&json) {
+        typed_json(&descriptor, &bytes);
+    }
+}
+
+#[test]
+fn invalid_descriptors_are_rejected() {
+    let descriptor: &TypeDescriptor = &<World as DescribeType>::type_descriptor();
+    let json = serde_json::to_value(descriptor).unwrap();
+
+    let mut missing_root = json.clone();
+    missing_root["root"] = 1.into();
+    assert!(serde_json::from_value::<TypeDescriptor>(missing_root).is_err());
+
+    // only keep the root node
+    let mut missing_node = json;
+    let root = missing_node["root"].to_string();
+    let graph = missing_node["graph"]["map"].as_object_mut().unwrap();
+    graph.retain(|id, _| *id == root);
+    assert_eq!(graph.len(), 1);
+    assert!(serde_json::from_value::<TypeDescriptor>(missing_node).is_err());
+}
+
+#[test]
+fn corrupted_evolution() {
+    let descriptor = <World as DescribeType>::type_descriptor();
+    for bytes in corrupted(&evolution::write_value(&world())) {
+        evolution_value(&descriptor, &bytes);
+    }
+
+    let mut deep = Vec::from([evolution::FORMAT_VERSION]);
+    for _ in 0..100_000 {
+        deep.extend([20, 1, 0, 0, 0]);
+    }
+    evolution_value(&descriptor, &deep);
+}
@@ -0,0 +1,106 @@
+use alloc::borrow::ToOwned;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::get_field::GetField;
+use crate::get_field::GetFieldMut;
+use crate::Reflect;
+
+#[derive(Reflect, Clone, Debug, PartialEq)]
+#[reflect(crate_name(crate))]
+struct Scene {
+    layers: Vec<Layer>,
+    lookup: BTreeMap<String, (i32, Shape)>,
+    grid: [[u8; 2]; 2],
+}
+
+#[derive(Reflect, Clone, Debug, PartialEq)]
+#[reflect(crate_name(crate))]
+struct Layer(String, Option<Shape>);
+
+#[derive(Reflect, Clone, Debug, PartialEq)]
+#[reflect(crate_name(crate))]
+enum Shape {
+    Circle { radius: f32 },
+    Polygon(Vec<(f32, f32)>),
+}
+
+fn scene() -> Scene {
+    Scene {
+        layers: Vec::from([
+            Layer("background".to_owned(), None),
+            Layer(
+                "foreground".to_owned(),
+                Some(Shape::Polygon(Vec::from([(0.0, 0.0), (1.0, 2.0)]))),
+            ),
+        ]),
+        lookup: BTreeMap::from([("a".to_owned(), (1, Shape::Circle { radius: 3.0 }))]),
+        grid: [[1, 2], [3, 4]],
+    }
+}
+
+#[test]
+fn chained() {
+    let scene = scene();
+
+    assert_eq!(
+        scene.get_field::<String>(("layers", 1, 0)).unwrap(),
+        "foreground"
+    );
+    assert_eq!(
+        scene
+            .get_field::<f32>(("layers", 1, 1, 0, 0, 1, 1))
+            .unwrap(),
+        &2.0
+    );
+    assert_eq!(scene.get_field::<i32>(("lookup", "a", 0)).unwrap(), &1);
+    assert_eq!(
+        scene
+            .get_field::<f32>(("lookup", "a", 1, "radius"))
+            .unwrap(),
+        &3.0
+    );
+    assert_eq!(scene.get_field::<u8>(("grid", 1, 0)).unwrap(), &3);
+
+    // through `Value`s and trait objects
+    let value = scene.to_value();
+    assert_eq!(value.get_field::<u8>(("grid", 0, 1)).unwrap(), &2);
+    let reflect: &dyn Reflect = &scene;
+    assert_eq!(reflect.get_field::<u8>(("grid", 0, 1)).unwrap(), &2);
+
+    // the shape of the path must match the value
+    assert!(scene.get_field::<u8>(("grid", 2, 0)).is_none());
+    assert!(scene.get_field::<f32>(("layers", 0, 1, 0)).is_none());
+    assert!(scene
+        .get_field::<f32>(("lookup", "b", 1, "radius"))
+        .is_none());
+    assert!(scene.get_field::<f32>(("lookup", "a", 1, 0)).is_none());
+    assert!(scene.get_field::<i32>(("layers", "0")).is_none());
+    // the type must match too
+    assert!(scene.get_field::<u32>(("grid", 0, 0)).is_none());
+}
+
+#[test]
+fn chained_mut() {
+    let mut scene = scene();
+
+    *scene
+        .get_field_mut::<f32>(("lookup", "a", 1, "radius"))
+        .unwrap() = 5.0;
+    *scene.get_field_mut::<u8>(("grid", 0, 0)).unwrap() = 10;
+    scene
+        .get_field_mut::<Vec<(f32, f32)>>(("layers", 1, 1, 0, 0))
+        .unwrap()
+        .clear();
+
+    assert_eq!(scene.lookup["a"].1, Shape::Circle { radius: 5.0 });
+    assert_eq!(scene.grid[0][0], 10);
+    assert_eq!(scene.layers[1].1, Some(Shape::Polygon(Vec::new())));
+
+    assert!(scene.get_field_mut::<f32>(("layers", 0, 1, 0)).is_none());
+
+    let mut value = scene.to_value();
+    *value.get_field_mut::<u8>(("grid", 1, 1)).unwrap() = 20;
+    assert_eq!(value.get_field::<u8>(("grid", 1, 1)).unwrap(), &20);
+}
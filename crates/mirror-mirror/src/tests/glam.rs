@@ -0,0 +1,95 @@
+use glam::{Affine3A, Mat4, Quat, Vec3, Vec3A, Vec4};
+
+use crate::key_path;
+use crate::key_path::GetPath;
+use crate::DescribeType;
+use crate::FromReflect;
+use crate::Reflect;
+
+#[derive(Reflect, Debug, Clone, PartialEq)]
+#[reflect(crate_name(crate))]
+struct Transform {
+    position: Vec3A,
+    color: Vec4,
+    rotation: Quat,
+    matrix: Mat4,
+    affine: Affine3A,
+}
+
+fn transform() -> Transform {
+    Transform {
+        position: Vec3A::new(1.0, 2.0, 3.0),
+        color: Vec4::new(0.1, 0.2, 0.3, 1.0),
+        rotation: Quat::from_rotation_y(1.0),
+        matrix: Mat4::from_translation(Vec3::new(1.0, 2.0, 3.0)),
+        affine: Affine3A::from_scale(Vec3::splat(2.0)),
+    }
+}
+
+#[test]
+fn round_trips_through_value() {
+    let transform = transform();
+    let value = transform.to_value();
+    assert_eq!(Transform::from_reflect(&value).unwrap(), transform);
+
+    // `Quat` is opaque, but converts to a struct value
+    assert!(transform.get_at::<f32>(&key_path!(.rotation.w)).is_none());
+    assert!(<Quat as DescribeType>::type_descriptor()
+        .get_type()
+        .as_opaque()
+        .is_some());
+    assert_eq!(
+        value.get_at::<f32>(&key_path!(.rotation.w)).unwrap(),
+        &transform.rotation.w
+    );
+}
+
+#[test]
+fn fields_can_be_mutated() {
+    let mut transform = transform();
+
+    *transform
+        .get_at_mut::<f32>(&key_path!(.position.y))
+        .unwrap() = 10.0;
+    *transform
+        .get_at_mut::<f32>(&key_path!(.matrix.w_axis.x))
+        .unwrap() = 5.0;
+    for (_, field) in transform.color.as_struct_mut().unwrap().fields_mut() {
+        field.patch(&0.5_f32);
+    }
+
+    assert_eq!(transform.position, Vec3A::new(1.0, 10.0, 3.0));
+    assert_eq!(transform.matrix.w_axis.x, 5.0);
+    assert_eq!(transform.color, Vec4::splat(0.5));
+}
+
+#[test]
+fn quat_is_patched_as_a_whole() {
+    let mut transform = transform();
+    let rotation = Quat::from_rotation_x(0.5);
+
+    transform.patch(&crate::struct_::StructValue::new().with_field("rotation", rotation));
+    assert_eq!(transform.rotation, rotation);
+
+    assert!(transform
+        .get_at_mut::<f32>(&key_path!(.rotation.x))
+        .is_none());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn typed_serde() {
+    use serde::de::DeserializeSeed;
+
+    use crate::serde::TypedDeserializer;
+    use crate::serde::TypedSerializer;
+
+    let transform = transform();
+    let descriptor = <Transform as DescribeType>::type_descriptor();
+
+    let json = serde_json::to_string(&TypedSerializer::new(&transform, &descriptor)).unwrap();
+    let value = TypedDeserializer::new(&descriptor)
+        .deserialize(&mut serde_json::Deserializer::from_str(&json))
+        .unwrap();
+    assert_eq!(Transform::from_reflect(&value).unwrap(), transform);
+}
@@ -57,6 +57,13 @@ fn works() {
     assert_eq!(a.b.c, true);
     *a.get_at_mut(&key_path!(.b.c)).unwrap() = false;
     assert_eq!(a.b.c, false);
+
+    a.set_at(&key_path!(.c::C.d), "bar".to_owned()).unwrap();
+    assert_eq!(a.get_at::<String>(&key_path!(.c::C.d)).unwrap(), &"bar");
+    a.set_at(&key_path!(.e[1]), 4.0_f32).unwrap();
+    assert_eq!(a.e, [1.0, 4.0, 3.0]);
+    assert!(a.set_at(&key_path!(.e[1]), 4.0_f64).is_err());
+    assert!(a.set_at(&key_path!(.e[3]), 4.0_f32).is_err());
 }
 
 #[test]
@@ -172,3 +179,363 @@ fn breadcrumbs() {
         assert_eq!(a, b);
     }
 }
+
+#[cfg(feature = "rand")]
+#[test]
+fn sample_paths() {
+    use crate::key_path::sample_paths;
+    use crate::key_path::GetTypePath;
+    use alloc::collections::BTreeMap;
+
+    struct XorShift(u64);
+
+    impl rand_core::RngCore for XorShift {
+        fn next_u32(&mut self) -> u32 {
+            self.next_u64() as u32
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            rand_core::impls::fill_bytes_via_next(self, dest)
+        }
+
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+
+    #[derive(Reflect, Clone, Debug)]
+    #[reflect(crate_name(crate))]
+    struct Foo {
+        list: Vec<Bar>,
+        map: BTreeMap<String, (i32, [bool; 2])>,
+        bar: Bar,
+        recursive: Option<Box<Foo>>,
+    }
+
+    #[derive(Reflect, Clone, Debug)]
+    #[reflect(crate_name(crate))]
+    enum Bar {
+        A { n: i32 },
+        B(u8, Baz),
+        C,
+    }
+
+    #[derive(Reflect, Clone, Debug)]
+    #[reflect(crate_name(crate))]
+    struct Baz(f32);
+
+    let descriptor = <Foo as DescribeType>::type_descriptor();
+    let paths = sample_paths(&descriptor, 500, &mut XorShift(1337));
+    assert_eq!(paths.len(), 500);
+
+    for path in &paths {
+        assert!(
+            descriptor.type_at(path).is_some(),
+            "invalid path generated: {path}"
+        );
+    }
+
+    assert!(paths.iter().any(|path| path.is_empty()));
+    assert!(paths.iter().any(|path| path.len() > 3));
+    assert!(paths
+        .iter()
+        .any(|path| path.to_string().starts_with(".map[\"")));
+}
+
+#[test]
+fn key_path_ids() {
+    use crate::key_path::KeyPathIds;
+
+    #[derive(Reflect, Clone, Debug)]
+    #[reflect(crate_name(crate))]
+    struct Foo {
+        a: (i32, [bool; 2]),
+        b: Option<Box<Foo>>,
+        c: Vec<Foo>,
+    }
+
+    let ids = KeyPathIds::new(&<Foo as DescribeType>::type_descriptor());
+
+    let paths = ids
+        .iter()
+        .map(|(_, path)| path.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        paths,
+        [
+            "",
+            ".a",
+            ".a.0",
+            ".a.1",
+            ".a.1[0]",
+            ".a.1[1]",
+            ".b",
+            ".b::Some.0",
+            ".c",
+        ]
+    );
+
+    for (id, path) in ids.iter() {
+        assert_eq!(ids.id(path), Some(id));
+        assert_eq!(ids.path(id), Some(path));
+    }
+
+    assert!(ids.id(&key_path!(.c[0])).is_none());
+    assert!(ids.path(ids.len() as u32).is_none());
+
+    // the same type always gives the same ids
+    let other = KeyPathIds::new(&<Foo as DescribeType>::type_descriptor());
+    assert!(ids.iter().eq(other.iter()));
+}
+
+#[test]
+fn path_set() {
+    use crate::key_path::PathSet;
+
+    #[derive(Reflect, Clone, Debug)]
+    #[reflect(crate_name(crate))]
+    struct Before {
+        a: i32,
+        b: Vec<(bool, String)>,
+        c: Option<String>,
+    }
+
+    #[derive(Reflect, Clone, Debug)]
+    #[reflect(crate_name(crate))]
+    struct After {
+        a: i32,
+        b: Vec<bool>,
+    }
+
+    let before = <Before as DescribeType>::type_descriptor();
+    let after = <After as DescribeType>::type_descriptor();
+
+    let mut paths = PathSet::new();
+    assert!(paths.insert(key_path!(.c::Some.0)));
+    assert!(paths.insert(key_path!(.a)));
+    assert!(paths.insert(key_path!(.b[3_usize].0)));
+    assert!(paths.insert(key_path!(.missing)));
+    assert!(!paths.insert(key_path!(.a)));
+    assert_eq!(paths.len(), 4);
+
+    assert_eq!(paths.invalid(&before), [&key_path!(.missing)]);
+    assert_eq!(
+        paths.invalidated(&before, &after),
+        [&key_path!(.c::Some.0), &key_path!(.b[3_usize].0)]
+    );
+
+    assert_eq!(
+        paths.retain_valid(&after),
+        [
+            key_path!(.c::Some.0),
+            key_path!(.b[3_usize].0),
+            key_path!(.missing)
+        ]
+    );
+    assert_eq!(paths.iter().collect::<Vec<_>>(), [&key_path!(.a)]);
+    assert!(paths.remove(&key_path!(.a)));
+    assert!(paths.is_empty());
+}
+
+#[test]
+fn parse() {
+    let key_path = key_path!(.employer::Some.0.countries[0].name);
+    assert_eq!(
+        KeyPath::parse(".employer::Some.0.countries[0].name").unwrap(),
+        key_path
+    );
+    assert_eq!(key_path.to_string().parse::<KeyPath>().unwrap(), key_path);
+    assert_eq!(KeyPath::parse("").unwrap(), key_path!());
+    assert_eq!(
+        KeyPath::parse(r#" .a [ "b\"\u{1F600}" ] :: C ."d""#).unwrap(),
+        key_path!(.a["b\"\u{1F600}"]::C."d")
+    );
+
+    assert_eq!(
+        KeyPath::parse("[1_000u64][-2][1.5][2.5f32]['x'][true]").unwrap(),
+        key_path!([1_000u64][-2][1.5][2.5f32]['x'][true])
+    );
+
+    let error = KeyPath::parse(".a.").unwrap_err();
+    assert_eq!((error.position(), error.expected()), (3, "identifier"));
+
+    let error = KeyPath::parse(".a-b").unwrap_err();
+    assert_eq!(
+        (error.position(), error.expected()),
+        (2, "`.`, `::`, or `[`")
+    );
+
+    let error = KeyPath::parse(r#"["a"#).unwrap_err();
+    assert_eq!((error.position(), error.expected()), (3, "`\"`"));
+
+    let error = KeyPath::parse("[256u8]").unwrap_err();
+    assert_eq!(
+        (error.position(), error.expected()),
+        (1, "number in range of its type")
+    );
+
+    let error = KeyPath::parse("[1.5u8]").unwrap_err();
+    assert_eq!(
+        (error.position(), error.expected()),
+        (4, "`f32` or `f64` suffix")
+    );
+
+    let error = KeyPath::parse("[foo]").unwrap_err();
+    assert_eq!((error.position(), error.expected()), (1, "literal"));
+}
+
+#[test]
+fn any_depth_pattern() {
+    let pattern = KeyPathPattern::default().field("a").any_depth().field("b");
+
+    assert!(pattern.matches(&key_path!(.a.b)));
+    assert!(pattern.matches(&key_path!(.a.x[1]::Some.b)));
+    assert!(!pattern.matches(&key_path!(.a.b.c)));
+    assert!(!pattern.matches(&key_path!(.x.b)));
+
+    assert!(pattern.matches_prefix_of(&key_path!(.a.x.b.c)));
+    assert!(!pattern.matches_prefix_of(&key_path!(.a.x)));
+
+    assert!(pattern.matches_descendant_of(&key_path!(.a.x)));
+    assert!(pattern.matches_descendant_of(&key_path!(.a.b)));
+    assert!(!pattern.matches_descendant_of(&key_path!(.x)));
+
+    let pattern = KeyPathPattern::default().field("a").any_depth();
+    assert!(pattern.matches(&key_path!(.a)));
+    assert!(pattern.matches_descendant_of(&key_path!(.a.b.c)));
+
+    let pattern = KeyPathPattern::parse(r#".a[**]::B[*]["c"]"#).unwrap();
+    assert_eq!(
+        pattern,
+        KeyPathPattern::default()
+            .field("a")
+            .any_depth()
+            .variant("B")
+            .any()
+            .get("c")
+    );
+    assert_eq!(
+        pattern.to_string().parse::<KeyPathPattern>().unwrap(),
+        pattern
+    );
+    assert!(KeyPath::parse("[*]").is_err());
+}
+
+#[test]
+fn get_all_at() {
+    #[derive(Reflect, Clone, Debug)]
+    #[reflect(crate_name(crate))]
+    struct Scene {
+        units: BTreeMap<String, Unit>,
+        boss: Option<Unit>,
+    }
+
+    #[derive(Reflect, Clone, Debug)]
+    #[reflect(crate_name(crate))]
+    struct Unit {
+        health: u32,
+        pet: Option<Box<Unit>>,
+    }
+
+    let unit = |health| Unit { health, pet: None };
+
+    let scene = Scene {
+        units: BTreeMap::from([
+            ("a".to_owned(), unit(1)),
+            (
+                "b".to_owned(),
+                Unit {
+                    health: 2,
+                    pet: Some(Box::new(unit(3))),
+                },
+            ),
+        ]),
+        boss: Some(unit(4)),
+    };
+
+    let healths = |pattern: &str| {
+        scene
+            .get_all_at(&KeyPathPattern::parse(pattern).unwrap())
+            .map(|(path, value)| {
+                assert_eq!(scene.get_at::<u32>(&path), value.downcast_ref::<u32>());
+                (path.to_string(), *value.downcast_ref::<u32>().unwrap())
+            })
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        healths("[**].health"),
+        [
+            (r#".units["a"].health"#.to_owned(), 1),
+            (r#".units["b"].health"#.to_owned(), 2),
+            (r#".units["b"].pet::Some.0.health"#.to_owned(), 3),
+            (".boss::Some.0.health".to_owned(), 4),
+        ]
+    );
+    assert_eq!(
+        healths(".units[*].health"),
+        [
+            (r#".units["a"].health"#.to_owned(), 1),
+            (r#".units["b"].health"#.to_owned(), 2),
+        ]
+    );
+    assert!(healths(".boss::None[**]").is_empty());
+    assert_eq!(scene.get_all_at(&KeyPathPattern::default()).len(), 1);
+}
+
+#[test]
+fn get_at_or_default() {
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    #[reflect(crate_name(crate))]
+    struct Config {
+        window: Option<Window>,
+        shaders: Vec<crate::Value>,
+    }
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    #[reflect(crate_name(crate))]
+    struct Window {
+        title: String,
+        size: (u32, u32),
+    }
+
+    let mut config = Config {
+        window: None,
+        shaders: Vec::new(),
+    };
+
+    assert_eq!(
+        config.get_at_or_default::<Window>(&key_path!(.window::Some.0)),
+        Some(Window {
+            title: String::new(),
+            size: (0, 0),
+        })
+    );
+    // `Value` has no default
+    assert_eq!(
+        config.get_at_or_default::<crate::Value>(&key_path!(.shaders[0])),
+        None
+    );
+
+    config.window = Some(Window {
+        title: "game".to_owned(),
+        size: (800, 600),
+    });
+    let value = config.to_value();
+    assert_eq!(
+        value.get_at_or_default::<u32>(&key_path!(.window::Some.0.size.1)),
+        Some(600)
+    );
+    assert_eq!(
+        value.get_at_or_default::<String>(&key_path!(.window::Some.0.title)),
+        Some("game".to_owned())
+    );
+}
@@ -1,5 +1,15 @@
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::collections::VecDeque;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::list::add_assign_list;
+use crate::list::scale;
 use crate::FromReflect;
+use crate::List;
 use crate::Reflect;
+use crate::Value;
 
 #[test]
 fn indexing() {
@@ -43,3 +53,219 @@ fn remove() {
     assert!(list.try_remove(2).is_none());
     assert!(list.try_remove(1337).is_none());
 }
+
+#[test]
+fn numeric_ops() {
+    let mut a = vec![Value::from(1_u8), Value::from(1.5_f32), Value::from("a")];
+    let b = vec![Value::from(u8::MAX), Value::from(1_i32), Value::from("b")];
+
+    // only the `u8`s have matching numeric types
+    let updated = add_assign_list(a.as_reflect_mut().as_list_mut().unwrap(), &b);
+    assert_eq!(updated, 1);
+    assert_eq!(a[0], Value::from(0_u8));
+    assert_eq!(a[1], Value::from(1.5_f32));
+
+    let mut c = vec![3_u8, 200, 9];
+    let updated = scale(c.as_reflect_mut().as_list_mut().unwrap(), 1.5);
+    assert_eq!(updated, 3);
+    assert_eq!(c, [4, 255, 13]);
+
+    let updated = scale(a.as_reflect_mut().as_list_mut().unwrap(), -2.0);
+    assert_eq!(updated, 2);
+    assert_eq!(a[1], Value::from(-3.0_f32));
+    assert_eq!(a[2], Value::from("a"));
+}
+
+#[test]
+fn reserve() {
+    let mut list = Vec::<i32>::new();
+    list.reflect_mut().as_list_mut().unwrap().reserve(10);
+    assert!(list.capacity() >= 10);
+
+    let list = Vec::<i32>::from_reflect(&Vec::from([1, 2, 3])).unwrap();
+    assert_eq!(list.capacity(), 3);
+}
+
+#[test]
+fn from_reflect_lossy() {
+    #[derive(Reflect, Debug, Clone, PartialEq)]
+    #[reflect(crate_name(crate))]
+    struct Foo {
+        n: i32,
+    }
+
+    let value = Value::List(Vec::from([
+        Foo { n: 1 }.to_value(),
+        Value::from(2_i32),
+        Foo { n: 3 }.to_value(),
+    ]));
+
+    let (list, skipped): (Vec<Foo>, _) = crate::list::from_reflect_lossy(&value).unwrap();
+    assert_eq!(list, [Foo { n: 1 }, Foo { n: 3 }]);
+    assert_eq!(skipped.len(), 1);
+    assert_eq!(skipped[0].0, 1);
+    assert_eq!(skipped[0].1.found(), Some("i32"));
+
+    let array = [1_i32, 2];
+    let (list, skipped): (Vec<i32>, _) = crate::list::from_reflect_lossy(&array).unwrap();
+    assert_eq!(list, [1, 2]);
+    assert!(skipped.is_empty());
+
+    assert!(crate::list::from_reflect_lossy::<Vec<i32>, i32>(&1_i32).is_none());
+}
+
+#[test]
+fn insert_remove_truncate_retain() {
+    fn check(list: &mut dyn List) {
+        list.insert(1, &10_i32);
+        list.insert(4, &20_i32);
+        // values of the wrong type are ignored
+        list.insert(0, &true);
+        assert_eq!(list.to_value(), Value::from(Vec::from([1, 10, 2, 3, 20])));
+
+        assert_eq!(list.remove(0).downcast_ref::<i32>(), Some(&1));
+        assert!(list.try_remove(10).is_none());
+
+        list.retain(&mut |value| value.downcast_ref::<i32>().unwrap() % 2 == 0);
+        assert_eq!(list.to_value(), Value::from(Vec::from([10, 2, 20])));
+
+        list.truncate(5);
+        assert_eq!(list.len(), 3);
+        list.truncate(1);
+        assert_eq!(list.to_value(), Value::from(Vec::from([10])));
+
+        list.clear();
+        assert!(list.is_empty());
+    }
+
+    check(&mut Vec::from([1, 2, 3]));
+    check(&mut VecDeque::from([1, 2, 3]));
+    #[cfg(feature = "smallvec")]
+    check(&mut smallvec::SmallVec::<[i32; 2]>::from_slice(&[1, 2, 3]));
+    #[cfg(feature = "arrayvec")]
+    check(&mut arrayvec::ArrayVec::<i32, 5>::from_iter([1, 2, 3]));
+    #[cfg(feature = "tinyvec")]
+    check(&mut tinyvec::TinyVec::<[i32; 2]>::from(&[1, 2, 3][..]));
+}
+
+#[test]
+#[should_panic = "removal index (is 3) should be < len (is 3)"]
+fn remove_out_of_bounds() {
+    let mut list = Vec::from([1, 2, 3]);
+    list.as_list_mut().unwrap().remove(3);
+}
+
+#[test]
+fn vec_deque() {
+    let list = VecDeque::from([1, 2, 3]);
+    let value = list.to_value();
+    assert_eq!(value, Value::from(Vec::from([1, 2, 3])));
+    assert_eq!(VecDeque::<i32>::from_reflect(&value).unwrap(), list);
+    assert_eq!(format!("{:?}", list.as_reflect()), "[1, 2, 3]");
+}
+
+#[test]
+#[cfg(feature = "smallvec")]
+fn smallvec() {
+    let list = smallvec::SmallVec::<[i32; 2]>::from_slice(&[1, 2, 3]);
+    let value = list.to_value();
+    assert_eq!(value, Value::from(Vec::from([1, 2, 3])));
+    assert_eq!(
+        smallvec::SmallVec::<[i32; 2]>::from_reflect(&value).unwrap(),
+        list
+    );
+    assert_eq!(
+        list.type_descriptor()
+            .get_type()
+            .as_list()
+            .unwrap()
+            .element_type()
+            .as_scalar(),
+        Some(crate::type_info::ScalarType::i32)
+    );
+}
+
+#[test]
+#[cfg(feature = "arrayvec")]
+fn arrayvec() {
+    let mut list = arrayvec::ArrayVec::<i32, 3>::from([1, 2, 3]);
+    let value = list.to_value();
+    assert_eq!(value, Value::from(Vec::from([1, 2, 3])));
+    assert_eq!(
+        arrayvec::ArrayVec::<i32, 3>::from_reflect(&value).unwrap(),
+        list
+    );
+
+    // values that don't fit are ignored
+    list.as_list_mut().unwrap().push(&4);
+    assert_eq!(list.as_slice(), [1, 2, 3]);
+    assert!(arrayvec::ArrayVec::<i32, 2>::from_reflect(&value).is_none());
+}
+
+#[test]
+#[cfg(feature = "tinyvec")]
+fn tinyvec() {
+    let list = tinyvec::TinyVec::<[i32; 2]>::from(&[1, 2, 3][..]);
+    let value = list.to_value();
+    assert_eq!(value, Value::from(Vec::from([1, 2, 3])));
+    assert_eq!(
+        tinyvec::TinyVec::<[i32; 2]>::from_reflect(&value).unwrap(),
+        list
+    );
+}
+
+#[test]
+fn cow() {
+    use alloc::borrow::Cow;
+
+    static NUMBERS: [i32; 3] = [1, 2, 3];
+
+    let mut list: Cow<'static, [i32]> = Cow::Borrowed(&NUMBERS);
+    let value = list.to_value();
+    assert_eq!(value, Value::from(Vec::from([1, 2, 3])));
+    assert_eq!(Cow::<'static, [i32]>::from_reflect(&value).unwrap(), list);
+
+    // borrowed slices are copied when mutated
+    list.as_list_mut().unwrap().push(&4);
+    *list
+        .as_list_mut()
+        .unwrap()
+        .get_mut(0)
+        .unwrap()
+        .downcast_mut::<i32>()
+        .unwrap() = 0;
+    assert!(matches!(list, Cow::Owned(_)));
+    assert_eq!(*list, [0, 2, 3, 4]);
+    assert_eq!(NUMBERS, [1, 2, 3]);
+}
+
+#[test]
+fn owned_iteration() {
+    let mut list = Vec::from([1, 2, 3]);
+    let drained = list
+        .as_reflect_mut()
+        .as_list_mut()
+        .unwrap()
+        .drain()
+        .map(|value| *value.downcast_ref::<i32>().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(drained, [1, 2, 3]);
+    assert!(list.is_empty());
+
+    // lists without their own implementation pop their elements
+    let mut list = Cow::<'static, [i32]>::Owned(Vec::from([1, 2, 3]));
+    let drained = List::drain(&mut list)
+        .map(|value| *value.downcast_ref::<i32>().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(drained, [1, 2, 3]);
+    assert!(list.is_empty());
+
+    let value: Box<dyn Reflect> = Box::new(VecDeque::from(["a".to_owned(), "b".to_owned()]));
+    let elements = value
+        .into_list()
+        .unwrap()
+        .into_elements()
+        .map(|value| value.downcast_ref::<String>().unwrap().clone())
+        .collect::<Vec<_>>();
+    assert_eq!(elements, ["a", "b"]);
+}
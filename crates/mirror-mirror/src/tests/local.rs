@@ -0,0 +1,65 @@
+use alloc::rc::Rc;
+use core::cell::RefCell;
+
+use crate::LocalReflect;
+use crate::Reflect;
+
+#[derive(Reflect, Clone, Debug, PartialEq)]
+#[reflect(crate_name(crate))]
+struct Node {
+    name: String,
+}
+
+fn node(name: &str) -> Node {
+    Node {
+        name: name.to_owned(),
+    }
+}
+
+#[test]
+fn reflect_types_are_local_reflect() {
+    let mut local: Box<dyn LocalReflect> = Box::new(node("a"));
+
+    assert_eq!(local.to_value(), Some(node("a").to_value()));
+    assert!(local.patch(&node("b")));
+    assert_eq!(local.downcast_ref::<Node>(), Some(&node("b")));
+    assert_eq!(
+        local.type_descriptor().unwrap().type_name(),
+        node("b").type_descriptor().type_name()
+    );
+    assert_eq!(format!("{local:?}"), r#"Node { name: "b" }"#);
+}
+
+#[test]
+fn rc() {
+    let shared = Rc::new(node("a"));
+    let mut local: Box<dyn LocalReflect> = Box::new(Rc::clone(&shared));
+
+    assert_eq!(local.to_value(), Some(node("a").to_value()));
+    assert!(local.downcast_ref::<Rc<Node>>().is_some());
+
+    // shared `Rc`s without interior mutability can't be mutated
+    assert!(!local.patch(&node("b")));
+    assert_eq!(*shared, node("a"));
+
+    drop(shared);
+    assert!(local.patch(&node("b")));
+    assert_eq!(**local.downcast_ref::<Rc<Node>>().unwrap(), node("b"));
+}
+
+#[test]
+fn rc_ref_cell() {
+    let shared = Rc::new(RefCell::new(node("a")));
+    let mut local: Box<dyn LocalReflect> = Box::new(Rc::clone(&shared));
+
+    assert!(local.patch(&node("b")));
+    assert_eq!(*shared.borrow(), node("b"));
+
+    let guard = shared.borrow_mut();
+    assert_eq!(local.to_value(), None);
+    assert!(!local.patch(&node("c")));
+    assert_eq!(format!("{local:?}"), "<borrowed>");
+    drop(guard);
+
+    assert_eq!(local.to_value(), Some(node("b").to_value()));
+}
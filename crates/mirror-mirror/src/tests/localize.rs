@@ -0,0 +1,81 @@
+use alloc::collections::BTreeMap;
+
+use crate::key_path;
+use crate::key_path::GetPath;
+use crate::localize::collect_strings_with_meta;
+use crate::Reflect;
+
+#[derive(Reflect, Clone, Debug)]
+#[reflect(crate_name(crate))]
+struct Quest {
+    id: String,
+    #[reflect(meta(localize = true))]
+    title: String,
+    steps: Vec<Step>,
+    rewards: BTreeMap<u32, Label>,
+}
+
+#[derive(Reflect, Clone, Debug)]
+#[reflect(crate_name(crate))]
+enum Step {
+    Talk {
+        npc: String,
+        #[reflect(meta(localize = true))]
+        line: String,
+    },
+    #[reflect(meta(localize = true))]
+    Hint(String, String),
+    Wait(u32),
+}
+
+#[derive(Reflect, Clone, Debug)]
+#[reflect(crate_name(crate), meta(localize = true))]
+struct Label(String);
+
+#[test]
+fn collects_tagged_strings() {
+    let mut quest = Quest {
+        id: "q1".to_owned(),
+        title: "The Quest".to_owned(),
+        steps: Vec::from([
+            Step::Talk {
+                npc: "npc_1".to_owned(),
+                line: "Hello".to_owned(),
+            },
+            Step::Hint("Look".to_owned(), "Left".to_owned()),
+            Step::Wait(1),
+        ]),
+        rewards: BTreeMap::from([(10, Label("Gold".to_owned()))]),
+    };
+
+    let strings = collect_strings_with_meta(&quest, "localize");
+    assert_eq!(
+        strings,
+        [
+            (key_path!(.title), "The Quest".to_owned()),
+            (key_path!(.steps[0_usize]::Talk.line), "Hello".to_owned()),
+            (key_path!(.steps[1_usize]::Hint.0), "Look".to_owned()),
+            (key_path!(.steps[1_usize]::Hint.1), "Left".to_owned()),
+            (key_path!(.rewards[10_u32].0), "Gold".to_owned()),
+        ]
+    );
+
+    // the paths can be used to write translations back
+    for (path, string) in strings {
+        *quest.get_at_mut::<String>(&path).unwrap() = string.to_uppercase();
+    }
+    assert_eq!(quest.title, "THE QUEST");
+    assert_eq!(quest.rewards[&10].0, "GOLD");
+    assert_eq!(quest.id, "q1");
+}
+
+#[test]
+fn other_keys() {
+    let quest = Quest {
+        id: "q1".to_owned(),
+        title: "The Quest".to_owned(),
+        steps: Vec::new(),
+        rewards: BTreeMap::new(),
+    };
+    assert!(collect_strings_with_meta(&quest, "other").is_empty());
+}
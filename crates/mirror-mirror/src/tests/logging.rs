@@ -0,0 +1,123 @@
+use alloc::collections::BTreeMap;
+
+use crate::key_path;
+use crate::key_path::KeyPathPattern;
+use crate::logging::LogOptions;
+use crate::logging::LogValue;
+use crate::type_info::GetMeta;
+use crate::DescribeType;
+use crate::Reflect;
+
+#[derive(Reflect, Clone, Debug)]
+#[reflect(crate_name(crate))]
+struct Config {
+    name: String,
+    credentials: Credentials,
+    limits: BTreeMap<String, u32>,
+    nested: Option<Box<Config>>,
+}
+
+#[derive(Reflect, Clone, Debug)]
+#[reflect(crate_name(crate))]
+enum Credentials {
+    Password { user: String, password: String },
+}
+
+fn config() -> Config {
+    Config {
+        name: "server".to_owned(),
+        credentials: Credentials::Password {
+            user: "admin".to_owned(),
+            password: "hunter2".to_owned(),
+        },
+        limits: BTreeMap::from([("a".to_owned(), 1), ("b".to_owned(), 2)]),
+        nested: Some(Box::new(Config {
+            name: "inner".to_owned(),
+            credentials: Credentials::Password {
+                user: "user".to_owned(),
+                password: "secret".to_owned(),
+            },
+            limits: BTreeMap::new(),
+            nested: None,
+        })),
+    }
+}
+
+#[test]
+fn redacts() {
+    let redact = [
+        KeyPathPattern::from(key_path!(.credentials::Password.password)),
+        KeyPathPattern::default().field("nested"),
+    ];
+    let options = LogOptions {
+        redact: &redact,
+        ..Default::default()
+    };
+
+    let formatted = LogValue::new(&config(), &options).to_string();
+    assert!(!formatted.contains("hunter2"));
+    assert!(!formatted.contains("secret"));
+    assert!(formatted.contains(r#"Password { user: "admin", password: <redacted> }"#));
+    assert!(formatted.contains("nested: <redacted>"));
+}
+
+#[test]
+fn truncates() {
+    let options = LogOptions {
+        max_depth: 2,
+        max_elems: 1,
+        ..Default::default()
+    };
+
+    let formatted = LogValue::new(&config(), &options).to_string();
+    assert!(formatted.contains(r#"limits: {"a": 1, ..1 more: ..}"#));
+    assert!(formatted.contains("nested: Some(..)"));
+
+    let options = LogOptions {
+        max_depth: 0,
+        ..Default::default()
+    };
+    assert_eq!(LogValue::new(&config(), &options).to_string(), "..");
+    assert_eq!(LogValue::new(&1_i32, &options).to_string(), "1");
+}
+
+#[test]
+fn sensitive() {
+    #[derive(Reflect, Clone, Debug)]
+    #[reflect(crate_name(crate))]
+    struct Account {
+        name: String,
+        #[reflect(sensitive)]
+        pin: u32,
+        keys: Vec<ApiKey>,
+        auth: Auth,
+    }
+
+    #[derive(Reflect, Clone, Debug)]
+    #[reflect(crate_name(crate), sensitive)]
+    struct ApiKey(String);
+
+    #[derive(Reflect, Clone, Debug)]
+    #[reflect(crate_name(crate))]
+    enum Auth {
+        #[reflect(sensitive)]
+        Token(String),
+    }
+
+    let account = Account {
+        name: "alice".to_owned(),
+        pin: 1234,
+        keys: Vec::from([ApiKey("abc".to_owned())]),
+        auth: Auth::Token("xyz".to_owned()),
+    };
+
+    let descriptor = <Account as DescribeType>::type_descriptor();
+    let struct_type = descriptor.as_struct().unwrap();
+    assert!(struct_type.field_type("pin").unwrap().is_sensitive());
+    assert!(!struct_type.field_type("name").unwrap().is_sensitive());
+
+    let formatted = LogValue::new(&account, &LogOptions::default()).to_string();
+    assert!(formatted.ends_with(
+        r#"Account { name: "alice", pin: <redacted>, keys: [<redacted>], auth: Token(<redacted>) }"#
+    ));
+}
@@ -0,0 +1,96 @@
+use glam::Quat;
+use glam::Vec3;
+use glam::Vec4;
+use macaw::BoundingBox;
+use macaw::ColorRgba8;
+use macaw::Conformal3;
+use macaw::IsoTransform;
+use macaw::Plane3;
+
+use crate::DescribeType;
+use crate::FromReflect;
+use crate::Reflect;
+use crate::Value;
+
+#[derive(Reflect, Debug, Clone, PartialEq)]
+#[reflect(crate_name(crate))]
+struct Shape {
+    color: ColorRgba8,
+    bounds: BoundingBox,
+    conformal: Conformal3,
+    transform: IsoTransform,
+    plane: Plane3,
+}
+
+#[test]
+fn round_trips_through_value() {
+    let shape = Shape {
+        color: ColorRgba8([255, 128, 0, 255]),
+        bounds: BoundingBox::from_min_max(Vec3::ZERO, Vec3::ONE),
+        conformal: Conformal3::from_scale_rotation_translation(
+            2.0,
+            Quat::from_rotation_x(1.0),
+            Vec3::new(1.0, 2.0, 3.0),
+        ),
+        transform: IsoTransform::from_rotation_translation(
+            Quat::from_rotation_z(1.0),
+            Vec3::new(4.0, 5.0, 6.0),
+        ),
+        plane: Plane3::from_normal_dist(Vec3::Z, 2.0),
+    };
+
+    assert_eq!(Shape::from_reflect(&shape.to_value()).unwrap(), shape);
+    assert!(<Shape as DescribeType>::type_descriptor()
+        .default_value()
+        .is_some());
+
+    let bounds = <BoundingBox as DescribeType>::type_descriptor();
+    let bounds = bounds.get_type().as_struct().unwrap();
+    assert_eq!(
+        bounds
+            .field_types()
+            .map(|field| field.name())
+            .collect::<Vec<_>>(),
+        ["min", "max"]
+    );
+}
+
+#[test]
+fn from_plain_arrays() {
+    assert_eq!(
+        ColorRgba8::from_reflect(&[1_u8, 2, 3, 4].to_value()).unwrap(),
+        ColorRgba8([1, 2, 3, 4])
+    );
+
+    assert_eq!(
+        BoundingBox::from_reflect(&[[0.0_f32, 0.0, 0.0], [1.0, 2.0, 3.0]].to_value()).unwrap(),
+        BoundingBox::from_min_max(Vec3::ZERO, Vec3::new(1.0, 2.0, 3.0))
+    );
+
+    assert_eq!(
+        Plane3::from_reflect(&[0.0_f32, 0.0, 1.0, 2.0].to_value()).unwrap(),
+        Plane3::from_normal_dist(Vec3::Z, 2.0)
+    );
+
+    let transform = Value::from(
+        crate::struct_::StructValue::new()
+            .with_field("rotation", [0.0_f32, 0.0, 0.0, 1.0])
+            .with_field("translation", [1.0_f32, 2.0, 3.0]),
+    );
+    assert_eq!(
+        IsoTransform::from_reflect(&transform).unwrap(),
+        IsoTransform::from_translation(Vec3::new(1.0, 2.0, 3.0))
+    );
+
+    let conformal = Value::from(
+        crate::struct_::StructValue::new()
+            .with_field("translation_and_scale", Vec4::new(1.0, 2.0, 3.0, 2.0))
+            .with_field("rotation", [0.0_f32, 0.0, 0.0, 1.0]),
+    );
+    assert_eq!(
+        Conformal3::from_reflect(&conformal).unwrap(),
+        Conformal3::from_scale_rotation_translation(2.0, Quat::IDENTITY, Vec3::new(1.0, 2.0, 3.0))
+    );
+
+    assert!(Plane3::from_reflect(&[1.0_f32, 2.0].to_value()).is_none());
+}
@@ -1,8 +1,15 @@
+use alloc::boxed::Box;
 use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use std::collections::HashMap;
 
 use crate::key_path;
 use crate::key_path::GetPath;
+use crate::map::SortedHashMap;
 use crate::DescribeType;
+use crate::FromReflect;
 use crate::GetField;
 use crate::GetFieldMut;
 use crate::Map;
@@ -63,3 +70,211 @@ fn exoctic_value_type() {
     map.as_map_mut().unwrap().insert(&1, &foo_default_value);
     assert_eq!(map.len(), 1);
 }
+
+#[test]
+fn string_keys_longer_than_scratch_capacity() {
+    let long = "x".repeat(crate::scratch::SCRATCH_CAPACITY + 1);
+    let mut map = BTreeMap::from([("foo".to_owned(), 1), (long.clone(), 1)]);
+    let value = map.to_value();
+
+    for key in ["foo", &long] {
+        let map = map.as_reflect_mut().as_map_mut().unwrap();
+        *map.get_field_mut::<i32>(key).unwrap() += 1;
+        assert!(map.get_field::<i32>("bar").is_none());
+
+        assert_eq!(value.get_field::<i32>(key.to_owned()), Some(&1));
+        assert!(value.get_field::<i32>("bar".to_owned()).is_none());
+    }
+
+    assert_eq!(map["foo"], 2);
+    assert_eq!(map.get_field::<i32>(long.as_str()).unwrap(), &2);
+}
+
+#[test]
+fn borrowed_string_keys() {
+    let mut map = BTreeMap::from([("foo".to_owned(), 1)]);
+    let reflect = map.as_reflect_mut().as_map_mut().unwrap();
+
+    assert_eq!(
+        reflect.get_str("foo").unwrap().downcast_ref::<i32>(),
+        Some(&1)
+    );
+    assert!(reflect.get_str("bar").is_none());
+    *reflect
+        .get_str_mut("foo")
+        .unwrap()
+        .downcast_mut::<i32>()
+        .unwrap() = 2;
+    assert_eq!(
+        reflect
+            .get(&crate::Value::from("foo"))
+            .unwrap()
+            .downcast_ref::<i32>(),
+        Some(&2)
+    );
+
+    let value = map.to_value();
+    let value = value.reflect_ref().as_map().unwrap();
+    assert_eq!(
+        value.get_str("foo").unwrap().downcast_ref::<i32>(),
+        Some(&2)
+    );
+
+    // keys that aren't strings are never found
+    let map = BTreeMap::from([(1, 1)]);
+    assert!(map.get_str("1").is_none());
+}
+
+#[test]
+fn entry() {
+    let mut map = BTreeMap::from([("a".to_owned(), 1)]);
+    let reflect = map.as_reflect_mut().as_map_mut().unwrap();
+
+    assert!(reflect.entry(&"a".to_owned()).is_occupied());
+    assert!(!reflect.entry(&"b".to_owned()).is_occupied());
+
+    for key in ["a", "b", "b"] {
+        reflect
+            .entry(&key.to_owned())
+            .and_modify(|value| *value.downcast_mut::<i32>().unwrap() += 10)
+            .or_insert_with(|| 1_i32)
+            .unwrap();
+    }
+    assert_eq!(
+        map,
+        BTreeMap::from([("a".to_owned(), 11), ("b".to_owned(), 11)])
+    );
+
+    // keys and values that can't be converted aren't inserted
+    let reflect = map.as_reflect_mut().as_map_mut().unwrap();
+    assert!(reflect.entry(&1_i32).or_insert(&1_i32).is_none());
+    assert!(reflect.entry(&"c".to_owned()).or_insert(&true).is_none());
+    assert_eq!(map.len(), 2);
+
+    // `Value` maps
+    let mut value = map.to_value();
+    let reflect = value.as_reflect_mut().as_map_mut().unwrap();
+    *reflect
+        .entry(&"c".to_owned())
+        .or_insert(&3_i32)
+        .unwrap()
+        .downcast_mut::<i32>()
+        .unwrap() += 1;
+    assert_eq!(reflect.get_field::<i32>("c").unwrap(), &4);
+}
+
+#[test]
+fn owned_iteration() {
+    let mut map = BTreeMap::from([(1, "a".to_owned()), (2, "b".to_owned())]);
+    let drained = map
+        .as_reflect_mut()
+        .as_map_mut()
+        .unwrap()
+        .drain()
+        .map(|(key, value)| {
+            (
+                *key.downcast_ref::<i32>().unwrap(),
+                value.downcast_ref::<String>().unwrap().clone(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(drained, [(1, "a".to_owned()), (2, "b".to_owned())]);
+    assert!(map.is_empty());
+
+    let value = Box::new(BTreeMap::from([(1, 2)]).to_value());
+    let entries = value
+        .into_map()
+        .unwrap()
+        .into_entries()
+        .map(|(key, value)| (i32::from_reflect(&*key), i32::from_reflect(&*value)))
+        .collect::<Vec<_>>();
+    assert_eq!(entries, [(Some(1), Some(2))]);
+}
+
+#[test]
+fn hash_map() {
+    let mut map = HashMap::from([("a".to_owned(), 1), ("b".to_owned(), 2)]);
+
+    assert_eq!(map.get_field::<i32>("a").unwrap(), &1);
+    *map.get_field_mut::<i32>("b").unwrap() = 3;
+    assert_eq!(map["b"], 3);
+
+    let reflect = map.as_reflect_mut().as_map_mut().unwrap();
+    assert!(reflect.insert(&"c".to_owned(), &4).is_none());
+    assert_eq!(
+        reflect
+            .remove(&"a".to_owned())
+            .unwrap()
+            .downcast_ref::<i32>()
+            .unwrap(),
+        &1
+    );
+    assert_eq!(reflect.len(), 2);
+
+    // values are ordered by key regardless of the iteration order
+    let value = map.to_value();
+    assert_eq!(
+        value,
+        BTreeMap::from([("b".to_owned(), 3), ("c".to_owned(), 4)]).to_value()
+    );
+    assert_eq!(HashMap::<String, i32>::from_reflect(&value).unwrap(), map);
+    assert_eq!(
+        map.clone_reflect().downcast_ref::<HashMap<String, i32>>(),
+        Some(&map)
+    );
+
+    let descriptor = <HashMap<String, i32> as DescribeType>::type_descriptor();
+    assert!(descriptor.get_type().as_map().is_some());
+}
+
+#[test]
+fn sorted_hash_map() {
+    let mut map = SortedHashMap::from(HashMap::from([(3, "c".to_owned()), (1, "a".to_owned())]));
+    map.0.insert(2, "b".to_owned());
+
+    let keys = Map::iter(&map)
+        .map(|(key, _)| *key.downcast_ref::<i32>().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(keys, [1, 2, 3]);
+    assert_eq!(
+        format!("{:?}", map.as_reflect()),
+        r#"{1: "a", 2: "b", 3: "c"}"#
+    );
+
+    let values = Map::iter_mut(&mut map)
+        .map(|(_, value)| value.downcast_ref::<String>().unwrap().clone())
+        .collect::<Vec<_>>();
+    assert_eq!(values, ["a", "b", "c"]);
+
+    let drained = Map::drain(&mut map)
+        .map(|(key, _)| *key.downcast_ref::<i32>().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(drained, [1, 2, 3]);
+    assert!(map.is_empty());
+}
+
+#[test]
+fn derive_with_hash_map() {
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    #[reflect(crate_name(crate), allow_hash_map)]
+    struct Foo {
+        scores: HashMap<String, u32>,
+        sorted: SortedHashMap<u32, bool>,
+    }
+
+    let mut foo = Foo {
+        scores: HashMap::from([("a".to_owned(), 1)]),
+        sorted: SortedHashMap::default(),
+    };
+
+    assert_eq!(foo.get_field::<u32>(("scores", "a")).unwrap(), &1);
+    foo.patch(&Foo {
+        scores: HashMap::from([("a".to_owned(), 2), ("b".to_owned(), 3)]),
+        sorted: SortedHashMap::from(HashMap::from([(1, true)])),
+    });
+    // patching only changes existing entries
+    assert_eq!(foo.scores, HashMap::from([("a".to_owned(), 2)]));
+    assert!(foo.sorted.is_empty());
+
+    assert_eq!(Foo::from_reflect(&foo.to_value()).unwrap(), foo);
+}
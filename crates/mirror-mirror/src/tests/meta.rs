@@ -57,3 +57,178 @@ enum C {
     #[allow(clippy::enum_variant_names)]
     C,
 }
+
+#[test]
+fn meta_at() {
+    use crate::key_path;
+    use alloc::vec::Vec;
+
+    #[derive(Reflect, Debug, Clone)]
+    #[reflect(crate_name(crate))]
+    struct Scene {
+        #[reflect(meta(label = "Light"))]
+        light: Light,
+        #[reflect(meta(label = "Lights"))]
+        lights: Vec<Light>,
+        shape: Shape,
+    }
+
+    #[derive(Reflect, Debug, Clone)]
+    #[reflect(crate_name(crate), meta(icon = "bulb", label = "A light"))]
+    struct Light(#[reflect(meta(max = 100))] u8);
+
+    #[derive(Reflect, Debug, Clone)]
+    #[reflect(crate_name(crate))]
+    enum Shape {
+        #[reflect(meta(label = "Circle"))]
+        Circle {
+            #[reflect(meta(min = 0.0))]
+            radius: f32,
+        },
+    }
+
+    let descriptor = <Scene as DescribeType>::type_descriptor();
+    let string = |value: Option<&dyn Reflect>| value?.downcast_ref::<String>().cloned();
+
+    assert_eq!(
+        string(descriptor.meta_at(&key_path!(.light), "label")).unwrap(),
+        "Light"
+    );
+    assert!(descriptor.meta_at(&key_path!(.light), "icon").is_none());
+    assert_eq!(
+        string(descriptor.inherited_meta_at(&key_path!(.light), "icon")).unwrap(),
+        "bulb"
+    );
+    assert!(descriptor.meta_at(&key_path!(.light.0), "max").is_some());
+    assert!(descriptor
+        .meta_at(&key_path!(.shape::Circle.radius), "min")
+        .is_some());
+    assert_eq!(
+        string(descriptor.meta_at(&key_path!(.shape::Circle), "label")).unwrap(),
+        "Circle"
+    );
+
+    // elements of lists aren't fields, so they get the metadata of their type
+    assert_eq!(
+        string(descriptor.meta_at(&key_path!(.lights[0]), "label")).unwrap(),
+        "A light"
+    );
+
+    assert!(descriptor.meta_at(&key_path!(.missing), "label").is_none());
+
+    let keys = |iter: crate::type_info::MetaIter<'_>| {
+        iter.map(|(key, _)| key.to_owned()).collect::<Vec<_>>()
+    };
+    assert_eq!(keys(descriptor.meta_iter_at(&key_path!(.light))), ["label"]);
+    let meta = descriptor
+        .inherited_meta_iter_at(&key_path!(.light))
+        .collect::<Vec<_>>();
+    assert_eq!(meta.len(), 2);
+    assert_eq!(meta[0].0, "icon");
+    // the field's metadata takes precedence
+    assert_eq!(meta[1].0, "label");
+    assert_eq!(meta[1].1.downcast_ref::<String>().unwrap(), "Light");
+    assert!(keys(descriptor.meta_iter_at(&key_path!(.missing))).is_empty());
+}
+
+#[test]
+fn typed_meta() {
+    use crate::meta::known::Category;
+    use crate::meta::known::Range;
+    use crate::meta::known::ReadOnly;
+    use crate::meta::known::Tooltip;
+
+    #[derive(Reflect, Debug, Clone)]
+    #[reflect(crate_name(crate), meta(category = "Lighting", tooltip = String::from("A light")))]
+    enum Light {
+        #[reflect(meta(read_only = true))]
+        Point {
+            #[reflect(meta(range = 0.5..=2.0, max = 2.0))]
+            radius: f32,
+        },
+    }
+
+    let descriptor = <Light as DescribeType>::type_descriptor();
+    assert_eq!(
+        descriptor.typed_meta::<Category>().as_deref(),
+        Some("Lighting")
+    );
+    assert_eq!(
+        descriptor.typed_meta::<Tooltip>().as_deref(),
+        Some("A light")
+    );
+    assert_eq!(descriptor.typed_meta::<ReadOnly>(), None);
+
+    let variant = descriptor.as_enum().unwrap().variant("Point").unwrap();
+    assert_eq!(variant.typed_meta::<ReadOnly>(), Some(true));
+
+    let radius = variant.field_type("radius").unwrap();
+    assert_eq!(radius.typed_meta::<Range>(), Some(0.5..=2.0));
+    // unknown keys aren't affected
+    assert_eq!(radius.get_meta::<f64>("max"), Some(2.0));
+}
+
+#[test]
+fn docs_summary() {
+    /// A `Light` that **shines**, see [`Spot`] and [the guide](https://example.com).
+    /// Placed by the [level editor][editor].
+    ///
+    /// More details.
+    #[derive(Reflect, Debug, Clone)]
+    #[reflect(crate_name(crate))]
+    struct Light {
+        /// # Radius
+        radius: f32,
+        intensity: f32,
+    }
+
+    let descriptor = <Light as DescribeType>::type_descriptor();
+    assert_eq!(
+        descriptor.get_type().docs_summary().unwrap(),
+        "A Light that shines, see Spot and the guide. Placed by the level editor."
+    );
+
+    let fields = descriptor.as_struct().unwrap();
+    assert_eq!(
+        fields.field_type("radius").unwrap().docs_summary().unwrap(),
+        "Radius"
+    );
+    assert_eq!(fields.field_type("intensity").unwrap().docs_summary(), None);
+}
+
+#[test]
+fn skip_docs() {
+    /// Docs.
+    #[derive(Reflect, Debug, Clone)]
+    #[reflect(crate_name(crate), docs(skip))]
+    enum Light {
+        /// Docs.
+        Point {
+            /// Docs.
+            radius: f32,
+        },
+    }
+
+    /// Docs.
+    #[derive(Reflect, Debug, Clone)]
+    #[reflect(crate_name(crate))]
+    struct Lamp {
+        /// Docs.
+        #[reflect(docs(skip))]
+        light: Light,
+        /// Kept.
+        on: bool,
+    }
+
+    let descriptor = <Light as DescribeType>::type_descriptor();
+    assert!(descriptor.get_type().docs().is_empty());
+    let variant = descriptor.as_enum().unwrap().variant("Point").unwrap();
+    assert!(variant.docs().is_empty());
+    assert!(variant.field_type("radius").unwrap().docs().is_empty());
+
+    let descriptor = <Lamp as DescribeType>::type_descriptor();
+    let lamp = descriptor.as_struct().unwrap();
+    assert_eq!(lamp.docs(), &[" Docs."]);
+    assert!(lamp.field_type("light").unwrap().docs().is_empty());
+    assert_eq!(lamp.field_type("on").unwrap().docs(), &[" Kept."]);
+}
@@ -1,17 +1,59 @@
 use crate::Reflect;
 
 mod array;
+mod coerce;
+mod compat;
+mod conformance;
+mod diff;
+#[cfg(feature = "speedy")]
+mod dump;
+mod edit;
 mod enum_;
+mod error;
+#[cfg(feature = "speedy_evolution")]
+mod evolution;
+mod fmt;
+#[cfg(feature = "fuzz")]
+mod fuzz;
+mod get_field;
+#[cfg(feature = "glam")]
+mod glam;
 mod key_path;
 mod list;
+mod local;
+mod localize;
+mod logging;
+#[cfg(feature = "macaw")]
+mod macaw;
 mod map;
 mod meta;
+mod opaque;
+mod option;
+mod parse;
+mod patch;
+mod permissions;
+mod query;
+mod registry;
+#[cfg(feature = "schema")]
+mod schema;
+#[cfg(feature = "serde")]
+mod serde;
+mod set;
 mod simple_type_name;
 mod struct_;
+mod table;
+mod tracked;
 mod tuple;
 mod tuple_struct;
 mod type_info;
+mod ui_hints;
+mod validate;
 mod value;
+mod value_builder;
+mod value_ref;
+#[cfg(feature = "speedy")]
+mod versioned;
+mod visit;
 
 #[derive(Reflect)]
 #[reflect(crate_name(crate), opt_out(Debug, Clone))]
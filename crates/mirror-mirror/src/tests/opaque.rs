@@ -0,0 +1,172 @@
+use crate::registry::OpaqueCodec;
+use crate::registry::TypeRegistry;
+use crate::type_info::GetMeta;
+use crate::type_info::Type;
+use crate::DescribeType;
+use crate::FromReflect;
+use crate::GetField;
+use crate::Opaque;
+use crate::Reflect;
+use crate::ReflectRef;
+use crate::Value;
+
+#[derive(Clone, PartialEq, Debug)]
+struct Handle(u32);
+
+#[derive(Reflect, Clone, Debug)]
+#[reflect(crate_name(crate))]
+struct Foo {
+    handle: Opaque<Handle>,
+    n: i32,
+}
+
+#[test]
+fn reflected_as_opaque() {
+    let foo = Foo {
+        handle: Opaque(Handle(1)),
+        n: 1,
+    };
+
+    let handle = foo.get_field::<Opaque<Handle>>("handle").unwrap();
+    assert_eq!(handle.0, Handle(1));
+    assert!(matches!(
+        foo.as_struct()
+            .unwrap()
+            .field("handle")
+            .unwrap()
+            .reflect_ref(),
+        ReflectRef::Opaque(_)
+    ));
+
+    let descriptor = <Foo as DescribeType>::type_descriptor();
+    let Type::Struct(struct_type) = descriptor.get_type() else {
+        panic!("expected a struct")
+    };
+    let Type::Opaque(opaque_type) = struct_type.field_type("handle").unwrap().get_type() else {
+        panic!("expected an opaque type")
+    };
+    assert_eq!(
+        opaque_type.get_meta::<String>("inner").unwrap(),
+        core::any::type_name::<Handle>(),
+    );
+}
+
+#[test]
+fn kept_by_from_reflect_and_patch() {
+    let mut foo = Foo {
+        handle: Opaque(Handle(1)),
+        n: 1,
+    };
+
+    let copy = Foo::from_reflect(&foo).unwrap();
+    assert_eq!(copy.handle, Opaque(Handle(1)));
+
+    foo.patch(&Foo {
+        handle: Opaque(Handle(2)),
+        n: 2,
+    });
+    assert_eq!(foo.handle, Opaque(Handle(2)));
+    assert_eq!(foo.n, 2);
+
+    // the handle can't be represented as a `Value`
+    assert!(Foo::from_reflect(&foo.to_value()).is_none());
+}
+
+#[derive(Clone, PartialEq, Debug)]
+struct Mesh {
+    id: u32,
+    name: String,
+}
+
+impl OpaqueCodec for Mesh {
+    fn encode(&self) -> Value {
+        (self.id, self.name.clone()).to_value()
+    }
+
+    fn decode(value: &dyn Reflect) -> Option<Self> {
+        let (id, name) = <(u32, String)>::from_reflect(value)?;
+        Some(Self { id, name })
+    }
+}
+
+#[derive(Reflect, Clone, Debug, PartialEq)]
+#[reflect(crate_name(crate))]
+struct Model {
+    mesh: Opaque<Mesh>,
+    scale: f32,
+}
+
+#[test]
+fn registered_codec() {
+    let mesh = Mesh {
+        id: 1,
+        name: "cube".to_owned(),
+    };
+
+    let registry = TypeRegistry::new().with_opaque_codec::<Mesh>();
+    let value = registry.encode_opaque(&mesh).unwrap();
+    assert_eq!(registry.decode_opaque::<Mesh>(&value).unwrap(), mesh);
+    assert!(registry.encode_opaque(&Handle(1)).is_none());
+
+    TypeRegistry::global()
+        .write()
+        .unwrap()
+        .register_opaque_codec::<Mesh>();
+
+    let model = Model {
+        mesh: Opaque(mesh),
+        scale: 2.0,
+    };
+    let value = model.to_value();
+    assert_eq!(Model::from_reflect(&value).unwrap(), model);
+
+    #[cfg(feature = "serde")]
+    {
+        let json = serde_json::to_string(&value).unwrap();
+        let value = serde_json::from_str::<Value>(&json).unwrap();
+        assert_eq!(Model::from_reflect(&value).unwrap(), model);
+    }
+
+    #[cfg(feature = "speedy")]
+    {
+        use speedy::Readable;
+        use speedy::Writable;
+
+        let bytes = value.write_to_vec().unwrap();
+        let value = Value::read_from_buffer(&bytes).unwrap();
+        assert_eq!(Model::from_reflect(&value).unwrap(), model);
+    }
+}
+
+#[test]
+fn compared_only_when_reached() {
+    use alloc::collections::BTreeMap;
+    use core::cmp::Ordering;
+
+    use crate::reflect_cmp;
+
+    let a = BTreeMap::from([(1, Opaque(Handle(1)))]);
+    let b = BTreeMap::from([(2, Opaque(Handle(1)))]);
+    assert_eq!(reflect_cmp(&a, &b), Some(Ordering::Less));
+    assert_eq!(reflect_cmp(&a, &a), None);
+
+    #[derive(Reflect, Clone, Debug)]
+    #[reflect(crate_name(crate))]
+    struct A {
+        a: Opaque<Handle>,
+    }
+
+    #[derive(Reflect, Clone, Debug)]
+    #[reflect(crate_name(crate))]
+    struct B {
+        b: Opaque<Handle>,
+    }
+
+    let a = A {
+        a: Opaque(Handle(1)),
+    };
+    let b = B {
+        b: Opaque(Handle(1)),
+    };
+    assert_eq!(reflect_cmp(&a, &b), Some(Ordering::Less));
+}
@@ -0,0 +1,57 @@
+use alloc::vec::Vec;
+
+use crate::enum_::EnumValue;
+use crate::Reflect;
+use crate::ReflectError;
+
+#[test]
+fn as_option() {
+    let value = Some(1_u8);
+    let option = value.as_option().unwrap();
+    assert!(option.is_some());
+    assert!(!option.is_none());
+    assert_eq!(option.get().unwrap().downcast_ref::<u8>(), Some(&1));
+
+    let value = None::<u8>;
+    let option = value.as_option().unwrap();
+    assert!(option.is_none());
+    assert!(option.get().is_none());
+
+    // values that aren't options, including enum values converted from them
+    assert!(1_u8.as_option().is_none());
+    assert!(Ok::<u8, u8>(1).as_option().is_none());
+    let value = EnumValue::new_tuple_variant_with_capacity("Some", 1)
+        .with_tuple_field(1_u8)
+        .finish();
+    assert!(value.as_option().is_none());
+}
+
+#[test]
+fn as_option_mut() {
+    let mut value = Some(Vec::from([1, 2, 3]));
+
+    let mut option = value.as_option_mut().unwrap();
+    option.get_mut().unwrap().as_list_mut().unwrap().push(&4);
+    assert_eq!(value, Some(Vec::from([1, 2, 3, 4])));
+
+    // the old value is replaced rather than patched
+    let mut option = value.as_option_mut().unwrap();
+    option.set_some(&Vec::from([5])).unwrap();
+    assert_eq!(value, Some(Vec::from([5])));
+
+    let mut option = value.as_option_mut().unwrap();
+    option.set_none();
+    assert!(option.get_mut().is_none());
+    assert_eq!(value, None);
+
+    let mut option = value.as_option_mut().unwrap();
+    option.set_some(&Vec::<i32>::new()).unwrap();
+    assert_eq!(value, Some(Vec::new()));
+
+    let mut option = value.as_option_mut().unwrap();
+    let error: ReflectError = option.set_some(&1_u8).unwrap_err();
+    assert_eq!(error.found(), Some("u8"));
+    assert_eq!(value, Some(Vec::new()));
+
+    assert!(1_u8.as_option_mut().is_none());
+}
@@ -0,0 +1,96 @@
+use crate::key_path;
+use crate::key_path::KeyPath;
+use crate::type_info::ParseScalarError;
+use crate::type_info::ScalarType;
+use crate::DescribeType;
+use crate::FromReflect;
+use crate::Reflect;
+use crate::Value;
+
+#[derive(Reflect, Clone, Debug, PartialEq)]
+#[reflect(crate_name(crate))]
+struct Settings {
+    name: String,
+    key: char,
+    enabled: bool,
+    quality: Quality,
+    shapes: Vec<Shape>,
+}
+
+#[derive(Reflect, Clone, Debug, PartialEq)]
+#[reflect(crate_name(crate))]
+enum Quality {
+    Low,
+    High { samples: u8 },
+}
+
+#[derive(Reflect, Clone, Debug, PartialEq)]
+#[reflect(crate_name(crate))]
+enum Shape {
+    Circle(Value),
+}
+
+#[test]
+fn scalars() {
+    assert_eq!(ScalarType::i32.parse_str("-12\n"), Ok(Value::from(-12)));
+    assert_eq!(ScalarType::u64.parse_str("+7"), Ok(Value::from(7_u64)));
+    assert_eq!(ScalarType::f64.parse_str("1e3"), Ok(Value::from(1000.0)));
+    assert_eq!(ScalarType::bool.parse_str("FALSE"), Ok(Value::from(false)));
+    assert_eq!(ScalarType::bool.parse_str(" 1"), Ok(Value::from(true)));
+    assert_eq!(ScalarType::char.parse_str(" "), Ok(Value::from(' ')));
+    assert_eq!(ScalarType::char.parse_str(" x "), Ok(Value::from('x')));
+    assert_eq!(
+        ScalarType::String.parse_str(" as is "),
+        Ok(Value::from(" as is ".to_owned()))
+    );
+
+    assert_eq!(
+        ScalarType::i8.parse_str("1.5"),
+        Err(ParseScalarError::Invalid {
+            expected: "i8".to_owned(),
+            input: "1.5".to_owned(),
+        })
+    );
+    assert!(ScalarType::bool.parse_str("yes").is_err());
+    assert!(ScalarType::char.parse_str("ab").is_err());
+}
+
+#[test]
+fn at_path() {
+    let descriptor = <Settings as DescribeType>::type_descriptor();
+
+    assert_eq!(
+        descriptor.parse_at(&key_path!(.key), "k"),
+        Ok(Value::from('k'))
+    );
+    assert_eq!(
+        descriptor.parse_at(&key_path!(.enabled), "true"),
+        Ok(Value::from(true))
+    );
+
+    let quality = descriptor.parse_at(&key_path!(.quality), "High").unwrap();
+    assert_eq!(
+        Quality::from_reflect(&quality),
+        Some(Quality::High { samples: 0 })
+    );
+
+    // paths can go through lists, to the type of their elements
+    assert_eq!(
+        descriptor.parse_at(&key_path!(.shapes[0]), "Circle"),
+        Err(ParseScalarError::MissingDefault("Circle".to_owned()))
+    );
+    assert_eq!(
+        descriptor.parse_at(&key_path!(.quality), "Medium"),
+        Err(ParseScalarError::UnknownVariant("Medium".to_owned()))
+    );
+    assert_eq!(
+        descriptor.parse_at(&key_path!(.missing), "1"),
+        Err(ParseScalarError::UnknownPath(key_path!(.missing)))
+    );
+    assert_eq!(
+        descriptor.parse_at(&KeyPath::default(), "1"),
+        Err(ParseScalarError::Unsupported(
+            descriptor.get_type().type_name().to_owned()
+        ))
+    );
+}
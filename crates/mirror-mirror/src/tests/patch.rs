@@ -0,0 +1,123 @@
+use alloc::collections::BTreeMap;
+
+use crate::key_path;
+use crate::struct_::StructValue;
+use crate::Reflect;
+
+#[derive(Reflect, Clone, Debug, PartialEq)]
+#[reflect(crate_name(crate))]
+struct Foo {
+    a: i32,
+    list: Vec<i32>,
+    map: BTreeMap<String, f32>,
+    fruit: Fruit,
+}
+
+#[derive(Reflect, Clone, Debug, PartialEq)]
+#[reflect(crate_name(crate))]
+enum Fruit {
+    Apple { weight: f32 },
+    Banana,
+}
+
+fn foo() -> Foo {
+    Foo {
+        a: 1,
+        list: Vec::from([1, 2]),
+        map: BTreeMap::from([("a".to_owned(), 1.0)]),
+        fruit: Fruit::Apple { weight: 1.0 },
+    }
+}
+
+#[test]
+fn complete() {
+    let mut foo = foo();
+    let report = foo
+        .try_patch(&Foo {
+            a: 2,
+            list: Vec::from([3]),
+            map: BTreeMap::from([("a".to_owned(), 2.0)]),
+            fruit: Fruit::Banana,
+        })
+        .unwrap();
+
+    assert!(report.is_complete());
+    assert_eq!(
+        report.applied(),
+        [
+            key_path!(.a),
+            key_path!(.list[0_usize]),
+            key_path!(.map["a"]),
+            key_path!(.fruit),
+        ]
+    );
+    assert_eq!(
+        foo,
+        Foo {
+            a: 2,
+            list: Vec::from([3, 2]),
+            map: BTreeMap::from([("a".to_owned(), 2.0)]),
+            fruit: Fruit::Banana,
+        }
+    );
+}
+
+#[test]
+fn partial() {
+    let mut foo = foo();
+    let patch = StructValue::new()
+        .with_field("a", 2_i32)
+        .with_field("b", 1_i32)
+        .with_field("list", Vec::from([10_i32, 20, 30]))
+        .with_field("map", BTreeMap::from([("b".to_owned(), 1.0_f32)]))
+        .with_field("fruit", Fruit::Apple { weight: 2.0 });
+
+    let error = foo.try_patch(&patch).unwrap_err();
+    let report = error.report();
+
+    assert_eq!(
+        report.applied(),
+        [
+            key_path!(.a),
+            key_path!(.list[0_usize]),
+            key_path!(.list[1_usize]),
+            key_path!(.fruit::Apple.weight),
+        ]
+    );
+    assert_eq!(
+        report
+            .skipped()
+            .iter()
+            .map(|error| error.path().clone())
+            .collect::<Vec<_>>(),
+        [
+            key_path!(.b),
+            key_path!(.list[2_usize]),
+            key_path!(.map["b"]),
+        ]
+    );
+
+    assert_eq!(foo.a, 2);
+    assert_eq!(foo.list, [10, 20]);
+    assert_eq!(foo.fruit, Fruit::Apple { weight: 2.0 });
+}
+
+#[test]
+fn mismatched_types() {
+    let mut foo = foo();
+
+    let error = foo.try_patch(&1_i32).unwrap_err();
+    assert!(error.report().applied().is_empty());
+    let skipped = &error.report().skipped()[0];
+    assert_eq!(skipped.path(), &key_path!());
+    assert!(skipped.expected().ends_with("Foo"));
+    assert_eq!(skipped.found(), Some("i32"));
+
+    let patch = StructValue::new().with_field("a", 1.0_f32);
+    let error = foo.try_patch(&patch).unwrap_err();
+    let skipped = &error.report().skipped()[0];
+    assert_eq!(skipped.path(), &key_path!(.a));
+    assert_eq!(skipped.expected(), "i32");
+    assert_eq!(skipped.found(), Some("f32"));
+    assert_eq!(foo.a, 1);
+}
@@ -0,0 +1,191 @@
+use alloc::collections::BTreeMap;
+use alloc::collections::BTreeSet;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::key_path;
+use crate::key_path::GetPath;
+use crate::key_path::KeyPathPattern;
+use crate::permissions::restricted_view;
+use crate::permissions::restricted_view_mut;
+use crate::permissions::Access;
+use crate::permissions::Permissions;
+use crate::FromReflect;
+use crate::Reflect;
+
+#[derive(Reflect, Debug, Clone, PartialEq)]
+#[reflect(crate_name(crate))]
+struct Game {
+    players: Vec<Player>,
+    scores: BTreeMap<String, u32>,
+    secret: String,
+}
+
+#[derive(Reflect, Debug, Clone, PartialEq)]
+#[reflect(crate_name(crate))]
+struct Player {
+    name: String,
+    password: String,
+    health: u32,
+}
+
+fn game() -> Game {
+    Game {
+        players: vec![Player {
+            name: "Alice".to_owned(),
+            password: "hunter2".to_owned(),
+            health: 100,
+        }],
+        scores: BTreeMap::from([("Alice".to_owned(), 10)]),
+        secret: "secret".to_owned(),
+    }
+}
+
+fn permissions() -> Permissions {
+    Permissions::new()
+        .allow(key_path!(.players))
+        .allow(key_path!(.scores))
+        .deny(
+            KeyPathPattern::default()
+                .field("players")
+                .any()
+                .field("password"),
+        )
+        .read_only(
+            KeyPathPattern::default()
+                .field("players")
+                .any()
+                .field("name"),
+        )
+}
+
+#[test]
+fn access() {
+    let permissions = permissions();
+
+    assert_eq!(permissions.access(&key_path!()), Access::ReadOnly);
+    assert_eq!(permissions.access(&key_path!(.secret)), Access::Hidden);
+    assert_eq!(permissions.access(&key_path!(.players)), Access::ReadWrite);
+    assert_eq!(
+        permissions.access(&key_path!(.players[0].health)),
+        Access::ReadWrite
+    );
+    assert_eq!(
+        permissions.access(&key_path!(.players[0].name)),
+        Access::ReadOnly
+    );
+    assert_eq!(
+        permissions.access(&key_path!(.players[0].password)),
+        Access::Hidden
+    );
+}
+
+#[test]
+fn read() {
+    let game = game();
+    let permissions = permissions();
+    let view = restricted_view(&game, &permissions);
+
+    assert!(view.at(&key_path!(.secret)).is_none());
+    assert!(view.at(&key_path!(.players[0].password)).is_none());
+    // would expose the password
+    assert!(view.at(&key_path!(.players[0])).is_none());
+    assert_eq!(
+        view.get_at::<String>(&key_path!(.players[0].name)).unwrap(),
+        "Alice"
+    );
+    assert_eq!(
+        view.get_at::<u32>(&key_path!(.scores["Alice"])).unwrap(),
+        &10
+    );
+
+    let value = view.to_value().unwrap();
+    let game = value.as_struct().unwrap();
+    assert!(game.field("secret").is_none());
+    assert!(game.field("scores").is_some());
+    let player = game
+        .field("players")
+        .unwrap()
+        .as_list()
+        .unwrap()
+        .get(0)
+        .unwrap();
+    let player = player.as_struct().unwrap();
+    assert!(player.field("password").is_none());
+    assert_eq!(
+        String::from_reflect(player.field("name").unwrap()).unwrap(),
+        "Alice"
+    );
+}
+
+#[test]
+fn write() {
+    let mut game = game();
+    let permissions = permissions();
+    let mut view = restricted_view_mut(&mut game, &permissions);
+
+    *view
+        .get_at_mut::<u32>(&key_path!(.players[0].health))
+        .unwrap() = 1;
+    assert!(view.at_mut(&key_path!(.players[0].name)).is_none());
+    assert!(view.at_mut(&key_path!(.players[0].password)).is_none());
+    assert!(view.at_mut(&key_path!(.players)).is_none());
+    assert!(view.at_mut(&key_path!(.secret)).is_none());
+    *view
+        .get_at_mut::<u32>(&key_path!(.scores["Alice"]))
+        .unwrap() = 20;
+
+    assert_eq!(game.players[0].health, 1);
+    assert_eq!(game.scores["Alice"], 20);
+}
+
+#[test]
+fn hidden_elements_keep_positions() {
+    let list = vec![1, 2, 3];
+    let tuple = (1, 2, 3);
+    let permissions = Permissions::new()
+        .allow(KeyPathPattern::default())
+        .deny(key_path!([1]))
+        .deny(key_path!(.1));
+
+    let value = restricted_view(&list, &permissions).to_value().unwrap();
+    let list = value.as_list().unwrap();
+    assert_eq!(list.len(), 3);
+    assert_eq!(list.get(0).unwrap().downcast_ref::<i32>(), Some(&1));
+    assert_eq!(list.get(1).unwrap().to_value(), ().to_value());
+    assert_eq!(list.get(2).unwrap().downcast_ref::<i32>(), Some(&3));
+
+    let value = restricted_view(&tuple, &permissions).to_value().unwrap();
+    let tuple = <(i32, (), i32)>::from_reflect(&value).unwrap();
+    assert_eq!(tuple, (1, (), 3));
+}
+
+#[test]
+fn sets_are_hidden_unless_fully_visible() {
+    #[derive(Reflect, Debug, Clone, PartialEq)]
+    #[reflect(crate_name(crate))]
+    struct Account {
+        name: String,
+        tokens: BTreeSet<String>,
+    }
+
+    let account = Account {
+        name: "Alice".to_owned(),
+        tokens: BTreeSet::from(["secret".to_owned()]),
+    };
+
+    let permissions = Permissions::new()
+        .allow(KeyPathPattern::default())
+        .deny(KeyPathPattern::default().field("tokens").any());
+    let view = restricted_view(&account, &permissions);
+    assert!(view.at(&key_path!(.tokens)).is_none());
+    assert!(view.value_at(&key_path!(.tokens)).is_none());
+    let value = view.to_value().unwrap();
+    assert_eq!(value.get_at::<String>(&key_path!(.name)).unwrap(), "Alice");
+    assert!(value.at(&key_path!(.tokens)).is_none());
+
+    let permissions = Permissions::new().allow(KeyPathPattern::default());
+    let value = restricted_view(&account, &permissions).to_value().unwrap();
+    assert_eq!(Account::from_reflect(&value).unwrap(), account);
+}
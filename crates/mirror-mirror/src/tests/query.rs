@@ -0,0 +1,221 @@
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::key_path;
+use crate::key_path::KeyPath;
+use crate::query::Query;
+use crate::Reflect;
+
+#[derive(Reflect, Clone, Debug, PartialEq)]
+#[reflect(crate_name(crate))]
+struct Scene {
+    #[reflect(meta(editable = true))]
+    gravity: f32,
+    bodies: Vec<Body>,
+    named: BTreeMap<String, Body>,
+    shape: Shape,
+}
+
+#[derive(Reflect, Clone, Debug, PartialEq)]
+#[reflect(crate_name(crate))]
+struct Body {
+    #[reflect(meta(editable = true, physics = true))]
+    mass: f32,
+    radius: f32,
+    material: Material,
+}
+
+#[derive(Reflect, Clone, Debug, PartialEq)]
+#[reflect(crate_name(crate), meta(editable = true))]
+struct Material {
+    friction: f32,
+}
+
+#[derive(Reflect, Clone, Debug, PartialEq)]
+#[reflect(crate_name(crate))]
+enum Shape {
+    Circle {
+        #[reflect(meta(editable = true))]
+        radius: f32,
+    },
+    Point,
+}
+
+fn body(mass: f32) -> Body {
+    Body {
+        mass,
+        radius: 1.0,
+        material: Material { friction: 0.5 },
+    }
+}
+
+fn scene() -> Scene {
+    Scene {
+        gravity: 9.8,
+        bodies: Vec::from([body(1.0), body(2.0)]),
+        named: BTreeMap::from([("ball".into(), body(3.0))]),
+        shape: Shape::Circle { radius: 4.0 },
+    }
+}
+
+#[test]
+fn of_type() {
+    let scene = scene();
+
+    assert_eq!(
+        Query::new().of_type::<Material>().find_paths(&scene),
+        [
+            key_path!(.bodies[0_usize].material),
+            key_path!(.bodies[1_usize].material),
+            key_path!(.named["ball"].material),
+        ]
+    );
+
+    assert_eq!(
+        Query::new().of_type::<Scene>().find_paths(&scene),
+        [KeyPath::default()]
+    );
+
+    // the last type wins
+    assert_eq!(
+        Query::new()
+            .of_type::<Material>()
+            .of_type::<Shape>()
+            .find_paths(&scene),
+        [key_path!(.shape)]
+    );
+}
+
+#[test]
+fn with_meta() {
+    let scene = scene();
+
+    assert_eq!(
+        Query::new().with_meta("editable").find_paths(&scene),
+        [
+            key_path!(.gravity),
+            key_path!(.bodies[0_usize].mass),
+            key_path!(.bodies[0_usize].material),
+            key_path!(.bodies[1_usize].mass),
+            key_path!(.bodies[1_usize].material),
+            key_path!(.named["ball"].mass),
+            key_path!(.named["ball"].material),
+            key_path!(.shape::Circle.radius),
+        ]
+    );
+
+    // every key is required
+    assert_eq!(
+        Query::new()
+            .with_meta("editable")
+            .with_meta("physics")
+            .of_type::<f32>()
+            .find_paths(&scene),
+        [
+            key_path!(.bodies[0_usize].mass),
+            key_path!(.bodies[1_usize].mass),
+            key_path!(.named["ball"].mass),
+        ]
+    );
+
+    assert!(Query::new()
+        .with_meta("missing")
+        .find_paths(&scene)
+        .is_empty());
+}
+
+#[test]
+fn matching() {
+    let scene = scene();
+
+    let matches = Query::new()
+        .of_type::<f32>()
+        .matching(|value| value.downcast_ref::<f32>().unwrap() > &2.0)
+        .find(&scene);
+
+    assert_eq!(
+        matches
+            .iter()
+            .map(|(path, value)| (path.clone(), *value.downcast_ref::<f32>().unwrap()))
+            .collect::<Vec<_>>(),
+        [
+            (key_path!(.gravity), 9.8),
+            (key_path!(.named["ball"].mass), 3.0),
+            (key_path!(.shape::Circle.radius), 4.0),
+        ]
+    );
+}
+
+#[test]
+fn everything_matches_empty_query() {
+    let value = (1_i32, Vec::from([2_i32, 3]));
+    assert_eq!(
+        Query::new().find_paths(&value),
+        [
+            KeyPath::default(),
+            key_path!(.0),
+            key_path!(.1),
+            key_path!(.1[0_usize]),
+            key_path!(.1[1_usize]),
+        ]
+    );
+}
+
+#[test]
+fn for_each_mut() {
+    let mut scene = scene();
+
+    let mut paths = Vec::new();
+    Query::new()
+        .of_type::<f32>()
+        .with_meta("physics")
+        .for_each_mut(&mut scene, |path, value| {
+            paths.push(path.clone());
+            *value.downcast_mut::<f32>().unwrap() *= 2.0;
+        });
+
+    assert_eq!(paths.len(), 3);
+    assert_eq!(scene.bodies[0].mass, 2.0);
+    assert_eq!(scene.bodies[1].mass, 4.0);
+    assert_eq!(scene.named["ball"].mass, 6.0);
+    assert_eq!(scene.gravity, 9.8);
+
+    // the root can match too
+    let mut value = 1_i32;
+    Query::new().for_each_mut(&mut value, |_, value| {
+        *value.downcast_mut::<i32>().unwrap() = 2;
+    });
+    assert_eq!(value, 2);
+}
+
+#[test]
+fn removed_matches_are_skipped() {
+    let mut value = Vec::from([1_i32, 2, 3]);
+
+    let mut visited = 0;
+    Query::new()
+        .of_type::<Vec<i32>>()
+        .for_each_mut(&mut value, |_, value| {
+            visited += 1;
+            value.downcast_mut::<Vec<i32>>().unwrap().clear();
+        });
+    assert_eq!(visited, 1);
+
+    let mut value = Vec::from([Vec::from([1_i32]), Vec::from([2])]);
+    let mut visited = Vec::new();
+    Query::new().for_each_mut(&mut value, |path, value| {
+        visited.push(path.clone());
+        if let Some(list) = value.downcast_mut::<Vec<Vec<i32>>>() {
+            list.truncate(1);
+        }
+    });
+    assert_eq!(
+        visited,
+        [
+            KeyPath::default(),
+            key_path!([0_usize]),
+            key_path!([0_usize][0_usize]),
+        ]
+    );
+}
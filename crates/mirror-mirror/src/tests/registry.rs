@@ -0,0 +1,162 @@
+use core::any::TypeId;
+
+use crate::registry::TypeRegistry;
+use crate::DescribeType;
+use crate::Reflect;
+
+#[derive(Reflect, Clone, Debug, PartialEq, Default)]
+#[reflect(crate_name(crate))]
+struct Player {
+    name: String,
+    score: u32,
+}
+
+#[derive(Reflect, Clone, Debug, PartialEq)]
+#[reflect(crate_name(crate), rename = "Player")]
+struct RenamedPlayer {
+    name: String,
+}
+
+#[test]
+fn lookup() {
+    let registry = TypeRegistry::new().with::<Player>().with::<i32>();
+    assert_eq!(registry.len(), 2);
+
+    let type_name = core::any::type_name::<Player>();
+    let registration = registry.get(type_name).unwrap();
+    assert_eq!(registration.type_name(), type_name);
+    assert_eq!(registration.type_id(), TypeId::of::<Player>());
+    assert_eq!(
+        registration.type_descriptor(),
+        &*<Player as DescribeType>::type_descriptor()
+    );
+    assert_eq!(
+        registry
+            .get_by_type_id(TypeId::of::<Player>())
+            .unwrap()
+            .type_name(),
+        type_name
+    );
+
+    let value = registry.from_reflect("i32", &1_i32.to_value()).unwrap();
+    assert_eq!(value.downcast_ref::<i32>(), Some(&1));
+
+    assert!(registry.get("Missing").is_none());
+    assert!(registry.from_reflect("i32", &"foo".to_owned()).is_none());
+}
+
+#[test]
+fn default_instance() {
+    let registry = TypeRegistry::new().with::<Player>();
+    let player = registry
+        .get(core::any::type_name::<Player>())
+        .unwrap()
+        .default_instance()
+        .unwrap();
+    assert_eq!(player.downcast_ref::<Player>(), Some(&Player::default()));
+}
+
+#[test]
+fn same_name_replaces() {
+    let mut registry = TypeRegistry::new();
+    registry.register::<RenamedPlayer>();
+    registry.register::<RenamedPlayer>();
+    assert_eq!(registry.len(), 1);
+
+    #[derive(Reflect, Clone, Debug)]
+    #[reflect(crate_name(crate), rename = "Player")]
+    struct OtherPlayer;
+
+    registry.register::<OtherPlayer>();
+    assert_eq!(registry.len(), 1);
+    assert!(registry
+        .get_by_type_id(TypeId::of::<RenamedPlayer>())
+        .is_none());
+    assert_eq!(
+        registry.get("Player").unwrap().type_id(),
+        TypeId::of::<OtherPlayer>()
+    );
+}
+
+#[test]
+fn global() {
+    TypeRegistry::global().write().unwrap().register::<Player>();
+    assert!(TypeRegistry::global()
+        .read()
+        .unwrap()
+        .contains(core::any::type_name::<Player>()));
+}
+
+#[test]
+fn debug_formatter() {
+    use alloc::collections::BTreeMap;
+    use core::fmt;
+
+    use crate::logging::LogValue;
+    use crate::reflect_debug;
+
+    #[derive(Reflect, Clone, Debug)]
+    #[reflect(crate_name(crate))]
+    struct Matrix([f32; 4]);
+
+    #[derive(Reflect, Clone, Debug)]
+    #[reflect(crate_name(crate), opt_out(Debug))]
+    struct Transform {
+        local: Matrix,
+        children: Vec<Matrix>,
+        named: BTreeMap<String, Matrix>,
+    }
+
+    fn debug_matrix(value: &dyn Reflect, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let matrix = value.downcast_ref::<Matrix>().unwrap();
+        write!(f, "Matrix(trace = {})", matrix.0[0] + matrix.0[3])
+    }
+
+    let registry = TypeRegistry::new().with_debug_formatter::<Matrix>(debug_matrix);
+    let descriptor = <Matrix as DescribeType>::type_descriptor();
+    assert!(registry.debug_formatter(&descriptor).is_some());
+    assert!(registry
+        .debug_formatter(&<Player as DescribeType>::type_descriptor())
+        .is_none());
+
+    let mut by_hash = TypeRegistry::new();
+    by_hash.register_debug_formatter_by_hash(descriptor.type_hash(), debug_matrix);
+    assert!(by_hash.debug_formatter(&descriptor).is_some());
+
+    let transform = Transform {
+        local: Matrix([1.0, 0.0, 0.0, 1.0]),
+        children: Vec::from([Matrix([2.0, 0.0, 0.0, 2.0])]),
+        named: BTreeMap::from([("root".to_owned(), Matrix([0.0; 4]))]),
+    };
+    assert_eq!(
+        format!("{:?}", transform.as_reflect()),
+        format!("{} {{", core::any::type_name::<Transform>()) + " local: Matrix([1.0, 0.0, 0.0, 1.0]), children: [Matrix([2.0, 0.0, 0.0, 2.0])], named: {\"root\": Matrix([0.0, 0.0, 0.0, 0.0])} }"
+    );
+
+    // only formatters in the global registry are used
+    TypeRegistry::global()
+        .write()
+        .unwrap()
+        .register_debug_formatter::<Matrix>(debug_matrix);
+
+    let expected = format!("{} {{", core::any::type_name::<Transform>()) + " local: Matrix(trace = 2), children: [Matrix(trace = 4)], named: {\"root\": Matrix(trace = 0)} }";
+    assert_eq!(format!("{:?}", transform.as_reflect()), expected);
+    assert_eq!(
+        format!(
+            "{:?}",
+            LogValue::new(transform.as_reflect(), &Default::default())
+        ),
+        expected
+    );
+
+    struct ReflectDebug<'a>(&'a dyn Reflect);
+    impl fmt::Debug for ReflectDebug<'_> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            reflect_debug(self.0, f)
+        }
+    }
+    assert_eq!(
+        format!("{:?}", ReflectDebug(&transform.local)),
+        "Matrix(trace = 2)"
+    );
+}
@@ -0,0 +1,201 @@
+use alloc::collections::BTreeMap;
+
+use serde_json::json;
+
+use crate::schema::to_json_schema;
+use crate::DescribeType;
+use crate::Reflect;
+
+#[derive(Reflect, Clone, Debug)]
+#[reflect(crate_name(crate))]
+struct Foo {
+    a: i32,
+    b: Vec<Bar>,
+    c: BTreeMap<u8, [f32; 2]>,
+    d: (bool, char),
+}
+
+/// A bar.
+#[derive(Reflect, Clone, Debug)]
+#[reflect(crate_name(crate))]
+enum Bar {
+    /// The first one.
+    A {
+        x: String,
+    },
+    #[reflect(meta(hidden = true))]
+    B(u16),
+    C,
+}
+
+#[test]
+fn struct_and_enum() {
+    let schema = to_json_schema(&<Foo as DescribeType>::type_descriptor());
+
+    let foo_name = <Foo as DescribeType>::type_descriptor()
+        .type_name()
+        .to_owned();
+    let bar_name = <Bar as DescribeType>::type_descriptor()
+        .type_name()
+        .to_owned();
+
+    assert_eq!(
+        schema,
+        json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "$ref": "#/$defs/mirror_mirror::tests::schema::Foo",
+            "$defs": {
+                foo_name.clone(): {
+                    "title": foo_name,
+                    "type": "object",
+                    "properties": {
+                        "a": {
+                            "type": "integer",
+                            "minimum": i32::MIN,
+                            "maximum": i32::MAX,
+                        },
+                        "b": {
+                            "type": "array",
+                            "items": { "$ref": "#/$defs/mirror_mirror::tests::schema::Bar" },
+                        },
+                        "c": {
+                            "type": "object",
+                            "additionalProperties": {
+                                "type": "array",
+                                "items": { "type": "number" },
+                                "minItems": 2,
+                                "maxItems": 2,
+                            },
+                            "propertyNames": { "pattern": "^[0-9]+$" },
+                        },
+                        "d": {
+                            "type": "array",
+                            "prefixItems": [
+                                { "type": "boolean" },
+                                { "type": "string", "minLength": 1, "maxLength": 1 },
+                            ],
+                            "minItems": 2,
+                            "maxItems": 2,
+                        },
+                    },
+                    "required": [],
+                },
+                bar_name.clone(): {
+                    "title": bar_name,
+                    "description": "A bar.",
+                    "oneOf": [
+                        {
+                            "type": "object",
+                            "properties": {
+                                "A": {
+                                    "type": "object",
+                                    "properties": { "x": { "type": "string" } },
+                                    "required": [],
+                                },
+                            },
+                            "required": ["A"],
+                            "additionalProperties": false,
+                            "description": "The first one.",
+                        },
+                        {
+                            "type": "object",
+                            "properties": {
+                                "B": { "type": "integer", "minimum": 0, "maximum": u16::MAX },
+                            },
+                            "required": ["B"],
+                            "additionalProperties": false,
+                            "x-meta": { "hidden": true },
+                        },
+                        { "const": "C" },
+                    ],
+                },
+            },
+        })
+    );
+}
+
+#[derive(Reflect, Clone, Debug)]
+#[reflect(crate_name(crate))]
+struct Tree<T>
+where
+    T: Reflect + crate::FromReflect + DescribeType + Clone + core::fmt::Debug,
+{
+    value: T,
+    children: Vec<Tree<T>>,
+}
+
+#[test]
+fn recursive_generic_type() {
+    let schema = to_json_schema(&<Tree<i8> as DescribeType>::type_descriptor());
+
+    let name = "mirror_mirror::tests::schema::Tree<i8>";
+    let reference = "#/$defs/mirror_mirror::tests::schema::Tree%3Ci8%3E";
+
+    assert_eq!(schema["$ref"], reference);
+    assert_eq!(schema["$defs"].as_object().unwrap().len(), 1);
+    assert_eq!(
+        schema["$defs"][name]["properties"]["children"]["items"]["$ref"],
+        reference
+    );
+}
+
+#[test]
+fn scalars() {
+    let schema = to_json_schema(&<u64 as DescribeType>::type_descriptor());
+    assert_eq!(
+        schema,
+        json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "integer",
+            "minimum": 0,
+            "maximum": u64::MAX,
+        })
+    );
+
+    let schema = to_json_schema(&<Option<String> as DescribeType>::type_descriptor());
+    assert_eq!(
+        schema,
+        json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "anyOf": [{ "type": "null" }, { "type": "string" }],
+        })
+    );
+
+    let schema = to_json_schema(&<() as DescribeType>::type_descriptor());
+    assert_eq!(
+        schema,
+        json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "null",
+        })
+    );
+}
+
+#[test]
+fn maps_with_non_scalar_keys() {
+    let schema = to_json_schema(&<BTreeMap<(u8, bool), ()> as DescribeType>::type_descriptor());
+    assert_eq!(
+        schema,
+        json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "array",
+            "items": {
+                "type": "array",
+                "prefixItems": [
+                    {
+                        "type": "array",
+                        "prefixItems": [
+                            { "type": "integer", "minimum": 0, "maximum": u8::MAX },
+                            { "type": "boolean" },
+                        ],
+                        "minItems": 2,
+                        "maxItems": 2,
+                    },
+                    { "type": "null" },
+                ],
+                "minItems": 2,
+                "maxItems": 2,
+            },
+        })
+    );
+}
@@ -0,0 +1,305 @@
+use alloc::collections::BTreeMap;
+use std::collections::HashSet;
+
+use serde::de::DeserializeSeed;
+
+use crate::key_path;
+use crate::serde::TypedDeserializer;
+use crate::serde::TypedSerializer;
+use crate::DescribeType;
+use crate::FromReflect;
+use crate::Reflect;
+
+#[derive(Reflect, Clone, Debug, PartialEq)]
+#[reflect(crate_name(crate))]
+struct Asset {
+    name: String,
+    id: Id,
+    size: (u32, u32),
+    tags: Vec<String>,
+    layers: BTreeMap<u8, Layer>,
+    parent: Option<Id>,
+}
+
+#[derive(Reflect, Clone, Debug, PartialEq, Eq, Hash)]
+#[reflect(crate_name(crate))]
+struct Id(u64);
+
+#[derive(Reflect, Clone, Debug, PartialEq)]
+#[reflect(crate_name(crate))]
+enum Layer {
+    Empty,
+    Solid(f32),
+    Gradient(f32, f32),
+    Image { path: String, opacity: f32 },
+}
+
+fn to_json<T>(value: &T) -> String
+where
+    T: Reflect + DescribeType,
+{
+    let descriptor = <T as DescribeType>::type_descriptor();
+    serde_json::to_string(&TypedSerializer::new(value, &descriptor)).unwrap()
+}
+
+fn from_json<T>(json: &str) -> Result<T, serde_json::Error>
+where
+    T: FromReflect + DescribeType,
+{
+    let descriptor = <T as DescribeType>::type_descriptor();
+    let value = TypedDeserializer::new(&descriptor)
+        .deserialize(&mut serde_json::Deserializer::from_str(json))?;
+    Ok(T::from_reflect(&value).unwrap())
+}
+
+#[test]
+fn round_trip() {
+    let asset = Asset {
+        name: "sky".to_owned(),
+        id: Id(1),
+        size: (1024, 512),
+        tags: Vec::from(["outdoor".to_owned()]),
+        layers: BTreeMap::from([
+            (0, Layer::Empty),
+            (1, Layer::Solid(0.5)),
+            (2, Layer::Gradient(0.0, 1.0)),
+            (
+                3,
+                Layer::Image {
+                    path: "clouds.png".to_owned(),
+                    opacity: 0.25,
+                },
+            ),
+        ]),
+        parent: Some(Id(0)),
+    };
+
+    let json = to_json(&asset);
+    assert_eq!(
+        json,
+        r#"{"name":"sky","id":1,"size":[1024,512],"tags":["outdoor"],"layers":{"0":"Empty","1":{"Solid":0.5},"2":{"Gradient":[0.0,1.0]},"3":{"Image":{"path":"clouds.png","opacity":0.25}}},"parent":0}"#
+    );
+    assert_eq!(from_json::<Asset>(&json).unwrap(), asset);
+}
+
+#[test]
+fn missing_fields_use_defaults() {
+    let asset = from_json::<Asset>(r#"{"name":"sky","id":1,"unknown":true}"#).unwrap();
+    assert_eq!(
+        asset,
+        Asset {
+            name: "sky".to_owned(),
+            id: Id(1),
+            size: (0, 0),
+            tags: Vec::new(),
+            layers: BTreeMap::new(),
+            parent: None,
+        }
+    );
+}
+
+#[test]
+fn sets() {
+    let set = HashSet::from([Id(1), Id(2)]);
+    let json = to_json(&set);
+    assert!(json == "[1,2]" || json == "[2,1]");
+    assert_eq!(from_json::<HashSet<Id>>(&json).unwrap(), set);
+}
+
+#[test]
+fn unit() {
+    assert_eq!(to_json(&()), "null");
+    from_json::<()>("null").unwrap();
+    assert!(from_json::<()>("[]").is_err());
+
+    let map = BTreeMap::from([("a".to_owned(), ())]);
+    assert_eq!(to_json(&map), r#"{"a":null}"#);
+    assert_eq!(
+        from_json::<BTreeMap<String, ()>>(r#"{"a":null}"#).unwrap(),
+        map
+    );
+
+    let result: Result<(), String> = Ok(());
+    assert_eq!(to_json(&result), r#"{"Ok":null}"#);
+    assert_eq!(
+        from_json::<Result<(), String>>(r#"{"Ok":null}"#).unwrap(),
+        result
+    );
+}
+
+#[test]
+fn errors() {
+    assert!(from_json::<Layer>(r#""Missing""#)
+        .unwrap_err()
+        .to_string()
+        .contains("unknown variant `Missing`"));
+
+    assert!(from_json::<Layer>(r#""Solid""#).is_err());
+    assert!(from_json::<[i32; 2]>("[1, 2, 3]").is_err());
+    assert!(from_json::<(i32, i32)>("[1]").is_err());
+    assert!(from_json::<u8>("256").is_err());
+}
+
+#[test]
+fn maps_as_entries() {
+    let map = BTreeMap::from([((1, 2), Layer::Empty), ((3, 4), Layer::Solid(1.0))]);
+    let descriptor = <BTreeMap<(i32, i32), Layer> as DescribeType>::type_descriptor();
+
+    let json =
+        serde_json::to_string(&TypedSerializer::new(&map, &descriptor).with_maps_as_entries())
+            .unwrap();
+    assert_eq!(json, r#"[[[1,2],"Empty"],[[3,4],{"Solid":1.0}]]"#);
+    assert_eq!(
+        from_json::<BTreeMap<(i32, i32), Layer>>(&json).unwrap(),
+        map
+    );
+
+    // maps with scalar keys are still maps
+    let map = BTreeMap::from([(1_u8, Layer::Empty)]);
+    let descriptor = <BTreeMap<u8, Layer> as DescribeType>::type_descriptor();
+    assert_eq!(
+        serde_json::to_string(&TypedSerializer::new(&map, &descriptor).with_maps_as_entries())
+            .unwrap(),
+        r#"{"1":"Empty"}"#
+    );
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn json_string_round_trip() {
+    use crate::value::from_json_str;
+    use crate::value::to_json_string;
+
+    #[derive(Reflect, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+    #[reflect(crate_name(crate))]
+    struct Tile {
+        x: i32,
+        y: i32,
+    }
+
+    let tiles = BTreeMap::from([
+        (Tile { x: 0, y: 1 }, Layer::Gradient(0.0, 1.0)),
+        (
+            Tile { x: -1, y: 0 },
+            Layer::Image {
+                path: "clouds.png".to_owned(),
+                opacity: 0.25,
+            },
+        ),
+    ]);
+    let descriptor = <BTreeMap<Tile, Layer> as DescribeType>::type_descriptor();
+
+    let json = to_json_string(&tiles, &descriptor).unwrap();
+    assert_eq!(
+        json,
+        r#"[[{"x":-1,"y":0},{"Image":{"path":"clouds.png","opacity":0.25}}],[{"x":0,"y":1},{"Gradient":[0.0,1.0]}]]"#
+    );
+    let value = from_json_str(&json, &descriptor).unwrap();
+    assert_eq!(
+        BTreeMap::<Tile, Layer>::from_reflect(&value).unwrap(),
+        tiles
+    );
+
+    assert!(from_json_str("[] trailing", &descriptor).is_err());
+}
+
+#[cfg(feature = "ron")]
+#[test]
+fn ron_string_round_trip() {
+    use crate::value::from_ron_str;
+    use crate::value::to_ron_string;
+
+    let asset = Asset {
+        name: "sky".to_owned(),
+        id: Id(1),
+        size: (1024, 512),
+        tags: Vec::new(),
+        layers: BTreeMap::from([
+            (0, Layer::Empty),
+            (1, Layer::Gradient(0.0, 1.0)),
+            (
+                2,
+                Layer::Image {
+                    path: "clouds.png".to_owned(),
+                    opacity: 0.25,
+                },
+            ),
+        ]),
+        parent: Some(Id(0)),
+    };
+    let descriptor = <Asset as DescribeType>::type_descriptor();
+
+    let ron = to_ron_string(&asset, &descriptor).unwrap();
+    assert_eq!(
+        ron,
+        r#"{"name":"sky","id":1,"size":[1024,512],"tags":[],"layers":{0:"Empty",1:{"Gradient":[0.0,1.0]},2:{"Image":{"path":"clouds.png","opacity":0.25}}},"parent":Some(0)}"#
+    );
+    let value = from_ron_str(&ron, &descriptor).unwrap();
+    assert_eq!(Asset::from_reflect(&value).unwrap(), asset);
+
+    assert!(from_ron_str(r#"{"name":"sky","id":1} trailing"#, &descriptor).is_err());
+}
+
+#[test]
+fn redact_sensitive() {
+    #[derive(Reflect, Clone, Debug)]
+    #[reflect(crate_name(crate))]
+    struct Login {
+        user: String,
+        #[reflect(sensitive)]
+        password: String,
+    }
+
+    let login = Login {
+        user: "alice".to_owned(),
+        password: "hunter2".to_owned(),
+    };
+    let descriptor = <Login as DescribeType>::type_descriptor();
+
+    assert_eq!(to_json(&login), r#"{"user":"alice","password":"hunter2"}"#);
+    assert_eq!(
+        serde_json::to_string(&TypedSerializer::new(&login, &descriptor).with_sensitive_redacted())
+            .unwrap(),
+        r#"{"user":"alice","password":"<redacted>"}"#
+    );
+}
+
+#[test]
+fn deserialize_at_key_path() {
+    let descriptor = <Asset as DescribeType>::type_descriptor();
+
+    let value = descriptor
+        .at(&key_path!(.layers))
+        .unwrap()
+        .deserialize(&mut serde_json::Deserializer::from_str(
+            r#"{"1":{"Solid":0.5},"2":"Empty"}"#,
+        ))
+        .unwrap();
+    assert_eq!(
+        BTreeMap::<u8, Layer>::from_reflect(&value).unwrap(),
+        BTreeMap::from([(1, Layer::Solid(0.5)), (2, Layer::Empty)])
+    );
+
+    let value = descriptor
+        .at(&key_path!(.size.1))
+        .unwrap()
+        .deserialize(&mut serde_json::Deserializer::from_str("512"))
+        .unwrap();
+    assert_eq!(u32::from_reflect(&value).unwrap(), 512);
+
+    let value = descriptor
+        .at(&key_path!(.parent))
+        .unwrap()
+        .deserialize(&mut serde_json::Deserializer::from_str("null"))
+        .unwrap();
+    assert_eq!(Option::<Id>::from_reflect(&value).unwrap(), None);
+
+    let err = descriptor
+        .at(&key_path!(.size.0))
+        .unwrap()
+        .deserialize(&mut serde_json::Deserializer::from_str(r#""wide""#));
+    assert!(err.is_err());
+
+    assert!(descriptor.at(&key_path!(.missing)).is_none());
+    assert!(descriptor.at(&key_path!(.layers[0]::Solid)).is_none());
+}
@@ -0,0 +1,112 @@
+use alloc::collections::BTreeSet;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
+use std::hash::BuildHasherDefault;
+
+use crate::DescribeType;
+use crate::FromReflect;
+use crate::Reflect;
+use crate::Set;
+use crate::Value;
+
+#[test]
+fn works() {
+    let mut set = BTreeSet::from([1, 2]);
+    let set: &mut dyn Set = set.as_set_mut().unwrap();
+
+    assert_eq!(set.len(), 2);
+    assert!(set.contains(&1));
+    assert!(!set.contains(&3));
+    assert!(!set.contains(&"foo".to_owned()));
+
+    assert!(set.insert(&3));
+    assert!(!set.insert(&3));
+    assert!(!set.insert(&"foo".to_owned()));
+    assert!(set.contains(&3));
+
+    assert!(set.remove(&1));
+    assert!(!set.remove(&1));
+
+    let elements = set
+        .iter()
+        .map(|value| *value.downcast_ref::<i32>().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(elements, [2, 3]);
+}
+
+#[test]
+fn hash_set() {
+    let mut set = HashSet::from(["a".to_owned()]);
+    let set: &mut dyn Set = set.as_set_mut().unwrap();
+
+    assert!(set.contains(&"a".to_owned()));
+    assert!(set.insert(&"b".to_owned()));
+    assert_eq!(set.len(), 2);
+
+    let value = set.to_value();
+    assert!(matches!(&value, Value::Set(set) if set.len() == 2));
+    assert!(value.as_set().unwrap().contains(&"b".to_owned()));
+}
+
+#[test]
+fn hash_set_with_custom_hasher() {
+    type FixedState = BuildHasherDefault<DefaultHasher>;
+
+    let mut set = HashSet::<i32, FixedState>::default();
+    set.extend([1, 2]);
+    assert!(set.as_set_mut().unwrap().insert(&3));
+
+    let value = set.to_value();
+    assert_eq!(value, HashSet::from([1, 2, 3]).to_value());
+    assert_eq!(
+        HashSet::<i32, FixedState>::from_reflect(&value).unwrap(),
+        set
+    );
+}
+
+#[test]
+fn from_reflect() {
+    let set = BTreeSet::from([1, 2, 3]);
+    let value = set.to_value();
+
+    assert_eq!(BTreeSet::<i32>::from_reflect(&value).unwrap(), set);
+    assert_eq!(
+        HashSet::<i32>::from_reflect(&value).unwrap(),
+        HashSet::from([1, 2, 3])
+    );
+    assert!(BTreeSet::<String>::from_reflect(&value).is_none());
+    assert!(BTreeSet::<i32>::from_reflect(&Vec::from([1, 2])).is_none());
+}
+
+#[test]
+fn patch() {
+    let mut set = BTreeSet::from([1, 2]);
+    set.patch(&BTreeSet::from([3]));
+    assert_eq!(set, BTreeSet::from([3]));
+}
+
+#[test]
+fn type_info() {
+    let descriptor = <HashSet<String> as DescribeType>::type_descriptor();
+    let set_type = descriptor.get_type().as_set().unwrap();
+    assert_eq!(
+        set_type.element_type().type_name(),
+        <String as DescribeType>::type_descriptor()
+            .get_type()
+            .type_name()
+    );
+    assert_eq!(
+        descriptor.default_value().unwrap(),
+        BTreeSet::<String>::new().to_value()
+    );
+}
+
+#[test]
+fn debug() {
+    let set = BTreeSet::from([1, 2]);
+    assert_eq!(format!("{:?}", set.as_reflect()), format!("{set:?}"));
+    assert_eq!(
+        format!("{:?}", set.to_value().as_reflect()),
+        format!("{set:?}")
+    );
+}
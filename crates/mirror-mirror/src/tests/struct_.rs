@@ -11,6 +11,7 @@ use crate::type_info::GetMeta;
 use crate::DescribeType;
 use crate::FromReflect;
 use crate::GetField;
+use crate::GetFieldMut;
 use crate::Reflect;
 use crate::Struct;
 use crate::Value;
@@ -328,3 +329,594 @@ fn consistent_iteration_order_of_struct_variant_fields() {
 
     assert_eq!(by_value, by_type);
 }
+
+#[test]
+fn assert_type_shape() {
+    #[derive(Reflect, Debug, Clone)]
+    #[reflect(crate_name(crate))]
+    struct Named {
+        a: i32,
+        b: Vec<String>,
+    }
+
+    #[derive(Reflect, Debug, Clone)]
+    #[reflect(crate_name(crate))]
+    struct Unnamed(i32, Vec<String>);
+
+    #[derive(Reflect, Debug, Clone)]
+    #[reflect(crate_name(crate), opt_out(Debug, Clone))]
+    struct Generic<T>
+    where
+        T: Reflect + FromReflect + DescribeType,
+    {
+        inner: T,
+    }
+
+    #[derive(Reflect, Debug, Clone, Default)]
+    #[reflect(crate_name(crate))]
+    struct Inner {
+        c: u8,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Reflect, Debug, Clone)]
+    #[reflect(crate_name(crate), rename_all = "kebab-case")]
+    struct Attributes {
+        first_field: i32,
+        #[reflect(rename = "second")]
+        second_field: String,
+        #[reflect(skip)]
+        skipped: bool,
+        #[reflect(flatten)]
+        inner: Inner,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Reflect, Debug, Clone)]
+    #[reflect(crate_name(crate))]
+    struct UnnamedSkip(i32, #[reflect(skip)] bool, String);
+
+    crate::assert_type_shape!(Named, { b: Vec<String>, a: i32 });
+    crate::assert_type_shape!(Unnamed, (i32, Vec<String>));
+    crate::assert_type_shape!(Generic<bool>, { inner: bool });
+    crate::assert_type_shape!(Attributes, { "first-field": i32, second: String, c: u8 });
+    crate::assert_type_shape!(UnnamedSkip, (i32, String));
+}
+
+#[test]
+#[should_panic = "field `a` has type `i32`, expected `u32`"]
+fn assert_type_shape_wrong_type() {
+    #[derive(Reflect, Debug, Clone)]
+    #[reflect(crate_name(crate))]
+    struct Named {
+        a: i32,
+    }
+
+    crate::assert_type_shape!(Named, { a: u32 });
+}
+
+#[test]
+#[should_panic = "field `skipped` is missing"]
+fn assert_type_shape_skipped_field() {
+    #[allow(dead_code)]
+    #[derive(Reflect, Debug, Clone)]
+    #[reflect(crate_name(crate))]
+    struct Named {
+        a: i32,
+        #[reflect(skip)]
+        skipped: bool,
+    }
+
+    crate::assert_type_shape!(Named, { a: i32, skipped: bool });
+}
+
+mod remote {
+    #[derive(Debug, Clone, PartialEq)]
+    pub(super) struct Point {
+        pub(super) x: f32,
+        pub(super) y: f32,
+    }
+}
+
+#[derive(Reflect, Debug, Clone, PartialEq)]
+#[reflect(crate_name(crate), remote = remote::Point)]
+struct PointDef {
+    x: f32,
+    y: f32,
+}
+
+#[test]
+fn remote() {
+    let point = remote::Point { x: 1.0, y: 2.0 };
+
+    let mut def = PointDef::from(point);
+    assert_eq!(def.get_field::<f32>("x").unwrap(), &1.0);
+    *def.get_field_mut::<f32>("y").unwrap() = 3.0;
+
+    let value = def.to_value();
+    let point: remote::Point = PointDef::from_reflect(&value).unwrap().into();
+    assert_eq!(point, remote::Point { x: 1.0, y: 3.0 });
+
+    let type_info = <PointDef as DescribeType>::type_descriptor();
+    assert!(type_info.type_name().ends_with("PointDef"));
+}
+
+#[test]
+fn function_pointer_fields() {
+    #[derive(Reflect, Debug, Clone)]
+    #[reflect(crate_name(crate))]
+    struct Callbacks {
+        on_click: fn(i32) -> bool,
+        on_hover: Option<fn()>,
+    }
+
+    fn on_click(n: i32) -> bool {
+        n > 0
+    }
+
+    let callbacks = Callbacks {
+        on_click,
+        on_hover: None,
+    };
+
+    let on_click = callbacks.get_field::<fn(i32) -> bool>("on_click").unwrap();
+    assert!(on_click(1));
+
+    // function pointers cannot be serialized, so they are replaced by placeholders that panic
+    let value = callbacks.to_value();
+    let from_value = Callbacks::from_reflect(&value).unwrap();
+    assert!(from_value.on_hover.is_none());
+    assert!(std::panic::catch_unwind(|| (from_value.on_click)(1)).is_err());
+    assert!(<fn(i32) -> bool>::from_reflect(&1_i32).is_none());
+    assert!(<fn(i32) -> bool>::from_reflect(&(1_i32,)).is_none());
+
+    let clone = Callbacks::from_reflect(&callbacks).unwrap();
+    assert!((clone.on_click)(1));
+
+    let type_info = <Callbacks as DescribeType>::type_descriptor();
+    let field = type_info
+        .as_struct()
+        .unwrap()
+        .field_type("on_click")
+        .unwrap()
+        .get_type();
+    assert!(field.as_opaque().is_some());
+    assert_eq!(
+        field.get_meta::<String>("signature").unwrap(),
+        "fn(i32) -> bool"
+    );
+}
+
+#[test]
+fn static_reference_fields() {
+    #[derive(Reflect, Debug, Clone, PartialEq)]
+    #[reflect(crate_name(crate))]
+    struct Material {
+        roughness: f32,
+    }
+
+    #[derive(Reflect, Debug, Clone)]
+    #[reflect(crate_name(crate))]
+    struct Entry {
+        id: u32,
+        material: &'static Material,
+    }
+
+    static METAL: Material = Material { roughness: 0.2 };
+    static WOOD: Material = Material { roughness: 0.8 };
+
+    let mut entry = Entry {
+        id: 1,
+        material: &METAL,
+    };
+
+    let material = entry.get_field::<&'static Material>("material").unwrap();
+    assert_eq!(material.roughness, 0.2);
+
+    // the referenced data is included in the value, but a reference cannot be created from it
+    let value = entry.to_value();
+    let material = value.as_struct().unwrap().field("material").unwrap();
+    assert_eq!(Material::from_reflect(material).unwrap(), METAL);
+    assert!(Entry::from_reflect(&value).is_none());
+
+    let clone = Entry::from_reflect(&entry).unwrap();
+    assert!(core::ptr::eq(clone.material, &METAL));
+
+    // references are read-only
+    entry.patch(&Entry {
+        id: 2,
+        material: &WOOD,
+    });
+    assert_eq!(entry.id, 2);
+    assert!(core::ptr::eq(entry.material, &METAL));
+
+    let type_info = <Entry as DescribeType>::type_descriptor();
+    let field = type_info
+        .as_struct()
+        .unwrap()
+        .field_type("material")
+        .unwrap()
+        .get_type();
+    assert!(field.as_opaque().is_some());
+    assert!(field.get_meta::<bool>("read_only").unwrap());
+    assert!(field
+        .get_meta::<String>("referent")
+        .unwrap()
+        .ends_with("Material"));
+}
+
+#[test]
+fn table_driven() {
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    #[reflect(crate_name(crate), table_driven, no_from_reflect_fast_path)]
+    struct Foo {
+        a: i32,
+        #[reflect(skip)]
+        skipped: bool,
+        b: String,
+    }
+
+    let mut foo = Foo {
+        a: 1,
+        skipped: true,
+        b: "b".to_owned(),
+    };
+
+    assert_eq!(foo.fields_len(), 2);
+    assert_eq!(foo.get_field::<i32>("a").unwrap(), &1);
+    assert!(foo.field("skipped").is_none());
+    assert_eq!(foo.name_at(1), Some("b"));
+    assert!(foo.name_at(2).is_none());
+    assert_eq!(
+        foo.field_at(1).unwrap().downcast_ref::<String>().unwrap(),
+        "b"
+    );
+    assert!(foo.field_at(2).is_none());
+
+    *foo.get_field_mut::<i32>("a").unwrap() = 2;
+    assert_eq!(foo.a, 2);
+    foo.field_at_mut(1).unwrap().patch(&"c".to_owned());
+    assert_eq!(foo.b, "c");
+    assert!(foo.field_at_mut(2).is_none());
+
+    let names = foo.fields_mut().map(|(name, _)| name).collect::<Vec<_>>();
+    assert_eq!(names, ["a", "b"]);
+
+    let value = foo.to_value();
+    assert_eq!(value.get_field::<i32>("a").unwrap(), &2);
+    assert!(value.get_field::<bool>("skipped").is_none());
+
+    let mut new_foo = Foo::from_reflect(&value).unwrap();
+    assert!(!new_foo.skipped);
+
+    new_foo.patch(&StructValue::new().with_field("b", "patched"));
+    assert_eq!(new_foo.b, "patched");
+    assert_eq!(new_foo.a, 2);
+}
+
+#[test]
+fn range_inclusive() {
+    use core::ops::RangeInclusive;
+
+    let mut range = 1..=5;
+
+    assert_eq!(range.get_field::<i32>("start").unwrap(), &1);
+    assert_eq!(range.get_field::<i32>("end").unwrap(), &5);
+    assert!(range.as_struct_mut().unwrap().field_mut("start").is_none());
+
+    let value = range.to_value();
+    assert_eq!(RangeInclusive::<i32>::from_reflect(&value).unwrap(), 1..=5);
+
+    let mut patch = StructValue::new();
+    patch.set_field("end", 10);
+    range.patch(&patch);
+    assert_eq!(range, 1..=10);
+
+    let type_info = <RangeInclusive<i32> as DescribeType>::type_descriptor();
+    let struct_type = type_info.as_struct().unwrap();
+    assert_eq!(
+        struct_type
+            .field_types()
+            .map(|field| field.name())
+            .collect::<Vec<_>>(),
+        ["start", "end"]
+    );
+
+    assert_eq!(
+        format!("{:?}", range.as_reflect()),
+        "core::ops::range::RangeInclusive<i32> { start: 1, end: 10 }"
+    );
+}
+
+#[test]
+fn rename() {
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    #[reflect(crate_name(crate), rename = "Player", rename_all = "camelCase")]
+    struct PlayerData {
+        display_name: String,
+        #[reflect(rename = "hp")]
+        health: u32,
+    }
+
+    let player = PlayerData {
+        display_name: "Alice".to_owned(),
+        health: 100,
+    };
+
+    assert_eq!(player.type_name(), "Player");
+    assert_eq!(player.get_field::<String>("displayName").unwrap(), "Alice");
+    assert_eq!(player.get_field::<u32>("hp").unwrap(), &100);
+    assert!(player.get_field::<String>("display_name").is_none());
+    assert_eq!(
+        player.fields().map(|(name, _)| name).collect::<Vec<_>>(),
+        ["displayName", "hp"]
+    );
+
+    let value = player.to_value();
+    assert_eq!(value.type_name(), "Player");
+    assert_eq!(value.get_field::<String>("displayName").unwrap(), "Alice");
+    assert_eq!(PlayerData::from_reflect(&value).unwrap(), player);
+
+    let type_info = <PlayerData as DescribeType>::type_descriptor();
+    let struct_type = type_info.as_struct().unwrap();
+    assert_eq!(struct_type.type_name(), "Player");
+    assert!(struct_type.field_type("displayName").is_some());
+    assert!(struct_type.field_type("hp").is_some());
+    assert!(struct_type.field_type("health").is_none());
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    #[reflect(crate_name(crate), table_driven, rename_all = "SCREAMING_SNAKE_CASE")]
+    struct TableDriven {
+        max_speed: f32,
+    }
+
+    let value = TableDriven { max_speed: 1.0 };
+    assert_eq!(value.get_field::<f32>("MAX_SPEED").unwrap(), &1.0);
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    #[reflect(crate_name(crate), rename = "Meters")]
+    struct Length(f32);
+
+    assert_eq!(Length(1.0).type_name(), "Meters");
+    assert_eq!(
+        <Length as DescribeType>::type_descriptor()
+            .get_type()
+            .type_name(),
+        "Meters"
+    );
+}
+
+#[test]
+fn const_generics() {
+    use crate::key_path::GetPath;
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    #[reflect(crate_name(crate))]
+    struct Grid<const W: usize> {
+        cells: [u8; W],
+    }
+
+    let grid = Grid { cells: [1, 2, 3] };
+    assert_eq!(grid.get_at::<u8>(&key_path!(.cells[2])), Some(&3));
+    assert_eq!(Grid::<3>::from_reflect(&grid.to_value()).unwrap(), grid);
+    assert_ne!(
+        <Grid<3> as DescribeType>::type_descriptor().type_name(),
+        <Grid<4> as DescribeType>::type_descriptor().type_name(),
+    );
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    #[reflect(crate_name(crate), rename = "Matrix")]
+    struct RenamedGrid<const W: usize, const H: usize> {
+        rows: [[f32; W]; H],
+    }
+
+    let matrix = RenamedGrid {
+        rows: [[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]],
+    };
+    assert_eq!(matrix.type_name(), "Matrix<2, 3>");
+    assert_eq!(matrix.to_value().type_name(), "Matrix<2, 3>");
+    assert_eq!(
+        <RenamedGrid<2, 3> as DescribeType>::type_descriptor().type_name(),
+        "Matrix<2, 3>"
+    );
+    assert_eq!(
+        RenamedGrid::<2, 3>::from_reflect(&matrix.to_value()).unwrap(),
+        matrix
+    );
+}
+
+#[test]
+fn flatten() {
+    use crate::key_path::GetPath;
+
+    #[derive(Reflect, Clone, Debug, PartialEq, Default)]
+    #[reflect(crate_name(crate))]
+    struct Graphics {
+        vsync: bool,
+        fov: f32,
+    }
+
+    #[derive(Reflect, Clone, Debug, PartialEq, Default)]
+    #[reflect(crate_name(crate))]
+    struct Audio {
+        volume: f32,
+    }
+
+    #[derive(Reflect, Clone, Debug, PartialEq, Default)]
+    #[reflect(crate_name(crate))]
+    struct Settings {
+        name: String,
+        #[reflect(flatten)]
+        graphics: Graphics,
+        #[reflect(flatten)]
+        audio: Audio,
+        #[reflect(skip)]
+        dirty: bool,
+        version: u32,
+    }
+
+    let mut settings = Settings {
+        name: "default".to_owned(),
+        graphics: Graphics {
+            vsync: true,
+            fov: 90.0,
+        },
+        audio: Audio { volume: 0.5 },
+        dirty: false,
+        version: 1,
+    };
+
+    assert_eq!(settings.fields_len(), 5);
+    assert_eq!(
+        settings.fields().map(|(name, _)| name).collect::<Vec<_>>(),
+        ["name", "vsync", "fov", "volume", "version"]
+    );
+    assert_eq!(settings.get_field::<bool>("vsync"), Some(&true));
+    assert!(settings.field("graphics").is_none());
+    assert_eq!(settings.name_at(3), Some("volume"));
+    assert_eq!(
+        settings.field_at(4).unwrap().downcast_ref::<u32>(),
+        Some(&1)
+    );
+    assert!(settings.field_at(5).is_none());
+
+    *settings.get_field_mut::<f32>("fov").unwrap() = 100.0;
+    *settings
+        .field_at_mut(3)
+        .unwrap()
+        .downcast_mut::<f32>()
+        .unwrap() = 1.0;
+    *settings.get_at_mut::<u32>(&key_path!(.version)).unwrap() = 2;
+    assert_eq!(settings.graphics.fov, 100.0);
+    assert_eq!(settings.audio.volume, 1.0);
+    assert_eq!(settings.version, 2);
+
+    for (name, field) in settings.fields_mut() {
+        if name == "vsync" {
+            field.patch(&false);
+        }
+    }
+    assert!(!settings.graphics.vsync);
+
+    let value = settings.to_value();
+    assert_eq!(value.get_field::<f32>("fov"), Some(&100.0));
+    assert!(value.get_field::<Value>("graphics").is_none());
+    assert_eq!(Settings::from_reflect(&value).unwrap(), settings);
+
+    let mut patched = Settings::default();
+    patched.patch(&settings);
+    assert_eq!(patched, settings);
+
+    let type_info = <Settings as DescribeType>::type_descriptor();
+    let struct_type = type_info.as_struct().unwrap();
+    assert_eq!(
+        struct_type
+            .field_types()
+            .map(|field| field.name())
+            .collect::<Vec<_>>(),
+        ["name", "vsync", "fov", "volume", "version"]
+    );
+    assert!(struct_type.field_type("graphics").is_none());
+    assert_eq!(
+        type_info
+            .default_value()
+            .and_then(|value| Settings::from_reflect(&value)),
+        Some(Settings::default())
+    );
+}
+
+#[test]
+fn arc() {
+    use alloc::sync::Arc;
+
+    use crate::key_path::GetPath;
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    #[reflect(crate_name(crate))]
+    struct Scene {
+        settings: Arc<Foo>,
+    }
+
+    let shared = Arc::new(Foo { field: 1 });
+    let mut scene = Scene {
+        settings: Arc::clone(&shared),
+    };
+
+    assert_eq!(
+        scene.settings.type_descriptor().type_name(),
+        <Foo as DescribeType>::type_descriptor().type_name()
+    );
+    assert_eq!(scene.get_at::<i32>(&key_path!(.settings.field)), Some(&1));
+
+    // mutating clones the shared value
+    *scene
+        .get_at_mut::<i32>(&key_path!(.settings.field))
+        .unwrap() = 2;
+    assert_eq!(scene.settings.field, 2);
+    assert_eq!(shared.field, 1);
+
+    let value = scene.to_value();
+    assert_eq!(
+        Scene::from_reflect(&value).unwrap(),
+        Scene {
+            settings: Arc::new(Foo { field: 2 })
+        }
+    );
+}
+
+#[test]
+fn owned_fields() {
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    #[reflect(crate_name(crate))]
+    struct Foo {
+        a: i32,
+        b: Vec<String>,
+    }
+
+    let foo = Foo {
+        a: 1,
+        b: Vec::from(["b".to_owned()]),
+    };
+
+    let fields = Box::new(foo.to_value())
+        .into_struct()
+        .unwrap()
+        .into_fields()
+        .map(|(name, value)| (name, value.to_value()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        fields,
+        [
+            ("a".to_owned(), 1_i32.to_value()),
+            ("b".to_owned(), foo.b.to_value()),
+        ]
+    );
+
+    let fields = Box::new(foo.clone())
+        .into_fields()
+        .map(|(name, _)| name)
+        .collect::<Vec<_>>();
+    assert_eq!(fields, ["a", "b"]);
+}
+
+#[test]
+fn range_inclusive_try_patch() {
+    let mut range = 1..=5;
+    let report = range
+        .try_patch(&StructValue::new().with_field("end", 10))
+        .unwrap();
+    assert_eq!(range, 1..=10);
+    assert_eq!(report.applied(), [key_path!()]);
+
+    // the range is rebuilt as a whole, so nothing is applied if some field has the wrong type
+    let error = range
+        .try_patch(
+            &StructValue::new()
+                .with_field("start", 2)
+                .with_field("end", "foo"),
+        )
+        .unwrap_err();
+    assert_eq!(range, 1..=10);
+    assert!(error.report().applied().is_empty());
+    assert_eq!(error.report().skipped()[0].path(), &key_path!(.end));
+}
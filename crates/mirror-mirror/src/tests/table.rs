@@ -0,0 +1,169 @@
+use alloc::collections::BTreeMap;
+use alloc::collections::BTreeSet;
+
+use crate::key_path;
+use crate::table::read_delimited;
+use crate::table::rows_to_values;
+use crate::table::RowError;
+use crate::table::Table;
+use crate::DescribeType;
+use crate::FromReflect;
+use crate::Reflect;
+
+#[derive(Reflect, Clone, Debug)]
+#[reflect(crate_name(crate))]
+struct Unit {
+    name: String,
+    cost: Option<u32>,
+    kind: Kind,
+    tags: BTreeSet<String>,
+}
+
+#[derive(Reflect, Clone, Debug, PartialEq)]
+#[reflect(crate_name(crate))]
+enum Kind {
+    Melee,
+    Ranged { range: f32 },
+}
+
+#[test]
+fn enums_and_options() {
+    let units = [
+        Unit {
+            name: "Knight".to_owned(),
+            cost: Some(10),
+            kind: Kind::Melee,
+            tags: BTreeSet::from(["armored".to_owned()]),
+        },
+        Unit {
+            name: "Archer".to_owned(),
+            cost: None,
+            kind: Kind::Ranged { range: 1.5 },
+            tags: BTreeSet::new(),
+        },
+    ];
+
+    let table = Table::new(units.iter().map(|unit| unit.as_reflect()));
+
+    assert_eq!(
+        table.columns(),
+        [
+            key_path!(.name),
+            key_path!(.cost),
+            key_path!(.cost::Some.0),
+            key_path!(.kind),
+            key_path!(.kind::Ranged.range),
+        ]
+    );
+    assert_eq!(table.len(), 2);
+
+    let mut tsv = String::new();
+    table.write_tsv(&mut tsv).unwrap();
+    assert_eq!(
+        tsv,
+        ".name\t.cost\t.cost::Some.0\t.kind\t.kind::Ranged.range\n\
+         Knight\tSome\t10\tMelee\t\n\
+         Archer\tNone\t\tRanged\t1.5\n"
+    );
+}
+
+#[test]
+fn empty() {
+    let table = Table::new([]);
+    assert!(table.is_empty());
+
+    let mut csv = String::new();
+    table.write_csv(&mut csv).unwrap();
+    assert_eq!(csv, "\n");
+}
+
+#[test]
+fn round_trip() {
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    #[reflect(crate_name(crate))]
+    struct Spawn {
+        position: [f32; 2],
+        wave: (u8, char),
+        weights: BTreeMap<u32, f64>,
+        kind: Kind,
+        cost: Option<u32>,
+        tags: Vec<String>,
+    }
+
+    let spawns = [
+        Spawn {
+            position: [1.5, -2.0],
+            wave: (1, 'a'),
+            weights: BTreeMap::from([(1, 0.5), (10, 2.0)]),
+            kind: Kind::Ranged { range: 3.0 },
+            cost: Some(5),
+            tags: Vec::from(["a,b".to_owned(), "\"quoted\"\nline".to_owned()]),
+        },
+        Spawn {
+            position: [0.0, 0.0],
+            wave: (2, 'b'),
+            weights: BTreeMap::new(),
+            kind: Kind::Melee,
+            cost: None,
+            tags: Vec::new(),
+        },
+    ];
+
+    let table = Table::new(spawns.iter().map(|spawn| spawn.as_reflect()));
+    let mut csv = String::new();
+    table.write_csv(&mut csv).unwrap();
+
+    let rows = read_delimited(&csv, ',');
+    let values = rows_to_values(
+        &rows[0],
+        &rows[1..],
+        &<Spawn as DescribeType>::type_descriptor(),
+    )
+    .unwrap();
+    let read = values
+        .iter()
+        .map(|value| Spawn::from_reflect(value).unwrap())
+        .collect::<Vec<_>>();
+
+    assert_eq!(read, spawns);
+}
+
+#[test]
+fn import_errors() {
+    let descriptor = <Unit as DescribeType>::type_descriptor();
+
+    assert!(matches!(
+        rows_to_values(&[".name", ".missing"], [[""; 2]], &descriptor),
+        Err(RowError::UnknownColumn { column: 1, .. })
+    ));
+    assert!(matches!(
+        rows_to_values(&[".name["], [[""]], &descriptor),
+        Err(RowError::InvalidHeader { column: 0, .. })
+    ));
+
+    let error = rows_to_values(&[".cost::Some.0"], [["ten"]], &descriptor).unwrap_err();
+    assert_eq!(
+        error,
+        RowError::InvalidCell {
+            row: 0,
+            column: 0,
+            expected: "u32".to_owned(),
+            cell: "ten".to_owned(),
+        }
+    );
+
+    let error = rows_to_values(&[".kind"], [["Flying"]], &descriptor).unwrap_err();
+    assert!(matches!(error, RowError::InvalidCell { ref cell, .. } if cell == "Flying"));
+
+    // missing cells get default values
+    let values = rows_to_values(
+        &[".name", ".kind::Ranged.range"],
+        [["Archer", "2"]],
+        &descriptor,
+    )
+    .unwrap();
+    let unit = Unit::from_reflect(&values[0]).unwrap();
+    assert_eq!(unit.name, "Archer");
+    assert_eq!(unit.cost, None);
+    assert_eq!(unit.kind, Kind::Ranged { range: 2.0 });
+}
@@ -0,0 +1,180 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::enum_::EnumValue;
+use crate::key_path;
+use crate::key_path::KeyPath;
+use crate::struct_::StructValue;
+use crate::tracked::Tracked;
+use crate::DescribeType;
+use crate::FromReflect;
+use crate::Reflect;
+
+#[derive(Reflect, Clone, Debug, PartialEq)]
+#[reflect(crate_name(crate))]
+struct Player {
+    name: String,
+    position: [f32; 2],
+    inventory: Vec<u32>,
+    state: State,
+}
+
+#[derive(Reflect, Clone, Debug, PartialEq)]
+#[reflect(crate_name(crate))]
+enum State {
+    Idle,
+    Moving { speed: f32 },
+}
+
+fn player() -> Player {
+    Player {
+        name: "alice".into(),
+        position: [0.0, 0.0],
+        inventory: Vec::from([1, 2]),
+        state: State::Moving { speed: 1.0 },
+    }
+}
+
+#[test]
+fn patch() {
+    let mut tracked = Tracked::new(player());
+    assert!(!tracked.is_dirty());
+
+    tracked.patch(
+        &StructValue::new()
+            .with_field("inventory", Vec::from([3_u32, 4, 5]))
+            .with_field(
+                "state",
+                EnumValue::new_struct_variant("Moving")
+                    .with_struct_field("speed", 2.0_f32)
+                    .finish(),
+            ),
+    );
+
+    assert_eq!(tracked.get().inventory, [3, 4]);
+    assert_eq!(tracked.get().state, State::Moving { speed: 2.0 });
+    assert_eq!(
+        tracked.take_dirty(),
+        [
+            key_path!(.inventory[0_usize]),
+            key_path!(.inventory[1_usize]),
+            key_path!(.state::Moving.speed),
+        ]
+    );
+    assert!(!tracked.is_dirty());
+
+    // switching variant replaces the whole enum
+    tracked.patch(&StructValue::new().with_field("state", EnumValue::new_unit_variant("Idle")));
+    assert_eq!(tracked.take_dirty(), [key_path!(.state)]);
+}
+
+#[test]
+fn patch_through_dyn_reflect() {
+    let mut tracked = Tracked::new(player());
+
+    let value: &mut dyn Reflect = tracked.as_reflect_mut();
+    value.patch(&StructValue::new().with_field("name", "bob"));
+
+    assert_eq!(tracked.get().name, "bob");
+    assert_eq!(tracked.take_dirty(), [key_path!(.name)]);
+}
+
+#[test]
+fn try_patch() {
+    let mut tracked = Tracked::new(player());
+
+    let error = tracked
+        .try_patch(
+            &StructValue::new()
+                .with_field("name", "bob")
+                .with_field("position", 1337_i32),
+        )
+        .unwrap_err();
+
+    assert_eq!(error.report().applied(), [key_path!(.name)]);
+    assert_eq!(tracked.take_dirty(), [key_path!(.name)]);
+}
+
+#[test]
+fn field_mut() {
+    let mut tracked = Tracked::new(player());
+
+    *tracked
+        .get_field_mut::<u32>(&key_path!(.inventory[1_usize]))
+        .unwrap() = 3;
+    tracked
+        .field_mut(&key_path!(.name))
+        .unwrap()
+        .patch(&String::from("bob"));
+
+    // missing paths and wrong types aren't marked
+    assert!(tracked.field_mut(&key_path!(.missing)).is_none());
+    assert!(tracked
+        .get_field_mut::<String>(&key_path!(.inventory[0_usize]))
+        .is_none());
+
+    assert_eq!(tracked.get().inventory, [1, 3]);
+    assert_eq!(tracked.get().name, "bob");
+    assert_eq!(
+        tracked.take_dirty(),
+        [key_path!(.inventory[1_usize]), key_path!(.name)]
+    );
+}
+
+#[test]
+fn paths_are_only_recorded_once() {
+    let mut tracked = Tracked::new(player());
+
+    tracked.mark_dirty(key_path!(.position[0_usize]));
+    tracked.mark_dirty(key_path!(.position[1_usize]));
+    tracked.mark_dirty(key_path!(.position[1_usize]));
+    assert_eq!(
+        tracked.take_dirty(),
+        [key_path!(.position[0_usize]), key_path!(.position[1_usize])]
+    );
+
+    // containing values replace the values inside them
+    tracked.mark_dirty(key_path!(.position[0_usize]));
+    tracked.mark_dirty(key_path!(.name));
+    tracked.mark_dirty(key_path!(.position));
+    tracked.mark_dirty(key_path!(.position[1_usize]));
+    assert_eq!(
+        tracked.take_dirty(),
+        [key_path!(.name), key_path!(.position)]
+    );
+}
+
+#[test]
+fn untracked_changes_mark_everything() {
+    let mut tracked = Tracked::new(player());
+    tracked.mark_dirty(key_path!(.name));
+    tracked.get_mut().position = [1.0, 1.0];
+    assert_eq!(tracked.take_dirty(), [KeyPath::default()]);
+
+    tracked.reflect_mut().as_struct_mut().unwrap();
+    assert_eq!(tracked.take_dirty(), [KeyPath::default()]);
+
+    // reading doesn't
+    tracked.reflect_ref().as_struct().unwrap();
+    assert_eq!(tracked.to_value(), tracked.get().to_value());
+    assert!(!tracked.is_dirty());
+}
+
+#[test]
+fn reflected_as_inner_type() {
+    let tracked = Tracked::new(player());
+
+    assert_eq!(
+        tracked.as_reflect().downcast_ref::<Player>(),
+        Some(&player())
+    );
+    assert_eq!(Reflect::type_name(&tracked), Reflect::type_name(&player()));
+    assert_eq!(
+        <Tracked<Player> as DescribeType>::type_descriptor().get_type(),
+        <Player as DescribeType>::type_descriptor().get_type(),
+    );
+
+    let from_value = Tracked::<Player>::from_reflect(&player().to_value()).unwrap();
+    assert_eq!(from_value.get(), &player());
+    assert!(!from_value.is_dirty());
+}
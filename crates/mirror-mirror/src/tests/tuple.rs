@@ -3,6 +3,7 @@ use crate::DescribeType;
 use crate::FromReflect;
 use crate::GetField;
 use crate::Reflect;
+use crate::Value;
 
 #[test]
 fn tuple_value() {
@@ -40,3 +41,24 @@ fn from_default() {
 
     assert_eq!(foo, (0, false));
 }
+
+#[test]
+fn unit() {
+    assert_eq!(().to_value(), Value::unit());
+    assert!(Value::from(()).is_unit());
+    assert!(!TupleValue::new().with_field(1).to_value().is_unit());
+    assert_eq!(<()>::from_reflect(&Value::unit()), Some(()));
+
+    let descriptor = <() as DescribeType>::type_descriptor();
+    assert!(descriptor.get_type().is_unit());
+    assert!(descriptor.default_value().unwrap().is_unit());
+    assert!(!<(i32,) as DescribeType>::type_descriptor()
+        .get_type()
+        .is_unit());
+
+    let result: Result<(), String> = Ok(());
+    assert_eq!(
+        Result::<(), String>::from_reflect(&result.to_value()),
+        Some(result)
+    );
+}
@@ -64,3 +64,20 @@ fn from_reflect_with_value() {
 
     assert!(Foo::from_reflect(&value).is_some());
 }
+
+#[test]
+fn wrapping() {
+    use core::num::Wrapping;
+
+    let mut wrapping = Wrapping(u8::MAX);
+    assert_eq!(wrapping.get_field::<u8>(0).unwrap(), &u8::MAX);
+
+    let value = wrapping.to_value();
+    assert_eq!(
+        Wrapping::<u8>::from_reflect(&value),
+        Some(Wrapping(u8::MAX))
+    );
+
+    wrapping.patch(&TupleStructValue::new().with_field(1_u8));
+    assert_eq!(wrapping + Wrapping(u8::MAX), Wrapping(0));
+}
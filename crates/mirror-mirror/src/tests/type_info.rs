@@ -5,6 +5,7 @@ use alloc::collections::BTreeMap;
 
 use crate::key_path;
 use crate::key_path::GetPath;
+use crate::key_path::GetTypePath;
 use crate::tuple_struct::TupleStructValue;
 use crate::type_info::graph::OpaqueNode;
 use crate::type_info::*;
@@ -301,3 +302,326 @@ fn has_default_value() {
     assert!(!<[Value; 3] as DescribeType>::type_descriptor().has_default_value());
     assert!(!<Value as DescribeType>::type_descriptor().has_default_value());
 }
+
+#[test]
+fn reuses_descriptors_of_nested_types() {
+    #[derive(Reflect, Clone, Debug)]
+    #[reflect(crate_name(crate))]
+    struct Outer {
+        inner: Inner,
+        inners: Vec<Inner>,
+    }
+
+    #[derive(Reflect, Clone, Debug)]
+    #[reflect(crate_name(crate))]
+    struct Inner {
+        outer: Option<Box<Outer>>,
+        n: i32,
+    }
+
+    // build `Inner` first so its graph is reused when building `Outer`
+    let inner = <Inner as DescribeType>::type_descriptor();
+    let outer = <Outer as DescribeType>::type_descriptor();
+
+    assert_eq!(
+        outer.type_at(&key_path!(.inner.n)).unwrap().type_name(),
+        "i32"
+    );
+    assert_eq!(
+        outer
+            .type_at(&key_path!(.inners[0].outer::Some.0))
+            .unwrap()
+            .type_name(),
+        inner
+            .type_at(&key_path!(.outer::Some.0))
+            .unwrap()
+            .type_name(),
+    );
+    assert_eq!(
+        outer.type_at(&key_path!(.inner)).unwrap().type_name(),
+        inner.type_name(),
+    );
+}
+
+#[test]
+fn type_at_path_into_type_descriptor() {
+    #[derive(Reflect, Clone, Debug)]
+    #[reflect(crate_name(crate))]
+    struct Foo {
+        bar: Bar,
+        n: i32,
+        list: Vec<String>,
+    }
+
+    #[derive(Reflect, Clone, Debug)]
+    #[reflect(crate_name(crate))]
+    enum Bar {
+        A { x: f32 },
+        B(u8),
+    }
+
+    let descriptor = <Foo as DescribeType>::type_descriptor();
+
+    let bar = descriptor
+        .type_at(&key_path!(.bar))
+        .unwrap()
+        .into_type_descriptor();
+    assert_eq!(bar.type_name(), type_name::<Bar>());
+    assert!(bar.as_enum().is_some());
+
+    // variants become the descriptor of their enum
+    let variant = descriptor.type_at(&key_path!(.bar::A)).unwrap();
+    assert!(variant.as_variant().is_some());
+    assert_eq!(
+        variant.into_type_descriptor().type_name(),
+        type_name::<Bar>()
+    );
+
+    let scalar = descriptor
+        .type_at(&key_path!(.bar::A.x))
+        .unwrap()
+        .into_type_descriptor();
+    assert_eq!(scalar.type_name(), "f32");
+
+    let list = descriptor
+        .type_at(&key_path!(.list))
+        .unwrap()
+        .into_type_descriptor();
+    assert_eq!(list.type_name(), type_name::<Vec<String>>());
+    assert!(list.as_list().is_some());
+
+    // fields
+    let foo = descriptor.as_struct().unwrap();
+    assert_eq!(
+        foo.field_type("n")
+            .unwrap()
+            .into_type_descriptor()
+            .type_name(),
+        "i32"
+    );
+    let b = bar.as_enum().unwrap().variant("B").unwrap();
+    assert_eq!(
+        b.field_type_at(0)
+            .unwrap()
+            .into_type_descriptor()
+            .type_name(),
+        "u8"
+    );
+    assert_eq!(b.into_type_descriptor().type_name(), type_name::<Bar>());
+}
+
+#[test]
+fn stats() {
+    /// Docs
+    #[derive(Reflect, Clone, Debug)]
+    #[reflect(crate_name(crate), meta(key = 1_u8))]
+    struct Foo {
+        /// More docs
+        a: i32,
+        b: String,
+    }
+
+    let stats = <Foo as DescribeType>::type_descriptor().stats();
+
+    // `Foo`, `i32`, and `String`
+    assert_eq!(stats.nodes, 3);
+    assert_eq!(stats.docs_bytes, " Docs".len() + " More docs".len());
+    assert_eq!(stats.meta_bytes, "key".len() + 1);
+    assert!(stats.strings_bytes >= type_name::<Foo>().len() + "ab".len());
+
+    let stats = <i32 as DescribeType>::type_descriptor().stats();
+    assert_eq!(stats.nodes, 1);
+    assert_eq!(stats.docs_bytes, 0);
+    assert_eq!(stats.meta_bytes, 0);
+}
+
+#[test]
+fn field_indices() {
+    #[derive(Reflect, Clone, Debug)]
+    #[reflect(crate_name(crate))]
+    #[allow(dead_code)]
+    struct Foo {
+        a: i32,
+        #[reflect(skip)]
+        skipped: i32,
+        b: String,
+        c: bool,
+    }
+
+    #[derive(Reflect, Clone, Debug)]
+    #[reflect(crate_name(crate))]
+    enum Bar {
+        A { x: f32, y: f32 },
+    }
+
+    let descriptor = <Foo as DescribeType>::type_descriptor();
+    let struct_type = descriptor.as_struct().unwrap();
+
+    assert_eq!(struct_type.field_index("a"), Some(0));
+    assert_eq!(struct_type.field_index("b"), Some(1));
+    assert_eq!(struct_type.field_index("c"), Some(2));
+    assert_eq!(struct_type.field_index("skipped"), None);
+
+    assert_eq!(struct_type.field_type("c").unwrap().index(), 2);
+    assert_eq!(
+        struct_type
+            .field_types()
+            .map(NamedField::index)
+            .collect::<Vec<_>>(),
+        [0, 1, 2]
+    );
+
+    let descriptor = <Bar as DescribeType>::type_descriptor();
+    let Variant::Struct(variant) = descriptor.as_enum().unwrap().variant("A").unwrap() else {
+        panic!()
+    };
+    assert_eq!(variant.field_index("y"), Some(1));
+    assert_eq!(variant.field_type("y").unwrap().index(), 1);
+}
+
+#[cfg(feature = "speedy")]
+#[test]
+fn speedy_descriptors_are_versioned_and_use_stable_ids() {
+    use speedy::Readable;
+    use speedy::Writable;
+
+    #[derive(Reflect, Clone, Debug)]
+    #[reflect(crate_name(crate))]
+    struct Foo {
+        children: Vec<Foo>,
+        n: i32,
+    }
+
+    let descriptor = <Foo as DescribeType>::type_descriptor();
+    let mut bytes = descriptor.write_to_vec().unwrap();
+
+    // the format version followed by the id of the root, which is the stable hash of the type
+    assert_eq!(bytes[..4], TypeDescriptor::FORMAT_VERSION.to_le_bytes());
+    assert_eq!(
+        bytes[4..12],
+        crate::versioned::stable_type_hash(&descriptor).to_le_bytes(),
+    );
+
+    let read = TypeDescriptor::read_from_buffer(&bytes).unwrap();
+    assert_eq!(read, *descriptor);
+    assert_eq!(
+        read.type_at(&key_path!(.children[0_usize].n))
+            .unwrap()
+            .type_name(),
+        "i32"
+    );
+
+    bytes[..4].copy_from_slice(&(TypeDescriptor::FORMAT_VERSION + 1).to_le_bytes());
+    assert!(TypeDescriptor::read_from_buffer(&bytes).is_err());
+}
+
+#[test]
+fn default_attributes() {
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    #[reflect(crate_name(crate))]
+    struct Settings {
+        #[reflect(default = 1.5)]
+        scale: f32,
+        #[reflect(default = Quality::High)]
+        quality: Quality,
+        name: String,
+        #[reflect(skip, default = NotReflect(7))]
+        skipped: NotReflect,
+    }
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    #[reflect(crate_name(crate))]
+    enum Quality {
+        Low,
+        High,
+        Custom(
+            #[reflect(default = 4)] u8,
+            #[reflect(skip, default = NotReflect(1))] NotReflect,
+        ),
+    }
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    #[reflect(crate_name(crate), default_with(Resolution::full_hd))]
+    struct Resolution(u32, u32);
+
+    impl Resolution {
+        fn full_hd() -> Self {
+            Self(1920, 1080)
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct NotReflect(u8);
+
+    let settings = <Settings as DescribeType>::type_descriptor();
+    assert_eq!(
+        Settings::from_reflect(&settings.default_value().unwrap()).unwrap(),
+        Settings {
+            scale: 1.5,
+            quality: Quality::High,
+            name: String::new(),
+            skipped: NotReflect(7),
+        }
+    );
+    assert_eq!(
+        settings
+            .as_struct()
+            .unwrap()
+            .field_type("scale")
+            .unwrap()
+            .default_value(),
+        Some(1.5_f32.to_value())
+    );
+
+    let quality = <Quality as DescribeType>::type_descriptor();
+    let custom = quality.as_enum().unwrap().variant("Custom").unwrap();
+    assert_eq!(
+        Quality::from_reflect(&custom.default_value().unwrap()).unwrap(),
+        Quality::Custom(4, NotReflect(1))
+    );
+
+    let resolution = <Resolution as DescribeType>::type_descriptor();
+    assert_eq!(
+        Resolution::from_reflect(&resolution.default_value().unwrap()).unwrap(),
+        Resolution(1920, 1080)
+    );
+}
+
+#[test]
+fn nodes_with_the_same_hash_are_only_merged_if_equal() {
+    mod a {
+        use crate::Reflect;
+
+        /// A
+        #[derive(Reflect, Clone, Debug)]
+        #[reflect(crate_name(crate), rename = "Same")]
+        pub(super) struct Same {
+            n: i32,
+        }
+    }
+
+    mod b {
+        use crate::Reflect;
+
+        /// B
+        #[derive(Reflect, Clone, Debug)]
+        #[reflect(crate_name(crate), rename = "Same")]
+        pub(super) struct Same {
+            n: i32,
+        }
+    }
+
+    #[derive(Reflect, Clone, Debug)]
+    #[reflect(crate_name(crate))]
+    #[allow(dead_code)]
+    struct Both {
+        a: a::Same,
+        b: b::Same,
+    }
+
+    let descriptor = <Both as DescribeType>::type_descriptor();
+    let struct_ = descriptor.as_struct().unwrap();
+    let docs = |name| struct_.field_type(name).unwrap().get_type().docs();
+    assert_eq!(docs("a"), [" A"]);
+    assert_eq!(docs("b"), [" B"]);
+}
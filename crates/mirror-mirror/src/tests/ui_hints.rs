@@ -0,0 +1,69 @@
+use crate::type_info::GetMeta;
+use crate::ui_hints::UiHints;
+use crate::ui_hints::Widget;
+use crate::DescribeType;
+use crate::Reflect;
+
+#[derive(Reflect, Clone, Debug)]
+#[reflect(crate_name(crate))]
+struct Settings {
+    #[reflect(meta(tooltip = "Overrides the docs", order = 2_u8))]
+    /// Not shown.
+    name: String,
+    #[reflect(meta(widget = "slider", min = 0.5_f32, max = 2_u64, step = 0.25))]
+    scale: f32,
+    #[reflect(meta(widget = "my_curve_editor", order = -1))]
+    curve: Vec<f32>,
+    plain: bool,
+}
+
+#[test]
+fn for_field() {
+    let descriptor = <Settings as DescribeType>::type_descriptor();
+    let struct_type = descriptor.as_struct().unwrap();
+    let hints = |name| UiHints::for_field(struct_type.field_type(name).unwrap());
+
+    let name = hints("name");
+    assert_eq!(name.tooltip.as_deref(), Some("Overrides the docs"));
+    assert_eq!(name.order, Some(2));
+    assert_eq!(name.widget, None);
+
+    assert_eq!(
+        hints("scale").widget,
+        Some(Widget::Slider {
+            min: Some(0.5),
+            max: Some(2.0),
+            step: Some(0.25),
+        })
+    );
+
+    let curve = hints("curve");
+    assert_eq!(curve.order, Some(-1));
+    assert_eq!(
+        curve.widget,
+        Some(Widget::Custom("my_curve_editor".to_owned()))
+    );
+
+    assert_eq!(hints("plain"), UiHints::default());
+}
+
+#[test]
+fn from_meta() {
+    #[derive(Reflect, Clone, Debug)]
+    #[reflect(crate_name(crate), meta(group = "Advanced"))]
+    /// A tuple struct.
+    struct Wrapper(#[reflect(meta(widget = "hidden"))] i32);
+
+    let descriptor = <Wrapper as DescribeType>::type_descriptor();
+    let hints = UiHints::from_meta(descriptor.get_type());
+    assert_eq!(hints.group.as_deref(), Some("Advanced"));
+    assert_eq!(hints.tooltip.as_deref(), Some("A tuple struct."));
+
+    let field = descriptor
+        .as_tuple_struct()
+        .unwrap()
+        .field_type_at(0)
+        .unwrap();
+    assert!(field.meta("widget").is_some());
+    assert_eq!(UiHints::from_meta(field).widget, Some(Widget::Hidden));
+}
@@ -0,0 +1,259 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::enum_::EnumValue;
+use crate::key_path;
+use crate::struct_::StructValue;
+use crate::tuple_struct::TupleStructValue;
+use crate::validate::validate;
+use crate::FromReflect;
+use crate::Reflect;
+
+#[derive(Reflect, Clone, Debug, PartialEq)]
+#[reflect(crate_name(crate), validate_with(Damage::check))]
+struct Damage {
+    #[reflect(validate_with(non_negative))]
+    min: f32,
+    max: f32,
+}
+
+impl Damage {
+    fn check(&self) -> Result<(), &'static str> {
+        if self.min <= self.max {
+            Ok(())
+        } else {
+            Err("min is larger than max")
+        }
+    }
+}
+
+fn non_negative(n: &f32) -> Result<(), String> {
+    if *n >= 0.0 {
+        Ok(())
+    } else {
+        Err(alloc::format!("{n} is negative"))
+    }
+}
+
+#[test]
+fn validate_self() {
+    assert!(Damage { min: 1.0, max: 2.0 }.validate_self().is_ok());
+
+    let error = Damage {
+        min: -1.0,
+        max: 2.0,
+    }
+    .validate_self()
+    .unwrap_err();
+    assert_eq!(error.path(), &key_path!(.min));
+    assert_eq!(error.reason(), Some("-1 is negative"));
+    assert_eq!(error.expected(), "f32");
+    assert_eq!(error.to_string(), "invalid `f32`: -1 is negative at `.min`");
+
+    let error = Damage { min: 3.0, max: 2.0 }.validate_self().unwrap_err();
+    assert_eq!(error.path(), &key_path!());
+    assert_eq!(error.reason(), Some("min is larger than max"));
+
+    // types without validators accept everything
+    assert!(1_i32.validate_self().is_ok());
+}
+
+#[test]
+fn from_reflect() {
+    let value = StructValue::new()
+        .with_field("min", 1.0_f32)
+        .with_field("max", 2.0_f32);
+    assert_eq!(
+        Damage::from_reflect(&value),
+        Some(Damage { min: 1.0, max: 2.0 })
+    );
+
+    let value = StructValue::new()
+        .with_field("min", -1.0_f32)
+        .with_field("max", 2.0_f32);
+    assert_eq!(Damage::from_reflect(&value), None);
+    let error = Damage::try_from_reflect(&value).unwrap_err();
+    assert_eq!(error.path(), &key_path!(.min));
+    assert_eq!(error.reason(), Some("-1 is negative"));
+
+    // type mismatches are still reported as such
+    let value = StructValue::new()
+        .with_field("min", 1_i32)
+        .with_field("max", 2.0_f32);
+    let error = Damage::try_from_reflect(&value).unwrap_err();
+    assert_eq!(error.path(), &key_path!(.min));
+    assert_eq!(error.found(), Some("i32"));
+    assert_eq!(error.reason(), None);
+}
+
+#[test]
+fn patch() {
+    let mut damage = Damage { min: 1.0, max: 2.0 };
+
+    damage.patch(&StructValue::new().with_field("min", 3.0_f32));
+    assert_eq!(damage, Damage { min: 1.0, max: 2.0 });
+
+    damage.patch(&StructValue::new().with_field("max", 4.0_f32));
+    assert_eq!(damage, Damage { min: 1.0, max: 4.0 });
+
+    damage.patch(
+        &StructValue::new()
+            .with_field("min", 3.0_f32)
+            .with_field("max", 5.0_f32),
+    );
+    assert_eq!(damage, Damage { min: 3.0, max: 5.0 });
+}
+
+#[test]
+fn try_patch() {
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    #[reflect(crate_name(crate))]
+    struct Weapon {
+        name: String,
+        damage: Damage,
+    }
+
+    let mut weapon = Weapon {
+        name: "sword".into(),
+        damage: Damage { min: 1.0, max: 2.0 },
+    };
+
+    let error = weapon
+        .try_patch(
+            &StructValue::new()
+                .with_field("name", String::from("axe"))
+                .with_field("damage", StructValue::new().with_field("min", 3.0_f32)),
+        )
+        .unwrap_err();
+    // the whole patch is undone
+    assert_eq!(weapon.name, "sword");
+    assert_eq!(weapon.damage, Damage { min: 1.0, max: 2.0 });
+    assert!(error.report().applied().is_empty());
+    let skipped = error.report().skipped();
+    assert_eq!(skipped.len(), 1);
+    assert_eq!(skipped[0].path(), &key_path!(.damage));
+    assert_eq!(skipped[0].reason(), Some("min is larger than max"));
+
+    let error = weapon
+        .try_patch(
+            &StructValue::new()
+                .with_field("damage", StructValue::new().with_field("min", -1.0_f32)),
+        )
+        .unwrap_err();
+    assert_eq!(error.report().skipped()[0].path(), &key_path!(.damage.min));
+
+    let report = weapon
+        .try_patch(
+            &StructValue::new().with_field("damage", StructValue::new().with_field("max", 3.0_f32)),
+        )
+        .unwrap();
+    assert_eq!(report.applied(), [key_path!(.damage.max)]);
+    assert_eq!(weapon.damage, Damage { min: 1.0, max: 3.0 });
+}
+
+#[test]
+fn undone_patches_restore_opaque_values() {
+    #[derive(Reflect, Clone, Debug)]
+    #[reflect(crate_name(crate))]
+    struct Button {
+        #[reflect(validate_with(not_empty))]
+        label: String,
+        on_click: fn() -> u32,
+    }
+
+    fn not_empty(label: &str) -> Result<(), &'static str> {
+        if label.is_empty() {
+            Err("empty")
+        } else {
+            Ok(())
+        }
+    }
+
+    let mut button = Button {
+        label: "ok".into(),
+        on_click: || 1,
+    };
+    let invalid = Button {
+        label: String::new(),
+        on_click: || 2,
+    };
+
+    // function pointers can't be restored from a `Value` so a copy of the original is put back
+    assert!(button.try_patch(&invalid).is_err());
+    assert_eq!(button.label, "ok");
+    assert_eq!((button.on_click)(), 1);
+
+    button.patch(&invalid);
+    assert_eq!(button.label, "ok");
+    assert_eq!((button.on_click)(), 1);
+}
+
+#[test]
+fn tuple_struct_and_enum() {
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    #[reflect(crate_name(crate))]
+    struct Percentage(#[reflect(validate_with(percentage))] f32);
+
+    fn percentage(n: &f32) -> Result<(), &'static str> {
+        if (0.0..=100.0).contains(n) {
+            Ok(())
+        } else {
+            Err("not a percentage")
+        }
+    }
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    #[reflect(crate_name(crate), validate_with(Shape::check))]
+    enum Shape {
+        Circle { radius: f32 },
+        Point,
+    }
+
+    impl Shape {
+        fn check(&self) -> Result<(), &'static str> {
+            match self {
+                Shape::Circle { radius } if *radius <= 0.0 => Err("radius must be positive"),
+                _ => Ok(()),
+            }
+        }
+    }
+
+    let error = Percentage(101.0).validate_self().unwrap_err();
+    assert_eq!(error.path(), &key_path!(.0));
+    assert_eq!(
+        Percentage::from_reflect(&TupleStructValue::new().with_field(101.0_f32)),
+        None
+    );
+
+    let mut shape = Shape::Point;
+    shape.patch(
+        &EnumValue::new_struct_variant("Circle")
+            .with_struct_field("radius", 0.0_f32)
+            .finish(),
+    );
+    assert_eq!(shape, Shape::Point);
+
+    shape.patch(&Shape::Circle { radius: 1.0 });
+    shape.patch(
+        &EnumValue::new_struct_variant("Circle")
+            .with_struct_field("radius", -1.0_f32)
+            .finish(),
+    );
+    assert_eq!(shape, Shape::Circle { radius: 1.0 });
+}
+
+#[test]
+fn validate_nested() {
+    let damages = Vec::from([Damage { min: 1.0, max: 2.0 }, Damage { min: 1.0, max: 0.0 }]);
+    let error = validate(&damages).unwrap_err();
+    assert_eq!(error.path(), &key_path!([1_usize]));
+
+    let boxed = alloc::boxed::Box::new(Damage {
+        min: -1.0,
+        max: 0.0,
+    });
+    let error = validate(&boxed).unwrap_err();
+    assert_eq!(error.path(), &key_path!(.min));
+
+    assert!(validate(&Damage { min: 1.0, max: 2.0 }).is_ok());
+}
@@ -1,6 +1,11 @@
+use alloc::collections::BTreeMap;
+use alloc::collections::BTreeSet;
 use std::collections::HashMap;
 
-use crate::{DescribeType, FromReflect, Reflect};
+use crate::enum_::EnumValue;
+use crate::struct_::StructValue;
+use crate::type_info::stable_hash::stable_hash;
+use crate::{key_path, DescribeType, FromReflect, Reflect, Value};
 
 #[test]
 fn option_uses_none_as_default() {
@@ -29,3 +34,184 @@ fn hash() {
     assert_eq!(map.get(&"foo".to_owned().to_value()).unwrap(), &"two");
     assert!(map.get(&true.to_value()).is_none());
 }
+
+#[test]
+fn preserves_type_name() {
+    #[derive(Reflect, Clone, Debug)]
+    #[reflect(crate_name(crate))]
+    struct Foo {
+        bar: Bar,
+        baz: Baz,
+    }
+
+    #[derive(Reflect, Clone, Debug)]
+    #[reflect(crate_name(crate))]
+    struct Bar(i32);
+
+    #[derive(Reflect, Clone, Debug)]
+    #[reflect(crate_name(crate))]
+    enum Baz {
+        A,
+    }
+
+    let value = Foo {
+        bar: Bar(1),
+        baz: Baz::A,
+    }
+    .to_value();
+
+    assert_eq!(value.type_name(), core::any::type_name::<Foo>());
+    let struct_ = value.as_struct().unwrap();
+    assert_eq!(
+        struct_.field("bar").unwrap().type_name(),
+        core::any::type_name::<Bar>()
+    );
+    assert_eq!(
+        struct_.field("baz").unwrap().type_name(),
+        core::any::type_name::<Baz>()
+    );
+
+    // the type name is ignored by comparisons
+    let by_hand: Value = StructValue::new()
+        .with_field("bar", Bar(1))
+        .with_field("baz", Baz::A)
+        .into();
+    assert_eq!(by_hand.type_name(), core::any::type_name::<StructValue>());
+    assert_eq!(value, by_hand);
+
+    // and survives conversions between value types
+    let struct_value = StructValue::from_reflect(&value).unwrap();
+    assert_eq!(struct_value.type_name(), core::any::type_name::<Foo>());
+
+    // along with the type's stable hash
+    assert_eq!(
+        struct_value.type_hash(),
+        Some(stable_hash(
+            <Foo as DescribeType>::type_descriptor().get_type()
+        ))
+    );
+    assert_eq!(StructValue::new().type_hash(), None);
+
+    // converting from the type itself keeps them as well
+    let foo = Foo {
+        bar: Bar(1),
+        baz: Baz::A,
+    };
+    let struct_value = StructValue::from_reflect(&foo).unwrap();
+    assert_eq!(struct_value.type_name(), core::any::type_name::<Foo>());
+    assert_eq!(
+        struct_value.type_hash(),
+        Some(<Foo as DescribeType>::type_descriptor().type_hash())
+    );
+    let enum_value = EnumValue::from_reflect(&Baz::A).unwrap();
+    assert_eq!(
+        enum_value.type_hash(),
+        Some(<Baz as DescribeType>::type_descriptor().type_hash())
+    );
+
+    // but neither is serialized
+    #[cfg(feature = "speedy")]
+    {
+        use speedy::Readable;
+        use speedy::Writable;
+
+        let bytes = value.write_to_vec().unwrap();
+        assert_eq!(bytes, by_hand.write_to_vec().unwrap());
+        let read = Value::read_from_buffer(&bytes).unwrap();
+        assert_eq!(read.type_name(), core::any::type_name::<StructValue>());
+    }
+    #[cfg(feature = "serde")]
+    assert_eq!(
+        serde_json::to_string(&value).unwrap(),
+        serde_json::to_string(&by_hand).unwrap()
+    );
+}
+
+#[test]
+fn compact() {
+    let mut list = Vec::with_capacity(100);
+    let mut name = String::with_capacity(100);
+    name.push_str("foo");
+    list.push(Value::String(name));
+
+    let mut value: Value = StructValue::new()
+        .with_field("list", Value::List(list))
+        .with_field(
+            "map",
+            std::collections::BTreeMap::from([(1_i32, vec![1_i32, 2, 3])]).to_value(),
+        )
+        .into();
+    let before = value.clone();
+
+    value.compact();
+    assert_eq!(value, before);
+
+    let list = value
+        .as_struct()
+        .unwrap()
+        .field("list")
+        .unwrap()
+        .downcast_ref::<Vec<Value>>()
+        .unwrap();
+    assert_eq!(list.capacity(), 1);
+    let Value::String(name) = &list[0] else {
+        panic!("expected a string")
+    };
+    assert_eq!(name.capacity(), 3);
+}
+
+#[test]
+fn try_from_value() {
+    let list = Value::from(Vec::from([1_i32, 2, 3]));
+    assert_eq!(Vec::<i32>::try_from(list).unwrap(), [1, 2, 3]);
+
+    let map = Value::from(BTreeMap::from([(1_i32, "one".to_owned())]));
+    assert_eq!(
+        BTreeMap::<i32, String>::try_from(map).unwrap(),
+        BTreeMap::from([(1, "one".to_owned())]),
+    );
+
+    let set = Value::from(BTreeSet::from([true]));
+    assert_eq!(
+        BTreeSet::<bool>::try_from(set).unwrap(),
+        BTreeSet::from([true])
+    );
+
+    let error = Vec::<i32>::try_from(1_i32.to_value()).unwrap_err();
+    assert!(error.expected().starts_with("alloc::vec::Vec"));
+    assert_eq!(error.found(), Some("i32"));
+
+    let list = Value::List(Vec::from([1_i32.to_value(), true.to_value()]));
+    let error = Vec::<i32>::try_from(list).unwrap_err();
+    assert_eq!(error.expected(), "i32");
+    assert_eq!(error.found(), Some("bool"));
+    assert_eq!(error.path(), &key_path!([1_usize]));
+
+    let map = Value::from(BTreeMap::from([(1_i32, 1_i32), (2, 2)]));
+    let error = BTreeMap::<i32, String>::try_from(map).unwrap_err();
+    assert_eq!(error.found(), Some("i32"));
+    assert_eq!(error.path(), &key_path!([1_i32]));
+}
+
+#[test]
+fn lists_are_preallocated() {
+    let list = Vec::from_iter(0..100_i32);
+
+    let mut iter = list.as_reflect().as_list().unwrap().iter();
+    assert_eq!(iter.size_hint(), (100, Some(100)));
+    iter.next();
+    assert_eq!(iter.len(), 99);
+
+    let Value::List(elements) = list.to_value() else {
+        panic!("not a list")
+    };
+    assert_eq!(elements.capacity(), 100);
+
+    let value = elements.into_iter().collect::<Value>();
+    assert_eq!(value, list.to_value());
+
+    let map = [(1_i32.to_value(), 2_i32.to_value())]
+        .into_iter()
+        .collect::<Value>();
+    assert_eq!(map, BTreeMap::from([(1_i32, 2_i32)]).to_value());
+}
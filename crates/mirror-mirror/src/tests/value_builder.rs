@@ -0,0 +1,346 @@
+use alloc::borrow::ToOwned;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::any::type_name;
+
+use crate::enum_::EnumValue;
+use crate::key_path;
+use crate::key_path::KeyPath;
+use crate::struct_::StructValue;
+use crate::type_info::ShapeError;
+use crate::DescribeType;
+use crate::FromReflect;
+use crate::Reflect;
+use crate::Value;
+
+#[derive(Reflect, Clone, Debug, PartialEq)]
+#[reflect(crate_name(crate))]
+struct Player {
+    name: String,
+    health: u32,
+    position: Position,
+    items: Vec<Item>,
+    stats: BTreeMap<String, f32>,
+}
+
+#[derive(Reflect, Clone, Debug, PartialEq)]
+#[reflect(crate_name(crate))]
+struct Position(f32, f32);
+
+#[derive(Reflect, Clone, Debug, PartialEq)]
+#[reflect(crate_name(crate))]
+enum Item {
+    Sword { damage: u32 },
+    Potion(u8, #[reflect(default = true)] bool),
+    Key,
+}
+
+#[test]
+fn build_struct() {
+    let descriptor = <Player as DescribeType>::type_descriptor();
+
+    let value = descriptor
+        .value_builder()
+        .unwrap()
+        .with_field("name", "Bob")
+        .unwrap()
+        .with_field("items", Vec::from([Item::Key]))
+        .unwrap()
+        .with_field_at(1, 10_u32)
+        .unwrap()
+        .finish()
+        .unwrap();
+
+    assert_eq!(value.type_name(), descriptor.type_name());
+    assert_eq!(
+        Player::from_reflect(&value).unwrap(),
+        Player {
+            name: "Bob".to_owned(),
+            health: 10,
+            position: Position(0.0, 0.0),
+            items: Vec::from([Item::Key]),
+            stats: BTreeMap::new(),
+        }
+    );
+
+    let value = <Position as DescribeType>::type_descriptor()
+        .value_builder()
+        .unwrap()
+        .with_field_at(1, 2.0_f32)
+        .unwrap()
+        .finish()
+        .unwrap();
+    assert_eq!(Position::from_reflect(&value).unwrap(), Position(0.0, 2.0));
+
+    let value = <(i32, String) as DescribeType>::type_descriptor()
+        .value_builder()
+        .unwrap()
+        .with_field_at(0, 1_i32)
+        .unwrap()
+        .finish()
+        .unwrap();
+    assert_eq!(
+        <(i32, String)>::from_reflect(&value).unwrap(),
+        (1, String::new())
+    );
+
+    assert!(<Vec<i32> as DescribeType>::type_descriptor()
+        .value_builder()
+        .is_none());
+}
+
+#[test]
+fn build_enum() {
+    let descriptor = <Item as DescribeType>::type_descriptor();
+    let builder = descriptor.value_builder().unwrap();
+
+    assert_eq!(
+        builder.clone().finish().unwrap_err(),
+        ShapeError::MissingVariant {
+            path: KeyPath::default()
+        }
+    );
+    assert_eq!(
+        builder.clone().with_field("damage", 1_u32).unwrap_err(),
+        ShapeError::MissingVariant {
+            path: KeyPath::default()
+        }
+    );
+    assert_eq!(
+        builder.clone().with_variant("Shield").unwrap_err(),
+        ShapeError::UnknownVariant {
+            path: KeyPath::default(),
+            name: "Shield".to_owned(),
+        }
+    );
+
+    let value = builder
+        .clone()
+        .with_variant("Sword")
+        .unwrap()
+        .with_field("damage", 5_u32)
+        .unwrap()
+        .finish()
+        .unwrap();
+    assert_eq!(
+        Item::from_reflect(&value).unwrap(),
+        Item::Sword { damage: 5 }
+    );
+
+    let value = builder
+        .clone()
+        .with_variant("Potion")
+        .unwrap()
+        .with_field_at(0, 3_u8)
+        .unwrap()
+        .finish()
+        .unwrap();
+    assert_eq!(Item::from_reflect(&value).unwrap(), Item::Potion(3, true));
+
+    // choosing another variant resets the fields
+    let value = builder
+        .clone()
+        .with_variant("Potion")
+        .unwrap()
+        .with_field_at(0, 3_u8)
+        .unwrap()
+        .with_variant("Potion")
+        .unwrap()
+        .finish()
+        .unwrap();
+    assert_eq!(Item::from_reflect(&value).unwrap(), Item::Potion(0, true));
+
+    let value = builder.with_variant("Key").unwrap().finish().unwrap();
+    assert_eq!(Item::from_reflect(&value).unwrap(), Item::Key);
+}
+
+#[test]
+fn builder_errors() {
+    let descriptor = <Player as DescribeType>::type_descriptor();
+    let mut builder = descriptor.value_builder().unwrap();
+
+    assert_eq!(
+        builder.set_field("mana", 1_u32).unwrap_err(),
+        ShapeError::UnknownField {
+            path: key_path!(.mana)
+        }
+    );
+    assert_eq!(
+        builder.set_field_at(5, 1_u32).unwrap_err(),
+        ShapeError::UnknownField {
+            path: key_path!(.5)
+        }
+    );
+    assert_eq!(
+        builder.set_field("health", "full").unwrap_err(),
+        ShapeError::TypeMismatch {
+            path: key_path!(.health),
+            expected: "u32".to_owned(),
+            found: type_name::<String>().to_owned(),
+        }
+    );
+    assert_eq!(
+        builder.set_variant("Sword").unwrap_err(),
+        ShapeError::UnknownVariant {
+            path: KeyPath::default(),
+            name: "Sword".to_owned(),
+        }
+    );
+
+    // nested values are checked too
+    let error = builder
+        .set_field(
+            "items",
+            Vec::from([
+                Item::Key.to_value(),
+                EnumValue::new_struct_variant("Sword")
+                    .with_struct_field("damage", -1_i32)
+                    .finish()
+                    .into(),
+            ]),
+        )
+        .unwrap_err();
+    assert_eq!(
+        error,
+        ShapeError::TypeMismatch {
+            path: key_path!(.items[1_usize]::Sword.damage),
+            expected: "u32".to_owned(),
+            found: "i32".to_owned(),
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "expected `u32`, found `i32` at `.items[1]::Sword.damage`"
+    );
+
+    // failed fields aren't set
+    let value = builder.finish().unwrap();
+    assert_eq!(Player::from_reflect(&value).unwrap().health, 0);
+
+    // fields without default values must be set
+    #[derive(Reflect, Clone, Debug)]
+    #[reflect(crate_name(crate))]
+    struct NoDefault {
+        // opaque types without `#[reflect(default)]` have no default value
+        value: StructValue,
+    }
+
+    assert_eq!(
+        <NoDefault as DescribeType>::type_descriptor()
+            .value_builder()
+            .unwrap()
+            .finish()
+            .unwrap_err(),
+        ShapeError::MissingField {
+            path: key_path!(.value)
+        }
+    );
+}
+
+#[test]
+fn validate_struct_value() {
+    let descriptor = <Player as DescribeType>::type_descriptor();
+
+    let player = Player {
+        name: "Bob".to_owned(),
+        health: 10,
+        position: Position(1.0, 2.0),
+        items: Vec::from([Item::Sword { damage: 1 }, Item::Potion(1, false)]),
+        stats: BTreeMap::from([("speed".to_owned(), 1.0)]),
+    };
+    let Value::StructValue(value) = player.to_value() else {
+        panic!()
+    };
+    assert_eq!(value.validate_against(&descriptor), Ok(()));
+
+    let value = StructValue::new()
+        .with_field("name", 1_i32)
+        .with_field("health", 10_u32)
+        .with_field("health", 11_u32)
+        .with_field("position", (1.0_f32, 2.0_f32).to_value())
+        .with_field(
+            "items",
+            Vec::from([
+                EnumValue::new_unit_variant("Shield").into(),
+                EnumValue::new_tuple_variant("Potion")
+                    .with_tuple_field(1_u8)
+                    .with_tuple_field(true)
+                    .with_tuple_field(1_u8)
+                    .finish()
+                    .to_value(),
+                EnumValue::new_unit_variant("Sword").to_value(),
+            ]),
+        )
+        .with_field("stats", BTreeMap::from([(1_i32, 1.0_f32)]))
+        .with_field("mana", 10_u32);
+
+    let errors = value.validate_against(&descriptor).unwrap_err();
+    assert_eq!(
+        errors.iter().map(ShapeError::path).collect::<Vec<_>>(),
+        [
+            &key_path!(.name),
+            &key_path!(.health),
+            &key_path!(.position),
+            &key_path!(.items[0_usize]),
+            &key_path!(.items[1_usize]::Potion.2),
+            &key_path!(.items[2_usize]::Sword),
+            &key_path!(.stats),
+            &key_path!(.mana),
+        ]
+    );
+    assert_eq!(
+        errors[0],
+        ShapeError::TypeMismatch {
+            path: key_path!(.name),
+            expected: type_name::<String>().to_owned(),
+            found: "i32".to_owned(),
+        }
+    );
+    assert!(matches!(errors[1], ShapeError::DuplicateField { .. }));
+    assert!(matches!(errors[2], ShapeError::TypeMismatch { .. }));
+    assert_eq!(
+        errors[3],
+        ShapeError::UnknownVariant {
+            path: key_path!(.items[0_usize]),
+            name: "Shield".to_owned(),
+        }
+    );
+    assert!(matches!(errors[4], ShapeError::UnknownField { .. }));
+    // a unit variant where a struct variant was expected
+    assert!(matches!(errors[5], ShapeError::TypeMismatch { .. }));
+    // map keys are reported at the map
+    assert!(matches!(errors[6], ShapeError::TypeMismatch { .. }));
+    assert!(matches!(errors[7], ShapeError::UnknownField { .. }));
+}
+
+#[test]
+fn validate_enum_value() {
+    let descriptor = <Item as DescribeType>::type_descriptor();
+
+    let value = EnumValue::new_struct_variant("Sword")
+        .with_struct_field("damage", 1_u32)
+        .finish();
+    assert_eq!(value.validate_against(&descriptor), Ok(()));
+
+    let value = EnumValue::new_struct_variant("Sword").finish();
+    assert_eq!(
+        value.validate_against(&descriptor).unwrap_err(),
+        [ShapeError::MissingField {
+            path: key_path!(::Sword.damage),
+        }]
+    );
+
+    let value = EnumValue::new_tuple_variant("Potion")
+        .with_tuple_field(1_u8)
+        .finish();
+    let errors = value.validate_against(&descriptor).unwrap_err();
+    assert_eq!(
+        errors,
+        [ShapeError::MissingField {
+            path: key_path!(::Potion.1),
+        }]
+    );
+    assert_eq!(errors[0].to_string(), "missing field `::Potion.1`");
+}
@@ -0,0 +1,116 @@
+use alloc::collections::BTreeMap;
+use alloc::collections::BTreeSet;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::Reflect;
+use crate::Value;
+
+#[derive(Reflect, Clone, Debug)]
+#[reflect(crate_name(crate))]
+struct Scene {
+    name: String,
+    // declared out of order, since `StructValue`s keep their fields sorted by name
+    nodes: Vec<Node>,
+    lookup: BTreeMap<String, u32>,
+    tags: BTreeSet<u8>,
+    size: (u32, f32),
+    grid: [[i8; 2]; 2],
+    extra: Value,
+}
+
+#[derive(Reflect, Clone, Debug)]
+#[reflect(crate_name(crate))]
+struct Node {
+    id: Id,
+    shape: Shape,
+    layer: Layer,
+    parent: Option<Id>,
+}
+
+#[derive(Reflect, Clone, Debug)]
+#[reflect(crate_name(crate))]
+struct Id(u64);
+
+#[derive(Reflect, Clone, Debug)]
+#[reflect(crate_name(crate))]
+enum Shape {
+    Circle { radius: f32 },
+    Polygon(Vec<(f32, f32)>),
+    Empty,
+}
+
+#[derive(Reflect, Clone, Copy, Debug)]
+#[reflect(crate_name(crate))]
+#[repr(u8)]
+enum Layer {
+    Background = 1,
+    Foreground = 7,
+}
+
+fn scene() -> Scene {
+    Scene {
+        name: "scene".to_owned(),
+        nodes: Vec::from([
+            Node {
+                id: Id(1),
+                shape: Shape::Circle { radius: 1.0 },
+                layer: Layer::Foreground,
+                parent: None,
+            },
+            Node {
+                id: Id(2),
+                shape: Shape::Polygon(Vec::from([(0.0, 0.0), (1.0, 0.5)])),
+                layer: Layer::Background,
+                parent: Some(Id(1)),
+            },
+            Node {
+                id: Id(3),
+                shape: Shape::Empty,
+                layer: Layer::Background,
+                parent: Some(Id(2)),
+            },
+        ]),
+        lookup: BTreeMap::from([("a".to_owned(), 1), ("b".to_owned(), 2)]),
+        tags: BTreeSet::from([3, 1]),
+        size: (1, 2.5),
+        grid: [[1, 2], [3, 4]],
+        extra: BTreeMap::from([(1_i32, "one".to_owned())]).to_value(),
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serializes_like_value_with_serde() {
+    // JSON only supports string keys so leave out the maps
+    let Scene {
+        name,
+        nodes,
+        tags,
+        size,
+        grid,
+        ..
+    } = scene();
+    let scene = (name, nodes, tags, size, grid);
+
+    let json = serde_json::to_string(&scene.to_value_ref()).unwrap();
+    assert_eq!(json, serde_json::to_string(&scene.to_value()).unwrap());
+
+    let value = serde_json::from_str::<Value>(&json).unwrap();
+    assert_eq!(value, scene.to_value());
+}
+
+#[cfg(feature = "speedy")]
+#[test]
+fn serializes_like_value_with_speedy() {
+    use speedy::Readable;
+    use speedy::Writable;
+
+    let scene = scene();
+
+    let bytes = scene.to_value_ref().write_to_vec().unwrap();
+    assert_eq!(bytes, scene.to_value().write_to_vec().unwrap());
+
+    let value = Value::read_from_buffer(&bytes).unwrap();
+    assert_eq!(value, scene.to_value());
+}
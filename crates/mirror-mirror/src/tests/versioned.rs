@@ -0,0 +1,75 @@
+use speedy::Readable;
+use speedy::Writable;
+
+use crate::versioned::*;
+use crate::DescribeType;
+use crate::Reflect;
+
+#[derive(Reflect, Clone, Debug, PartialEq, Readable, Writable)]
+#[reflect(crate_name(crate), meta(version = 1_u32))]
+struct FooV1 {
+    a: i32,
+}
+
+#[derive(Reflect, Clone, Debug, PartialEq, Readable, Writable)]
+#[reflect(crate_name(crate), meta(version = 2_u32))]
+struct Foo {
+    a: i32,
+    b: bool,
+}
+
+// same version as `Foo` but a different shape
+#[derive(Reflect, Clone, Debug, PartialEq, Readable, Writable)]
+#[reflect(crate_name(crate), meta(version = 2_u32))]
+struct FooChanged {
+    a: i64,
+}
+
+#[test]
+fn round_trip() {
+    let foo = Foo { a: 1, b: true };
+    let bytes = Versioned(foo.clone()).write_to_vec().unwrap();
+    let Versioned(new_foo) = Versioned::<Foo>::read_from_buffer(&bytes).unwrap();
+    assert_eq!(foo, new_foo);
+}
+
+#[test]
+fn upgrade() {
+    let bytes = Versioned(FooV1 { a: 1 }).write_to_vec().unwrap();
+
+    assert!(matches!(
+        Versioned::<Foo>::read_from_buffer(&bytes).unwrap_err(),
+        VersionedError::NoUpgrade { version: 1, .. }
+    ));
+
+    let reader =
+        VersionedReader::<Foo>::new().with_upgrade(|old: FooV1| Foo { a: old.a, b: false });
+    let Versioned(foo) = reader.read_from_buffer(&bytes).unwrap();
+    assert_eq!(foo, Foo { a: 1, b: false });
+}
+
+#[test]
+fn schema_mismatch() {
+    let bytes = Versioned(FooChanged { a: 1 }).write_to_vec().unwrap();
+    assert!(matches!(
+        Versioned::<Foo>::read_from_buffer(&bytes).unwrap_err(),
+        VersionedError::SchemaMismatch { version: 2, .. }
+    ));
+
+    assert!(matches!(
+        Versioned::<Foo>::read_from_buffer(&bytes[..4]).unwrap_err(),
+        VersionedError::MissingHeader
+    ));
+}
+
+#[test]
+fn stable_hash() {
+    assert_eq!(schema_version(&<Foo as DescribeType>::type_descriptor()), 2);
+    assert_eq!(schema_version(&<i32 as DescribeType>::type_descriptor()), 0);
+
+    // the hash must never change between releases, as that would break existing data
+    assert_eq!(
+        stable_type_hash(&<Vec<(i32, String)> as DescribeType>::type_descriptor()),
+        471853286338992738
+    );
+}
@@ -0,0 +1,180 @@
+use alloc::collections::BTreeMap;
+use alloc::collections::BTreeSet;
+
+use crate::key_path;
+use crate::key_path::KeyPath;
+use crate::key_path::KeyPathPattern;
+use crate::visit::walk;
+use crate::visit::walk_mut;
+use crate::visit::ReflectVisitor;
+use crate::visit::ReflectVisitorMut;
+use crate::visit::WalkCursor;
+use crate::visit::WalkStatus;
+use crate::Enum;
+use crate::List;
+use crate::Reflect;
+use crate::ScalarMut;
+use crate::ScalarRef;
+
+#[derive(Reflect, Clone, Debug, PartialEq)]
+#[reflect(crate_name(crate))]
+struct Scene {
+    units: Vec<Unit>,
+    spawns: BTreeMap<String, (i32, i32)>,
+}
+
+#[derive(Reflect, Clone, Debug, PartialEq)]
+#[reflect(crate_name(crate))]
+enum Unit {
+    Player { health: i32 },
+    Npc(i32),
+}
+
+fn scene() -> Scene {
+    Scene {
+        units: Vec::from([Unit::Player { health: 10 }, Unit::Npc(5)]),
+        spawns: BTreeMap::from([("start".to_owned(), (1, 2))]),
+    }
+}
+
+#[derive(Default)]
+struct Recorder(Vec<(&'static str, KeyPath)>);
+
+impl ReflectVisitor for Recorder {
+    fn visit_struct(&mut self, path: &KeyPath, _value: &dyn crate::Struct) {
+        self.0.push(("struct", path.clone()));
+    }
+
+    fn visit_enum(&mut self, path: &KeyPath, _value: &dyn Enum) {
+        self.0.push(("enum", path.clone()));
+    }
+
+    fn visit_list(&mut self, path: &KeyPath, _value: &dyn List) {
+        self.0.push(("list", path.clone()));
+    }
+
+    fn visit_field(&mut self, path: &KeyPath, _value: &dyn Reflect) {
+        self.0.push(("field", path.clone()));
+    }
+
+    fn visit_element(&mut self, path: &KeyPath, _value: &dyn Reflect) {
+        self.0.push(("element", path.clone()));
+    }
+
+    fn visit_scalar(&mut self, path: &KeyPath, value: ScalarRef<'_>) {
+        assert!(matches!(value, ScalarRef::i32(_)));
+        self.0.push(("scalar", path.clone()));
+    }
+}
+
+#[test]
+fn walk_visits_everything_with_paths() {
+    let mut recorder = Recorder::default();
+    walk(&scene(), &mut recorder);
+
+    assert_eq!(
+        recorder.0,
+        [
+            ("struct", key_path!()),
+            ("field", key_path!(.units)),
+            ("list", key_path!(.units)),
+            ("element", key_path!(.units[0_usize])),
+            ("enum", key_path!(.units[0_usize])),
+            ("field", key_path!(.units[0_usize]::Player.health)),
+            ("scalar", key_path!(.units[0_usize]::Player.health)),
+            ("element", key_path!(.units[1_usize])),
+            ("enum", key_path!(.units[1_usize])),
+            ("field", key_path!(.units[1_usize]::Npc.0)),
+            ("scalar", key_path!(.units[1_usize]::Npc.0)),
+            ("field", key_path!(.spawns)),
+            ("element", key_path!(.spawns["start"])),
+            ("field", key_path!(.spawns["start"].0)),
+            ("scalar", key_path!(.spawns["start"].0)),
+            ("field", key_path!(.spawns["start"].1)),
+            ("scalar", key_path!(.spawns["start"].1)),
+        ]
+    );
+}
+
+#[test]
+fn walk_mut_changes_values() {
+    struct Heal;
+
+    impl ReflectVisitorMut for Heal {
+        fn visit_list(&mut self, _path: &KeyPath, value: &mut dyn List) {
+            value.push(&Unit::Npc(0));
+        }
+
+        fn visit_scalar(&mut self, path: &KeyPath, value: ScalarMut<'_>) {
+            if let ScalarMut::i32(n) = value {
+                if KeyPathPattern::from(key_path!(.units)).matches_prefix_of(path) {
+                    *n += 100;
+                }
+            }
+        }
+    }
+
+    let mut scene = scene();
+    walk_mut(&mut scene, &mut Heal);
+
+    assert_eq!(
+        scene,
+        Scene {
+            units: Vec::from([Unit::Player { health: 110 }, Unit::Npc(105), Unit::Npc(100),]),
+            spawns: BTreeMap::from([("start".to_owned(), (1, 2))]),
+        }
+    );
+}
+
+#[test]
+fn cursor_matches_walk() {
+    #[derive(Reflect, Clone, Debug)]
+    #[reflect(crate_name(crate))]
+    struct WithSet {
+        scene: Scene,
+        ids: BTreeSet<i32>,
+    }
+
+    let value = WithSet {
+        scene: scene(),
+        ids: BTreeSet::from([3, 1, 2]),
+    };
+
+    let mut expected = Recorder::default();
+    walk(&value, &mut expected);
+
+    for budget in [1, 3, usize::MAX] {
+        let mut cursor = WalkCursor::new();
+        let mut recorder = Recorder::default();
+        let mut steps = 0;
+        while cursor.step(&value, &mut recorder, budget) == WalkStatus::Paused {
+            steps += 1;
+        }
+        assert!(cursor.is_done());
+        assert_eq!(recorder.0, expected.0);
+        assert_eq!(steps == 0, budget == usize::MAX);
+    }
+}
+
+#[test]
+fn cursor_skips_removed_values() {
+    let mut scene = scene();
+    let mut cursor = WalkCursor::new();
+    let mut recorder = Recorder::default();
+
+    // the scene and the `units` field
+    assert_eq!(cursor.step(&scene, &mut recorder, 2), WalkStatus::Paused);
+    scene.units.pop();
+    assert_eq!(
+        cursor.step_while(&scene, &mut recorder, || true),
+        WalkStatus::Done
+    );
+
+    assert!(!recorder
+        .0
+        .iter()
+        .any(|(_, path)| path == &key_path!(.units[1_usize])));
+    assert!(recorder
+        .0
+        .contains(&("scalar", key_path!(.spawns["start"].1))));
+}
@@ -0,0 +1,345 @@
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::any::Any;
+use core::any::TypeId;
+use core::fmt;
+
+use crate::enum_::VariantField;
+use crate::key_path::GetPath;
+use crate::key_path::KeyPath;
+use crate::patch::PatchError;
+use crate::patch::PatchReport;
+use crate::reflect_debug;
+use crate::type_info::graph::NodeId;
+use crate::type_info::graph::TypeGraph;
+use crate::DescribeType;
+use crate::FromReflect;
+use crate::Reflect;
+use crate::ReflectError;
+use crate::ReflectMut;
+use crate::ReflectOwned;
+use crate::ReflectRef;
+use crate::TypeDescriptor;
+use crate::Value;
+
+/// A value that remembers which parts of it have been changed through reflection.
+///
+/// `Tracked<T>` is reflected as `T`, and records the paths of the values changed by
+/// [`patch`](Reflect::patch), [`try_patch`](Reflect::try_patch), and
+/// [`field_mut`](Self::field_mut). [`take_dirty`](Self::take_dirty) returns them, which is
+/// cheaper than diffing the whole value to find out what to send over the network or push onto
+/// an undo stack.
+///
+/// Changes that can't be tracked more precisely, such as through [`get_mut`](Self::get_mut) or
+/// [`Reflect::reflect_mut`], mark the whole value as changed, with an empty path.
+///
+/// A path is only recorded once, and not if the value containing it is already recorded.
+///
+/// # Example
+///
+/// ```
+/// use mirror_mirror::{key_path, Reflect};
+/// use mirror_mirror::struct_::StructValue;
+/// use mirror_mirror::tracked::Tracked;
+///
+/// #[derive(Reflect, Clone, Debug)]
+/// struct Player {
+///     name: String,
+///     position: [f32; 2],
+///     health: u32,
+/// }
+///
+/// let mut player = Tracked::new(Player {
+///     name: "alice".to_owned(),
+///     position: [0.0, 0.0],
+///     health: 100,
+/// });
+///
+/// player.patch(&StructValue::new().with_field("health", 90_u32));
+/// *player.get_field_mut::<f32>(&key_path!(.position[1_usize])).unwrap() = 2.0;
+///
+/// assert_eq!(
+///     player.take_dirty(),
+///     [key_path!(.health), key_path!(.position[1_usize])],
+/// );
+/// assert!(!player.is_dirty());
+/// assert_eq!(player.get().position, [0.0, 2.0]);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Tracked<T> {
+    value: T,
+    dirty: Vec<KeyPath>,
+}
+
+impl<T> Tracked<T>
+where
+    T: Reflect,
+{
+    /// Start tracking changes to `value`, which starts out clean.
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            dirty: Vec::new(),
+        }
+    }
+
+    pub fn get(&self) -> &T {
+        &self.value
+    }
+
+    /// Borrow the value mutably, which marks all of it as changed.
+    pub fn get_mut(&mut self) -> &mut T {
+        self.mark_dirty(KeyPath::default());
+        &mut self.value
+    }
+
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    /// Get the value at `key_path` mutably, marking it as changed.
+    ///
+    /// Nothing is marked if there is no value at `key_path`.
+    pub fn field_mut(&mut self, key_path: &KeyPath) -> Option<&mut dyn Reflect> {
+        self.value.at(key_path)?;
+        self.mark_dirty(key_path.clone());
+        self.value.at_mut(key_path)
+    }
+
+    /// Get the value at `key_path` mutably as a `U`, marking it as changed.
+    ///
+    /// Nothing is marked if there is no value of type `U` at `key_path`.
+    pub fn get_field_mut<U>(&mut self, key_path: &KeyPath) -> Option<&mut U>
+    where
+        U: Reflect,
+    {
+        self.value.get_at::<U>(key_path)?;
+        self.mark_dirty(key_path.clone());
+        self.value.get_at_mut(key_path)
+    }
+
+    /// Mark the value at `key_path` as changed, for changes made some other way.
+    pub fn mark_dirty(&mut self, key_path: KeyPath) {
+        if self.dirty.iter().any(|path| is_prefix(path, &key_path)) {
+            return;
+        }
+        self.dirty.retain(|path| !is_prefix(&key_path, path));
+        self.dirty.push(key_path);
+    }
+
+    /// Whether anything has changed since the last call to [`take_dirty`](Self::take_dirty).
+    pub fn is_dirty(&self) -> bool {
+        !self.dirty.is_empty()
+    }
+
+    /// The paths of the values that changed, in the order they were first changed.
+    ///
+    /// The value is clean again afterwards.
+    pub fn take_dirty(&mut self) -> Vec<KeyPath> {
+        core::mem::take(&mut self.dirty)
+    }
+
+    fn mark_applied(&mut self, report: &PatchReport) {
+        for path in report.applied() {
+            self.mark_dirty(path.clone());
+        }
+    }
+}
+
+/// The paths of the values in `target` that patching it with `patch` changes, the same ones
+/// [`PatchReport::applied`] lists.
+fn patched_paths(
+    target: &dyn Reflect,
+    patch: &dyn Reflect,
+    path: &mut KeyPath,
+    out: &mut Vec<KeyPath>,
+) {
+    macro_rules! field {
+        ($key:expr, $target:expr, $patch:expr) => {
+            if let Some(target) = $target {
+                path.push_field($key);
+                patched_paths(target, $patch, path, out);
+                path.pop();
+            }
+        };
+    }
+
+    macro_rules! elements {
+        ($target:expr, $patch:expr) => {{
+            let target = $target;
+            for (index, element) in $patch.iter().enumerate() {
+                if let Some(target) = target.get(index) {
+                    path.push_get(index);
+                    patched_paths(target, element, path, out);
+                    path.pop();
+                }
+            }
+        }};
+    }
+
+    match (target.reflect_ref(), patch.reflect_ref()) {
+        (ReflectRef::Struct(target), ReflectRef::Struct(patch)) => {
+            for (name, field) in patch.fields() {
+                field!(name, target.field(name), field);
+            }
+        }
+        (ReflectRef::TupleStruct(target), ReflectRef::TupleStruct(patch)) => {
+            for (index, field) in patch.fields().enumerate() {
+                field!(index, target.field_at(index), field);
+            }
+        }
+        (ReflectRef::Tuple(target), ReflectRef::Tuple(patch)) => {
+            for (index, field) in patch.fields().enumerate() {
+                field!(index, target.field_at(index), field);
+            }
+        }
+        (ReflectRef::Enum(target), ReflectRef::Enum(patch))
+            if target.variant_name() == patch.variant_name() =>
+        {
+            path.push_variant(patch.variant_name());
+            for (index, field) in patch.fields().enumerate() {
+                match field {
+                    VariantField::Struct(name, field) => field!(name, target.field(name), field),
+                    VariantField::Tuple(field) => field!(index, target.field_at(index), field),
+                }
+            }
+            path.pop();
+        }
+        (ReflectRef::Array(target), ReflectRef::Array(patch)) => elements!(target, patch),
+        (ReflectRef::Array(target), ReflectRef::List(patch)) => elements!(target, patch),
+        (ReflectRef::List(target), ReflectRef::List(patch)) => elements!(target, patch),
+        (ReflectRef::List(target), ReflectRef::Array(patch)) => elements!(target, patch),
+        (ReflectRef::Map(target), ReflectRef::Map(patch)) => {
+            for (key, value) in patch.iter() {
+                if let Some(target) = target.get(key) {
+                    path.push_get(key.to_value());
+                    patched_paths(target, value, path, out);
+                    path.pop();
+                }
+            }
+        }
+        _ => out.push(path.clone()),
+    }
+}
+
+fn is_prefix(prefix: &KeyPath, key_path: &KeyPath) -> bool {
+    prefix.len() <= key_path.len() && prefix.iter().zip(key_path.iter()).all(|(a, b)| a == b)
+}
+
+impl<T> From<T> for Tracked<T>
+where
+    T: Reflect,
+{
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T> DescribeType for Tracked<T>
+where
+    T: DescribeType,
+{
+    fn build(graph: &mut TypeGraph) -> NodeId {
+        T::build(graph)
+    }
+}
+
+impl<T> Reflect for Tracked<T>
+where
+    T: Reflect + DescribeType,
+{
+    fn type_descriptor(&self) -> Cow<'static, TypeDescriptor> {
+        <T as DescribeType>::type_descriptor()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self.value.as_any()
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self.mark_dirty(KeyPath::default());
+        self.value.as_any_mut()
+    }
+
+    fn as_reflect(&self) -> &dyn Reflect {
+        self
+    }
+
+    fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+        self
+    }
+
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        Box::new(self.value).reflect_owned()
+    }
+
+    fn reflect_ref(&self) -> ReflectRef<'_> {
+        self.value.reflect_ref()
+    }
+
+    fn reflect_mut(&mut self) -> ReflectMut<'_> {
+        self.mark_dirty(KeyPath::default());
+        self.value.reflect_mut()
+    }
+
+    fn patch(&mut self, value: &dyn Reflect) {
+        let mut patched = Vec::new();
+        patched_paths(&self.value, value, &mut KeyPath::default(), &mut patched);
+        for path in patched {
+            self.mark_dirty(path);
+        }
+        self.value.patch(value);
+    }
+
+    fn try_patch(&mut self, value: &dyn Reflect) -> Result<PatchReport, PatchError> {
+        let result = self.value.try_patch(value);
+        match &result {
+            Ok(report) => self.mark_applied(report),
+            Err(error) => self.mark_applied(error.report()),
+        }
+        result
+    }
+
+    fn validate_self(&self) -> Result<(), ReflectError> {
+        self.value.validate_self()
+    }
+
+    fn to_value(&self) -> Value {
+        self.value.to_value()
+    }
+
+    fn clone_reflect(&self) -> Box<dyn Reflect> {
+        self.value.clone_reflect()
+    }
+
+    fn debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        reflect_debug(self, f)
+    }
+
+    fn type_id(&self) -> TypeId {
+        Reflect::type_id(&self.value)
+    }
+
+    fn type_name(&self) -> &str {
+        Reflect::type_name(&self.value)
+    }
+}
+
+impl<T> FromReflect for Tracked<T>
+where
+    T: FromReflect + DescribeType,
+{
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        Some(Self::new(T::from_reflect(reflect)?))
+    }
+}
+
+impl<T> From<Tracked<T>> for Value
+where
+    T: Into<Value>,
+{
+    fn from(tracked: Tracked<T>) -> Self {
+        tracked.value.into()
+    }
+}
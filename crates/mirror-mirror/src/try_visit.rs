@@ -135,6 +135,14 @@ where
                 try_visit(visitor, value, value_ty)?;
             }
         }
+        Type::Set(set_ty) => {
+            let set = value.as_set().unwrap();
+            let element_ty = set_ty.element_type();
+
+            for element in set.iter() {
+                try_visit(visitor, element, element_ty)?;
+            }
+        }
         Type::Opaque(opaque_ty) => {
             visitor.try_visit_opaque(value, opaque_ty)?;
         }
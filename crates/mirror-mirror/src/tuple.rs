@@ -46,8 +46,8 @@ pub struct TupleValue {
 }
 
 impl TupleValue {
-    pub fn new() -> Self {
-        Self::default()
+    pub const fn new() -> Self {
+        Self { fields: Vec::new() }
     }
 
     pub fn with_capacity(capacity: usize) -> Self {
@@ -56,6 +56,13 @@ impl TupleValue {
         }
     }
 
+    pub(crate) fn compact(&mut self) {
+        for field in &mut self.fields {
+            field.compact();
+        }
+        self.fields.shrink_to_fit();
+    }
+
     pub fn with_field(mut self, value: impl Into<Value>) -> Self {
         self.push_field(value);
         self
@@ -339,11 +346,16 @@ impl<'a> Iterator for Iter<'a> {
         self.index += 1;
         Some(value)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
 }
 
 impl<'a> ExactSizeIterator for Iter<'a> {
     fn len(&self) -> usize {
-        self.tuple.fields_len()
+        self.tuple.fields_len().saturating_sub(self.index)
     }
 }
 
@@ -1,3 +1,4 @@
+use alloc::borrow::Cow;
 use alloc::boxed::Box;
 use core::any::Any;
 use core::fmt;
@@ -8,6 +9,7 @@ use crate::tuple::TupleValue;
 use crate::type_info::graph::NodeId;
 use crate::type_info::graph::OpaqueNode;
 use crate::type_info::graph::TypeGraph;
+use crate::value::Origin;
 use crate::DescribeType;
 use crate::FromReflect;
 use crate::Reflect;
@@ -43,6 +45,9 @@ impl fmt::Debug for dyn TupleStruct {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TupleStructValue {
     tuple: TupleValue,
+    #[cfg_attr(feature = "speedy", speedy(skip))]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) origin: Origin,
 }
 
 impl TupleStructValue {
@@ -53,13 +58,50 @@ impl TupleStructValue {
     pub fn with_capacity(capacity: usize) -> Self {
         Self {
             tuple: TupleValue::with_capacity(capacity),
+            origin: Default::default(),
         }
     }
 
-    pub fn with_field(self, value: impl Into<Value>) -> Self {
-        Self {
-            tuple: self.tuple.with_field(value),
-        }
+    pub fn with_field(mut self, value: impl Into<Value>) -> Self {
+        self.push_field(value);
+        self
+    }
+
+    /// Set the name of the type this value was created from.
+    ///
+    /// Returned by [`Reflect::type_name`] instead of the name of this type. Set by
+    /// `#[derive(Reflect)]`.
+    pub fn with_type_name(mut self, type_name: impl Into<Cow<'static, str>>) -> Self {
+        self.set_type_name(type_name);
+        self
+    }
+
+    pub fn set_type_name(&mut self, type_name: impl Into<Cow<'static, str>>) {
+        self.origin.set_type_name(type_name);
+    }
+
+    /// Set the type this value was created from, whose stable hash is returned by
+    /// [`type_hash`](Self::type_hash). Also sets the type name to `T`'s, unless one is set
+    /// already. Set by `#[derive(Reflect)]`.
+    pub fn with_origin<T>(mut self) -> Self
+    where
+        T: DescribeType,
+    {
+        self.origin.set_type::<T>();
+        self
+    }
+
+    /// The stable hash of the type this value was created from, if known.
+    ///
+    /// This is the hash that type descriptors use to identify the type, so it can be used to look
+    /// the type up in a registry.
+    pub fn type_hash(&self) -> Option<u64> {
+        self.origin.type_hash()
+    }
+
+    pub(crate) fn compact(&mut self) {
+        self.tuple.compact();
+        self.origin.compact();
     }
 
     pub fn push_field(&mut self, value: impl Into<Value>) {
@@ -78,6 +120,12 @@ impl DescribeType for TupleStructValue {
 impl Reflect for TupleStructValue {
     trivial_reflect_methods!();
 
+    fn type_name(&self) -> &str {
+        self.origin
+            .type_name()
+            .unwrap_or_else(|| core::any::type_name::<Self>())
+    }
+
     fn patch(&mut self, value: &dyn Reflect) {
         if let Some(tuple) = value.reflect_ref().as_tuple_struct() {
             for (index, value) in self.fields_mut().enumerate() {
@@ -142,11 +190,12 @@ impl TupleStruct for TupleStructValue {
 impl FromReflect for TupleStructValue {
     fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
         let tuple_struct = reflect.reflect_ref().as_tuple_struct()?;
-        let this = tuple_struct
+        let mut this = tuple_struct
             .fields()
             .fold(TupleStructValue::default(), |builder, value| {
                 builder.with_field(value.to_value())
             });
+        this.origin = Origin::of::<Self>(reflect);
         Some(this)
     }
 }
@@ -190,11 +239,16 @@ impl<'a> Iterator for Iter<'a> {
         self.index += 1;
         Some(value)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
 }
 
 impl<'a> ExactSizeIterator for Iter<'a> {
     fn len(&self) -> usize {
-        self.tuple_struct.fields_len()
+        self.tuple_struct.fields_len().saturating_sub(self.index)
     }
 }
 
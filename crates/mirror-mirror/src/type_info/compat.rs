@@ -0,0 +1,762 @@
+//! Comparing two versions of a type, and converting values between them.
+//!
+//! Values serialized with an old version of a type can outlive it, for example in asset files.
+//! [`check`] lists what changed between two [`TypeDescriptor`]s, and [`migrate`] converts a
+//! value of the old version into the new version as well as it can.
+//!
+//! # Example
+//!
+//! ```
+//! use mirror_mirror::{key_path, DescribeType, FromReflect, Reflect};
+//! use mirror_mirror::type_info::compat::{check, migrate, CompatChange};
+//!
+//! mod v1 {
+//!     use mirror_mirror::Reflect;
+//!
+//!     #[derive(Reflect, Clone, Debug)]
+//!     pub struct Light {
+//!         pub intensity: f32,
+//!         pub colour: [f32; 3],
+//!         pub radius: u16,
+//!     }
+//! }
+//!
+//! mod v2 {
+//!     use mirror_mirror::Reflect;
+//!
+//!     #[derive(Reflect, Clone, Debug, PartialEq)]
+//!     pub struct Light {
+//!         pub intensity: f32,
+//!         pub color: [f32; 3],
+//!         pub radius: f64,
+//!         pub enabled: bool,
+//!     }
+//! }
+//!
+//! let old = <v1::Light as DescribeType>::type_descriptor();
+//! let new = <v2::Light as DescribeType>::type_descriptor();
+//!
+//! let report = check(&old, &new);
+//! assert_eq!(
+//!     report.changes(),
+//!     [
+//!         CompatChange::FieldRenamed { path: key_path!(.colour), to: "color".to_owned() },
+//!         CompatChange::TypeChanged {
+//!             path: key_path!(.radius),
+//!             from: "u16".to_owned(),
+//!             to: "f64".to_owned(),
+//!         },
+//!         CompatChange::FieldAdded { path: key_path!(.enabled), has_default: true },
+//!     ]
+//! );
+//! assert!(report.is_migratable());
+//!
+//! let light = v1::Light { intensity: 1.0, colour: [1.0, 0.5, 0.0], radius: 10 };
+//! let migrated = migrate(light.to_value(), &old, &new);
+//! assert_eq!(
+//!     v2::Light::from_reflect(&migrated).unwrap(),
+//!     v2::Light { intensity: 1.0, color: [1.0, 0.5, 0.0], radius: 10.0, enabled: false },
+//! );
+//! ```
+
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::coerce::Coercions;
+use crate::enum_::EnumValue;
+use crate::enum_::VariantKind;
+use crate::key_path::KeyPath;
+use crate::struct_::StructValue;
+use crate::tuple::TupleValue;
+use crate::tuple_struct::TupleStructValue;
+use crate::type_info::NamedField;
+use crate::type_info::Type;
+use crate::type_info::UnnamedField;
+use crate::type_info::Variant;
+use crate::Reflect;
+use crate::ReflectRef;
+use crate::TypeDescriptor;
+use crate::Value;
+
+/// The differences between two versions of a type, returned by [`check`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CompatReport {
+    changes: Vec<CompatChange>,
+}
+
+impl CompatReport {
+    /// The changes, outermost first and otherwise in declaration order of the old type.
+    pub fn changes(&self) -> &[CompatChange] {
+        &self.changes
+    }
+
+    pub fn iter(&self) -> core::slice::Iter<'_, CompatChange> {
+        self.changes.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.changes.len()
+    }
+
+    /// Returns `true` if the two versions have the same structure.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Whether [`migrate`] can convert every value of the old version without losing anything
+    /// but removed fields, see [`CompatChange::is_migratable`].
+    pub fn is_migratable(&self) -> bool {
+        self.changes.iter().all(CompatChange::is_migratable)
+    }
+}
+
+impl<'a> IntoIterator for &'a CompatReport {
+    type Item = &'a CompatChange;
+    type IntoIter = core::slice::Iter<'a, CompatChange>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl fmt::Display for CompatReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for change in &self.changes {
+            writeln!(f, "{change}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A single difference in a [`CompatReport`].
+///
+/// The path is where the change is in the old version of the type, or for added fields and
+/// variants where they are in the new version. Changes inside lists, arrays, and sets are
+/// reported at element `[0]`, and inside maps at the default key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CompatChange {
+    /// A field was added. Migrated values get its default value, if it has one.
+    FieldAdded { path: KeyPath, has_default: bool },
+    /// A field was removed, and is dropped from migrated values.
+    FieldRemoved { path: KeyPath },
+    /// A field was renamed to `to`.
+    ///
+    /// Detected when a removed and an added field have the same type, either at the same
+    /// position or as the only removed and added fields of that type.
+    FieldRenamed { path: KeyPath, to: String },
+    /// The value at `path` has a different type, such as a number that became a string, or an
+    /// array with a different length.
+    TypeChanged {
+        path: KeyPath,
+        from: String,
+        to: String,
+    },
+    /// A variant was added.
+    VariantAdded { path: KeyPath },
+    /// A variant was removed, so values of it can't be migrated.
+    VariantRemoved { path: KeyPath },
+    /// A variant switched between having named fields, unnamed fields, or no fields.
+    VariantKindChanged { path: KeyPath },
+}
+
+impl CompatChange {
+    pub fn path(&self) -> &KeyPath {
+        match self {
+            CompatChange::FieldAdded { path, .. }
+            | CompatChange::FieldRemoved { path }
+            | CompatChange::FieldRenamed { path, .. }
+            | CompatChange::TypeChanged { path, .. }
+            | CompatChange::VariantAdded { path }
+            | CompatChange::VariantRemoved { path }
+            | CompatChange::VariantKindChanged { path } => path,
+        }
+    }
+
+    /// Whether [`migrate`] handles this change for every value.
+    ///
+    /// Added fields without default values, type changes other than between scalars, and
+    /// removed or changed variants aren't.
+    pub fn is_migratable(&self) -> bool {
+        match self {
+            CompatChange::FieldAdded { has_default, .. } => *has_default,
+            CompatChange::FieldRemoved { .. }
+            | CompatChange::FieldRenamed { .. }
+            | CompatChange::VariantAdded { .. } => true,
+            CompatChange::TypeChanged { from, to, .. } => is_scalar(from) && is_scalar(to),
+            CompatChange::VariantRemoved { .. } | CompatChange::VariantKindChanged { .. } => false,
+        }
+    }
+}
+
+impl fmt::Display for CompatChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CompatChange::FieldAdded { path, has_default } => {
+                write!(f, "added field `{path}`")?;
+                if !has_default {
+                    write!(f, " without a default value")?;
+                }
+                Ok(())
+            }
+            CompatChange::FieldRemoved { path } => write!(f, "removed field `{path}`"),
+            CompatChange::FieldRenamed { path, to } => {
+                write!(f, "renamed field `{path}` to `{to}`")
+            }
+            CompatChange::TypeChanged { path, from, to } => {
+                write!(f, "changed type of `{path}` from `{from}` to `{to}`")
+            }
+            CompatChange::VariantAdded { path } => write!(f, "added variant `{path}`"),
+            CompatChange::VariantRemoved { path } => write!(f, "removed variant `{path}`"),
+            CompatChange::VariantKindChanged { path } => {
+                write!(f, "changed the kind of fields of variant `{path}`")
+            }
+        }
+    }
+}
+
+fn is_scalar(type_name: &str) -> bool {
+    matches!(
+        type_name,
+        "usize"
+            | "u8"
+            | "u16"
+            | "u32"
+            | "u64"
+            | "u128"
+            | "i8"
+            | "i16"
+            | "i32"
+            | "i64"
+            | "i128"
+            | "bool"
+            | "char"
+            | "f32"
+            | "f64"
+            | "alloc::string::String"
+    )
+}
+
+/// List the differences between the `old` and `new` versions of a type.
+///
+/// Types are compared by structure, so renaming a type doesn't count as a change, but renaming
+/// one of its fields does. Opaque types are compared by name.
+pub fn check(old: &TypeDescriptor, new: &TypeDescriptor) -> CompatReport {
+    let mut checker = Checker {
+        changes: Vec::new(),
+        ancestors: Vec::new(),
+    };
+    checker.check(old.get_type(), new.get_type(), &mut KeyPath::default());
+    CompatReport {
+        changes: checker.changes,
+    }
+}
+
+struct Checker<'a, 'b> {
+    changes: Vec<CompatChange>,
+    /// The pairs of types being compared, so recursive types don't recurse forever.
+    ancestors: Vec<(Type<'a>, Type<'b>)>,
+}
+
+impl<'a, 'b> Checker<'a, 'b> {
+    fn check(&mut self, old: Type<'a>, new: Type<'b>, path: &mut KeyPath) {
+        if self.ancestors.contains(&(old, new)) {
+            return;
+        }
+        self.ancestors.push((old, new));
+        self.check_type(old, new, path);
+        self.ancestors.pop();
+    }
+
+    fn check_type(&mut self, old: Type<'a>, new: Type<'b>, path: &mut KeyPath) {
+        match (old, new) {
+            (Type::Struct(old), Type::Struct(new)) => {
+                self.named_fields(
+                    &old.field_types().collect::<Vec<_>>(),
+                    &new.field_types().collect::<Vec<_>>(),
+                    path,
+                );
+            }
+            (Type::TupleStruct(old), Type::TupleStruct(new)) => {
+                self.unnamed_fields(
+                    &old.field_types().collect::<Vec<_>>(),
+                    &new.field_types().collect::<Vec<_>>(),
+                    path,
+                );
+            }
+            (Type::Tuple(old), Type::Tuple(new)) => {
+                self.unnamed_fields(
+                    &old.field_types().collect::<Vec<_>>(),
+                    &new.field_types().collect::<Vec<_>>(),
+                    path,
+                );
+            }
+            (Type::Enum(old), Type::Enum(new)) => {
+                for old_variant in old.variants() {
+                    path.push_variant(old_variant.name());
+                    match new.variant(old_variant.name()) {
+                        Some(new_variant) => self.variant(old_variant, new_variant, path),
+                        None => self
+                            .changes
+                            .push(CompatChange::VariantRemoved { path: path.clone() }),
+                    }
+                    path.pop();
+                }
+                for new_variant in new.variants() {
+                    if old.variant(new_variant.name()).is_none() {
+                        self.changes.push(CompatChange::VariantAdded {
+                            path: path.clone().variant(new_variant.name()),
+                        });
+                    }
+                }
+            }
+            (Type::Array(old_array), Type::Array(new_array))
+                if old_array.len() == new_array.len() =>
+            {
+                self.element(old_array.element_type(), new_array.element_type(), path);
+            }
+            (Type::List(old), Type::List(new)) => {
+                self.element(old.element_type(), new.element_type(), path);
+            }
+            (Type::Set(old), Type::Set(new)) => {
+                self.element(old.element_type(), new.element_type(), path);
+            }
+            (Type::Map(old_map), Type::Map(new_map)) => {
+                if old_map.key_type().type_name() != new_map.key_type().type_name() {
+                    self.type_changed(old, new, path);
+                    return;
+                }
+                match new_map.key_type().default_value() {
+                    Some(key) => path.push_get(key),
+                    None => path.push_get(0_usize),
+                }
+                self.check(old_map.value_type(), new_map.value_type(), path);
+                path.pop();
+            }
+            (Type::Scalar(old_scalar), Type::Scalar(new_scalar)) => {
+                if old_scalar != new_scalar {
+                    self.type_changed(old, new, path);
+                }
+            }
+            (Type::Opaque(old_opaque), Type::Opaque(new_opaque)) => {
+                if old_opaque.type_name() != new_opaque.type_name() {
+                    self.type_changed(old, new, path);
+                }
+            }
+            _ => self.type_changed(old, new, path),
+        }
+    }
+
+    fn type_changed(&mut self, old: Type<'a>, new: Type<'b>, path: &KeyPath) {
+        self.changes.push(CompatChange::TypeChanged {
+            path: path.clone(),
+            from: old.type_name().to_owned(),
+            to: new.type_name().to_owned(),
+        });
+    }
+
+    fn element(&mut self, old: Type<'a>, new: Type<'b>, path: &mut KeyPath) {
+        path.push_get(0_usize);
+        self.check(old, new, path);
+        path.pop();
+    }
+
+    fn variant(&mut self, old: Variant<'a>, new: Variant<'b>, path: &mut KeyPath) {
+        match (old, new) {
+            (Variant::Struct(old), Variant::Struct(new)) => {
+                self.named_fields(
+                    &old.field_types().collect::<Vec<_>>(),
+                    &new.field_types().collect::<Vec<_>>(),
+                    path,
+                );
+            }
+            (Variant::Tuple(old), Variant::Tuple(new)) => {
+                self.unnamed_fields(
+                    &old.field_types().collect::<Vec<_>>(),
+                    &new.field_types().collect::<Vec<_>>(),
+                    path,
+                );
+            }
+            (Variant::Unit(_), Variant::Unit(_)) => {}
+            _ => self
+                .changes
+                .push(CompatChange::VariantKindChanged { path: path.clone() }),
+        }
+    }
+
+    fn named_fields(&mut self, old: &[NamedField<'a>], new: &[NamedField<'b>], path: &mut KeyPath) {
+        let renames = renames(old, new);
+        for old_field in old {
+            path.push_field(old_field.name());
+            let renamed_to = renames
+                .iter()
+                .find(|(from, _)| from.name() == old_field.name())
+                .map(|(_, to)| *to);
+            let new_field = new.iter().find(|field| field.name() == old_field.name());
+            match (new_field, renamed_to) {
+                (Some(new_field), _) => {
+                    self.check(old_field.get_type(), new_field.get_type(), path)
+                }
+                (None, Some(to)) => {
+                    self.changes.push(CompatChange::FieldRenamed {
+                        path: path.clone(),
+                        to: to.name().to_owned(),
+                    });
+                    self.check(old_field.get_type(), to.get_type(), path);
+                }
+                (None, None) => self
+                    .changes
+                    .push(CompatChange::FieldRemoved { path: path.clone() }),
+            }
+            path.pop();
+        }
+        for new_field in new {
+            let is_old = old.iter().any(|field| field.name() == new_field.name());
+            let is_renamed = renames.iter().any(|(_, to)| to.name() == new_field.name());
+            if !is_old && !is_renamed {
+                self.changes.push(CompatChange::FieldAdded {
+                    path: path.clone().field(new_field.name()),
+                    has_default: new_field.has_default_value(),
+                });
+            }
+        }
+    }
+
+    fn unnamed_fields(
+        &mut self,
+        old: &[UnnamedField<'a>],
+        new: &[UnnamedField<'b>],
+        path: &mut KeyPath,
+    ) {
+        for (index, old_field) in old.iter().enumerate() {
+            path.push_field(index);
+            match new.get(index) {
+                Some(new_field) => self.check(old_field.get_type(), new_field.get_type(), path),
+                None => self
+                    .changes
+                    .push(CompatChange::FieldRemoved { path: path.clone() }),
+            }
+            path.pop();
+        }
+        for (index, new_field) in new.iter().enumerate().skip(old.len()) {
+            self.changes.push(CompatChange::FieldAdded {
+                path: path.clone().field(index),
+                has_default: new_field.has_default_value(),
+            });
+        }
+    }
+}
+
+/// Pair up fields that were removed from `old` with fields that were added to `new` and have the
+/// same type, first by position and then if they are the only ones with that type.
+fn renames<'a, 'b>(
+    old: &[NamedField<'a>],
+    new: &[NamedField<'b>],
+) -> Vec<(NamedField<'a>, NamedField<'b>)> {
+    let removed = old
+        .iter()
+        .filter(|old_field| new.iter().all(|field| field.name() != old_field.name()))
+        .copied()
+        .collect::<Vec<_>>();
+    let added = new
+        .iter()
+        .filter(|new_field| old.iter().all(|field| field.name() != new_field.name()))
+        .copied()
+        .collect::<Vec<_>>();
+    let type_name = |ty: Type<'_>| ty.type_name().to_owned();
+
+    let mut pairs = Vec::new();
+    for old_field in &removed {
+        let same_position = added.iter().find(|new_field| {
+            new_field.index() == old_field.index()
+                && type_name(new_field.get_type()) == type_name(old_field.get_type())
+        });
+        if let Some(new_field) = same_position {
+            pairs.push((*old_field, *new_field));
+        }
+    }
+
+    for old_field in &removed {
+        if pairs
+            .iter()
+            .any(|(from, _)| from.name() == old_field.name())
+        {
+            continue;
+        }
+        let field_type = type_name(old_field.get_type());
+        let unpaired_removed = removed
+            .iter()
+            .filter(|field| type_name(field.get_type()) == field_type)
+            .filter(|field| pairs.iter().all(|(from, _)| from.name() != field.name()))
+            .count();
+        let mut unpaired_added = added
+            .iter()
+            .filter(|field| type_name(field.get_type()) == field_type)
+            .filter(|field| pairs.iter().all(|(_, to)| to.name() != field.name()));
+        if let (1, Some(new_field), None) = (
+            unpaired_removed,
+            unpaired_added.next(),
+            unpaired_added.next(),
+        ) {
+            pairs.push((*old_field, *new_field));
+        }
+    }
+
+    pairs
+}
+
+/// Convert `value`, of the `old` version of a type, into the `new` version, as well as possible.
+///
+/// The changes [`check`] finds are handled like so:
+///
+/// - Removed fields are dropped, and renamed fields moved.
+/// - Added fields get their default values. Fields without default values are left out.
+/// - Scalars are converted with every [`Coercion`](crate::coerce::Coercion), or replaced by
+///   their default value if that fails.
+/// - Values of removed variants, and of types that changed in other ways, are replaced by the
+///   default value of the new type, or kept as they are if it doesn't have one.
+/// - Arrays that changed length are truncated or padded with default values.
+///
+/// Use [`CompatReport::is_migratable`] to find out whether anything might be lost.
+pub fn migrate(value: Value, old: &TypeDescriptor, new: &TypeDescriptor) -> Value {
+    let coercions = Coercions::all();
+    migrate_value(&value, Some(old.get_type()), new.get_type(), &coercions)
+}
+
+fn migrate_value(
+    value: &dyn Reflect,
+    old: Option<Type<'_>>,
+    new: Type<'_>,
+    coercions: &Coercions,
+) -> Value {
+    let fallback = || new.default_value().unwrap_or_else(|| value.to_value());
+
+    match (new, value.reflect_ref()) {
+        (Type::Struct(new), ReflectRef::Struct(struct_)) => {
+            let old_fields = old
+                .and_then(|old| old.as_struct())
+                .map(|old| old.field_types().collect::<Vec<_>>())
+                .unwrap_or_default();
+            let new_fields = new.field_types().collect::<Vec<_>>();
+            let renames = renames(&old_fields, &new_fields);
+
+            let mut out = StructValue::with_capacity(new.fields_len())
+                .with_type_name(new.type_name().to_owned());
+            for field in new_fields {
+                let old_name = renames
+                    .iter()
+                    .find(|(_, to)| to.name() == field.name())
+                    .map_or(field.name(), |(from, _)| from.name());
+                let old_field = old_fields.iter().find(|field| field.name() == old_name);
+                match struct_.field(old_name) {
+                    Some(value) => out.set_field(
+                        field.name(),
+                        migrate_value(
+                            value,
+                            old_field.map(|field| field.get_type()),
+                            field.get_type(),
+                            coercions,
+                        ),
+                    ),
+                    None => {
+                        if let Some(default) = field.default_value() {
+                            out.set_field(field.name(), default);
+                        }
+                    }
+                }
+            }
+            out.into()
+        }
+        (Type::TupleStruct(new), ReflectRef::TupleStruct(tuple_struct)) => {
+            let old_fields = old
+                .and_then(|old| old.as_tuple_struct())
+                .map(|old| old.field_types().collect::<Vec<_>>())
+                .unwrap_or_default();
+            let mut out = TupleStructValue::with_capacity(new.fields_len())
+                .with_type_name(new.type_name().to_owned());
+            for field in migrate_unnamed(
+                |index| tuple_struct.field_at(index),
+                &old_fields,
+                new.field_types(),
+                coercions,
+            ) {
+                out.push_field(field);
+            }
+            out.into()
+        }
+        (Type::Tuple(new), ReflectRef::Tuple(tuple)) => {
+            let old_fields = old
+                .and_then(|old| old.as_tuple())
+                .map(|old| old.field_types().collect::<Vec<_>>())
+                .unwrap_or_default();
+            let mut out = TupleValue::with_capacity(new.fields_len());
+            for field in migrate_unnamed(
+                |index| tuple.field_at(index),
+                &old_fields,
+                new.field_types(),
+                coercions,
+            ) {
+                out.push_field(field);
+            }
+            out.into()
+        }
+        (Type::Enum(new), ReflectRef::Enum(enum_)) => {
+            let old_variant = old
+                .and_then(|old| old.as_enum())
+                .and_then(|old| old.variant(enum_.variant_name()));
+            match new.variant(enum_.variant_name()) {
+                Some(Variant::Struct(variant)) if enum_.variant_kind() == VariantKind::Struct => {
+                    let mut out = EnumValue::new_struct_variant(variant.name());
+                    for field in variant.field_types() {
+                        let old_field = match old_variant {
+                            Some(Variant::Struct(old)) => old.field_type(field.name()),
+                            _ => None,
+                        };
+                        match enum_.field(field.name()) {
+                            Some(value) => out.set_struct_field(
+                                field.name(),
+                                migrate_value(
+                                    value,
+                                    old_field.map(|field| field.get_type()),
+                                    field.get_type(),
+                                    coercions,
+                                ),
+                            ),
+                            None => {
+                                if let Some(default) = field.default_value() {
+                                    out.set_struct_field(field.name(), default);
+                                }
+                            }
+                        }
+                    }
+                    out.finish().into()
+                }
+                Some(Variant::Tuple(variant)) if enum_.variant_kind() == VariantKind::Tuple => {
+                    let old_fields = match old_variant {
+                        Some(Variant::Tuple(old)) => old.field_types().collect::<Vec<_>>(),
+                        _ => Vec::new(),
+                    };
+                    let mut out = EnumValue::new_tuple_variant(variant.name());
+                    for field in migrate_unnamed(
+                        |index| enum_.field_at(index),
+                        &old_fields,
+                        variant.field_types(),
+                        coercions,
+                    ) {
+                        out.push_tuple_field(field);
+                    }
+                    out.finish().into()
+                }
+                Some(Variant::Unit(variant)) if enum_.variant_kind() == VariantKind::Unit => {
+                    EnumValue::new_unit_variant(variant.name()).into()
+                }
+                _ => fallback(),
+            }
+        }
+        (Type::Array(new), ReflectRef::Array(_) | ReflectRef::List(_)) => {
+            let old_element = old.and_then(element_type);
+            let elements = elements(value);
+            let mut out = elements
+                .iter()
+                .take(new.len())
+                .map(|element| migrate_value(*element, old_element, new.element_type(), coercions))
+                .collect::<Vec<_>>();
+            while out.len() < new.len() {
+                match new.element_type().default_value() {
+                    Some(default) => out.push(default),
+                    None => return fallback(),
+                }
+            }
+            Value::List(out)
+        }
+        (Type::List(new), ReflectRef::Array(_) | ReflectRef::List(_)) => {
+            let old_element = old.and_then(element_type);
+            let out = elements(value)
+                .into_iter()
+                .map(|element| migrate_value(element, old_element, new.element_type(), coercions))
+                .collect();
+            Value::List(out)
+        }
+        (Type::Set(new), ReflectRef::Set(set)) => {
+            let old_element = old.and_then(element_type);
+            let out = set
+                .iter()
+                .map(|element| migrate_value(element, old_element, new.element_type(), coercions))
+                .collect();
+            Value::Set(out)
+        }
+        (Type::Map(new), ReflectRef::Map(map)) => {
+            let old_map = old.and_then(|old| old.as_map());
+            let out = map
+                .iter()
+                .map(|(key, value)| {
+                    (
+                        migrate_value(
+                            key,
+                            old_map.map(|old| old.key_type()),
+                            new.key_type(),
+                            coercions,
+                        ),
+                        migrate_value(
+                            value,
+                            old_map.map(|old| old.value_type()),
+                            new.value_type(),
+                            coercions,
+                        ),
+                    )
+                })
+                .collect();
+            Value::Map(out)
+        }
+        (Type::Scalar(_), ReflectRef::Scalar(_)) | (Type::Opaque(_), _) => {
+            if value.type_name() == new.type_name() || matches!(new, Type::Opaque(_)) {
+                return value.to_value();
+            }
+            coercions.coerce(value, new).unwrap_or_else(fallback)
+        }
+        _ => coercions.coerce(value, new).unwrap_or_else(fallback),
+    }
+}
+
+fn migrate_unnamed<'a, 'b>(
+    field_at: impl Fn(usize) -> Option<&'a dyn Reflect>,
+    old_fields: &[UnnamedField<'_>],
+    new_fields: impl Iterator<Item = UnnamedField<'b>>,
+    coercions: &Coercions,
+) -> Vec<Value> {
+    let mut out = Vec::new();
+    for (index, field) in new_fields.enumerate() {
+        match field_at(index) {
+            Some(value) => out.push(migrate_value(
+                value,
+                old_fields.get(index).map(|field| field.get_type()),
+                field.get_type(),
+                coercions,
+            )),
+            None => match field.default_value() {
+                Some(default) => out.push(default),
+                // later fields can't be added without this one
+                None => break,
+            },
+        }
+    }
+    out
+}
+
+fn element_type(ty: Type<'_>) -> Option<Type<'_>> {
+    match ty {
+        Type::Array(array) => Some(array.element_type()),
+        Type::List(list) => Some(list.element_type()),
+        Type::Set(set) => Some(set.element_type()),
+        _ => None,
+    }
+}
+
+fn elements(value: &dyn Reflect) -> Vec<&dyn Reflect> {
+    match value.reflect_ref() {
+        ReflectRef::Array(array) => array.iter().collect(),
+        ReflectRef::List(list) => list.iter().collect(),
+        _ => Vec::new(),
+    }
+}
@@ -1,18 +1,28 @@
+use alloc::borrow::Cow;
 use alloc::borrow::ToOwned;
 use alloc::boxed::Box;
 use alloc::collections::BTreeMap;
 use alloc::string::String;
+use alloc::sync::Arc;
 use alloc::vec::Vec;
 use core::any::type_name;
 use core::any::TypeId;
+use core::borrow::Borrow;
+use core::fmt;
 use core::hash::BuildHasher;
 use core::ops::Deref;
 
+use super::stable_hash::rehash;
+use super::stable_hash::StableHashes;
 use super::*;
 use crate::Value;
 use crate::STATIC_RANDOM_STATE;
 
-/// A `TypeGraph`'s node that refers to a specific type via its `TypeId'.
+/// A `TypeGraph`'s node that refers to a specific type.
+///
+/// While a graph is being built nodes are identified by their types' `TypeId`s. Once built, the
+/// ids are replaced by stable hashes of the types' names and shapes, which don't change between
+/// compilations.
 #[derive(Clone, Copy, Hash, PartialEq, PartialOrd, Ord, Eq, Debug)]
 #[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -30,6 +40,10 @@ impl NodeId {
         TypeId::of::<T>().hash(&mut hasher);
         Self(hasher.finish())
     }
+
+    pub(crate) fn to_u64(self) -> u64 {
+        self.0
+    }
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
@@ -52,17 +66,218 @@ impl<T> Deref for WithId<T> {
     }
 }
 
+/// A reference counted value in a type graph.
+///
+/// Nodes are shared between all the graphs that contain them, and graphs between all the
+/// descriptors and types that point into them, so copying either is cheap. Encoded the same way
+/// as the value itself.
+#[derive(Default, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct Shared<T>(Arc<T>);
+
+impl<T> Shared<T> {
+    pub(crate) fn new(value: T) -> Self {
+        Self(Arc::new(value))
+    }
+}
+
+impl<T> Shared<T>
+where
+    T: Clone,
+{
+    /// Take the value out, cloning it if it's shared.
+    fn into_inner(self) -> T {
+        Arc::try_unwrap(self.0).unwrap_or_else(|shared| T::clone(&shared))
+    }
+
+    /// Get mutable access to the value, cloning it first if it's shared.
+    fn make_mut(&mut self) -> &mut T {
+        Arc::make_mut(&mut self.0)
+    }
+}
+
+impl<T> Deref for Shared<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> fmt::Debug for Shared<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        T::fmt(self, f)
+    }
+}
+
+#[cfg(feature = "speedy")]
+impl<'a, C, T> speedy::Readable<'a, C> for Shared<T>
+where
+    C: speedy::Context,
+    T: speedy::Readable<'a, C>,
+{
+    fn read_from<R: speedy::Reader<'a, C>>(reader: &mut R) -> Result<Self, C::Error> {
+        reader.read_value().map(Self::new)
+    }
+
+    fn minimum_bytes_needed() -> usize {
+        T::minimum_bytes_needed()
+    }
+}
+
+#[cfg(feature = "speedy")]
+impl<C, T> speedy::Writable<C> for Shared<T>
+where
+    C: speedy::Context,
+    T: speedy::Writable<C>,
+{
+    fn write_to<W: ?Sized + speedy::Writer<C>>(&self, writer: &mut W) -> Result<(), C::Error> {
+        T::write_to(self, writer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T> serde::Serialize for Shared<T>
+where
+    T: serde::Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        T::serialize(self, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T> serde::Deserialize<'de> for Shared<T>
+where
+    T: serde::Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        T::deserialize(deserializer).map(Self::new)
+    }
+}
+
+/// A type, field, or variant name in a type graph.
+///
+/// Names given by `type_name` and the derive macros are `&'static str`s, so they're borrowed
+/// rather than copied into every node. Only names set at runtime, or read from encoded
+/// descriptors, are allocated.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) struct Name(Cow<'static, str>);
+
+impl Deref for Name {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Borrow<str> for Name {
+    fn borrow(&self) -> &str {
+        self
+    }
+}
+
+impl PartialEq<str> for Name {
+    fn eq(&self, other: &str) -> bool {
+        **self == *other
+    }
+}
+
+impl From<&'static str> for Name {
+    fn from(name: &'static str) -> Self {
+        Self(Cow::Borrowed(name))
+    }
+}
+
+impl From<String> for Name {
+    fn from(name: String) -> Self {
+        Self(Cow::Owned(name))
+    }
+}
+
+impl fmt::Debug for Name {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        str::fmt(self, f)
+    }
+}
+
+#[cfg(feature = "speedy")]
+impl<'a, C> speedy::Readable<'a, C> for Name
+where
+    C: speedy::Context,
+{
+    fn read_from<R: speedy::Reader<'a, C>>(reader: &mut R) -> Result<Self, C::Error> {
+        reader.read_value::<String>().map(Self::from)
+    }
+
+    // lets speedy reject lengths of lists of names that can't fit in the input, before
+    // allocating them
+    fn minimum_bytes_needed() -> usize {
+        <String as speedy::Readable<'a, C>>::minimum_bytes_needed()
+    }
+}
+
+#[cfg(feature = "speedy")]
+impl<C> speedy::Writable<C> for Name
+where
+    C: speedy::Context,
+{
+    fn write_to<W: ?Sized + speedy::Writer<C>>(&self, writer: &mut W) -> Result<(), C::Error> {
+        str::write_to(self, writer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Name {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Name {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(Self::from)
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TypeGraph {
-    pub(super) map: BTreeMap<NodeId, Option<TypeNode>>,
+    pub(super) map: BTreeMap<NodeId, Option<Shared<TypeNode>>>,
 }
 
 impl TypeGraph {
     pub(super) fn get(&self, id: NodeId) -> &TypeNode {
-        const ERROR: &str = "no node found in graph. This is a bug. Please open an issue.";
-        self.map.get(&id).expect(ERROR).as_ref().expect(ERROR)
+        self.try_get(id)
+            .expect("no node found in graph. This is a bug. Please open an issue.")
+    }
+
+    /// The node with the given id, or `None` if the graph doesn't contain it.
+    ///
+    /// Graphs built from types always contain every node they reference, as do those read with
+    /// `speedy` or `serde` since they're validated, so this is for checking graphs built by hand.
+    pub fn try_get(&self, id: NodeId) -> Option<&TypeNode> {
+        match self.map.get(&id) {
+            Some(Some(node)) => Some(node),
+            _ => None,
+        }
     }
 
     pub fn get_or_build_node_with<T, I>(&mut self, f: impl FnOnce(&mut Self) -> I) -> NodeId
@@ -78,13 +293,101 @@ impl TypeGraph {
             Some(None) => id,
             // the data isn't there yet
             None => {
+                // reuse the subgraph if the type descriptor has already been built
+                // cached descriptors have stable ids so the returned id is the root's, rather
+                // than the one derived from the `TypeId`
+                #[cfg(feature = "std")]
+                if let Some(info) = super::cache::get(TypeId::of::<T>()) {
+                    for (id, node) in &info.graph.map {
+                        self.map.entry(*id).or_insert_with(|| node.clone());
+                    }
+                    return info.root;
+                }
+
                 self.map.insert(id, None);
                 let info = f(self).into();
-                self.map.insert(id, Some(info));
+                self.map.insert(id, Some(Shared::new(info)));
                 id
             }
         }
     }
+
+    /// Replace the ids of all nodes with stable hashes of the types they describe.
+    ///
+    /// Nodes that end up with the same id, such as a type reached both through a cached subgraph
+    /// and by building it, describe the same type so only one of them is kept. Nodes whose hashes
+    /// collide but that differ otherwise, for example in their docs, are given distinct ids.
+    ///
+    /// Nodes that already have stable ids, such as those from cached subgraphs, are left as is so
+    /// they stay shared with the graphs they came from.
+    pub(super) fn with_stable_ids(graph: Shared<Self>, root: NodeId) -> (NodeId, Shared<Self>) {
+        let mut stable_hashes = StableHashes::default();
+        let hashes = graph
+            .map
+            .keys()
+            .map(|&id| (id, NodeId(stable_hashes.hash(Type::new(id, &graph)))))
+            .collect::<BTreeMap<_, _>>();
+
+        // the node as it would be merged, with the ids it refers to replaced by their hashes
+        let merged_node = |id: NodeId| {
+            graph.map[&id].as_deref().map(|node| {
+                let mut node = node.clone();
+                node.map_ids(|id| hashes[&id]);
+                node
+            })
+        };
+
+        let mut ids = BTreeMap::new();
+        let mut owners = BTreeMap::<NodeId, NodeId>::new();
+        for (&id, &hash) in &hashes {
+            let mut stable_id = hash;
+            while let Some(&owner) = owners.get(&stable_id) {
+                if merged_node(owner) == merged_node(id) {
+                    break;
+                }
+                stable_id = NodeId(rehash(stable_id.0));
+            }
+            owners.entry(stable_id).or_insert(id);
+            ids.insert(id, stable_id);
+        }
+        let stable_id = |id: NodeId| ids[&id];
+
+        let map = graph
+            .into_inner()
+            .map
+            .into_iter()
+            .map(|(id, node)| {
+                let node = node.map(|mut node| {
+                    // nodes that already have a stable id come from cached descriptors, whose
+                    // graphs only contain stable ids
+                    if stable_id(id) != id {
+                        node.make_mut().map_ids(stable_id);
+                    }
+                    node
+                });
+                (stable_id(id), node)
+            })
+            .collect();
+
+        (stable_id(root), Shared::new(Self { map }))
+    }
+
+    /// Returns `true` if every node referenced from the graph, including `root`, exists, and the
+    /// field names of structs match their fields.
+    ///
+    /// Graphs built from types always are, but ones read from untrusted data might not be and
+    /// would panic when used.
+    #[cfg(any(feature = "speedy", feature = "serde"))]
+    pub(super) fn is_valid(&self, root: NodeId) -> bool {
+        let exists = |id: NodeId| self.try_get(id).is_some();
+
+        exists(root)
+            && self.map.values().flatten().all(|node| {
+                let mut valid = node.has_valid_field_names();
+                node.for_each_id(|id| valid &= exists(id));
+                valid
+            })
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -100,6 +403,108 @@ pub enum TypeNode {
     Map(MapNode),
     Scalar(ScalarNode),
     Opaque(OpaqueNode),
+    Set(SetNode),
+}
+
+impl TypeNode {
+    #[cfg(any(feature = "speedy", feature = "serde"))]
+    fn for_each_id(&self, mut f: impl FnMut(NodeId)) {
+        fn named(fields: &BTreeMap<Name, NamedFieldNode>, f: &mut impl FnMut(NodeId)) {
+            for field in fields.values() {
+                f(field.id);
+            }
+        }
+
+        fn unnamed(fields: &[UnnamedFieldNode], f: &mut impl FnMut(NodeId)) {
+            for field in fields {
+                f(field.id);
+            }
+        }
+
+        match self {
+            TypeNode::Struct(node) => named(&node.fields, &mut f),
+            TypeNode::TupleStruct(node) => unnamed(&node.fields, &mut f),
+            TypeNode::Tuple(node) => unnamed(&node.fields, &mut f),
+            TypeNode::Enum(node) => {
+                for variant in &node.variants {
+                    match variant {
+                        VariantNode::Struct(variant) => named(&variant.fields, &mut f),
+                        VariantNode::Tuple(variant) => unnamed(&variant.fields, &mut f),
+                        VariantNode::Unit(_) => {}
+                    }
+                }
+            }
+            TypeNode::List(node) => f(node.field_type_id),
+            TypeNode::Array(node) => f(node.field_type_id),
+            TypeNode::Map(node) => {
+                f(node.key_type_id);
+                f(node.value_type_id);
+            }
+            TypeNode::Set(node) => f(node.element_type_id),
+            TypeNode::Scalar(_) | TypeNode::Opaque(_) => {}
+        }
+    }
+
+    #[cfg(any(feature = "speedy", feature = "serde"))]
+    fn has_valid_field_names(&self) -> bool {
+        fn matches(fields: &BTreeMap<Name, NamedFieldNode>, field_names: &[Name]) -> bool {
+            fields.len() == field_names.len()
+                && field_names.iter().all(|name| fields.contains_key(&**name))
+        }
+
+        match self {
+            TypeNode::Struct(node) => matches(&node.fields, &node.field_names),
+            TypeNode::Enum(node) => node.variants.iter().all(|variant| match variant {
+                VariantNode::Struct(variant) => matches(&variant.fields, &variant.field_names),
+                VariantNode::Tuple(_) | VariantNode::Unit(_) => true,
+            }),
+            TypeNode::TupleStruct(_)
+            | TypeNode::Tuple(_)
+            | TypeNode::List(_)
+            | TypeNode::Array(_)
+            | TypeNode::Map(_)
+            | TypeNode::Set(_)
+            | TypeNode::Scalar(_)
+            | TypeNode::Opaque(_) => true,
+        }
+    }
+
+    fn map_ids(&mut self, f: impl Fn(NodeId) -> NodeId) {
+        fn map_named(fields: &mut BTreeMap<Name, NamedFieldNode>, f: &impl Fn(NodeId) -> NodeId) {
+            for field in fields.values_mut() {
+                field.id = f(field.id);
+            }
+        }
+
+        fn map_unnamed(fields: &mut [UnnamedFieldNode], f: &impl Fn(NodeId) -> NodeId) {
+            for field in fields {
+                field.id = f(field.id);
+            }
+        }
+
+        match self {
+            TypeNode::Struct(node) => map_named(&mut node.fields, &f),
+            TypeNode::TupleStruct(node) => map_unnamed(&mut node.fields, &f),
+            TypeNode::Tuple(node) => map_unnamed(&mut node.fields, &f),
+            TypeNode::Enum(node) => {
+                for variant in &mut node.variants {
+                    match variant {
+                        VariantNode::Struct(variant) => map_named(&mut variant.fields, &f),
+                        VariantNode::Tuple(variant) => map_unnamed(&mut variant.fields, &f),
+                        VariantNode::Unit(_) => {}
+                    }
+                }
+            }
+            TypeNode::List(node) => node.field_type_id = f(node.field_type_id),
+            TypeNode::Array(node) => node.field_type_id = f(node.field_type_id),
+            TypeNode::Map(node) => {
+                node.key_type_id = f(node.key_type_id);
+                node.value_type_id = f(node.value_type_id);
+            }
+            TypeNode::Set(node) => node.element_type_id = f(node.element_type_id),
+            TypeNode::Scalar(_) | TypeNode::Opaque(_) => {}
+        }
+    }
 }
 
 macro_rules! impl_from {
@@ -121,14 +526,15 @@ impl_from! { Array(ArrayNode) }
 impl_from! { Map(MapNode) }
 impl_from! { Scalar(ScalarNode) }
 impl_from! { Opaque(OpaqueNode) }
+impl_from! { Set(SetNode) }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StructNode {
-    pub(super) type_name: String,
-    pub(super) fields: BTreeMap<String, NamedFieldNode>,
-    pub(super) field_names: Box<[String]>,
+    pub(super) type_name: Name,
+    pub(super) fields: BTreeMap<Name, NamedFieldNode>,
+    pub(super) field_names: Box<[Name]>,
     pub(super) metadata: BTreeMap<String, Value>,
     pub(super) docs: Box<[String]>,
 }
@@ -143,7 +549,7 @@ impl StructNode {
         T: DescribeType,
     {
         Self {
-            type_name: type_name::<T>().to_owned(),
+            type_name: type_name::<T>().into(),
             fields: fields
                 .iter()
                 .map(|field| (field.name.clone(), field.clone()))
@@ -153,6 +559,12 @@ impl StructNode {
             docs: map_docs(docs),
         }
     }
+
+    /// Override the type name, as done by `#[reflect(rename = "...")]`.
+    pub fn with_type_name(mut self, type_name: &str) -> Self {
+        self.type_name = String::from(type_name).into();
+        self
+    }
 }
 
 fn map_metadata(metadata: BTreeMap<&'static str, Value>) -> BTreeMap<String, Value> {
@@ -170,7 +582,7 @@ fn map_docs(docs: &[&'static str]) -> Box<[String]> {
 #[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TupleStructNode {
-    pub(super) type_name: String,
+    pub(super) type_name: Name,
     pub(super) fields: Vec<UnnamedFieldNode>,
     pub(super) metadata: BTreeMap<String, Value>,
     pub(super) docs: Box<[String]>,
@@ -186,22 +598,30 @@ impl TupleStructNode {
         T: DescribeType,
     {
         Self {
-            type_name: type_name::<T>().to_owned(),
+            type_name: type_name::<T>().into(),
             fields: fields.to_vec(),
             metadata: map_metadata(metadata),
             docs: map_docs(docs),
         }
     }
+
+    /// Override the type name, as done by `#[reflect(rename = "...")]`.
+    pub fn with_type_name(mut self, type_name: &str) -> Self {
+        self.type_name = String::from(type_name).into();
+        self
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EnumNode {
-    pub(super) type_name: String,
+    pub(super) type_name: Name,
     pub(super) variants: Vec<VariantNode>,
     pub(super) metadata: BTreeMap<String, Value>,
     pub(super) docs: Box<[String]>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(super) repr: Option<String>,
 }
 
 impl EnumNode {
@@ -214,12 +634,25 @@ impl EnumNode {
         T: DescribeType,
     {
         Self {
-            type_name: type_name::<T>().to_owned(),
+            type_name: type_name::<T>().into(),
             variants: variants.to_vec(),
             metadata: map_metadata(metadata),
             docs: map_docs(docs),
+            repr: None,
         }
     }
+
+    /// Override the type name, as done by `#[reflect(rename = "...")]`.
+    pub fn with_type_name(mut self, type_name: &str) -> Self {
+        self.type_name = String::from(type_name).into();
+        self
+    }
+
+    /// Set the integer type from the enum's `#[repr(...)]`.
+    pub fn with_repr(mut self, repr: &str) -> Self {
+        self.repr = Some(repr.to_owned());
+        self
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -235,11 +668,13 @@ pub enum VariantNode {
 #[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StructVariantNode {
-    pub(super) name: String,
-    pub(super) fields: BTreeMap<String, NamedFieldNode>,
-    pub(super) field_names: Box<[String]>,
+    pub(super) name: Name,
+    pub(super) fields: BTreeMap<Name, NamedFieldNode>,
+    pub(super) field_names: Box<[Name]>,
     pub(super) metadata: BTreeMap<String, Value>,
     pub(super) docs: Box<[String]>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(super) discriminant: Option<i128>,
 }
 
 impl StructVariantNode {
@@ -250,7 +685,7 @@ impl StructVariantNode {
         docs: &[&'static str],
     ) -> Self {
         Self {
-            name: name.to_owned(),
+            name: name.into(),
             fields: fields
                 .iter()
                 .map(|field| (field.name.clone(), field.clone()))
@@ -258,18 +693,27 @@ impl StructVariantNode {
             field_names: fields.iter().map(|field| field.name.clone()).collect(),
             metadata: map_metadata(metadata),
             docs: map_docs(docs),
+            discriminant: None,
         }
     }
+
+    /// Set the discriminant of the variant, for enums with an integer `#[repr(...)]`.
+    pub fn with_discriminant(mut self, discriminant: i128) -> Self {
+        self.discriminant = Some(discriminant);
+        self
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TupleVariantNode {
-    pub(super) name: String,
+    pub(super) name: Name,
     pub(super) fields: Vec<UnnamedFieldNode>,
     pub(super) metadata: BTreeMap<String, Value>,
     pub(super) docs: Box<[String]>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(super) discriminant: Option<i128>,
 }
 
 impl TupleVariantNode {
@@ -280,21 +724,30 @@ impl TupleVariantNode {
         docs: &[&'static str],
     ) -> Self {
         Self {
-            name: name.to_owned(),
+            name: name.into(),
             fields: fields.to_vec(),
             metadata: map_metadata(metadata),
             docs: map_docs(docs),
+            discriminant: None,
         }
     }
+
+    /// Set the discriminant of the variant, for enums with an integer `#[repr(...)]`.
+    pub fn with_discriminant(mut self, discriminant: i128) -> Self {
+        self.discriminant = Some(discriminant);
+        self
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UnitVariantNode {
-    pub(super) name: String,
+    pub(super) name: Name,
     pub(super) metadata: BTreeMap<String, Value>,
     pub(super) docs: Box<[String]>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(super) discriminant: Option<i128>,
 }
 
 impl UnitVariantNode {
@@ -304,18 +757,25 @@ impl UnitVariantNode {
         docs: &[&'static str],
     ) -> Self {
         Self {
-            name: name.to_owned(),
+            name: name.into(),
             metadata: map_metadata(metadata),
             docs: map_docs(docs),
+            discriminant: None,
         }
     }
+
+    /// Set the discriminant of the variant, for enums with an integer `#[repr(...)]`.
+    pub fn with_discriminant(mut self, discriminant: i128) -> Self {
+        self.discriminant = Some(discriminant);
+        self
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TupleNode {
-    pub(super) type_name: String,
+    pub(super) type_name: Name,
     pub(super) fields: Vec<UnnamedFieldNode>,
     pub(super) metadata: BTreeMap<String, Value>,
     pub(super) docs: Box<[String]>,
@@ -331,7 +791,7 @@ impl TupleNode {
         T: DescribeType,
     {
         Self {
-            type_name: type_name::<T>().to_owned(),
+            type_name: type_name::<T>().into(),
             fields: fields.to_vec(),
             metadata: map_metadata(metadata),
             docs: map_docs(docs),
@@ -343,7 +803,7 @@ impl TupleNode {
 #[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NamedFieldNode {
-    pub(super) name: String,
+    pub(super) name: Name,
     pub(super) id: NodeId,
     pub(super) metadata: BTreeMap<String, Value>,
     pub(super) docs: Box<[String]>,
@@ -360,12 +820,35 @@ impl NamedFieldNode {
         T: DescribeType,
     {
         Self {
-            name: name.to_owned(),
+            name: name.into(),
             id: T::build(graph),
             metadata: map_metadata(metadata),
             docs: map_docs(docs),
         }
     }
+
+    /// The fields of the struct `T`, for embedding them in another struct as done by
+    /// `#[reflect(flatten)]`.
+    ///
+    /// Returns no fields if `T` isn't a struct, or is still being built because it contains
+    /// itself.
+    pub fn flattened<T>(graph: &mut TypeGraph) -> Vec<Self>
+    where
+        T: DescribeType,
+    {
+        let id = T::build(graph);
+        match graph.map.get(&id) {
+            Some(Some(node)) => match &**node {
+                TypeNode::Struct(node) => node
+                    .field_names
+                    .iter()
+                    .map(|name| node.fields[name].clone())
+                    .collect(),
+                _ => Vec::new(),
+            },
+            _ => Vec::new(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -398,7 +881,7 @@ impl UnnamedFieldNode {
 #[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ArrayNode {
-    pub(super) type_name: String,
+    pub(super) type_name: Name,
     pub(super) field_type_id: NodeId,
     pub(super) len: usize,
 }
@@ -410,7 +893,7 @@ impl ArrayNode {
         T: DescribeType,
     {
         Self {
-            type_name: type_name::<L>().to_owned(),
+            type_name: type_name::<L>().into(),
             field_type_id: T::build(graph),
             len: N,
         }
@@ -421,7 +904,7 @@ impl ArrayNode {
 #[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ListNode {
-    pub(super) type_name: String,
+    pub(super) type_name: Name,
     pub(super) field_type_id: NodeId,
 }
 
@@ -432,7 +915,7 @@ impl ListNode {
         T: DescribeType,
     {
         Self {
-            type_name: type_name::<L>().to_owned(),
+            type_name: type_name::<L>().into(),
             field_type_id: T::build(graph),
         }
     }
@@ -442,7 +925,7 @@ impl ListNode {
 #[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MapNode {
-    pub(super) type_name: String,
+    pub(super) type_name: Name,
     pub(super) key_type_id: NodeId,
     pub(super) value_type_id: NodeId,
 }
@@ -455,13 +938,34 @@ impl MapNode {
         V: DescribeType,
     {
         Self {
-            type_name: type_name::<M>().to_owned(),
+            type_name: type_name::<M>().into(),
             key_type_id: K::build(graph),
             value_type_id: V::build(graph),
         }
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetNode {
+    pub(super) type_name: Name,
+    pub(super) element_type_id: NodeId,
+}
+
+impl SetNode {
+    pub(crate) fn new<S, T>(graph: &mut TypeGraph) -> Self
+    where
+        S: DescribeType,
+        T: DescribeType,
+    {
+        Self {
+            type_name: type_name::<S>().into(),
+            element_type_id: T::build(graph),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
 #[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
@@ -508,7 +1012,7 @@ scalar_typed! {
 #[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OpaqueNode {
-    pub(super) type_name: String,
+    pub(super) type_name: Name,
     pub(super) metadata: BTreeMap<String, Value>,
     pub(super) default_value: Option<Value>,
 }
@@ -519,7 +1023,7 @@ impl OpaqueNode {
         T: DescribeType,
     {
         Self {
-            type_name: type_name::<T>().to_owned(),
+            type_name: type_name::<T>().into(),
             metadata: map_metadata(metadata),
             default_value: None,
         }
@@ -3,8 +3,11 @@ use core::iter::Peekable;
 
 use alloc::borrow::Cow;
 use alloc::boxed::Box;
+use alloc::collections::btree_map;
 use alloc::collections::BTreeMap;
+use alloc::collections::BTreeSet;
 use alloc::string::String;
+use alloc::vec;
 use alloc::vec::Vec;
 
 use graph::*;
@@ -15,6 +18,7 @@ use crate::key_path::GetTypePath;
 use crate::key_path::Key;
 use crate::key_path::KeyPath;
 use crate::key_path::NamedOrNumbered;
+use crate::meta::MetaKey;
 use crate::struct_::StructValue;
 use crate::tuple::TupleValue;
 use crate::tuple_struct::TupleStructValue;
@@ -22,15 +26,26 @@ use crate::FromReflect;
 use crate::Reflect;
 use crate::Value;
 
+pub mod compat;
 pub mod graph;
 pub mod pretty_print;
 
+mod parse;
+pub(crate) mod stable_hash;
+mod stats;
+pub(crate) mod value_builder;
+mod variant_builder;
+
 #[cfg(feature = "std")]
 mod simple_type_name;
 
+pub use self::parse::ParseScalarError;
 pub use self::pretty_print::{PrettyPrintRoot, RootPrettyPrinter};
 #[cfg(feature = "std")]
 pub use self::simple_type_name::SimpleTypeName;
+pub use self::stats::DescriptorStats;
+pub use self::value_builder::{ShapeError, ValueBuilder};
+pub use self::variant_builder::{VariantBuilder, VariantBuilderError};
 
 /// Trait for accessing type information.
 ///
@@ -45,34 +60,19 @@ pub trait DescribeType: 'static {
         #[cfg(feature = "std")]
         {
             use crate::__private::*;
-            use std::collections::HashMap;
-            use std::sync::RwLock;
-
-            // a map required for generic types to have different type descriptors such as
-            // `Vec<i32>` and `Vec<bool>`
-            static INFO: OnceBox<
-                RwLock<HashMap<TypeId, &'static TypeDescriptor, ahash::RandomState>>,
-            > = OnceBox::new();
 
             let type_id = TypeId::of::<Self>();
 
-            let lock = INFO.get_or_init(|| {
-                Box::from(RwLock::new(HashMap::with_hasher(
-                    STATIC_RANDOM_STATE.clone(),
-                ))) // use seeded random state
-            });
-            if let Some(info) = lock.read().unwrap().get(&type_id) {
+            if let Some(info) = cache::get(type_id) {
                 return Cow::Borrowed(info);
             }
 
-            let mut map = lock.write().unwrap();
-            let info = map.entry(type_id).or_insert_with(|| {
-                let mut graph = TypeGraph::default();
-                let id = Self::build(&mut graph);
-                let info = TypeDescriptor::new(id, graph);
-                Box::leak(Box::new(info))
-            });
-            Cow::Borrowed(*info)
+            // build without holding the lock since `build` will look up the descriptors of
+            // nested types that have already been built
+            let mut graph = TypeGraph::default();
+            let id = Self::build(&mut graph);
+            let info = TypeDescriptor::new(id, graph);
+            Cow::Borrowed(cache::insert(type_id, info))
         }
 
         #[cfg(not(feature = "std"))]
@@ -90,22 +90,106 @@ pub trait DescribeType: 'static {
     fn build(graph: &mut TypeGraph) -> NodeId;
 }
 
+/// Process wide cache of the type descriptors built by [`DescribeType::type_descriptor`].
+///
+/// Also used by [`TypeGraph`] to reuse the already built subgraphs of nested types rather than
+/// building them again for every type that contains them.
+#[cfg(feature = "std")]
+pub(crate) mod cache {
+    use core::any::TypeId;
+    use std::collections::HashMap;
+    use std::sync::RwLock;
+
+    use alloc::boxed::Box;
+    use once_cell::race::OnceBox;
+
+    use super::TypeDescriptor;
+    use crate::STATIC_RANDOM_STATE;
+
+    // a map required for generic types to have different type descriptors such as
+    // `Vec<i32>` and `Vec<bool>`
+    static INFO: OnceBox<RwLock<HashMap<TypeId, &'static TypeDescriptor, ahash::RandomState>>> =
+        OnceBox::new();
+
+    fn map() -> &'static RwLock<HashMap<TypeId, &'static TypeDescriptor, ahash::RandomState>> {
+        INFO.get_or_init(|| {
+            Box::from(RwLock::new(HashMap::with_hasher(
+                STATIC_RANDOM_STATE.clone(),
+            ))) // use seeded random state
+        })
+    }
+
+    pub(crate) fn get(type_id: TypeId) -> Option<&'static TypeDescriptor> {
+        map().read().unwrap().get(&type_id).copied()
+    }
+
+    /// Insert a descriptor, unless another thread got there first in which case theirs is
+    /// returned.
+    pub(crate) fn insert(type_id: TypeId, info: TypeDescriptor) -> &'static TypeDescriptor {
+        map()
+            .write()
+            .unwrap()
+            .entry(type_id)
+            .or_insert_with(|| Box::leak(Box::new(info)))
+    }
+}
+
 /// The root of a type.
 ///
 /// Accessed via the [`DescribeType`] trait.
 ///
 /// `mirror-mirror` represents types as (possibly cyclic) graphs since types can contain
 /// themselves. For example `struct Foo(Vec<Foo>)`.
+///
+/// Types in the graph are identified by a hash of their names and shapes, rather than their
+/// [`TypeId`]s, so descriptors serialized by one build can be consumed by another, such as an
+/// editor built separately from the game it's inspecting.
+///
+/// The graph and its nodes are reference counted and shared with the descriptors of the types
+/// they contain, so cloning a descriptor, or getting one from a [`Type`] with
+/// `into_type_descriptor`, doesn't copy it.
+///
+/// [`TypeId`]: core::any::TypeId
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "UncheckedTypeDescriptor"))]
 pub struct TypeDescriptor {
     root: NodeId,
-    graph: TypeGraph,
+    graph: Shared<TypeGraph>,
+}
+
+/// A deserialized [`TypeDescriptor`] whose graph hasn't been validated yet.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct UncheckedTypeDescriptor {
+    root: NodeId,
+    graph: Shared<TypeGraph>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<UncheckedTypeDescriptor> for TypeDescriptor {
+    type Error = &'static str;
+
+    fn try_from(unchecked: UncheckedTypeDescriptor) -> Result<Self, Self::Error> {
+        let UncheckedTypeDescriptor { root, graph } = unchecked;
+        if graph.is_valid(root) {
+            Ok(Self { root, graph })
+        } else {
+            Err("invalid type descriptor graph")
+        }
+    }
 }
 
 impl TypeDescriptor {
+    /// The version of the format descriptors are encoded in with `speedy`.
+    ///
+    /// Written before the descriptor and checked when reading, so descriptors from incompatible
+    /// builds are rejected rather than misinterpreted. Bumped whenever the encoding of the type
+    /// graph, or how nodes are identified in it, changes.
+    pub const FORMAT_VERSION: u32 = 1;
+
     fn new(root: NodeId, graph: TypeGraph) -> Self {
+        let (root, graph) = TypeGraph::with_stable_ids(Shared::new(graph), root);
         Self { root, graph }
     }
 
@@ -117,6 +201,67 @@ impl TypeDescriptor {
         self.get_type().type_name()
     }
 
+    /// The stable hash of the root type, which is also its id in the graph.
+    pub(crate) fn type_hash(&self) -> u64 {
+        self.root.to_u64()
+    }
+
+    /// Get a deserializer for just the part of the type at `key_path`.
+    ///
+    /// This allows deserializing a fragment of a document, such as a single nested object,
+    /// without the rest of the document. Returns `None` if the path doesn't exist in the type or
+    /// points to an enum variant rather than a type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mirror_mirror::{key_path, DescribeType, FromReflect, Reflect};
+    /// use serde::de::DeserializeSeed;
+    ///
+    /// #[derive(Reflect, Clone, Debug)]
+    /// struct Config {
+    ///     settings: Settings,
+    /// }
+    ///
+    /// #[derive(Reflect, Clone, Debug)]
+    /// struct Settings {
+    ///     audio: Audio,
+    /// }
+    ///
+    /// #[derive(Reflect, Clone, Debug, PartialEq)]
+    /// struct Audio {
+    ///     volume: f32,
+    /// }
+    ///
+    /// let descriptor = <Config as DescribeType>::type_descriptor();
+    ///
+    /// let value = descriptor
+    ///     .at(&key_path!(.settings.audio))
+    ///     .unwrap()
+    ///     .deserialize(&mut serde_json::Deserializer::from_str(r#"{ "volume": 0.5 }"#))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(Audio::from_reflect(&value).unwrap(), Audio { volume: 0.5 });
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn at(&self, key_path: &KeyPath) -> Option<crate::serde::TypedDeserializer<'_>> {
+        let ty = self.type_at(key_path)?.as_type()?;
+        Some(crate::serde::TypedDeserializer::with_type(ty))
+    }
+
+    /// Start building a value of the type, checking fields as they're set.
+    ///
+    /// Returns `None` unless the type is a struct, tuple struct, tuple, or enum. See
+    /// [`ValueBuilder`].
+    pub fn value_builder(&self) -> Option<ValueBuilder<'_>> {
+        self.get_type().value_builder()
+    }
+
+    /// Get statistics about how much memory the type information takes up.
+    pub fn stats(&self) -> DescriptorStats {
+        DescriptorStats::new(self)
+    }
+
     pub fn default_value(&self) -> Option<Value> {
         self.get_type().default_value()
     }
@@ -153,6 +298,10 @@ impl TypeDescriptor {
         self.get_type().as_map()
     }
 
+    pub fn as_set(&self) -> Option<SetType<'_>> {
+        self.get_type().as_set()
+    }
+
     pub fn as_scalar(&self) -> Option<ScalarType> {
         self.get_type().as_scalar()
     }
@@ -162,6 +311,46 @@ impl TypeDescriptor {
     }
 }
 
+#[cfg(feature = "speedy")]
+impl<'a, C> speedy::Readable<'a, C> for TypeDescriptor
+where
+    C: speedy::Context,
+{
+    fn read_from<R: speedy::Reader<'a, C>>(reader: &mut R) -> Result<Self, C::Error> {
+        let version = reader.read_u32()?;
+        if version != Self::FORMAT_VERSION {
+            return Err(speedy::Error::custom(format_args!(
+                "unsupported type descriptor format version {version}, expected {}",
+                Self::FORMAT_VERSION
+            ))
+            .into());
+        }
+        let root = reader.read_value()?;
+        let graph: Shared<TypeGraph> = reader.read_value()?;
+        if !graph.is_valid(root) {
+            return Err(speedy::Error::custom("invalid type descriptor graph").into());
+        }
+        Ok(Self { root, graph })
+    }
+
+    fn minimum_bytes_needed() -> usize {
+        4 + <NodeId as speedy::Readable<'a, C>>::minimum_bytes_needed()
+            + <TypeGraph as speedy::Readable<'a, C>>::minimum_bytes_needed()
+    }
+}
+
+#[cfg(feature = "speedy")]
+impl<C> speedy::Writable<C> for TypeDescriptor
+where
+    C: speedy::Context,
+{
+    fn write_to<T: ?Sized + speedy::Writer<C>>(&self, writer: &mut T) -> Result<(), C::Error> {
+        writer.write_u32(Self::FORMAT_VERSION)?;
+        writer.write_value(&self.root)?;
+        writer.write_value(&self.graph)
+    }
+}
+
 impl<'a> GetTypePath<'a> for &'a TypeDescriptor {
     fn type_at(self, key_path: &KeyPath) -> Option<TypeAtPath<'a>> {
         self.get_type().type_at(key_path)
@@ -173,6 +362,10 @@ impl<'a> GetMeta<'a> for &'a TypeDescriptor {
         self.get_type().meta(key)
     }
 
+    fn meta_iter(self) -> MetaIter<'a> {
+        self.get_type().meta_iter()
+    }
+
     fn docs(self) -> &'a [String] {
         self.get_type().docs()
     }
@@ -187,12 +380,13 @@ pub enum Type<'a> {
     List(ListType<'a>),
     Array(ArrayType<'a>),
     Map(MapType<'a>),
+    Set(SetType<'a>),
     Scalar(ScalarType),
     Opaque(OpaqueType<'a>),
 }
 
 impl<'a> Type<'a> {
-    fn new(id: NodeId, graph: &'a TypeGraph) -> Self {
+    fn new(id: NodeId, graph: &'a Shared<TypeGraph>) -> Self {
         match graph.get(id) {
             TypeNode::Struct(node) => {
                 let node = StructType {
@@ -243,6 +437,13 @@ impl<'a> Type<'a> {
                 };
                 Type::Map(node)
             }
+            TypeNode::Set(node) => {
+                let node = SetType {
+                    node: WithId::new(id, node),
+                    graph,
+                };
+                Type::Set(node)
+            }
             TypeNode::Scalar(scalar) => {
                 let node = match scalar {
                     ScalarNode::usize => ScalarType::usize,
@@ -283,11 +484,29 @@ impl<'a> Type<'a> {
             Type::List(inner) => inner.type_name(),
             Type::Array(inner) => inner.type_name(),
             Type::Map(inner) => inner.type_name(),
+            Type::Set(inner) => inner.type_name(),
             Type::Scalar(inner) => inner.type_name(),
             Type::Opaque(inner) => inner.type_name(),
         }
     }
 
+    /// The id of the type's node in its graph, or `None` for scalars whose `Type`s don't keep
+    /// track of their nodes.
+    pub(super) fn node_id(self) -> Option<NodeId> {
+        match self {
+            Type::Struct(inner) => Some(inner.node.id),
+            Type::TupleStruct(inner) => Some(inner.node.id),
+            Type::Tuple(inner) => Some(inner.node.id),
+            Type::Enum(inner) => Some(inner.node.id),
+            Type::List(inner) => Some(inner.node.id),
+            Type::Array(inner) => Some(inner.node.id),
+            Type::Map(inner) => Some(inner.node.id),
+            Type::Set(inner) => Some(inner.node.id),
+            Type::Scalar(_) => None,
+            Type::Opaque(inner) => Some(inner.node.id),
+        }
+    }
+
     fn into_type_info_at_path(self) -> TypeAtPath<'a> {
         match self {
             Type::Struct(inner) => inner.into_type_info_at_path(),
@@ -297,6 +516,7 @@ impl<'a> Type<'a> {
             Type::List(inner) => inner.into_type_info_at_path(),
             Type::Array(inner) => inner.into_type_info_at_path(),
             Type::Map(inner) => inner.into_type_info_at_path(),
+            Type::Set(inner) => inner.into_type_info_at_path(),
             Type::Scalar(inner) => inner.into_type_info_at_path(),
             Type::Opaque(inner) => inner.into_type_info_at_path(),
         }
@@ -311,11 +531,20 @@ impl<'a> Type<'a> {
             Type::List(inner) => Some(inner.default_value()),
             Type::Array(inner) => inner.default_value(),
             Type::Map(inner) => Some(inner.default_value()),
+            Type::Set(inner) => Some(inner.default_value()),
             Type::Scalar(inner) => Some(inner.default_value()),
             Type::Opaque(inner) => inner.default_value(),
         }
     }
 
+    /// Start building a value of the type, checking fields as they're set.
+    ///
+    /// Returns `None` unless the type is a struct, tuple struct, tuple, or enum. See
+    /// [`ValueBuilder`].
+    pub fn value_builder(self) -> Option<ValueBuilder<'a>> {
+        ValueBuilder::new(self)
+    }
+
     pub fn has_default_value(&self) -> bool {
         match self {
             Type::Struct(inner) => inner.has_default_value(),
@@ -325,6 +554,7 @@ impl<'a> Type<'a> {
             Type::List(inner) => inner.has_default_value(),
             Type::Array(inner) => inner.has_default_value(),
             Type::Map(inner) => inner.has_default_value(),
+            Type::Set(inner) => inner.has_default_value(),
             Type::Scalar(inner) => inner.has_default_value(),
             Type::Opaque(inner) => inner.has_default_value(),
         }
@@ -339,6 +569,7 @@ impl<'a> Type<'a> {
             Type::List(inner) => Cow::Owned(inner.into_type_descriptor()),
             Type::Array(inner) => Cow::Owned(inner.into_type_descriptor()),
             Type::Map(inner) => Cow::Owned(inner.into_type_descriptor()),
+            Type::Set(inner) => Cow::Owned(inner.into_type_descriptor()),
             Type::Scalar(inner) => match inner {
                 ScalarType::usize => <usize as DescribeType>::type_descriptor(),
                 ScalarType::u8 => <u8 as DescribeType>::type_descriptor(),
@@ -410,6 +641,13 @@ impl<'a> Type<'a> {
         }
     }
 
+    pub fn as_set(self) -> Option<SetType<'a>> {
+        match self {
+            Type::Set(inner) => Some(inner),
+            _ => None,
+        }
+    }
+
     pub fn as_scalar(self) -> Option<ScalarType> {
         match self {
             Type::Scalar(inner) => Some(inner),
@@ -423,6 +661,11 @@ impl<'a> Type<'a> {
             _ => None,
         }
     }
+
+    /// Whether this is the unit type `()`. See [`TupleType::is_unit`].
+    pub fn is_unit(self) -> bool {
+        matches!(self, Type::Tuple(tuple) if tuple.is_unit())
+    }
 }
 
 impl<'a> GetTypePath<'a> for Type<'a> {
@@ -467,6 +710,12 @@ impl<'a> GetTypePath<'a> for MapType<'a> {
     }
 }
 
+impl<'a> GetTypePath<'a> for SetType<'a> {
+    fn type_at(self, key_path: &KeyPath) -> Option<TypeAtPath<'a>> {
+        self.into_type_info_at_path().type_at(key_path)
+    }
+}
+
 impl GetTypePath<'static> for ScalarType {
     fn type_at(self, key_path: &KeyPath) -> Option<TypeAtPath<'static>> {
         self.into_type_info_at_path().type_at(key_path)
@@ -490,6 +739,7 @@ mod private {
     impl<'a> Sealed for ListType<'a> {}
     impl<'a> Sealed for ArrayType<'a> {}
     impl<'a> Sealed for MapType<'a> {}
+    impl<'a> Sealed for SetType<'a> {}
     impl Sealed for ScalarType {}
     impl Sealed for Type<'_> {}
     impl Sealed for StructType<'_> {}
@@ -509,6 +759,9 @@ mod private {
 pub trait GetMeta<'a>: private::Sealed {
     fn meta(self, key: &str) -> Option<&'a dyn Reflect>;
 
+    /// Iterate over all metadata, ordered by key.
+    fn meta_iter(self) -> MetaIter<'a>;
+
     fn get_meta<T>(self, key: &str) -> Option<T>
     where
         T: FromReflect,
@@ -517,7 +770,312 @@ pub trait GetMeta<'a>: private::Sealed {
         T::from_reflect(self.meta(key)?)
     }
 
+    /// Get metadata stored under a [`MetaKey`], such as one of the [`known`] keys.
+    ///
+    /// Returns `None` if the metadata is missing or has a different type.
+    ///
+    /// [`known`]: crate::meta::known
+    fn typed_meta<K>(self) -> Option<K::Value>
+    where
+        K: MetaKey,
+        Self: Sized,
+    {
+        self.get_meta::<K::Value>(K::KEY)
+    }
+
     fn docs(self) -> &'a [String];
+
+    /// The first paragraph of the docs on a single line, with markdown such as `` ` ``, `*`, and
+    /// links removed.
+    ///
+    /// Returns `None` if there are no docs.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mirror_mirror::{DescribeType, Reflect};
+    /// use mirror_mirror::type_info::GetMeta;
+    ///
+    /// /// The **player** character, see [`Npc`] and
+    /// /// [the guide](https://example.com).
+    /// ///
+    /// /// More details.
+    /// #[derive(Reflect, Clone, Debug)]
+    /// struct Player {}
+    ///
+    /// assert_eq!(
+    ///     <Player as DescribeType>::type_descriptor().get_type().docs_summary().unwrap(),
+    ///     "The player character, see Npc and the guide.",
+    /// );
+    /// ```
+    fn docs_summary(self) -> Option<String>
+    where
+        Self: Sized,
+    {
+        let summary = self
+            .docs()
+            .iter()
+            .map(|line| line.trim())
+            .skip_while(|line| line.is_empty())
+            .take_while(|line| !line.is_empty())
+            .map(|line| line.trim_start_matches('#').trim_start())
+            .collect::<Vec<_>>()
+            .join(" ");
+        let summary = strip_markdown(&summary);
+        (!summary.is_empty()).then_some(summary)
+    }
+
+    /// Get metadata of the field or variant at `key_path`.
+    ///
+    /// Paths that don't end at a field or variant, such as the empty path or `[0]`, get metadata
+    /// of the type at the path instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mirror_mirror::{key_path, DescribeType, Reflect};
+    /// use mirror_mirror::type_info::GetMeta;
+    ///
+    /// #[derive(Reflect, Clone, Debug)]
+    /// struct Player {
+    ///     stats: Stats,
+    /// }
+    ///
+    /// #[derive(Reflect, Clone, Debug)]
+    /// #[reflect(meta(category = "gameplay"))]
+    /// struct Stats {
+    ///     #[reflect(meta(max = 100_u32))]
+    ///     health: u32,
+    /// }
+    ///
+    /// let descriptor = <Player as DescribeType>::type_descriptor();
+    ///
+    /// let max = descriptor.meta_at(&key_path!(.stats.health), "max");
+    /// assert_eq!(max.unwrap().downcast_ref::<u32>(), Some(&100));
+    ///
+    /// // `stats` itself doesn't have the metadata, but its type does
+    /// assert!(descriptor.meta_at(&key_path!(.stats), "category").is_none());
+    /// assert!(descriptor
+    ///     .inherited_meta_at(&key_path!(.stats), "category")
+    ///     .is_some());
+    /// ```
+    fn meta_at(self, key_path: &KeyPath, key: &str) -> Option<&'a dyn Reflect>
+    where
+        Self: GetTypePath<'a> + Sized,
+    {
+        MetaAtPath::new(self, key_path, false)?.meta(key)
+    }
+
+    /// Like [`meta_at`](Self::meta_at) but fields and variants that don't have the metadata
+    /// inherit it from their type.
+    fn inherited_meta_at(self, key_path: &KeyPath, key: &str) -> Option<&'a dyn Reflect>
+    where
+        Self: GetTypePath<'a> + Sized,
+    {
+        MetaAtPath::new(self, key_path, true)?.meta(key)
+    }
+
+    /// Iterate over the metadata [`meta_at`](Self::meta_at) finds at `key_path`, ordered by
+    /// key.
+    ///
+    /// The iterator is empty if there is nothing at the path.
+    fn meta_iter_at(self, key_path: &KeyPath) -> MetaIter<'a>
+    where
+        Self: GetTypePath<'a> + Sized,
+    {
+        MetaAtPath::new(self, key_path, false).map_or_else(MetaIter::empty, MetaAtPath::iter)
+    }
+
+    /// Iterate over the metadata [`inherited_meta_at`](Self::inherited_meta_at) finds at
+    /// `key_path`, ordered by key.
+    ///
+    /// Metadata of the field or variant takes precedence over metadata of its type with the same
+    /// key.
+    fn inherited_meta_iter_at(self, key_path: &KeyPath) -> MetaIter<'a>
+    where
+        Self: GetTypePath<'a> + Sized,
+    {
+        MetaAtPath::new(self, key_path, true).map_or_else(MetaIter::empty, MetaAtPath::iter)
+    }
+
+    /// Whether this was marked with `#[reflect(sensitive)]`.
+    ///
+    /// Sensitive values are replaced by `<redacted>` in [`LogValue`] and, if enabled, in
+    /// `serde::TypedSerializer`.
+    ///
+    /// [`LogValue`]: crate::logging::LogValue
+    #[allow(clippy::wrong_self_convention)]
+    fn is_sensitive(self) -> bool
+    where
+        Self: Sized,
+    {
+        self.meta("sensitive").is_some()
+    }
+}
+
+/// An iterator over metadata, created by [`GetMeta::meta_iter`] and friends.
+#[derive(Debug, Clone)]
+pub struct MetaIter<'a> {
+    iter: Option<Peekable<btree_map::Iter<'a, String, Value>>>,
+    /// Metadata inherited from a type, skipping keys `iter` also has.
+    inherited: Option<Peekable<btree_map::Iter<'a, String, Value>>>,
+}
+
+impl<'a> MetaIter<'a> {
+    fn new(metadata: &'a BTreeMap<String, Value>) -> Self {
+        Self {
+            iter: Some(metadata.iter().peekable()),
+            inherited: None,
+        }
+    }
+
+    fn empty() -> Self {
+        Self {
+            iter: None,
+            inherited: None,
+        }
+    }
+}
+
+impl<'a> Iterator for MetaIter<'a> {
+    type Item = (&'a str, &'a dyn Reflect);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let own_key = self
+            .iter
+            .as_mut()
+            .and_then(|iter| iter.peek())
+            .map(|(key, _)| *key);
+        let inherited_key = self
+            .inherited
+            .as_mut()
+            .and_then(|iter| iter.peek())
+            .map(|(key, _)| *key);
+
+        let (key, value) = match (own_key, inherited_key) {
+            (Some(own_key), Some(inherited_key)) if inherited_key < own_key => {
+                self.inherited.as_mut()?.next()?
+            }
+            (Some(own_key), Some(inherited_key)) => {
+                if inherited_key == own_key {
+                    self.inherited.as_mut()?.next();
+                }
+                self.iter.as_mut()?.next()?
+            }
+            (Some(_), None) => self.iter.as_mut()?.next()?,
+            (None, Some(_)) => self.inherited.as_mut()?.next()?,
+            (None, None) => return None,
+        };
+        Some((key, value.as_reflect()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = |iter: &Option<Peekable<_>>| iter.as_ref().map_or(0, ExactSizeIterator::len);
+        let own = len(&self.iter);
+        let inherited = len(&self.inherited);
+        (own.max(inherited), Some(own + inherited))
+    }
+}
+
+/// Remove inline markdown from a line of docs: code spans keep their text but lose their
+/// backticks, emphasis markers are dropped, and links are replaced by their text.
+fn strip_markdown(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    let mut in_code = false;
+    while let Some(c) = chars.next() {
+        match c {
+            '`' => in_code = !in_code,
+            _ if in_code => out.push(c),
+            '*' | '[' => {}
+            ']' => {
+                // skip the target of `[text](url)` and `[text][reference]`
+                let close = match chars.peek() {
+                    Some('(') => ')',
+                    Some('[') => ']',
+                    _ => continue,
+                };
+                for c in chars.by_ref() {
+                    if c == close {
+                        break;
+                    }
+                }
+            }
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// The metadata [`GetMeta::meta_at`] and friends look up.
+struct MetaAtPath<'a> {
+    metadata: Option<&'a BTreeMap<String, Value>>,
+    inherited: Option<&'a BTreeMap<String, Value>>,
+}
+
+impl<'a> MetaAtPath<'a> {
+    fn new<T>(root: T, key_path: &KeyPath, inherit: bool) -> Option<Self>
+    where
+        T: GetTypePath<'a>,
+    {
+        let root = root.type_at(&KeyPath::default())?;
+        let ty = root.type_at(key_path)?;
+        let Some((last, parent)) = key_path.path.split_last() else {
+            return Some(Self {
+                metadata: ty.metadata(),
+                inherited: None,
+            });
+        };
+
+        let parent = root.type_at(&KeyPath {
+            path: parent.to_vec(),
+        });
+        let field = match (last, parent?) {
+            (Key::Field(NamedOrNumbered::Named(name)), TypeAtPath::Struct(inner)) => {
+                Some(&inner.field_type(name)?.node.metadata)
+            }
+            (
+                Key::Field(NamedOrNumbered::Named(name)),
+                TypeAtPath::Variant(Variant::Struct(inner)),
+            ) => Some(&inner.field_type(name)?.node.metadata),
+            (Key::Field(NamedOrNumbered::Numbered(index)), TypeAtPath::TupleStruct(inner)) => {
+                Some(&inner.field_type_at(*index)?.node.metadata)
+            }
+            (Key::Field(NamedOrNumbered::Numbered(index)), TypeAtPath::Tuple(inner)) => {
+                Some(&inner.field_type_at(*index)?.node.metadata)
+            }
+            (
+                Key::Field(NamedOrNumbered::Numbered(index)),
+                TypeAtPath::Variant(Variant::Tuple(inner)),
+            ) => Some(&inner.field_type_at(*index)?.node.metadata),
+            _ => None,
+        };
+
+        Some(match field {
+            Some(metadata) => Self {
+                metadata: Some(metadata),
+                inherited: if inherit { ty.metadata() } else { None },
+            },
+            None => Self {
+                metadata: ty.metadata(),
+                inherited: None,
+            },
+        })
+    }
+
+    fn meta(self, key: &str) -> Option<&'a dyn Reflect> {
+        self.metadata
+            .and_then(|metadata| metadata.get(key))
+            .or_else(|| self.inherited?.get(key))
+            .map(Value::as_reflect)
+    }
+
+    fn iter(self) -> MetaIter<'a> {
+        MetaIter {
+            iter: self.metadata.map(|metadata| metadata.iter().peekable()),
+            inherited: self.inherited.map(|metadata| metadata.iter().peekable()),
+        }
+    }
 }
 
 impl<'a> GetMeta<'a> for Type<'a> {
@@ -527,9 +1085,27 @@ impl<'a> GetMeta<'a> for Type<'a> {
             Type::TupleStruct(inner) => inner.meta(key),
             Type::Enum(inner) => inner.meta(key),
             Type::Opaque(inner) => inner.meta(key),
-            Type::Tuple(_) | Type::List(_) | Type::Array(_) | Type::Map(_) | Type::Scalar(_) => {
-                None
-            }
+            Type::Tuple(_)
+            | Type::List(_)
+            | Type::Array(_)
+            | Type::Map(_)
+            | Type::Set(_)
+            | Type::Scalar(_) => None,
+        }
+    }
+
+    fn meta_iter(self) -> MetaIter<'a> {
+        match self {
+            Type::Struct(inner) => inner.meta_iter(),
+            Type::TupleStruct(inner) => inner.meta_iter(),
+            Type::Enum(inner) => inner.meta_iter(),
+            Type::Opaque(inner) => inner.meta_iter(),
+            Type::Tuple(_)
+            | Type::List(_)
+            | Type::Array(_)
+            | Type::Map(_)
+            | Type::Set(_)
+            | Type::Scalar(_) => MetaIter::empty(),
         }
     }
 
@@ -542,6 +1118,7 @@ impl<'a> GetMeta<'a> for Type<'a> {
             | Type::List(_)
             | Type::Array(_)
             | Type::Map(_)
+            | Type::Set(_)
             | Type::Scalar(_)
             | Type::Opaque(_) => &[],
         }
@@ -556,6 +1133,10 @@ macro_rules! impl_get_meta {
                     Some(self.node.metadata.get(key)?.as_reflect())
                 }
 
+                fn meta_iter(self) -> MetaIter<'a> {
+                    MetaIter::new(&self.node.metadata)
+                }
+
                 fn docs(self) -> &'a [String] {
                     &self.node.docs
                 }
@@ -580,6 +1161,10 @@ impl<'a> GetMeta<'a> for OpaqueType<'a> {
         Some(self.node.metadata.get(key)?.as_reflect())
     }
 
+    fn meta_iter(self) -> MetaIter<'a> {
+        MetaIter::new(&self.node.metadata)
+    }
+
     fn docs(self) -> &'a [String] {
         &[]
     }
@@ -663,7 +1248,7 @@ impl ScalarType {
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct StructType<'a> {
     node: WithId<&'a StructNode>,
-    graph: &'a TypeGraph,
+    graph: &'a Shared<TypeGraph>,
 }
 
 impl<'a> StructType<'a> {
@@ -672,13 +1257,18 @@ impl<'a> StructType<'a> {
     }
 
     pub fn field_types(self) -> impl Iterator<Item = NamedField<'a>> {
-        self.node.field_names.iter().map(move |field_name| {
-            let node = self.node.fields.get(field_name).unwrap();
-            NamedField {
-                node,
-                graph: self.graph,
-            }
-        })
+        self.node
+            .field_names
+            .iter()
+            .enumerate()
+            .map(move |(index, field_name)| {
+                let node = self.node.fields.get(field_name).unwrap();
+                NamedField {
+                    node,
+                    graph: self.graph,
+                    index,
+                }
+            })
     }
 
     pub fn fields_len(self) -> usize {
@@ -686,16 +1276,28 @@ impl<'a> StructType<'a> {
     }
 
     pub fn field_type(self, name: &str) -> Option<NamedField<'a>> {
+        self.field_type_at(self.field_index(name)?)
+    }
+
+    pub fn field_type_at(self, index: usize) -> Option<NamedField<'a>> {
+        let name = self.node.field_names.get(index)?;
         let node = self.node.fields.get(name)?;
         Some(NamedField {
             node,
             graph: self.graph,
+            index,
         })
     }
 
-    pub fn field_type_at(self, index: usize) -> Option<NamedField<'a>> {
-        let name = self.node.field_names.get(index)?;
-        self.field_type(name)
+    /// The position of the field named `name` in declaration order, ignoring skipped fields.
+    ///
+    /// This is the index accepted by [`StructType::field_type_at`] and stays the same as long as
+    /// fields aren't added, removed, or reordered.
+    pub fn field_index(self, name: &str) -> Option<usize> {
+        self.node
+            .field_names
+            .iter()
+            .position(|field_name| field_name == name)
     }
 
     fn into_type_info_at_path(self) -> TypeAtPath<'a> {
@@ -710,23 +1312,25 @@ impl<'a> StructType<'a> {
     }
 
     pub fn default_value(self) -> Option<Value> {
+        if let Some(default) = self.meta("default") {
+            return Some(default.to_value());
+        }
         let mut value = StructValue::new();
         for field in self.field_types() {
-            value.set_field(field.name(), field.get_type().default_value()?);
+            value.set_field(field.name(), field.default_value()?);
         }
         Some(value.to_value())
     }
 
     pub fn has_default_value(&self) -> bool {
-        self.field_types()
-            .all(|field| field.get_type().has_default_value())
+        self.meta("default").is_some() || self.field_types().all(|field| field.has_default_value())
     }
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct TupleStructType<'a> {
     node: WithId<&'a TupleStructNode>,
-    graph: &'a TypeGraph,
+    graph: &'a Shared<TypeGraph>,
 }
 
 impl<'a> TupleStructType<'a> {
@@ -765,23 +1369,25 @@ impl<'a> TupleStructType<'a> {
     }
 
     pub fn default_value(self) -> Option<Value> {
+        if let Some(default) = self.meta("default") {
+            return Some(default.to_value());
+        }
         let mut value = TupleStructValue::new();
         for field in self.field_types() {
-            value.push_field(field.get_type().default_value()?);
+            value.push_field(field.default_value()?);
         }
         Some(value.to_value())
     }
 
     pub fn has_default_value(&self) -> bool {
-        self.field_types()
-            .all(|field| field.get_type().has_default_value())
+        self.meta("default").is_some() || self.field_types().all(|field| field.has_default_value())
     }
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct TupleType<'a> {
     node: WithId<&'a TupleNode>,
-    graph: &'a TypeGraph,
+    graph: &'a Shared<TypeGraph>,
 }
 
 impl<'a> TupleType<'a> {
@@ -800,6 +1406,14 @@ impl<'a> TupleType<'a> {
         self.node.fields.len()
     }
 
+    /// Whether this is the unit type `()`, the tuple without fields.
+    ///
+    /// Its values are [`Value::unit`], and the typed serializer writes them as serde's unit, like
+    /// serde's derives do.
+    pub fn is_unit(self) -> bool {
+        self.node.fields.is_empty()
+    }
+
     pub fn field_type_at(self, index: usize) -> Option<UnnamedField<'a>> {
         let node = self.node.fields.get(index)?;
         Some(UnnamedField {
@@ -836,7 +1450,7 @@ impl<'a> TupleType<'a> {
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct EnumType<'a> {
     node: WithId<&'a EnumNode>,
-    graph: &'a TypeGraph,
+    graph: &'a Shared<TypeGraph>,
 }
 
 impl<'a> EnumType<'a> {
@@ -868,10 +1482,70 @@ impl<'a> EnumType<'a> {
         self.node.variants.len()
     }
 
+    /// The integer type from the enum's `#[repr(...)]`, such as `"u8"`.
+    ///
+    /// Only enums with an integer representation have [discriminants](Variant::discriminant).
+    pub fn repr(self) -> Option<&'a str> {
+        self.node.repr.as_deref()
+    }
+
     pub fn variant(self, name: &str) -> Option<Variant<'a>> {
         self.variants().find(|variant| variant.name() == name)
     }
 
+    /// The variant at `index`, in declaration order.
+    pub fn variant_at(self, index: usize) -> Option<Variant<'a>> {
+        self.variants().nth(index)
+    }
+
+    /// The index of the variant called `name`, in declaration order.
+    pub fn variant_index(self, name: &str) -> Option<usize> {
+        self.variants().position(|variant| variant.name() == name)
+    }
+
+    /// Start building a value of the variant called `name`.
+    ///
+    /// Fields are pre-populated with their default values. Returns `None` if there is no variant
+    /// with that name.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mirror_mirror::{Reflect, DescribeType, FromReflect};
+    /// use mirror_mirror::type_info::Type;
+    ///
+    /// #[derive(Reflect, Debug, Clone, PartialEq)]
+    /// enum Shape {
+    ///     Circle { radius: f32 },
+    ///     Rect { width: f32, height: f32 },
+    /// }
+    ///
+    /// let descriptor = <Shape as DescribeType>::type_descriptor();
+    /// let Type::Enum(enum_type) = descriptor.get_type() else {
+    ///     panic!()
+    /// };
+    ///
+    /// let value = enum_type
+    ///     .build_variant("Rect")
+    ///     .unwrap()
+    ///     .with_field("width", 2.0_f32)
+    ///     .unwrap()
+    ///     .finish()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(
+    ///     Shape::from_reflect(&value).unwrap(),
+    ///     Shape::Rect { width: 2.0, height: 0.0 },
+    /// );
+    ///
+    /// // fields are validated
+    /// assert!(enum_type.build_variant("Rect").unwrap().with_field("depth", 1.0_f32).is_err());
+    /// assert!(enum_type.build_variant("Rect").unwrap().with_field("width", "wide").is_err());
+    /// ```
+    pub fn build_variant(self, name: &str) -> Option<VariantBuilder<'a>> {
+        self.variant(name).map(VariantBuilder::new)
+    }
+
     fn into_type_info_at_path(self) -> TypeAtPath<'a> {
         TypeAtPath::Enum(self)
     }
@@ -884,12 +1558,18 @@ impl<'a> EnumType<'a> {
     }
 
     pub fn default_value(self) -> Option<Value> {
+        if let Some(default) = self.meta("default") {
+            return Some(default.to_value());
+        }
         let mut variants = self.variants();
         let first_variant = variants.next()?;
         first_variant.default_value()
     }
 
     pub fn has_default_value(&self) -> bool {
+        if self.meta("default").is_some() {
+            return true;
+        }
         let mut variants = self.variants();
         variants
             .next()
@@ -921,6 +1601,15 @@ impl<'a> Variant<'a> {
         }
     }
 
+    /// The discriminant of the variant, if the enum has an integer `#[repr(...)]`.
+    pub fn discriminant(self) -> Option<i128> {
+        match self {
+            Variant::Struct(inner) => inner.discriminant(),
+            Variant::Tuple(inner) => inner.discriminant(),
+            Variant::Unit(inner) => inner.discriminant(),
+        }
+    }
+
     pub fn field_types(self) -> impl Iterator<Item = VariantField<'a>> {
         match self {
             Variant::Struct(inner) => Box::new(inner.field_types().map(VariantField::Named))
@@ -965,6 +1654,11 @@ impl<'a> Variant<'a> {
         TypeAtPath::Variant(self)
     }
 
+    /// Create a standalone descriptor for the enum the variant belongs to.
+    pub fn into_type_descriptor(self) -> TypeDescriptor {
+        self.enum_type().into_type_descriptor()
+    }
+
     pub fn default_value(self) -> Option<Value> {
         match self {
             Variant::Struct(variant) => variant.default_value(),
@@ -991,6 +1685,14 @@ impl<'a> GetMeta<'a> for Variant<'a> {
         }
     }
 
+    fn meta_iter(self) -> MetaIter<'a> {
+        match self {
+            Variant::Struct(inner) => inner.meta_iter(),
+            Variant::Tuple(inner) => inner.meta_iter(),
+            Variant::Unit(inner) => inner.meta_iter(),
+        }
+    }
+
     fn docs(self) -> &'a [String] {
         match self {
             Variant::Struct(inner) => inner.docs(),
@@ -1020,6 +1722,10 @@ impl<'a> VariantField<'a> {
             VariantField::Unnamed(_) => None,
         }
     }
+    /// Create a standalone descriptor for the type of the field.
+    pub fn into_type_descriptor(self) -> Cow<'static, TypeDescriptor> {
+        self.get_type().into_type_descriptor()
+    }
 }
 
 impl<'a> GetMeta<'a> for VariantField<'a> {
@@ -1030,6 +1736,13 @@ impl<'a> GetMeta<'a> for VariantField<'a> {
         }
     }
 
+    fn meta_iter(self) -> MetaIter<'a> {
+        match self {
+            VariantField::Named(inner) => inner.meta_iter(),
+            VariantField::Unnamed(inner) => inner.meta_iter(),
+        }
+    }
+
     fn docs(self) -> &'a [String] {
         match self {
             VariantField::Named(inner) => inner.docs(),
@@ -1042,7 +1755,7 @@ impl<'a> GetMeta<'a> for VariantField<'a> {
 pub struct StructVariant<'a> {
     node: &'a StructVariantNode,
     enum_node: WithId<&'a EnumNode>,
-    graph: &'a TypeGraph,
+    graph: &'a Shared<TypeGraph>,
 }
 
 impl<'a> StructVariant<'a> {
@@ -1050,18 +1763,27 @@ impl<'a> StructVariant<'a> {
         &self.node.name
     }
 
+    pub fn discriminant(self) -> Option<i128> {
+        self.node.discriminant
+    }
+
     pub fn type_name(self) -> &'a str {
         self.enum_type().type_name()
     }
 
     pub fn field_types(self) -> impl Iterator<Item = NamedField<'a>> {
-        self.node.field_names.iter().map(|field_name| {
-            let node = self.node.fields.get(field_name).unwrap();
-            NamedField {
-                node,
-                graph: self.graph,
-            }
-        })
+        self.node
+            .field_names
+            .iter()
+            .enumerate()
+            .map(move |(index, field_name)| {
+                let node = self.node.fields.get(field_name).unwrap();
+                NamedField {
+                    node,
+                    graph: self.graph,
+                    index,
+                }
+            })
     }
 
     pub fn fields_len(self) -> usize {
@@ -1069,16 +1791,28 @@ impl<'a> StructVariant<'a> {
     }
 
     pub fn field_type(self, name: &str) -> Option<NamedField<'a>> {
+        self.field_type_at(self.field_index(name)?)
+    }
+
+    pub fn field_type_at(self, index: usize) -> Option<NamedField<'a>> {
+        let name = self.node.field_names.get(index)?;
         let node = self.node.fields.get(name)?;
         Some(NamedField {
             node,
             graph: self.graph,
+            index,
         })
     }
 
-    pub fn field_type_at(self, index: usize) -> Option<NamedField<'a>> {
-        let name = self.node.field_names.get(index)?;
-        self.field_type(name)
+    /// The position of the field named `name` in declaration order, ignoring skipped fields.
+    ///
+    /// This is the index accepted by [`StructVariant::field_type_at`] and stays the same as long as
+    /// fields aren't added, removed, or reordered.
+    pub fn field_index(self, name: &str) -> Option<usize> {
+        self.node
+            .field_names
+            .iter()
+            .position(|field_name| field_name == name)
     }
 
     pub fn enum_type(self) -> EnumType<'a> {
@@ -1091,14 +1825,15 @@ impl<'a> StructVariant<'a> {
     pub fn default_value(self) -> Option<Value> {
         let mut value = EnumValue::new_struct_variant(self.name());
         for field in self.field_types() {
-            value.set_struct_field(field.name(), field.get_type().default_value()?);
+            value.set_struct_field(field.name(), field.default_value()?);
         }
-        Some(value.finish().to_value())
+        let mut value = value.finish();
+        value.set_discriminant(self.discriminant());
+        Some(value.to_value())
     }
 
     pub fn has_default_value(&self) -> bool {
-        self.field_types()
-            .all(|field| field.get_type().has_default_value())
+        self.field_types().all(|field| field.has_default_value())
     }
 }
 
@@ -1106,7 +1841,7 @@ impl<'a> StructVariant<'a> {
 pub struct TupleVariant<'a> {
     node: &'a TupleVariantNode,
     enum_node: WithId<&'a EnumNode>,
-    graph: &'a TypeGraph,
+    graph: &'a Shared<TypeGraph>,
 }
 
 impl<'a> TupleVariant<'a> {
@@ -1114,6 +1849,10 @@ impl<'a> TupleVariant<'a> {
         &self.node.name
     }
 
+    pub fn discriminant(self) -> Option<i128> {
+        self.node.discriminant
+    }
+
     pub fn type_name(self) -> &'a str {
         self.enum_type().type_name()
     }
@@ -1147,14 +1886,15 @@ impl<'a> TupleVariant<'a> {
     pub fn default_value(self) -> Option<Value> {
         let mut value = EnumValue::new_tuple_variant(self.name());
         for field in self.field_types() {
-            value.push_tuple_field(field.get_type().default_value()?);
+            value.push_tuple_field(field.default_value()?);
         }
-        Some(value.finish().to_value())
+        let mut value = value.finish();
+        value.set_discriminant(self.discriminant());
+        Some(value.to_value())
     }
 
     pub fn has_default_value(&self) -> bool {
-        self.field_types()
-            .all(|field| field.get_type().has_default_value())
+        self.field_types().all(|field| field.has_default_value())
     }
 }
 
@@ -1162,7 +1902,7 @@ impl<'a> TupleVariant<'a> {
 pub struct UnitVariant<'a> {
     node: &'a UnitVariantNode,
     enum_node: WithId<&'a EnumNode>,
-    graph: &'a TypeGraph,
+    graph: &'a Shared<TypeGraph>,
 }
 
 impl<'a> UnitVariant<'a> {
@@ -1170,6 +1910,10 @@ impl<'a> UnitVariant<'a> {
         &self.node.name
     }
 
+    pub fn discriminant(self) -> Option<i128> {
+        self.node.discriminant
+    }
+
     pub fn type_name(self) -> &'a str {
         self.enum_type().type_name()
     }
@@ -1182,7 +1926,9 @@ impl<'a> UnitVariant<'a> {
     }
 
     pub fn default_value(self) -> Value {
-        EnumValue::new_unit_variant(self.name()).to_value()
+        let mut value = EnumValue::new_unit_variant(self.name());
+        value.set_discriminant(self.discriminant());
+        value.to_value()
     }
 
     pub fn has_default_value(&self) -> bool {
@@ -1193,7 +1939,7 @@ impl<'a> UnitVariant<'a> {
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct UnnamedField<'a> {
     node: &'a UnnamedFieldNode,
-    graph: &'a TypeGraph,
+    graph: &'a Shared<TypeGraph>,
 }
 
 impl<'a> UnnamedField<'a> {
@@ -1201,6 +1947,26 @@ impl<'a> UnnamedField<'a> {
         Type::new(self.node.id, self.graph)
     }
 
+    /// Create a standalone descriptor for the type of the field.
+    pub fn into_type_descriptor(self) -> Cow<'static, TypeDescriptor> {
+        self.get_type().into_type_descriptor()
+    }
+
+    /// The default value of the field.
+    ///
+    /// Uses `#[reflect(default = ...)]` if set, and otherwise the default value of the field's
+    /// type.
+    pub fn default_value(self) -> Option<Value> {
+        match self.meta("default") {
+            Some(default) => Some(default.to_value()),
+            None => self.get_type().default_value(),
+        }
+    }
+
+    pub fn has_default_value(&self) -> bool {
+        self.meta("default").is_some() || self.get_type().has_default_value()
+    }
+
     fn into_type_info_at_path(self) -> TypeAtPath<'a> {
         self.get_type().into_type_info_at_path()
     }
@@ -1209,7 +1975,8 @@ impl<'a> UnnamedField<'a> {
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct NamedField<'a> {
     node: &'a NamedFieldNode,
-    graph: &'a TypeGraph,
+    graph: &'a Shared<TypeGraph>,
+    index: usize,
 }
 
 impl<'a> NamedField<'a> {
@@ -1217,10 +1984,35 @@ impl<'a> NamedField<'a> {
         &self.node.name
     }
 
+    /// The position of the field in declaration order, ignoring skipped fields.
+    pub fn index(self) -> usize {
+        self.index
+    }
+
     pub fn get_type(self) -> Type<'a> {
         Type::new(self.node.id, self.graph)
     }
 
+    /// Create a standalone descriptor for the type of the field.
+    pub fn into_type_descriptor(self) -> Cow<'static, TypeDescriptor> {
+        self.get_type().into_type_descriptor()
+    }
+
+    /// The default value of the field.
+    ///
+    /// Uses `#[reflect(default = ...)]` if set, and otherwise the default value of the field's
+    /// type.
+    pub fn default_value(self) -> Option<Value> {
+        match self.meta("default") {
+            Some(default) => Some(default.to_value()),
+            None => self.get_type().default_value(),
+        }
+    }
+
+    pub fn has_default_value(&self) -> bool {
+        self.meta("default").is_some() || self.get_type().has_default_value()
+    }
+
     fn into_type_info_at_path(self) -> TypeAtPath<'a> {
         self.get_type().into_type_info_at_path()
     }
@@ -1229,7 +2021,7 @@ impl<'a> NamedField<'a> {
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct ArrayType<'a> {
     node: WithId<&'a ArrayNode>,
-    graph: &'a TypeGraph,
+    graph: &'a Shared<TypeGraph>,
 }
 
 impl<'a> ArrayType<'a> {
@@ -1249,6 +2041,36 @@ impl<'a> ArrayType<'a> {
         self.node.len == 0
     }
 
+    /// The lengths of this array and the arrays nested directly within it, outermost first.
+    ///
+    /// `[[f32; 4]; 3]` has the shape `[3, 4]`. Lists and other types end the shape, so
+    /// `[Vec<f32>; 3]` has the shape `[3]`.
+    pub fn shape(self) -> Vec<usize> {
+        let mut shape = vec![self.len()];
+        let mut element = self.element_type();
+        while let Type::Array(array) = element {
+            shape.push(array.len());
+            element = array.element_type();
+        }
+        shape
+    }
+
+    /// The total number of innermost elements, that is the product of [`ArrayType::shape`].
+    pub fn flat_len(self) -> usize {
+        self.shape().into_iter().product()
+    }
+
+    /// The type of the innermost elements, after all nested arrays.
+    ///
+    /// `f32` for `[[f32; 4]; 3]`.
+    pub fn flat_element_type(self) -> Type<'a> {
+        let mut element = self.element_type();
+        while let Type::Array(array) = element {
+            element = array.element_type();
+        }
+        element
+    }
+
     fn into_type_info_at_path(self) -> TypeAtPath<'a> {
         TypeAtPath::Array(self)
     }
@@ -1276,7 +2098,7 @@ impl<'a> ArrayType<'a> {
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct ListType<'a> {
     node: WithId<&'a ListNode>,
-    graph: &'a TypeGraph,
+    graph: &'a Shared<TypeGraph>,
 }
 
 impl<'a> ListType<'a> {
@@ -1311,7 +2133,7 @@ impl<'a> ListType<'a> {
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct MapType<'a> {
     node: WithId<&'a MapNode>,
-    graph: &'a TypeGraph,
+    graph: &'a Shared<TypeGraph>,
 }
 
 impl<'a> MapType<'a> {
@@ -1347,10 +2169,45 @@ impl<'a> MapType<'a> {
     }
 }
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SetType<'a> {
+    node: WithId<&'a SetNode>,
+    graph: &'a Shared<TypeGraph>,
+}
+
+impl<'a> SetType<'a> {
+    pub fn type_name(self) -> &'a str {
+        &self.node.type_name
+    }
+
+    pub fn element_type(self) -> Type<'a> {
+        Type::new(self.node.element_type_id, self.graph)
+    }
+
+    fn into_type_info_at_path(self) -> TypeAtPath<'a> {
+        TypeAtPath::Set(self)
+    }
+
+    pub fn into_type_descriptor(self) -> TypeDescriptor {
+        TypeDescriptor {
+            root: self.node.id,
+            graph: self.graph.clone(),
+        }
+    }
+
+    pub fn default_value(self) -> Value {
+        BTreeSet::<()>::new().to_value()
+    }
+
+    pub fn has_default_value(&self) -> bool {
+        true
+    }
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct OpaqueType<'a> {
     node: WithId<&'a OpaqueNode>,
-    graph: &'a TypeGraph,
+    graph: &'a Shared<TypeGraph>,
 }
 
 impl<'a> OpaqueType<'a> {
@@ -1389,6 +2246,7 @@ pub enum TypeAtPath<'a> {
     List(ListType<'a>),
     Array(ArrayType<'a>),
     Map(MapType<'a>),
+    Set(SetType<'a>),
     Scalar(ScalarType),
     Opaque(OpaqueType<'a>),
 }
@@ -1400,25 +2258,43 @@ impl<'a> GetMeta<'a> for TypeAtPath<'a> {
             TypeAtPath::TupleStruct(inner) => inner.meta(key),
             TypeAtPath::Enum(inner) => inner.meta(key),
             TypeAtPath::Opaque(inner) => inner.meta(key),
-            TypeAtPath::Variant(_)
-            | TypeAtPath::Tuple(_)
+            TypeAtPath::Variant(inner) => inner.meta(key),
+            TypeAtPath::Tuple(_)
             | TypeAtPath::List(_)
             | TypeAtPath::Array(_)
             | TypeAtPath::Map(_)
+            | TypeAtPath::Set(_)
             | TypeAtPath::Scalar(_) => None,
         }
     }
 
+    fn meta_iter(self) -> MetaIter<'a> {
+        match self {
+            TypeAtPath::Struct(inner) => inner.meta_iter(),
+            TypeAtPath::TupleStruct(inner) => inner.meta_iter(),
+            TypeAtPath::Enum(inner) => inner.meta_iter(),
+            TypeAtPath::Opaque(inner) => inner.meta_iter(),
+            TypeAtPath::Variant(inner) => inner.meta_iter(),
+            TypeAtPath::Tuple(_)
+            | TypeAtPath::List(_)
+            | TypeAtPath::Array(_)
+            | TypeAtPath::Map(_)
+            | TypeAtPath::Set(_)
+            | TypeAtPath::Scalar(_) => MetaIter::empty(),
+        }
+    }
+
     fn docs(self) -> &'a [String] {
         match self {
             TypeAtPath::Struct(inner) => inner.docs(),
             TypeAtPath::TupleStruct(inner) => inner.docs(),
             TypeAtPath::Enum(inner) => inner.docs(),
-            TypeAtPath::Variant(_)
-            | TypeAtPath::Tuple(_)
+            TypeAtPath::Variant(inner) => inner.docs(),
+            TypeAtPath::Tuple(_)
             | TypeAtPath::List(_)
             | TypeAtPath::Array(_)
             | TypeAtPath::Map(_)
+            | TypeAtPath::Set(_)
             | TypeAtPath::Scalar(_)
             | TypeAtPath::Opaque(_) => &[],
         }
@@ -1426,6 +2302,24 @@ impl<'a> GetMeta<'a> for TypeAtPath<'a> {
 }
 
 impl<'a> TypeAtPath<'a> {
+    fn metadata(self) -> Option<&'a BTreeMap<String, Value>> {
+        match self {
+            TypeAtPath::Struct(inner) => Some(&inner.node.metadata),
+            TypeAtPath::TupleStruct(inner) => Some(&inner.node.metadata),
+            TypeAtPath::Enum(inner) => Some(&inner.node.metadata),
+            TypeAtPath::Variant(Variant::Struct(inner)) => Some(&inner.node.metadata),
+            TypeAtPath::Variant(Variant::Tuple(inner)) => Some(&inner.node.metadata),
+            TypeAtPath::Variant(Variant::Unit(inner)) => Some(&inner.node.metadata),
+            TypeAtPath::Opaque(inner) => Some(&inner.node.metadata),
+            TypeAtPath::Tuple(_)
+            | TypeAtPath::List(_)
+            | TypeAtPath::Array(_)
+            | TypeAtPath::Map(_)
+            | TypeAtPath::Set(_)
+            | TypeAtPath::Scalar(_) => None,
+        }
+    }
+
     pub fn default_value(self) -> Option<Value> {
         match self {
             TypeAtPath::Struct(inner) => inner.default_value(),
@@ -1436,6 +2330,7 @@ impl<'a> TypeAtPath<'a> {
             TypeAtPath::List(inner) => Some(inner.default_value()),
             TypeAtPath::Array(inner) => inner.default_value(),
             TypeAtPath::Map(inner) => Some(inner.default_value()),
+            TypeAtPath::Set(inner) => Some(inner.default_value()),
             TypeAtPath::Scalar(inner) => Some(inner.default_value()),
             TypeAtPath::Opaque(inner) => inner.default_value(),
         }
@@ -1451,11 +2346,31 @@ impl<'a> TypeAtPath<'a> {
             TypeAtPath::List(inner) => inner.has_default_value(),
             TypeAtPath::Array(inner) => inner.has_default_value(),
             TypeAtPath::Map(inner) => inner.has_default_value(),
+            TypeAtPath::Set(inner) => inner.has_default_value(),
             TypeAtPath::Scalar(inner) => inner.has_default_value(),
             TypeAtPath::Opaque(inner) => inner.has_default_value(),
         }
     }
 
+    /// Create a standalone descriptor for the type at the path.
+    ///
+    /// For [`TypeAtPath::Variant`] this is the descriptor of the enum the variant belongs to.
+    pub fn into_type_descriptor(self) -> Cow<'static, TypeDescriptor> {
+        match self {
+            TypeAtPath::Struct(inner) => Cow::Owned(inner.into_type_descriptor()),
+            TypeAtPath::TupleStruct(inner) => Cow::Owned(inner.into_type_descriptor()),
+            TypeAtPath::Tuple(inner) => Cow::Owned(inner.into_type_descriptor()),
+            TypeAtPath::Enum(inner) => Cow::Owned(inner.into_type_descriptor()),
+            TypeAtPath::Variant(inner) => Cow::Owned(inner.into_type_descriptor()),
+            TypeAtPath::List(inner) => Cow::Owned(inner.into_type_descriptor()),
+            TypeAtPath::Array(inner) => Cow::Owned(inner.into_type_descriptor()),
+            TypeAtPath::Map(inner) => Cow::Owned(inner.into_type_descriptor()),
+            TypeAtPath::Set(inner) => Cow::Owned(inner.into_type_descriptor()),
+            TypeAtPath::Scalar(inner) => Type::Scalar(inner).into_type_descriptor(),
+            TypeAtPath::Opaque(inner) => Cow::Owned(inner.into_type_descriptor()),
+        }
+    }
+
     pub fn type_name(self) -> &'a str {
         match self {
             TypeAtPath::Struct(inner) => inner.type_name(),
@@ -1466,6 +2381,7 @@ impl<'a> TypeAtPath<'a> {
             TypeAtPath::List(inner) => inner.type_name(),
             TypeAtPath::Array(inner) => inner.type_name(),
             TypeAtPath::Map(inner) => inner.type_name(),
+            TypeAtPath::Set(inner) => inner.type_name(),
             TypeAtPath::Scalar(inner) => inner.type_name(),
             TypeAtPath::Opaque(inner) => inner.type_name(),
         }
@@ -1527,6 +2443,13 @@ impl<'a> TypeAtPath<'a> {
         }
     }
 
+    pub fn as_set(self) -> Option<SetType<'a>> {
+        match self {
+            Self::Set(inner) => Some(inner),
+            _ => None,
+        }
+    }
+
     pub fn as_scalar(self) -> Option<ScalarType> {
         match self {
             Self::Scalar(inner) => Some(inner),
@@ -1540,6 +2463,23 @@ impl<'a> TypeAtPath<'a> {
             _ => None,
         }
     }
+
+    /// Get the type at the path, or `None` if the path points to a [`TypeAtPath::Variant`].
+    pub fn as_type(self) -> Option<Type<'a>> {
+        Some(match self {
+            TypeAtPath::Struct(inner) => Type::Struct(inner),
+            TypeAtPath::TupleStruct(inner) => Type::TupleStruct(inner),
+            TypeAtPath::Tuple(inner) => Type::Tuple(inner),
+            TypeAtPath::Enum(inner) => Type::Enum(inner),
+            TypeAtPath::List(inner) => Type::List(inner),
+            TypeAtPath::Array(inner) => Type::Array(inner),
+            TypeAtPath::Map(inner) => Type::Map(inner),
+            TypeAtPath::Set(inner) => Type::Set(inner),
+            TypeAtPath::Scalar(inner) => Type::Scalar(inner),
+            TypeAtPath::Opaque(inner) => Type::Opaque(inner),
+            TypeAtPath::Variant(_) => return None,
+        })
+    }
 }
 
 impl<'a> GetTypePath<'a> for TypeAtPath<'a> {
@@ -1568,6 +2508,7 @@ impl<'a> GetTypePath<'a> for TypeAtPath<'a> {
                     | TypeAtPath::List(_)
                     | TypeAtPath::Array(_)
                     | TypeAtPath::Map(_)
+                    | TypeAtPath::Set(_)
                     | TypeAtPath::Scalar(_)
                     | TypeAtPath::Opaque(_) => return None,
                 },
@@ -1590,6 +2531,7 @@ impl<'a> GetTypePath<'a> for TypeAtPath<'a> {
                     | TypeAtPath::List(_)
                     | TypeAtPath::Array(_)
                     | TypeAtPath::Map(_)
+                    | TypeAtPath::Set(_)
                     | TypeAtPath::Scalar(_)
                     | TypeAtPath::Opaque(_) => return None,
                 },
@@ -1615,6 +2557,7 @@ impl<'a> GetTypePath<'a> for TypeAtPath<'a> {
                     | TypeAtPath::Tuple(_)
                     | TypeAtPath::Enum(_)
                     | TypeAtPath::Variant(_)
+                    | TypeAtPath::Set(_)
                     | TypeAtPath::Scalar(_)
                     | TypeAtPath::Opaque(_) => return None,
                 },
@@ -1637,6 +2580,7 @@ impl<'a> GetTypePath<'a> for TypeAtPath<'a> {
                     | TypeAtPath::List(_)
                     | TypeAtPath::Array(_)
                     | TypeAtPath::Map(_)
+                    | TypeAtPath::Set(_)
                     | TypeAtPath::Scalar(_)
                     | TypeAtPath::Opaque(_) => return None,
                 },
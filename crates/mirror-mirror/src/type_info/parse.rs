@@ -0,0 +1,172 @@
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use core::fmt;
+
+use super::GetTypePath;
+use super::ScalarType;
+use super::Type;
+use super::TypeDescriptor;
+use crate::key_path::KeyPath;
+use crate::Value;
+
+impl ScalarType {
+    /// Parse a scalar of this type from text, such as the contents of a text field.
+    ///
+    /// Surrounding whitespace is ignored, except for strings which are kept as is, and chars
+    /// where the input is a single whitespace character. Bools are parsed from `true`, `false`,
+    /// `1`, or `0`, ignoring case.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mirror_mirror::Value;
+    /// use mirror_mirror::type_info::ScalarType;
+    ///
+    /// assert_eq!(ScalarType::f32.parse_str(" 3.5 "), Ok(Value::from(3.5_f32)));
+    /// assert_eq!(ScalarType::bool.parse_str("True"), Ok(Value::from(true)));
+    /// assert!(ScalarType::u8.parse_str("256").is_err());
+    /// ```
+    pub fn parse_str(self, input: &str) -> Result<Value, ParseScalarError> {
+        let invalid = || ParseScalarError::Invalid {
+            expected: self.type_name().to_owned(),
+            input: input.to_owned(),
+        };
+
+        macro_rules! parse {
+            ($ty:ty) => {
+                input.trim().parse::<$ty>().map_err(|_| invalid())?.into()
+            };
+        }
+
+        Ok(match self {
+            ScalarType::usize => parse!(usize),
+            ScalarType::u8 => parse!(u8),
+            ScalarType::u16 => parse!(u16),
+            ScalarType::u32 => parse!(u32),
+            ScalarType::u64 => parse!(u64),
+            ScalarType::u128 => parse!(u128),
+            ScalarType::i8 => parse!(i8),
+            ScalarType::i16 => parse!(i16),
+            ScalarType::i32 => parse!(i32),
+            ScalarType::i64 => parse!(i64),
+            ScalarType::i128 => parse!(i128),
+            ScalarType::f32 => parse!(f32),
+            ScalarType::f64 => parse!(f64),
+            ScalarType::bool => {
+                let input = input.trim();
+                if input.eq_ignore_ascii_case("true") || input == "1" {
+                    true.into()
+                } else if input.eq_ignore_ascii_case("false") || input == "0" {
+                    false.into()
+                } else {
+                    return Err(invalid());
+                }
+            }
+            ScalarType::char => match input.parse::<char>() {
+                Ok(c) => c.into(),
+                Err(_) => parse!(char),
+            },
+            ScalarType::String => input.to_owned().into(),
+        })
+    }
+}
+
+impl TypeDescriptor {
+    /// Parse text into a value of the type at `key_path`.
+    ///
+    /// Scalars are parsed with [`ScalarType::parse_str`]. For enums the text is the name of a
+    /// variant, whose fields get their default values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mirror_mirror::{key_path, DescribeType, FromReflect, Reflect};
+    ///
+    /// #[derive(Reflect, Clone, Debug, PartialEq)]
+    /// struct Settings {
+    ///     volume: f32,
+    ///     quality: Quality,
+    /// }
+    ///
+    /// #[derive(Reflect, Clone, Debug, PartialEq)]
+    /// enum Quality {
+    ///     Low,
+    ///     High,
+    /// }
+    ///
+    /// let descriptor = <Settings as DescribeType>::type_descriptor();
+    ///
+    /// let volume = descriptor.parse_at(&key_path!(.volume), "0.5").unwrap();
+    /// assert_eq!(f32::from_reflect(&volume), Some(0.5));
+    ///
+    /// let quality = descriptor.parse_at(&key_path!(.quality), "High").unwrap();
+    /// assert_eq!(Quality::from_reflect(&quality), Some(Quality::High));
+    /// ```
+    pub fn parse_at(&self, key_path: &KeyPath, input: &str) -> Result<Value, ParseScalarError> {
+        let ty = self
+            .type_at(key_path)
+            .and_then(|ty| ty.as_type())
+            .ok_or_else(|| ParseScalarError::UnknownPath(key_path.clone()))?;
+
+        match ty {
+            Type::Scalar(scalar_type) => scalar_type.parse_str(input),
+            Type::Enum(enum_type) => {
+                let name = input.trim();
+                let variant = enum_type
+                    .build_variant(name)
+                    .ok_or_else(|| ParseScalarError::UnknownVariant(name.to_owned()))?;
+                let value = variant
+                    .finish()
+                    .map_err(|_| ParseScalarError::MissingDefault(name.to_owned()))?;
+                Ok(value.into())
+            }
+            Type::Struct(_)
+            | Type::TupleStruct(_)
+            | Type::Tuple(_)
+            | Type::List(_)
+            | Type::Array(_)
+            | Type::Map(_)
+            | Type::Set(_)
+            | Type::Opaque(_) => Err(ParseScalarError::Unsupported(ty.type_name().to_owned())),
+        }
+    }
+}
+
+/// Error returned by [`ScalarType::parse_str`] and [`TypeDescriptor::parse_at`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParseScalarError {
+    /// The input isn't a valid value of the expected type.
+    Invalid {
+        /// The type name of the expected scalar.
+        expected: String,
+        input: String,
+    },
+    /// The enum has no variant with this name.
+    UnknownVariant(String),
+    /// A field of the variant with this name has no default value.
+    MissingDefault(String),
+    /// The type has no value at the key path.
+    UnknownPath(KeyPath),
+    /// Values of the type with this name can't be parsed from text.
+    Unsupported(String),
+}
+
+impl fmt::Display for ParseScalarError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Invalid { expected, input } => {
+                write!(f, "`{input}` is not a valid `{expected}`")
+            }
+            Self::UnknownVariant(name) => write!(f, "unknown variant `{name}`"),
+            Self::MissingDefault(name) => {
+                write!(f, "variant `{name}` has fields without a default value")
+            }
+            Self::UnknownPath(key_path) => write!(f, "no type at `{key_path}`"),
+            Self::Unsupported(type_name) => write!(f, "can't parse `{type_name}` from text"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseScalarError {}
@@ -0,0 +1,200 @@
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+use super::NodeId;
+use super::ScalarType;
+use super::Type;
+use super::VariantField;
+
+/// A hash of the shape of a type that is stable across compilations and platforms.
+///
+/// Includes type names, field names, variant names, and array lengths, of the type itself and
+/// all types it contains. Metadata and docs are not included.
+#[cfg_attr(not(feature = "speedy"), allow(dead_code))]
+pub(crate) fn stable_hash(ty: Type<'_>) -> u64 {
+    StableHashes::default().hash(ty)
+}
+
+/// Perturb a hash, for giving different types whose hashes collide distinct ids.
+pub(crate) fn rehash(hash: u64) -> u64 {
+    let mut hasher = StableHasher::new();
+    hasher.write_u64(hash);
+    hasher.finish()
+}
+
+/// Computes [`stable_hash`]es of several types in the same graph, reusing the hashes of the types
+/// they have in common.
+///
+/// A type's hash combines the hashes of the types it contains, so every type is only hashed
+/// once. Recursive types are the exception as they're hashed again for each type in the cycle.
+#[derive(Default)]
+pub(crate) struct StableHashes<'a> {
+    memo: BTreeMap<NodeId, u64>,
+    // the names of the types currently being hashed, outermost first
+    stack: Vec<&'a str>,
+}
+
+impl<'a> StableHashes<'a> {
+    pub(crate) fn hash(&mut self, ty: Type<'a>) -> u64 {
+        self.hash_at(ty).0
+    }
+
+    // returns the hash and whether the type refers back to a type that is being hashed
+    fn hash_at(&mut self, ty: Type<'a>) -> (u64, bool) {
+        let id = ty.node_id();
+        if let Some(hash) = id.and_then(|id| self.memo.get(&id)) {
+            return (*hash, false);
+        }
+
+        let mut hasher = StableHasher::new();
+
+        // recursive types are only expanded once
+        if self.stack.contains(&ty.type_name()) {
+            hasher.write_u8(u8::MAX);
+            hasher.write_str(ty.type_name());
+            return (hasher.finish(), true);
+        }
+
+        self.stack.push(ty.type_name());
+        let mut recursive = false;
+        let mut child = |this: &mut Self, hasher: &mut StableHasher, ty: Type<'a>| {
+            let (hash, child_recursive) = this.hash_at(ty);
+            recursive |= child_recursive;
+            hasher.write_u64(hash);
+        };
+
+        hasher.write_str(ty.type_name());
+        match ty {
+            Type::Struct(struct_) => {
+                hasher.write_u8(0);
+                for field in struct_.field_types() {
+                    hasher.write_str(field.name());
+                    child(self, &mut hasher, field.get_type());
+                }
+            }
+            Type::TupleStruct(tuple_struct) => {
+                hasher.write_u8(1);
+                for field in tuple_struct.field_types() {
+                    child(self, &mut hasher, field.get_type());
+                }
+            }
+            Type::Tuple(tuple) => {
+                hasher.write_u8(2);
+                for field in tuple.field_types() {
+                    child(self, &mut hasher, field.get_type());
+                }
+            }
+            Type::Enum(enum_) => {
+                hasher.write_u8(3);
+                for variant in enum_.variants() {
+                    hasher.write_str(variant.name());
+                    for field in variant.field_types() {
+                        if let VariantField::Named(field) = field {
+                            hasher.write_str(field.name());
+                        }
+                        child(self, &mut hasher, field.get_type());
+                    }
+                }
+            }
+            Type::List(list) => {
+                hasher.write_u8(4);
+                child(self, &mut hasher, list.element_type());
+            }
+            Type::Array(array) => {
+                hasher.write_u8(5);
+                hasher.write_u64(array.len() as u64);
+                child(self, &mut hasher, array.element_type());
+            }
+            Type::Map(map) => {
+                hasher.write_u8(6);
+                child(self, &mut hasher, map.key_type());
+                child(self, &mut hasher, map.value_type());
+            }
+            Type::Scalar(scalar) => {
+                hasher.write_u8(7);
+                hasher.write_u8(match scalar {
+                    ScalarType::usize => 0,
+                    ScalarType::u8 => 1,
+                    ScalarType::u16 => 2,
+                    ScalarType::u32 => 3,
+                    ScalarType::u64 => 4,
+                    ScalarType::u128 => 5,
+                    ScalarType::i8 => 6,
+                    ScalarType::i16 => 7,
+                    ScalarType::i32 => 8,
+                    ScalarType::i64 => 9,
+                    ScalarType::i128 => 10,
+                    ScalarType::bool => 11,
+                    ScalarType::char => 12,
+                    ScalarType::f32 => 13,
+                    ScalarType::f64 => 14,
+                    ScalarType::String => 15,
+                });
+            }
+            Type::Opaque(_) => {
+                hasher.write_u8(8);
+            }
+            Type::Set(set) => {
+                hasher.write_u8(9);
+                child(self, &mut hasher, set.element_type());
+            }
+        }
+        self.stack.pop();
+
+        // the hashes of recursive types depend on where in the cycle hashing started, so only
+        // those of types that aren't part of a cycle are reused
+        let hash = hasher.finish();
+        if !recursive {
+            if let Some(id) = id {
+                self.memo.insert(id, hash);
+            }
+        }
+        (hash, recursive)
+    }
+}
+
+// 64 bit FNV-1a. Used rather than `core::hash::Hasher` implementations from other crates since
+// those don't promise stable output
+pub(crate) struct StableHasher(u64);
+
+impl StableHasher {
+    pub(crate) fn new() -> Self {
+        Self(0xcbf29ce484222325)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(0x100000001b3);
+        }
+    }
+
+    fn write_u8(&mut self, n: u8) {
+        self.write(&[n]);
+    }
+
+    fn write_u64(&mut self, n: u64) {
+        self.write(&n.to_le_bytes());
+    }
+
+    fn write_str(&mut self, s: &str) {
+        // length prefixed so adjacent strings can't run together
+        self.write_u64(s.len() as u64);
+        self.write(s.as_bytes());
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+// `reflect_hash` writes everything as little endian bytes, so this is stable as well
+impl core::hash::Hasher for StableHasher {
+    fn finish(&self) -> u64 {
+        StableHasher::finish(self)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        StableHasher::write(self, bytes);
+    }
+}
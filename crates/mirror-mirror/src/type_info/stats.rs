@@ -0,0 +1,189 @@
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use core::mem::size_of;
+
+use super::graph::*;
+use super::TypeDescriptor;
+use crate::enum_::VariantField;
+use crate::Enum;
+use crate::Reflect;
+use crate::Struct;
+use crate::Tuple;
+use crate::TupleStruct;
+use crate::Value;
+
+/// Statistics about the size of a [`TypeDescriptor`].
+///
+/// Useful for tracking how much memory type information takes up and deciding what to strip,
+/// such as docs.
+///
+/// Constructed with [`TypeDescriptor::stats`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct DescriptorStats {
+    /// The number of nodes in the type graph. That is the number of distinct types.
+    pub nodes: usize,
+    /// The number of bytes used by type names, field names, and variant names.
+    pub strings_bytes: usize,
+    /// The number of bytes used by doc comments.
+    pub docs_bytes: usize,
+    /// The number of bytes used by metadata keys and values, including the default values of
+    /// opaque types.
+    pub meta_bytes: usize,
+}
+
+impl DescriptorStats {
+    pub(super) fn new(descriptor: &TypeDescriptor) -> Self {
+        let mut stats = Self::default();
+        for node in descriptor.graph.map.values().flatten() {
+            stats.nodes += 1;
+            stats.add_node(node);
+        }
+        stats
+    }
+
+    fn add_node(&mut self, node: &TypeNode) {
+        match node {
+            TypeNode::Struct(node) => {
+                self.strings_bytes += node.type_name.len();
+                self.add_named_fields(&node.fields, &node.field_names);
+                self.add_meta(&node.metadata);
+                self.add_docs(&node.docs);
+            }
+            TypeNode::TupleStruct(node) => {
+                self.strings_bytes += node.type_name.len();
+                self.add_unnamed_fields(&node.fields);
+                self.add_meta(&node.metadata);
+                self.add_docs(&node.docs);
+            }
+            TypeNode::Tuple(node) => {
+                self.strings_bytes += node.type_name.len();
+                self.add_unnamed_fields(&node.fields);
+                self.add_meta(&node.metadata);
+                self.add_docs(&node.docs);
+            }
+            TypeNode::Enum(node) => {
+                self.strings_bytes += node.type_name.len();
+                for variant in &node.variants {
+                    match variant {
+                        VariantNode::Struct(variant) => {
+                            self.strings_bytes += variant.name.len();
+                            self.add_named_fields(&variant.fields, &variant.field_names);
+                            self.add_meta(&variant.metadata);
+                            self.add_docs(&variant.docs);
+                        }
+                        VariantNode::Tuple(variant) => {
+                            self.strings_bytes += variant.name.len();
+                            self.add_unnamed_fields(&variant.fields);
+                            self.add_meta(&variant.metadata);
+                            self.add_docs(&variant.docs);
+                        }
+                        VariantNode::Unit(variant) => {
+                            self.strings_bytes += variant.name.len();
+                            self.add_meta(&variant.metadata);
+                            self.add_docs(&variant.docs);
+                        }
+                    }
+                }
+                self.add_meta(&node.metadata);
+                self.add_docs(&node.docs);
+            }
+            TypeNode::List(node) => {
+                self.strings_bytes += node.type_name.len();
+            }
+            TypeNode::Array(node) => {
+                self.strings_bytes += node.type_name.len();
+            }
+            TypeNode::Map(node) => {
+                self.strings_bytes += node.type_name.len();
+            }
+            TypeNode::Set(node) => {
+                self.strings_bytes += node.type_name.len();
+            }
+            TypeNode::Scalar(_) => {}
+            TypeNode::Opaque(node) => {
+                self.strings_bytes += node.type_name.len();
+                self.add_meta(&node.metadata);
+                if let Some(default_value) = &node.default_value {
+                    self.meta_bytes += value_bytes(default_value);
+                }
+            }
+        }
+    }
+
+    fn add_named_fields(&mut self, fields: &BTreeMap<Name, NamedFieldNode>, field_names: &[Name]) {
+        for (name, field) in fields {
+            self.strings_bytes += name.len() + field.name.len();
+            self.add_meta(&field.metadata);
+            self.add_docs(&field.docs);
+        }
+        self.strings_bytes += field_names.iter().map(|name| name.len()).sum::<usize>();
+    }
+
+    fn add_unnamed_fields(&mut self, fields: &[UnnamedFieldNode]) {
+        for field in fields {
+            self.add_meta(&field.metadata);
+            self.add_docs(&field.docs);
+        }
+    }
+
+    fn add_meta(&mut self, metadata: &BTreeMap<String, Value>) {
+        for (key, value) in metadata {
+            self.meta_bytes += key.len() + value_bytes(value);
+        }
+    }
+
+    fn add_docs(&mut self, docs: &[String]) {
+        self.docs_bytes += docs.iter().map(String::len).sum::<usize>();
+    }
+}
+
+/// The number of bytes used by the data in a value, not counting allocator overhead or
+/// padding.
+fn value_bytes(value: &Value) -> usize {
+    match value {
+        Value::usize(_) => size_of::<usize>(),
+        Value::u8(_) => size_of::<u8>(),
+        Value::u16(_) => size_of::<u16>(),
+        Value::u32(_) => size_of::<u32>(),
+        Value::u64(_) => size_of::<u64>(),
+        Value::u128(_) => size_of::<u128>(),
+        Value::i8(_) => size_of::<i8>(),
+        Value::i16(_) => size_of::<i16>(),
+        Value::i32(_) => size_of::<i32>(),
+        Value::i64(_) => size_of::<i64>(),
+        Value::i128(_) => size_of::<i128>(),
+        Value::bool(_) => size_of::<bool>(),
+        Value::char(_) => size_of::<char>(),
+        Value::f32(_) => size_of::<f32>(),
+        Value::f64(_) => size_of::<f64>(),
+        Value::String(inner) => inner.len(),
+        Value::StructValue(inner) => inner
+            .fields()
+            .map(|(name, value)| name.len() + reflect_bytes(value))
+            .sum(),
+        Value::EnumValue(inner) => {
+            inner.variant_name().len()
+                + inner
+                    .fields()
+                    .map(|field| match field {
+                        VariantField::Struct(name, value) => name.len() + reflect_bytes(value),
+                        VariantField::Tuple(value) => reflect_bytes(value),
+                    })
+                    .sum::<usize>()
+        }
+        Value::TupleStructValue(inner) => inner.fields().map(reflect_bytes).sum(),
+        Value::TupleValue(inner) => inner.fields().map(reflect_bytes).sum(),
+        Value::List(inner) => inner.iter().map(value_bytes).sum(),
+        Value::Map(inner) => inner
+            .iter()
+            .map(|(key, value)| value_bytes(key) + value_bytes(value))
+            .sum(),
+        Value::Set(inner) => inner.iter().map(value_bytes).sum(),
+    }
+}
+
+fn reflect_bytes(value: &dyn Reflect) -> usize {
+    value
+        .downcast_ref::<Value>()
+        .map_or_else(|| value_bytes(&value.to_value()), value_bytes)
+}
@@ -0,0 +1,615 @@
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use super::NamedField;
+use super::Type;
+use super::UnnamedField;
+use super::Variant;
+use super::VariantField;
+use crate::enum_::EnumValue;
+use crate::enum_::VariantKind;
+use crate::key_path::KeyPath;
+use crate::struct_::StructValue;
+use crate::tuple::TupleValue;
+use crate::tuple_struct::TupleStructValue;
+use crate::Reflect;
+use crate::ReflectRef;
+use crate::Value;
+
+/// A builder for values of a struct, tuple struct, tuple, or enum that is checked against its
+/// type descriptor.
+///
+/// Fields start out with their default values, if they have one. Setting fields that don't
+/// exist, choosing variants that don't exist, or setting fields to values of the wrong shape is
+/// an error, rather than something that is only noticed when converting the value with
+/// [`FromReflect`].
+///
+/// Values of enums need a variant, chosen with [`with_variant`](Self::with_variant), before
+/// their fields can be set.
+///
+/// Constructed with [`TypeDescriptor::value_builder`].
+///
+/// # Example
+///
+/// ```
+/// use mirror_mirror::{key_path, DescribeType, FromReflect, Reflect};
+/// use mirror_mirror::type_info::ShapeError;
+///
+/// #[derive(Reflect, Debug, Clone, PartialEq)]
+/// struct Player {
+///     name: String,
+///     health: u32,
+/// }
+///
+/// let descriptor = <Player as DescribeType>::type_descriptor();
+///
+/// let value = descriptor
+///     .value_builder()
+///     .unwrap()
+///     .with_field("name", "Bob")
+///     .unwrap()
+///     .finish()
+///     .unwrap();
+///
+/// assert_eq!(
+///     Player::from_reflect(&value).unwrap(),
+///     Player { name: "Bob".to_owned(), health: 0 },
+/// );
+///
+/// // fields are checked when they're set
+/// let builder = descriptor.value_builder().unwrap();
+/// assert_eq!(
+///     builder.clone().with_field("mana", 10_u32).unwrap_err(),
+///     ShapeError::UnknownField { path: key_path!(.mana) },
+/// );
+/// assert_eq!(
+///     builder.with_field("health", -1_i32).unwrap_err(),
+///     ShapeError::TypeMismatch {
+///         path: key_path!(.health),
+///         expected: "u32".to_owned(),
+///         found: "i32".to_owned(),
+///     },
+/// );
+/// ```
+///
+/// [`FromReflect`]: crate::FromReflect
+/// [`TypeDescriptor::value_builder`]: super::TypeDescriptor::value_builder
+#[derive(Debug, Clone)]
+pub struct ValueBuilder<'a> {
+    ty: Type<'a>,
+    variant: Option<Variant<'a>>,
+    fields: Vec<BuilderField<'a>>,
+}
+
+#[derive(Debug, Clone)]
+struct BuilderField<'a> {
+    name: Option<&'a str>,
+    ty: Type<'a>,
+    value: Option<Value>,
+}
+
+impl<'a> BuilderField<'a> {
+    fn named(field: NamedField<'a>) -> Self {
+        Self {
+            name: Some(field.name()),
+            ty: field.get_type(),
+            value: field.default_value(),
+        }
+    }
+
+    fn unnamed(field: UnnamedField<'a>) -> Self {
+        Self {
+            name: None,
+            ty: field.get_type(),
+            value: field.default_value(),
+        }
+    }
+
+    fn variant(field: VariantField<'a>) -> Self {
+        match field {
+            VariantField::Named(field) => Self::named(field),
+            VariantField::Unnamed(field) => Self::unnamed(field),
+        }
+    }
+}
+
+impl<'a> ValueBuilder<'a> {
+    pub(super) fn new(ty: Type<'a>) -> Option<Self> {
+        let fields = match ty {
+            Type::Struct(struct_type) => {
+                struct_type.field_types().map(BuilderField::named).collect()
+            }
+            Type::TupleStruct(tuple_struct_type) => tuple_struct_type
+                .field_types()
+                .map(BuilderField::unnamed)
+                .collect(),
+            Type::Tuple(tuple_type) => tuple_type
+                .field_types()
+                .map(BuilderField::unnamed)
+                .collect(),
+            Type::Enum(_) => Vec::new(),
+            Type::List(_)
+            | Type::Array(_)
+            | Type::Map(_)
+            | Type::Set(_)
+            | Type::Scalar(_)
+            | Type::Opaque(_) => return None,
+        };
+        Some(Self {
+            ty,
+            variant: None,
+            fields,
+        })
+    }
+
+    /// The type being built.
+    pub fn get_type(&self) -> Type<'a> {
+        self.ty
+    }
+
+    /// The variant being built, if the type is an enum and a variant has been chosen.
+    pub fn variant(&self) -> Option<Variant<'a>> {
+        self.variant
+    }
+
+    /// Choose the variant of an enum by name.
+    ///
+    /// Resets the fields to their default values.
+    pub fn with_variant(mut self, name: &str) -> Result<Self, ShapeError> {
+        self.set_variant(name)?;
+        Ok(self)
+    }
+
+    /// Choose the variant of an enum by name.
+    ///
+    /// Resets the fields to their default values.
+    pub fn set_variant(&mut self, name: &str) -> Result<(), ShapeError> {
+        let variant = self
+            .ty
+            .as_enum()
+            .and_then(|enum_type| enum_type.variant(name))
+            .ok_or_else(|| ShapeError::UnknownVariant {
+                path: KeyPath::default(),
+                name: name.into(),
+            })?;
+        self.fields = variant.field_types().map(BuilderField::variant).collect();
+        self.variant = Some(variant);
+        Ok(())
+    }
+
+    /// Set a named field.
+    pub fn with_field(mut self, name: &str, value: impl Into<Value>) -> Result<Self, ShapeError> {
+        self.set_field(name, value)?;
+        Ok(self)
+    }
+
+    /// Set a named field.
+    pub fn set_field(&mut self, name: &str, value: impl Into<Value>) -> Result<(), ShapeError> {
+        let mut path = self.variant_path()?;
+        path.push_field(name);
+        let index = self
+            .fields
+            .iter()
+            .position(|field| field.name == Some(name))
+            .ok_or(ShapeError::UnknownField { path })?;
+        self.set_field_at(index, value)
+    }
+
+    /// Set a field by its index, in declaration order.
+    pub fn with_field_at(
+        mut self,
+        index: usize,
+        value: impl Into<Value>,
+    ) -> Result<Self, ShapeError> {
+        self.set_field_at(index, value)?;
+        Ok(self)
+    }
+
+    /// Set a field by its index, in declaration order.
+    pub fn set_field_at(
+        &mut self,
+        index: usize,
+        value: impl Into<Value>,
+    ) -> Result<(), ShapeError> {
+        let mut path = self.variant_path()?;
+        let Some(field) = self.fields.get_mut(index) else {
+            path.push_field(index);
+            return Err(ShapeError::UnknownField { path });
+        };
+        match field.name {
+            Some(name) => path.push_field(name),
+            None => path.push_field(index),
+        }
+
+        let value = value.into();
+        let mut errors = Vec::new();
+        check(field.ty, value.as_reflect(), &mut path, &mut errors);
+        if let Some(error) = errors.into_iter().next() {
+            return Err(error);
+        }
+
+        field.value = Some(value);
+        Ok(())
+    }
+
+    /// Build the value.
+    ///
+    /// Fails if a field without a default value was never set, or if no variant was chosen for
+    /// an enum.
+    pub fn finish(self) -> Result<Value, ShapeError> {
+        let path = self.variant_path()?;
+        let Self {
+            ty,
+            variant,
+            fields,
+        } = self;
+
+        let mut values = Vec::with_capacity(fields.len());
+        for (index, field) in fields.into_iter().enumerate() {
+            let Some(value) = field.value else {
+                let mut path = path;
+                match field.name {
+                    Some(name) => path.push_field(name),
+                    None => path.push_field(index),
+                }
+                return Err(ShapeError::MissingField { path });
+            };
+            values.push((field.name, value));
+        }
+
+        let value = match (ty, variant) {
+            (Type::Struct(struct_type), _) => {
+                let mut value = StructValue::with_capacity(values.len())
+                    .with_type_name(struct_type.type_name().to_owned());
+                for (name, field_value) in values {
+                    value.set_field(name.unwrap_or_default(), field_value);
+                }
+                value.into()
+            }
+            (Type::TupleStruct(tuple_struct_type), _) => {
+                let mut value = TupleStructValue::with_capacity(values.len())
+                    .with_type_name(tuple_struct_type.type_name().to_owned());
+                for (_, field_value) in values {
+                    value.push_field(field_value);
+                }
+                value.into()
+            }
+            (Type::Tuple(_), _) => {
+                let mut value = TupleValue::with_capacity(values.len());
+                for (_, field_value) in values {
+                    value.push_field(field_value);
+                }
+                value.into()
+            }
+            (_, Some(variant)) => {
+                let type_name = variant.type_name();
+                let mut value = match variant {
+                    Variant::Struct(variant) => {
+                        let mut value = EnumValue::new_struct_variant_with_capacity(
+                            variant.name(),
+                            values.len(),
+                        )
+                        .with_type_name(type_name.to_owned());
+                        for (name, field_value) in values {
+                            value.set_struct_field(name.unwrap_or_default(), field_value);
+                        }
+                        value.finish()
+                    }
+                    Variant::Tuple(variant) => {
+                        let mut value = EnumValue::new_tuple_variant_with_capacity(
+                            variant.name(),
+                            values.len(),
+                        )
+                        .with_type_name(type_name.to_owned());
+                        for (_, field_value) in values {
+                            value.push_tuple_field(field_value);
+                        }
+                        value.finish()
+                    }
+                    Variant::Unit(variant) => EnumValue::new_unit_variant(variant.name())
+                        .with_type_name(type_name.to_owned()),
+                };
+                value.set_discriminant(variant.discriminant());
+                value.into()
+            }
+            (_, None) => unreachable!("`variant_path` checks that enums have a variant"),
+        };
+        Ok(value)
+    }
+
+    /// The path fields are relative to, which is the chosen variant for enums.
+    fn variant_path(&self) -> Result<KeyPath, ShapeError> {
+        let mut path = KeyPath::default();
+        if let Type::Enum(_) = self.ty {
+            let variant = self.variant.ok_or(ShapeError::MissingVariant {
+                path: KeyPath::default(),
+            })?;
+            path.push_variant(variant.name());
+        }
+        Ok(path)
+    }
+}
+
+/// A difference between the shape of a value and the type it's supposed to have.
+///
+/// Returned by [`ValueBuilder`] and by checks such as [`StructValue::validate_against`]. Paths
+/// are relative to the value being built or checked.
+///
+/// [`StructValue::validate_against`]: crate::struct_::StructValue::validate_against
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ShapeError {
+    /// The type has no field at this path.
+    UnknownField { path: KeyPath },
+    /// The field at this path is set more than once.
+    DuplicateField { path: KeyPath },
+    /// The field at this path is missing, or has no default value and was never set.
+    MissingField { path: KeyPath },
+    /// The enum at `path` has no variant called `name`.
+    UnknownVariant { path: KeyPath, name: String },
+    /// No variant was chosen for the enum at this path.
+    MissingVariant { path: KeyPath },
+    /// The value at `path` isn't of the type `expected`.
+    TypeMismatch {
+        path: KeyPath,
+        expected: String,
+        found: String,
+    },
+}
+
+impl ShapeError {
+    /// The path of the value with the wrong shape.
+    pub fn path(&self) -> &KeyPath {
+        match self {
+            ShapeError::UnknownField { path }
+            | ShapeError::DuplicateField { path }
+            | ShapeError::MissingField { path }
+            | ShapeError::UnknownVariant { path, .. }
+            | ShapeError::MissingVariant { path }
+            | ShapeError::TypeMismatch { path, .. } => path,
+        }
+    }
+}
+
+impl fmt::Display for ShapeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShapeError::UnknownField { path } => return write!(f, "unknown field `{path}`"),
+            ShapeError::DuplicateField { path } => {
+                return write!(f, "field `{path}` is set more than once")
+            }
+            ShapeError::MissingField { path } => return write!(f, "missing field `{path}`"),
+            ShapeError::UnknownVariant { name, .. } => write!(f, "unknown variant `{name}`")?,
+            ShapeError::MissingVariant { .. } => f.write_str("no variant chosen")?,
+            ShapeError::TypeMismatch {
+                expected, found, ..
+            } => write!(f, "expected `{expected}`, found `{found}`")?,
+        }
+        let path = self.path();
+        if !path.is_empty() {
+            write!(f, " at `{path}`")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ShapeError {}
+
+/// Check that `value` has the shape of `ty`, collecting every difference.
+///
+/// Opaque types are assumed to match since there is nothing to compare them against.
+pub(crate) fn validate_shape(ty: Type<'_>, value: &dyn Reflect) -> Result<(), Vec<ShapeError>> {
+    let mut errors = Vec::new();
+    check(ty, value, &mut KeyPath::default(), &mut errors);
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+fn check(ty: Type<'_>, value: &dyn Reflect, path: &mut KeyPath, errors: &mut Vec<ShapeError>) {
+    let mismatch = |path: &KeyPath| ShapeError::TypeMismatch {
+        path: path.clone(),
+        expected: ty.type_name().into(),
+        found: value.type_name().into(),
+    };
+
+    match (ty, value.reflect_ref()) {
+        (Type::Struct(struct_type), ReflectRef::Struct(struct_)) => {
+            check_named(
+                struct_type.field_types().collect(),
+                struct_.fields(),
+                path,
+                errors,
+            );
+        }
+        (Type::TupleStruct(tuple_struct_type), ReflectRef::TupleStruct(tuple_struct)) => {
+            check_unnamed(
+                tuple_struct_type
+                    .field_types()
+                    .map(|field| field.get_type())
+                    .collect(),
+                tuple_struct.fields(),
+                path,
+                errors,
+            );
+        }
+        (Type::Tuple(tuple_type), ReflectRef::Tuple(tuple)) => {
+            check_unnamed(
+                tuple_type
+                    .field_types()
+                    .map(|field| field.get_type())
+                    .collect(),
+                tuple.fields(),
+                path,
+                errors,
+            );
+        }
+        (Type::Enum(enum_type), ReflectRef::Enum(enum_)) => {
+            let Some(variant) = enum_type.variant(enum_.variant_name()) else {
+                errors.push(ShapeError::UnknownVariant {
+                    path: path.clone(),
+                    name: enum_.variant_name().into(),
+                });
+                return;
+            };
+            path.push_variant(variant.name());
+            match (variant, enum_.variant_kind()) {
+                (Variant::Struct(variant), VariantKind::Struct) => {
+                    let fields = (0..enum_.fields_len())
+                        .filter_map(|index| Some((enum_.name_at(index)?, enum_.field_at(index)?)));
+                    check_named(variant.field_types().collect(), fields, path, errors);
+                }
+                (Variant::Tuple(variant), VariantKind::Tuple) => {
+                    let fields = (0..enum_.fields_len()).filter_map(|index| enum_.field_at(index));
+                    check_unnamed(
+                        variant
+                            .field_types()
+                            .map(|field| field.get_type())
+                            .collect(),
+                        fields,
+                        path,
+                        errors,
+                    );
+                }
+                (Variant::Unit(_), VariantKind::Unit) => {}
+                _ => errors.push(mismatch(path)),
+            }
+            path.pop();
+        }
+        (Type::Array(array_type), ReflectRef::Array(array)) => {
+            if array.len() == array_type.len() {
+                check_elements(array_type.element_type(), array.iter(), path, errors);
+            } else {
+                errors.push(mismatch(path));
+            }
+        }
+        (Type::Array(array_type), ReflectRef::List(list)) => {
+            if list.len() == array_type.len() {
+                check_elements(array_type.element_type(), list.iter(), path, errors);
+            } else {
+                errors.push(mismatch(path));
+            }
+        }
+        (Type::List(list_type), ReflectRef::List(list)) => {
+            check_elements(list_type.element_type(), list.iter(), path, errors);
+        }
+        (Type::Map(map_type), ReflectRef::Map(map)) => {
+            for (key, value) in map.iter() {
+                // keys don't have paths of their own so report them at the map
+                let mut key_errors = Vec::new();
+                check(
+                    map_type.key_type(),
+                    key,
+                    &mut KeyPath::default(),
+                    &mut key_errors,
+                );
+                if !key_errors.is_empty() {
+                    errors.push(ShapeError::TypeMismatch {
+                        path: path.clone(),
+                        expected: map_type.key_type().type_name().into(),
+                        found: key.type_name().into(),
+                    });
+                }
+                path.push_get(key.to_value());
+                check(map_type.value_type(), value, path, errors);
+                path.pop();
+            }
+        }
+        (Type::Set(set_type), ReflectRef::Set(set)) => {
+            for element in set.iter() {
+                let mut element_errors = Vec::new();
+                check(
+                    set_type.element_type(),
+                    element,
+                    &mut KeyPath::default(),
+                    &mut element_errors,
+                );
+                if !element_errors.is_empty() {
+                    errors.push(ShapeError::TypeMismatch {
+                        path: path.clone(),
+                        expected: set_type.element_type().type_name().into(),
+                        found: element.type_name().into(),
+                    });
+                }
+            }
+        }
+        (Type::Scalar(scalar_type), ReflectRef::Scalar(scalar)) => {
+            if scalar.as_reflect().type_name() != scalar_type.type_name() {
+                errors.push(mismatch(path));
+            }
+        }
+        (Type::Opaque(_), _) => {}
+        _ => errors.push(mismatch(path)),
+    }
+}
+
+fn check_named<'v>(
+    field_types: Vec<NamedField<'_>>,
+    fields: impl Iterator<Item = (&'v str, &'v dyn Reflect)>,
+    path: &mut KeyPath,
+    errors: &mut Vec<ShapeError>,
+) {
+    let mut seen = Vec::<&str>::new();
+    for (name, value) in fields {
+        path.push_field(name);
+        if seen.contains(&name) {
+            errors.push(ShapeError::DuplicateField { path: path.clone() });
+        } else if let Some(field) = field_types.iter().find(|field| field.name() == name) {
+            check(field.get_type(), value, path, errors);
+        } else {
+            errors.push(ShapeError::UnknownField { path: path.clone() });
+        }
+        seen.push(name);
+        path.pop();
+    }
+
+    for field in field_types {
+        if !seen.contains(&field.name()) {
+            path.push_field(field.name());
+            errors.push(ShapeError::MissingField { path: path.clone() });
+            path.pop();
+        }
+    }
+}
+
+fn check_unnamed<'v>(
+    field_types: Vec<Type<'_>>,
+    fields: impl Iterator<Item = &'v dyn Reflect>,
+    path: &mut KeyPath,
+    errors: &mut Vec<ShapeError>,
+) {
+    let mut len = 0;
+    for (index, value) in fields.enumerate() {
+        path.push_field(index);
+        match field_types.get(index) {
+            Some(ty) => check(*ty, value, path, errors),
+            None => errors.push(ShapeError::UnknownField { path: path.clone() }),
+        }
+        path.pop();
+        len = index + 1;
+    }
+
+    for index in len..field_types.len() {
+        path.push_field(index);
+        errors.push(ShapeError::MissingField { path: path.clone() });
+        path.pop();
+    }
+}
+
+fn check_elements<'v>(
+    ty: Type<'_>,
+    elements: impl Iterator<Item = &'v dyn Reflect>,
+    path: &mut KeyPath,
+    errors: &mut Vec<ShapeError>,
+) {
+    for (index, element) in elements.enumerate() {
+        path.push_get(index);
+        check(ty, element, path, errors);
+        path.pop();
+    }
+}
@@ -0,0 +1,176 @@
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::fmt;
+use core::mem::discriminant;
+
+use super::Type;
+use super::Variant;
+use super::VariantField;
+use crate::enum_::EnumValue;
+use crate::Value;
+
+/// A builder for [`EnumValue`]s that is checked against a [`Variant`] descriptor.
+///
+/// Fields start out with their default values, if they have one. Setting fields that don't exist
+/// on the variant, or setting scalar fields to values of the wrong type, is an error.
+///
+/// Constructed with [`EnumType::build_variant`].
+///
+/// [`EnumType::build_variant`]: super::EnumType::build_variant
+#[derive(Debug, Clone)]
+pub struct VariantBuilder<'a> {
+    variant: Variant<'a>,
+    fields: Vec<Option<Value>>,
+}
+
+impl<'a> VariantBuilder<'a> {
+    pub(super) fn new(variant: Variant<'a>) -> Self {
+        let fields = variant
+            .field_types()
+            .map(|field| field.get_type().default_value())
+            .collect();
+        Self { variant, fields }
+    }
+
+    /// The variant being built.
+    pub fn variant(&self) -> Variant<'a> {
+        self.variant
+    }
+
+    /// Set a field of a struct variant by name.
+    pub fn with_field(
+        mut self,
+        name: &str,
+        value: impl Into<Value>,
+    ) -> Result<Self, VariantBuilderError> {
+        self.set_field(name, value)?;
+        Ok(self)
+    }
+
+    /// Set a field of a struct variant by name.
+    pub fn set_field(
+        &mut self,
+        name: &str,
+        value: impl Into<Value>,
+    ) -> Result<(), VariantBuilderError> {
+        let index = self
+            .variant
+            .field_types()
+            .position(|field| field.name() == Some(name))
+            .ok_or_else(|| VariantBuilderError::UnknownField(name.into()))?;
+        self.set_field_at(index, value)
+    }
+
+    /// Set a field of a struct or tuple variant by index.
+    pub fn with_field_at(
+        mut self,
+        index: usize,
+        value: impl Into<Value>,
+    ) -> Result<Self, VariantBuilderError> {
+        self.set_field_at(index, value)?;
+        Ok(self)
+    }
+
+    /// Set a field of a struct or tuple variant by index.
+    pub fn set_field_at(
+        &mut self,
+        index: usize,
+        value: impl Into<Value>,
+    ) -> Result<(), VariantBuilderError> {
+        let field = self
+            .variant
+            .field_type_at(index)
+            .ok_or(VariantBuilderError::IndexOutOfBounds(index))?;
+        let value = value.into();
+
+        if let Type::Scalar(scalar) = field.get_type() {
+            let expected = Type::Scalar(scalar).default_value();
+            if expected.map_or(false, |expected| {
+                discriminant(&expected) != discriminant(&value)
+            }) {
+                return Err(VariantBuilderError::TypeMismatch(field_key(field, index)));
+            }
+        }
+
+        self.fields[index] = Some(value);
+        Ok(())
+    }
+
+    /// Build the value.
+    ///
+    /// Fails if a field without a default value was never set.
+    pub fn finish(self) -> Result<EnumValue, VariantBuilderError> {
+        let Self { variant, fields } = self;
+        let type_name = variant.type_name();
+
+        let mut value = match variant {
+            Variant::Struct(variant) => {
+                let mut value = EnumValue::new_struct_variant_with_capacity(
+                    variant.name(),
+                    variant.fields_len(),
+                )
+                .with_type_name(type_name.to_owned());
+                for (field, field_value) in variant.field_types().zip(fields) {
+                    let field_value = field_value
+                        .ok_or_else(|| VariantBuilderError::MissingField(field.name().into()))?;
+                    value.set_struct_field(field.name(), field_value);
+                }
+                value.finish()
+            }
+            Variant::Tuple(variant) => {
+                let mut value = EnumValue::new_tuple_variant_with_capacity(
+                    variant.name(),
+                    variant.fields_len(),
+                )
+                .with_type_name(type_name.to_owned());
+                for (index, field_value) in fields.into_iter().enumerate() {
+                    let field_value = field_value
+                        .ok_or_else(|| VariantBuilderError::MissingField(index.to_string()))?;
+                    value.push_tuple_field(field_value);
+                }
+                value.finish()
+            }
+            Variant::Unit(variant) => {
+                EnumValue::new_unit_variant(variant.name()).with_type_name(type_name.to_owned())
+            }
+        };
+        value.set_discriminant(variant.discriminant());
+        Ok(value)
+    }
+}
+
+fn field_key(field: VariantField<'_>, index: usize) -> String {
+    field.name().map_or_else(|| index.to_string(), Into::into)
+}
+
+/// Error returned by [`VariantBuilder`].
+///
+/// Fields are identified by their name or, for tuple variants, their index.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum VariantBuilderError {
+    /// The variant has no field with this name.
+    UnknownField(String),
+    /// The variant has fewer fields than the index.
+    IndexOutOfBounds(usize),
+    /// The value has a different type than the field.
+    TypeMismatch(String),
+    /// The field has no default value and was never set.
+    MissingField(String),
+}
+
+impl fmt::Display for VariantBuilderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownField(name) => write!(f, "unknown field `{name}`"),
+            Self::IndexOutOfBounds(index) => write!(f, "no field at index {index}"),
+            Self::TypeMismatch(field) => write!(f, "wrong type for field `{field}`"),
+            Self::MissingField(field) => write!(f, "field `{field}` has no default value"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for VariantBuilderError {}
@@ -0,0 +1,188 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::meta::known::Tooltip;
+use crate::type_info::GetMeta;
+use crate::type_info::NamedField;
+use crate::Reflect;
+use crate::ReflectRef;
+use crate::ScalarRef;
+
+/// Hints for how an inspector should lay out and edit a field, read from its metadata.
+///
+/// The following `#[reflect(meta(...))]` keys are recognized:
+///
+/// - `group`: a string naming the section the field belongs to.
+/// - `order`: an integer used to sort fields, lower first.
+/// - `tooltip`: a string shown on hover. Falls back to the field's doc comment.
+/// - `widget`: a string naming the [`Widget`] used to edit the field.
+/// - `min`, `max`, and `step`: numbers bounding a `"slider"` widget.
+///
+/// Numbers may be of any integer or float type.
+///
+/// # Example
+///
+/// ```
+/// use mirror_mirror::{DescribeType, Reflect};
+/// use mirror_mirror::ui_hints::{UiHints, Widget};
+///
+/// #[derive(Reflect, Clone, Debug)]
+/// struct Light {
+///     /// How bright the light is.
+///     #[reflect(meta(group = "Appearance", order = 1, widget = "slider", min = 0, max = 10))]
+///     intensity: f32,
+///     #[reflect(meta(widget = "color"))]
+///     color: [f32; 3],
+/// }
+///
+/// let descriptor = <Light as DescribeType>::type_descriptor();
+/// let struct_type = descriptor.as_struct().unwrap();
+///
+/// let hints = UiHints::for_field(struct_type.field_type("intensity").unwrap());
+/// assert_eq!(hints.group.as_deref(), Some("Appearance"));
+/// assert_eq!(hints.order, Some(1));
+/// assert_eq!(hints.tooltip.as_deref(), Some("How bright the light is."));
+/// assert_eq!(
+///     hints.widget,
+///     Some(Widget::Slider {
+///         min: Some(0.0),
+///         max: Some(10.0),
+///         step: None,
+///     }),
+/// );
+///
+/// let hints = UiHints::for_field(struct_type.field_type("color").unwrap());
+/// assert_eq!(hints.widget, Some(Widget::Color));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+#[non_exhaustive]
+pub struct UiHints {
+    pub group: Option<String>,
+    pub order: Option<i64>,
+    pub tooltip: Option<String>,
+    pub widget: Option<Widget>,
+}
+
+impl UiHints {
+    /// Read the hints for a struct field.
+    pub fn for_field(field: NamedField<'_>) -> Self {
+        Self::from_meta(field)
+    }
+
+    /// Read the hints from anything that has metadata, such as types, variants, and tuple
+    /// fields.
+    pub fn from_meta<'a, M>(meta: M) -> Self
+    where
+        M: GetMeta<'a> + Copy,
+    {
+        let tooltip = meta.typed_meta::<Tooltip>().or_else(|| {
+            let docs = meta.docs();
+            if docs.is_empty() {
+                None
+            } else {
+                Some(
+                    docs.iter()
+                        .map(|line| line.trim())
+                        .collect::<Vec<_>>()
+                        .join("\n"),
+                )
+            }
+        });
+
+        let widget = meta.get_meta::<String>("widget").map(|name| match &*name {
+            "slider" => Widget::Slider {
+                min: meta.meta("min").and_then(as_f64),
+                max: meta.meta("max").and_then(as_f64),
+                step: meta.meta("step").and_then(as_f64),
+            },
+            "checkbox" => Widget::Checkbox,
+            "text" => Widget::Text,
+            "multiline" => Widget::Multiline,
+            "color" => Widget::Color,
+            "dropdown" => Widget::Dropdown,
+            "hidden" => Widget::Hidden,
+            _ => Widget::Custom(name),
+        });
+
+        Self {
+            group: meta.get_meta::<String>("group"),
+            order: meta.meta("order").and_then(as_i64),
+            tooltip,
+            widget,
+        }
+    }
+}
+
+/// The kind of widget an inspector should use to edit a field.
+///
+/// Set with `#[reflect(meta(widget = "..."))]`, using the name in the docs of each variant.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum Widget {
+    /// `"slider"`, bounded by the `min`, `max`, and `step` meta.
+    Slider {
+        min: Option<f64>,
+        max: Option<f64>,
+        step: Option<f64>,
+    },
+    /// `"checkbox"`
+    Checkbox,
+    /// `"text"`, a single line text field.
+    Text,
+    /// `"multiline"`, a multi line text field.
+    Multiline,
+    /// `"color"`, a color picker.
+    Color,
+    /// `"dropdown"`, for picking an enum variant or one of a set of values.
+    Dropdown,
+    /// `"hidden"`, not shown at all.
+    Hidden,
+    /// Any other name, for inspector specific widgets.
+    Custom(String),
+}
+
+fn as_f64(value: &dyn Reflect) -> Option<f64> {
+    let ReflectRef::Scalar(scalar) = value.reflect_ref() else {
+        return None;
+    };
+    Some(match scalar {
+        ScalarRef::usize(n) => n as f64,
+        ScalarRef::u8(n) => n as f64,
+        ScalarRef::u16(n) => n as f64,
+        ScalarRef::u32(n) => n as f64,
+        ScalarRef::u64(n) => n as f64,
+        ScalarRef::u128(n) => n as f64,
+        ScalarRef::i8(n) => n as f64,
+        ScalarRef::i16(n) => n as f64,
+        ScalarRef::i32(n) => n as f64,
+        ScalarRef::i64(n) => n as f64,
+        ScalarRef::i128(n) => n as f64,
+        ScalarRef::f32(n) => n as f64,
+        ScalarRef::f64(n) => n,
+        ScalarRef::bool(_) | ScalarRef::char(_) | ScalarRef::String(_) => return None,
+    })
+}
+
+fn as_i64(value: &dyn Reflect) -> Option<i64> {
+    let ReflectRef::Scalar(scalar) = value.reflect_ref() else {
+        return None;
+    };
+    match scalar {
+        ScalarRef::usize(n) => n.try_into().ok(),
+        ScalarRef::u8(n) => Some(n.into()),
+        ScalarRef::u16(n) => Some(n.into()),
+        ScalarRef::u32(n) => Some(n.into()),
+        ScalarRef::u64(n) => n.try_into().ok(),
+        ScalarRef::u128(n) => n.try_into().ok(),
+        ScalarRef::i8(n) => Some(n.into()),
+        ScalarRef::i16(n) => Some(n.into()),
+        ScalarRef::i32(n) => Some(n.into()),
+        ScalarRef::i64(n) => Some(n),
+        ScalarRef::i128(n) => n.try_into().ok(),
+        ScalarRef::f32(_)
+        | ScalarRef::f64(_)
+        | ScalarRef::bool(_)
+        | ScalarRef::char(_)
+        | ScalarRef::String(_) => None,
+    }
+}
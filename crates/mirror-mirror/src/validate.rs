@@ -0,0 +1,85 @@
+use crate::key_path::KeyPath;
+use crate::visit::walk;
+use crate::visit::ReflectVisitor;
+use crate::Reflect;
+use crate::ReflectError;
+
+/// Check `value` and everything nested inside it with their validators, see
+/// [`Reflect::validate_self`].
+///
+/// Returns the first value that was rejected, outermost first. Map keys aren't checked.
+///
+/// # Example
+///
+/// ```
+/// use mirror_mirror::{key_path, Reflect};
+/// use mirror_mirror::validate::validate;
+///
+/// #[derive(Reflect, Debug, Clone)]
+/// struct Level {
+///     enemies: Vec<Enemy>,
+/// }
+///
+/// #[derive(Reflect, Debug, Clone)]
+/// #[reflect(validate_with(Enemy::check))]
+/// struct Enemy {
+///     min_damage: u32,
+///     max_damage: u32,
+/// }
+///
+/// impl Enemy {
+///     fn check(&self) -> Result<(), &'static str> {
+///         if self.min_damage <= self.max_damage {
+///             Ok(())
+///         } else {
+///             Err("`min_damage` is larger than `max_damage`")
+///         }
+///     }
+/// }
+///
+/// let level = Level {
+///     enemies: Vec::from([
+///         Enemy { min_damage: 1, max_damage: 2 },
+///         Enemy { min_damage: 3, max_damage: 2 },
+///     ]),
+/// };
+///
+/// let error = validate(&level).unwrap_err();
+/// assert_eq!(error.path(), &key_path!(.enemies[1_usize]));
+/// assert_eq!(error.reason(), Some("`min_damage` is larger than `max_damage`"));
+/// ```
+pub fn validate(value: &dyn Reflect) -> Result<(), ReflectError> {
+    value.validate_self()?;
+
+    let mut visitor = Validate { error: None };
+    walk(value, &mut visitor);
+    match visitor.error {
+        Some(error) => Err(error),
+        None => Ok(()),
+    }
+}
+
+struct Validate {
+    error: Option<ReflectError>,
+}
+
+impl Validate {
+    fn check(&mut self, path: &KeyPath, value: &dyn Reflect) {
+        if self.error.is_some() {
+            return;
+        }
+        if let Err(error) = value.validate_self() {
+            self.error = Some(error.under(path));
+        }
+    }
+}
+
+impl ReflectVisitor for Validate {
+    fn visit_field(&mut self, path: &KeyPath, value: &dyn Reflect) {
+        self.check(path, value);
+    }
+
+    fn visit_element(&mut self, path: &KeyPath, value: &dyn Reflect) {
+        self.check(path, value);
+    }
+}
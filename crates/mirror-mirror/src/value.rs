@@ -1,6 +1,8 @@
+use alloc::borrow::Cow;
 use alloc::borrow::ToOwned;
 use alloc::boxed::Box;
 use alloc::collections::BTreeMap;
+use alloc::collections::BTreeSet;
 use alloc::string::String;
 use alloc::vec::Vec;
 use core::any::Any;
@@ -27,6 +29,7 @@ use crate::ReflectRef;
 use crate::ScalarMut;
 use crate::ScalarOwned;
 use crate::ScalarRef;
+use crate::Tuple;
 use crate::TypeDescriptor;
 
 /// A type erased value type.
@@ -59,6 +62,7 @@ pub enum Value {
     TupleValue(TupleValue),
     List(Vec<Value>),
     Map(BTreeMap<Value, Value>),
+    Set(BTreeSet<Value>),
 }
 
 impl FromReflect for Value {
@@ -67,6 +71,85 @@ impl FromReflect for Value {
     }
 }
 
+impl Value {
+    /// The value of the unit type `()`, an empty [`TupleValue`].
+    ///
+    /// ```
+    /// use mirror_mirror::{Reflect, Value};
+    ///
+    /// assert_eq!(().to_value(), Value::unit());
+    /// assert!(Value::from(()).is_unit());
+    /// ```
+    pub const fn unit() -> Self {
+        Value::TupleValue(TupleValue::new())
+    }
+
+    /// Whether this is the value of the unit type `()`, that is an empty [`TupleValue`].
+    pub fn is_unit(&self) -> bool {
+        matches!(self, Value::TupleValue(tuple) if tuple.fields_len() == 0)
+    }
+
+    /// Shrink the allocations of this value, and all values nested in it, to fit their contents.
+    ///
+    /// Values built up incrementally, for example by pushing onto lists or deserializing, often
+    /// have more capacity than they need. Compacting them before keeping them around for a long
+    /// time, such as when caching large assets in memory, releases that excess capacity.
+    ///
+    /// Every string and container is owned by the value it's in, so repeated strings and
+    /// identical subtrees aren't shared.
+    pub fn compact(&mut self) {
+        match self {
+            Value::usize(_)
+            | Value::u8(_)
+            | Value::u16(_)
+            | Value::u32(_)
+            | Value::u64(_)
+            | Value::u128(_)
+            | Value::i8(_)
+            | Value::i16(_)
+            | Value::i32(_)
+            | Value::i64(_)
+            | Value::i128(_)
+            | Value::bool(_)
+            | Value::char(_)
+            | Value::f32(_)
+            | Value::f64(_) => {}
+            Value::String(inner) => inner.shrink_to_fit(),
+            Value::StructValue(inner) => inner.compact(),
+            Value::EnumValue(inner) => inner.compact(),
+            Value::TupleStructValue(inner) => inner.compact(),
+            Value::TupleValue(inner) => inner.compact(),
+            Value::List(inner) => {
+                for value in inner.iter_mut() {
+                    value.compact();
+                }
+                inner.shrink_to_fit();
+            }
+            Value::Map(inner) => {
+                // keys can't be modified in place, so rebuild the map. The entries come out in
+                // order which makes that linear.
+                *inner = core::mem::take(inner)
+                    .into_iter()
+                    .map(|(mut key, mut value)| {
+                        key.compact();
+                        value.compact();
+                        (key, value)
+                    })
+                    .collect();
+            }
+            Value::Set(inner) => {
+                *inner = core::mem::take(inner)
+                    .into_iter()
+                    .map(|mut value| {
+                        value.compact();
+                        value
+                    })
+                    .collect();
+            }
+        }
+    }
+}
+
 #[allow(non_camel_case_types)]
 #[derive(Eq, PartialEq, PartialOrd, Ord, Hash)]
 enum OrdEqHashValue<'a> {
@@ -92,6 +175,7 @@ enum OrdEqHashValue<'a> {
     TupleValue(&'a TupleValue),
     List(&'a [Value]),
     Map(&'a BTreeMap<Value, Value>),
+    Set(&'a BTreeSet<Value>),
 }
 
 impl<'a> From<&'a Value> for OrdEqHashValue<'a> {
@@ -119,6 +203,7 @@ impl<'a> From<&'a Value> for OrdEqHashValue<'a> {
             Value::TupleValue(inner) => OrdEqHashValue::TupleValue(inner),
             Value::List(inner) => OrdEqHashValue::List(inner),
             Value::Map(inner) => OrdEqHashValue::Map(inner),
+            Value::Set(inner) => OrdEqHashValue::Set(inner),
         }
     }
 }
@@ -152,6 +237,128 @@ impl Hash for Value {
     }
 }
 
+/// The type a [`StructValue`], [`TupleStructValue`], or [`EnumValue`] was created from.
+///
+/// Ignored when comparing and hashing so values created from types are equal to the same values
+/// built by hand. It isn't serialized either, so it doesn't change the encoding of values.
+#[derive(Default, Debug, Clone)]
+pub(crate) struct Origin {
+    type_name: Option<Cow<'static, str>>,
+    // descriptors are cached with `std` and store their hash, so it isn't computed again here
+    descriptor: Option<fn() -> Cow<'static, TypeDescriptor>>,
+    // the hash of the type the value was converted from, which has no `descriptor` to look it up
+    type_hash: Option<u64>,
+}
+
+impl Origin {
+    /// The origin of a value converted from `reflect` into a `T`.
+    pub(crate) fn of<T>(reflect: &dyn Reflect) -> Self
+    where
+        T: 'static,
+    {
+        // `Value` boxes some of these, and reflects as what it contains
+        let origin = reflect
+            .downcast_ref::<StructValue>()
+            .map(|value| &value.origin)
+            .or_else(|| {
+                reflect
+                    .downcast_ref::<Box<StructValue>>()
+                    .map(|value| &value.origin)
+            })
+            .or_else(|| {
+                reflect
+                    .downcast_ref::<TupleStructValue>()
+                    .map(|value| &value.origin)
+            })
+            .or_else(|| {
+                reflect
+                    .downcast_ref::<EnumValue>()
+                    .map(|value| &value.origin)
+            })
+            .or_else(|| {
+                reflect
+                    .downcast_ref::<Box<EnumValue>>()
+                    .map(|value| &value.origin)
+            });
+        if let Some(origin) = origin {
+            return origin.clone();
+        }
+
+        let type_name = reflect.type_name();
+        if type_name == core::any::type_name::<T>() {
+            return Self::default();
+        }
+
+        Self {
+            type_name: Some(Cow::Owned(type_name.to_owned())),
+            descriptor: None,
+            type_hash: Some(reflect.type_descriptor().type_hash()),
+        }
+    }
+
+    pub(crate) fn set_type_name(&mut self, type_name: impl Into<Cow<'static, str>>) {
+        self.type_name = Some(type_name.into());
+    }
+
+    /// Set the type the value was created from, along with its type name unless one is set
+    /// already.
+    pub(crate) fn set_type<T>(&mut self)
+    where
+        T: DescribeType,
+    {
+        if self.type_name.is_none() {
+            self.type_name = Some(Cow::Borrowed(core::any::type_name::<T>()));
+        }
+        self.descriptor = Some(<T as DescribeType>::type_descriptor);
+        self.type_hash = None;
+    }
+
+    pub(crate) fn type_name(&self) -> Option<&str> {
+        self.type_name.as_deref()
+    }
+
+    pub(crate) fn type_hash(&self) -> Option<u64> {
+        match self.descriptor {
+            Some(descriptor) => Some(descriptor().type_hash()),
+            None => self.type_hash,
+        }
+    }
+
+    pub(crate) fn compact(&mut self) {
+        if let Some(Cow::Owned(type_name)) = &mut self.type_name {
+            type_name.shrink_to_fit();
+        }
+    }
+}
+
+impl PartialEq for Origin {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for Origin {}
+
+impl PartialOrd for Origin {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Origin {
+    fn cmp(&self, _other: &Self) -> Ordering {
+        Ordering::Equal
+    }
+}
+
+impl Hash for Origin {
+    fn hash<H>(&self, _state: &mut H)
+    where
+        H: Hasher,
+    {
+    }
+}
+
 macro_rules! for_each_variant {
     ($self:expr, $inner:ident => $expr:expr) => {
         match $self {
@@ -177,6 +384,7 @@ macro_rules! for_each_variant {
             Value::TupleValue($inner) => $expr,
             Value::List($inner) => $expr,
             Value::Map($inner) => $expr,
+            Value::Set($inner) => $expr,
         }
     };
 }
@@ -210,6 +418,10 @@ impl Reflect for Value {
         for_each_variant!(self, inner => inner)
     }
 
+    fn type_name(&self) -> &str {
+        for_each_variant!(self, inner => inner.as_reflect().type_name())
+    }
+
     fn reflect_owned(self: Box<Self>) -> ReflectOwned {
         match *self {
             Value::usize(inner) => ReflectOwned::Scalar(ScalarOwned::from(inner)),
@@ -234,6 +446,7 @@ impl Reflect for Value {
             Value::TupleValue(inner) => ReflectOwned::Tuple(Box::new(inner)),
             Value::List(inner) => ReflectOwned::List(Box::new(inner)),
             Value::Map(inner) => ReflectOwned::Map(Box::new(inner)),
+            Value::Set(inner) => ReflectOwned::Set(Box::new(inner)),
         }
     }
 
@@ -261,6 +474,7 @@ impl Reflect for Value {
             Value::TupleValue(inner) => ReflectRef::Tuple(inner),
             Value::List(inner) => ReflectRef::List(inner),
             Value::Map(inner) => ReflectRef::Map(inner),
+            Value::Set(inner) => ReflectRef::Set(inner),
         }
     }
 
@@ -288,6 +502,7 @@ impl Reflect for Value {
             Value::TupleValue(inner) => ReflectMut::Tuple(inner),
             Value::List(inner) => ReflectMut::List(inner),
             Value::Map(inner) => ReflectMut::Map(inner),
+            Value::Set(inner) => ReflectMut::Set(inner),
         }
     }
 
@@ -338,6 +553,12 @@ impl From<EnumValue> for Value {
     }
 }
 
+impl From<()> for Value {
+    fn from((): ()) -> Self {
+        Value::unit()
+    }
+}
+
 impl From<&str> for Value {
     fn from(value: &str) -> Self {
         value.to_owned().into()
@@ -357,3 +578,113 @@ from_impls! {
     bool char String
     TupleValue TupleStructValue
 }
+
+/// Collect values into a [`Value::List`].
+///
+/// ```
+/// use mirror_mirror::Value;
+///
+/// let list = (0..3_i32).map(Value::from).collect::<Value>();
+/// assert_eq!(list, Value::List(Vec::from([0.into(), 1.into(), 2.into()])));
+/// ```
+impl FromIterator<Value> for Value {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = Value>,
+    {
+        Value::List(Vec::from_iter(iter))
+    }
+}
+
+/// Collect key-value pairs into a [`Value::Map`].
+impl FromIterator<(Value, Value)> for Value {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (Value, Value)>,
+    {
+        Value::Map(BTreeMap::from_iter(iter))
+    }
+}
+
+/// Write a value as JSON, in the shape described by `descriptor`.
+///
+/// The data is the same as `serde`'s derives would produce for the original type, as written by
+/// [`TypedSerializer`], except that maps whose keys aren't scalars are written as arrays of
+/// `[key, value]` pairs, since JSON only supports string keys. That makes the output much easier
+/// to read and edit by hand than `Value`'s own `Serialize` implementation, and enum variants,
+/// struct fields, and map keys survive the round trip through [`from_json_str`].
+///
+/// # Example
+///
+/// ```
+/// use mirror_mirror::{value, DescribeType, FromReflect, Reflect};
+///
+/// #[derive(Reflect, Clone, Debug, PartialEq)]
+/// enum Shape {
+///     Circle { radius: f32 },
+///     Rect(f32, f32),
+/// }
+///
+/// let descriptor = <Shape as DescribeType>::type_descriptor();
+///
+/// let json = value::to_json_string(&Shape::Rect(1.0, 2.0), &descriptor).unwrap();
+/// assert_eq!(json, r#"{"Rect":[1.0,2.0]}"#);
+///
+/// let value = value::from_json_str(r#"{ "Circle": { "radius": 3.0 } }"#, &descriptor).unwrap();
+/// assert_eq!(Shape::from_reflect(&value), Some(Shape::Circle { radius: 3.0 }));
+/// ```
+///
+/// [`TypedSerializer`]: crate::serde::TypedSerializer
+#[cfg(feature = "json")]
+pub fn to_json_string(
+    value: &dyn Reflect,
+    descriptor: &TypeDescriptor,
+) -> Result<String, serde_json::Error> {
+    let serializer = crate::serde::TypedSerializer::new(value, descriptor).with_maps_as_entries();
+    serde_json::to_string(&serializer)
+}
+
+/// Read a value from JSON, in the shape described by `descriptor`.
+///
+/// This is the inverse of [`to_json_string`], and accepts anything [`TypedDeserializer`] does.
+///
+/// [`TypedDeserializer`]: crate::serde::TypedDeserializer
+#[cfg(feature = "json")]
+pub fn from_json_str(json: &str, descriptor: &TypeDescriptor) -> Result<Value, serde_json::Error> {
+    use serde::de::DeserializeSeed;
+
+    let mut deserializer = serde_json::Deserializer::from_str(json);
+    let value = crate::serde::TypedDeserializer::new(descriptor).deserialize(&mut deserializer)?;
+    deserializer.end()?;
+    Ok(value)
+}
+
+/// Write a value as [RON], in the shape described by `descriptor`.
+///
+/// The data is the same as [`TypedSerializer`] writes, so enums are written as maps from the
+/// variant name to its fields, such as `{"Circle":{"radius":3.0}}`, and can be read back with
+/// [`from_ron_str`].
+///
+/// [RON]: https://github.com/ron-rs/ron
+/// [`TypedSerializer`]: crate::serde::TypedSerializer
+#[cfg(feature = "ron")]
+pub fn to_ron_string(
+    value: &dyn Reflect,
+    descriptor: &TypeDescriptor,
+) -> Result<String, ron::Error> {
+    ron::to_string(&crate::serde::TypedSerializer::new(value, descriptor))
+}
+
+/// Read a value from [RON], in the shape described by `descriptor`.
+///
+/// This is the inverse of [`to_ron_string`], and accepts anything [`TypedDeserializer`] does.
+///
+/// [RON]: https://github.com/ron-rs/ron
+/// [`TypedDeserializer`]: crate::serde::TypedDeserializer
+#[cfg(feature = "ron")]
+pub fn from_ron_str(
+    ron: &str,
+    descriptor: &TypeDescriptor,
+) -> Result<Value, ron::error::SpannedError> {
+    ron::Options::default().from_str_seed(ron, crate::serde::TypedDeserializer::new(descriptor))
+}
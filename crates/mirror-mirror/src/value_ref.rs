@@ -0,0 +1,649 @@
+// the helpers below are only used by the serde and speedy impls
+#![cfg_attr(not(any(feature = "serde", feature = "speedy")), allow(dead_code))]
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::array::Array;
+use crate::enum_::EnumValue;
+use crate::enum_::VariantField;
+use crate::struct_::StructValue;
+use crate::tuple::TupleValue;
+use crate::tuple_struct::TupleStructValue;
+use crate::Enum;
+use crate::List;
+use crate::Map;
+use crate::Reflect;
+use crate::ReflectRef;
+use crate::ScalarRef;
+use crate::Set;
+use crate::Struct;
+use crate::Tuple;
+use crate::TupleStruct;
+use crate::Value;
+
+/// A borrowed view of a reflected value that serializes exactly like the [`Value`] returned by
+/// [`Reflect::to_value`], without cloning it first.
+///
+/// Strings and nested containers are serialized straight from the reflected value. Only opaque
+/// values, whose contents can't be reached through reflection, are converted with `to_value`.
+///
+/// Constructed with [`Reflect::to_value_ref`].
+///
+/// # Example
+///
+/// ```
+/// use mirror_mirror::{Reflect, Value};
+///
+/// #[derive(Reflect, Clone, Debug)]
+/// struct Scene {
+///     names: Vec<String>,
+/// }
+///
+/// let scene = Scene {
+///     names: Vec::from(["root".to_owned()]),
+/// };
+///
+/// let json = serde_json::to_string(&scene.to_value_ref()).unwrap();
+/// assert_eq!(json, serde_json::to_string(&scene.to_value()).unwrap());
+///
+/// let value = serde_json::from_str::<Value>(&json).unwrap();
+/// assert_eq!(value, scene.to_value());
+/// ```
+#[derive(Clone, Copy)]
+pub struct ValueRef<'a> {
+    reflect: &'a dyn Reflect,
+}
+
+impl<'a> ValueRef<'a> {
+    pub fn new(reflect: &'a dyn Reflect) -> Self {
+        Self { reflect }
+    }
+
+    pub fn as_reflect(&self) -> &'a dyn Reflect {
+        self.reflect
+    }
+
+    /// Clone the value into an owned [`Value`].
+    pub fn to_value(&self) -> Value {
+        self.reflect.to_value()
+    }
+}
+
+impl fmt::Debug for ValueRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.reflect.debug(f)
+    }
+}
+
+// The shape of a reflected value, in terms of the `Value` variant it serializes as.
+enum Node<'a> {
+    // types that are already `Value`s, or parts of them, serialize themselves
+    Value(&'a Value),
+    StructValue(&'a StructValue),
+    EnumValue(&'a EnumValue),
+    TupleStructValue(&'a TupleStructValue),
+    TupleValue(&'a TupleValue),
+    Scalar(ScalarRef<'a>),
+    Struct(&'a dyn Struct),
+    TupleStruct(&'a dyn TupleStruct),
+    Tuple(&'a dyn Tuple),
+    Enum(&'a dyn Enum),
+    Array(&'a dyn Array),
+    List(&'a dyn List),
+    Map(&'a dyn Map),
+    Set(&'a dyn Set),
+    Opaque(&'a dyn Reflect),
+}
+
+impl<'a> Node<'a> {
+    fn of(reflect: &'a dyn Reflect) -> Self {
+        let any = reflect.as_any();
+        if let Some(value) = any.downcast_ref::<Value>() {
+            return Node::Value(value);
+        }
+        if let Some(value) = any.downcast_ref::<StructValue>() {
+            return Node::StructValue(value);
+        }
+        if let Some(value) = any.downcast_ref::<EnumValue>() {
+            return Node::EnumValue(value);
+        }
+        if let Some(value) = any.downcast_ref::<TupleStructValue>() {
+            return Node::TupleStructValue(value);
+        }
+        if let Some(value) = any.downcast_ref::<TupleValue>() {
+            return Node::TupleValue(value);
+        }
+        match reflect.reflect_ref() {
+            ReflectRef::Struct(inner) => Node::Struct(inner),
+            ReflectRef::TupleStruct(inner) => Node::TupleStruct(inner),
+            ReflectRef::Tuple(inner) => Node::Tuple(inner),
+            ReflectRef::Enum(inner) => Node::Enum(inner),
+            ReflectRef::Array(inner) => Node::Array(inner),
+            ReflectRef::List(inner) => Node::List(inner),
+            ReflectRef::Map(inner) => Node::Map(inner),
+            ReflectRef::Set(inner) => Node::Set(inner),
+            ReflectRef::Scalar(inner) => Node::Scalar(inner),
+            ReflectRef::Opaque(inner) => Node::Opaque(inner),
+        }
+    }
+}
+
+// `StructValue` stores its fields by name, so they are serialized sorted by name.
+fn sorted_fields<'a>(
+    fields: impl Iterator<Item = (&'a str, &'a dyn Reflect)>,
+) -> Vec<(&'a str, &'a dyn Reflect)> {
+    let mut fields = fields.collect::<Vec<_>>();
+    fields.sort_by_key(|(name, _)| *name);
+    fields
+}
+
+fn struct_variant_fields(enum_: &dyn Enum) -> impl Iterator<Item = (&str, &dyn Reflect)> {
+    enum_.fields().filter_map(|field| match field {
+        VariantField::Struct(name, value) => Some((name, value)),
+        VariantField::Tuple(_) => None,
+    })
+}
+
+fn tuple_variant_fields(enum_: &dyn Enum) -> impl Iterator<Item = &dyn Reflect> {
+    enum_.fields().filter_map(|field| match field {
+        VariantField::Struct(_, _) => None,
+        VariantField::Tuple(value) => Some(value),
+    })
+}
+
+// Indices of the variants of `Value`, `EnumValueKind`, and the fields they are made of, in
+// declaration order, which both serde and speedy encode them with.
+mod variant {
+    pub(super) const STRING: u32 = 15;
+    pub(super) const STRUCT_VALUE: u32 = 16;
+    pub(super) const ENUM_VALUE: u32 = 17;
+    pub(super) const TUPLE_STRUCT_VALUE: u32 = 18;
+    pub(super) const TUPLE_VALUE: u32 = 19;
+    pub(super) const LIST: u32 = 20;
+    pub(super) const MAP: u32 = 21;
+    pub(super) const SET: u32 = 22;
+
+    pub(super) const KIND_STRUCT: u32 = 0;
+    pub(super) const KIND_TUPLE: u32 = 1;
+    pub(super) const KIND_UNIT: u32 = 2;
+}
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use alloc::vec::Vec;
+
+    use serde::ser::SerializeStruct;
+    use serde::Serialize;
+    use serde::Serializer;
+
+    use super::sorted_fields;
+    use super::struct_variant_fields;
+    use super::tuple_variant_fields;
+    use super::variant;
+    use super::Node;
+    use super::ValueRef;
+    use crate::array::Array;
+    use crate::enum_::VariantKind;
+    use crate::Enum;
+    use crate::List;
+    use crate::Map;
+    use crate::Reflect;
+    use crate::ScalarRef;
+    use crate::Set;
+
+    impl Serialize for ValueRef<'_> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            macro_rules! serialize_scalar {
+                ($scalar:expr, $($ident:ident = $index:literal)*) => {
+                    match $scalar {
+                        $(
+                            ScalarRef::$ident(value) => serializer.serialize_newtype_variant(
+                                "Value",
+                                $index,
+                                stringify!($ident),
+                                &value,
+                            ),
+                        )*
+                        ScalarRef::String(value) => serializer.serialize_newtype_variant(
+                            "Value",
+                            variant::STRING,
+                            "String",
+                            value,
+                        ),
+                    }
+                };
+            }
+
+            match Node::of(self.reflect) {
+                Node::Scalar(scalar) => serialize_scalar! {
+                    scalar,
+                    usize = 0 u8 = 1 u16 = 2 u32 = 3 u64 = 4 u128 = 5
+                    i8 = 6 i16 = 7 i32 = 8 i64 = 9 i128 = 10
+                    bool = 11 char = 12 f32 = 13 f64 = 14
+                },
+                node => serialize_node(node, serializer),
+            }
+        }
+    }
+
+    fn serialize_node<S>(node: Node<'_>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match node {
+            // serialized by `ValueRef::serialize`
+            Node::Scalar(scalar) => ValueRef::new(scalar.as_reflect()).serialize(serializer),
+            Node::Value(value) => value.serialize(serializer),
+            Node::StructValue(value) => serializer.serialize_newtype_variant(
+                "Value",
+                variant::STRUCT_VALUE,
+                "StructValue",
+                value,
+            ),
+            Node::EnumValue(value) => serializer.serialize_newtype_variant(
+                "Value",
+                variant::ENUM_VALUE,
+                "EnumValue",
+                value,
+            ),
+            Node::TupleStructValue(value) => serializer.serialize_newtype_variant(
+                "Value",
+                variant::TUPLE_STRUCT_VALUE,
+                "TupleStructValue",
+                value,
+            ),
+            Node::TupleValue(value) => serializer.serialize_newtype_variant(
+                "Value",
+                variant::TUPLE_VALUE,
+                "TupleValue",
+                value,
+            ),
+            Node::Struct(struct_) => serializer.serialize_newtype_variant(
+                "Value",
+                variant::STRUCT_VALUE,
+                "StructValue",
+                &StructValueRef(struct_.fields().collect()),
+            ),
+            Node::TupleStruct(tuple_struct) => serializer.serialize_newtype_variant(
+                "Value",
+                variant::TUPLE_STRUCT_VALUE,
+                "TupleStructValue",
+                &TupleStructValueRef(tuple_struct.fields().collect()),
+            ),
+            Node::Tuple(tuple) => serializer.serialize_newtype_variant(
+                "Value",
+                variant::TUPLE_VALUE,
+                "TupleValue",
+                &TupleValueRef(tuple.fields().collect()),
+            ),
+            Node::Enum(enum_) => serializer.serialize_newtype_variant(
+                "Value",
+                variant::ENUM_VALUE,
+                "EnumValue",
+                &EnumValueRef(enum_),
+            ),
+            Node::Array(array) => serializer.serialize_newtype_variant(
+                "Value",
+                variant::LIST,
+                "List",
+                &ArrayRef(array),
+            ),
+            Node::List(list) => {
+                serializer.serialize_newtype_variant("Value", variant::LIST, "List", &ListRef(list))
+            }
+            Node::Map(map) => {
+                serializer.serialize_newtype_variant("Value", variant::MAP, "Map", &MapRef(map))
+            }
+            Node::Set(set) => {
+                serializer.serialize_newtype_variant("Value", variant::SET, "Set", &SetRef(set))
+            }
+            Node::Opaque(value) => value.to_value().serialize(serializer),
+        }
+    }
+
+    struct Elements<'a>(Vec<&'a dyn Reflect>);
+
+    impl Serialize for Elements<'_> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.collect_seq(self.0.iter().map(|value| ValueRef::new(*value)))
+        }
+    }
+
+    struct ArrayRef<'a>(&'a dyn Array);
+
+    impl Serialize for ArrayRef<'_> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.collect_seq(self.0.iter().map(ValueRef::new))
+        }
+    }
+
+    struct ListRef<'a>(&'a dyn List);
+
+    impl Serialize for ListRef<'_> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.collect_seq(self.0.iter().map(ValueRef::new))
+        }
+    }
+
+    struct SetRef<'a>(&'a dyn Set);
+
+    impl Serialize for SetRef<'_> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.collect_seq(self.0.iter().map(ValueRef::new))
+        }
+    }
+
+    struct MapRef<'a>(&'a dyn Map);
+
+    impl Serialize for MapRef<'_> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.collect_map(
+                self.0
+                    .iter()
+                    .map(|(key, value)| (ValueRef::new(key), ValueRef::new(value))),
+            )
+        }
+    }
+
+    struct StructValueRef<'a>(Vec<(&'a str, &'a dyn Reflect)>);
+
+    impl Serialize for StructValueRef<'_> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            struct Names<'a>(&'a [(&'a str, &'a dyn Reflect)]);
+
+            impl Serialize for Names<'_> {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    serializer.collect_seq(self.0.iter().map(|(name, _)| name))
+                }
+            }
+
+            struct Fields<'a>(&'a [(&'a str, &'a dyn Reflect)]);
+
+            impl Serialize for Fields<'_> {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    serializer.collect_map(
+                        sorted_fields(self.0.iter().copied())
+                            .into_iter()
+                            .map(|(name, value)| (name, ValueRef::new(value))),
+                    )
+                }
+            }
+
+            let mut state = serializer.serialize_struct("StructValue", 2)?;
+            state.serialize_field("field_names", &Names(&self.0))?;
+            state.serialize_field("fields", &Fields(&self.0))?;
+            state.end()
+        }
+    }
+
+    struct TupleValueRef<'a>(Vec<&'a dyn Reflect>);
+
+    impl Serialize for TupleValueRef<'_> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let mut state = serializer.serialize_struct("TupleValue", 1)?;
+            state.serialize_field("fields", &Elements(self.0.clone()))?;
+            state.end()
+        }
+    }
+
+    struct TupleStructValueRef<'a>(Vec<&'a dyn Reflect>);
+
+    impl Serialize for TupleStructValueRef<'_> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let mut state = serializer.serialize_struct("TupleStructValue", 1)?;
+            state.serialize_field("tuple", &TupleValueRef(self.0.clone()))?;
+            state.end()
+        }
+    }
+
+    struct EnumValueRef<'a>(&'a dyn Enum);
+
+    impl Serialize for EnumValueRef<'_> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            struct Kind<'a>(&'a dyn Enum);
+
+            impl Serialize for Kind<'_> {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    match self.0.variant_kind() {
+                        VariantKind::Struct => serializer.serialize_newtype_variant(
+                            "EnumValueKind",
+                            variant::KIND_STRUCT,
+                            "Struct",
+                            &StructValueRef(struct_variant_fields(self.0).collect()),
+                        ),
+                        VariantKind::Tuple => serializer.serialize_newtype_variant(
+                            "EnumValueKind",
+                            variant::KIND_TUPLE,
+                            "Tuple",
+                            &TupleValueRef(tuple_variant_fields(self.0).collect()),
+                        ),
+                        VariantKind::Unit => serializer.serialize_unit_variant(
+                            "EnumValueKind",
+                            variant::KIND_UNIT,
+                            "Unit",
+                        ),
+                    }
+                }
+            }
+
+            let mut state = serializer.serialize_struct("EnumValue", 2)?;
+            state.serialize_field("name", self.0.variant_name())?;
+            state.serialize_field("kind", &Kind(self.0))?;
+            state.end()
+        }
+    }
+}
+
+#[cfg(feature = "speedy")]
+mod speedy_impl {
+    use speedy::Context;
+    use speedy::Writable;
+    use speedy::Writer;
+
+    use super::sorted_fields;
+    use super::struct_variant_fields;
+    use super::tuple_variant_fields;
+    use super::variant;
+    use super::Node;
+    use super::ValueRef;
+    use crate::enum_::VariantKind;
+    use crate::Reflect;
+    use crate::ScalarRef;
+
+    impl<C> Writable<C> for ValueRef<'_>
+    where
+        C: Context,
+    {
+        fn write_to<W>(&self, writer: &mut W) -> Result<(), C::Error>
+        where
+            W: ?Sized + Writer<C>,
+        {
+            macro_rules! write_scalar {
+                ($scalar:expr, $($ident:ident = $index:literal)*) => {
+                    match $scalar {
+                        $(
+                            ScalarRef::$ident(value) => {
+                                writer.write_u32($index)?;
+                                value.write_to(writer)
+                            }
+                        )*
+                        ScalarRef::String(value) => {
+                            writer.write_u32(variant::STRING)?;
+                            value.write_to(writer)
+                        }
+                    }
+                };
+            }
+
+            match Node::of(self.reflect) {
+                Node::Scalar(scalar) => write_scalar! {
+                    scalar,
+                    usize = 0 u8 = 1 u16 = 2 u32 = 3 u64 = 4 u128 = 5
+                    i8 = 6 i16 = 7 i32 = 8 i64 = 9 i128 = 10
+                    bool = 11 char = 12 f32 = 13 f64 = 14
+                },
+                node => write_node(node, writer),
+            }
+        }
+    }
+
+    fn write_node<C, W>(node: Node<'_>, writer: &mut W) -> Result<(), C::Error>
+    where
+        C: Context,
+        W: ?Sized + Writer<C>,
+    {
+        match node {
+            // written by `ValueRef::write_to`
+            Node::Scalar(scalar) => ValueRef::new(scalar.as_reflect()).write_to(writer),
+            Node::Value(value) => value.write_to(writer),
+            Node::StructValue(value) => {
+                writer.write_u32(variant::STRUCT_VALUE)?;
+                value.write_to(writer)
+            }
+            Node::EnumValue(value) => {
+                writer.write_u32(variant::ENUM_VALUE)?;
+                value.write_to(writer)
+            }
+            Node::TupleStructValue(value) => {
+                writer.write_u32(variant::TUPLE_STRUCT_VALUE)?;
+                value.write_to(writer)
+            }
+            Node::TupleValue(value) => {
+                writer.write_u32(variant::TUPLE_VALUE)?;
+                value.write_to(writer)
+            }
+            Node::Struct(struct_) => {
+                writer.write_u32(variant::STRUCT_VALUE)?;
+                write_struct_value(struct_.fields_len(), || struct_.fields(), writer)
+            }
+            Node::TupleStruct(tuple_struct) => {
+                writer.write_u32(variant::TUPLE_STRUCT_VALUE)?;
+                write_elements(tuple_struct.fields_len(), tuple_struct.fields(), writer)
+            }
+            Node::Tuple(tuple) => {
+                writer.write_u32(variant::TUPLE_VALUE)?;
+                write_elements(tuple.fields_len(), tuple.fields(), writer)
+            }
+            Node::Enum(enum_) => {
+                writer.write_u32(variant::ENUM_VALUE)?;
+                enum_.variant_name().write_to(writer)?;
+                match enum_.variant_kind() {
+                    VariantKind::Struct => {
+                        writer.write_u32(variant::KIND_STRUCT)?;
+                        write_struct_value(
+                            enum_.fields_len(),
+                            || struct_variant_fields(enum_),
+                            writer,
+                        )?;
+                    }
+                    VariantKind::Tuple => {
+                        writer.write_u32(variant::KIND_TUPLE)?;
+                        write_elements(enum_.fields_len(), tuple_variant_fields(enum_), writer)?;
+                    }
+                    VariantKind::Unit => writer.write_u32(variant::KIND_UNIT)?,
+                }
+                Ok(())
+            }
+            Node::Array(array) => {
+                writer.write_u32(variant::LIST)?;
+                write_elements(array.len(), array.iter(), writer)
+            }
+            Node::List(list) => {
+                writer.write_u32(variant::LIST)?;
+                write_elements(list.len(), list.iter(), writer)
+            }
+            Node::Map(map) => {
+                writer.write_u32(variant::MAP)?;
+                speedy::private::write_length(map.len(), writer)?;
+                for (key, value) in map.iter() {
+                    ValueRef::new(key).write_to(writer)?;
+                    ValueRef::new(value).write_to(writer)?;
+                }
+                Ok(())
+            }
+            Node::Set(set) => {
+                writer.write_u32(variant::SET)?;
+                write_elements(set.len(), set.iter(), writer)
+            }
+            Node::Opaque(value) => value.to_value().write_to(writer),
+        }
+    }
+
+    fn write_elements<'a, C, W>(
+        len: usize,
+        elements: impl Iterator<Item = &'a dyn Reflect>,
+        writer: &mut W,
+    ) -> Result<(), C::Error>
+    where
+        C: Context,
+        W: ?Sized + Writer<C>,
+    {
+        speedy::private::write_length(len, writer)?;
+        for element in elements {
+            ValueRef::new(element).write_to(writer)?;
+        }
+        Ok(())
+    }
+
+    fn write_struct_value<'a, C, W, I>(
+        len: usize,
+        fields: impl Fn() -> I,
+        writer: &mut W,
+    ) -> Result<(), C::Error>
+    where
+        C: Context,
+        W: ?Sized + Writer<C>,
+        I: Iterator<Item = (&'a str, &'a dyn Reflect)>,
+    {
+        speedy::private::write_length(len, writer)?;
+        for (name, _) in fields() {
+            name.write_to(writer)?;
+        }
+        speedy::private::write_length(len, writer)?;
+        for (name, value) in sorted_fields(fields()) {
+            name.write_to(writer)?;
+            ValueRef::new(value).write_to(writer)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,290 @@
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::fmt;
+use core::marker::PhantomData;
+
+use speedy::LittleEndian;
+use speedy::Readable;
+use speedy::Writable;
+
+use crate::key_path::value_to_usize;
+use crate::type_info::stable_hash::stable_hash;
+use crate::type_info::GetMeta;
+use crate::DescribeType;
+use crate::TypeDescriptor;
+
+/// The number of bytes in the header written before the payload. An 8 byte type hash followed by
+/// a 4 byte version.
+const HEADER_LEN: usize = 12;
+
+/// A wrapper that writes a schema header before the [`speedy`] encoding of a value.
+///
+/// The header contains a [stable hash](stable_type_hash) of the type's shape and its
+/// [version](schema_version). When reading, data written by an older version of the type can be
+/// upgraded with functions registered on a [`VersionedReader`].
+///
+/// # Example
+///
+/// ```
+/// use mirror_mirror::{versioned::{Versioned, VersionedReader}, Reflect};
+/// use speedy::{Readable, Writable};
+///
+/// // the old version of the type, kept around for upgrading
+/// #[derive(Reflect, Clone, Debug, Readable, Writable)]
+/// #[reflect(meta(version = 1_u32))]
+/// struct PlayerV1 {
+///     name: String,
+/// }
+///
+/// #[derive(Reflect, Clone, Debug, Readable, Writable, PartialEq)]
+/// #[reflect(meta(version = 2_u32))]
+/// struct Player {
+///     name: String,
+///     score: u32,
+/// }
+///
+/// let bytes = Versioned(PlayerV1 { name: "Bob".to_owned() }).write_to_vec().unwrap();
+///
+/// let reader = VersionedReader::<Player>::new().with_upgrade(|old: PlayerV1| Player {
+///     name: old.name,
+///     score: 0,
+/// });
+///
+/// let Versioned(player) = reader.read_from_buffer(&bytes).unwrap();
+/// assert_eq!(player, Player { name: "Bob".to_owned(), score: 0 });
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Versioned<T>(pub T);
+
+impl<T> Versioned<T>
+where
+    T: DescribeType + Writable<LittleEndian>,
+{
+    /// Write the header followed by the value.
+    pub fn write_to_vec(&self) -> Result<Vec<u8>, speedy::Error> {
+        let mut bytes = Vec::with_capacity(HEADER_LEN);
+        write_header::<T>(&mut bytes);
+        bytes.extend(self.0.write_to_vec()?);
+        Ok(bytes)
+    }
+}
+
+impl<T> Versioned<T>
+where
+    T: DescribeType + for<'a> Readable<'a, LittleEndian>,
+{
+    /// Read a value written with [`Versioned::write_to_vec`].
+    ///
+    /// Fails unless the data was written by the current version of `T`. Use a
+    /// [`VersionedReader`] to upgrade data from older versions.
+    pub fn read_from_buffer(bytes: &[u8]) -> Result<Self, VersionedError> {
+        VersionedReader::new().read_from_buffer(bytes)
+    }
+}
+
+/// Reads [`Versioned`] values, upgrading data written by older versions of the type.
+pub struct VersionedReader<T> {
+    upgrades: BTreeMap<Header, Upgrade<T>>,
+    _marker: PhantomData<fn() -> T>,
+}
+
+type Upgrade<T> = Box<dyn Fn(&[u8]) -> Result<T, speedy::Error> + Send + Sync>;
+
+impl<T> VersionedReader<T>
+where
+    T: DescribeType + for<'a> Readable<'a, LittleEndian>,
+{
+    /// Create a reader without any upgrades.
+    pub fn new() -> Self {
+        Self {
+            upgrades: BTreeMap::new(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Register a function that upgrades data written by `Old` into `T`.
+    ///
+    /// `Old` is a copy of a previous version of `T`. Data is matched to the upgrade using the
+    /// stable hash and version of `Old`. Upgrades can be chained by having an upgrade call into
+    /// the previous one.
+    pub fn with_upgrade<Old, F>(mut self, upgrade: F) -> Self
+    where
+        Old: DescribeType + for<'a> Readable<'a, LittleEndian>,
+        F: Fn(Old) -> T + Send + Sync + 'static,
+    {
+        self.push_upgrade(upgrade);
+        self
+    }
+
+    /// Register a function that upgrades data written by `Old` into `T`.
+    ///
+    /// See [`VersionedReader::with_upgrade`].
+    pub fn push_upgrade<Old, F>(&mut self, upgrade: F)
+    where
+        Old: DescribeType + for<'a> Readable<'a, LittleEndian>,
+        F: Fn(Old) -> T + Send + Sync + 'static,
+    {
+        self.upgrades.insert(
+            Header::of::<Old>(),
+            Box::new(move |bytes| Old::read_from_buffer(bytes).map(&upgrade)),
+        );
+    }
+
+    /// Read a value, upgrading it if it was written by a registered older version.
+    pub fn read_from_buffer(&self, bytes: &[u8]) -> Result<Versioned<T>, VersionedError> {
+        if bytes.len() < HEADER_LEN {
+            return Err(VersionedError::MissingHeader);
+        }
+        let (header, payload) = bytes.split_at(HEADER_LEN);
+        let found = Header {
+            type_hash: u64::from_le_bytes(header[..8].try_into().unwrap()),
+            version: u32::from_le_bytes(header[8..].try_into().unwrap()),
+        };
+        let expected = Header::of::<T>();
+
+        let value = if found == expected {
+            T::read_from_buffer(payload)?
+        } else if let Some(upgrade) = self.upgrades.get(&found) {
+            upgrade(payload)?
+        } else if found.version == expected.version {
+            return Err(VersionedError::SchemaMismatch {
+                version: found.version,
+                expected_hash: expected.type_hash,
+                found_hash: found.type_hash,
+            });
+        } else {
+            return Err(VersionedError::NoUpgrade {
+                version: found.version,
+                type_hash: found.type_hash,
+            });
+        };
+
+        Ok(Versioned(value))
+    }
+}
+
+impl<T> Default for VersionedReader<T>
+where
+    T: DescribeType + for<'a> Readable<'a, LittleEndian>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> fmt::Debug for VersionedReader<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VersionedReader")
+            .field("upgrades", &self.upgrades.keys())
+            .finish()
+    }
+}
+
+/// Errors that can happen when reading [`Versioned`] values.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum VersionedError {
+    /// The data is too short to contain the header.
+    MissingHeader,
+    /// The data was written by the same version of the type but its shape has since changed,
+    /// which likely means the version wasn't bumped.
+    SchemaMismatch {
+        version: u32,
+        expected_hash: u64,
+        found_hash: u64,
+    },
+    /// The data was written by another version of the type and there is no upgrade registered
+    /// for it.
+    NoUpgrade { version: u32, type_hash: u64 },
+    /// The payload couldn't be decoded.
+    Speedy(speedy::Error),
+}
+
+impl fmt::Display for VersionedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VersionedError::MissingHeader => write!(f, "data is too short to contain a header"),
+            VersionedError::SchemaMismatch {
+                version,
+                expected_hash,
+                found_hash,
+            } => write!(
+                f,
+                "schema of version {version} has changed \
+                (expected hash {expected_hash:#x}, found {found_hash:#x})"
+            ),
+            VersionedError::NoUpgrade { version, type_hash } => write!(
+                f,
+                "no upgrade registered for version {version} (hash {type_hash:#x})"
+            ),
+            VersionedError::Speedy(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for VersionedError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            VersionedError::Speedy(err) => Some(err),
+            VersionedError::MissingHeader
+            | VersionedError::SchemaMismatch { .. }
+            | VersionedError::NoUpgrade { .. } => None,
+        }
+    }
+}
+
+impl From<speedy::Error> for VersionedError {
+    fn from(err: speedy::Error) -> Self {
+        Self::Speedy(err)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct Header {
+    type_hash: u64,
+    version: u32,
+}
+
+impl Header {
+    fn of<T>() -> Self
+    where
+        T: DescribeType,
+    {
+        let descriptor = T::type_descriptor();
+        Self {
+            type_hash: stable_type_hash(&descriptor),
+            version: schema_version(&descriptor),
+        }
+    }
+}
+
+fn write_header<T>(bytes: &mut Vec<u8>)
+where
+    T: DescribeType,
+{
+    let header = Header::of::<T>();
+    bytes.extend(header.type_hash.to_le_bytes());
+    bytes.extend(header.version.to_le_bytes());
+}
+
+/// The schema version of a type, as set with `#[reflect(meta(version = ...))]`.
+///
+/// Defaults to `0` for types without a version.
+pub fn schema_version(descriptor: &TypeDescriptor) -> u32 {
+    descriptor
+        .get_type()
+        .meta("version")
+        .and_then(|meta| value_to_usize(&meta.to_value()))
+        .map_or(0, |version| version as u32)
+}
+
+/// A hash of the shape of a type that is stable across compilations and platforms.
+///
+/// Includes type names, field names, variant names, and array lengths, of the type itself and
+/// all types it contains. Metadata and docs are not included.
+///
+/// This is the same hash that [`TypeDescriptor`]s use to identify the types in their graphs.
+pub fn stable_type_hash(descriptor: &TypeDescriptor) -> u64 {
+    stable_hash(descriptor.get_type())
+}
@@ -0,0 +1,591 @@
+use alloc::vec::Vec;
+
+use crate::enum_::VariantField;
+use crate::enum_::VariantFieldMut;
+use crate::key_path::value_at_key;
+use crate::key_path::Key;
+use crate::key_path::KeyPath;
+use crate::Array;
+use crate::Enum;
+use crate::List;
+use crate::Map;
+use crate::Reflect;
+use crate::ReflectMut;
+use crate::ReflectRef;
+use crate::ScalarMut;
+use crate::ScalarRef;
+use crate::Set;
+use crate::Struct;
+use crate::Tuple;
+use crate::TupleStruct;
+
+/// Callbacks for [`walk`].
+///
+/// Every method does nothing by default so visitors only have to implement the ones they care
+/// about. Each callback receives the [`KeyPath`] from the root of the walk to the value.
+///
+/// Containers are visited before the values inside them. Fields of structs, tuple structs, tuples,
+/// and enum variants are passed to [`visit_field`](Self::visit_field), and elements of lists,
+/// arrays, and sets, and values of maps, to [`visit_element`](Self::visit_element), right
+/// before they are walked themselves. Map keys are not walked.
+///
+/// # Example
+///
+/// ```
+/// use mirror_mirror::{key_path, Reflect, ScalarRef};
+/// use mirror_mirror::key_path::KeyPath;
+/// use mirror_mirror::visit::{walk, ReflectVisitor};
+///
+/// #[derive(Reflect, Clone, Debug)]
+/// struct Player {
+///     name: String,
+///     inventory: Vec<Item>,
+/// }
+///
+/// #[derive(Reflect, Clone, Debug)]
+/// struct Item {
+///     name: String,
+/// }
+///
+/// #[derive(Default)]
+/// struct CollectStrings(Vec<(KeyPath, String)>);
+///
+/// impl ReflectVisitor for CollectStrings {
+///     fn visit_scalar(&mut self, path: &KeyPath, value: ScalarRef<'_>) {
+///         if let ScalarRef::String(string) = value {
+///             self.0.push((path.clone(), string.clone()));
+///         }
+///     }
+/// }
+///
+/// let player = Player {
+///     name: "Alice".to_owned(),
+///     inventory: Vec::from([Item { name: "Sword".to_owned() }]),
+/// };
+///
+/// let mut visitor = CollectStrings::default();
+/// walk(&player, &mut visitor);
+///
+/// assert_eq!(
+///     visitor.0,
+///     [
+///         (key_path!(.name), "Alice".to_owned()),
+///         (key_path!(.inventory[0_usize].name), "Sword".to_owned()),
+///     ],
+/// );
+/// ```
+#[allow(unused_variables)]
+pub trait ReflectVisitor {
+    fn visit_struct(&mut self, path: &KeyPath, value: &dyn Struct) {}
+
+    fn visit_tuple_struct(&mut self, path: &KeyPath, value: &dyn TupleStruct) {}
+
+    fn visit_tuple(&mut self, path: &KeyPath, value: &dyn Tuple) {}
+
+    fn visit_enum(&mut self, path: &KeyPath, value: &dyn Enum) {}
+
+    fn visit_array(&mut self, path: &KeyPath, value: &dyn Array) {}
+
+    fn visit_list(&mut self, path: &KeyPath, value: &dyn List) {}
+
+    fn visit_map(&mut self, path: &KeyPath, value: &dyn Map) {}
+
+    fn visit_set(&mut self, path: &KeyPath, value: &dyn Set) {}
+
+    fn visit_field(&mut self, path: &KeyPath, value: &dyn Reflect) {}
+
+    fn visit_element(&mut self, path: &KeyPath, value: &dyn Reflect) {}
+
+    fn visit_scalar(&mut self, path: &KeyPath, value: ScalarRef<'_>) {}
+
+    fn visit_opaque(&mut self, path: &KeyPath, value: &dyn Reflect) {}
+}
+
+/// Walk through `value` and everything nested inside it, calling `visitor` along the way.
+///
+/// See [`ReflectVisitor`] for the order of the callbacks.
+pub fn walk(value: &dyn Reflect, visitor: &mut dyn ReflectVisitor) {
+    fn go(value: &dyn Reflect, visitor: &mut dyn ReflectVisitor, path: &mut KeyPath) {
+        match value.reflect_ref() {
+            ReflectRef::Struct(inner) => {
+                visitor.visit_struct(path, inner);
+                for (name, field) in inner.fields() {
+                    path.push_field(name);
+                    visitor.visit_field(path, field);
+                    go(field, visitor, path);
+                    path.pop();
+                }
+            }
+            ReflectRef::TupleStruct(inner) => {
+                visitor.visit_tuple_struct(path, inner);
+                for (index, field) in inner.fields().enumerate() {
+                    path.push_field(index);
+                    visitor.visit_field(path, field);
+                    go(field, visitor, path);
+                    path.pop();
+                }
+            }
+            ReflectRef::Tuple(inner) => {
+                visitor.visit_tuple(path, inner);
+                for (index, field) in inner.fields().enumerate() {
+                    path.push_field(index);
+                    visitor.visit_field(path, field);
+                    go(field, visitor, path);
+                    path.pop();
+                }
+            }
+            ReflectRef::Enum(inner) => {
+                visitor.visit_enum(path, inner);
+                path.push_variant(inner.variant_name());
+                for (index, field) in inner.fields().enumerate() {
+                    let field = match field {
+                        VariantField::Struct(name, field) => {
+                            path.push_field(name);
+                            field
+                        }
+                        VariantField::Tuple(field) => {
+                            path.push_field(index);
+                            field
+                        }
+                    };
+                    visitor.visit_field(path, field);
+                    go(field, visitor, path);
+                    path.pop();
+                }
+                path.pop();
+            }
+            ReflectRef::Array(inner) => {
+                visitor.visit_array(path, inner);
+                for (index, element) in inner.iter().enumerate() {
+                    path.push_get(index);
+                    visitor.visit_element(path, element);
+                    go(element, visitor, path);
+                    path.pop();
+                }
+            }
+            ReflectRef::List(inner) => {
+                visitor.visit_list(path, inner);
+                for (index, element) in inner.iter().enumerate() {
+                    path.push_get(index);
+                    visitor.visit_element(path, element);
+                    go(element, visitor, path);
+                    path.pop();
+                }
+            }
+            ReflectRef::Map(inner) => {
+                visitor.visit_map(path, inner);
+                for (key, value) in inner.iter() {
+                    path.push_get(key.to_value());
+                    visitor.visit_element(path, value);
+                    go(value, visitor, path);
+                    path.pop();
+                }
+            }
+            ReflectRef::Set(inner) => {
+                visitor.visit_set(path, inner);
+                // elements of sets can't be accessed by key so they all get the path of the set
+                for element in inner.iter() {
+                    visitor.visit_element(path, element);
+                    go(element, visitor, path);
+                }
+            }
+            ReflectRef::Scalar(inner) => visitor.visit_scalar(path, inner),
+            ReflectRef::Opaque(inner) => visitor.visit_opaque(path, inner),
+        }
+    }
+
+    go(value, visitor, &mut KeyPath::default());
+}
+
+/// A [`walk`] that can be paused and resumed.
+///
+/// Each call to [`step`](Self::step) visits at most a given number of values, so walking a huge
+/// value can be spread across several frames. The visitor callbacks happen in the same order as
+/// with [`walk`].
+///
+/// The cursor doesn't borrow the value being walked, so it has to be passed to every call. Values
+/// are looked up again by their path when they're visited, which means the value may be changed
+/// in between calls. Values that no longer exist are skipped, and new values are only walked if
+/// the container they were added to hasn't been visited yet. Elements of sets are tracked by their
+/// position in the set.
+///
+/// # Example
+///
+/// ```
+/// use mirror_mirror::{Reflect, ScalarRef};
+/// use mirror_mirror::key_path::KeyPath;
+/// use mirror_mirror::visit::{ReflectVisitor, WalkCursor, WalkStatus};
+///
+/// #[derive(Default)]
+/// struct Sum(i64);
+///
+/// impl ReflectVisitor for Sum {
+///     fn visit_scalar(&mut self, _path: &KeyPath, value: ScalarRef<'_>) {
+///         if let ScalarRef::i64(n) = value {
+///             self.0 += n;
+///         }
+///     }
+/// }
+///
+/// let numbers = (1..=100).collect::<Vec<i64>>();
+///
+/// let mut cursor = WalkCursor::new();
+/// let mut sum = Sum::default();
+/// let mut frames = 0;
+/// while cursor.step(&numbers, &mut sum, 10) == WalkStatus::Paused {
+///     frames += 1;
+/// }
+///
+/// assert_eq!(sum.0, 5050);
+/// assert_eq!(frames, 10);
+/// ```
+#[derive(Debug, Clone)]
+pub struct WalkCursor {
+    stack: Vec<Pending>,
+}
+
+#[derive(Debug, Clone)]
+struct Pending {
+    steps: Vec<Step>,
+    visit: Visit,
+}
+
+#[derive(Debug, Clone)]
+enum Step {
+    Key(Key),
+    SetElement(usize),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Visit {
+    Root,
+    Field,
+    Element,
+}
+
+/// Whether a [`WalkCursor`] has finished.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WalkStatus {
+    /// Everything has been visited.
+    Done,
+    /// There are more values to visit.
+    Paused,
+}
+
+impl WalkCursor {
+    /// Create a cursor that starts at the root of the value.
+    pub fn new() -> Self {
+        Self {
+            stack: Vec::from([Pending {
+                steps: Vec::new(),
+                visit: Visit::Root,
+            }]),
+        }
+    }
+
+    /// Returns `true` if everything has been visited.
+    pub fn is_done(&self) -> bool {
+        self.stack.is_empty()
+    }
+
+    /// Start over from the root.
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Visit at most `budget` values inside `root`.
+    pub fn step(
+        &mut self,
+        root: &dyn Reflect,
+        visitor: &mut dyn ReflectVisitor,
+        budget: usize,
+    ) -> WalkStatus {
+        let mut remaining = budget;
+        self.step_while(root, visitor, || {
+            if remaining == 0 {
+                return false;
+            }
+            remaining -= 1;
+            true
+        })
+    }
+
+    /// Visit values inside `root` for as long as `keep_going` returns `true`.
+    ///
+    /// `keep_going` is called before each value is visited, so it can be used to stop after a
+    /// time budget runs out.
+    pub fn step_while(
+        &mut self,
+        root: &dyn Reflect,
+        visitor: &mut dyn ReflectVisitor,
+        mut keep_going: impl FnMut() -> bool,
+    ) -> WalkStatus {
+        while !self.stack.is_empty() {
+            if !keep_going() {
+                return WalkStatus::Paused;
+            }
+            if let Some(pending) = self.stack.pop() {
+                self.visit(root, visitor, pending);
+            }
+        }
+        WalkStatus::Done
+    }
+
+    fn visit(&mut self, root: &dyn Reflect, visitor: &mut dyn ReflectVisitor, pending: Pending) {
+        let Pending { steps, visit } = pending;
+
+        let Some(value) = steps.iter().try_fold(root, |value, step| match step {
+            Step::Key(key) => value_at_key(value, key),
+            Step::SetElement(index) => value.as_set()?.iter().nth(*index),
+        }) else {
+            return;
+        };
+
+        let mut path = KeyPath::default();
+        path.extend(steps.iter().filter_map(|step| match step {
+            Step::Key(key) => Some(key.clone()),
+            Step::SetElement(_) => None,
+        }));
+
+        match visit {
+            Visit::Root => {}
+            Visit::Field => visitor.visit_field(&path, value),
+            Visit::Element => visitor.visit_element(&path, value),
+        }
+
+        let children_start = self.stack.len();
+        let mut push = |child: &[Step], visit: Visit| {
+            let mut child_steps = steps.clone();
+            child_steps.extend_from_slice(child);
+            self.stack.push(Pending {
+                steps: child_steps,
+                visit,
+            });
+        };
+
+        match value.reflect_ref() {
+            ReflectRef::Struct(inner) => {
+                visitor.visit_struct(&path, inner);
+                for (name, _) in inner.fields() {
+                    push(&[Step::Key(Key::named_field(name))], Visit::Field);
+                }
+            }
+            ReflectRef::TupleStruct(inner) => {
+                visitor.visit_tuple_struct(&path, inner);
+                for index in 0..inner.fields_len() {
+                    push(&[Step::Key(Key::numbered_field(index))], Visit::Field);
+                }
+            }
+            ReflectRef::Tuple(inner) => {
+                visitor.visit_tuple(&path, inner);
+                for index in 0..inner.fields_len() {
+                    push(&[Step::Key(Key::numbered_field(index))], Visit::Field);
+                }
+            }
+            ReflectRef::Enum(inner) => {
+                visitor.visit_enum(&path, inner);
+                let variant = Step::Key(Key::variant(inner.variant_name()));
+                for (index, field) in inner.fields().enumerate() {
+                    let key = match field {
+                        VariantField::Struct(name, _) => Key::named_field(name),
+                        VariantField::Tuple(_) => Key::numbered_field(index),
+                    };
+                    push(&[variant.clone(), Step::Key(key)], Visit::Field);
+                }
+            }
+            ReflectRef::Array(inner) => {
+                visitor.visit_array(&path, inner);
+                for index in 0..inner.len() {
+                    push(&[Step::Key(Key::get(index))], Visit::Element);
+                }
+            }
+            ReflectRef::List(inner) => {
+                visitor.visit_list(&path, inner);
+                for index in 0..inner.len() {
+                    push(&[Step::Key(Key::get(index))], Visit::Element);
+                }
+            }
+            ReflectRef::Map(inner) => {
+                visitor.visit_map(&path, inner);
+                for (key, _) in inner.iter() {
+                    push(&[Step::Key(Key::get(key.to_value()))], Visit::Element);
+                }
+            }
+            ReflectRef::Set(inner) => {
+                visitor.visit_set(&path, inner);
+                for index in 0..inner.len() {
+                    push(&[Step::SetElement(index)], Visit::Element);
+                }
+            }
+            ReflectRef::Scalar(inner) => visitor.visit_scalar(&path, inner),
+            ReflectRef::Opaque(inner) => visitor.visit_opaque(&path, inner),
+        }
+
+        // children are popped from the back so the first one has to be last
+        self.stack[children_start..].reverse();
+    }
+}
+
+impl Default for WalkCursor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Callbacks for [`walk_mut`].
+///
+/// Like [`ReflectVisitor`] but with mutable access to the values. Elements of sets cannot be
+/// mutated, as that could change their hashes, so only [`visit_set`](Self::visit_set) is
+/// called for sets.
+#[allow(unused_variables)]
+pub trait ReflectVisitorMut {
+    fn visit_struct(&mut self, path: &KeyPath, value: &mut dyn Struct) {}
+
+    fn visit_tuple_struct(&mut self, path: &KeyPath, value: &mut dyn TupleStruct) {}
+
+    fn visit_tuple(&mut self, path: &KeyPath, value: &mut dyn Tuple) {}
+
+    fn visit_enum(&mut self, path: &KeyPath, value: &mut dyn Enum) {}
+
+    fn visit_array(&mut self, path: &KeyPath, value: &mut dyn Array) {}
+
+    fn visit_list(&mut self, path: &KeyPath, value: &mut dyn List) {}
+
+    fn visit_map(&mut self, path: &KeyPath, value: &mut dyn Map) {}
+
+    fn visit_set(&mut self, path: &KeyPath, value: &mut dyn Set) {}
+
+    fn visit_field(&mut self, path: &KeyPath, value: &mut dyn Reflect) {}
+
+    fn visit_element(&mut self, path: &KeyPath, value: &mut dyn Reflect) {}
+
+    fn visit_scalar(&mut self, path: &KeyPath, value: ScalarMut<'_>) {}
+
+    fn visit_opaque(&mut self, path: &KeyPath, value: &mut dyn Reflect) {}
+}
+
+/// Walk through `value` and everything nested inside it, calling `visitor` along the way.
+///
+/// Values are walked after the visitor has been called for them, so changes the visitor makes,
+/// such as switching an enum's variant or pushing to a list, are reflected in what gets walked.
+///
+/// # Example
+///
+/// ```
+/// use mirror_mirror::{Reflect, ScalarMut};
+/// use mirror_mirror::key_path::KeyPath;
+/// use mirror_mirror::visit::{walk_mut, ReflectVisitorMut};
+///
+/// #[derive(Reflect, Clone, Debug)]
+/// struct Stats {
+///     health: f32,
+///     speed: f32,
+///     boosts: Vec<f32>,
+/// }
+///
+/// struct Scale(f32);
+///
+/// impl ReflectVisitorMut for Scale {
+///     fn visit_scalar(&mut self, _path: &KeyPath, value: ScalarMut<'_>) {
+///         if let ScalarMut::f32(n) = value {
+///             *n *= self.0;
+///         }
+///     }
+/// }
+///
+/// let mut stats = Stats {
+///     health: 1.0,
+///     speed: 2.0,
+///     boosts: Vec::from([3.0]),
+/// };
+/// walk_mut(&mut stats, &mut Scale(2.0));
+///
+/// assert_eq!(stats.health, 2.0);
+/// assert_eq!(stats.speed, 4.0);
+/// assert_eq!(stats.boosts, [6.0]);
+/// ```
+pub fn walk_mut(value: &mut dyn Reflect, visitor: &mut dyn ReflectVisitorMut) {
+    fn go(value: &mut dyn Reflect, visitor: &mut dyn ReflectVisitorMut, path: &mut KeyPath) {
+        match value.reflect_mut() {
+            ReflectMut::Struct(inner) => {
+                visitor.visit_struct(path, inner);
+                for (name, field) in inner.fields_mut() {
+                    path.push_field(name);
+                    visitor.visit_field(path, field);
+                    go(field, visitor, path);
+                    path.pop();
+                }
+            }
+            ReflectMut::TupleStruct(inner) => {
+                visitor.visit_tuple_struct(path, inner);
+                for (index, field) in inner.fields_mut().enumerate() {
+                    path.push_field(index);
+                    visitor.visit_field(path, field);
+                    go(field, visitor, path);
+                    path.pop();
+                }
+            }
+            ReflectMut::Tuple(inner) => {
+                visitor.visit_tuple(path, inner);
+                for (index, field) in inner.fields_mut().enumerate() {
+                    path.push_field(index);
+                    visitor.visit_field(path, field);
+                    go(field, visitor, path);
+                    path.pop();
+                }
+            }
+            ReflectMut::Enum(inner) => {
+                visitor.visit_enum(path, inner);
+                path.push_variant(inner.variant_name());
+                for (index, field) in inner.fields_mut().enumerate() {
+                    let field = match field {
+                        VariantFieldMut::Struct(name, field) => {
+                            path.push_field(name);
+                            field
+                        }
+                        VariantFieldMut::Tuple(field) => {
+                            path.push_field(index);
+                            field
+                        }
+                    };
+                    visitor.visit_field(path, field);
+                    go(field, visitor, path);
+                    path.pop();
+                }
+                path.pop();
+            }
+            ReflectMut::Array(inner) => {
+                visitor.visit_array(path, inner);
+                for (index, element) in inner.iter_mut().enumerate() {
+                    path.push_get(index);
+                    visitor.visit_element(path, element);
+                    go(element, visitor, path);
+                    path.pop();
+                }
+            }
+            ReflectMut::List(inner) => {
+                visitor.visit_list(path, inner);
+                for (index, element) in inner.iter_mut().enumerate() {
+                    path.push_get(index);
+                    visitor.visit_element(path, element);
+                    go(element, visitor, path);
+                    path.pop();
+                }
+            }
+            ReflectMut::Map(inner) => {
+                visitor.visit_map(path, inner);
+                for (key, value) in inner.iter_mut() {
+                    path.push_get(key.to_value());
+                    visitor.visit_element(path, value);
+                    go(value, visitor, path);
+                    path.pop();
+                }
+            }
+            ReflectMut::Set(inner) => visitor.visit_set(path, inner),
+            ReflectMut::Scalar(inner) => visitor.visit_scalar(path, inner),
+            ReflectMut::Opaque(inner) => visitor.visit_opaque(path, inner),
+        }
+    }
+
+    go(value, visitor, &mut KeyPath::default());
+}